@@ -20,6 +20,7 @@ use roc_load::{ExpectMetadata, Threading};
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_packaging::tarball::Compression;
+use roc_reporting::report::RenderTarget;
 use roc_target::Target;
 use std::env;
 use std::ffi::{CString, OsStr, OsString};
@@ -28,6 +29,7 @@ use std::mem::ManuallyDrop;
 use std::os::raw::{c_char, c_int};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::AtomicBool;
 use std::time::Instant;
 use strum::IntoEnumIterator;
 use target_lexicon::{Architecture, Triple};
@@ -35,7 +37,10 @@ use target_lexicon::{Architecture, Triple};
 use tempfile::TempDir;
 
 mod format;
+mod watch;
 pub use format::{format_files, format_src, FormatMode};
+pub use watch::watch;
+use watch::Watcher;
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
@@ -49,6 +54,7 @@ pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
+pub const CMD_EXPLAIN: &str = "explain";
 
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_BUNDLE: &str = "bundle";
@@ -67,12 +73,15 @@ pub const FLAG_STDIN: &str = "stdin";
 pub const FLAG_STDOUT: &str = "stdout";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_WATCH: &str = "watch";
+pub const FLAG_JSON: &str = "json";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_DIR: &str = "GLUE_DIR";
 pub const GLUE_SPEC: &str = "GLUE_SPEC";
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
+pub const ERROR_CODE: &str = "ERROR_CODE";
 
 const VERSION: &str = include_str!("../../../version.txt");
 const DEFAULT_GENERATED_DOCS_DIR: &str = "generated-docs";
@@ -104,7 +113,7 @@ pub fn build_app() -> Command {
 
     let flag_debug = Arg::new(FLAG_DEBUG)
         .long(FLAG_DEBUG)
-        .help("Store LLVM debug information in the generated program")
+        .help("Store debug information (source lines and named values) in the generated program")
         .action(ArgAction::SetTrue)
         .required(false);
 
@@ -132,6 +141,18 @@ pub fn build_app() -> Command {
         .value_parser(value_parser!(u32))
         .required(false);
 
+    let flag_watch = Arg::new(FLAG_WATCH)
+        .long(FLAG_WATCH)
+        .help("Run again every time one of the .roc files (or ingested files) used changes")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_json = Arg::new(FLAG_JSON)
+        .long(FLAG_JSON)
+        .help("Print problems as JSON, one object per line\n(Anything else, like the summary of how many problems were found, goes to stderr.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .value_parser(value_parser!(PathBuf))
@@ -168,6 +189,7 @@ pub fn build_app() -> Command {
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
             .arg(flag_wasm_stack_size_kb)
+            .arg(flag_json.clone())
             .arg(
                 Arg::new(FLAG_TARGET)
                     .long(FLAG_TARGET)
@@ -216,6 +238,7 @@ pub fn build_app() -> Command {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_watch.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -251,6 +274,7 @@ pub fn build_app() -> Command {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_watch.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone().last(true))
         )
@@ -290,6 +314,8 @@ pub fn build_app() -> Command {
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_watch)
+            .arg(flag_json.conflicts_with(FLAG_WATCH))
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -298,6 +324,14 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_EXPLAIN)
+            .about("Explain an error code from a compiler report, like E0301")
+            .arg(
+                Arg::new(ERROR_CODE)
+                    .help("The code to explain, as printed in the report's header")
+                    .required(true),
+            )
+        )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
@@ -412,22 +446,6 @@ pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
 
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
-    use roc_build::program::report_problems_monomorphized;
-    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError};
-    use roc_packaging::cache;
-    use roc_target::TargetInfo;
-
-    let start_time = Instant::now();
-    let arena = Bump::new();
-    let opt_level = opt_level_from_flags(matches);
-
-    let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
-        None => Threading::AllAvailable,
-        Some(0) => user_error!("cannot build with at most 0 threads"),
-        Some(1) => Threading::Single,
-        Some(n) => Threading::AtMost(*n),
-    };
-
     let path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
 
     // Spawn the root task
@@ -451,22 +469,55 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         process::exit(1);
     }
 
+    if matches.get_flag(FLAG_WATCH) {
+        watch(path, |source_paths| {
+            test_once(matches, &triple, path, source_paths)
+        })
+    } else {
+        test_once(matches, &triple, path, &mut Vec::new())
+    }
+}
+
+#[cfg(not(windows))]
+fn test_once(
+    matches: &ArgMatches,
+    triple: &Triple,
+    path: &Path,
+    source_paths: &mut Vec<PathBuf>,
+) -> io::Result<i32> {
+    use roc_build::program::report_problems_monomorphized;
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError};
+    use roc_packaging::cache;
+    use roc_target::TargetInfo;
+
+    let start_time = Instant::now();
+    let arena = Bump::new();
+    let opt_level = opt_level_from_flags(matches);
+
+    let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
+        None => Threading::AllAvailable,
+        Some(0) => user_error!("cannot build with at most 0 threads"),
+        Some(1) => Threading::Single,
+        Some(n) => Threading::AtMost(*n),
+    };
+
     let arena = &arena;
-    let target = &triple;
-    let opt_level = opt_level;
+    let target = triple;
     let target_info = TargetInfo::from(target);
     // TODO may need to determine this dynamically based on dev builds.
     let function_kind = FunctionKind::LambdaSet;
+    // TODO: expose this from CLI?
+    let render = RenderTarget::ColorTerminal;
 
     // Step 1: compile the app and generate the .o file
     let load_config = LoadConfig {
         target_info,
         function_kind,
-        // TODO: expose this from CLI?
-        render: roc_reporting::report::RenderTarget::ColorTerminal,
+        render,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Test,
+        opt_level,
     };
     let load_result = roc_load::load_and_monomorphize(
         arena,
@@ -481,10 +532,20 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             return handle_loading_problem(problem);
         }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
-            return handle_error_module(module, start_time.elapsed(), path.as_os_str(), false);
+            source_paths.extend(module.source_paths());
+
+            return handle_error_module(
+                module,
+                start_time.elapsed(),
+                path.as_os_str(),
+                false,
+                render,
+            );
         }
     };
-    let problems = report_problems_monomorphized(&mut loaded);
+    source_paths.extend(loaded.source_paths());
+
+    let problems = report_problems_monomorphized(&mut loaded, render);
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
 
@@ -519,7 +580,7 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
 
     let (failed, passed) = roc_repl_expect::run::run_toplevel_expects(
         &mut writer,
-        roc_reporting::report::RenderTarget::ColorTerminal,
+        render,
         arena,
         interns,
         &layout_interner.into_global(),
@@ -682,10 +743,12 @@ pub fn build(
     // Note: This allows using `--dev` with `--optimize`.
     // This means frontend optimizations and dev backend.
     let code_gen_backend = if matches.get_flag(FLAG_DEV) {
-        if matches!(triple.architecture, Architecture::Wasm32) {
-            CodeGenBackend::Wasm
-        } else {
-            CodeGenBackend::Assembly(AssemblyBackendMode::Binary)
+        match triple.architecture {
+            Architecture::Wasm32 => CodeGenBackend::Wasm,
+            Architecture::Riscv64(_) => user_error!(
+                "The dev backend (`--dev`) does not support riscv64 yet. Build without `--dev` to use the LLVM backend."
+            ),
+            _ => CodeGenBackend::Assembly(AssemblyBackendMode::Binary),
         }
     } else {
         let backend_mode = match opt_level {
@@ -747,7 +810,111 @@ pub fn build(
         emit_debug_info,
     };
 
-    let load_config = standard_load_config(&triple, build_ordering, threading);
+    let render = match matches.try_get_one::<bool>(FLAG_JSON) {
+        Ok(Some(true)) => RenderTarget::Json,
+        _ => RenderTarget::ColorTerminal,
+    };
+
+    let watching = matches!(matches.try_get_one::<bool>(FLAG_WATCH), Ok(Some(true)));
+
+    if watching {
+        debug_assert_eq!(config, BuildAndRunIfNoErrors);
+
+        let mut watcher = Watcher::new(path);
+
+        loop {
+            watcher.start_run();
+
+            // Unlike a single build, the arena is dropped after each run
+            let arena = Bump::new();
+            let mut load_config =
+                standard_load_config(&triple, BuildOrdering::BuildIfChecks, threading, opt_level);
+            load_config.render = render;
+
+            let res_binary_path = build_file(
+                &arena,
+                &triple,
+                path.to_owned(),
+                code_gen_options,
+                emit_timings,
+                link_type,
+                linking_strategy,
+                prebuilt,
+                wasm_dev_stack_bytes,
+                roc_cache_dir,
+                load_config,
+                out_path,
+            );
+
+            let changed = match res_binary_path {
+                Ok(BuiltFile {
+                    binary_path,
+                    problems,
+                    total_time,
+                    expect_metadata,
+                    source_paths,
+                }) => {
+                    watcher.set_source_paths(source_paths);
+
+                    if problems.fatally_errored {
+                        problems.print_to_stdout(total_time);
+                        println!(".\n\nCannot run program due to fatal error…");
+
+                        false
+                    } else {
+                        if problems.warnings > 0 {
+                            problems.print_to_stdout(total_time);
+                            println!(
+                                ".\n\nRunning program…\n\n\x1B[36m{}\x1B[39m",
+                                "─".repeat(80)
+                            );
+                        }
+
+                        let args = matches
+                            .get_many::<OsString>(ARGS_FOR_APP)
+                            .unwrap_or_default()
+                            .map(|s| s.as_os_str());
+                        let ran = std::fs::read(&binary_path).and_then(|bytes| {
+                            roc_dev_watched(&arena, &watcher, args, &bytes, expect_metadata)
+                        });
+
+                        ran.unwrap_or_else(|error| {
+                            watch::report_run_error(&error);
+
+                            false
+                        })
+                    }
+                }
+                Err(BuildFileError::ErrorModule { module, total_time }) => {
+                    watcher.set_source_paths(module.source_paths());
+                    let reported =
+                        handle_error_module(module, total_time, path.as_os_str(), true, render);
+
+                    if let Err(error) = reported {
+                        watch::report_run_error(&error);
+                    }
+
+                    false
+                }
+                Err(BuildFileError::LoadingProblem(problem)) => {
+                    if let Err(error) = handle_loading_problem(problem) {
+                        watch::report_run_error(&error);
+                    }
+
+                    false
+                }
+            };
+
+            // If a change killed the app, rebuild right away
+            if !changed {
+                watch::print_watching();
+                watcher.wait_for_change(&AtomicBool::new(false));
+            }
+        }
+    }
+
+    let mut load_config = standard_load_config(&triple, build_ordering, threading, opt_level);
+    load_config.render = render;
 
     let res_binary_path = build_file(
         &arena,
@@ -770,6 +937,7 @@ pub fn build(
             problems,
             total_time,
             expect_metadata,
+            source_paths: _,
         }) => {
             match config {
                 BuildOnly => {
@@ -784,8 +952,13 @@ pub fn build(
                     // since the process is about to exit anyway.
                     // std::mem::forget(arena);
 
-                    problems.print_to_stdout(total_time);
-                    println!(" while successfully building:\n\n    {generated_filename}");
+                    if let RenderTarget::Json = render {
+                        problems.print_to_stderr(total_time);
+                        eprintln!(" while successfully building:\n\n    {generated_filename}");
+                    } else {
+                        problems.print_to_stdout(total_time);
+                        println!(" while successfully building:\n\n    {generated_filename}");
+                    }
 
                     // Return a nonzero exit code if there were problems
                     Ok(problems.exit_code())
@@ -858,7 +1031,7 @@ pub fn build(
             }
         }
         Err(BuildFileError::ErrorModule { module, total_time }) => {
-            handle_error_module(module, total_time, path.as_os_str(), true)
+            handle_error_module(module, total_time, path.as_os_str(), true, render)
         }
         Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
     }
@@ -1026,6 +1199,16 @@ enum ExecutableFile {
     OnDisk(TempDir, PathBuf),
 }
 
+#[cfg(target_os = "linux")]
+impl Drop for ExecutableFile {
+    fn drop(&mut self) {
+        // `roc dev --watch` creates a new one for every run
+        let ExecutableFile::MemFd(fd, _) = self;
+
+        unsafe { libc::close(*fd) };
+    }
+}
+
 impl ExecutableFile {
     #[cfg(target_os = "linux")]
     const SYSCALL: &'static str = "fexecve";
@@ -1074,7 +1257,82 @@ fn roc_dev_native(
     envp: bumpalo::collections::Vec<*const c_char>,
     expect_metadata: ExpectMetadata,
 ) -> ! {
-    use std::sync::{atomic::AtomicBool, Arc};
+    let exit_code = roc_dev_native_help(arena, executable, argv, envp, expect_metadata, |_| {});
+
+    std::process::exit(exit_code)
+}
+
+/// Runs the app in a child process like [roc_dev_native] does, but kills it as soon as one of
+/// the watched files changes. Returns whether that happened, as opposed to the app exiting.
+#[cfg(target_family = "unix")]
+fn roc_dev_watched<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
+    arena: &Bump,
+    watcher: &Watcher,
+    args: I,
+    binary_bytes: &[u8],
+    expect_metadata: ExpectMetadata,
+) -> io::Result<bool> {
+    use bumpalo::collections::CollectIn;
+    use std::sync::atomic::Ordering;
+
+    let executable = roc_run_executable_file_path(binary_bytes)?;
+    let (argv_cstrings, envp_cstrings) = make_argv_envp(arena, &executable, args);
+
+    let argv: bumpalo::collections::Vec<*const c_char> = argv_cstrings
+        .iter()
+        .map(|s| s.as_ptr())
+        .chain([std::ptr::null()])
+        .collect_in(arena);
+
+    let envp: bumpalo::collections::Vec<*const c_char> = envp_cstrings
+        .iter()
+        .map(|s| s.as_ptr())
+        .chain([std::ptr::null()])
+        .collect_in(arena);
+
+    let app_exited = &AtomicBool::new(false);
+    let changed = &AtomicBool::new(false);
+
+    std::thread::scope(|scope| {
+        roc_dev_native_help(arena, executable, argv, envp, expect_metadata, |pid| {
+            scope.spawn(move || {
+                if watcher.wait_for_change(app_exited) {
+                    changed.store(true, Ordering::Relaxed);
+
+                    unsafe { libc::kill(pid, libc::SIGKILL) };
+                }
+            });
+        });
+
+        app_exited.store(true, Ordering::Relaxed);
+    });
+
+    Ok(changed.load(Ordering::Relaxed))
+}
+
+#[cfg(not(target_family = "unix"))]
+fn roc_dev_watched<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
+    _arena: &Bump,
+    _watcher: &Watcher,
+    _args: I,
+    _binary_bytes: &[u8],
+    _expect_metadata: ExpectMetadata,
+) -> io::Result<bool> {
+    internal_error!("running `expect`s does not currently work on windows")
+}
+
+/// Forks and runs the app, rendering the `expect`s and `dbg`s it reports, until it exits.
+/// `on_spawn` is called with the app's pid right after it starts. Returns the app's exit status.
+#[cfg(target_family = "unix")]
+fn roc_dev_native_help(
+    arena: &Bump,
+    executable: ExecutableFile,
+    argv: bumpalo::collections::Vec<*const c_char>,
+    envp: bumpalo::collections::Vec<*const c_char>,
+    expect_metadata: ExpectMetadata,
+    on_spawn: impl FnOnce(libc::pid_t),
+) -> i32 {
+    use std::sync::Arc;
 
     use roc_repl_expect::run::{ChildProcessMsg, ExpectMemory};
 
@@ -1113,8 +1371,11 @@ fn roc_dev_native(
         }
         pid @ 1.. => {
             let sigchld = Arc::new(AtomicBool::new(false));
-            signal_hook::flag::register(signal_hook::consts::SIGCHLD, Arc::clone(&sigchld))
-                .unwrap();
+            let sigchld_id =
+                signal_hook::flag::register(signal_hook::consts::SIGCHLD, Arc::clone(&sigchld))
+                    .unwrap();
+
+            on_spawn(pid);
 
             let exit_code = loop {
                 match memory.wait_for_child(sigchld.clone()) {
@@ -1154,7 +1415,9 @@ fn roc_dev_native(
                 }
             };
 
-            std::process::exit(exit_code)
+            signal_hook::low_level::unregister(sigchld_id);
+
+            exit_code
         }
         _ => unreachable!(),
     }
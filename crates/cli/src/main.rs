@@ -3,11 +3,11 @@ use bumpalo::Bump;
 use roc_build::link::LinkType;
 use roc_build::program::{check_file, CodeGenBackend};
 use roc_cli::{
-    build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
-    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL,
-    CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB,
-    FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR,
-    GLUE_SPEC, ROC_FILE,
+    build_app, format_files, format_src, test, watch, BuildConfig, FormatMode, CMD_BUILD,
+    CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_EXPLAIN, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE,
+    CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, ERROR_CODE,
+    FLAG_CHECK, FLAG_DEV, FLAG_JSON, FLAG_LIB, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT,
+    FLAG_TARGET, FLAG_TIME, FLAG_WATCH, GLUE_DIR, GLUE_SPEC, ROC_FILE,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
@@ -15,6 +15,9 @@ use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
 use roc_load::{FunctionKind, LoadingProblem, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
+use roc_problem::code::ErrorCode;
+use roc_reporting::explain::explanation;
+use roc_reporting::report::{pretty_header, RenderTarget};
 use roc_target::{get_target_triple_str, Target};
 use std::fs::{self, FileType};
 use std::io::{self, Read, Write};
@@ -191,8 +194,6 @@ fn main() -> io::Result<()> {
             )?)
         }
         Some((CMD_CHECK, matches)) => {
-            let arena = Bump::new();
-
             let emit_timings = matches.get_flag(FLAG_TIME);
             let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
@@ -201,56 +202,76 @@ fn main() -> io::Result<()> {
                 Some(1) => Threading::Single,
                 Some(n) => Threading::AtMost(*n),
             };
+            let render = if matches.get_flag(FLAG_JSON) {
+                RenderTarget::Json
+            } else {
+                RenderTarget::ColorTerminal
+            };
 
-            match check_file(
-                &arena,
-                roc_file_path.to_owned(),
-                emit_timings,
-                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-                threading,
-            ) {
-                Ok((problems, total_time)) => {
-                    println!(
-                        "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
-                        if problems.errors == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.errors,
-                        if problems.errors == 1 {
-                            "error"
-                        } else {
-                            "errors"
-                        },
-                        if problems.warnings == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.warnings,
-                        if problems.warnings == 1 {
-                            "warning"
+            let check = |source_paths: &mut Vec<PathBuf>| -> io::Result<i32> {
+                let arena = Bump::new();
+
+                let res = check_file(
+                    &arena,
+                    roc_file_path.to_owned(),
+                    emit_timings,
+                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    threading,
+                    render,
+                );
+
+                match res {
+                    Ok((problems, total_time, paths)) => {
+                        source_paths.extend(paths);
+
+                        if let RenderTarget::Json = render {
+                            problems.print_to_stderr(total_time);
+                            eprintln!(".");
                         } else {
-                            "warnings"
-                        },
-                        total_time.as_millis(),
-                    );
+                            problems.print_to_stdout(total_time);
+                            println!(".");
+                        }
+
+                        Ok(problems.exit_code())
+                    }
 
-                    Ok(problems.exit_code())
+                    Err(LoadingProblem::FormattedReport(report)) => {
+                        print!("{report}");
+
+                        Ok(1)
+                    }
+                    Err(other) => {
+                        panic!("build_file failed with error:\n{other:?}");
+                    }
                 }
+            };
+
+            if matches.get_flag(FLAG_WATCH) {
+                watch(roc_file_path, check)
+            } else {
+                check(&mut Vec::new())
+            }
+        }
+        Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
+        Some((CMD_EXPLAIN, matches)) => {
+            let input = matches.get_one::<String>(ERROR_CODE).unwrap();
 
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{report}");
+            let explained =
+                ErrorCode::parse(input).and_then(|code| explanation(code).map(|text| (code, text)));
 
-                    Ok(1)
+            match explained {
+                Some((code, text)) => {
+                    print!("{}\n\n{}", pretty_header(&code.to_string()), text);
+
+                    Ok(0)
                 }
-                Err(other) => {
-                    panic!("build_file failed with error:\n{other:?}");
+                None => {
+                    eprintln!("`{input}` is not an error code I know about. Codes look like E0301, and are printed in the header of each report.");
+
+                    Ok(1)
                 }
             }
         }
-        Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
         Some((CMD_DOCS, matches)) => {
             let root_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let out_dir = matches.get_one::<OsString>(FLAG_OUTPUT).unwrap();
@@ -0,0 +1,396 @@
+//! Re-running a command whenever one of the files it depends on changes, for `--watch`.
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// How often to check the watched files for changes. We poll modification times rather than
+/// subscribing to file system events; that needs no platform-specific code, and a Roc project
+/// only has a handful of files.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Editors often save a file in several steps, so after we see a change we wait until the files
+/// stop changing for this long before re-running.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What we know about a watched file; `None` in a [`Snapshot`] means it doesn't exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Snapshot {
+    files: BTreeMap<PathBuf, Option<FileStamp>>,
+    /// Every directory under the root directory. Adding, removing or renaming a file changes the
+    /// modification time of its directory, so we only walk the directory tree again when one of
+    /// these changed, and otherwise just check the files we already know about.
+    dirs: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+/// Watches every .roc file in the root module's directory (so that newly added modules are
+/// noticed too) plus every file that the latest run read, some of which may live elsewhere,
+/// like the modules of packages and ingested files.
+#[derive(Debug)]
+pub struct Watcher {
+    root_dir: PathBuf,
+    source_paths: Vec<PathBuf>,
+    snapshot: Snapshot,
+    poll_interval: Duration,
+    settle_interval: Duration,
+}
+
+impl Watcher {
+    pub fn new(root_path: &Path) -> Self {
+        Self::with_intervals(root_path, POLL_INTERVAL, SETTLE_INTERVAL)
+    }
+
+    fn with_intervals(
+        root_path: &Path,
+        poll_interval: Duration,
+        settle_interval: Duration,
+    ) -> Self {
+        let root_dir = match root_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let mut watcher = Self {
+            root_dir,
+            source_paths: vec![root_path.to_path_buf()],
+            snapshot: Snapshot::default(),
+            poll_interval,
+            settle_interval,
+        };
+
+        watcher.snapshot = watcher.scan();
+
+        watcher
+    }
+
+    /// Clears the terminal and records the current state of the watched files, so that changes
+    /// made from now on, even while the run is still going, trigger another run.
+    pub fn start_run(&mut self) {
+        print!("\x1B[2J\x1B[3J\x1B[H");
+        let _ = io::stdout().flush();
+
+        self.snapshot = self.scan();
+    }
+
+    /// Watches the files that the latest run read. A run that failed before it knew which files
+    /// it needed reports none, in which case we keep watching the ones from the run before.
+    pub fn set_source_paths(&mut self, source_paths: Vec<PathBuf>) {
+        if source_paths.is_empty() {
+            return;
+        }
+
+        self.source_paths = source_paths;
+
+        let mut snapshot = self.scan();
+        for (path, stamp) in snapshot.files.iter_mut() {
+            match self.snapshot.files.get(path) {
+                Some(before) => *stamp = *before,
+                // A source file we hadn't seen before the run started is compared against its
+                // current state, but a module that appeared in the root directory during the run
+                // may have been missed by it, so that counts as a change.
+                None if !self.source_paths.contains(path) => *stamp = None,
+                None => {}
+            }
+        }
+
+        self.snapshot = snapshot;
+    }
+
+    /// Blocks until one of the watched files changes, returning `true`, or until `stop` is set,
+    /// returning `false`.
+    pub fn wait_for_change(&self, stop: &AtomicBool) -> bool {
+        let mut current = self.snapshot.clone();
+
+        loop {
+            std::thread::sleep(self.poll_interval);
+
+            if stop.load(Ordering::Relaxed) {
+                return false;
+            }
+
+            current = self.rescan(&current);
+
+            if current.files != self.snapshot.files {
+                loop {
+                    std::thread::sleep(self.settle_interval);
+
+                    let next = self.rescan(&current);
+
+                    if next.files == current.files {
+                        return true;
+                    }
+
+                    current = next;
+                }
+            }
+        }
+    }
+
+    fn scan(&self) -> Snapshot {
+        let mut roc_files = Vec::new();
+        let mut dirs = BTreeMap::new();
+        find_roc_files(&self.root_dir, &mut roc_files, &mut dirs);
+
+        let files = roc_files
+            .into_iter()
+            .chain(self.source_paths.iter().cloned())
+            .map(|path| {
+                let stamp = file_stamp(&path);
+
+                (path, stamp)
+            })
+            .collect();
+
+        Snapshot { files, dirs }
+    }
+
+    fn rescan(&self, previous: &Snapshot) -> Snapshot {
+        let dirs_changed = previous
+            .dirs
+            .iter()
+            .any(|(dir, modified)| dir_modified(dir) != *modified);
+
+        if dirs_changed {
+            return self.scan();
+        }
+
+        let files = previous
+            .files
+            .keys()
+            .map(|path| (path.clone(), file_stamp(path)))
+            .collect();
+
+        Snapshot {
+            files,
+            dirs: previous.dirs.clone(),
+        }
+    }
+}
+
+/// Runs `run` now, and then again every time one of the files it read changes, until the process
+/// is interrupted. `run` reports the files it read by pushing them onto its argument.
+pub fn watch(
+    root_path: &Path,
+    mut run: impl FnMut(&mut Vec<PathBuf>) -> io::Result<i32>,
+) -> io::Result<i32> {
+    let mut watcher = Watcher::new(root_path);
+    let never_stop = AtomicBool::new(false);
+
+    loop {
+        watcher.start_run();
+
+        let mut source_paths = Vec::new();
+        if let Err(error) = run(&mut source_paths) {
+            report_run_error(&error);
+        }
+        watcher.set_source_paths(source_paths);
+
+        print_watching();
+        watcher.wait_for_change(&never_stop);
+    }
+}
+
+/// An I/O error ends the run it happened in, but not watch mode; the next change may well fix it.
+pub fn report_run_error(error: &io::Error) {
+    eprintln!("\n\x1B[31mThis run failed: {error}\x1B[39m");
+}
+
+pub fn print_watching() {
+    println!("\n\x1B[36mWatching for changes… (Press Ctrl-C to stop.)\x1B[39m");
+}
+
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = std::fs::metadata(path).ok()?;
+
+    Some(FileStamp {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
+
+fn dir_modified(dir: &Path) -> Option<SystemTime> {
+    std::fs::metadata(dir)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn find_roc_files(
+    dir: &Path,
+    roc_files: &mut Vec<PathBuf>,
+    dirs: &mut BTreeMap<PathBuf, Option<SystemTime>>,
+) {
+    dirs.insert(dir.to_path_buf(), dir_modified(dir));
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() && !is_hidden => {
+                find_roc_files(&path, roc_files, dirs)
+            }
+            Ok(file_type) if file_type.is_file() => {
+                if path.extension() == Some(OsStr::new("roc")) {
+                    roc_files.push(path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    const POLL: Duration = Duration::from_millis(10);
+
+    fn watcher(dir: &Path, settle_interval: Duration) -> Watcher {
+        Watcher::with_intervals(&dir.join("main.roc"), POLL, settle_interval)
+    }
+
+    /// Waits for a change for at most half a second.
+    fn changed(watcher: &Watcher) -> bool {
+        let stop = AtomicBool::new(false);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(500));
+                stop.store(true, Ordering::Relaxed);
+            });
+
+            watcher.wait_for_change(&stop)
+        })
+    }
+
+    fn project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("main.roc"), "app \"main\"").unwrap();
+        fs::create_dir(dir.path().join("Sub")).unwrap();
+        fs::write(dir.path().join("Sub").join("Dep.roc"), "interface Dep").unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn no_change() {
+        let dir = project();
+        let watcher = watcher(dir.path(), POLL);
+
+        assert!(!changed(&watcher));
+    }
+
+    #[test]
+    fn modified_file() {
+        let dir = project();
+        let watcher = watcher(dir.path(), POLL);
+
+        fs::write(
+            dir.path().join("Sub").join("Dep.roc"),
+            "interface Dep exposes []",
+        )
+        .unwrap();
+
+        assert!(changed(&watcher));
+    }
+
+    #[test]
+    fn added_file() {
+        let dir = project();
+        let watcher = watcher(dir.path(), POLL);
+
+        fs::write(dir.path().join("Sub").join("New.roc"), "interface New").unwrap();
+
+        assert!(changed(&watcher));
+    }
+
+    #[test]
+    fn removed_file() {
+        let dir = project();
+        let watcher = watcher(dir.path(), POLL);
+
+        fs::remove_file(dir.path().join("Sub").join("Dep.roc")).unwrap();
+
+        assert!(changed(&watcher));
+    }
+
+    #[test]
+    fn other_files_are_ignored() {
+        let dir = project();
+        let watcher = watcher(dir.path(), POLL);
+
+        fs::write(dir.path().join("notes.txt"), "todo").unwrap();
+        fs::write(dir.path().join("Sub").join(".Dep.roc.swp"), "swap").unwrap();
+
+        assert!(!changed(&watcher));
+    }
+
+    #[test]
+    fn source_paths_outside_root_dir() {
+        let dir = project();
+        let elsewhere = tempfile::tempdir().unwrap();
+        let ingested = elsewhere.path().join("data.txt");
+        fs::write(&ingested, "1").unwrap();
+
+        let mut watcher = watcher(dir.path(), POLL);
+        watcher.set_source_paths(vec![dir.path().join("main.roc"), ingested.clone()]);
+
+        assert!(!changed(&watcher));
+
+        fs::write(&ingested, "12").unwrap();
+
+        assert!(changed(&watcher));
+    }
+
+    #[test]
+    fn file_added_during_run() {
+        let dir = project();
+        let mut watcher = watcher(dir.path(), POLL);
+
+        watcher.start_run();
+        fs::write(dir.path().join("New.roc"), "interface New").unwrap();
+        watcher.set_source_paths(vec![dir.path().join("main.roc")]);
+
+        assert!(changed(&watcher));
+    }
+
+    #[test]
+    fn waits_for_changes_to_settle() {
+        let dir = project();
+        let settle = Duration::from_millis(200);
+        let watcher = watcher(dir.path(), settle);
+        let dep = dir.path().join("Sub").join("Dep.roc");
+        let last_write = Mutex::new(Instant::now());
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for len in 1..=5 {
+                    fs::write(&dep, "#".repeat(len)).unwrap();
+                    *last_write.lock().unwrap() = Instant::now();
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+            });
+
+            assert!(watcher.wait_for_change(&AtomicBool::new(false)));
+
+            assert_eq!(fs::read_to_string(&dep).unwrap(), "#####");
+            assert!(last_write.lock().unwrap().elapsed() >= settle);
+        });
+    }
+}
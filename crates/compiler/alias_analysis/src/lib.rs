@@ -590,6 +590,7 @@ fn stmt_spec<'a>(
             branches,
             default_branch,
             ret_layout: _lies,
+            region: _,
         } => {
             let mut cases = Vec::with_capacity(branches.len() + 1);
 
@@ -609,7 +610,7 @@ fn stmt_spec<'a>(
         Dbg { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
         Expect { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
         ExpectFx { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
-        Ret(symbol) => Ok(env.symbols[symbol]),
+        Ret(symbol, _) => Ok(env.symbols[symbol]),
         Refcounting(modify_rc, continuation) => {
             apply_refcount_operation(builder, env, block, modify_rc)?;
 
@@ -620,6 +621,7 @@ fn stmt_spec<'a>(
             parameters,
             body,
             remainder,
+            region: _,
         } => {
             let mut type_ids = Vec::new();
 
@@ -672,7 +674,7 @@ fn stmt_spec<'a>(
 
             builder.add_sub_block(block, BlockExpr(cont_block, cont_value_id))
         }
-        Jump(id, symbols) => {
+        Jump(id, symbols, _) => {
             let ret_type_id = layout_spec(env, builder, interner, interner.get_repr(layout))?;
             let argument = build_tuple_value(builder, env, block, symbols)?;
 
@@ -1113,7 +1115,7 @@ fn lowlevel_spec<'a>(
             // just dream up a unit value
             builder.add_make_tuple(block, &[])
         }
-        Eq | NotEq => {
+        Eq | NotEq | Compare => {
             // just dream up a unit value
             builder.add_make_tuple(block, &[])
         }
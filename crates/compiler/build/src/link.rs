@@ -515,7 +515,7 @@ pub fn rebuild_host(
                 shared_lib_path,
                 builtins_host_tempfile.path(),
             ),
-            Architecture::Aarch64(_) => build_zig_host_native(
+            Architecture::Aarch64(_) | Architecture::Riscv64(_) => build_zig_host_native(
                 &env_path,
                 &env_home,
                 host_dest.to_str().unwrap(),
@@ -787,6 +787,10 @@ fn get_target_str(target: &Triple) -> &str {
         && target.environment == target_lexicon::Environment::Gnu
     {
         "x86_64-windows-gnu"
+    } else if target.operating_system == OperatingSystem::Linux
+        && target.environment == target_lexicon::Environment::Musl
+    {
+        target_zig_str(target)
     } else {
         "native"
     }
@@ -866,14 +870,25 @@ fn link_linux(
     //        .output()
     //        .unwrap();
 
-    if let Architecture::X86_32(_) = target.architecture {
+    // zig ships its own libc for these targets, so we don't need a cross-compiling `ld` and sysroot.
+    // musl targets are linked statically, which is the whole point of asking for them.
+    if matches!(
+        target.architecture,
+        Architecture::X86_32(_) | Architecture::Riscv64(_)
+    ) || target.environment == target_lexicon::Environment::Musl
+    {
+        let zig_target = match target.architecture {
+            Architecture::X86_32(_) => "i386-linux-musl",
+            _ => target_zig_str(target),
+        };
+
         return Ok((
             zig()
                 .args(["build-exe"])
                 .args(input_paths)
                 .args([
                     "-target",
-                    "i386-linux-musl",
+                    zig_target,
                     "-lc",
                     &format!("-femit-bin={}", output_path.to_str().unwrap()),
                 ])
@@ -11,6 +11,12 @@ pub fn target_triple_str(target: &Triple) -> &'static str {
     //
     // https://stackoverflow.com/questions/15036909/clang-how-to-list-supported-target-architectures
     match target {
+        Triple {
+            architecture: Architecture::X86_64,
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
+            ..
+        } => "x86_64-unknown-linux-musl",
         Triple {
             architecture: Architecture::X86_64,
             operating_system: OperatingSystem::Linux,
@@ -25,11 +31,28 @@ pub fn target_triple_str(target: &Triple) -> &'static str {
             architecture: Architecture::Wasm32,
             ..
         } => "wasm32-unknown-unknown",
+        Triple {
+            architecture: Architecture::Aarch64(_),
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
+            ..
+        } => "aarch64-unknown-linux-musl",
         Triple {
             architecture: Architecture::Aarch64(_),
             operating_system: OperatingSystem::Linux,
             ..
         } => "aarch64-unknown-linux-gnu",
+        Triple {
+            architecture: Architecture::Riscv64(_),
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
+            ..
+        } => "riscv64-unknown-linux-musl",
+        Triple {
+            architecture: Architecture::Riscv64(_),
+            operating_system: OperatingSystem::Linux,
+            ..
+        } => "riscv64-unknown-linux-gnu",
         Triple {
             architecture: Architecture::Aarch64(_),
             operating_system: OperatingSystem::Darwin,
@@ -78,11 +101,28 @@ pub fn target_zig_str(target: &Triple) -> &'static str {
             operating_system: OperatingSystem::Linux,
             ..
         } => "i386-linux-gnu",
+        Triple {
+            architecture: Architecture::Aarch64(_),
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
+            ..
+        } => "aarch64-linux-musl",
         Triple {
             architecture: Architecture::Aarch64(_),
             operating_system: OperatingSystem::Linux,
             ..
         } => "aarch64-linux-gnu",
+        Triple {
+            architecture: Architecture::Riscv64(_),
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
+            ..
+        } => "riscv64-linux-musl",
+        Triple {
+            architecture: Architecture::Riscv64(_),
+            operating_system: OperatingSystem::Linux,
+            ..
+        } => "riscv64-linux-gnu",
         Triple {
             architecture: Architecture::X86_64,
             operating_system: OperatingSystem::Darwin,
@@ -110,6 +150,9 @@ pub fn init_arch(target: &Triple) {
         Architecture::Arm(_) if cfg!(feature = "target-arm") => {
             Target::initialize_arm(&InitializationConfig::default());
         }
+        Architecture::Riscv64(_) if cfg!(feature = "target-riscv64") => {
+            Target::initialize_riscv(&InitializationConfig::default());
+        }
         Architecture::Wasm32 if cfg!(feature = "target-wasm32") => {
             Target::initialize_webassembly(&InitializationConfig::default());
         }
@@ -132,6 +175,7 @@ pub fn arch_str(target: &Triple) -> &'static str {
         Architecture::X86_32(_) if cfg!(feature = "target-x86") => "x86",
         Architecture::Aarch64(_) if cfg!(feature = "target-aarch64") => "aarch64",
         Architecture::Arm(_) if cfg!(feature = "target-arm") => "arm",
+        Architecture::Riscv64(_) if cfg!(feature = "target-riscv64") => "riscv64",
         Architecture::Wasm32 if cfg!(feature = "target-webassembly") => "wasm32",
         _ => internal_error!(
             "TODO gracefully handle unsupported target architecture: {:?}",
@@ -165,13 +209,26 @@ pub fn target_machine(
             // failed macOS CI here: https://github.com/roc-lang/roc/pull/5644
             CodeModel::Large
         }
+        Triple {
+            architecture: Architecture::Riscv64(_),
+            ..
+        } => {
+            // medany, which is what gcc and the Linux distributions use for RV64
+            CodeModel::Medium
+        }
         _ => CodeModel::Default,
     };
 
+    let (cpu, features) = match target.architecture {
+        // RV64GC: the baseline that Linux distributions target
+        Architecture::Riscv64(_) => ("generic-rv64", "+m,+a,+f,+d,+c"),
+        _ => ("generic", ""),
+    };
+
     Target::from_name(arch).unwrap().create_target_machine(
         &TargetTriple::create(target_triple_str(target)),
-        "generic",
-        "",
+        cpu,
+        features,
         opt,
         reloc,
         code_model,
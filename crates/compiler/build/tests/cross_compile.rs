@@ -0,0 +1,148 @@
+//! Compiles a small app for targets other than the host, and checks the resulting object file.
+//! We can't run these objects here, but we can make sure LLVM produced what the linker expects.
+#[cfg(test)]
+mod cross_compile {
+    use std::path::PathBuf;
+
+    use indoc::indoc;
+    use object::{Architecture, BinaryFormat, FileFlags, Object, ObjectSymbol};
+    use roc_build::program::{gen_from_mono_module, CodeGenBackend, CodeGenOptions};
+    use roc_gen_llvm::llvm::build::LlvmBackendMode;
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, Threading};
+    use roc_mono::ir::OptLevel;
+    use roc_packaging::cache::RocCacheDir;
+    use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+    use roc_target::Target;
+
+    /// `EF_RISCV_FLOAT_ABI_DOUBLE`, i.e. the lp64d ABI that Linux distributions use
+    const EF_RISCV_FLOAT_ABI_DOUBLE: u32 = 0x4;
+
+    const SRC: &str = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main : List F64 -> Str
+        main = \list ->
+            list
+            |> List.map (\x -> x * 2.5)
+            |> List.sum
+            |> Num.toStr
+        "#
+    );
+
+    fn compile_for(target: Target) -> Vec<u8> {
+        let arena = bumpalo::Bump::new();
+        let triple = target.to_triple();
+
+        let load_config = LoadConfig {
+            target_info: roc_target::TargetInfo::from(&triple),
+            function_kind: FunctionKind::LambdaSet,
+            render: RenderTarget::Generic,
+            palette: DEFAULT_PALETTE,
+            threading: Threading::Single,
+            exec_mode: ExecutionMode::Executable,
+            opt_level: OptLevel::Development,
+        };
+
+        let loaded = roc_load::load_and_monomorphize_from_str(
+            &arena,
+            PathBuf::from("Test.roc"),
+            SRC,
+            PathBuf::from("fake/test/path"),
+            RocCacheDir::Disallowed,
+            load_config,
+        )
+        .unwrap_or_else(|problem| panic!("{problem:?}"));
+
+        let code_gen_options = CodeGenOptions {
+            backend: CodeGenBackend::Llvm(LlvmBackendMode::Binary),
+            opt_level: OptLevel::Development,
+            emit_debug_info: false,
+        };
+
+        let (code_object, _, _) = gen_from_mono_module(
+            &arena,
+            loaded,
+            &PathBuf::from("fake/test/path/Test.roc"),
+            &triple,
+            code_gen_options,
+            &PathBuf::new(),
+            None,
+        );
+
+        code_object.to_vec()
+    }
+
+    fn check_elf_object(target: Target, architecture: Architecture) -> FileFlags {
+        let bytes = compile_for(target);
+        let object = object::File::parse(bytes.as_slice()).unwrap();
+
+        assert_eq!(object.format(), BinaryFormat::Elf);
+        assert_eq!(object.architecture(), architecture);
+        assert!(object.is_little_endian());
+        assert!(object.is_64());
+
+        let exposes_main = object.symbols().any(|symbol| {
+            let name = symbol.name().unwrap_or_default();
+
+            symbol.is_definition() && name.starts_with("roc__main_") && name.contains("_exposed")
+        });
+
+        assert!(
+            exposes_main,
+            "{target} object does not expose `main` to the host"
+        );
+
+        object.flags()
+    }
+
+    fn riscv_float_abi(flags: FileFlags) -> u32 {
+        match flags {
+            FileFlags::Elf { e_flags, .. } => e_flags & 0x6,
+            other => panic!("expected ELF flags, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "target-riscv64")]
+    fn linux_riscv64() {
+        let flags = check_elf_object(Target::LinuxRiscv64, Architecture::Riscv64);
+
+        assert_eq!(riscv_float_abi(flags), EF_RISCV_FLOAT_ABI_DOUBLE);
+    }
+
+    #[test]
+    #[cfg(feature = "target-riscv64")]
+    fn linux_riscv64_musl() {
+        let flags = check_elf_object(Target::LinuxRiscv64Musl, Architecture::Riscv64);
+
+        assert_eq!(riscv_float_abi(flags), EF_RISCV_FLOAT_ABI_DOUBLE);
+    }
+
+    #[test]
+    #[cfg(feature = "target-x86_64")]
+    fn linux_x64_musl() {
+        check_elf_object(Target::LinuxX64Musl, Architecture::X86_64);
+    }
+
+    #[test]
+    #[cfg(feature = "target-aarch64")]
+    fn linux_arm64_musl() {
+        check_elf_object(Target::LinuxArm64Musl, Architecture::Aarch64);
+    }
+
+    #[test]
+    fn target_names_round_trip() {
+        for name in [
+            "linux-riscv64",
+            "linux-riscv64-musl",
+            "linux-x64-musl",
+            "linux-arm64-musl",
+        ] {
+            let target: Target = name.parse().unwrap();
+            let triple = target.to_triple();
+
+            assert_eq!(roc_target::get_target_triple_str(&triple), Some(name));
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Compiles small apps with `--debug` and checks the DWARF in the resulting object file.
+#[cfg(test)]
+mod debug_info {
+    use std::borrow::Cow;
+    use std::path::PathBuf;
+
+    use gimli::{AttributeValue, EndianSlice, RunTimeEndian};
+    use indoc::indoc;
+    use object::{Object, ObjectSection, ObjectSymbol, RelocationTarget};
+    use roc_build::program::{gen_from_mono_module, CodeGenBackend, CodeGenOptions};
+    use roc_gen_llvm::llvm::build::LlvmBackendMode;
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, Threading};
+    use roc_mono::ir::OptLevel;
+    use roc_packaging::cache::RocCacheDir;
+    use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+    use target_lexicon::Triple;
+
+    type Reader<'a> = EndianSlice<'a, RunTimeEndian>;
+
+    /// What the DWARF of an object file says about the roc source.
+    #[derive(Debug, Default)]
+    struct DebugInfo {
+        /// (file, line) pairs in the line table
+        lines: Vec<(String, u64)>,
+        /// (name, line) of each function
+        subprograms: Vec<(String, u64)>,
+        /// (name, line) of each local variable and parameter
+        variables: Vec<(String, u64)>,
+    }
+
+    fn compile_with_debug_info(src: &str) -> Vec<u8> {
+        let arena = bumpalo::Bump::new();
+        let target = Triple::host();
+
+        let load_config = LoadConfig {
+            target_info: roc_target::TargetInfo::from(&target),
+            function_kind: FunctionKind::LambdaSet,
+            render: RenderTarget::Generic,
+            palette: DEFAULT_PALETTE,
+            threading: Threading::Single,
+            exec_mode: ExecutionMode::Executable,
+            opt_level: OptLevel::Development,
+        };
+
+        let loaded = roc_load::load_and_monomorphize_from_str(
+            &arena,
+            PathBuf::from("Test.roc"),
+            src,
+            PathBuf::from("fake/test/path"),
+            RocCacheDir::Disallowed,
+            load_config,
+        )
+        .unwrap_or_else(|problem| panic!("{problem:?}"));
+
+        let code_gen_options = CodeGenOptions {
+            backend: CodeGenBackend::Llvm(LlvmBackendMode::Binary),
+            opt_level: OptLevel::Development,
+            emit_debug_info: true,
+        };
+
+        let (code_object, _, _) = gen_from_mono_module(
+            &arena,
+            loaded,
+            &PathBuf::from("fake/test/path/Test.roc"),
+            &target,
+            code_gen_options,
+            &PathBuf::new(),
+            None,
+        );
+
+        code_object.to_vec()
+    }
+
+    /// Section contents with their relocations applied, which in a relocatable object hold e.g.
+    /// the offsets of strings in `.debug_str`.
+    fn relocated_section<'data>(
+        object: &object::File<'data>,
+        id: gimli::SectionId,
+    ) -> Cow<'data, [u8]> {
+        let Some(section) = object.section_by_name(id.name()) else {
+            return Cow::Borrowed(&[]);
+        };
+
+        let mut data = section.uncompressed_data().unwrap();
+
+        for (offset, relocation) in section.relocations() {
+            let RelocationTarget::Symbol(index) = relocation.target() else {
+                continue;
+            };
+
+            let symbol_address = object.symbol_by_index(index).unwrap().address() as i64;
+            let width = relocation.size() as usize / 8;
+            let range = offset as usize..offset as usize + width;
+
+            let bytes = data.to_mut();
+            let mut buf = [0; 8];
+
+            let addend = if relocation.has_implicit_addend() {
+                buf[..width].copy_from_slice(&bytes[range.clone()]);
+                i64::from_le_bytes(buf)
+            } else {
+                relocation.addend()
+            };
+
+            let value = (symbol_address + addend).to_le_bytes();
+            bytes[range].copy_from_slice(&value[..width]);
+        }
+
+        data
+    }
+
+    fn read_debug_info(bytes: &[u8]) -> DebugInfo {
+        let object = object::File::parse(bytes).unwrap();
+        let endian = if object.is_little_endian() {
+            RunTimeEndian::Little
+        } else {
+            RunTimeEndian::Big
+        };
+
+        let dwarf_sections =
+            gimli::Dwarf::load(|id| Ok::<_, gimli::Error>(relocated_section(&object, id))).unwrap();
+        let dwarf = dwarf_sections.borrow(|section| Reader::new(section, endian));
+
+        let attr_string = |unit: &gimli::Unit<Reader>, value| {
+            dwarf
+                .attr_string(unit, value)
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+
+        let mut info = DebugInfo::default();
+        let mut units = dwarf.units();
+
+        while let Some(header) = units.next().unwrap() {
+            let unit = dwarf.unit(header).unwrap();
+
+            if let Some(program) = unit.line_program.clone() {
+                let mut rows = program.rows();
+
+                while let Some((header, row)) = rows.next_row().unwrap() {
+                    if let (Some(file), Some(line)) = (row.file(header), row.line()) {
+                        let name = attr_string(&unit, file.path_name());
+                        info.lines.push((name, line.get()));
+                    }
+                }
+            }
+
+            let mut entries = unit.entries();
+            while let Some((_, entry)) = entries.next_dfs().unwrap() {
+                let list = match entry.tag() {
+                    gimli::DW_TAG_subprogram => &mut info.subprograms,
+                    gimli::DW_TAG_variable | gimli::DW_TAG_formal_parameter => &mut info.variables,
+                    _ => continue,
+                };
+
+                let name = match entry.attr_value(gimli::DW_AT_name).unwrap() {
+                    Some(value) => attr_string(&unit, value),
+                    None => continue,
+                };
+
+                let line = match entry.attr_value(gimli::DW_AT_decl_line).unwrap() {
+                    Some(AttributeValue::Udata(line)) => line,
+                    Some(other) => other.udata_value().unwrap_or_default(),
+                    None => 0,
+                };
+
+                list.push((name, line));
+            }
+        }
+
+        info
+    }
+
+    #[test]
+    fn lets_and_functions_point_at_their_source_lines() {
+        let src = indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            addThree = \start ->
+                oneMore = start + 1
+                twoMore = oneMore + 2
+                twoMore
+
+            main = addThree 39
+            "#
+        );
+
+        let info = read_debug_info(&compile_with_debug_info(src));
+
+        // DWARF lines are 1-based
+        let has_line = |line| {
+            info.lines
+                .iter()
+                .any(|(file, l)| file == "Test.roc" && *l == line)
+        };
+
+        assert!(has_line(4), "no line info for `oneMore`: {info:#?}");
+        assert!(has_line(5), "no line info for `twoMore`: {info:#?}");
+
+        assert!(
+            info.subprograms
+                .iter()
+                .any(|(name, line)| name.contains("addThree") && (3..=4).contains(line)),
+            "no subprogram for `addThree`: {:#?}",
+            info.subprograms
+        );
+
+        assert!(info.variables.contains(&("oneMore".to_string(), 4)));
+        assert!(info.variables.contains(&("twoMore".to_string(), 5)));
+        assert!(
+            info.variables.contains(&("start".to_string(), 3)),
+            "no parameter `start`: {:#?}",
+            info.variables
+        );
+    }
+
+    #[test]
+    fn calls_and_returns_point_at_their_source_lines() {
+        let src = indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            double = \n -> n * 2
+
+            main =
+                x = 20
+                if x > 10 then
+                    double x
+                else
+                    x
+            "#
+        );
+
+        let info = read_debug_info(&compile_with_debug_info(src));
+
+        let has_line = |line| {
+            info.lines
+                .iter()
+                .any(|(file, l)| file == "Test.roc" && *l == line)
+        };
+
+        assert!(has_line(7), "no line info for the `if`: {info:#?}");
+        assert!(
+            has_line(8),
+            "no line info for the call to `double`: {info:#?}"
+        );
+        assert!(has_line(10), "no line info for returning `x`: {info:#?}");
+    }
+
+    #[test]
+    fn top_level_values_describe_their_locals() {
+        let src = indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                answer = "forty-two"
+                answer
+            "#
+        );
+
+        let info = read_debug_info(&compile_with_debug_info(src));
+
+        assert!(info
+            .lines
+            .iter()
+            .any(|(file, line)| file == "Test.roc" && *line == 4));
+        assert!(info.variables.contains(&("answer".to_string(), 4)));
+    }
+}
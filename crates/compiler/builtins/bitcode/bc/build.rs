@@ -36,6 +36,7 @@ fn main() {
     generate_bc_file(&bitcode_path, "ir-x86", "builtins-x86");
     generate_bc_file(&bitcode_path, "ir-x86_64", "builtins-x86_64");
     generate_bc_file(&bitcode_path, "ir-aarch64", "builtins-aarch64");
+    generate_bc_file(&bitcode_path, "ir-riscv64", "builtins-riscv64");
     generate_bc_file(
         &bitcode_path,
         "ir-windows-x86_64",
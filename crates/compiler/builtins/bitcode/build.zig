@@ -30,6 +30,7 @@ pub fn build(b: *Build) void {
     const linux32_target = makeLinux32Target();
     const linux_x64_target = makeLinuxX64Target();
     const linux_aarch64_target = makeLinuxAarch64Target();
+    const linux_riscv64_target = makeLinuxRiscv64Target();
     const windows64_target = makeWindows64Target();
     const wasm32_target = makeWasm32Target();
 
@@ -38,6 +39,7 @@ pub fn build(b: *Build) void {
     generateLlvmIrFile(b, mode, linux32_target, main_path, "ir-x86", "builtins-x86");
     generateLlvmIrFile(b, mode, linux_x64_target, main_path, "ir-x86_64", "builtins-x86_64");
     generateLlvmIrFile(b, mode, linux_aarch64_target, main_path, "ir-aarch64", "builtins-aarch64");
+    generateLlvmIrFile(b, mode, linux_riscv64_target, main_path, "ir-riscv64", "builtins-riscv64");
     generateLlvmIrFile(b, mode, windows64_target, main_path, "ir-windows-x86_64", "builtins-windows-x86_64");
     generateLlvmIrFile(b, mode, wasm32_target, main_path, "ir-wasm32", "builtins-wasm32");
 
@@ -125,6 +127,16 @@ fn makeLinuxAarch64Target() CrossTarget {
     return target;
 }
 
+fn makeLinuxRiscv64Target() CrossTarget {
+    var target = CrossTarget.parse(.{}) catch unreachable;
+
+    target.cpu_arch = std.Target.Cpu.Arch.riscv64;
+    target.os_tag = std.Target.Os.Tag.linux;
+    target.abi = std.Target.Abi.musl;
+
+    return target;
+}
+
 fn makeLinuxX64Target() CrossTarget {
     var target = CrossTarget.parse(.{}) catch unreachable;
 
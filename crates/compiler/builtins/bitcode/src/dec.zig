@@ -219,6 +219,115 @@ pub const RocDec = extern struct {
         return RocStr.init(&str_bytes, position);
     }
 
+    // Formats the number with exactly `decimals` digits after the decimal point, rounding half away from zero.
+    // There is no decimal point when `decimals` is 0.
+    pub fn toStrDecimals(self: RocDec, decimals: u8) RocStr {
+        const kept_places = @min(decimals, decimal_places);
+        const divisor = math.pow(u128, 10, decimal_places - kept_places);
+
+        const magnitude = math.absCast(self.num);
+        var rounded = magnitude / divisor;
+        if ((magnitude % divisor) * 2 >= divisor) {
+            rounded += 1;
+        }
+
+        const scale = math.pow(u128, 10, kept_places);
+        const before_point = rounded / scale;
+        const after_point = rounded % scale;
+
+        // a minus sign, the digits before the point, the point, and the decimals
+        var str_bytes: [1 + @as(usize, max_digits) + 1 + math.maxInt(u8)]u8 = undefined;
+        var position: usize = 0;
+
+        if (self.num < 0 and rounded != 0) {
+            str_bytes[position] = '-';
+            position += 1;
+        }
+
+        position += std.fmt.formatIntBuf(str_bytes[position..], before_point, 10, .lower, .{});
+
+        if (decimals > 0) {
+            str_bytes[position] = '.';
+            position += 1;
+
+            if (kept_places > 0) {
+                position += std.fmt.formatIntBuf(str_bytes[position..], after_point, 10, .lower, .{ .width = kept_places, .fill = '0' });
+            }
+
+            @memset(str_bytes[position .. position + decimals - kept_places], '0');
+            position += decimals - kept_places;
+        }
+
+        return RocStr.init(&str_bytes, position);
+    }
+
+    // Formats the number as a single digit, `decimals` digits after the decimal point, and a base 10 exponent,
+    // like `1.25e-3`. The digits are rounded half away from zero, and there is no decimal point when `decimals` is 0.
+    pub fn toStrScientific(self: RocDec, decimals: u8) RocStr {
+        const magnitude = math.absCast(self.num);
+
+        var digit_bytes_storage: [max_digits]u8 = undefined;
+        const num_digits = std.fmt.formatIntBuf(digit_bytes_storage[0..], magnitude, 10, .lower, .{});
+        const digits = digit_bytes_storage[0..num_digits];
+
+        var exponent: i32 = if (magnitude == 0) 0 else @as(i32, @intCast(num_digits)) - 1 - decimal_places;
+
+        // the mantissa's digits, rounded to `decimals + 1` significant digits and padded with zeros
+        var mantissa: [1 + math.maxInt(u8)]u8 = undefined;
+        const significant_digits = @as(usize, decimals) + 1;
+
+        if (num_digits > significant_digits) {
+            @memcpy(mantissa[0..significant_digits], digits[0..significant_digits]);
+
+            if (digits[significant_digits] >= '5') {
+                var index = significant_digits;
+                while (index > 0) {
+                    index -= 1;
+                    if (mantissa[index] == '9') {
+                        mantissa[index] = '0';
+                    } else {
+                        mantissa[index] += 1;
+                        break;
+                    }
+                } else {
+                    // every digit was a 9, so rounding carried over into a new leading digit
+                    mantissa[0] = '1';
+                    exponent += 1;
+                }
+            }
+        } else {
+            @memcpy(mantissa[0..num_digits], digits);
+            @memset(mantissa[num_digits..significant_digits], '0');
+        }
+
+        // a minus sign, the mantissa and its point, and an exponent of at most 3 characters
+        var str_bytes: [1 + 1 + 1 + math.maxInt(u8) + 1 + 3]u8 = undefined;
+        var position: usize = 0;
+
+        if (self.num < 0) {
+            str_bytes[position] = '-';
+            position += 1;
+        }
+
+        str_bytes[position] = mantissa[0];
+        position += 1;
+
+        if (decimals > 0) {
+            str_bytes[position] = '.';
+            position += 1;
+
+            @memcpy(str_bytes[position .. position + decimals], mantissa[1..significant_digits]);
+            position += decimals;
+        }
+
+        str_bytes[position] = 'e';
+        position += 1;
+
+        position += std.fmt.formatIntBuf(str_bytes[position..], exponent, 10, .lower, .{});
+
+        return RocStr.init(&str_bytes, position);
+    }
+
     pub fn toI128(self: RocDec) i128 {
         return self.num;
     }
@@ -1085,6 +1194,40 @@ test "toStr: 0" {
     try expectEqualSlices(u8, res_slice, res_roc_str.asSlice());
 }
 
+test "toStrDecimals" {
+    const cases = [_]struct { RocDec, u8, []const u8 }{
+        .{ RocDec.fromU64(1234), 2, "1234.00" },
+        .{ RocDec.fromStr(RocStr.init("3.14159", 7)).?, 3, "3.142" },
+        .{ RocDec.fromStr(RocStr.init("-2.5", 4)).?, 0, "-3" },
+        .{ RocDec.fromStr(RocStr.init("-0.001", 6)).?, 2, "0.00" },
+        .{ RocDec.fromStr(RocStr.init("0.1", 3)).?, 20, "0.10000000000000000000" },
+    };
+
+    for (cases) |case| {
+        const res_roc_str = case[0].toStrDecimals(case[1]);
+        defer res_roc_str.decref();
+
+        try expectEqualSlices(u8, case[2], res_roc_str.asSlice());
+    }
+}
+
+test "toStrScientific" {
+    const cases = [_]struct { RocDec, u8, []const u8 }{
+        .{ RocDec.fromU64(1234), 2, "1.23e3" },
+        .{ RocDec.fromU64(9999), 1, "1.0e4" },
+        .{ RocDec.fromStr(RocStr.init("-0.00125", 8)).?, 1, "-1.3e-3" },
+        .{ RocDec.fromU64(0), 2, "0.00e0" },
+        .{ RocDec.fromU64(5), 0, "5e0" },
+    };
+
+    for (cases) |case| {
+        const res_roc_str = case[0].toStrScientific(case[1]);
+        defer res_roc_str.decref();
+
+        try expectEqualSlices(u8, case[2], res_roc_str.asSlice());
+    }
+}
+
 test "add: 0" {
     var dec: RocDec = .{ .num = 0 };
 
@@ -1204,6 +1347,14 @@ pub fn toStr(arg: RocDec) callconv(.C) RocStr {
     return @call(.always_inline, RocDec.toStr, .{arg});
 }
 
+pub fn toStrDecimals(arg: RocDec, decimals: u8) callconv(.C) RocStr {
+    return @call(.always_inline, RocDec.toStrDecimals, .{ arg, decimals });
+}
+
+pub fn toStrScientific(arg: RocDec, decimals: u8) callconv(.C) RocStr {
+    return @call(.always_inline, RocDec.toStrScientific, .{ arg, decimals });
+}
+
 pub fn fromF64C(arg: f64) callconv(.C) i128 {
     if (@call(.always_inline, RocDec.fromF64, .{arg})) |dec| {
         return dec.num;
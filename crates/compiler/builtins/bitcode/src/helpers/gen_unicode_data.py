@@ -0,0 +1,177 @@
+#!/usr/bin/env python3
+# Generates unicode_data.zig from the Unicode Character Database that ships with Python's
+# `unicodedata` module. Run it from this directory to regenerate the tables:
+#
+#     python3 gen_unicode_data.py > unicode_data.zig
+#
+# The Unicode version of the generated tables is the one reported by `unicodedata.unidata_version`.
+
+import unicodedata
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+
+# Code points that are case-ignorable without being marks, format characters, modifiers or
+# modifier letters: the Word_Break=MidLetter, MidNumLet and Single_Quote characters.
+EXTRA_CASE_IGNORABLE = {
+    0x0027, 0x002E, 0x003A, 0x00B7, 0x0387, 0x05F4, 0x2018, 0x2019,
+    0x2024, 0x2027, 0xFE13, 0xFE52, 0xFE55, 0xFF07, 0xFF0E, 0xFF1A,
+}
+
+
+def scalars():
+    for code_point in range(0x110000):
+        if 0xD800 <= code_point <= 0xDFFF:
+            continue
+        yield code_point
+
+
+def mappings(transform, width):
+    rows = []
+    for code_point in scalars():
+        mapped = transform(chr(code_point))
+        if mapped != chr(code_point):
+            values = [ord(c) for c in mapped]
+            assert len(values) <= width
+            rows.append([code_point] + values + [0] * (width - len(values)))
+    return rows
+
+
+def ranges(predicate):
+    rows = []
+    start = None
+    for code_point in range(0x110000):
+        if predicate(code_point):
+            if start is None:
+                start = code_point
+            end = code_point
+        elif start is not None:
+            rows.append([start, end])
+            start = None
+    return rows
+
+
+def combining_classes():
+    rows = []
+    for code_point in range(0x110000):
+        ccc = unicodedata.combining(chr(code_point))
+        if ccc == 0:
+            continue
+        if rows and rows[-1][1] == code_point - 1 and rows[-1][2] == ccc:
+            rows[-1][1] = code_point
+        else:
+            rows.append([code_point, code_point, ccc])
+    return rows
+
+
+def decompositions():
+    rows = []
+    for code_point in scalars():
+        if code_point in HANGUL_SYLLABLES:
+            continue
+        decomposed = unicodedata.normalize("NFD", chr(code_point))
+        if decomposed != chr(code_point):
+            values = [ord(c) for c in decomposed]
+            assert len(values) <= 4
+            rows.append([code_point] + values + [0] * (4 - len(values)))
+    return rows
+
+
+def compositions():
+    rows = []
+    for code_point in scalars():
+        if code_point in HANGUL_SYLLABLES:
+            continue
+        decomposition = unicodedata.decomposition(chr(code_point))
+        if not decomposition or decomposition.startswith("<"):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        if len(parts) != 2:
+            continue
+        # Excluded and non-starter decompositions do not recompose under NFC.
+        if unicodedata.normalize("NFC", chr(parts[0]) + chr(parts[1])) == chr(code_point):
+            rows.append(parts + [code_point])
+    rows.sort()
+    return rows
+
+
+def is_cased(code_point):
+    return unicodedata.category(chr(code_point)) in ("Lu", "Ll", "Lt")
+
+
+def is_case_ignorable(code_point):
+    category = unicodedata.category(chr(code_point))
+    return category in ("Mn", "Me", "Cf", "Lm", "Sk") or code_point in EXTRA_CASE_IGNORABLE
+
+
+def emit(name, doc, rows, width):
+    print(f"// {doc}")
+    print(f"pub const {name} = [_][{width}]u21{{")
+    per_line = max(1, 96 // (width * 9))
+    for i in range(0, len(rows), per_line):
+        chunk = rows[i : i + per_line]
+        cells = " ".join(
+            ".{ " + ", ".join(f"0x{value:04X}" for value in row) + " },"
+            for row in chunk
+        )
+        print(f"    {cells}")
+    print("};")
+    print()
+
+
+def main():
+    print("// THIS FILE IS GENERATED BY gen_unicode_data.py. DO NOT EDIT IT BY HAND.")
+    print(f"// Unicode version {unicodedata.unidata_version}.")
+    print()
+    emit(
+        "upper_mappings",
+        "{ code point, full uppercase mapping padded with zeroes }, sorted by code point",
+        mappings(str.upper, 3),
+        4,
+    )
+    emit(
+        "lower_mappings",
+        "{ code point, full lowercase mapping padded with zeroes }, sorted by code point",
+        mappings(str.lower, 3),
+        4,
+    )
+    emit(
+        "fold_mappings",
+        "{ code point, full case folding padded with zeroes }, sorted by code point",
+        mappings(str.casefold, 3),
+        4,
+    )
+    emit(
+        "combining_classes",
+        "{ first code point, last code point, canonical combining class }, sorted by code point",
+        combining_classes(),
+        3,
+    )
+    emit(
+        "decompositions",
+        "{ code point, full canonical decomposition padded with zeroes }, sorted by code point."
+        " Hangul syllables are decomposed algorithmically instead.",
+        decompositions(),
+        5,
+    )
+    emit(
+        "compositions",
+        "{ starter, combining code point, primary composite }, sorted by starter then combining code point",
+        compositions(),
+        3,
+    )
+    emit(
+        "cased",
+        "{ first code point, last code point } of the ranges of cased letters",
+        ranges(is_cased),
+        2,
+    )
+    emit(
+        "case_ignorable",
+        "{ first code point, last code point } of the ranges of case-ignorable code points",
+        ranges(is_case_ignorable),
+        2,
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,211 @@
+const std = @import("std");
+const data = @import("unicode_data.zig");
+const expect = std.testing.expect;
+const expectEqualSlices = std.testing.expectEqualSlices;
+
+// Lookups into the generated Unicode Character Database tables in `unicode_data.zig`,
+// for case mapping and canonical (NFC/NFD) normalization.
+
+pub const CaseMapping = enum {
+    Upper,
+    Lower,
+    Fold,
+};
+
+// Returns the code points that `code_point` maps to, or null if the mapping leaves it unchanged.
+// This is the full, context-free mapping: contextual rules like the Greek final sigma are up to the caller.
+pub fn mapCase(mapping: CaseMapping, code_point: u21) ?[]const u21 {
+    const table: []const [4]u21 = switch (mapping) {
+        .Upper => &data.upper_mappings,
+        .Lower => &data.lower_mappings,
+        .Fold => &data.fold_mappings,
+    };
+
+    const entry = findEntry(4, table, code_point) orelse return null;
+
+    return withoutPadding(entry[1..]);
+}
+
+pub fn isCased(code_point: u21) bool {
+    return inRanges(&data.cased, code_point);
+}
+
+pub fn isCaseIgnorable(code_point: u21) bool {
+    return inRanges(&data.case_ignorable, code_point);
+}
+
+pub fn combiningClass(code_point: u21) u8 {
+    var low: usize = 0;
+    var high: usize = data.combining_classes.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = data.combining_classes[mid];
+
+        if (code_point < entry[0]) {
+            high = mid;
+        } else if (code_point > entry[1]) {
+            low = mid + 1;
+        } else {
+            return @as(u8, @intCast(entry[2]));
+        }
+    }
+
+    return 0;
+}
+
+// Hangul syllables are composed and decomposed arithmetically rather than through the tables.
+// See section 3.12 of the Unicode standard.
+const HANGUL_S_BASE: u21 = 0xAC00;
+const HANGUL_L_BASE: u21 = 0x1100;
+const HANGUL_V_BASE: u21 = 0x1161;
+const HANGUL_T_BASE: u21 = 0x11A7;
+const HANGUL_L_COUNT: u21 = 19;
+const HANGUL_V_COUNT: u21 = 21;
+const HANGUL_T_COUNT: u21 = 28;
+const HANGUL_N_COUNT: u21 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u21 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+// Writes the full canonical decomposition of `code_point` into `buffer`, and returns the part of
+// the buffer that was written. A code point without a decomposition decomposes to itself.
+pub fn decompose(code_point: u21, buffer: *[4]u21) []const u21 {
+    if (code_point >= HANGUL_S_BASE and code_point < HANGUL_S_BASE + HANGUL_S_COUNT) {
+        const index = code_point - HANGUL_S_BASE;
+
+        buffer[0] = HANGUL_L_BASE + index / HANGUL_N_COUNT;
+        buffer[1] = HANGUL_V_BASE + (index % HANGUL_N_COUNT) / HANGUL_T_COUNT;
+
+        const trailing = index % HANGUL_T_COUNT;
+        if (trailing == 0) {
+            return buffer[0..2];
+        }
+
+        buffer[2] = HANGUL_T_BASE + trailing;
+        return buffer[0..3];
+    }
+
+    if (findEntry(5, &data.decompositions, code_point)) |entry| {
+        const decomposed = withoutPadding(entry[1..]);
+        @memcpy(buffer[0..decomposed.len], decomposed);
+
+        return buffer[0..decomposed.len];
+    }
+
+    buffer[0] = code_point;
+    return buffer[0..1];
+}
+
+// Returns the primary composite of `starter` followed by `combining`, if there is one.
+pub fn compose(starter: u21, combining: u21) ?u21 {
+    // <L, V> -> LV
+    if (starter >= HANGUL_L_BASE and starter < HANGUL_L_BASE + HANGUL_L_COUNT and
+        combining >= HANGUL_V_BASE and combining < HANGUL_V_BASE + HANGUL_V_COUNT)
+    {
+        const l_index = starter - HANGUL_L_BASE;
+        const v_index = combining - HANGUL_V_BASE;
+
+        return HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT;
+    }
+
+    // <LV, T> -> LVT
+    if (starter >= HANGUL_S_BASE and starter < HANGUL_S_BASE + HANGUL_S_COUNT and
+        (starter - HANGUL_S_BASE) % HANGUL_T_COUNT == 0 and
+        combining > HANGUL_T_BASE and combining < HANGUL_T_BASE + HANGUL_T_COUNT)
+    {
+        return starter + (combining - HANGUL_T_BASE);
+    }
+
+    var low: usize = 0;
+    var high: usize = data.compositions.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = data.compositions[mid];
+
+        if (entry[0] == starter and entry[1] == combining) {
+            return entry[2];
+        } else if (entry[0] < starter or (entry[0] == starter and entry[1] < combining)) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+fn findEntry(comptime width: usize, table: []const [width]u21, code_point: u21) ?*const [width]u21 {
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = &table[mid];
+
+        if (entry[0] == code_point) {
+            return entry;
+        } else if (entry[0] < code_point) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+fn inRanges(table: []const [2]u21, code_point: u21) bool {
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = table[mid];
+
+        if (code_point < entry[0]) {
+            high = mid;
+        } else if (code_point > entry[1]) {
+            low = mid + 1;
+        } else {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// The tables pad mappings of different lengths with zeroes, and no mapping contains U+0000.
+fn withoutPadding(values: []const u21) []const u21 {
+    var length: usize = 0;
+    while (length < values.len and values[length] != 0) {
+        length += 1;
+    }
+
+    return values[0..length];
+}
+
+test "mapCase" {
+    try expectEqualSlices(u21, &[_]u21{'A'}, mapCase(.Upper, 'a').?);
+    try expectEqualSlices(u21, &[_]u21{ 'S', 'S' }, mapCase(.Upper, 0x00DF).?);
+    try expectEqualSlices(u21, &[_]u21{ 'i', 0x0307 }, mapCase(.Lower, 0x0130).?);
+    try expectEqualSlices(u21, &[_]u21{ 's', 's' }, mapCase(.Fold, 0x1E9E).?);
+    try expect(mapCase(.Upper, 'A') == null);
+    try expect(mapCase(.Lower, '1') == null);
+}
+
+test "decompose and compose" {
+    var buffer: [4]u21 = undefined;
+
+    try expectEqualSlices(u21, &[_]u21{ 'e', 0x0301 }, decompose(0x00E9, &buffer));
+    try expectEqualSlices(u21, &[_]u21{ 0x1112, 0x1161, 0x11AB }, decompose(0xD55C, &buffer));
+    try expectEqualSlices(u21, &[_]u21{'x'}, decompose('x', &buffer));
+
+    try expect(compose('e', 0x0301).? == 0x00E9);
+    try expect(compose(0x1112, 0x1161).? == 0xD558);
+    try expect(compose(0xD558, 0x11AB).? == 0xD55C);
+    try expect(compose('x', 0x0301) == null);
+
+    try expect(combiningClass(0x0301) == 230);
+    try expect(combiningClass(0x0323) == 220);
+    try expect(combiningClass('a') == 0);
+}
@@ -734,37 +734,107 @@ pub fn listDropAt(
     }
 }
 
-fn partition(source_ptr: [*]u8, transform: Opaque, wrapper: CompareFn, element_width: usize, low: isize, high: isize) isize {
-    const pivot = source_ptr + (@as(usize, @intCast(high)) * element_width);
-    var i = (low - 1); // Index of smaller element and indicates the right position of pivot found so far
-    var j = low;
+// SORT
 
-    while (j <= high - 1) : (j += 1) {
-        const current_elem = source_ptr + (@as(usize, @intCast(j)) * element_width);
+// Runs of this many elements are sorted with an insertion sort before they are merged.
+const SORT_RUN_LENGTH: usize = 16;
 
-        const ordering = wrapper(transform, current_elem, pivot);
-        const order = @as(utils.Ordering, @enumFromInt(ordering));
+inline fn sortLessThan(transform: Opaque, wrapper: CompareFn, a: [*]u8, b: [*]u8) bool {
+    const ordering = wrapper(transform, a, b);
+    const order = @as(utils.Ordering, @enumFromInt(ordering));
 
-        switch (order) {
-            utils.Ordering.LT => {
-                // the current element is smaller than the pivot; swap it
-                i += 1;
-                swapElements(source_ptr, element_width, @as(usize, @intCast(i)), @as(usize, @intCast(j)));
-            },
-            utils.Ordering.EQ, utils.Ordering.GT => {},
+    return order == utils.Ordering.LT;
+}
+
+// Stable insertion sort of the elements in [start, end). `temporary` must have room for one element.
+fn insertionSort(source_ptr: [*]u8, transform: Opaque, wrapper: CompareFn, element_width: usize, start: usize, end: usize, temporary: [*]u8) void {
+    var i = start + 1;
+    while (i < end) : (i += 1) {
+        const current_elem = source_ptr + (i * element_width);
+
+        // only move past elements that are strictly greater, so equal elements keep their order
+        var j = i;
+        while (j > start and sortLessThan(transform, wrapper, current_elem, source_ptr + ((j - 1) * element_width))) {
+            j -= 1;
+        }
+
+        if (j < i) {
+            const target = source_ptr + (j * element_width);
+            const shifted_size = (i - j) * element_width;
+
+            @memcpy(temporary[0..element_width], current_elem[0..element_width]);
+            std.mem.copyBackwards(u8, target[element_width..(element_width + shifted_size)], target[0..shifted_size]);
+            @memcpy(target[0..element_width], temporary[0..element_width]);
         }
     }
-    swapElements(source_ptr, element_width, @as(usize, @intCast(i + 1)), @as(usize, @intCast(high)));
-    return (i + 1);
 }
 
-fn quicksort(source_ptr: [*]u8, transform: Opaque, wrapper: CompareFn, element_width: usize, low: isize, high: isize) void {
-    if (low < high) {
-        // partition index
-        const pi = partition(source_ptr, transform, wrapper, element_width, low, high);
+// Stable merge of the sorted runs [low, mid) and [mid, high) of `source` into the same range of `target`.
+fn merge(source: [*]u8, target: [*]u8, transform: Opaque, wrapper: CompareFn, element_width: usize, low: usize, mid: usize, high: usize) void {
+    var left = low;
+    var right = mid;
+    var out = low;
+
+    while (left < mid and right < high) : (out += 1) {
+        const left_elem = source + (left * element_width);
+        const right_elem = source + (right * element_width);
+        const out_elem = target + (out * element_width);
+
+        // on ties, take from the left run, so equal elements keep their order
+        if (sortLessThan(transform, wrapper, right_elem, left_elem)) {
+            @memcpy(out_elem[0..element_width], right_elem[0..element_width]);
+            right += 1;
+        } else {
+            @memcpy(out_elem[0..element_width], left_elem[0..element_width]);
+            left += 1;
+        }
+    }
+
+    // at most one of the runs has elements left over; they are already in order
+    const rest_start = if (left < mid) left else right;
+    const rest_end = if (left < mid) mid else high;
+    const rest_size = (rest_end - rest_start) * element_width;
+    @memcpy((target + (out * element_width))[0..rest_size], (source + (rest_start * element_width))[0..rest_size]);
+}
+
+// A stable, bottom-up merge sort. It makes O(n log n) comparisons in the worst case, O(n) comparisons
+// on input that is already sorted, and does not recurse, so it runs in constant stack space.
+fn mergesort(source_ptr: [*]u8, transform: Opaque, wrapper: CompareFn, element_width: usize, length: usize, scratch_ptr: [*]u8) void {
+    var start: usize = 0;
+    while (start < length) : (start += SORT_RUN_LENGTH) {
+        const end = @min(start + SORT_RUN_LENGTH, length);
+        insertionSort(source_ptr, transform, wrapper, element_width, start, end, scratch_ptr);
+    }
+
+    var source = source_ptr;
+    var target = scratch_ptr;
+    var width: usize = SORT_RUN_LENGTH;
+
+    while (width < length) : (width *= 2) {
+        var low: usize = 0;
+        while (low < length) : (low += 2 * width) {
+            const mid = @min(low + width, length);
+            const high = @min(low + 2 * width, length);
+
+            // the runs are already in order if the first element of the right run is not
+            // smaller than the last element of the left run
+            if (mid == high or !sortLessThan(transform, wrapper, source + (mid * element_width), source + ((mid - 1) * element_width))) {
+                const size = (high - low) * element_width;
+                const offset = low * element_width;
+                @memcpy((target + offset)[0..size], (source + offset)[0..size]);
+            } else {
+                merge(source, target, transform, wrapper, element_width, low, mid, high);
+            }
+        }
 
-        _ = quicksort(source_ptr, transform, wrapper, element_width, low, pi - 1); // before pi
-        _ = quicksort(source_ptr, transform, wrapper, element_width, pi + 1, high); // after pi
+        const tmp = source;
+        source = target;
+        target = tmp;
+    }
+
+    if (source != source_ptr) {
+        const size = length * element_width;
+        @memcpy(source_ptr[0..size], source[0..size]);
     }
 }
 
@@ -784,9 +854,13 @@ pub fn listSortWith(
     }
 
     if (list.bytes) |source_ptr| {
-        const low = 0;
-        const high: isize = @as(isize, @intCast(list.len())) - 1;
-        quicksort(source_ptr, data, caller, element_width, low, high);
+        const length = list.len();
+
+        if (length > 1 and element_width > 0) {
+            const scratch_ptr = utils.alloc(length * element_width, alignment) orelse unreachable;
+            mergesort(source_ptr, data, caller, element_width, length, scratch_ptr);
+            utils.dealloc(scratch_ptr, alignment);
+        }
     }
 
     return list;
@@ -988,3 +1062,37 @@ test "listConcat: non-unique with unique overlapping" {
 
     try expect(concatted.eql(wanted));
 }
+
+fn testingCompareFirstByte(_: Opaque, a: Opaque, b: Opaque) callconv(.C) u8 {
+    const x = (a orelse unreachable)[0];
+    const y = (b orelse unreachable)[0];
+
+    const order = if (x < y) utils.Ordering.LT else if (x > y) utils.Ordering.GT else utils.Ordering.EQ;
+    return @intFromEnum(order);
+}
+
+fn testingIncN(_: Opaque, _: usize) callconv(.C) void {}
+
+test "listSortWith: stable on duplicate-heavy input longer than one run" {
+    // pairs of (key, original position); only the key is compared
+    var input: [100][2]u8 = undefined;
+    for (&input, 0..) |*pair, i| {
+        pair.* = .{ @as(u8, @intCast((99 - i) % 3)), @as(u8, @intCast(i)) };
+    }
+
+    const list = RocList.fromSlice([2]u8, input[0..]);
+    const sorted = listSortWith(list, testingCompareFirstByte, null, testingIncN, false, 1, 2);
+    defer sorted.decref(1);
+
+    const elements = sorted.elements([2]u8) orelse unreachable;
+    var i: usize = 1;
+    while (i < sorted.len()) : (i += 1) {
+        const previous = elements[i - 1];
+        const current = elements[i];
+
+        try expect(previous[0] <= current[0]);
+        if (previous[0] == current[0]) {
+            try expect(previous[1] < current[1]);
+        }
+    }
+}
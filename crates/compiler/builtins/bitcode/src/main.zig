@@ -45,6 +45,8 @@ comptime {
     exportDecFn(dec.toF64, "to_f64");
     exportDecFn(dec.toI128, "to_i128");
     exportDecFn(dec.toStr, "to_str");
+    exportDecFn(dec.toStrDecimals, "to_str_decimals");
+    exportDecFn(dec.toStrScientific, "to_str_scientific");
 
     inline for (INTEGERS) |T| {
         dec.exportFromInt(T, ROC_BUILTINS ++ ".dec.from_int.");
@@ -200,6 +202,7 @@ comptime {
     exportStrFn(str.strJoinWithC, "joinWith");
     exportStrFn(str.strNumberOfBytes, "number_of_bytes");
     exportStrFn(str.strEqual, "equal");
+    exportStrFn(str.strCompare, "compare");
     exportStrFn(str.substringUnsafe, "substring_unsafe");
     exportStrFn(str.getUnsafe, "get_unsafe");
     exportStrFn(str.reserve, "reserve");
@@ -216,15 +219,26 @@ comptime {
     exportStrFn(str.strGraphemes, "graphemes");
     exportStrFn(str.strRefcountPtr, "refcount_ptr");
     exportStrFn(str.strReleaseExcessCapacity, "release_excess_capacity");
+    exportStrFn(str.strToUpper, "to_upper");
+    exportStrFn(str.strToLower, "to_lower");
+    exportStrFn(str.strCaseInsensitiveEq, "case_insensitive_eq");
+    exportStrFn(str.strToNfc, "to_nfc");
+    exportStrFn(str.strToNfd, "to_nfd");
+    exportStrFn(str.strToUtf16, "to_utf16");
+    exportStrFn(str.strFromUtf16Lossy, "from_utf16_lossy");
 
     inline for (INTEGERS) |T| {
         str.exportFromInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int.");
         num.exportParseInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_int.");
+        str.exportFromIntRadix(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int_radix.");
+        num.exportParseIntRadix(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_int_radix.");
     }
 
     inline for (FLOATS) |T| {
         str.exportFromFloat(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_float.");
         num.exportParseFloat(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_float.");
+        str.exportFromFloatDecimals(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_float_decimals.");
+        str.exportFromFloatScientific(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_float_scientific.");
     }
 }
 
@@ -73,6 +73,25 @@ pub fn exportParseInt(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportParseIntRadix(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(buf: RocStr, radix: u8) callconv(.C) NumParseResult(T) {
+            if (radix < 2 or radix > 36) {
+                roc_panic("Str.toIntRadix only supports radixes from 2 to 36", 0);
+                unreachable;
+            }
+
+            // unlike with a radix of 0, prefixes like "0x" are not accepted here
+            if (std.fmt.parseInt(T, buf.asSlice(), radix)) |success| {
+                return .{ .errorcode = 0, .value = success };
+            } else |_| {
+                return .{ .errorcode = 1, .value = 0 };
+            }
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn exportParseFloat(comptime T: type, comptime name: []const u8) void {
     comptime var f = struct {
         fn func(buf: RocStr) callconv(.C) NumParseResult(T) {
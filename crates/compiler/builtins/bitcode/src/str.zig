@@ -1,6 +1,8 @@
 const utils = @import("utils.zig");
 const RocList = @import("list.zig").RocList;
 const grapheme = @import("helpers/grapheme.zig");
+const ucd = @import("helpers/unicode.zig");
+const roc_panic = @import("panic.zig").panic_help;
 const UpdateMode = utils.UpdateMode;
 const std = @import("std");
 const mem = std.mem;
@@ -542,6 +544,31 @@ pub fn strEqual(self: RocStr, other: RocStr) callconv(.C) bool {
     return self.eq(other);
 }
 
+// Str.compareUtf8
+// Returns the tag id of `[LT, EQ, GT]`. Tag ids follow the alphabetical order of the tag
+// names, so EQ is 0, GT is 1, and LT is 2, like the result of Num.compare.
+pub fn strCompare(self: RocStr, other: RocStr) callconv(.C) u8 {
+    return switch (std.mem.order(u8, self.asSlice(), other.asSlice())) {
+        .eq => 0,
+        .gt => 1,
+        .lt => 2,
+    };
+}
+
+test "strCompare" {
+    const apple = RocStr.fromSlice("apple");
+    defer apple.decref();
+    const apples = RocStr.fromSlice("apples");
+    defer apples.decref();
+    const banana = RocStr.fromSlice("banana, a fruit that is too long to be a small string");
+    defer banana.decref();
+
+    try expectEqual(strCompare(apple, apple), 0);
+    try expectEqual(strCompare(banana, apple), 1);
+    try expectEqual(strCompare(apple, apples), 2);
+    try expectEqual(strCompare(apples, banana), 2);
+}
+
 // Str.numberOfBytes
 pub fn strNumberOfBytes(string: RocStr) callconv(.C) usize {
     return string.len();
@@ -829,6 +856,101 @@ fn strFromFloatHelp(comptime T: type, float: T) RocStr {
     return RocStr.init(&buf, result.len);
 }
 
+// Num.toStrRadixPadded
+pub fn exportFromIntRadix(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(int: T, radix: u8, min_digits: u8) callconv(.C) RocStr {
+            return @call(.always_inline, strFromIntRadixHelp, .{ T, int, radix, min_digits });
+        }
+    }.func;
+
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+const RADIX_DIGITS = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn strFromIntRadixHelp(comptime T: type, int: T, radix: u8, min_digits: u8) RocStr {
+    if (radix < 2 or radix > 36) {
+        roc_panic("Num.toStrRadix only supports radixes from 2 to 36", 0);
+        unreachable;
+    }
+
+    // a minus sign, followed by either every digit of the value in base 2, or the zero padding
+    const size = 1 + @max(@bitSizeOf(T), std.math.maxInt(u8));
+    var buf: [size]u8 = undefined;
+    var index: usize = size;
+
+    var magnitude = std.math.absCast(int);
+    const base: @TypeOf(magnitude) = radix;
+    const digit_count = @max(min_digits, 1);
+
+    while (magnitude != 0 or size - index < digit_count) {
+        index -= 1;
+        buf[index] = RADIX_DIGITS[@as(usize, @intCast(magnitude % base))];
+        magnitude /= base;
+    }
+
+    if (@typeInfo(T).Int.signedness == .signed and int < 0) {
+        index -= 1;
+        buf[index] = '-';
+    }
+
+    return RocStr.init(buf[index..].ptr, size - index);
+}
+
+// Num.toStrDecimals
+pub fn exportFromFloatDecimals(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(float: T, decimals: u8) callconv(.C) RocStr {
+            return @call(.always_inline, strFromFloatFormatHelp, .{ T, float, .decimal, decimals });
+        }
+    }.func;
+
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+// Num.toStrScientific
+pub fn exportFromFloatScientific(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(float: T, decimals: u8) callconv(.C) RocStr {
+            return @call(.always_inline, strFromFloatFormatHelp, .{ T, float, .scientific, decimals });
+        }
+    }.func;
+
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+fn strFromFloatFormatHelp(comptime T: type, float: T, comptime mode: std.fmt.format_float.Format, decimals: u8) RocStr {
+    // a minus sign, the 309 digits before the decimal point of the largest f64, the point, and the decimals
+    var buf: [1 + 309 + 1 + std.math.maxInt(u8)]u8 = undefined;
+    const result = std.fmt.formatFloat(&buf, float, .{ .mode = mode, .precision = decimals }) catch unreachable;
+
+    return RocStr.init(&buf, result.len);
+}
+
+fn expectStrFromNum(actual: RocStr, expected: []const u8) !void {
+    defer actual.decref();
+
+    try expect(mem.eql(u8, actual.asSlice(), expected));
+}
+
+test "strFromIntRadixHelp" {
+    try expectStrFromNum(strFromIntRadixHelp(u8, 255, 16, 0), "ff");
+    try expectStrFromNum(strFromIntRadixHelp(i32, -10, 2, 0), "-1010");
+    try expectStrFromNum(strFromIntRadixHelp(u64, 0, 8, 0), "0");
+    try expectStrFromNum(strFromIntRadixHelp(i16, -42, 10, 5), "-00042");
+    try expectStrFromNum(strFromIntRadixHelp(u32, 1295, 36, 0), "zz");
+    try expectStrFromNum(strFromIntRadixHelp(i8, -128, 2, 0), "-10000000");
+    try expectStrFromNum(strFromIntRadixHelp(u128, std.math.maxInt(u128), 16, 0), "ffffffffffffffffffffffffffffffff");
+}
+
+test "strFromFloatFormatHelp" {
+    try expectStrFromNum(strFromFloatFormatHelp(f64, 3.14159, .decimal, 2), "3.14");
+    try expectStrFromNum(strFromFloatFormatHelp(f64, -2.5, .decimal, 3), "-2.500");
+    try expectStrFromNum(strFromFloatFormatHelp(f64, 1234.5, .scientific, 2), "1.23e3");
+    try expectStrFromNum(strFromFloatFormatHelp(f32, 0.000123, .scientific, 1), "1.2e-4");
+}
+
 // Str.split
 pub fn strSplit(string: RocStr, delimiter: RocStr) callconv(.C) RocList {
     const segment_count = countSegments(string, delimiter);
@@ -2901,3 +3023,448 @@ pub fn strReleaseExcessCapacity(
         return output;
     }
 }
+
+// CASE MAPPING
+
+// Str.toUpper
+pub fn strToUpper(string: RocStr) callconv(.C) RocStr {
+    return strMapCase(string, .Upper);
+}
+
+// Str.toLower
+pub fn strToLower(string: RocStr) callconv(.C) RocStr {
+    return strMapCase(string, .Lower);
+}
+
+const GREEK_CAPITAL_SIGMA: u21 = 0x03A3;
+const GREEK_SMALL_FINAL_SIGMA: u21 = 0x03C2;
+
+// The mapping of the code point at bytes[start..end], or null if it maps to itself.
+fn caseMappingAt(bytes: []const u8, start: usize, end: usize, code_point: u21, mapping: ucd.CaseMapping) ?[]const u21 {
+    if (mapping == .Lower and code_point == GREEK_CAPITAL_SIGMA and isFinalSigma(bytes, start, end)) {
+        return &[_]u21{GREEK_SMALL_FINAL_SIGMA};
+    }
+
+    return ucd.mapCase(mapping, code_point);
+}
+
+// A capital sigma lowercases to a final sigma at the end of a word: when it follows a cased letter
+// and is not followed by one, skipping case-ignorable code points like apostrophes and accents.
+// This is the Final_Sigma condition from section 3.13 of the Unicode standard.
+fn isFinalSigma(bytes: []const u8, start: usize, end: usize) bool {
+    var before = ReverseUtf8View.initUnchecked(bytes[0..start]).iterator();
+    const follows_cased = while (before.nextCodepoint()) |code_point| {
+        if (!ucd.isCaseIgnorable(code_point)) {
+            break ucd.isCased(code_point);
+        }
+    } else false;
+
+    if (!follows_cased) {
+        return false;
+    }
+
+    var after = unicode.Utf8View.initUnchecked(bytes[end..]).iterator();
+    while (after.nextCodepoint()) |code_point| {
+        if (!ucd.isCaseIgnorable(code_point)) {
+            return !ucd.isCased(code_point);
+        }
+    }
+
+    return true;
+}
+
+// The argument is borrowed; the result is always a new reference.
+fn strMapCase(string: RocStr, mapping: ucd.CaseMapping) RocStr {
+    const bytes = string.asU8ptr()[0..string.len()];
+
+    // first, find out how many bytes the result needs
+    var result_length: usize = 0;
+    var changed = false;
+    var iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepointSlice()) |slice| {
+        const code_point = unicode.utf8Decode(slice) catch unreachable;
+
+        if (caseMappingAt(bytes, iter.i - slice.len, iter.i, code_point, mapping)) |mapped| {
+            changed = true;
+            for (mapped) |mapped_code_point| {
+                result_length += unicode.utf8CodepointSequenceLength(mapped_code_point) catch unreachable;
+            }
+        } else {
+            result_length += slice.len;
+        }
+    }
+
+    if (!changed) {
+        string.incref(1);
+        return string;
+    }
+
+    var result = RocStr.allocate(result_length);
+    const output = result.asU8ptrMut();
+    var index: usize = 0;
+
+    iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepointSlice()) |slice| {
+        const code_point = unicode.utf8Decode(slice) catch unreachable;
+
+        if (caseMappingAt(bytes, iter.i - slice.len, iter.i, code_point, mapping)) |mapped| {
+            for (mapped) |mapped_code_point| {
+                index += unicode.utf8Encode(mapped_code_point, output[index..result_length]) catch unreachable;
+            }
+        } else {
+            @memcpy(output[index..(index + slice.len)], slice);
+            index += slice.len;
+        }
+    }
+
+    return result;
+}
+
+// Iterates over the full case folding of the code points of a string.
+const CaseFoldIterator = struct {
+    code_points: unicode.Utf8Iterator,
+    pending: []const u21 = &[_]u21{},
+
+    // Takes a pointer, because a small string's bytes live inside the struct itself.
+    fn init(string: *const RocStr) CaseFoldIterator {
+        const bytes = string.asU8ptr()[0..string.len()];
+
+        return CaseFoldIterator{ .code_points = unicode.Utf8View.initUnchecked(bytes).iterator() };
+    }
+
+    fn next(self: *CaseFoldIterator) ?u21 {
+        if (self.pending.len == 0) {
+            const code_point = self.code_points.nextCodepoint() orelse return null;
+            self.pending = ucd.mapCase(.Fold, code_point) orelse return code_point;
+        }
+
+        const code_point = self.pending[0];
+        self.pending = self.pending[1..];
+
+        return code_point;
+    }
+};
+
+// Str.caseInsensitiveEq
+pub fn strCaseInsensitiveEq(a: RocStr, b: RocStr) callconv(.C) bool {
+    if (a.eq(b)) {
+        return true;
+    }
+
+    var a_folded = CaseFoldIterator.init(&a);
+    var b_folded = CaseFoldIterator.init(&b);
+
+    while (true) {
+        const a_code_point = a_folded.next();
+        const b_code_point = b_folded.next();
+
+        if (a_code_point == null or b_code_point == null) {
+            return a_code_point == null and b_code_point == null;
+        }
+
+        if (a_code_point.? != b_code_point.?) {
+            return false;
+        }
+    }
+}
+
+test "strToUpper" {
+    const input = RocStr.fromSlice("straße ǆ ﬁ");
+    defer input.decref();
+
+    const upper = strToUpper(input);
+    defer upper.decref();
+
+    const expected = RocStr.fromSlice("STRASSE Ǆ FI");
+    defer expected.decref();
+
+    try expect(upper.eq(expected));
+}
+
+test "strToLower: final sigma" {
+    const input = RocStr.fromSlice("ΟΔΟΣ ΣΑ Σ");
+    defer input.decref();
+
+    const lower = strToLower(input);
+    defer lower.decref();
+
+    const expected = RocStr.fromSlice("οδος σα σ");
+    defer expected.decref();
+
+    try expect(lower.eq(expected));
+}
+
+test "strCaseInsensitiveEq" {
+    const a = RocStr.fromSlice("Straße");
+    defer a.decref();
+    const b = RocStr.fromSlice("STRASSE");
+    defer b.decref();
+    const c = RocStr.fromSlice("STRASS");
+    defer c.decref();
+
+    try expect(strCaseInsensitiveEq(a, b));
+    try expect(!strCaseInsensitiveEq(a, c));
+}
+
+// NORMALIZATION
+
+// Str.toNfd
+pub fn strToNfd(string: RocStr) callconv(.C) RocStr {
+    return strNormalize(string, false);
+}
+
+// Str.toNfc
+pub fn strToNfc(string: RocStr) callconv(.C) RocStr {
+    return strNormalize(string, true);
+}
+
+// The argument is borrowed; the result is always a new reference.
+fn strNormalize(string: RocStr, compose: bool) RocStr {
+    const bytes = string.asU8ptr()[0..string.len()];
+
+    // ASCII text is already in every normal form
+    if (isAscii(bytes)) {
+        string.incref(1);
+        return string;
+    }
+
+    var decomposition: [4]u21 = undefined;
+    var decomposed_length: usize = 0;
+
+    var iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepoint()) |code_point| {
+        decomposed_length += ucd.decompose(code_point, &decomposition).len;
+    }
+
+    const alignment = @alignOf(u21);
+    const buffer = utils.alloc(decomposed_length * @sizeOf(u21), alignment) orelse unreachable;
+    defer utils.dealloc(buffer, alignment);
+
+    const code_points = @as([*]u21, @ptrCast(@alignCast(buffer)))[0..decomposed_length];
+
+    // decompose, putting each run of non-starters in canonical order as we go
+    var length: usize = 0;
+    iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepoint()) |code_point| {
+        for (ucd.decompose(code_point, &decomposition)) |decomposed| {
+            const class = ucd.combiningClass(decomposed);
+
+            var index = length;
+            while (class != 0 and index > 0 and ucd.combiningClass(code_points[index - 1]) > class) {
+                code_points[index] = code_points[index - 1];
+                index -= 1;
+            }
+
+            code_points[index] = decomposed;
+            length += 1;
+        }
+    }
+
+    if (compose) {
+        length = composeInPlace(code_points);
+    }
+
+    return encodeUtf8(code_points[0..length]);
+}
+
+// The canonical composition algorithm from section 3.11 of the Unicode standard. The code points
+// must already be in canonical order. Returns the number of code points after composing.
+fn composeInPlace(code_points: []u21) usize {
+    var length: usize = 0;
+    var starter_index: ?usize = null;
+    var last_class: u8 = 0;
+
+    for (code_points) |code_point| {
+        const class = ucd.combiningClass(code_point);
+
+        if (starter_index) |starter| {
+            // a code point is blocked from the starter by anything in between with a class
+            // that is zero, or at least as high as its own
+            const adjacent = starter == length - 1;
+            if (adjacent or (last_class != 0 and last_class < class)) {
+                if (ucd.compose(code_points[starter], code_point)) |composite| {
+                    code_points[starter] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if (class == 0) {
+            starter_index = length;
+        }
+
+        last_class = class;
+        code_points[length] = code_point;
+        length += 1;
+    }
+
+    return length;
+}
+
+fn isAscii(bytes: []const u8) bool {
+    for (bytes) |byte| {
+        if (byte >= 0x80) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+fn encodeUtf8(code_points: []const u21) RocStr {
+    var result_length: usize = 0;
+    for (code_points) |code_point| {
+        result_length += unicode.utf8CodepointSequenceLength(code_point) catch unreachable;
+    }
+
+    var result = RocStr.allocate(result_length);
+    const output = result.asU8ptrMut();
+
+    var index: usize = 0;
+    for (code_points) |code_point| {
+        index += unicode.utf8Encode(code_point, output[index..result_length]) catch unreachable;
+    }
+
+    return result;
+}
+
+test "strToNfd and strToNfc" {
+    // "é" precomposed, "e" with a combining acute accent, and a dot below and a dot above in the wrong order
+    const composed = RocStr.fromSlice("caf\u{E9} \u{1E0B}\u{323}");
+    defer composed.decref();
+    const decomposed = RocStr.fromSlice("cafe\u{301} d\u{323}\u{307}");
+    defer decomposed.decref();
+    const expected_nfc = RocStr.fromSlice("caf\u{E9} \u{1E0D}\u{307}");
+    defer expected_nfc.decref();
+
+    const nfd = strToNfd(composed);
+    defer nfd.decref();
+    try expect(nfd.eq(decomposed));
+
+    const nfc = strToNfc(decomposed);
+    defer nfc.decref();
+    try expect(nfc.eq(expected_nfc));
+}
+
+test "strToNfc: hangul" {
+    const jamo = RocStr.fromSlice("\u{1112}\u{1161}\u{11AB}");
+    defer jamo.decref();
+    const syllable = RocStr.fromSlice("\u{D55C}");
+    defer syllable.decref();
+
+    const nfc = strToNfc(jamo);
+    defer nfc.decref();
+    try expect(nfc.eq(syllable));
+
+    const nfd = strToNfd(syllable);
+    defer nfd.decref();
+    try expect(nfd.eq(jamo));
+}
+
+// UTF-16
+
+const REPLACEMENT_CHARACTER: u21 = 0xFFFD;
+
+// Str.toUtf16
+pub fn strToUtf16(string: RocStr) callconv(.C) RocList {
+    const bytes = string.asU8ptr()[0..string.len()];
+
+    var length: usize = 0;
+    var iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepoint()) |code_point| {
+        length += if (code_point >= 0x10000) @as(usize, 2) else 1;
+    }
+
+    const list = RocList.allocate(@alignOf(u16), length, @sizeOf(u16));
+    const units = list.elements(u16) orelse return list;
+
+    var index: usize = 0;
+    iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepoint()) |code_point| {
+        if (code_point >= 0x10000) {
+            const offset = code_point - 0x10000;
+            units[index] = @as(u16, @intCast(0xD800 + (offset >> 10)));
+            units[index + 1] = @as(u16, @intCast(0xDC00 + (offset & 0x3FF)));
+            index += 2;
+        } else {
+            units[index] = @as(u16, @intCast(code_point));
+            index += 1;
+        }
+    }
+
+    return list;
+}
+
+// Decodes the code point starting at units[index.*], and moves the index past it.
+// Unpaired surrogates decode to the replacement character.
+fn nextUtf16CodePoint(units: []const u16, index: *usize) u21 {
+    const unit = units[index.*];
+    index.* += 1;
+
+    if (unit >= 0xD800 and unit <= 0xDBFF and index.* < units.len) {
+        const next = units[index.*];
+        if (next >= 0xDC00 and next <= 0xDFFF) {
+            index.* += 1;
+            return 0x10000 + ((@as(u21, unit) - 0xD800) << 10) + (@as(u21, next) - 0xDC00);
+        }
+    }
+
+    if (unit >= 0xD800 and unit <= 0xDFFF) {
+        return REPLACEMENT_CHARACTER;
+    }
+
+    return unit;
+}
+
+// Str.fromUtf16Lossy. `Str.fromUtf16` checks for unpaired surrogates before calling this.
+pub fn strFromUtf16Lossy(list: RocList) callconv(.C) RocStr {
+    const units = (list.elements(u16) orelse return RocStr.empty())[0..list.len()];
+
+    var result_length: usize = 0;
+    var index: usize = 0;
+    while (index < units.len) {
+        const code_point = nextUtf16CodePoint(units, &index);
+        result_length += unicode.utf8CodepointSequenceLength(code_point) catch unreachable;
+    }
+
+    var result = RocStr.allocate(result_length);
+    const output = result.asU8ptrMut();
+
+    var output_index: usize = 0;
+    index = 0;
+    while (index < units.len) {
+        const code_point = nextUtf16CodePoint(units, &index);
+        output_index += unicode.utf8Encode(code_point, output[output_index..result_length]) catch unreachable;
+    }
+
+    return result;
+}
+
+test "strToUtf16 and strFromUtf16Lossy" {
+    const string = RocStr.fromSlice("a\u{E9}\u{1F600}");
+    defer string.decref();
+
+    const units = strToUtf16(string);
+    defer units.decref(@alignOf(u16));
+
+    const expected = [_]u16{ 0x61, 0xE9, 0xD83D, 0xDE00 };
+    try expectEqual(expected.len, units.len());
+    try expect(mem.eql(u16, &expected, (units.elements(u16) orelse unreachable)[0..units.len()]));
+
+    const round_tripped = strFromUtf16Lossy(units);
+    defer round_tripped.decref();
+    try expect(round_tripped.eq(string));
+}
+
+test "strFromUtf16Lossy: unpaired surrogate" {
+    const list = RocList.fromSlice(u16, &[_]u16{ 0x61, 0xD83D, 0x62 });
+    defer list.decref(@alignOf(u16));
+
+    const string = strFromUtf16Lossy(list);
+    defer string.decref();
+
+    const expected = RocStr.fromSlice("a\u{FFFD}b");
+    defer expected.decref();
+
+    try expect(string.eq(expected));
+}
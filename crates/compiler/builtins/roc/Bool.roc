@@ -1,5 +1,5 @@
 interface Bool
-    exposes [Bool, Eq, true, false, and, or, not, isEq, isNotEq]
+    exposes [Bool, Eq, Ord, true, false, and, or, not, isEq, isNotEq, compare]
     imports []
 
 ## Defines a type that can be compared for total equality.
@@ -32,6 +32,33 @@ Eq implements
     ## cannot derive `isEq` for types that contain functions.
     isEq : a, a -> Bool where a implements Eq
 
+## Defines a type whose values can be put in a total order.
+##
+## A total order means that any two values `a`, `b` of the type can be
+## compared, and `compare a b` returns exactly one of `LT`, `EQ`, or `GT`
+## depending on whether `a` sorts before, the same as, or after `b`.
+##
+## `List.sortAsc` and `List.sortDesc` sort lists of any type that implements `Ord`.
+Ord implements
+    ## Returns `LT` if the first value sorts before the second, `GT` if it sorts
+    ## after the second, and `EQ` otherwise.
+    ##
+    ## **Note** that when `compare` is determined by the Roc compiler, values
+    ## are compared structurally. The rules for this are as follows:
+    ##
+    ## 1. [Num] values are compared by their numeric value. [Str] values are
+    ## compared by their UTF-8 bytes.
+    ## 2. Records are compared field by field, in alphabetical order of the
+    ## field names. The first pair of fields that differ decides the ordering.
+    ## 3. Tuples are compared element by element, in order.
+    ## 4. A [List] is compared element by element; if one list is a prefix of
+    ## the other, the shorter list sorts first.
+    ## 5. Tags are ordered by their names in alphabetical order. Two values with
+    ## the same tag are compared by their payloads.
+    ## 6. Functions cannot be ordered, therefore Roc cannot derive `compare`
+    ## for types that contain functions.
+    compare : a, a -> [LT, EQ, GT] where a implements Ord
+
 ## Represents the boolean true and false using an opaque type.
 ## `Bool` implements the `Eq` ability.
 Bool := [True, False] implements [Eq { isEq: boolIsEq }]
@@ -126,3 +153,7 @@ structuralEq : a, a -> Bool
 # INTERNAL COMPILER USE ONLY: used to lower calls to `isNotEq` to structural
 # inequality via the `NotEq` low-level for derived types.
 structuralNotEq : a, a -> Bool
+
+# INTERNAL COMPILER USE ONLY: used to lower calls to `compare` to structural
+# ordering via the `Compare` low-level for derived types.
+structuralCompare : a, a -> [LT, EQ, GT]
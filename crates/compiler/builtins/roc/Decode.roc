@@ -24,6 +24,7 @@ interface Decode
         list,
         record,
         tuple,
+        tag,
         custom,
         decodeWith,
         fromBytesPartial,
@@ -115,6 +116,15 @@ DecoderFormatting implements
     ## `finalizer` should produce the tuple value from the decoded `state`.
     tuple : state, (state, Nat -> [Next (Decoder state fmt), TooLong]), (state -> Result val DecodeError) -> Decoder val fmt where fmt implements DecoderFormatting
 
+    ## `tag stepTag` decodes a value of a tag union.
+    ##
+    ## `stepTag` is passed the name of the tag that was found, and returns a
+    ## decoder for the whole tag value, or `UnknownTag` if the tag is not a
+    ## part of the decoded tag union. Derived decoders build the decoder of a
+    ## tag's payloads with [tuple], so formats should present the payloads of
+    ## a tag the same way they present the elements of a tuple.
+    tag : (Str -> [Known (Decoder val fmt), UnknownTag]) -> Decoder val fmt where fmt implements DecoderFormatting
+
 ## Build a custom [Decoder] function. For example the implementation of
 ## `decodeBool` could be defined as follows;
 ##
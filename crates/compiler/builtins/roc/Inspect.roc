@@ -29,13 +29,14 @@ interface Inspect
         f32,
         f64,
         dec,
+        nat,
         custom,
         apply,
         toInspector,
     ]
     imports [
         Bool.{ Bool },
-        Num.{ U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec },
+        Num.{ U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec, Nat },
         List,
         Str,
     ]
@@ -76,6 +77,7 @@ InspectFormatter implements
     f32 : F32 -> Inspector f where f implements InspectFormatter
     f64 : F64 -> Inspector f where f implements InspectFormatter
     dec : Dec -> Inspector f where f implements InspectFormatter
+    nat : Nat -> Inspector f where f implements InspectFormatter
 
 Inspector f := f -> f where f implements InspectFormatter
 
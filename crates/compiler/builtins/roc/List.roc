@@ -71,7 +71,7 @@ interface List
         chunksOf,
     ]
     imports [
-        Bool.{ Bool, Eq },
+        Bool.{ Bool, Eq, Ord },
         Result.{ Result },
         Num.{ Nat, Num, Int },
     ]
@@ -917,22 +917,38 @@ expect
 expect
     List.range { start: At 4, end: At 0 } == [4, 3, 2, 1, 0]
 
-## Sort with a custom comparison function
+## Sort with a custom comparison function.
+## ```
+## expect List.sortWith [3, 1, 2] Num.compare == [1, 2, 3]
+## ```
+##
+## The sort is stable: elements that compare as `EQ` stay in the same order
+## relative to each other as they were in the original list. It takes
+## `O(n log n)` comparisons in the worst case.
 sortWith : List a, (a, a -> [LT, EQ, GT]) -> List a
 
-## Sorts a list in ascending order (lowest to highest), using a function which
-## specifies a way to represent each element as a number.
+## Sorts a list in ascending order (lowest to highest), using the [Ord](../Bool#Ord)
+## implementation of its elements.
+## ```
+## expect List.sortAsc [3, 1, 2] == [1, 2, 3]
+## expect List.sortAsc ["b", "c", "a"] == ["a", "b", "c"]
+## ```
+##
+## Like [List.sortWith], this sort is stable.
 ##
 ## To sort in descending order (highest to lowest), use [List.sortDesc] instead.
-sortAsc : List (Num a) -> List (Num a)
-sortAsc = \list -> List.sortWith list Num.compare
+sortAsc : List a -> List a where a implements Ord
+sortAsc = \list -> List.sortWith list Bool.compare
 
-## Sorts a list in descending order (highest to lowest), using a function which
-## specifies a way to represent each element as a number.
+## Sorts a list in descending order (highest to lowest), using the [Ord](../Bool#Ord)
+## implementation of its elements.
+##
+## Like [List.sortWith], this sort is stable: elements that compare as `EQ`
+## keep their original relative order, rather than being reversed.
 ##
 ## To sort in ascending order (lowest to highest), use [List.sortAsc] instead.
-sortDesc : List (Num a) -> List (Num a)
-sortDesc = \list -> List.sortWith list (\a, b -> Num.compare b a)
+sortDesc : List a -> List a where a implements Ord
+sortDesc = \list -> List.sortWith list (\a, b -> Bool.compare b a)
 
 swap : List a, Nat, Nat -> List a
 
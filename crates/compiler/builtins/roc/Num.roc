@@ -106,6 +106,10 @@ interface Num
         divTrunc,
         divTruncChecked,
         toStr,
+        toStrRadix,
+        toStrRadixPadded,
+        toStrDecimals,
+        toStrScientific,
         isMultipleOf,
         minI8,
         maxI8,
@@ -573,8 +577,59 @@ tau = 2 * pi
 ## When this function is given a non-[finite](Num.isFinite)
 ## [F64] or [F32] value, the returned string will be `"NaN"`, `"∞"`, or `"-∞"`.
 ##
-## To get strings in hexadecimal, octal, or binary format, use `Num.format`.
+## To get strings in hexadecimal, octal, or binary format, use [Num.toStrRadix].
+## To control how many digits come after the decimal point, use [Num.toStrDecimals]
+## or [Num.toStrScientific].
 toStr : Num * -> Str
+
+## Convert an integer to a [Str] in the given radix (also known as base), which
+## must be between 2 and 36. Digits above 9 are written as lowercase letters, and
+## negative numbers start with a `-`.
+## ```
+## Num.toStrRadix 255 16 # "ff"
+## Num.toStrRadix -10 2 # "-1010"
+## Num.toStrRadix 1295 36 # "zz"
+## ```
+## This crashes if the radix is less than 2 or greater than 36.
+##
+## To read a number back in from a [Str] in a given radix, use `Str.toIntRadix`.
+toStrRadix : Int *, U8 -> Str
+toStrRadix = \num, radix -> toStrRadixPadded num radix 0
+
+## Like [Num.toStrRadix], but adds zeros in front of the number until it has at
+## least the given number of digits. A leading `-` does not count as a digit.
+## ```
+## Num.toStrRadixPadded 10 2 8 # "00001010"
+## Num.toStrRadixPadded -42 10 5 # "-00042"
+## Num.toStrRadixPadded 255 16 1 # "ff"
+## ```
+## This crashes if the radix is less than 2 or greater than 36.
+toStrRadixPadded : Int *, U8, U8 -> Str
+
+## Convert a fractional number to a [Str] with exactly the given number of
+## digits after the decimal point, rounding the last digit if needed.
+## There is no decimal point when the number of digits is 0.
+## ```
+## Num.toStrDecimals 3.14159 2 # "3.14"
+## Num.toStrDecimals 2.5 3 # "2.500"
+## Num.toStrDecimals 1234.56 0 # "1235"
+## ```
+## When this function is given a non-[finite](Num.isFinite) [F64] or [F32]
+## value, the returned string will be `"nan"`, `"inf"`, or `"-inf"`.
+toStrDecimals : Frac *, U8 -> Str
+
+## Convert a fractional number to a [Str] in scientific notation: a single
+## digit, a decimal point followed by the given number of digits, and then `e`
+## and the power of 10 to multiply by. The last digit is rounded if needed, and
+## there is no decimal point when the number of digits is 0.
+## ```
+## Num.toStrScientific 1234.5 2 # "1.23e3"
+## Num.toStrScientific 0.00125 1 # "1.3e-3"
+## Num.toStrScientific 5 0 # "5e0"
+## ```
+## When this function is given a non-[finite](Num.isFinite) [F64] or [F32]
+## value, the returned string will be `"nan"`, `"inf"`, or `"-inf"`.
+toStrScientific : Frac *, U8 -> Str
 intCast : Int a -> Int b
 
 bytesToU16Lowlevel : List U8, Nat -> U16
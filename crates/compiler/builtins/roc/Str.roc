@@ -136,12 +136,20 @@ interface Str
         withPrefix,
         graphemes,
         contains,
+        toUpper,
+        toLower,
+        caseInsensitiveEq,
+        toNfc,
+        toNfd,
+        toUtf16,
+        fromUtf16,
+        toIntRadix,
     ]
     imports [
         Bool.{ Bool, Eq },
         Result.{ Result },
         List,
-        Num.{ Nat, Num, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec },
+        Num.{ Int, Nat, Num, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec },
     ]
 
 Utf8ByteProblem : [
@@ -426,6 +434,58 @@ FromUtf8Result : {
 
 fromUtf8RangeLowlevel : List U8, Nat, Nat -> FromUtf8Result
 
+## Returns a [List] of the string's [U16] UTF-16 [code units](https://unicode.org/glossary/#code_unit),
+## as used by Windows APIs and JavaScript strings. Code points outside the
+## [Basic Multilingual Plane](https://unicode.org/glossary/#basic_multilingual_plane)
+## become a pair of surrogate code units.
+## ```
+## expect Str.toUtf16 "Roc" == [82, 111, 99]
+## expect Str.toUtf16 "鹏" == [40527]
+## expect Str.toUtf16 "🐦" == [55357, 56358]
+## ```
+toUtf16 : Str -> List U16
+
+## Converts a [List] of [U16] UTF-16 [code units](https://unicode.org/glossary/#code_unit) to a string.
+##
+## Returns `Err` with the index of the first unpaired surrogate code unit if the given
+## code units are invalid UTF-16, and returns `Ok ""` when given `[]`.
+## ```
+## expect Str.fromUtf16 [82, 111, 99] == Ok "Roc"
+## expect Str.fromUtf16 [55357, 56358] == Ok "🐦"
+## expect Str.fromUtf16 [82, 55357] == Err (BadUtf16 1)
+## ```
+fromUtf16 : List U16 -> Result Str [BadUtf16 Nat]
+fromUtf16 = \units ->
+    when firstUnpairedSurrogate units 0 is
+        Ok index -> Err (BadUtf16 index)
+        Err NotFound -> Ok (fromUtf16Lossy units)
+
+expect (Str.fromUtf16 [82, 111, 99]) == Ok "Roc"
+expect (Str.fromUtf16 [40527]) == Ok "鹏"
+expect (Str.fromUtf16 [55357, 56358]) == Ok "🐦"
+expect (Str.fromUtf16 []) == Ok ""
+expect (Str.fromUtf16 [82, 55357]) == Err (BadUtf16 1)
+expect (Str.fromUtf16 [56358, 55357]) == Err (BadUtf16 0)
+
+firstUnpairedSurrogate : List U16, Nat -> Result Nat [NotFound]
+firstUnpairedSurrogate = \units, index ->
+    when List.get units index is
+        Err OutOfBounds -> Err NotFound
+        Ok unit ->
+            if unit >= 0xD800 && unit <= 0xDBFF then
+                when List.get units (index + 1) is
+                    Ok next if next >= 0xDC00 && next <= 0xDFFF ->
+                        firstUnpairedSurrogate units (index + 2)
+
+                    _ -> Ok index
+            else if unit >= 0xDC00 && unit <= 0xDFFF then
+                Ok index
+            else
+                firstUnpairedSurrogate units (index + 1)
+
+## Unpaired surrogates become the replacement character U+FFFD.
+fromUtf16Lossy : List U16 -> Str
+
 ## Check if the given [Str] starts with a value.
 ## ```
 ## expect Str.startsWith "ABC" "A" == Bool.true
@@ -459,6 +519,60 @@ trimStart : Str -> Str
 ## ```
 trimEnd : Str -> Str
 
+## Returns the [Str] with every letter converted to uppercase, using the full
+## [Unicode case mappings](https://www.unicode.org/versions/latest/ch03.pdf#G33992).
+## Some letters become more than one letter in uppercase.
+## ```
+## expect Str.toUpper "Hello, Roc!" == "HELLO, ROC!"
+## expect Str.toUpper "straße" == "STRASSE"
+## expect Str.toUpper "ǆemper" == "ǄEMPER"
+## ```
+##
+## The mappings do not depend on the language of the text, so for example the
+## Turkish dotless `ı` and dotted `İ` are not treated specially.
+toUpper : Str -> Str
+
+## Returns the [Str] with every letter converted to lowercase, using the full
+## [Unicode case mappings](https://www.unicode.org/versions/latest/ch03.pdf#G33992).
+## A Greek capital sigma becomes a final sigma `ς` at the end of a word.
+## ```
+## expect Str.toLower "Hello, Roc!" == "hello, roc!"
+## expect Str.toLower "ΟΔΥΣΣΕΥΣ" == "οδυσσευς"
+## ```
+##
+## The mappings do not depend on the language of the text, so for example the
+## Turkish dotless `ı` and dotted `İ` are not treated specially.
+toLower : Str -> Str
+
+## Returns [Bool.true] if the two strings are equal when ignoring case, using the full
+## [Unicode case folding](https://www.unicode.org/versions/latest/ch05.pdf#G21180).
+## ```
+## expect Str.caseInsensitiveEq "Roc" "rOC"
+## expect Str.caseInsensitiveEq "Straße" "STRASSE"
+## expect !(Str.caseInsensitiveEq "Roc" "Rock")
+## ```
+##
+## Strings that look the same but are not [normalized](#toNfc) the same way may
+## still compare as different, so normalize both strings first if that matters.
+caseInsensitiveEq : Str, Str -> Bool
+
+## Returns the [Str] in Unicode [Normalization Form C](https://unicode.org/reports/tr15/),
+## where letters and their accents are composed into single code points wherever possible.
+## This is the form most text on the web is in.
+## ```
+## expect Str.toNfc "e\u(301)" == "\u(e9)"
+## ```
+##
+## Two strings that contain the same text in different forms are only equal after normalizing them.
+toNfc : Str -> Str
+
+## Returns the [Str] in Unicode [Normalization Form D](https://unicode.org/reports/tr15/),
+## where letters and their accents are decomposed into separate code points.
+## ```
+## expect Str.toNfd "\u(e9)" == "e\u(301)"
+## ```
+toNfd : Str -> Str
+
 ## Encode a [Str] to a [Dec]. A [Dec] value is a 128-bit decimal
 ## [fixed-point number](https://en.wikipedia.org/wiki/Fixed-point_arithmetic).
 ## ```
@@ -635,6 +749,11 @@ getUnsafe : Str, Nat -> U8
 ## ```
 countUtf8Bytes : Str -> Nat
 
+## Compares two strings by their UTF-8 bytes. This is how [Str] implements
+## [Ord](../Bool#Ord): it sorts strings by their Unicode code points, and a string
+## sorts before any longer string that it is a prefix of.
+compareUtf8 : Str, Str -> [LT, EQ, GT]
+
 ## string slice that does not do bounds checking or utf-8 verification
 substringUnsafe : Str, Nat, Nat -> Str
 
@@ -980,6 +1099,33 @@ walkScalarsUntilHelp = \string, state, step, index, length ->
 
 strToNum : Str -> { berrorcode : U8, aresult : Num * }
 
+## Parses a [Str] of digits in the given radix (also known as base), which must
+## be between 2 and 36, into an integer. Letters stand for the digits above 9,
+## in either uppercase or lowercase, and the number may start with a `+` or `-`.
+## ```
+## expect Str.toIntRadix "ff" 16 == Ok 255u8
+## expect Str.toIntRadix "-1010" 2 == Ok -10i32
+## expect Str.toIntRadix "ZZ" 36 == Ok 1295u64
+## expect Str.toIntRadix "12" 2 == Err InvalidNumStr
+## expect Str.toIntRadix "1ff" 16 == Err InvalidNumStr
+## ```
+## Returns `Err InvalidNumStr` if the [Str] has a digit that is too big for the
+## radix, or if the number doesn't fit in the integer type. This crashes if the
+## radix is less than 2 or greater than 36.
+##
+## To convert an integer to a [Str] in a given radix, use `Num.toStrRadix`.
+toIntRadix : Str, U8 -> Result (Int a) [InvalidNumStr]
+toIntRadix = \string, radix ->
+    result : { berrorcode : U8, aresult : Int a }
+    result = strToIntRadix string radix
+
+    if result.berrorcode == 0 then
+        Ok result.aresult
+    else
+        Err InvalidNumStr
+
+strToIntRadix : Str, U8 -> { berrorcode : U8, aresult : Int * }
+
 strToNumHelp : Str -> Result (Num a) [InvalidNumStr]
 strToNumHelp = \string ->
     result : { berrorcode : U8, aresult : Num a }
@@ -85,6 +85,7 @@ Json := { fieldNameMapping : FieldNameMapping }
             list: decodeList,
             record: decodeRecord,
             tuple: decodeTuple,
+            tag: decodeTag,
         },
     ]
 
@@ -691,9 +692,15 @@ decodeTuple = \initialState, stepElem, finalizer -> Decode.custom \initialBytes,
                 Ok {} -> decodeElems stepElem newState (index + 1) nextBytes
                 Err _ -> { result: Ok newState, rest: nextBytes }
 
+        decodeAllElems = \bytes ->
+            when bytes is
+                # An empty tuple, such as the payload of a tag without arguments
+                [']', ..] -> { result: Ok initialState, rest: bytes }
+                _ -> decodeElems stepElem initialState 0 bytes
+
         { rest: afterBracketBytes } <- initialBytes |> openBracket |> tryDecode
 
-        { val: endStateResult, rest: beforeClosingBracketBytes } <- decodeElems stepElem initialState 0 afterBracketBytes |> tryDecode
+        { val: endStateResult, rest: beforeClosingBracketBytes } <- decodeAllElems afterBracketBytes |> tryDecode
 
         { rest: afterTupleBytes } <- beforeClosingBracketBytes |> closingBracket |> tryDecode
 
@@ -708,6 +715,37 @@ expect
 
     actual.result == Ok ("The Answer is", 42)
 
+decodeTag = \stepTag -> Decode.custom \initialBytes, @Json {} ->
+        # Idea: decode `{"A": [v1, v2]}` as `A v1 v2`, mirroring `encodeTag`
+        { rest: afterBraceBytes } <- initialBytes |> openBrace |> tryDecode
+
+        { val: name, rest: afterNameBytes } <- Decode.decodeWith afterBraceBytes decodeString json |> tryDecode
+
+        { rest: afterColonBytes } <- afterNameBytes |> colon |> tryDecode
+
+        when stepTag name is
+            UnknownTag -> { result: Err TooShort, rest: initialBytes }
+            Known decoder ->
+                { val: tag, rest: beforeClosingBraceBytes } <- Decode.decodeWith afterColonBytes decoder json |> tryDecode
+
+                { rest: afterTagBytes } <- beforeClosingBraceBytes |> closingBrace |> tryDecode
+
+                { result: Ok tag, rest: afterTagBytes }
+
+# Test decode of tag
+expect
+    input = Str.toUtf8 "{\"A\":[\"The Answer is\",42]}"
+    actual = Decode.fromBytesPartial input json
+
+    actual.result == Ok (A "The Answer is" 42)
+
+# Test decode of tag without arguments
+expect
+    input = Str.toUtf8 "{\"B\":[]}"
+    actual = Decode.fromBytesPartial input json
+
+    actual.result == Ok B
+
 parseExactChar : List U8, U8 -> DecodeResult {}
 parseExactChar = \bytes, char ->
     when List.get bytes 0 is
@@ -733,6 +771,15 @@ anything = \bytes -> { result: Err TooShort, rest: bytes }
 comma : List U8 -> DecodeResult {}
 comma = \bytes -> parseExactChar bytes ','
 
+openBrace : List U8 -> DecodeResult {}
+openBrace = \bytes -> parseExactChar bytes '{'
+
+closingBrace : List U8 -> DecodeResult {}
+closingBrace = \bytes -> parseExactChar bytes '}'
+
+colon : List U8 -> DecodeResult {}
+colon = \bytes -> parseExactChar bytes ':'
+
 tryDecode : DecodeResult a, ({ val : a, rest : List U8 } -> DecodeResult b) -> DecodeResult b
 tryDecode = \{ result, rest }, mapper ->
     when result is
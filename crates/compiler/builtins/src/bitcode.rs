@@ -48,7 +48,7 @@ impl FloatWidth {
         match self {
             F32 => 4,
             F64 => match target_info.architecture {
-                X86_64 | Aarch64 | Wasm32 => 8,
+                X86_64 | Aarch64 | Riscv64 | Wasm32 => 8,
                 X86_32 | Aarch32 => 4,
             },
         }
@@ -122,6 +122,7 @@ impl IntWidth {
                 Architecture::X86_64
                 | Architecture::Aarch64
                 | Architecture::Aarch32
+                | Architecture::Riscv64
                 | Architecture::Wasm32 => 8,
                 Architecture::X86_32 => 4,
             },
@@ -132,7 +133,10 @@ impl IntWidth {
                 // however, rust does not always think that this is true
                 match target_info.architecture {
                     Architecture::X86_64 => 16,
-                    Architecture::Aarch64 | Architecture::Aarch32 | Architecture::Wasm32 => 16,
+                    Architecture::Aarch64
+                    | Architecture::Aarch32
+                    | Architecture::Riscv64
+                    | Architecture::Wasm32 => 16,
                     Architecture::X86_32 => 8,
                 }
             }
@@ -355,7 +359,14 @@ pub const STR_FROM_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.fro
 pub const STR_TO_INT: IntrinsicName = int_intrinsic!("roc_builtins.str.to_int");
 pub const STR_TO_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.to_float");
 pub const STR_TO_DECIMAL: &str = "roc_builtins.str.to_decimal";
+pub const STR_FROM_INT_RADIX: IntrinsicName = int_intrinsic!("roc_builtins.str.from_int_radix");
+pub const STR_TO_INT_RADIX: IntrinsicName = int_intrinsic!("roc_builtins.str.to_int_radix");
+pub const STR_FROM_FLOAT_DECIMALS: IntrinsicName =
+    float_intrinsic!("roc_builtins.str.from_float_decimals");
+pub const STR_FROM_FLOAT_SCIENTIFIC: IntrinsicName =
+    float_intrinsic!("roc_builtins.str.from_float_scientific");
 pub const STR_EQUAL: &str = "roc_builtins.str.equal";
+pub const STR_COMPARE: &str = "roc_builtins.str.compare";
 pub const STR_SUBSTRING_UNSAFE: &str = "roc_builtins.str.substring_unsafe";
 pub const STR_TO_UTF8: &str = "roc_builtins.str.to_utf8";
 pub const STR_FROM_UTF8_RANGE: &str = "roc_builtins.str.from_utf8_range";
@@ -372,6 +383,13 @@ pub const STR_WITH_CAPACITY: &str = "roc_builtins.str.with_capacity";
 pub const STR_GRAPHEMES: &str = "roc_builtins.str.graphemes";
 pub const STR_REFCOUNT_PTR: &str = "roc_builtins.str.refcount_ptr";
 pub const STR_RELEASE_EXCESS_CAPACITY: &str = "roc_builtins.str.release_excess_capacity";
+pub const STR_TO_UPPER: &str = "roc_builtins.str.to_upper";
+pub const STR_TO_LOWER: &str = "roc_builtins.str.to_lower";
+pub const STR_CASE_INSENSITIVE_EQ: &str = "roc_builtins.str.case_insensitive_eq";
+pub const STR_TO_NFC: &str = "roc_builtins.str.to_nfc";
+pub const STR_TO_NFD: &str = "roc_builtins.str.to_nfd";
+pub const STR_TO_UTF16: &str = "roc_builtins.str.to_utf16";
+pub const STR_FROM_UTF16_LOSSY: &str = "roc_builtins.str.from_utf16_lossy";
 
 pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
@@ -420,6 +438,8 @@ pub const DEC_SUB_WITH_OVERFLOW: &str = "roc_builtins.dec.sub_with_overflow";
 pub const DEC_TAN: &str = "roc_builtins.dec.tan";
 pub const DEC_TO_I128: &str = "roc_builtins.dec.to_i128";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
+pub const DEC_TO_STR_DECIMALS: &str = "roc_builtins.dec.to_str_decimals";
+pub const DEC_TO_STR_SCIENTIFIC: &str = "roc_builtins.dec.to_str_scientific";
 
 pub const UTILS_TEST_PANIC: &str = "roc_builtins.utils.test_panic";
 pub const UTILS_ALLOCATE_WITH_REFCOUNT: &str = "roc_builtins.utils.allocate_with_refcount";
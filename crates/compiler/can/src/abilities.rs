@@ -122,6 +122,15 @@ pub struct ImplKey {
     pub ability_member: Symbol,
 }
 
+impl ImplKey {
+    fn map_symbols(self, f: &mut impl FnMut(Symbol) -> Symbol) -> Self {
+        Self {
+            opaque: f(self.opaque),
+            ability_member: f(self.ability_member),
+        }
+    }
+}
+
 /// Fully-resolved implementation of an ability member for an opaque type.
 /// This is only fully known after type solving of the owning module.
 #[derive(Clone, Debug)]
@@ -484,6 +493,83 @@ impl IAbilitiesStore<Resolved> {
     pub fn deserialize(bytes: &[u8]) -> (Self, usize) {
         serialize::deserialize(bytes)
     }
+
+    /// Replace every symbol in the store, like `Subs::map_symbols` does for types.
+    pub fn map_symbols(&mut self, f: &mut impl FnMut(Symbol) -> Symbol) {
+        let Self {
+            members_of_ability,
+            specialization_to_root,
+            ability_members,
+            declared_implementations,
+            specializations,
+            next_specialization_id: _,
+            resolved_specializations,
+        } = self;
+
+        *members_of_ability = std::mem::take(members_of_ability)
+            .into_iter()
+            .map(|(ability, members)| {
+                let ability = f(ability);
+                (ability, members.into_iter().map(&mut *f).collect())
+            })
+            .collect();
+
+        *specialization_to_root = std::mem::take(specialization_to_root)
+            .into_iter()
+            .map(|(symbol, impl_key)| (f(symbol), impl_key.map_symbols(f)))
+            .collect();
+
+        *ability_members = std::mem::take(ability_members)
+            .into_iter()
+            .map(|(member, mut data)| {
+                let member = f(member);
+                data.parent_ability = f(data.parent_ability);
+                (member, data)
+            })
+            .collect();
+
+        *declared_implementations = std::mem::take(declared_implementations)
+            .into_iter()
+            .map(|(impl_key, member_impl)| {
+                let impl_key = impl_key.map_symbols(f);
+                let member_impl = match member_impl {
+                    MemberImpl::Impl(symbol) => MemberImpl::Impl(f(symbol)),
+                    MemberImpl::Error => MemberImpl::Error,
+                };
+                (impl_key, member_impl)
+            })
+            .collect();
+
+        *specializations = std::mem::take(specializations)
+            .into_iter()
+            .map(|(symbol, mut info)| {
+                let symbol = f(symbol);
+                info.symbol = f(info.symbol);
+                (symbol, info)
+            })
+            .collect();
+
+        for symbol in resolved_specializations.values_mut() {
+            *symbol = f(*symbol);
+        }
+    }
+}
+
+/// Replace every symbol in a module's solved implementations; see [`AbilitiesStore::map_symbols`].
+pub fn map_solved_implementations_symbols(
+    solved_impls: &mut ResolvedImplementations,
+    f: &mut impl FnMut(Symbol) -> Symbol,
+) {
+    *solved_impls = std::mem::take(solved_impls)
+        .into_iter()
+        .map(|(impl_key, mut resolved_impl)| {
+            let impl_key = impl_key.map_symbols(f);
+            if let ResolvedImpl::Impl(info) = &mut resolved_impl {
+                info.symbol = f(info.symbol);
+            }
+            (impl_key, resolved_impl)
+        })
+        .collect();
 }
 
 pub use serialize::deserialize_solved_implementations;
@@ -133,10 +133,19 @@ map_symbol_to_lowlevel_and_arity! {
     StrAppendScalar; STR_APPEND_SCALAR_UNSAFE; 2,
     StrGetScalarUnsafe; STR_GET_SCALAR_UNSAFE; 2,
     StrToNum; STR_TO_NUM; 1,
+    StrToIntRadix; STR_TO_INT_RADIX_LOWLEVEL; 2,
     StrGetCapacity; STR_CAPACITY; 1,
     StrWithCapacity; STR_WITH_CAPACITY; 1,
     StrGraphemes; STR_GRAPHEMES; 1,
     StrReleaseExcessCapacity; STR_RELEASE_EXCESS_CAPACITY; 1,
+    StrToUpper; STR_TO_UPPER; 1,
+    StrToLower; STR_TO_LOWER; 1,
+    StrCaseInsensitiveEq; STR_CASE_INSENSITIVE_EQ; 2,
+    StrToNfc; STR_TO_NFC; 1,
+    StrToNfd; STR_TO_NFD; 1,
+    StrToUtf16; STR_TO_UTF16; 1,
+    StrFromUtf16Lossy; STR_FROM_UTF16_LOSSY; 1,
+    StrCompare; STR_COMPARE_UTF8; 2,
 
     ListLen; LIST_LEN; 1,
     ListWithCapacity; LIST_WITH_CAPACITY; 1,
@@ -212,6 +221,9 @@ map_symbol_to_lowlevel_and_arity! {
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
     NumToStr; NUM_TO_STR; 1,
+    NumToStrRadix; NUM_TO_STR_RADIX_PADDED; 3,
+    NumToStrDecimals; NUM_TO_STR_DECIMALS; 2,
+    NumToStrScientific; NUM_TO_STR_SCIENTIFIC; 2,
     NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
     NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
     NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
@@ -219,6 +231,7 @@ map_symbol_to_lowlevel_and_arity! {
 
     Eq; BOOL_STRUCTURAL_EQ; 2,
     NotEq; BOOL_STRUCTURAL_NOT_EQ; 2,
+    Compare; BOOL_STRUCTURAL_COMPARE; 2,
     And; BOOL_AND; 2,
     Or; BOOL_OR; 2,
     Not; BOOL_NOT; 1,
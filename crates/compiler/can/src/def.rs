@@ -1051,7 +1051,7 @@ fn canonicalize_value_defs<'a>(
                 // that get would have gotten added later in the defs list!
                 pending_value_defs.push(pending_def);
             }
-            PendingValue::SignatureDefMismatch => { /* skip */ }
+            PendingValue::SignatureDefMismatch | PendingValue::Malformed => { /* skip */ }
             PendingValue::Dbg(pending_dbg) => {
                 pending_dbgs.push(pending_dbg);
             }
@@ -2750,6 +2750,8 @@ enum PendingValue<'a> {
     Expect(PendingExpectOrDbg<'a>),
     ExpectFx(PendingExpectOrDbg<'a>),
     SignatureDefMismatch,
+    /// A def that didn't parse, and that doesn't define a name
+    Malformed,
 }
 
 struct PendingExpectOrDbg<'a> {
@@ -2873,6 +2875,39 @@ fn to_pending_value_def<'a>(
             condition,
             preceding_comment: *preceding_comment,
         }),
+
+        Malformed {
+            name: Some(name), ..
+        } => {
+            // The syntax error was already reported by the parser. Still define the name, so its
+            // uses don't become errors too; without a body, it's like an annotation that's
+            // entirely inferred.
+            let loc_pattern = &*env
+                .arena
+                .alloc(Loc::at(name.region, ast::Pattern::Identifier(name.value)));
+            let loc_ann = &*env
+                .arena
+                .alloc(Loc::at(name.region, ast::TypeAnnotation::Inferred));
+
+            let loc_can_pattern = canonicalize_def_header_pattern(
+                env,
+                var_store,
+                scope,
+                pending_abilities_in_scope,
+                output,
+                pattern_type,
+                &loc_pattern.value,
+                loc_pattern.region,
+            );
+
+            PendingValue::Def(PendingValueDef::AnnotationOnly(
+                loc_pattern,
+                loc_can_pattern,
+                loc_ann,
+            ))
+        }
+
+        Malformed { name: None, .. } => PendingValue::Malformed,
     }
 }
 
@@ -141,10 +141,12 @@ impl<'a> Env<'a> {
             }
         } else {
             match self.dep_idents.get(&module_id) {
-                Some(exposed_ids) => match exposed_ids.get_id(ident) {
-                    Some(ident_id) => {
-                        let symbol = Symbol::new(module_id, ident_id);
-
+                Some(exposed_ids) => match exposed_ids
+                    .get_id(ident)
+                    .map(|ident_id| Symbol::new(module_id, ident_id))
+                    .filter(|symbol| !symbol.is_unexposed())
+                {
+                    Some(symbol) => {
                         if is_type_name {
                             self.qualified_type_lookups.insert(symbol);
                         } else {
@@ -156,6 +158,9 @@ impl<'a> Env<'a> {
                     None => {
                         let exposed_values = exposed_ids
                             .ident_strs()
+                            .filter(|(ident_id, _)| {
+                                !Symbol::new(module_id, *ident_id).is_unexposed()
+                            })
                             .filter(|(_, ident)| ident.starts_with(|c: char| c.is_lowercase()))
                             .map(|(_, ident)| Lowercase::from(ident))
                             .collect();
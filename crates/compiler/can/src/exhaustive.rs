@@ -4,11 +4,13 @@ use roc_collections::all::HumanIndex;
 use roc_collections::VecMap;
 use roc_error_macros::internal_error;
 use roc_exhaustive::{
-    is_useful, Ctor, CtorName, Error, Guard, ListArity, Literal, Pattern, RenderAs, TagId, Union,
+    is_useful, Ctor, CtorName, Error, Guard, IntDomain, IntRange, ListArity, Literal, Pattern,
+    RenderAs, TagId, Union,
 };
 use roc_module::ident::{Lowercase, TagIdIntType, TagName};
 use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
+use roc_types::num::NumericRange;
 use roc_types::subs::{
     Content, FlatType, GetSubsSlice, RedundantMark, SortedTagsIterator, Subs, SubsFmtContent,
     Variable,
@@ -259,7 +261,7 @@ impl SketchedPattern {
     fn reify(self, subs: &Subs, real_var: Variable) -> Result<Pattern, TypeError> {
         match self {
             Self::Anything => Ok(Pattern::Anything),
-            Self::Literal(lit) => Ok(Pattern::Literal(lit)),
+            Self::Literal(lit) => reify_literal(subs, real_var, lit),
             Self::KnownCtor(union, tag_id, patterns) => {
                 let index_ctor = IndexCtor::of_union(&union, tag_id);
                 let arg_vars = index_var(subs, real_var, index_ctor, &union.render_as)?;
@@ -299,6 +301,68 @@ impl SketchedPattern {
     }
 }
 
+/// Integer literals of a type with a finite domain become singleton ranges, so that the domain
+/// can be exhausted without a wildcard.
+fn reify_literal(subs: &Subs, real_var: Variable, lit: Literal) -> Result<Pattern, TypeError> {
+    let value = match &lit {
+        Literal::Int(bytes) => i128::from_ne_bytes(*bytes),
+        Literal::U128(bytes) => match i128::try_from(u128::from_ne_bytes(*bytes)) {
+            Ok(value) => value,
+            // Only a U128 can hold this, and its domain is not modeled.
+            Err(_) => return Ok(Pattern::Literal(lit)),
+        },
+        _ => return Ok(Pattern::Literal(lit)),
+    };
+
+    match int_domain(subs, real_var) {
+        Some(domain) if domain.contains(value) => {
+            Ok(Pattern::IntRange(IntRange::singleton(value, domain)))
+        }
+        Some(_) => Err(TypeError),
+        None => Ok(Pattern::Literal(lit)),
+    }
+}
+
+/// The finite domain of an integer type, if we know it.
+///
+/// U128 and Nat are left unbounded: the former doesn't fit in an `i128`, and the width of the
+/// latter depends on the target.
+fn int_domain(subs: &Subs, mut var: Variable) -> Option<IntDomain> {
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, _, real_var, _) => {
+                let domain = match *symbol {
+                    Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => IntDomain::I8,
+                    Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => IntDomain::U8,
+                    Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => IntDomain::I16,
+                    Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => IntDomain::U16,
+                    Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => IntDomain::I32,
+                    Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => IntDomain::U32,
+                    Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => IntDomain::I64,
+                    Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => IntDomain::U64,
+                    Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => IntDomain::I128,
+                    Symbol::NUM_U128
+                    | Symbol::NUM_UNSIGNED128
+                    | Symbol::NUM_NAT
+                    | Symbol::NUM_NATURAL => return None,
+                    _ => {
+                        var = *real_var;
+                        continue;
+                    }
+                };
+
+                return Some(domain);
+            }
+            // A signed integer of unknown width is at most an I128, so covering every I128 covers
+            // it, whatever width it ends up with.
+            Content::RangedNumber(NumericRange::IntAtLeastSigned(_)) => {
+                return Some(IntDomain::I128)
+            }
+            _ => return None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct SketchedRow {
     patterns: Vec<SketchedPattern>,
@@ -337,7 +401,7 @@ fn sketch_pattern(pattern: &crate::pattern::Pattern) -> SketchedPattern {
         }
         &FloatLiteral(_, _, _, f, _) => SP::Literal(Literal::Float(f64::to_bits(f))),
         StrLiteral(v) => SP::Literal(Literal::Str(v.clone())),
-        &SingleQuote(_, _, c, _) => SP::Literal(Literal::Byte(c as u8)),
+        &SingleQuote(_, _, c, _) => SP::Literal(Literal::Int((c as i128).to_ne_bytes())),
         RecordDestructure { destructs, .. } => {
             let tag_id = TagId(0);
             let mut patterns = std::vec::Vec::with_capacity(destructs.len());
@@ -633,7 +697,7 @@ fn is_inhabited_pattern(pat: &Pattern) -> bool {
     while let Some(pat) = stack.pop() {
         match pat {
             Pattern::Anything => {}
-            Pattern::Literal(_) => {}
+            Pattern::Literal(_) | Pattern::IntRange(_) => {}
             Pattern::Ctor(union, id, pats) => {
                 if !union.alternatives.iter().any(|alt| alt.tag_id == *id) {
                     // The tag ID was dropped from the union, which means that this tag ID is one
@@ -19,6 +19,7 @@ use roc_parse::header::HeaderType;
 use roc_parse::pattern::PatternType;
 use roc_problem::can::{Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
+use roc_serialize::bytes::next_multiple_of;
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
 use roc_types::types::{AbilitySet, Alias, AliasKind, AliasVar, Type};
 
@@ -1253,6 +1254,10 @@ pub struct TypeState {
 }
 
 impl TypeState {
+    /// Each part of the type state is deserialized from its own slice of the bytes, which must
+    /// be aligned for anything stored in it.
+    const PART_ALIGNMENT: usize = 16;
+
     pub fn serialize(&self, writer: &mut impl std::io::Write) -> std::io::Result<usize> {
         let Self {
             subs,
@@ -1261,25 +1266,34 @@ impl TypeState {
             solved_implementations,
         } = self;
 
-        let written_subs = subs.serialize(exposed_vars_by_symbol, writer)?;
-        let written_ab = abilities.serialize(writer)?;
-        let written_solved_impls =
-            crate::abilities::serialize_solved_implementations(solved_implementations, writer)?;
+        let written = subs.serialize(exposed_vars_by_symbol, writer)?;
+        let written = Self::write_padding(writer, written)?;
+        let written = written + abilities.serialize(writer)?;
+        let written = Self::write_padding(writer, written)?;
+        let written = written
+            + crate::abilities::serialize_solved_implementations(solved_implementations, writer)?;
 
-        Ok(written_subs + written_ab + written_solved_impls)
+        Ok(written)
+    }
+
+    fn write_padding(writer: &mut impl std::io::Write, written: usize) -> std::io::Result<usize> {
+        let padding = next_multiple_of(written, Self::PART_ALIGNMENT) - written;
+        writer.write_all(&[0; Self::PART_ALIGNMENT][..padding])?;
+
+        Ok(written + padding)
     }
 
     pub fn deserialize(bytes: &[u8]) -> (Self, usize) {
         let ((subs, exposed_vars_by_symbol), len_subs) = Subs::deserialize(bytes);
-        let bytes = &bytes[len_subs..];
+        let offset = next_multiple_of(len_subs, Self::PART_ALIGNMENT);
 
-        let (abilities, len_abilities) = AbilitiesStore::deserialize(bytes);
-        let bytes = &bytes[len_abilities..];
+        let (abilities, len_abilities) = AbilitiesStore::deserialize(&bytes[offset..]);
+        let offset = next_multiple_of(offset + len_abilities, Self::PART_ALIGNMENT);
 
         let (solved_implementations, len_solved_impls) =
-            crate::abilities::deserialize_solved_implementations(bytes);
+            crate::abilities::deserialize_solved_implementations(&bytes[offset..]);
 
-        let total_offset = len_subs + len_abilities + len_solved_impls;
+        let total_offset = offset + len_solved_impls;
 
         (
             Self {
@@ -1291,4 +1305,17 @@ impl TypeState {
             total_offset,
         )
     }
+
+    /// Replace every symbol in the type state, e.g. after deserializing it in a different
+    /// compiler run where the `ModuleId`s of user modules may have changed.
+    pub fn map_symbols(&mut self, f: &mut impl FnMut(Symbol) -> Symbol) {
+        self.subs.map_symbols(f);
+
+        for (symbol, _) in self.exposed_vars_by_symbol.iter_mut() {
+            *symbol = f(*symbol);
+        }
+
+        self.abilities.map_symbols(f);
+        crate::abilities::map_solved_implementations_symbols(&mut self.solved_implementations, f);
+    }
 }
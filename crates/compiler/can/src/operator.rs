@@ -72,6 +72,7 @@ fn desugar_value_def<'a>(arena: &'a Bump, def: &'a ValueDef<'a>) -> ValueDef<'a>
     match def {
         Body(loc_pattern, loc_expr) => Body(loc_pattern, desugar_expr(arena, loc_expr)),
         ann @ Annotation(_, _) => *ann,
+        malformed @ Malformed { .. } => *malformed,
         AnnotatedBody {
             ann_pattern,
             ann_type,
@@ -127,6 +127,10 @@ flags! {
     /// Writes a pretty-printed mono IR to stderr after function specialization.
     ROC_PRINT_IR_AFTER_SPECIALIZATION
 
+    /// Writes a pretty-printed mono IR to stderr after the mono optimizations (inlining,
+    /// constant folding, etc.) have run. These only run when optimizing.
+    ROC_PRINT_IR_AFTER_OPTIMIZATION
+
     /// Writes a pretty-printed mono IR to stderr after insertion of reset/reuse
     /// instructions.
     ROC_PRINT_IR_AFTER_RESET_REUSE
@@ -160,9 +164,13 @@ flags! {
 
     // ===WASM Gen===
 
-    /// Writes a `final.wasm` file to /tmp
+    /// Writes a `final.wasm` file to /tmp, plus a `final.wat` text version for the Wasm backend
     ROC_WRITE_FINAL_WASM
 
+    /// Validates the Wasm module generated for each test_gen test, before running it.
+    /// On by default in debug builds; set it to 0 to skip validation.
+    ROC_VALIDATE_WASM
+
     /// Prints Wasm interpreter debug log in test_gen
     ROC_LOG_WASM_INTERP
 
@@ -173,4 +181,8 @@ flags! {
 
     /// Don't build and use the subs cache (speeds up compilation of load and previous crates)
     ROC_SKIP_SUBS_CACHE
+
+    /// Solve every module from scratch, instead of loading the types of unchanged modules from
+    /// the on-disk cache. Unlike the other flags, this also works in release builds.
+    ROC_SKIP_MODULE_CACHE
 }
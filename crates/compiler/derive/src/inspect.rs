@@ -0,0 +1,1009 @@
+//! Derivers for the `Inspect` ability.
+
+use std::iter::once;
+
+use roc_can::expr::{
+    AnnotatedMark, ClosureData, Expr, Field, Recursive, WhenBranch, WhenBranchPattern,
+};
+use roc_can::pattern::Pattern;
+use roc_collections::SendMap;
+use roc_derive_key::inspect::FlatInspectableKey;
+use roc_module::called_via::CalledVia;
+use roc_module::ident::Lowercase;
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+use roc_types::subs::{
+    Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+    RedundantMark, SubsSlice, TagExt, TupleElems, UnionLambdas, UnionTags, Variable,
+    VariableSubsSlice,
+};
+use roc_types::types::RecordField;
+
+use crate::util::Env;
+use crate::{synth_var, DerivedBody};
+
+pub(crate) fn derive_to_inspector(
+    env: &mut Env<'_>,
+    key: FlatInspectableKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body, body_type) = match key {
+        FlatInspectableKey::List() => to_inspector_elems(
+            env,
+            def_symbol,
+            Symbol::LIST_LIST,
+            Symbol::LIST_WALK,
+            Symbol::INSPECT_LIST,
+        ),
+        FlatInspectableKey::Set() => to_inspector_elems(
+            env,
+            def_symbol,
+            Symbol::SET_SET,
+            Symbol::SET_WALK,
+            Symbol::INSPECT_SET,
+        ),
+        FlatInspectableKey::Dict() => to_inspector_dict(env, def_symbol),
+        FlatInspectableKey::Record(fields) => {
+            // Generalized record var so we can reuse this impl between many records:
+            // if fields = { a, b }, this is { a: t1, b: t2 } for fresh t1, t2.
+            let flex_fields = fields
+                .into_iter()
+                .map(|name| {
+                    (
+                        name,
+                        RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                    )
+                })
+                .collect::<Vec<(Lowercase, _)>>();
+            let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+            let record_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+            );
+
+            to_inspector_record(env, record_var, fields, def_symbol)
+        }
+        FlatInspectableKey::Tuple(arity) => {
+            // Generalized tuple var so we can reuse this impl between many tuples:
+            // if arity = n, this is (t1, ..., tn) for fresh t1, ..., tn.
+            let flex_elems = (0..arity)
+                .map(|idx| (idx as usize, env.subs.fresh_unnamed_flex_var()))
+                .collect::<Vec<_>>();
+            let elems = TupleElems::insert_into_subs(env.subs, flex_elems);
+            let tuple_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Tuple(elems, Variable::EMPTY_TUPLE)),
+            );
+
+            to_inspector_tuple(env, tuple_var, elems, def_symbol)
+        }
+        FlatInspectableKey::TagUnion(tags) => {
+            // Generalized tag union var so we can reuse this impl between many unions:
+            // if tags = [ A arity=2, B arity=1 ], this is [ A t1 t2, B t3 ] for fresh t1, t2, t3
+            let flex_tag_labels = tags
+                .into_iter()
+                .map(|(label, arity)| {
+                    let variables_slice =
+                        VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                    for var_index in variables_slice {
+                        env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                    }
+                    (label, variables_slice)
+                })
+                .collect::<Vec<_>>();
+            let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+            let tag_union_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::TagUnion(
+                    union_tags,
+                    TagExt::Any(Variable::EMPTY_TAG_UNION),
+                )),
+            );
+
+            to_inspector_tag_union(env, tag_union_var, union_tags, def_symbol)
+        }
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::INSPECT_TO_INSPECTOR);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+/// Build `Inspect.toInspector val`, returning the call and the type of the inspector it makes.
+fn to_inspector_call(env: &mut Env<'_>, val: Expr, val_var: Variable) -> (Expr, Variable) {
+    // val -[uls]-> Inspector f where f implements InspectFormatter
+    let to_inspector_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_TO_INSPECTOR);
+
+    // (typeof val) -[clos]-> t1
+    let val_var_slice = VariableSubsSlice::insert_into_subs(env.subs, [val_var]);
+    let to_inspector_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let inspector_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_to_inspector_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            val_var_slice,
+            to_inspector_clos_var,
+            inspector_var,
+        )),
+    );
+
+    //   val          -[uls]->  Inspector f where f implements InspectFormatter
+    // ~ (typeof val) -[clos]-> t1
+    env.unify(to_inspector_fn_var, this_to_inspector_fn_var);
+
+    // toInspector : (typeof val) -[clos]-> Inspector f where f implements InspectFormatter
+    let to_inspector_var =
+        Expr::AbilityMember(Symbol::INSPECT_TO_INSPECTOR, None, this_to_inspector_fn_var);
+    let to_inspector_fn = Box::new((
+        this_to_inspector_fn_var,
+        Loc::at_zero(to_inspector_var),
+        to_inspector_clos_var,
+        inspector_var,
+    ));
+
+    // toInspector val
+    let to_inspector_call = Expr::Call(
+        to_inspector_fn,
+        vec![(val_var, Loc::at_zero(val))],
+        CalledVia::Space,
+    );
+
+    (to_inspector_call, inspector_var)
+}
+
+/// Build `\elem -> Inspect.toInspector elem` for elements of type `elem_var`, returning the
+/// closure and its function type.
+fn to_elem_inspector(env: &mut Env<'_>, elem_var: Variable, name_hint: &str) -> (Expr, Variable) {
+    let elem_sym = env.new_symbol("elem");
+
+    // toInspector elem
+    let (to_inspector_call, elem_inspector_var) =
+        to_inspector_call(env, Expr::Var(elem_sym, elem_var), elem_var);
+
+    // elem -[to_elem_inspector]-> toInspector elem
+    let to_elem_inspector_sym = env.new_symbol(name_hint);
+
+    // Create fn_var for ambient capture; we fix it up below.
+    let to_elem_inspector_fn_var = synth_var(env.subs, Content::Error);
+
+    // -[to_elem_inspector]->
+    let to_elem_inspector_labels =
+        UnionLambdas::insert_into_subs(env.subs, once((to_elem_inspector_sym, vec![])));
+    let to_elem_inspector_lset = synth_var(
+        env.subs,
+        Content::LambdaSet(LambdaSet {
+            solved: to_elem_inspector_labels,
+            recursion_var: OptVariable::NONE,
+            unspecialized: SubsSlice::default(),
+            ambient_function: to_elem_inspector_fn_var,
+        }),
+    );
+    // elem -[to_elem_inspector]-> toInspector elem
+    let elem_var_slice = VariableSubsSlice::insert_into_subs(env.subs, [elem_var]);
+    env.subs.set_content(
+        to_elem_inspector_fn_var,
+        Content::Structure(FlatType::Func(
+            elem_var_slice,
+            to_elem_inspector_lset,
+            elem_inspector_var,
+        )),
+    );
+
+    // \elem -> toInspector elem
+    let to_elem_inspector = Expr::Closure(ClosureData {
+        function_type: to_elem_inspector_fn_var,
+        closure_type: to_elem_inspector_lset,
+        return_type: elem_inspector_var,
+        name: to_elem_inspector_sym,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![(
+            elem_var,
+            AnnotatedMark::known_exhaustive(),
+            Loc::at_zero(Pattern::Identifier(elem_sym)),
+        )],
+        loc_body: Box::new(Loc::at_zero(to_inspector_call)),
+    });
+
+    (to_elem_inspector, to_elem_inspector_fn_var)
+}
+
+/// Build `\arg -[fn_name]-> body`, the top-level closure of a derived implementation.
+fn wrap_in_to_inspector_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    arg_sym: Symbol,
+    arg_var: Variable,
+    body: Expr,
+    inspector_var: Variable,
+) -> (Expr, Variable) {
+    // Create fn_var for ambient capture; we fix it up below.
+    let fn_var = synth_var(env.subs, Content::Error);
+
+    // -[fn_name]->
+    let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, vec![])));
+    let fn_clos_var = synth_var(
+        env.subs,
+        Content::LambdaSet(LambdaSet {
+            solved: fn_name_labels,
+            recursion_var: OptVariable::NONE,
+            unspecialized: SubsSlice::default(),
+            ambient_function: fn_var,
+        }),
+    );
+    // typeof arg -[fn_name]-> Inspector f
+    let arg_var_slice = SubsSlice::insert_into_subs(env.subs, once(arg_var));
+    env.subs.set_content(
+        fn_var,
+        Content::Structure(FlatType::Func(arg_var_slice, fn_clos_var, inspector_var)),
+    );
+
+    // \arg -[fn_name]-> body
+    let clos = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: inspector_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![(
+            arg_var,
+            AnnotatedMark::known_exhaustive(),
+            Loc::at_zero(Pattern::Identifier(arg_sym)),
+        )],
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (clos, fn_var)
+}
+
+fn to_inspector_elems(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    collection: Symbol,
+    walk: Symbol,
+    inspect_elems: Symbol,
+) -> (Expr, Variable) {
+    // Build \lst -> Inspect.list lst List.walk (\elem -> Inspect.toInspector elem)
+    // (or likewise for `Set`s, with `Inspect.set` and `Set.walk`)
+
+    use Expr::*;
+
+    let lst_sym = env.new_symbol("lst");
+
+    // List elem
+    let elem_var = env.subs.fresh_unnamed_flex_var();
+    let elem_var_slice = SubsSlice::insert_into_subs(env.subs, [elem_var]);
+    let list_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Apply(collection, elem_var_slice)),
+    );
+
+    // \elem -> toInspector elem
+    let (to_elem_inspector, to_elem_inspector_fn_var) =
+        to_elem_inspector(env, elem_var, "to_elem_inspector");
+
+    // List.walk : List elem, state, (state, elem -> state) -> state
+    let walk_fn_var = env.import_builtin_symbol_var(walk);
+
+    // build `Inspect.list lst List.walk (\elem -> Inspect.toInspector elem)` type
+    // list, ElemWalker state list elem, (elem -> Inspector f) -[uls]-> Inspector f where f implements InspectFormatter
+    let inspect_list_fn_var = env.import_builtin_symbol_var(inspect_elems);
+
+    // List elem, typeof List.walk, to_elem_inspector_fn_var -[clos]-> t1
+    let this_inspect_list_args_slice = VariableSubsSlice::insert_into_subs(
+        env.subs,
+        [list_var, walk_fn_var, to_elem_inspector_fn_var],
+    );
+    let this_inspect_list_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let this_list_inspector_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_inspect_list_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_inspect_list_args_slice,
+            this_inspect_list_clos_var,
+            this_list_inspector_var,
+        )),
+    );
+
+    //   list,      ElemWalker state list elem, (elem -> Inspector f)   -[uls]->  Inspector f where f implements InspectFormatter
+    // ~ List elem, typeof List.walk,           to_elem_inspector_fn_var -[clos]-> t1
+    env.unify(inspect_list_fn_var, this_inspect_list_fn_var);
+
+    // Inspect.list : List elem, typeof List.walk, to_elem_inspector_fn_var -[clos]-> Inspector f where f implements InspectFormatter
+    let inspect_list = AbilityMember(inspect_elems, None, this_inspect_list_fn_var);
+    let inspect_list_fn = Box::new((
+        this_inspect_list_fn_var,
+        Loc::at_zero(inspect_list),
+        this_inspect_list_clos_var,
+        this_list_inspector_var,
+    ));
+
+    // Inspect.list lst List.walk to_elem_inspector
+    let inspect_list_call = Call(
+        inspect_list_fn,
+        vec![
+            (list_var, Loc::at_zero(Var(lst_sym, list_var))),
+            (walk_fn_var, Loc::at_zero(Var(walk, walk_fn_var))),
+            (to_elem_inspector_fn_var, Loc::at_zero(to_elem_inspector)),
+        ],
+        CalledVia::Space,
+    );
+
+    // Inspect.custom \fmt -> Inspect.apply (Inspect.list ..) fmt
+    let (body, this_inspector_var) = wrap_in_inspect_custom(
+        env,
+        inspect_list_call,
+        this_list_inspector_var,
+        lst_sym,
+        list_var,
+    );
+
+    // \lst -> Inspect.list lst List.walk (\elem -> Inspect.toInspector elem)
+    wrap_in_to_inspector_closure(env, fn_name, lst_sym, list_var, body, this_inspector_var)
+}
+
+fn to_inspector_dict(env: &mut Env<'_>, fn_name: Symbol) -> (Expr, Variable) {
+    // Build
+    //
+    // \dict -> Inspect.dict dict Dict.walk
+    //      (\elem -> Inspect.toInspector elem)
+    //      (\elem -> Inspect.toInspector elem)
+
+    use Expr::*;
+
+    let dict_sym = env.new_symbol("dict");
+
+    // Dict k v
+    let key_var = env.subs.fresh_unnamed_flex_var();
+    let value_var = env.subs.fresh_unnamed_flex_var();
+    let dict_vars_slice = SubsSlice::insert_into_subs(env.subs, [key_var, value_var]);
+    let dict_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Apply(Symbol::DICT_DICT, dict_vars_slice)),
+    );
+
+    // \elem -> toInspector elem
+    let (key_to_inspector, key_to_inspector_fn_var) =
+        to_elem_inspector(env, key_var, "key_to_inspector");
+    let (value_to_inspector, value_to_inspector_fn_var) =
+        to_elem_inspector(env, value_var, "value_to_inspector");
+
+    // Dict.walk : Dict k v, state, (state, k, v -> state) -> state
+    let walk_fn_var = env.import_builtin_symbol_var(Symbol::DICT_WALK);
+
+    // build `Inspect.dict dict Dict.walk (\elem -> ..) (\elem -> ..)` type
+    // dict, KeyValWalker state dict key value, (key -> Inspector f), (value -> Inspector f) -[uls]-> Inspector f where f implements InspectFormatter
+    let inspect_dict_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_DICT);
+
+    // Dict k v, typeof Dict.walk, key_to_inspector_fn_var, value_to_inspector_fn_var -[clos]-> t1
+    let this_inspect_dict_args_slice = VariableSubsSlice::insert_into_subs(
+        env.subs,
+        [
+            dict_var,
+            walk_fn_var,
+            key_to_inspector_fn_var,
+            value_to_inspector_fn_var,
+        ],
+    );
+    let this_inspect_dict_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let this_dict_inspector_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_inspect_dict_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_inspect_dict_args_slice,
+            this_inspect_dict_clos_var,
+            this_dict_inspector_var,
+        )),
+    );
+
+    //   dict,     KeyValWalker state dict key value, (key -> Inspector f),   (value -> Inspector f)    -[uls]->  Inspector f where f implements InspectFormatter
+    // ~ Dict k v, typeof Dict.walk,                  key_to_inspector_fn_var, value_to_inspector_fn_var -[clos]-> t1
+    env.unify(inspect_dict_fn_var, this_inspect_dict_fn_var);
+
+    // Inspect.dict : Dict k v, typeof Dict.walk, .. -[clos]-> Inspector f where f implements InspectFormatter
+    let inspect_dict = AbilityMember(Symbol::INSPECT_DICT, None, this_inspect_dict_fn_var);
+    let inspect_dict_fn = Box::new((
+        this_inspect_dict_fn_var,
+        Loc::at_zero(inspect_dict),
+        this_inspect_dict_clos_var,
+        this_dict_inspector_var,
+    ));
+
+    // Inspect.dict dict Dict.walk key_to_inspector value_to_inspector
+    let inspect_dict_call = Call(
+        inspect_dict_fn,
+        vec![
+            (dict_var, Loc::at_zero(Var(dict_sym, dict_var))),
+            (
+                walk_fn_var,
+                Loc::at_zero(Var(Symbol::DICT_WALK, walk_fn_var)),
+            ),
+            (key_to_inspector_fn_var, Loc::at_zero(key_to_inspector)),
+            (value_to_inspector_fn_var, Loc::at_zero(value_to_inspector)),
+        ],
+        CalledVia::Space,
+    );
+
+    // Inspect.custom \fmt -> Inspect.apply (Inspect.dict ..) fmt
+    let (body, this_inspector_var) = wrap_in_inspect_custom(
+        env,
+        inspect_dict_call,
+        this_dict_inspector_var,
+        dict_sym,
+        dict_var,
+    );
+
+    // \dict -> Inspect.dict dict Dict.walk (\elem -> ..) (\elem -> ..)
+    wrap_in_to_inspector_closure(env, fn_name, dict_sym, dict_var, body, this_inspector_var)
+}
+
+fn to_inspector_record(
+    env: &mut Env<'_>,
+    record_var: Variable,
+    fields: RecordFields,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose rcd = { a: t1, b: t2 }. Build
+    //
+    // \rcd -> Inspect.record [
+    //      { key: "a", value: Inspect.toInspector rcd.a },
+    //      { key: "b", value: Inspect.toInspector rcd.b },
+    //   ]
+
+    let rcd_sym = env.new_symbol("rcd");
+    let whole_rcd_var = env.subs.fresh_unnamed_flex_var(); // type of the { key, value } records in the list
+
+    use Expr::*;
+
+    let fields_list = fields
+        .iter_all()
+        .map(|(field_name_index, field_var_index, _)| {
+            let field_name = env.subs[field_name_index].clone();
+            let field_var = env.subs[field_var_index];
+
+            // key: "a"
+            let key_field = Field {
+                var: Variable::STR,
+                region: Region::zero(),
+                loc_expr: Box::new(Loc::at_zero(Str(field_name.as_str().into()))),
+            };
+
+            // rcd.a
+            let field_access = RecordAccess {
+                record_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                field_var,
+                loc_expr: Box::new(Loc::at_zero(Var(
+                    rcd_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                field: field_name,
+            };
+
+            // toInspector rcd.a
+            let (to_inspector_call, inspector_var) =
+                to_inspector_call(env, field_access, field_var);
+
+            // value: toInspector rcd.a
+            let value_field = Field {
+                var: inspector_var,
+                region: Region::zero(),
+                loc_expr: Box::new(Loc::at_zero(to_inspector_call)),
+            };
+
+            // { key: "a", value: toInspector rcd.a }
+            let mut kv = SendMap::default();
+            kv.insert("key".into(), key_field);
+            kv.insert("value".into(), value_field);
+
+            let this_record_fields = RecordFields::insert_into_subs(
+                env.subs,
+                (once(("key".into(), RecordField::Required(Variable::STR))))
+                    .chain(once(("value".into(), RecordField::Required(inspector_var)))),
+            );
+            let this_record_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Record(this_record_fields, Variable::EMPTY_RECORD)),
+            );
+            // NOTE: must be done to unify the lambda sets under `inspector_var`
+            env.unify(this_record_var, whole_rcd_var);
+
+            Loc::at_zero(Record {
+                record_var: whole_rcd_var,
+                fields: kv,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // typeof [ { key: .., value: .. }, { key: .., value: .. } ]
+    let fields_rcd_var_slice = VariableSubsSlice::insert_into_subs(env.subs, once(whole_rcd_var));
+    let fields_list_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Apply(Symbol::LIST_LIST, fields_rcd_var_slice)),
+    );
+
+    // [ { key: .., value: ..}, .. ]
+    let fields_list = List {
+        elem_var: whole_rcd_var,
+        loc_elems: fields_list,
+    };
+
+    // build `Inspect.record [ { key: .., value: ..}, .. ]` type
+    // List { key : Str, value : Inspector f } -[uls]-> Inspector f where f implements InspectFormatter
+    let inspect_record_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_RECORD);
+
+    // fields_list_var -[clos]-> t1
+    let fields_list_var_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, once(fields_list_var));
+    let inspect_record_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let inspector_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_inspect_record_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            fields_list_var_slice,
+            inspect_record_clos_var,
+            inspector_var,
+        )),
+    );
+
+    //   List { key : Str, value : Inspector f } -[uls]->  Inspector f where f implements InspectFormatter
+    // ~ fields_list_var                         -[clos]-> t1
+    env.unify(inspect_record_fn_var, this_inspect_record_fn_var);
+
+    // Inspect.record : fields_list_var -[clos]-> Inspector f where f implements InspectFormatter
+    let inspect_record_var = AbilityMember(Symbol::INSPECT_RECORD, None, inspect_record_fn_var);
+    let inspect_record_fn = Box::new((
+        inspect_record_fn_var,
+        Loc::at_zero(inspect_record_var),
+        inspect_record_clos_var,
+        inspector_var,
+    ));
+
+    // Inspect.record [ { key: .., value: .. }, .. ]
+    let inspect_record_call = Call(
+        inspect_record_fn,
+        vec![(fields_list_var, Loc::at_zero(fields_list))],
+        CalledVia::Space,
+    );
+
+    // Inspect.custom \fmt -> Inspect.apply (Inspect.record ..) fmt
+    let (body, this_inspector_var) =
+        wrap_in_inspect_custom(env, inspect_record_call, inspector_var, rcd_sym, record_var);
+
+    // \rcd -[fn_name]-> Inspect.record [ { key: .., value: .. }, .. ]
+    wrap_in_to_inspector_closure(env, fn_name, rcd_sym, record_var, body, this_inspector_var)
+}
+
+fn to_inspector_tuple(
+    env: &mut Env<'_>,
+    tuple_var: Variable,
+    elems: TupleElems,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose tup = (t1, t2). Build
+    //
+    // \tup -> Inspect.tuple [
+    //      Inspect.toInspector tup.0,
+    //      Inspect.toInspector tup.1,
+    //   ]
+
+    let tup_sym = env.new_symbol("tup");
+    let whole_inspector_in_list_var = env.subs.fresh_unnamed_flex_var(); // type of the inspector in the list
+
+    use Expr::*;
+
+    let elem_inspectors_list = elems
+        .iter_all()
+        .map(|(elem_index, elem_var_index)| {
+            let index = env.subs[elem_index];
+            let elem_var = env.subs[elem_var_index];
+
+            // tup.0
+            let tuple_access = TupleAccess {
+                tuple_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                elem_var,
+                loc_expr: Box::new(Loc::at_zero(Var(
+                    tup_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                index,
+            };
+
+            // toInspector tup.0
+            let (to_inspector_call, inspector_var) = to_inspector_call(env, tuple_access, elem_var);
+
+            // NOTE: must be done to unify the lambda sets under `inspector_var`
+            env.unify(inspector_var, whole_inspector_in_list_var);
+
+            Loc::at_zero(to_inspector_call)
+        })
+        .collect::<Vec<_>>();
+
+    // typeof [ toInspector tup.0, toInspector tup.1 ]
+    let whole_inspector_in_list_var_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, once(whole_inspector_in_list_var));
+    let elem_inspectors_list_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Apply(
+            Symbol::LIST_LIST,
+            whole_inspector_in_list_var_slice,
+        )),
+    );
+
+    // [ toInspector tup.0, toInspector tup.1 ]
+    let elem_inspectors_list = List {
+        elem_var: whole_inspector_in_list_var,
+        loc_elems: elem_inspectors_list,
+    };
+
+    // build `Inspect.tuple [ toInspector tup.0, toInspector tup.1 ]` type
+    // List (Inspector f) -[uls]-> Inspector f where f implements InspectFormatter
+    let inspect_tuple_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_TUPLE);
+
+    // elem_inspectors_list_var -[clos]-> t1
+    let elem_inspectors_list_var_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, once(elem_inspectors_list_var));
+    let inspect_tuple_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let inspector_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_inspect_tuple_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            elem_inspectors_list_var_slice,
+            inspect_tuple_clos_var,
+            inspector_var,
+        )),
+    );
+
+    //   List (Inspector f)       -[uls]->  Inspector f where f implements InspectFormatter
+    // ~ elem_inspectors_list_var -[clos]-> t1
+    env.unify(inspect_tuple_fn_var, this_inspect_tuple_fn_var);
+
+    // Inspect.tuple : elem_inspectors_list_var -[clos]-> Inspector f where f implements InspectFormatter
+    let inspect_tuple_var = AbilityMember(Symbol::INSPECT_TUPLE, None, inspect_tuple_fn_var);
+    let inspect_tuple_fn = Box::new((
+        inspect_tuple_fn_var,
+        Loc::at_zero(inspect_tuple_var),
+        inspect_tuple_clos_var,
+        inspector_var,
+    ));
+
+    // Inspect.tuple [ toInspector tup.0, toInspector tup.1 ]
+    let inspect_tuple_call = Call(
+        inspect_tuple_fn,
+        vec![(elem_inspectors_list_var, Loc::at_zero(elem_inspectors_list))],
+        CalledVia::Space,
+    );
+
+    // Inspect.custom \fmt -> Inspect.apply (Inspect.tuple ..) fmt
+    let (body, this_inspector_var) =
+        wrap_in_inspect_custom(env, inspect_tuple_call, inspector_var, tup_sym, tuple_var);
+
+    // \tup -[fn_name]-> Inspect.tuple [ toInspector tup.0, toInspector tup.1 ]
+    wrap_in_to_inspector_closure(env, fn_name, tup_sym, tuple_var, body, this_inspector_var)
+}
+
+fn to_inspector_tag_union(
+    env: &mut Env<'_>,
+    tag_union_var: Variable,
+    tags: UnionTags,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose tag = [ A t1 t2, B t3 ]. Build
+    //
+    // \tag -> when tag is
+    //     A v1 v2 -> Inspect.tag "A" [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+    //     B v3 -> Inspect.tag "B" [ Inspect.toInspector v3 ]
+
+    let tag_sym = env.new_symbol("tag");
+    let whole_tag_inspectors_var = env.subs.fresh_unnamed_flex_var(); // type of the Inspect.tag ... calls in the branch bodies
+
+    use Expr::*;
+
+    let branches = tags
+        .iter_all()
+        .map(|(tag_name_index, tag_vars_slice_index)| {
+            // A
+            let tag_name = &env.subs[tag_name_index].clone();
+            let vars_slice = env.subs[tag_vars_slice_index];
+            // t1 t2
+            let payload_vars = env.subs.get_subs_slice(vars_slice).to_vec();
+            // v1 v2
+            let payload_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            // `A v1 v2` pattern
+            let pattern = Pattern::AppliedTag {
+                whole_var: tag_union_var,
+                tag_name: tag_name.clone(),
+                ext_var: Variable::EMPTY_TAG_UNION,
+                // (t1, v1) (t2, v2)
+                arguments: (payload_vars.iter())
+                    .zip(payload_syms.iter())
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect(),
+            };
+            let branch_pattern = WhenBranchPattern {
+                pattern: Loc::at_zero(pattern),
+                degenerate: false,
+            };
+
+            // whole type of the elements in [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+            let whole_payload_inspectors_var = env.subs.fresh_unnamed_flex_var();
+            // [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+            let payload_to_inspectors = (payload_syms.iter())
+                .zip(payload_vars.iter())
+                .map(|(&sym, &sym_var)| {
+                    // toInspector v1
+                    let (to_inspector_call, inspector_var) =
+                        to_inspector_call(env, Var(sym, sym_var), sym_var);
+
+                    // NOTE: must be done to unify the lambda sets under `inspector_var`
+                    env.unify(inspector_var, whole_payload_inspectors_var);
+
+                    Loc::at_zero(to_inspector_call)
+                })
+                .collect();
+
+            // typeof [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+            let whole_inspectors_var_slice =
+                VariableSubsSlice::insert_into_subs(env.subs, [whole_payload_inspectors_var]);
+            let payload_inspectors_list_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Apply(
+                    Symbol::LIST_LIST,
+                    whole_inspectors_var_slice,
+                )),
+            );
+
+            // [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+            let payload_inspectors_list = List {
+                elem_var: whole_payload_inspectors_var,
+                loc_elems: payload_to_inspectors,
+            };
+
+            // build `Inspect.tag "A" [ ... ]` type
+            // expected: Str, List (Inspector f) -[uls]-> Inspector f where f implements InspectFormatter
+            let inspect_tag_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_TAG);
+
+            // wanted: Str, List whole_inspectors_var -[clos]-> t'
+            let this_inspect_tag_args_var_slice = VariableSubsSlice::insert_into_subs(
+                env.subs,
+                [Variable::STR, payload_inspectors_list_var],
+            );
+            let this_inspect_tag_clos_var = env.subs.fresh_unnamed_flex_var(); // -[clos]->
+            let this_inspector_var = env.subs.fresh_unnamed_flex_var(); // t'
+            let this_inspect_tag_fn_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Func(
+                    this_inspect_tag_args_var_slice,
+                    this_inspect_tag_clos_var,
+                    this_inspector_var,
+                )),
+            );
+
+            //   Str, List (Inspector f)        -[uls]->  Inspector f where f implements InspectFormatter
+            // ~ Str, List whole_inspectors_var -[clos]-> t'
+            env.unify(inspect_tag_fn_var, this_inspect_tag_fn_var);
+
+            // Inspect.tag : Str, List whole_inspectors_var -[clos]-> Inspector f where f implements InspectFormatter
+            let inspect_tag_var = AbilityMember(Symbol::INSPECT_TAG, None, this_inspect_tag_fn_var);
+            let inspect_tag_fn = Box::new((
+                this_inspect_tag_fn_var,
+                Loc::at_zero(inspect_tag_var),
+                this_inspect_tag_clos_var,
+                this_inspector_var,
+            ));
+
+            // Inspect.tag "A" [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+            let inspect_tag_call = Call(
+                inspect_tag_fn,
+                vec![
+                    // (Str, "A")
+                    (Variable::STR, Loc::at_zero(Str(tag_name.0.as_str().into()))),
+                    // (List (Inspector f), [ Inspect.toInspector v1, Inspect.toInspector v2 ])
+                    (
+                        payload_inspectors_list_var,
+                        Loc::at_zero(payload_inspectors_list),
+                    ),
+                ],
+                CalledVia::Space,
+            );
+
+            // NOTE: must be done to unify the lambda sets under `inspector_var`
+            // Inspect.tag "A" [ Inspect.toInspector v1, Inspect.toInspector v2 ] ~ whole_inspectors
+            env.unify(this_inspector_var, whole_tag_inspectors_var);
+
+            WhenBranch {
+                patterns: vec![branch_pattern],
+                value: Loc::at_zero(inspect_tag_call),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // when tag is
+    //     A v1 v2 -> Inspect.tag "A" [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+    //     B v3 -> Inspect.tag "B" [ Inspect.toInspector v3 ]
+    let when_branches = When {
+        loc_cond: Box::new(Loc::at_zero(Var(tag_sym, tag_union_var))),
+        cond_var: tag_union_var,
+        expr_var: whole_tag_inspectors_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: tag_union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    // Inspect.custom \fmt -> Inspect.apply (when ..) fmt
+    let (body, this_inspector_var) = wrap_in_inspect_custom(
+        env,
+        when_branches,
+        whole_tag_inspectors_var,
+        tag_sym,
+        tag_union_var,
+    );
+
+    // \tag ->
+    //   Inspect.custom \fmt -> Inspect.apply (
+    //     when tag is
+    //        A v1 v2 -> Inspect.tag "A" [ Inspect.toInspector v1, Inspect.toInspector v2 ]
+    //        B v3 -> Inspect.tag "B" [ Inspect.toInspector v3 ])
+    //     fmt
+    wrap_in_to_inspector_closure(
+        env,
+        fn_name,
+        tag_sym,
+        tag_union_var,
+        body,
+        this_inspector_var,
+    )
+}
+
+/// Lift `inspector` to `Inspect.custom \fmt -> Inspect.apply inspector fmt`
+///
+/// This mirrors how derived encoders are lifted with `Encode.custom`; see
+/// `wrap_in_encode_custom` for why the lift is needed.
+fn wrap_in_inspect_custom(
+    env: &mut Env,
+    inspector: Expr,
+    inspector_var: Variable,
+    captured_symbol: Symbol,
+    captured_var: Variable,
+) -> (Expr, Variable) {
+    use Expr::*;
+
+    let fn_name = env.new_symbol("custom");
+
+    // fmt: f where f implements InspectFormatter
+    let fmt_sym = env.new_symbol("fmt");
+    let fmt_var = env.subs.fresh_unnamed_flex_var();
+
+    // build `Inspect.apply inspector fmt` type
+    // expected: Inspect.apply : Inspector f, f -[apply]-> f where f implements InspectFormatter
+    let apply_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_APPLY);
+
+    // wanted: Inspect.apply : inspector_var, f -[clos]-> f where f implements InspectFormatter
+    let this_apply_args_var_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, [inspector_var, fmt_var]);
+    let this_apply_clos_var = env.subs.fresh_unnamed_flex_var(); // -[clos]->
+    let this_apply_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_apply_args_var_slice,
+            this_apply_clos_var,
+            fmt_var,
+        )),
+    );
+
+    //   Inspector f,   f -[apply]-> f where f implements InspectFormatter
+    // ~ inspector_var, f -[clos]->  f where f implements InspectFormatter
+    env.unify(apply_fn_var, this_apply_fn_var);
+
+    // Inspect.apply : inspector_var, f -[apply]-> f where f implements InspectFormatter
+    let apply_fn = Box::new((
+        this_apply_fn_var,
+        Loc::at_zero(Var(Symbol::INSPECT_APPLY, this_apply_fn_var)),
+        this_apply_clos_var,
+        fmt_var,
+    ));
+
+    // Inspect.apply inspector fmt
+    let apply_call = Call(
+        apply_fn,
+        vec![
+            // (inspector_var, inspector)
+            (inspector_var, Loc::at_zero(inspector)),
+            // (fmt, fmt_var)
+            (fmt_var, Loc::at_zero(Var(fmt_sym, fmt_var))),
+        ],
+        CalledVia::Space,
+    );
+
+    // Create fn_var for ambient capture; we fix it up below.
+    let fn_var = synth_var(env.subs, Content::Error);
+
+    // -[[FN_name captured_var]]->
+    let fn_name_labels =
+        UnionLambdas::insert_into_subs(env.subs, once((fn_name, vec![captured_var])));
+    let fn_clos_var = synth_var(
+        env.subs,
+        Content::LambdaSet(LambdaSet {
+            solved: fn_name_labels,
+            recursion_var: OptVariable::NONE,
+            unspecialized: SubsSlice::default(),
+            ambient_function: fn_var,
+        }),
+    );
+
+    // f -[[FN_name captured_var]]-> Inspect.apply inspector fmt
+    let args_slice = SubsSlice::insert_into_subs(env.subs, vec![fmt_var]);
+    env.subs.set_content(
+        fn_var,
+        Content::Structure(FlatType::Func(args_slice, fn_clos_var, fmt_var)),
+    );
+
+    // \fmt -[[fn_name captured_var]]-> Inspect.apply inspector fmt
+    let clos = Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: fmt_var,
+        name: fn_name,
+        captured_symbols: vec![(captured_symbol, captured_var)],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![(
+            fmt_var,
+            AnnotatedMark::known_exhaustive(),
+            Loc::at_zero(Pattern::Identifier(fmt_sym)),
+        )],
+        loc_body: Box::new(Loc::at_zero(apply_call)),
+    });
+
+    // Build
+    // Inspect.custom \fmt -> Inspect.apply inspector fmt
+    //
+    // expected: Inspect.custom : (f -> f) -> Inspector f where f implements InspectFormatter
+    let custom_fn_var = env.import_builtin_symbol_var(Symbol::INSPECT_CUSTOM);
+
+    // wanted: Inspect.custom : fn_var -[clos]-> t'
+    let this_custom_args_var_slice = VariableSubsSlice::insert_into_subs(env.subs, [fn_var]);
+    let this_custom_clos_var = env.subs.fresh_unnamed_flex_var(); // -[clos]->
+    let this_custom_inspector_var = env.subs.fresh_unnamed_flex_var(); // t'
+    let this_custom_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_custom_args_var_slice,
+            this_custom_clos_var,
+            this_custom_inspector_var,
+        )),
+    );
+
+    //   (f -> f) -[..]->   Inspector f where f implements InspectFormatter
+    // ~ fn_var   -[clos]-> t'
+    env.unify(custom_fn_var, this_custom_fn_var);
+
+    // Inspect.custom : (f -> f) -> Inspector f where f implements InspectFormatter
+    let custom_fn = Box::new((
+        this_custom_fn_var,
+        Loc::at_zero(Var(Symbol::INSPECT_CUSTOM, this_custom_fn_var)),
+        this_custom_clos_var,      // -[clos]->
+        this_custom_inspector_var, // t' ~ Inspector f
+    ));
+
+    // Inspect.custom \fmt -> Inspect.apply inspector fmt
+    let custom_call = Call(
+        custom_fn,
+        vec![(fn_var, Loc::at_zero(clos))],
+        CalledVia::Space,
+    );
+
+    (custom_call, this_custom_inspector_var)
+}
@@ -19,6 +19,7 @@ use util::Env;
 mod decoding;
 mod encoding;
 mod hash;
+mod inspect;
 
 mod util;
 
@@ -79,6 +80,9 @@ fn build_derived_body(
             decoding::derive_decoder(&mut env, decoder_key, derived_symbol)
         }
         DeriveKey::Hash(hash_key) => hash::derive_hash(&mut env, hash_key, derived_symbol),
+        DeriveKey::ToInspector(to_inspector_key) => {
+            inspect::derive_to_inspector(&mut env, to_inspector_key, derived_symbol)
+        }
     };
 
     let def = Def {
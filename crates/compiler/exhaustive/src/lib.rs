@@ -73,6 +73,110 @@ pub enum Pattern {
     Literal(Literal),
     Ctor(Union, TagId, std::vec::Vec<Pattern>),
     List(ListArity, std::vec::Vec<Pattern>),
+    /// A range of values of an integer type with a finite domain.
+    /// Integer literals of those types are singleton ranges.
+    IntRange(IntRange),
+}
+
+/// The values of a fixed-width integer type, as inclusive bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntDomain {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl IntDomain {
+    pub const I8: Self = Self::new(i8::MIN as i128, i8::MAX as i128);
+    pub const U8: Self = Self::new(u8::MIN as i128, u8::MAX as i128);
+    pub const I16: Self = Self::new(i16::MIN as i128, i16::MAX as i128);
+    pub const U16: Self = Self::new(u16::MIN as i128, u16::MAX as i128);
+    pub const I32: Self = Self::new(i32::MIN as i128, i32::MAX as i128);
+    pub const U32: Self = Self::new(u32::MIN as i128, u32::MAX as i128);
+    pub const I64: Self = Self::new(i64::MIN as i128, i64::MAX as i128);
+    pub const U64: Self = Self::new(u64::MIN as i128, u64::MAX as i128);
+    pub const I128: Self = Self::new(i128::MIN, i128::MAX);
+
+    const fn new(min: i128, max: i128) -> Self {
+        Self { min, max }
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        self.min <= value && value <= self.max
+    }
+}
+
+/// An inclusive range of values of an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntRange {
+    pub start: i128,
+    pub end: i128,
+    pub domain: IntDomain,
+}
+
+impl IntRange {
+    pub fn singleton(value: i128, domain: IntDomain) -> Self {
+        debug_assert!(domain.contains(value));
+
+        Self {
+            start: value,
+            end: value,
+            domain,
+        }
+    }
+
+    fn full(domain: IntDomain) -> Self {
+        Self {
+            start: domain.min,
+            end: domain.max,
+            domain,
+        }
+    }
+
+    pub fn is_singleton(&self) -> bool {
+        self.start == self.end
+    }
+
+    fn covers(&self, other: &IntRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Splits this range into disjoint segments, such that every segment is either contained in,
+    /// or disjoint from, each of the `others`.
+    ///
+    /// For example, splitting `0..=255` by `3` and `5..=9` gives
+    ///   0..=2, 3, 4, 5..=9, 10..=255
+    fn split<'a>(self, others: impl IntoIterator<Item = &'a IntRange>) -> Vec<IntRange> {
+        // The values at which a new segment starts.
+        let mut boundaries: Vec<i128> = others
+            .into_iter()
+            .flat_map(|other| [Some(other.start), other.end.checked_add(1)])
+            .flatten()
+            .filter(|&boundary| self.start < boundary && boundary <= self.end)
+            .collect();
+
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut segments = Vec::with_capacity(boundaries.len() + 1);
+        let mut start = self.start;
+
+        for boundary in boundaries {
+            segments.push(IntRange {
+                start,
+                end: boundary - 1,
+                domain: self.domain,
+            });
+            start = boundary;
+        }
+
+        segments.push(IntRange {
+            start,
+            end: self.end,
+            domain: self.domain,
+        });
+
+        segments
+    }
 }
 
 /// The arity of list pattern.
@@ -302,6 +406,54 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
 
             alt_lists.into_iter().flat_map(is_alt_exhaustive).collect()
         }
+        CollectedCtors::IntRanges(domain, ranges) => {
+            let (covered, missing): (Vec<_>, Vec<_>) = IntRange::full(domain)
+                .split(&ranges)
+                .into_iter()
+                .partition(|segment| ranges.iter().any(|range| range.covers(segment)));
+
+            if !missing.is_empty() {
+                let new_matrix: Vec<_> = matrix
+                    .iter()
+                    .filter_map(|row| specialize_row_by_anything(row))
+                    .collect();
+                let rest: Vec<Vec<Pattern>> = is_exhaustive(&new_matrix, n - 1);
+
+                // Segments are split at the edges of the ranges we've seen, so no two missing
+                // segments are adjacent, and each one is as large as it can be.
+                let mut result = Vec::new();
+
+                for segment in missing {
+                    for mut row in rest.clone() {
+                        row.push(Pattern::IntRange(segment));
+
+                        result.push(row);
+                    }
+                }
+
+                result
+            } else {
+                let is_segment_exhaustive = |segment: IntRange| {
+                    let new_matrix: Vec<_> = matrix
+                        .iter()
+                        .filter_map(|row| specialize_row_by_int_range(segment, row.to_owned()))
+                        .collect();
+
+                    let mut rest = is_exhaustive(&new_matrix, n - 1);
+
+                    for row in rest.iter_mut() {
+                        row.push(Pattern::IntRange(segment));
+                    }
+
+                    rest
+                };
+
+                covered
+                    .into_iter()
+                    .flat_map(is_segment_exhaustive)
+                    .collect()
+            }
+        }
     }
 }
 
@@ -457,6 +609,53 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
 
                                 break false;
                             }
+                            Complete::YesRanges(segments) => {
+                                // Every integer is covered, but maybe not together with
+                                // everything that follows it in the row.
+                                for segment in segments {
+                                    let matrix = old_matrix
+                                        .iter()
+                                        .filter_map(|row| {
+                                            specialize_row_by_int_range(segment, row.clone())
+                                        })
+                                        .collect();
+
+                                    if is_useful(matrix, vector.clone()) {
+                                        break 'outer true;
+                                    }
+                                }
+
+                                break false;
+                            }
+                        }
+                    }
+
+                    Pattern::IntRange(range) => {
+                        // Split the range at the edges of the ranges in the matrix, so that
+                        // each part is either fully matched or not matched at all by every row.
+                        let segments = range.split(filter_matrix_int_ranges(&old_matrix));
+
+                        if let [segment] = segments[..] {
+                            specialize_matrix_by_int_range(segment, &mut old_matrix, &mut matrix);
+
+                            std::mem::swap(&mut old_matrix, &mut matrix);
+                        } else {
+                            for segment in segments {
+                                let mut old_matrix = old_matrix.clone();
+                                let mut spec_matrix = Vec::with_capacity(old_matrix.len());
+
+                                specialize_matrix_by_int_range(
+                                    segment,
+                                    &mut old_matrix,
+                                    &mut spec_matrix,
+                                );
+
+                                if is_useful(spec_matrix, vector.clone()) {
+                                    return true;
+                                }
+                            }
+
+                            return false;
                         }
                     }
 
@@ -477,6 +676,10 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                                 }
                                 Some(Anything) => matrix.push(patterns),
 
+                                // Only a malformed pattern is a literal in a column of
+                                // integer ranges, and it matches none of them.
+                                Some(Pattern::IntRange(_)) => {}
+
                                 Some(List(..)) => internal_error!("After type checking, lists and literals should never align in exhaustiveness checking"),
 
                                 Some(Ctor(_, _, _)) => panic!(
@@ -595,6 +798,7 @@ fn specialize_row_by_list(spec_arity: ListArity, mut row: Row) -> Option<Row> {
         }
         Some(Ctor(..)) => internal_error!("After type checking, lists and constructors should never align in exhaustiveness checking"),
         Some(Literal(..)) => internal_error!("After type checking, lists and literals should never align in exhaustiveness checking"),
+        Some(Pattern::IntRange(..)) => internal_error!("After type checking, lists and integer ranges should never align in exhaustiveness checking"),
         None => internal_error!("Empty matrices should not get specialized"),
     }
 }
@@ -637,6 +841,52 @@ fn specialize_row_by_ctor(tag_id: TagId, arity: usize, mut row: Row) -> Option<R
         Some(Literal(_)) => internal_error!(
             r#"After type checking, a constructor can never align with a literal: that should be a type error!"#
         ),
+        Some(Pattern::IntRange(_)) => internal_error!(
+            r#"After type checking, a constructor can never align with an integer range: that should be a type error!"#
+        ),
+        None => internal_error!("Empty matrices should not get specialized."),
+    }
+}
+
+fn specialize_matrix_by_int_range(
+    segment: IntRange,
+    old_matrix: &mut PatternMatrix,
+    matrix: &mut PatternMatrix,
+) {
+    for row in old_matrix.drain(..) {
+        if let Some(spec_row) = specialize_row_by_int_range(segment, row) {
+            matrix.push(spec_row);
+        }
+    }
+}
+
+/// INVARIANT: (length row == N) ==> (length result == N-1)
+///
+/// The segment must be either contained in, or disjoint from, the range at the head of the row;
+/// see [IntRange::split].
+fn specialize_row_by_int_range(segment: IntRange, mut row: Row) -> Option<Row> {
+    let head = row.pop();
+    let spec_patterns = row;
+
+    match head {
+        Some(Pattern::IntRange(range)) => {
+            if range.covers(&segment) {
+                Some(spec_patterns)
+            } else {
+                debug_assert!(segment.end < range.start || range.end < segment.start);
+                None
+            }
+        }
+        Some(Anything) => Some(spec_patterns),
+        // Only a malformed pattern is a literal in a column of integer ranges, and it matches
+        // none of them.
+        Some(Literal(_)) => None,
+        Some(Ctor(..)) => internal_error!(
+            r#"After type checking, a constructor can never align with an integer range: that should be a type error!"#
+        ),
+        Some(List(..)) => internal_error!(
+            "After type checking, lists and integer ranges should never align in exhaustiveness checking"
+        ),
         None => internal_error!("Empty matrices should not get specialized."),
     }
 }
@@ -655,6 +905,8 @@ fn specialize_row_by_anything(row: &RefRow) -> Option<Row> {
 
 pub enum Complete {
     Yes(Vec<Ctor>),
+    /// Every value of an integer domain is covered, by the given disjoint segments.
+    YesRanges(Vec<IntRange>),
     No,
 }
 
@@ -677,6 +929,18 @@ fn is_complete(matrix: &RefPatternMatrix) -> Complete {
                 }
             }
         }
+        CollectedCtors::IntRanges(domain, ranges) => {
+            let segments = IntRange::full(domain).split(&ranges);
+
+            if segments
+                .iter()
+                .all(|segment| ranges.iter().any(|range| range.covers(segment)))
+            {
+                Complete::YesRanges(segments)
+            } else {
+                Complete::No
+            }
+        }
     }
 }
 
@@ -691,6 +955,7 @@ enum CollectedCtors {
     NonExhaustiveAny,
     NonExhaustiveList(Vec<ListArity>),
     Ctors(MutMap<TagId, Union>),
+    IntRanges(IntDomain, Vec<IntRange>),
 }
 
 fn collect_ctors(matrix: &RefPatternMatrix) -> CollectedCtors {
@@ -723,6 +988,11 @@ fn collect_ctors(matrix: &RefPatternMatrix) -> CollectedCtors {
 
                 CollectedCtors::Ctors(ctors)
             }
+            Pattern::IntRange(range) => {
+                let ranges = filter_matrix_int_ranges(matrix).copied().collect();
+
+                CollectedCtors::IntRanges(range.domain, ranges)
+            }
         }
     } else {
         CollectedCtors::NonExhaustiveAny
@@ -903,3 +1173,10 @@ fn filter_matrix_list_ctors(matrix: &RefPatternMatrix) -> impl Iterator<Item = L
         _ => None,
     })
 }
+
+fn filter_matrix_int_ranges(matrix: &RefPatternMatrix) -> impl Iterator<Item = &IntRange> + '_ {
+    matrix.iter().filter_map(|row| match row.last() {
+        Some(Pattern::IntRange(range)) => Some(range),
+        _ => None,
+    })
+}
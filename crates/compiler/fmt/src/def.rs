@@ -196,6 +196,7 @@ impl<'a> Formattable for ValueDef<'a> {
             Expect { condition, .. } => condition.is_multiline(),
             ExpectFx { condition, .. } => condition.is_multiline(),
             Dbg { condition, .. } => condition.is_multiline(),
+            Malformed { source, .. } => source.contains('\n'),
         }
     }
 
@@ -220,6 +221,19 @@ impl<'a> Formattable for ValueDef<'a> {
             ExpectFx { condition, .. } => {
                 fmt_expect_fx(buf, condition, self.is_multiline(), indent)
             }
+            Malformed { source, .. } => {
+                // There's nothing to format in a def that didn't parse, so keep it as written.
+                for (index, line) in source.lines().enumerate() {
+                    if index > 0 {
+                        buf.push_newline_literal();
+                    }
+
+                    if !line.is_empty() {
+                        buf.indent(indent);
+                        buf.push_str_allow_spaces(line);
+                    }
+                }
+            }
             AnnotatedBody {
                 ann_pattern,
                 ann_type,
@@ -565,6 +565,10 @@ impl<'a> RemoveSpaces<'a> for ValueDef<'a> {
                 condition: arena.alloc(condition.remove_spaces(arena)),
                 preceding_comment: Region::zero(),
             },
+            Malformed { source, name } => Malformed {
+                source,
+                name: name.remove_spaces(arena),
+            },
         }
     }
 }
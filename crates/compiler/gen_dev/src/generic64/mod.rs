@@ -1905,6 +1905,66 @@ impl<
         }
     }
 
+    fn build_compare(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        arg_layout: &InLayout<'a>,
+    ) {
+        match self.interner().get_repr(*arg_layout) {
+            LayoutRepr::Builtin(Builtin::Int(_) | Builtin::Float(_) | Builtin::Decimal) => {
+                self.build_num_cmp(dst, src1, src2, arg_layout)
+            }
+            LayoutRepr::BOOL => self.build_num_cmp(dst, src1, src2, &Layout::U8),
+            LayoutRepr::STR => self.build_fn_call(
+                dst,
+                bitcode::STR_COMPARE.to_string(),
+                &[*src1, *src2],
+                &[Layout::STR, Layout::STR],
+                &Layout::U8,
+            ),
+            LayoutRepr::Union(UnionLayout::NonRecursive([])) => {
+                // This instruction will never execute, but we need a value the symbol
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                ASM::mov_reg64_imm64(&mut self.buf, dst_reg, 0);
+            }
+            _ => {
+                let ident_ids = self
+                    .interns
+                    .all_ident_ids
+                    .get_mut(&self.env.module_id)
+                    .unwrap();
+
+                // generate a proc
+
+                let (compare_symbol, compare_linker_data) = self.helper_proc_gen.gen_refcount_proc(
+                    ident_ids,
+                    self.layout_interner,
+                    *arg_layout,
+                    HelperOp::Compare,
+                );
+
+                let fn_name = self.lambda_name_to_string(
+                    LambdaName::no_niche(compare_symbol),
+                    [*arg_layout, *arg_layout].into_iter(),
+                    None,
+                    Layout::U8,
+                );
+
+                self.helper_proc_symbols.extend(compare_linker_data);
+
+                self.build_fn_call(
+                    dst,
+                    fn_name,
+                    &[*src1, *src2],
+                    &[*arg_layout, *arg_layout],
+                    &Layout::U8,
+                )
+            }
+        }
+    }
+
     fn build_not(&mut self, dst: &Symbol, src: &Symbol, arg_layout: &InLayout<'a>) {
         match self.interner().get_repr(*arg_layout) {
             LayoutRepr::BOOL => {
@@ -219,7 +219,7 @@ impl<'a> LastSeenMap<'a> {
                 }
                 self.scan_ast_help(default_branch.1);
             }
-            Stmt::Ret(sym) => {
+            Stmt::Ret(sym, _) => {
                 self.set_last_seen(*sym, stmt);
             }
             Stmt::Refcounting(modify, following) => {
@@ -256,7 +256,7 @@ impl<'a> LastSeenMap<'a> {
                     self.set_last_seen(param.symbol, stmt);
                 }
             }
-            Stmt::Jump(JoinPointId(sym), symbols) => {
+            Stmt::Jump(JoinPointId(sym), symbols, _) => {
                 if let Some(parameters) = self.join_map.get(&JoinPointId(*sym)) {
                     // Keep the parameters around. They will be overwritten when jumping.
                     for param in *parameters {
@@ -545,7 +545,7 @@ trait Backend<'a> {
                 self.free_symbols(stmt);
                 self.build_stmt(layout_ids, following, ret_layout);
             }
-            Stmt::Ret(sym) => {
+            Stmt::Ret(sym, _) => {
                 self.load_literal_symbols(&[*sym]);
                 self.return_symbol(sym, ret_layout);
                 self.free_symbols(stmt);
@@ -606,6 +606,7 @@ trait Backend<'a> {
                 branches,
                 default_branch,
                 ret_layout,
+                ..
             } => {
                 self.load_literal_symbols(&[*cond_symbol]);
                 self.build_switch(
@@ -623,6 +624,7 @@ trait Backend<'a> {
                 parameters,
                 body,
                 remainder,
+                ..
             } => {
                 for param in parameters.iter() {
                     self.set_layout_map(param.symbol, &param.layout);
@@ -630,7 +632,7 @@ trait Backend<'a> {
                 self.build_join(layout_ids, id, parameters, body, remainder, ret_layout);
                 self.free_symbols(stmt);
             }
-            Stmt::Jump(id, args) => {
+            Stmt::Jump(id, args, _) => {
                 self.load_literal_symbols(args);
                 let mut arg_layouts: bumpalo::collections::Vec<InLayout<'a>> =
                     bumpalo::vec![in self.env().arena];
@@ -1229,6 +1231,25 @@ trait Backend<'a> {
                 );
                 self.build_neq(sym, &args[0], &args[1], &arg_layouts[0])
             }
+            LowLevel::Compare => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "Compare: expected to have exactly two argument"
+                );
+
+                let a = Layout::runtime_representation_in(arg_layouts[0], self.interner());
+                let b = Layout::runtime_representation_in(arg_layouts[1], self.interner());
+
+                debug_assert!(
+                    self.interner().eq_repr(a, b),
+                    "Compare: expected all arguments to have the same layout, but {} != {}",
+                    self.interner().dbg(a),
+                    self.interner().dbg(b),
+                );
+
+                self.build_compare(sym, &args[0], &args[1], &arg_layouts[0])
+            }
             LowLevel::Not => {
                 debug_assert_eq!(1, args.len(), "Not: expected to have exactly one argument");
                 debug_assert!(
@@ -1567,6 +1588,62 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrToUpper => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UPPER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToLower => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_LOWER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCaseInsensitiveEq => self.build_fn_call(
+                sym,
+                bitcode::STR_CASE_INSENSITIVE_EQ.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfc => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFC.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfd => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToUtf16 => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UTF16.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrFromUtf16Lossy => self.build_fn_call(
+                sym,
+                bitcode::STR_FROM_UTF16_LOSSY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCompare => self.build_fn_call(
+                sym,
+                bitcode::STR_COMPARE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::StrReserve => self.build_fn_call(
                 sym,
                 bitcode::STR_RESERVE.to_string(),
@@ -1620,6 +1697,21 @@ trait Backend<'a> {
 
                 self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
             }
+            LowLevel::StrToIntRadix => {
+                let number_layout = match self.interner().get_repr(*ret_layout) {
+                    LayoutRepr::Struct(field_layouts) => field_layouts[0],
+                    _ => unreachable!(),
+                };
+
+                let intrinsic = match self.interner().get_repr(number_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(int_width)) => {
+                        &bitcode::STR_TO_INT_RADIX[int_width]
+                    }
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -1735,6 +1827,34 @@ trait Backend<'a> {
 
                 self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
             }
+            LowLevel::NumToStrRadix => {
+                let intrinsic = match self.interner().get_repr(arg_layouts[0]) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => &bitcode::STR_FROM_INT_RADIX[width],
+                    x => internal_error!("NumToStrRadix is not defined for {:?}", x),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumToStrDecimals | LowLevel::NumToStrScientific => {
+                let (float_intrinsic, dec_intrinsic) = match lowlevel {
+                    LowLevel::NumToStrDecimals => (
+                        &bitcode::STR_FROM_FLOAT_DECIMALS,
+                        bitcode::DEC_TO_STR_DECIMALS,
+                    ),
+                    _ => (
+                        &bitcode::STR_FROM_FLOAT_SCIENTIFIC,
+                        bitcode::DEC_TO_STR_SCIENTIFIC,
+                    ),
+                };
+
+                let intrinsic = match self.interner().get_repr(arg_layouts[0]) {
+                    LayoutRepr::Builtin(Builtin::Float(width)) => &float_intrinsic[width],
+                    LayoutRepr::Builtin(Builtin::Decimal) => dec_intrinsic,
+                    x => internal_error!("{:?} is not defined for {:?}", lowlevel, x),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::StrIsEmpty => {
                 let intrinsic = bitcode::STR_IS_EMPTY.to_string();
                 self.build_fn_call(sym, intrinsic, args, arg_layouts, ret_layout);
@@ -2191,6 +2311,15 @@ trait Backend<'a> {
     /// build_neq stores the result of `src1 != src2` into dst.
     fn build_neq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &InLayout<'a>);
 
+    /// build_compare stores the `[LT, EQ, GT]` ordering of `src1` and `src2` into dst.
+    fn build_compare(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        arg_layout: &InLayout<'a>,
+    );
+
     /// build_not stores the result of `!src` into dst.
     fn build_not(&mut self, dst: &Symbol, src: &Symbol, arg_layout: &InLayout<'a>);
 
@@ -14,6 +14,7 @@ use roc_module::symbol::Interns;
 use roc_mono::ir::{Call, CallSpecId, Expr, UpdateModeId};
 use roc_mono::ir::{Proc, ProcLayout, Stmt};
 use roc_mono::layout::{LambdaName, Layout, LayoutIds, LayoutInterner, STLayoutInterner};
+use roc_region::all::Region;
 use roc_target::TargetInfo;
 use target_lexicon::{Architecture as TargetArch, BinaryFormat as TargetBF, Triple};
 
@@ -365,6 +366,9 @@ fn create_relocation(target_info: TargetInfo, symbol: SymbolId, offset: u64) ->
                 )
             }
         }
+        roc_target::Architecture::Riscv64 => {
+            internal_error!("the dev backend does not support riscv64, `build_module` rejects it")
+        }
         roc_target::Architecture::Wasm32 => todo!(),
         roc_target::Architecture::X86_32 => todo!(),
         roc_target::Architecture::X86_64 => (
@@ -721,7 +725,7 @@ fn build_exposed_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'a>) -> P
         s4,
         Expr::Call(call),
         proc.ret_layout,
-        arena.alloc(Stmt::Ret(s4)),
+        arena.alloc(Stmt::Ret(s4, Region::zero())),
     );
 
     Proc {
@@ -732,6 +736,7 @@ fn build_exposed_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'a>) -> P
         ret_layout: proc.ret_layout,
         is_self_recursive: roc_mono::ir::SelfRecursive::NotSelfRecursive,
         is_erased: proc.is_erased,
+        regions: roc_mono::ir::ProcRegions::default(),
     }
 }
 
@@ -797,7 +802,7 @@ fn build_exposed_generic_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'
                         Layout::UNIT,
                         arena.alloc(
                             //
-                            Stmt::Ret(s3),
+                            Stmt::Ret(s3, Region::zero()),
                         ),
                     ),
                 ),
@@ -813,6 +818,7 @@ fn build_exposed_generic_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'
         ret_layout: roc_mono::layout::Layout::UNIT,
         is_self_recursive: roc_mono::ir::SelfRecursive::NotSelfRecursive,
         is_erased: proc.is_erased,
+        regions: roc_mono::ir::ProcRegions::default(),
     }
 }
 
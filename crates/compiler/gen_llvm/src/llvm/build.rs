@@ -17,10 +17,10 @@ use inkwell::basic_block::BasicBlock;
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::debug_info::{
-    AsDIScope, DICompileUnit, DIFlagsConstants, DISubprogram, DebugInfoBuilder,
+    AsDIScope, DICompileUnit, DIFile, DIFlagsConstants, DISubprogram, DebugInfoBuilder,
 };
 use inkwell::memory_buffer::MemoryBuffer;
-use inkwell::module::{Linkage, Module};
+use inkwell::module::{FlagBehavior, Linkage, Module};
 use inkwell::passes::{PassManager, PassManagerBuilder};
 use inkwell::types::{
     AnyType, BasicMetadataTypeEnum, BasicType, BasicTypeEnum, FloatMathType, FunctionType,
@@ -58,6 +58,9 @@ use std::path::Path;
 use target_lexicon::{Aarch64Architecture, Architecture, OperatingSystem, Triple};
 
 use super::convert::{struct_type_from_union_layout, RocUnion};
+use super::debug_info::{
+    add_debug_info_flags, declare_local_variable, set_debug_location, DebugSources,
+};
 use super::intrinsics::{
     add_intrinsics, LLVM_FRAME_ADDRESS, LLVM_MEMSET_I32, LLVM_MEMSET_I64, LLVM_SETJMP,
     LLVM_STACK_SAVE,
@@ -740,6 +743,7 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target_info: TargetInfo,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    pub debug_sources: DebugSources<'ctx>,
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -920,6 +924,8 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
     }
 
     pub fn new_debug_info(module: &Module<'ctx>) -> (DebugInfoBuilder<'ctx>, DICompileUnit<'ctx>) {
+        add_debug_info_flags(module);
+
         module.create_debug_info_builder(
             true,
             /* language */ inkwell::debug_info::DWARFSourceLanguage::C,
@@ -940,8 +946,16 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
     }
 
     pub fn new_subprogram(&self, function_name: &str) -> DISubprogram<'ctx> {
+        self.new_subprogram_at(function_name, self.compile_unit.get_file(), 0)
+    }
+
+    pub fn new_subprogram_at(
+        &self,
+        function_name: &str,
+        file: DIFile<'ctx>,
+        line_no: u32,
+    ) -> DISubprogram<'ctx> {
         let dibuilder = self.dibuilder;
-        let compile_unit = self.compile_unit;
 
         let ditype = dibuilder
             .create_basic_type(
@@ -953,22 +967,22 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
             .unwrap();
 
         let subroutine_type = dibuilder.create_subroutine_type(
-            compile_unit.get_file(),
+            file,
             /* return type */ Some(ditype.as_type()),
             /* parameter types */ &[],
             inkwell::debug_info::DIFlags::PUBLIC,
         );
 
         dibuilder.create_function(
-            /* scope */ compile_unit.get_file().as_debug_info_scope(),
+            /* scope */ file.as_debug_info_scope(),
             /* func name */ function_name,
             /* linkage_name */ None,
-            /* file */ compile_unit.get_file(),
-            /* line_no */ 0,
+            /* file */ file,
+            /* line_no */ line_no,
             /* DIType */ subroutine_type,
             /* is_local_to_unit */ true,
             /* is_definition */ true,
-            /* scope_line */ 0,
+            /* scope_line */ line_no,
             /* flags */ inkwell::debug_info::DIFlags::PUBLIC,
             /* is_optimized */ false,
         )
@@ -1013,6 +1027,13 @@ pub fn module_from_builtins<'ctx>(
             } => {
                 include_bytes!("../../../builtins/bitcode/zig-out/builtins-aarch64.bc")
             }
+            Triple {
+                architecture: Architecture::Riscv64(_),
+                operating_system: OperatingSystem::Linux,
+                ..
+            } => {
+                include_bytes!("../../../builtins/bitcode/zig-out/builtins-riscv64.bc")
+            }
             Triple {
                 architecture: Architecture::X86_64,
                 operating_system: OperatingSystem::Windows,
@@ -1032,6 +1053,18 @@ pub fn module_from_builtins<'ctx>(
     // Add LLVM intrinsics.
     add_intrinsics(ctx, &module);
 
+    // Without this, LLVM picks the soft-float lp64 ABI for riscv64, which does not link against
+    // hosts built for Linux (lp64d).
+    if matches!(target.architecture, Architecture::Riscv64(_))
+        && module.get_flag("target-abi").is_none()
+    {
+        module.add_metadata_flag(
+            "target-abi",
+            FlagBehavior::Error,
+            ctx.metadata_string("lp64d"),
+        );
+    }
+
     module
 }
 
@@ -3170,6 +3203,12 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                     LayoutRepr::RecursivePointer(_)
                 ));
 
+                // named values point at their def, the results of calls at the call
+                let opt_region = scope.symbol_region(*symbol);
+                if let Some(region) = opt_region.or_else(|| scope.call_region(*symbol)) {
+                    set_debug_location(env, parent, symbol.module_id(), region);
+                }
+
                 let val = build_exp_expr(
                     env,
                     layout_interner,
@@ -3188,6 +3227,19 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                 // access itself!
                 // scope = scope.clone();
 
+                if let Some(region) = opt_region {
+                    declare_local_variable(
+                        env,
+                        layout_interner,
+                        parent,
+                        *symbol,
+                        region,
+                        *layout,
+                        val,
+                        None,
+                    );
+                }
+
                 scope.insert(*symbol, *layout, val);
                 stack.push(*symbol);
             }
@@ -3208,7 +3260,9 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
 
             result
         }
-        Ret(symbol) => {
+        Ret(symbol, region) => {
+            set_debug_location(env, parent, symbol.module_id(), *region);
+
             let (value, layout) = scope.load_symbol_and_layout(symbol);
 
             build_return(
@@ -3228,7 +3282,10 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
             ret_layout,
             cond_layout,
             cond_symbol,
+            region,
         } => {
+            set_debug_location(env, parent, cond_symbol.module_id(), *region);
+
             let ret_type =
                 basic_type_from_layout(env, layout_interner, layout_interner.get_repr(*ret_layout));
 
@@ -3255,7 +3312,10 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
             parameters,
             remainder,
             body: continuation,
+            region,
         } => {
+            set_debug_location(env, parent, id.0.module_id(), *region);
+
             let builder = env.builder;
             let context = env.context;
 
@@ -3330,7 +3390,9 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
             result
         }
 
-        Jump(join_point, arguments) => {
+        Jump(join_point, arguments, region) => {
+            set_debug_location(env, parent, join_point.0.module_id(), *region);
+
             let builder = env.builder;
             let context = env.context;
             let (cont_block, argument_phi_values) = scope.get_join_point(*join_point).unwrap();
@@ -5801,7 +5863,13 @@ fn build_proc_header<'a, 'ctx>(
         Linkage::Internal,
     );
 
-    let subprogram = env.new_subprogram(&fn_name);
+    let subprogram = match env
+        .debug_sources
+        .location(symbol.module_id(), proc.regions.body)
+    {
+        Some((file, position)) => env.new_subprogram_at(&fn_name, file, position.line),
+        None => env.new_subprogram(&fn_name),
+    };
     fn_val.set_subprogram(subprogram);
 
     if env.exposed_to_host.contains(&symbol) {
@@ -6106,14 +6174,30 @@ fn build_proc<'a, 'ctx>(
 
     builder.position_at_end(entry);
 
-    debug_info_init!(env, fn_val);
+    let home = proc.name.name().module_id();
+    if !set_debug_location(env, fn_val, home, proc.regions.body) {
+        debug_info_init!(env, fn_val);
+    }
 
     // Add args to scope
-    for (arg_val, (layout, arg_symbol)) in fn_val.get_param_iter().zip(args) {
+    for (arg_no, (arg_val, (layout, arg_symbol))) in fn_val.get_param_iter().zip(args).enumerate() {
         arg_val.set_name(arg_symbol.as_str(&env.interns));
         scope.insert(*arg_symbol, *layout, arg_val);
+
+        declare_local_variable(
+            env,
+            layout_interner,
+            fn_val,
+            *arg_symbol,
+            proc.regions.arg_region(arg_no).unwrap_or(proc.regions.body),
+            *layout,
+            arg_val,
+            Some(arg_no as u32 + 1),
+        );
     }
 
+    scope.set_regions(proc.regions);
+
     let body = build_exp_stmt(
         env,
         layout_interner,
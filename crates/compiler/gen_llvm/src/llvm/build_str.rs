@@ -46,6 +46,21 @@ pub(crate) fn str_equal<'ctx>(
     )
 }
 
+/// Str.compareUtf8 : Str, Str -> [LT, EQ, GT]
+pub(crate) fn str_compare<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    value1: BasicValueEnum<'ctx>,
+    value2: BasicValueEnum<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    call_str_bitcode_fn(
+        env,
+        &[value1, value2],
+        &[],
+        BitcodeReturns::Basic,
+        bitcode::STR_COMPARE,
+    )
+}
+
 // Gets a pointer to just after the refcount for a list or seamless slice.
 // The value is just after the refcount so that normal lists and seamless slices can share code paths easily.
 pub(crate) fn str_refcount_ptr<'ctx>(
@@ -1,9 +1,10 @@
+use crate::llvm::bitcode::call_bitcode_fn;
 use crate::llvm::build::{get_tag_id, tag_pointer_clear_tag_id, Env, FAST_CALL_CONV};
 use crate::llvm::build_list::{list_len, load_list_ptr};
-use crate::llvm::build_str::str_equal;
+use crate::llvm::build_str::{str_compare, str_equal};
 use crate::llvm::convert::basic_type_from_layout;
 use bumpalo::collections::Vec;
-use inkwell::types::BasicType;
+use inkwell::types::{BasicType, BasicTypeEnum};
 use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue, StructValue};
 use inkwell::{AddressSpace, FloatPredicate, IntPredicate};
 use roc_builtins::bitcode;
@@ -59,6 +60,17 @@ pub fn generic_neq<'a, 'ctx>(
     )
 }
 
+/// Returns the `[LT, EQ, GT]` ordering of two values of the same layout, as its `u8` tag id.
+pub fn generic_compare<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    lhs_val: BasicValueEnum<'ctx>,
+    rhs_val: BasicValueEnum<'ctx>,
+    layout: InLayout<'a>,
+) -> BasicValueEnum<'ctx> {
+    build_compare(env, layout_interner, lhs_val, rhs_val, layout).into()
+}
+
 fn build_eq_builtin<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -1508,3 +1520,611 @@ fn build_box_eq_help<'a, 'ctx>(
 
     env.builder.new_build_return(Some(&is_equal));
 }
+
+/// ----
+
+// The tag ids of `[LT, EQ, GT]`, which are sorted alphabetically.
+const ORDERING_EQ: u64 = 0;
+const ORDERING_GT: u64 = 1;
+const ORDERING_LT: u64 = 2;
+
+fn ordering<'ctx>(env: &Env<'_, 'ctx, '_>, tag_id: u64) -> IntValue<'ctx> {
+    env.context.i8_type().const_int(tag_id, false)
+}
+
+fn int_ordering<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    lhs_val: IntValue<'ctx>,
+    rhs_val: IntValue<'ctx>,
+    is_signed: bool,
+) -> IntValue<'ctx> {
+    let are_equal = env
+        .builder
+        .new_build_int_compare(IntPredicate::EQ, lhs_val, rhs_val, "int_eq");
+
+    let predicate = if is_signed {
+        IntPredicate::SLT
+    } else {
+        IntPredicate::ULT
+    };
+
+    let is_less_than =
+        env.builder
+            .new_build_int_compare(predicate, lhs_val, rhs_val, "int_compare");
+
+    let step1 = env.builder.new_build_select(
+        is_less_than,
+        ordering(env, ORDERING_LT),
+        ordering(env, ORDERING_GT),
+        "lt_or_gt",
+    );
+
+    env.builder
+        .new_build_select(
+            are_equal,
+            ordering(env, ORDERING_EQ),
+            step1.into_int_value(),
+            "lt_or_gt",
+        )
+        .into_int_value()
+}
+
+/// The `[LT, EQ, GT]` ordering of two numbers (or booleans), as its `u8` tag id.
+pub(crate) fn build_num_compare<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    lhs_val: BasicValueEnum<'ctx>,
+    rhs_val: BasicValueEnum<'ctx>,
+    builtin: Builtin<'_>,
+) -> BasicValueEnum<'ctx> {
+    match builtin {
+        Builtin::Int(int_width) => int_ordering(
+            env,
+            lhs_val.into_int_value(),
+            rhs_val.into_int_value(),
+            int_width.is_signed(),
+        )
+        .into(),
+        Builtin::Bool => int_ordering(
+            env,
+            lhs_val.into_int_value(),
+            rhs_val.into_int_value(),
+            false,
+        )
+        .into(),
+        Builtin::Float(_) => {
+            let are_equal = env.builder.new_build_float_compare(
+                FloatPredicate::OEQ,
+                lhs_val.into_float_value(),
+                rhs_val.into_float_value(),
+                "float_eq",
+            );
+            let is_less_than = env.builder.new_build_float_compare(
+                FloatPredicate::OLT,
+                lhs_val.into_float_value(),
+                rhs_val.into_float_value(),
+                "float_compare",
+            );
+
+            let step1 = env.builder.new_build_select(
+                is_less_than,
+                ordering(env, ORDERING_LT),
+                ordering(env, ORDERING_GT),
+                "lt_or_gt",
+            );
+
+            env.builder.new_build_select(
+                are_equal,
+                ordering(env, ORDERING_EQ),
+                step1.into_int_value(),
+                "lt_or_gt",
+            )
+        }
+        Builtin::Decimal => call_bitcode_fn(
+            env,
+            &[lhs_val, rhs_val],
+            &bitcode::NUM_COMPARE[IntWidth::I128],
+        ),
+        Builtin::Str | Builtin::List(_) => {
+            internal_error!("{builtin:?} is not a number")
+        }
+    }
+}
+
+fn build_compare<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    lhs_val: BasicValueEnum<'ctx>,
+    rhs_val: BasicValueEnum<'ctx>,
+    layout: InLayout<'a>,
+) -> IntValue<'ctx> {
+    match layout_interner.get_repr(layout) {
+        LayoutRepr::Builtin(Builtin::Str) => str_compare(env, lhs_val, rhs_val).into_int_value(),
+        LayoutRepr::Builtin(Builtin::List(_)) => {
+            let arg_type =
+                basic_type_from_layout(env, layout_interner, layout_interner.get_repr(layout));
+
+            build_compare_call(env, layout_interner, layout, arg_type, lhs_val, rhs_val)
+        }
+        LayoutRepr::Builtin(builtin) => {
+            build_num_compare(env, lhs_val, rhs_val, builtin).into_int_value()
+        }
+
+        LayoutRepr::Struct(_) => {
+            // The field order of a newtype is decided by the struct it wraps.
+            let layout = layout_interner.unwrap_newtype(layout);
+            let arg_type =
+                argument_type_from_layout(env, layout_interner, layout_interner.get_repr(layout));
+
+            build_compare_call(env, layout_interner, layout, arg_type, lhs_val, rhs_val)
+        }
+
+        LayoutRepr::Union(union_layout) => {
+            let arg_type = argument_type_from_union_layout(env, layout_interner, &union_layout);
+
+            build_compare_call(env, layout_interner, layout, arg_type, lhs_val, rhs_val)
+        }
+
+        LayoutRepr::Ptr(_) => {
+            let arg_type =
+                basic_type_from_layout(env, layout_interner, layout_interner.get_repr(layout));
+
+            build_compare_call(env, layout_interner, layout, arg_type, lhs_val, rhs_val)
+        }
+
+        LayoutRepr::RecursivePointer(rec_layout) => {
+            let bt =
+                basic_type_from_layout(env, layout_interner, layout_interner.get_repr(rec_layout));
+
+            // cast the i64 pointer to a pointer to block of memory
+            let lhs_cast = env.builder.new_build_pointer_cast(
+                lhs_val.into_pointer_value(),
+                bt.into_pointer_type(),
+                "i64_to_opaque",
+            );
+
+            let rhs_cast = env.builder.new_build_pointer_cast(
+                rhs_val.into_pointer_value(),
+                bt.into_pointer_type(),
+                "i64_to_opaque",
+            );
+
+            build_compare(
+                env,
+                layout_interner,
+                lhs_cast.into(),
+                rhs_cast.into(),
+                rec_layout,
+            )
+        }
+
+        LayoutRepr::LambdaSet(_) => unreachable!("cannot compare closures"),
+        LayoutRepr::FunctionPointer(_) => unreachable!("cannot compare function pointers"),
+        LayoutRepr::Erased(_) => unreachable!("cannot compare erased types"),
+    }
+}
+
+fn build_compare_call<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+    arg_type: BasicTypeEnum<'ctx>,
+    lhs_val: BasicValueEnum<'ctx>,
+    rhs_val: BasicValueEnum<'ctx>,
+) -> IntValue<'ctx> {
+    let block = env.builder.get_insert_block().expect("to be in a function");
+    let di_location = env.builder.get_current_debug_location().unwrap();
+
+    // Key the helper by the interned layout rather than its representation: two records with
+    // the same representation can still order their fields differently.
+    let fn_name = format!(
+        "{}_{}",
+        Symbol::GENERIC_COMPARE_REF.as_str(&env.interns),
+        layout.index()
+    );
+
+    let function = match env.module.get_function(fn_name.as_str()) {
+        Some(function_value) => function_value,
+        None => {
+            let function_value = crate::llvm::refcounting::build_header_help(
+                env,
+                &fn_name,
+                env.context.i8_type().into(),
+                &[arg_type, arg_type],
+            );
+
+            build_compare_help(env, layout_interner, function_value, layout);
+
+            function_value
+        }
+    };
+
+    env.builder.position_at_end(block);
+    env.builder.set_current_debug_location(di_location);
+    let call = env
+        .builder
+        .new_build_call(function, &[lhs_val.into(), rhs_val.into()], "compare");
+
+    call.set_call_convention(FAST_CALL_CONV);
+
+    call.try_as_basic_value().left().unwrap().into_int_value()
+}
+
+fn build_compare_help<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    layout: InLayout<'a>,
+) {
+    let ctx = env.context;
+    let builder = env.builder;
+
+    {
+        use inkwell::debug_info::AsDIScope;
+
+        let func_scope = parent.get_subprogram().unwrap();
+        let lexical_block = env.dibuilder.create_lexical_block(
+            /* scope */ func_scope.as_debug_info_scope(),
+            /* file */ env.compile_unit.get_file(),
+            /* line_no */ 0,
+            /* column_no */ 0,
+        );
+
+        let loc = env.dibuilder.create_debug_location(
+            ctx,
+            /* line */ 0,
+            /* column */ 0,
+            /* current_scope */ lexical_block.as_debug_info_scope(),
+            /* inlined_at */ None,
+        );
+        builder.set_current_debug_location(loc);
+    }
+
+    // Add args to scope
+    let mut it = parent.get_param_iter();
+    let arg1 = it.next().unwrap();
+    let arg2 = it.next().unwrap();
+
+    arg1.set_name(Symbol::ARG_1.as_str(&env.interns));
+    arg2.set_name(Symbol::ARG_2.as_str(&env.interns));
+
+    let entry = ctx.append_basic_block(parent, "entry");
+    env.builder.position_at_end(entry);
+
+    match layout_interner.get_repr(layout) {
+        LayoutRepr::Struct(field_layouts) => {
+            let struct_layout = layout_interner.get_repr(layout);
+
+            // compare the fields in the order they were written in the type, not in the layout
+            for index in layout_interner.struct_field_order(layout) {
+                let field1 = struct_::RocStruct::from(arg1).load_at_index(
+                    env,
+                    layout_interner,
+                    struct_layout,
+                    index as _,
+                );
+
+                let field2 = struct_::RocStruct::from(arg2).load_at_index(
+                    env,
+                    layout_interner,
+                    struct_layout,
+                    index as _,
+                );
+
+                let answer =
+                    build_compare(env, layout_interner, field1, field2, field_layouts[index]);
+
+                return_if_not_equal(env, parent, answer);
+            }
+
+            env.builder
+                .new_build_return(Some(&ordering(env, ORDERING_EQ)));
+        }
+        LayoutRepr::Union(union_layout) => {
+            build_tag_compare_help(env, layout_interner, parent, &union_layout, arg1, arg2);
+        }
+        LayoutRepr::Builtin(Builtin::List(element_layout)) => {
+            build_list_compare_help(
+                env,
+                layout_interner,
+                parent,
+                element_layout,
+                arg1.into_struct_value(),
+                arg2.into_struct_value(),
+            );
+        }
+        LayoutRepr::Ptr(inner_layout) => {
+            let ptr_equal = env.builder.new_build_int_compare(
+                IntPredicate::EQ,
+                env.builder
+                    .new_build_ptr_to_int(arg1.into_pointer_value(), env.ptr_int(), "pti"),
+                env.builder
+                    .new_build_ptr_to_int(arg2.into_pointer_value(), env.ptr_int(), "pti"),
+                "compare_pointers",
+            );
+
+            let return_eq = ctx.append_basic_block(parent, "return_eq");
+            let compare_inner_values = ctx.append_basic_block(parent, "compare_inner_values");
+
+            env.builder
+                .new_build_conditional_branch(ptr_equal, return_eq, compare_inner_values);
+
+            env.builder.position_at_end(return_eq);
+            env.builder
+                .new_build_return(Some(&ordering(env, ORDERING_EQ)));
+
+            env.builder.position_at_end(compare_inner_values);
+
+            let inner_repr = layout_interner.get_repr(inner_layout);
+            let value1 = load_roc_value(
+                env,
+                layout_interner,
+                inner_repr,
+                arg1.into_pointer_value(),
+                "load_box1",
+            );
+            let value2 = load_roc_value(
+                env,
+                layout_interner,
+                inner_repr,
+                arg2.into_pointer_value(),
+                "load_box2",
+            );
+
+            let answer = build_compare(env, layout_interner, value1, value2, inner_layout);
+
+            env.builder.new_build_return(Some(&answer));
+        }
+        other => internal_error!("no comparison helper for {other:?}"),
+    }
+}
+
+/// Returns `answer` from the current function unless it is `EQ`, and continues in a new block
+/// otherwise.
+fn return_if_not_equal<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    parent: FunctionValue<'ctx>,
+    answer: IntValue<'ctx>,
+) {
+    let ctx = env.context;
+
+    let return_answer = ctx.append_basic_block(parent, "return_answer");
+    let next = ctx.append_basic_block(parent, "compare_next");
+
+    let is_equal = env.builder.new_build_int_compare(
+        IntPredicate::EQ,
+        answer,
+        ordering(env, ORDERING_EQ),
+        "is_eq",
+    );
+
+    env.builder
+        .new_build_conditional_branch(is_equal, next, return_answer);
+
+    env.builder.position_at_end(return_answer);
+    env.builder.new_build_return(Some(&answer));
+
+    env.builder.position_at_end(next);
+}
+
+fn build_tag_compare_help<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    union_layout: &UnionLayout<'a>,
+    tag1: BasicValueEnum<'ctx>,
+    tag2: BasicValueEnum<'ctx>,
+) {
+    use UnionLayout::*;
+
+    if let NonRecursive(&[]) = union_layout {
+        // we're comparing empty tag unions; this code is effectively unreachable
+        env.builder.new_build_unreachable();
+        return;
+    }
+
+    // tags are ordered by their tag id first, which follows the alphabetical order of their names
+    let id1 = get_tag_id(env, layout_interner, parent, union_layout, tag1);
+    let id2 = get_tag_id(env, layout_interner, parent, union_layout, tag2);
+
+    let answer = int_ordering(env, id1, id2, false);
+    return_if_not_equal(env, parent, answer);
+
+    // get a pointer to the actual data
+    let (data1, data2) = match union_layout {
+        Recursive(_) | NullableWrapped { .. } => (
+            tag_pointer_clear_tag_id(env, tag1.into_pointer_value()),
+            tag_pointer_clear_tag_id(env, tag2.into_pointer_value()),
+        ),
+        NonRecursive(_) | NonNullableUnwrapped(_) | NullableUnwrapped { .. } => {
+            (tag1.into_pointer_value(), tag2.into_pointer_value())
+        }
+    };
+
+    let mut tags: Vec<(u64, &'a [InLayout<'a>])> = Vec::new_in(env.arena);
+
+    match union_layout {
+        NonRecursive(other_tags) | Recursive(other_tags) => {
+            tags.extend(other_tags.iter().enumerate().map(|(i, f)| (i as u64, *f)));
+        }
+        NonNullableUnwrapped(field_layouts) => {
+            tags.push((0, *field_layouts));
+        }
+        NullableWrapped {
+            other_tags,
+            nullable_id,
+        } => {
+            tags.extend(other_tags.iter().enumerate().map(|(i, f)| {
+                let tag_id = if i >= (*nullable_id as _) { i + 1 } else { i };
+
+                (tag_id as u64, *f)
+            }));
+        }
+        NullableUnwrapped {
+            nullable_id,
+            other_fields,
+        } => {
+            tags.push((!*nullable_id as u64, *other_fields));
+        }
+    }
+
+    let switch_block = env.builder.get_insert_block().unwrap();
+
+    // the null tag (if any) has no payload, so two of them are equal
+    let return_eq = env.context.append_basic_block(parent, "return_eq");
+    env.builder.position_at_end(return_eq);
+    env.builder
+        .new_build_return(Some(&ordering(env, ORDERING_EQ)));
+
+    let mut cases = Vec::with_capacity_in(tags.len(), env.arena);
+
+    for (tag_id, field_layouts) in tags {
+        let block = env.context.append_basic_block(parent, "tag_id_compare");
+        env.builder.position_at_end(block);
+
+        let struct_layout = LayoutRepr::struct_(field_layouts);
+        let wrapper_type = basic_type_from_layout(env, layout_interner, struct_layout);
+
+        let load_struct = |data: PointerValue<'ctx>| {
+            // cast the opaque pointer to a pointer of the correct shape
+            let struct_ptr = env.builder.new_build_pointer_cast(
+                data,
+                wrapper_type.ptr_type(AddressSpace::default()),
+                "opaque_to_correct",
+            );
+
+            load_roc_value(
+                env,
+                layout_interner,
+                struct_layout,
+                struct_ptr,
+                "load_struct",
+            )
+        };
+
+        let struct1 = load_struct(data1);
+        let struct2 = load_struct(data2);
+
+        // payloads are sorted by alignment, so their fields are compared in layout order
+        for (index, field_layout) in field_layouts.iter().enumerate() {
+            let field1 = struct_::RocStruct::from(struct1).load_at_index(
+                env,
+                layout_interner,
+                struct_layout,
+                index as _,
+            );
+
+            let field2 = struct_::RocStruct::from(struct2).load_at_index(
+                env,
+                layout_interner,
+                struct_layout,
+                index as _,
+            );
+
+            let answer = build_compare(env, layout_interner, field1, field2, *field_layout);
+
+            return_if_not_equal(env, parent, answer);
+        }
+
+        env.builder
+            .new_build_return(Some(&ordering(env, ORDERING_EQ)));
+
+        cases.push((id1.get_type().const_int(tag_id, false), block));
+    }
+
+    env.builder.position_at_end(switch_block);
+    env.builder.new_build_switch(id1, return_eq, &cases);
+}
+
+fn build_list_compare_help<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    element_layout: InLayout<'a>,
+    list1: StructValue<'ctx>,
+    list2: StructValue<'ctx>,
+) {
+    let ctx = env.context;
+    let builder = env.builder;
+
+    let len1 = list_len(env.builder, list1);
+    let len2 = list_len(env.builder, list2);
+
+    let element_repr = layout_interner.get_repr(element_layout);
+    let element_type = basic_type_from_layout(env, layout_interner, element_repr);
+    let ptr_type = element_type.ptr_type(AddressSpace::default());
+    let ptr1 = load_list_ptr(env.builder, list1, ptr_type);
+    let ptr2 = load_list_ptr(env.builder, list2, ptr_type);
+
+    // allocate a stack slot for the current index
+    let index_alloca = builder.new_build_alloca(env.ptr_int(), "index");
+    builder.new_build_store(index_alloca, env.ptr_int().const_zero());
+
+    let loop_bb = ctx.append_basic_block(parent, "loop");
+    let end_of_list1 = ctx.append_basic_block(parent, "end_of_list1");
+    let check_list2 = ctx.append_basic_block(parent, "check_list2");
+    let return_gt = ctx.append_basic_block(parent, "return_gt");
+    let body_bb = ctx.append_basic_block(parent, "body");
+
+    // the "top" of the loop
+    builder.new_build_unconditional_branch(loop_bb);
+    builder.position_at_end(loop_bb);
+
+    let curr_index = builder
+        .new_build_load(env.ptr_int(), index_alloca, "index")
+        .into_int_value();
+
+    let at_end1 = builder.new_build_int_compare(IntPredicate::UGE, curr_index, len1, "at_end1");
+    builder.new_build_conditional_branch(at_end1, end_of_list1, check_list2);
+
+    {
+        // all elements of the first list matched, so the shorter list comes first
+        builder.position_at_end(end_of_list1);
+
+        let answer = int_ordering(env, len1, len2, false);
+        builder.new_build_return(Some(&answer));
+    }
+
+    {
+        builder.position_at_end(check_list2);
+
+        let at_end2 = builder.new_build_int_compare(IntPredicate::UGE, curr_index, len2, "at_end2");
+        builder.new_build_conditional_branch(at_end2, return_gt, body_bb);
+    }
+
+    {
+        builder.position_at_end(return_gt);
+        builder.new_build_return(Some(&ordering(env, ORDERING_GT)));
+    }
+
+    {
+        // loop body
+        builder.position_at_end(body_bb);
+
+        let elem1 = {
+            let elem_ptr = unsafe {
+                builder.new_build_in_bounds_gep(element_type, ptr1, &[curr_index], "load_index")
+            };
+            load_roc_value(env, layout_interner, element_repr, elem_ptr, "get_elem")
+        };
+
+        let elem2 = {
+            let elem_ptr = unsafe {
+                builder.new_build_in_bounds_gep(element_type, ptr2, &[curr_index], "load_index")
+            };
+            load_roc_value(env, layout_interner, element_repr, elem_ptr, "get_elem")
+        };
+
+        let answer = build_compare(env, layout_interner, elem1, elem2, element_layout);
+
+        // if the elements are equal, increment the index and check the next element
+        return_if_not_equal(env, parent, answer);
+
+        let one = env.ptr_int().const_int(1, false);
+        let next_index = builder.new_build_int_add(curr_index, one, "nextindex");
+
+        builder.new_build_store(index_alloca, next_index);
+
+        // jump back to the top of the loop
+        builder.new_build_unconditional_branch(loop_bb);
+    }
+}
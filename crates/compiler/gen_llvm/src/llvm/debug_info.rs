@@ -0,0 +1,275 @@
+//! Source-level debug info (DWARF) for the procs we generate.
+//!
+//! Every Roc proc gets a `DISubprogram` in the file of the module it was defined in, every named
+//! `let` in its body moves the current `DILocation` to the line of that def, and the named
+//! symbols themselves become `DILocalVariable`s so a debugger can print them.
+//!
+//! All of this is only emitted when the [DebugSources] of the [Env] know the source of the module
+//! that a proc comes from; without sources, procs are placed at line 0 of the compile unit, as
+//! generated helpers (refcounting, equality, etc.) always are.
+use std::path::{Path, PathBuf};
+
+use inkwell::debug_info::{AsDIScope, DIFile, DIFlags, DIFlagsConstants, DIType, DebugInfoBuilder};
+use inkwell::module::FlagBehavior;
+use inkwell::values::{BasicValueEnum, FunctionValue};
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::all::MutMap;
+use roc_module::symbol::{ModuleId, Symbol};
+use roc_mono::layout::{Builtin, InLayout, LayoutInterner, LayoutRepr, STLayoutInterner};
+use roc_region::all::{LineColumn, LineInfo, Region};
+
+use super::build::{create_entry_block_alloca, BuilderExt, Env};
+
+/// DWARF base type encodings, from the DWARF 5 spec, section 7.8
+const DW_ATE_ADDRESS: u32 = 0x01;
+const DW_ATE_BOOLEAN: u32 = 0x02;
+const DW_ATE_FLOAT: u32 = 0x04;
+const DW_ATE_SIGNED: u32 = 0x05;
+const DW_ATE_UNSIGNED: u32 = 0x08;
+
+/// The files (and line tables) of the modules whose procs we emit debug locations for.
+#[derive(Default)]
+pub struct DebugSources<'ctx> {
+    files: MutMap<ModuleId, (DIFile<'ctx>, LineInfo)>,
+}
+
+impl<'ctx> DebugSources<'ctx> {
+    pub fn new(
+        dibuilder: &DebugInfoBuilder<'ctx>,
+        sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    ) -> Self {
+        let mut files = MutMap::default();
+
+        for (module_id, (path, src)) in sources {
+            let filename = path
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_default();
+            let directory = path.parent().map(Path::to_string_lossy).unwrap_or_default();
+
+            let file = dibuilder.create_file(&filename, &directory);
+
+            files.insert(*module_id, (file, LineInfo::new(src)));
+        }
+
+        Self { files }
+    }
+
+    /// The file and 1-based line and column of a region in the given module, if we have its source.
+    pub(crate) fn location(
+        &self,
+        module_id: ModuleId,
+        region: Region,
+    ) -> Option<(DIFile<'ctx>, LineColumn)> {
+        if region.is_empty() {
+            // generated code, or a def we don't know the region of
+            return None;
+        }
+
+        let (file, line_info) = self.files.get(&module_id)?;
+        let LineColumn { line, column } = line_info.convert_region(region).start;
+
+        Some((
+            *file,
+            LineColumn {
+                line: line + 1,
+                column: column + 1,
+            },
+        ))
+    }
+}
+
+/// LLVM drops debug info from modules that don't say which version of it they use. The builtins
+/// are usually compiled with debug info and already carry these flags; they must not be repeated.
+pub(crate) fn add_debug_info_flags(module: &inkwell::module::Module<'_>) {
+    let context = module.get_context();
+
+    if module.get_flag("Debug Info Version").is_none() {
+        module.add_basic_value_flag(
+            "Debug Info Version",
+            FlagBehavior::Warning,
+            context.i32_type().const_int(3, false),
+        );
+    }
+
+    if module.get_flag("Dwarf Version").is_none() {
+        module.add_basic_value_flag(
+            "Dwarf Version",
+            FlagBehavior::Warning,
+            context.i32_type().const_int(4, false),
+        );
+    }
+}
+
+/// Move the current debug location to the given region, in the scope of the function we're
+/// building. Returns `false` when we don't have a source location for the region.
+pub(crate) fn set_debug_location(
+    env: &Env<'_, '_, '_>,
+    parent: FunctionValue<'_>,
+    module_id: ModuleId,
+    region: Region,
+) -> bool {
+    let (Some((_, position)), Some(subprogram)) = (
+        env.debug_sources.location(module_id, region),
+        parent.get_subprogram(),
+    ) else {
+        return false;
+    };
+
+    let loc = env.dibuilder.create_debug_location(
+        env.context,
+        position.line,
+        position.column,
+        subprogram.as_debug_info_scope(),
+        None,
+    );
+    env.builder.set_current_debug_location(loc);
+
+    true
+}
+
+/// Describe a named symbol to the debugger, so it can be inspected while stepping through `parent`.
+///
+/// LLVM values don't have an address, so the value is spilled to a stack slot that the
+/// `DILocalVariable` refers to.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn declare_local_variable<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    symbol: Symbol,
+    region: Region,
+    layout: InLayout<'a>,
+    value: BasicValueEnum<'ctx>,
+    arg_no: Option<u32>,
+) {
+    let module_id = symbol.module_id();
+
+    let (Some((file, position)), Some(subprogram)) = (
+        env.debug_sources.location(module_id, region),
+        parent.get_subprogram(),
+    ) else {
+        return;
+    };
+
+    let Some(block) = env.builder.get_insert_block() else {
+        return;
+    };
+
+    let name = symbol.as_str(&env.interns);
+    let scope = subprogram.as_debug_info_scope();
+    let ditype = debug_type(env, layout_interner, layout, value);
+
+    let variable = match arg_no {
+        Some(arg_no) => env.dibuilder.create_parameter_variable(
+            scope,
+            name,
+            arg_no,
+            file,
+            position.line,
+            ditype,
+            true,
+            DIFlags::ZERO,
+        ),
+        None => env.dibuilder.create_auto_variable(
+            scope,
+            name,
+            file,
+            position.line,
+            ditype,
+            true,
+            DIFlags::ZERO,
+            0,
+        ),
+    };
+
+    let loc = env.dibuilder.create_debug_location(
+        env.context,
+        position.line,
+        position.column,
+        scope,
+        None,
+    );
+
+    let slot = create_entry_block_alloca(env, parent, value.get_type(), name);
+    env.builder.new_build_store(slot, value);
+
+    env.dibuilder
+        .insert_declare_at_end(slot, Some(variable), None, loc, block);
+}
+
+/// A debugger-facing description of a value. Numbers get a proper base type; anything else is
+/// shown as its raw bytes (or as an address, when we only hold a pointer to it).
+fn debug_type<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+    value: BasicValueEnum<'ctx>,
+) -> DIType<'ctx> {
+    let ptr_bits = env.target_info.ptr_width() as u64 * 8;
+
+    let basic_type = |name: &str, size_in_bits: u64, encoding: u32| {
+        env.dibuilder
+            .create_basic_type(name, size_in_bits, encoding, DIFlags::ZERO)
+            .unwrap()
+            .as_type()
+    };
+
+    if value.is_pointer_value() {
+        return basic_type("Ptr", ptr_bits, DW_ATE_ADDRESS);
+    }
+
+    match layout_interner.get_repr(layout) {
+        LayoutRepr::Builtin(Builtin::Bool) => basic_type("Bool", 8, DW_ATE_BOOLEAN),
+        LayoutRepr::Builtin(Builtin::Int(int_width)) => {
+            let encoding = if int_width.is_signed() {
+                DW_ATE_SIGNED
+            } else {
+                DW_ATE_UNSIGNED
+            };
+
+            basic_type(
+                int_width_name(int_width),
+                int_width.stack_size() as u64 * 8,
+                encoding,
+            )
+        }
+        LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+            let name = match float_width {
+                FloatWidth::F32 => "F32",
+                FloatWidth::F64 => "F64",
+            };
+
+            basic_type(name, float_width.stack_size() as u64 * 8, DW_ATE_FLOAT)
+        }
+        LayoutRepr::Builtin(Builtin::Decimal) => basic_type("Dec", 128, DW_ATE_SIGNED),
+        repr => {
+            let size = repr.stack_size(layout_interner) as i64;
+            let byte = basic_type("U8", 8, DW_ATE_UNSIGNED);
+
+            env.dibuilder
+                .create_array_type(
+                    byte,
+                    size as u64 * 8,
+                    repr.alignment_bytes(layout_interner) * 8,
+                    &[0..size],
+                )
+                .as_type()
+        }
+    }
+}
+
+fn int_width_name(int_width: IntWidth) -> &'static str {
+    match int_width {
+        IntWidth::U8 => "U8",
+        IntWidth::U16 => "U16",
+        IntWidth::U32 => "U32",
+        IntWidth::U64 => "U64",
+        IntWidth::U128 => "U128",
+        IntWidth::I8 => "I8",
+        IntWidth::I16 => "I16",
+        IntWidth::I32 => "I32",
+        IntWidth::I64 => "I64",
+        IntWidth::I128 => "I128",
+    }
+}
@@ -39,7 +39,8 @@ use crate::llvm::{
         list_replace_unsafe, list_reserve, list_sort_with, list_sublist, list_swap,
         list_symbol_to_c_abi, list_with_capacity, pass_update_mode,
     },
-    compare::{generic_eq, generic_neq},
+    build_str::str_compare,
+    compare::{build_num_compare, generic_compare, generic_eq, generic_neq},
     convert::{
         self, argument_type_from_layout, basic_type_from_layout, zig_num_parse_result_type,
         zig_to_int_checked_result_type,
@@ -220,178 +221,43 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 _ => unreachable!(),
             };
 
-            use roc_target::Architecture::*;
-            let result = match env.target_info.architecture {
-                Aarch32 | X86_32 => {
-                    let zig_function = env.module.get_function(intrinsic).unwrap();
-                    let zig_function_type = zig_function.get_type();
-
-                    match zig_function_type.get_return_type() {
-                        Some(_) => call_str_bitcode_fn(
-                            env,
-                            &[string],
-                            &[],
-                            BitcodeReturns::Basic,
-                            intrinsic,
-                        ),
-                        None => {
-                            let return_type_name = match layout_interner.get_repr(number_layout) {
-                                LayoutRepr::Builtin(Builtin::Int(int_width)) => {
-                                    int_width.type_name()
-                                }
-                                LayoutRepr::Builtin(Builtin::Decimal) => {
-                                    // zig picks 128 for dec.RocDec
-                                    "i128"
-                                }
-                                _ => unreachable!(),
-                            };
-
-                            let return_type = zig_num_parse_result_type(env, return_type_name);
-
-                            let zig_return_alloca = create_entry_block_alloca(
-                                env,
-                                parent,
-                                return_type.into(),
-                                "str_to_num",
-                            );
-
-                            let (a, b) =
-                                pass_list_or_string_to_zig_32bit(env, string.into_struct_value());
-
-                            call_void_bitcode_fn(
-                                env,
-                                &[zig_return_alloca.into(), a.into(), b.into()],
-                                intrinsic,
-                            );
-
-                            let roc_return_type = basic_type_from_layout(
-                                env,
-                                layout_interner,
-                                layout_interner.get_repr(layout),
-                            )
-                            .ptr_type(AddressSpace::default());
-
-                            let roc_return_alloca = env.builder.new_build_pointer_cast(
-                                zig_return_alloca,
-                                roc_return_type,
-                                "cast_to_roc",
-                            );
-
-                            load_roc_value(
-                                env,
-                                layout_interner,
-                                layout_interner.get_repr(layout),
-                                roc_return_alloca,
-                                "str_to_num_result",
-                            )
-                        }
-                    }
-                }
-                Aarch64 | X86_64 => {
-                    let (type_name, width) = {
-                        match layout_interner.get_repr(number_layout) {
-                            LayoutRepr::Builtin(Builtin::Int(int_width)) => {
-                                (int_width.type_name(), int_width.stack_size())
-                            }
-                            LayoutRepr::Builtin(Builtin::Decimal) => {
-                                // zig picks 128 for dec.RocDec
-                                ("i128", 16)
-                            }
-                            LayoutRepr::Builtin(Builtin::Float(float_width)) => {
-                                (float_width.type_name(), float_width.stack_size())
-                            }
-                            _ => {
-                                unreachable!("other layout types are non-numeric")
-                            }
-                        }
-                    };
-
-                    use roc_target::OperatingSystem::*;
-                    let cc_return_by_pointer = match env.target_info.operating_system {
-                        Windows => {
-                            // there is just one return register on Windows
-                            (width + 1) as usize > env.target_info.ptr_size()
-                        }
-                        _ => {
-                            // on other systems we have two return registers
-                            (width + 1) as usize > 2 * env.target_info.ptr_size()
-                        }
-                    };
-
-                    if cc_return_by_pointer {
-                        let bitcode_return_type = zig_num_parse_result_type(env, type_name);
-
-                        call_bitcode_fn_fixing_for_convention(
-                            env,
-                            layout_interner,
-                            bitcode_return_type,
-                            &[string],
-                            layout,
-                            intrinsic,
-                        )
-                    } else {
-                        call_bitcode_fn(env, &[string], intrinsic)
-                    }
-                }
-                Wasm32 => {
-                    let return_type_name = match layout_interner.get_repr(number_layout) {
-                        LayoutRepr::Builtin(Builtin::Float(float_width)) => float_width.type_name(),
-                        LayoutRepr::Builtin(Builtin::Int(int_width)) => int_width.type_name(),
-                        LayoutRepr::Builtin(Builtin::Decimal) => {
-                            // zig picks 128 for dec.RocDec
-                            "i128"
-                        }
-                        _ => unreachable!(),
-                    };
-
-                    let return_type = zig_num_parse_result_type(env, return_type_name);
-
-                    let zig_return_alloca =
-                        create_entry_block_alloca(env, parent, return_type.into(), "str_to_num");
-
-                    call_void_bitcode_fn(
-                        env,
-                        &[
-                            zig_return_alloca.into(),
-                            pass_string_to_zig_wasm(env, string).into(),
-                        ],
-                        intrinsic,
-                    );
-
-                    let roc_return_type = basic_type_from_layout(
-                        env,
-                        layout_interner,
-                        layout_interner.get_repr(layout),
-                    )
-                    .ptr_type(AddressSpace::default());
+            str_to_num(
+                env,
+                layout_interner,
+                parent,
+                layout,
+                number_layout,
+                intrinsic,
+                string,
+                &[],
+            )
+        }
+        StrToIntRadix => {
+            // strToIntRadix : Str, U8 -> { berrorcode : U8, aresult : Int * }
+            arguments!(string, radix);
 
-                    let roc_return_alloca = env.builder.new_build_pointer_cast(
-                        zig_return_alloca,
-                        roc_return_type,
-                        "cast_to_roc",
-                    );
+            let number_layout = match layout_interner.get_repr(layout) {
+                LayoutRepr::Struct(field_layouts) => field_layouts[0],
+                _ => unreachable!(),
+            };
 
-                    load_roc_value(
-                        env,
-                        layout_interner,
-                        layout_interner.get_repr(layout),
-                        roc_return_alloca,
-                        "str_to_num_result",
-                    )
+            let intrinsic = match layout_interner.get_repr(number_layout) {
+                LayoutRepr::Builtin(Builtin::Int(int_width)) => {
+                    &bitcode::STR_TO_INT_RADIX[int_width]
                 }
+                _ => unreachable!(),
             };
 
-            // zig passes the result as a packed integer sometimes, instead of a struct. So we cast if needed.
-            // We check the type as expected in an argument position, since that is how we actually will use it.
-            let expected_type =
-                argument_type_from_layout(env, layout_interner, layout_interner.get_repr(layout));
-            let actual_type = result.get_type();
-
-            if expected_type != actual_type {
-                complex_bitcast_check_size(env, result, expected_type, "str_to_num_cast")
-            } else {
-                result
-            }
+            str_to_num(
+                env,
+                layout_interner,
+                parent,
+                layout,
+                number_layout,
+                intrinsic,
+                string,
+                &[radix],
+            )
         }
         StrFromInt => {
             // Str.fromInt : Int -> Str
@@ -457,7 +323,7 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                         bitcode::STR_FROM_UTF8_RANGE,
                     );
                 }
-                Aarch64 | X86_64 | Wasm32 => {
+                Aarch64 | Riscv64 | X86_64 | Wasm32 => {
                     arguments!(_list, start, count);
 
                     // we use the symbol here instead
@@ -695,6 +561,96 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 bitcode::STR_RELEASE_EXCESS_CAPACITY,
             )
         }
+        StrToUpper => {
+            // Str.toUpper : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_UPPER,
+            )
+        }
+        StrToLower => {
+            // Str.toLower : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_LOWER,
+            )
+        }
+        StrCaseInsensitiveEq => {
+            // Str.caseInsensitiveEq : Str, Str -> Bool
+            arguments!(string1, string2);
+
+            call_str_bitcode_fn(
+                env,
+                &[string1, string2],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_CASE_INSENSITIVE_EQ,
+            )
+        }
+        StrToNfc => {
+            // Str.toNfc : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFC,
+            )
+        }
+        StrToNfd => {
+            // Str.toNfd : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFD,
+            )
+        }
+        StrToUtf16 => {
+            // Str.toUtf16 : Str -> List U16
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::List,
+                bitcode::STR_TO_UTF16,
+            )
+        }
+        StrFromUtf16Lossy => {
+            // Str.fromUtf16Lossy : List U16 -> Str
+            arguments!(list);
+
+            call_list_bitcode_fn(
+                env,
+                &[list.into_struct_value()],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_FROM_UTF16_LOSSY,
+            )
+        }
+        StrCompare => {
+            // Str.compareUtf8 : Str, Str -> [LT, EQ, GT]
+            arguments!(string1, string2);
+
+            str_compare(env, string1, string2)
+        }
         StrAppendScalar => {
             // Str.appendScalar : Str, U32 -> Str
             arguments!(string, capacity);
@@ -994,7 +950,54 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                         &bitcode::STR_FROM_FLOAT[float_width],
                     )
                 }
-                LayoutRepr::Builtin(Builtin::Decimal) => dec_to_str(env, num),
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    dec_to_str(env, bitcode::DEC_TO_STR, num, &[])
+                }
+                _ => unreachable!(),
+            }
+        }
+        NumToStrRadix => {
+            // Num.toStrRadixPadded : Int *, U8, U8 -> Str
+            arguments_with_layouts!((num, num_layout), (radix, _l1), (min_digits, _l2));
+
+            match layout_interner.get_repr(num_layout) {
+                LayoutRepr::Builtin(Builtin::Int(int_width)) => call_str_bitcode_fn(
+                    env,
+                    &[],
+                    &[num, radix, min_digits],
+                    BitcodeReturns::Str,
+                    &bitcode::STR_FROM_INT_RADIX[int_width],
+                ),
+                _ => unreachable!(),
+            }
+        }
+        NumToStrDecimals | NumToStrScientific => {
+            // Num.toStrDecimals : Frac *, U8 -> Str
+            // Num.toStrScientific : Frac *, U8 -> Str
+            arguments_with_layouts!((num, num_layout), (decimals, _l));
+
+            let (float_intrinsic, dec_intrinsic) = match op {
+                NumToStrDecimals => (
+                    &bitcode::STR_FROM_FLOAT_DECIMALS,
+                    bitcode::DEC_TO_STR_DECIMALS,
+                ),
+                _ => (
+                    &bitcode::STR_FROM_FLOAT_SCIENTIFIC,
+                    bitcode::DEC_TO_STR_SCIENTIFIC,
+                ),
+            };
+
+            match layout_interner.get_repr(num_layout) {
+                LayoutRepr::Builtin(Builtin::Float(float_width)) => call_str_bitcode_fn(
+                    env,
+                    &[],
+                    &[num, decimals],
+                    BitcodeReturns::Str,
+                    &float_intrinsic[float_width],
+                ),
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    dec_to_str(env, dec_intrinsic, num, &[decimals])
+                }
                 _ => unreachable!(),
             }
         }
@@ -1111,7 +1114,6 @@ pub(crate) fn run_low_level<'a, 'ctx>(
         NumCompare => {
             arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, rhs_layout));
 
-            use inkwell::FloatPredicate;
             match (
                 layout_interner.get_repr(lhs_layout),
                 layout_interner.get_repr(rhs_layout),
@@ -1121,81 +1123,9 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 {
                     use roc_mono::layout::Builtin::*;
 
-                    let tag_eq = env.context.i8_type().const_int(0_u64, false);
-                    let tag_gt = env.context.i8_type().const_int(1_u64, false);
-                    let tag_lt = env.context.i8_type().const_int(2_u64, false);
-
                     match lhs_builtin {
-                        Int(int_width) => {
-                            let are_equal = env.builder.new_build_int_compare(
-                                IntPredicate::EQ,
-                                lhs_arg.into_int_value(),
-                                rhs_arg.into_int_value(),
-                                "int_eq",
-                            );
-
-                            let predicate = if int_width.is_signed() {
-                                IntPredicate::SLT
-                            } else {
-                                IntPredicate::ULT
-                            };
-
-                            let is_less_than = env.builder.new_build_int_compare(
-                                predicate,
-                                lhs_arg.into_int_value(),
-                                rhs_arg.into_int_value(),
-                                "int_compare",
-                            );
-
-                            let step1 = env.builder.new_build_select(
-                                is_less_than,
-                                tag_lt,
-                                tag_gt,
-                                "lt_or_gt",
-                            );
-
-                            env.builder.new_build_select(
-                                are_equal,
-                                tag_eq,
-                                step1.into_int_value(),
-                                "lt_or_gt",
-                            )
-                        }
-                        Float(_) => {
-                            let are_equal = env.builder.new_build_float_compare(
-                                FloatPredicate::OEQ,
-                                lhs_arg.into_float_value(),
-                                rhs_arg.into_float_value(),
-                                "float_eq",
-                            );
-                            let is_less_than = env.builder.new_build_float_compare(
-                                FloatPredicate::OLT,
-                                lhs_arg.into_float_value(),
-                                rhs_arg.into_float_value(),
-                                "float_compare",
-                            );
-
-                            let step1 = env.builder.new_build_select(
-                                is_less_than,
-                                tag_lt,
-                                tag_gt,
-                                "lt_or_gt",
-                            );
-
-                            env.builder.new_build_select(
-                                are_equal,
-                                tag_eq,
-                                step1.into_int_value(),
-                                "lt_or_gt",
-                            )
-                        }
-                        Decimal => {
-                            //
-                            call_bitcode_fn(
-                                env,
-                                &[lhs_arg, rhs_arg],
-                                &bitcode::NUM_COMPARE[IntWidth::I128],
-                            )
+                        Int(_) | Float(_) | Decimal => {
+                            build_num_compare(env, lhs_arg, rhs_arg, lhs_builtin)
                         }
 
                         _ => {
@@ -1364,6 +1294,11 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 rhs_layout,
             )
         }
+        Compare => {
+            arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, _rhs_layout));
+
+            generic_compare(env, layout_interner, lhs_arg, rhs_arg, lhs_layout)
+        }
         And => {
             // The (&&) operator
             arguments!(lhs_arg, rhs_arg);
@@ -2104,45 +2039,227 @@ fn dec_alloca<'ctx>(env: &Env<'_, 'ctx, '_>, value: IntValue<'ctx>) -> BasicValu
     }
 }
 
-fn dec_to_str<'ctx>(env: &Env<'_, 'ctx, '_>, dec: BasicValueEnum<'ctx>) -> BasicValueEnum<'ctx> {
+/// Calls a zig function that parses a number out of a string, and returns a
+/// `{ berrorcode : U8, aresult : Num * }` struct. Any arguments after the string
+/// are passed along as they are.
+#[allow(clippy::too_many_arguments)]
+fn str_to_num<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    layout: InLayout<'a>,
+    number_layout: InLayout<'a>,
+    intrinsic: &str,
+    string: BasicValueEnum<'ctx>,
+    extra_args: &[BasicValueEnum<'ctx>],
+) -> BasicValueEnum<'ctx> {
+    use roc_target::Architecture::*;
+
+    let string_and_args = [&[string], extra_args].concat();
+
+    let result = match env.target_info.architecture {
+        Aarch32 | X86_32 => {
+            let zig_function = env.module.get_function(intrinsic).unwrap();
+            let zig_function_type = zig_function.get_type();
+
+            match zig_function_type.get_return_type() {
+                Some(_) => call_str_bitcode_fn(
+                    env,
+                    &[string],
+                    extra_args,
+                    BitcodeReturns::Basic,
+                    intrinsic,
+                ),
+                None => {
+                    let return_type_name = match layout_interner.get_repr(number_layout) {
+                        LayoutRepr::Builtin(Builtin::Int(int_width)) => int_width.type_name(),
+                        LayoutRepr::Builtin(Builtin::Decimal) => {
+                            // zig picks 128 for dec.RocDec
+                            "i128"
+                        }
+                        _ => unreachable!(),
+                    };
+
+                    let return_type = zig_num_parse_result_type(env, return_type_name);
+
+                    let zig_return_alloca =
+                        create_entry_block_alloca(env, parent, return_type.into(), "str_to_num");
+
+                    let (a, b) = pass_list_or_string_to_zig_32bit(env, string.into_struct_value());
+
+                    call_void_bitcode_fn(
+                        env,
+                        &[&[zig_return_alloca.into(), a.into(), b.into()], extra_args].concat(),
+                        intrinsic,
+                    );
+
+                    let roc_return_type = basic_type_from_layout(
+                        env,
+                        layout_interner,
+                        layout_interner.get_repr(layout),
+                    )
+                    .ptr_type(AddressSpace::default());
+
+                    let roc_return_alloca = env.builder.new_build_pointer_cast(
+                        zig_return_alloca,
+                        roc_return_type,
+                        "cast_to_roc",
+                    );
+
+                    load_roc_value(
+                        env,
+                        layout_interner,
+                        layout_interner.get_repr(layout),
+                        roc_return_alloca,
+                        "str_to_num_result",
+                    )
+                }
+            }
+        }
+        Aarch64 | Riscv64 | X86_64 => {
+            let (type_name, width) = {
+                match layout_interner.get_repr(number_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(int_width)) => {
+                        (int_width.type_name(), int_width.stack_size())
+                    }
+                    LayoutRepr::Builtin(Builtin::Decimal) => {
+                        // zig picks 128 for dec.RocDec
+                        ("i128", 16)
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                        (float_width.type_name(), float_width.stack_size())
+                    }
+                    _ => {
+                        unreachable!("other layout types are non-numeric")
+                    }
+                }
+            };
+
+            use roc_target::OperatingSystem::*;
+            let cc_return_by_pointer = match env.target_info.operating_system {
+                Windows => {
+                    // there is just one return register on Windows
+                    (width + 1) as usize > env.target_info.ptr_size()
+                }
+                _ => {
+                    // on other systems we have two return registers
+                    (width + 1) as usize > 2 * env.target_info.ptr_size()
+                }
+            };
+
+            if cc_return_by_pointer {
+                let bitcode_return_type = zig_num_parse_result_type(env, type_name);
+
+                call_bitcode_fn_fixing_for_convention(
+                    env,
+                    layout_interner,
+                    bitcode_return_type,
+                    &string_and_args,
+                    layout,
+                    intrinsic,
+                )
+            } else {
+                call_bitcode_fn(env, &string_and_args, intrinsic)
+            }
+        }
+        Wasm32 => {
+            let return_type_name = match layout_interner.get_repr(number_layout) {
+                LayoutRepr::Builtin(Builtin::Float(float_width)) => float_width.type_name(),
+                LayoutRepr::Builtin(Builtin::Int(int_width)) => int_width.type_name(),
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    // zig picks 128 for dec.RocDec
+                    "i128"
+                }
+                _ => unreachable!(),
+            };
+
+            let return_type = zig_num_parse_result_type(env, return_type_name);
+
+            let zig_return_alloca =
+                create_entry_block_alloca(env, parent, return_type.into(), "str_to_num");
+
+            call_void_bitcode_fn(
+                env,
+                &[
+                    &[
+                        zig_return_alloca.into(),
+                        pass_string_to_zig_wasm(env, string).into(),
+                    ],
+                    extra_args,
+                ]
+                .concat(),
+                intrinsic,
+            );
+
+            let roc_return_type =
+                basic_type_from_layout(env, layout_interner, layout_interner.get_repr(layout))
+                    .ptr_type(AddressSpace::default());
+
+            let roc_return_alloca = env.builder.new_build_pointer_cast(
+                zig_return_alloca,
+                roc_return_type,
+                "cast_to_roc",
+            );
+
+            load_roc_value(
+                env,
+                layout_interner,
+                layout_interner.get_repr(layout),
+                roc_return_alloca,
+                "str_to_num_result",
+            )
+        }
+    };
+
+    // zig passes the result as a packed integer sometimes, instead of a struct. So we cast if needed.
+    // We check the type as expected in an argument position, since that is how we actually will use it.
+    let expected_type =
+        argument_type_from_layout(env, layout_interner, layout_interner.get_repr(layout));
+    let actual_type = result.get_type();
+
+    if expected_type != actual_type {
+        complex_bitcast_check_size(env, result, expected_type, "str_to_num_cast")
+    } else {
+        result
+    }
+}
+
+/// Calls a zig function that formats a `Dec` as a string, passing the dec
+/// however the target's C calling convention expects it.
+fn dec_to_str<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    fn_name: &str,
+    dec: BasicValueEnum<'ctx>,
+    other_args: &[BasicValueEnum<'ctx>],
+) -> BasicValueEnum<'ctx> {
     use roc_target::Architecture::*;
     use roc_target::OperatingSystem::*;
 
     let dec = dec.into_int_value();
 
-    match env.target_info {
+    let dec_args = match env.target_info {
         TargetInfo {
             architecture: X86_64 | X86_32,
             operating_system: Unix,
         } => {
             let (low, high) = dec_split_into_words(env, dec);
 
-            call_str_bitcode_fn(
-                env,
-                &[],
-                &[low.into(), high.into()],
-                BitcodeReturns::Str,
-                bitcode::DEC_TO_STR,
-            )
+            vec![low.into(), high.into()]
         }
         TargetInfo {
             architecture: Wasm32,
             operating_system: Unix,
-        } => call_str_bitcode_fn(
-            env,
-            &[],
-            &[dec.into()],
-            BitcodeReturns::Str,
-            bitcode::DEC_TO_STR,
-        ),
-        _ => call_str_bitcode_fn(
-            env,
-            &[],
-            &[dec_alloca(env, dec)],
-            BitcodeReturns::Str,
-            bitcode::DEC_TO_STR,
-        ),
-    }
+        } => vec![dec.into()],
+        _ => vec![dec_alloca(env, dec)],
+    };
+
+    call_str_bitcode_fn(
+        env,
+        &[],
+        &[&dec_args, other_args].concat(),
+        BitcodeReturns::Str,
+        fn_name,
+    )
 }
 
 fn dec_unary_op<'ctx>(
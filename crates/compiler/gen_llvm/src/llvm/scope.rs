@@ -5,15 +5,18 @@ use inkwell::{
 use roc_collections::ImMap;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_mono::{
-    ir::{JoinPointId, Param, ProcLayout},
+    ir::{JoinPointId, Param, ProcLayout, ProcRegions},
     layout::InLayout,
 };
+use roc_region::all::Region;
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Scope<'a, 'ctx> {
     symbols: ImMap<Symbol, (InLayout<'a>, BasicValueEnum<'ctx>)>,
     top_level_thunks: ImMap<Symbol, (ProcLayout<'a>, FunctionValue<'ctx>)>,
     join_points: ImMap<JoinPointId, (BasicBlock<'ctx>, Vec<PhiValue<'ctx>>)>,
+    /// Source regions of the proc being built, for debug info
+    regions: ProcRegions<'a>,
 }
 
 #[derive(Debug)]
@@ -49,6 +52,18 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
             .insert(symbol, (layout, function_value));
     }
 
+    pub fn set_regions(&mut self, regions: ProcRegions<'a>) {
+        self.regions = regions;
+    }
+
+    pub fn symbol_region(&self, symbol: Symbol) -> Option<Region> {
+        self.regions.symbol_region(symbol)
+    }
+
+    pub fn call_region(&self, symbol: Symbol) -> Option<Region> {
+        self.regions.call_region(symbol)
+    }
+
     pub fn remove(&mut self, symbol: &Symbol) {
         self.symbols.remove(symbol);
     }
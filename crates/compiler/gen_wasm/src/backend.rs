@@ -5,16 +5,17 @@ use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
-use roc_module::symbol::{Interns, Symbol};
+use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::{CodeGenHelp, HelperOp, REFCOUNT_MAX};
 use roc_mono::ir::{
     BranchInfo, CallType, CrashTag, Expr, JoinPointId, ListLiteralElement, Literal, ModifyRc,
-    Param, Proc, ProcLayout, Stmt,
+    Param, Proc, ProcLayout, ProcRegions, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
     TagIdIntType, UnionLayout,
 };
+use roc_region::all::Region;
 use roc_std::RocDec;
 
 use roc_wasm_module::linking::{DataSymbol, WasmObjectSymbol};
@@ -31,8 +32,8 @@ use crate::layout::{ReturnMethod, WasmLayout};
 use crate::low_level::{call_higher_order_lowlevel, LowLevelCall};
 use crate::storage::{AddressValue, Storage, StoredValue, StoredVarKind};
 use crate::{
-    copy_memory, CopyMemoryConfig, Env, DEBUG_SETTINGS, MEMORY_NAME, PTR_SIZE, PTR_TYPE,
-    TARGET_INFO,
+    copy_memory, zero_memory, CopyMemoryConfig, Env, DEBUG_SETTINGS, MEMORY_NAME, PTR_SIZE,
+    PTR_TYPE, TARGET_INFO,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -74,6 +75,9 @@ pub struct WasmBackend<'a, 'r> {
     /// how many blocks deep are we (used for jumps)
     block_depth: u32,
     joinpoint_label_map: MutMap<JoinPointId, (u32, Vec<'a, StoredValue>)>,
+
+    /// Where the current proc and its named symbols were defined, for debug info
+    proc_regions: ProcRegions<'a>,
 }
 
 impl<'a, 'r> WasmBackend<'a, 'r> {
@@ -114,6 +118,13 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             )
         }
 
+        // Source files for the DWARF line table
+        let debug_files = env
+            .debug_sources
+            .paths()
+            .map(|path| &*env.arena.alloc_str(path));
+        module.dwarf.files.extend(debug_files);
+
         let import_fn_count = module.import.function_count();
         let host_function_count = import_fn_count
             + module.code.dead_import_dummy_count as usize
@@ -140,8 +151,9 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             // Function-level data
             block_depth: 0,
             joinpoint_label_map: MutMap::default(),
-            code_builder: CodeBuilder::new(env.arena),
+            code_builder: CodeBuilder::new(env.arena, env.features),
             storage: Storage::new(env.arena),
+            proc_regions: ProcRegions::default(),
         }
     }
 
@@ -340,7 +352,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena],
-            ret_type: None,
+            ret_types: bumpalo::vec![in self.env.arena],
         });
 
         self.module.export.append(Export {
@@ -394,9 +406,17 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         self.code_builder.clear();
         self.storage.clear();
         self.joinpoint_label_map.clear();
+        self.proc_regions = ProcRegions::default();
         assert_eq!(self.block_depth, 0);
     }
 
+    /// Map the code we generate next to a region of the source, in the DWARF line table
+    fn set_source_location(&mut self, module_id: ModuleId, region: Region) {
+        if let Some(location) = self.env.debug_sources.location(module_id, region) {
+            self.code_builder.set_source_location(location);
+        }
+    }
+
     /**********************************************************
 
             PROCEDURE
@@ -424,16 +444,21 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
     fn start_proc(&mut self, proc: &Proc<'a>) {
         use ReturnMethod::*;
-        let ret_layout = WasmLayout::new(self.layout_interner, proc.ret_layout);
 
-        let ret_type = match ret_layout.return_method() {
-            Primitive(ty, _) => Some(ty),
-            NoReturnValue => None,
-            WriteToPointerArg => {
-                self.storage.arg_types.push(PTR_TYPE);
-                None
-            }
+        self.proc_regions = proc.regions;
+        self.set_source_location(proc.name.name().module_id(), proc.regions.body);
+
+        let ret_layout = WasmLayout::new(self.layout_interner, proc.ret_layout);
+        let ret_method = self.proc_return_method(proc.name.name(), &ret_layout);
+
+        let mut ret_types = Vec::with_capacity_in(2, self.env.arena);
+        match ret_method {
+            Primitive(ty, _) => ret_types.push(ty),
+            NoReturnValue => {}
+            WriteToPointerArg => self.storage.arg_types.push(PTR_TYPE),
+            MultiValue(types) => ret_types.extend_from_slice(types),
         };
+        self.storage.return_method = ret_method;
 
         // Create a block so we can exit the function without skipping stack frame "pop" code.
         // We never use the `return` instruction. Instead, we break from this block.
@@ -446,23 +471,33 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             self.env.arena,
         );
 
-        if let Some(ty) = ret_type {
-            let ret_var = self.storage.create_anonymous_local(ty);
-            self.storage.return_var = Some(ret_var);
+        for ty in ret_types.iter() {
+            let ret_var = self.storage.create_anonymous_local(*ty);
+            self.storage.return_vars.push(ret_var);
         }
 
         self.module.add_function_signature(Signature {
             param_types: self.storage.arg_types.clone(),
-            ret_type,
+            ret_types,
         });
     }
 
+    /// How a Roc proc returns its value. With the multi-value feature, small structs are returned
+    /// as values, except from procs that the host calls, since it expects them in memory.
+    fn proc_return_method(&self, proc_name: Symbol, ret_layout: &WasmLayout) -> ReturnMethod {
+        if self.env.features.multi_value && !self.env.exposed_to_host.contains(&proc_name) {
+            ret_layout.multi_value_return_method()
+        } else {
+            ret_layout.return_method()
+        }
+    }
+
     fn finalize_proc(&mut self) {
         // end the block from start_proc, to ensure all paths pop stack memory (if any)
         self.end_block();
 
-        if let Some(ret_var) = self.storage.return_var {
-            self.code_builder.get_local(ret_var);
+        for ret_var in self.storage.return_vars.iter() {
+            self.code_builder.get_local(*ret_var);
         }
 
         // Write local declarations and stack frame push/pop code
@@ -514,6 +549,12 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             ..
         } = self.proc_lookup[wrapper_lookup_idx];
         let wrapper_arg_layouts = wrapper_proc_layout.arguments;
+        let inner_name = self.proc_lookup[inner_lookup_idx].name;
+
+        // The wrapper doesn't have any Roc symbols, but we may need locals for multiple return values
+        self.storage
+            .arg_types
+            .extend(std::iter::repeat(I32).take(wrapper_arg_layouts.len()));
 
         // Our convention is that the last arg of the wrapper is the heap return pointer
         let heap_return_ptr_id = LocalId(wrapper_arg_layouts.len() as u32 - 1);
@@ -524,8 +565,9 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             Some(LayoutRepr::Ptr(inner)) => WasmLayout::new(self.layout_interner, inner),
             x => internal_error!("Higher-order wrapper: invalid return layout {:?}", x),
         };
+        let inner_ret_method = self.proc_return_method(inner_name, &inner_ret_layout);
 
-        let ret_type_and_size = match inner_ret_layout.return_method() {
+        let ret_type_and_size = match inner_ret_method {
             ReturnMethod::NoReturnValue => None,
             ReturnMethod::Primitive(ty, size) => {
                 // If the inner function returns a primitive, load the address to store it at
@@ -538,6 +580,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 self.code_builder.get_local(heap_return_ptr_id);
                 None
             }
+            ReturnMethod::MultiValue(_) => None, // stored after the call
         };
 
         // Load all the arguments for the inner function
@@ -600,12 +643,23 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             }
         }
 
-        // Write empty function header (local variables array with zero length)
-        self.code_builder.build_fn_header_and_footer(&[], 0, None);
+        if let (
+            ReturnMethod::MultiValue(types),
+            WasmLayout::StackMemory {
+                alignment_bytes, ..
+            },
+        ) = (inner_ret_method, &inner_ret_layout)
+        {
+            self.store_multi_value_result(types, heap_return_ptr_id, 0, *alignment_bytes);
+        }
+
+        // Write the function header (no local variables, unless we stored multiple return values)
+        self.code_builder
+            .build_fn_header_and_footer(&self.storage.local_types, 0, None);
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena; I32; wrapper_arg_layouts.len()],
-            ret_type: None,
+            ret_types: bumpalo::vec![in self.env.arena],
         });
 
         self.append_proc_debug_name(wrapper_name);
@@ -655,7 +709,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena; I32; 3],
-            ret_type: Some(ValueType::I32),
+            ret_types: bumpalo::vec![in self.env.arena; I32],
         });
 
         self.append_proc_debug_name(wrapper_name);
@@ -703,7 +757,10 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         match stmt {
             Stmt::Let(_, _, _, _) => self.stmt_let(stmt),
 
-            Stmt::Ret(sym) => self.stmt_ret(*sym),
+            Stmt::Ret(sym, region) => {
+                self.set_source_location(sym.module_id(), *region);
+                self.stmt_ret(*sym)
+            }
 
             Stmt::Switch {
                 cond_symbol,
@@ -711,16 +768,24 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 branches,
                 default_branch,
                 ret_layout: _,
-            } => self.stmt_switch(*cond_symbol, *cond_layout, branches, default_branch),
+                region,
+            } => {
+                self.set_source_location(cond_symbol.module_id(), *region);
+                self.stmt_switch(*cond_symbol, *cond_layout, branches, default_branch)
+            }
 
             Stmt::Join {
                 id,
                 parameters,
                 body,
                 remainder,
+                region: _,
             } => self.stmt_join(*id, parameters, body, remainder),
 
-            Stmt::Jump(id, arguments) => self.stmt_jump(*id, arguments),
+            Stmt::Jump(id, arguments, region) => {
+                self.set_source_location(id.0.module_id(), *region);
+                self.stmt_jump(*id, arguments)
+            }
 
             Stmt::Refcounting(modify, following) => match modify {
                 ModifyRc::Free(symbol) => self.stmt_refcounting_free(*symbol, following),
@@ -761,8 +826,13 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 print!("\nlet {:?} = {}", sym, expr.to_pretty(200, true));
             }
 
+            let opt_region = self.proc_regions.symbol_region(*sym);
+            if let Some(region) = opt_region.or_else(|| self.proc_regions.call_region(*sym)) {
+                self.set_source_location(sym.module_id(), region);
+            }
+
             let kind = match following {
-                Stmt::Ret(ret_sym) if *sym == *ret_sym => StoredVarKind::ReturnValue,
+                Stmt::Ret(ret_sym, _) if *sym == *ret_sym => StoredVarKind::ReturnValue,
                 _ => StoredVarKind::Variable,
             };
 
@@ -806,17 +876,35 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             } => {
                 let (from_ptr, from_offset) =
                     location.local_and_offset(self.storage.stack_frame_pointer);
-                copy_memory(
-                    &mut self.code_builder,
-                    CopyMemoryConfig {
-                        from_ptr,
-                        from_offset,
-                        to_ptr: LocalId(0),
-                        to_offset: 0,
-                        size: *size,
-                        alignment_bytes: *alignment_bytes,
-                    },
-                );
+
+                if let ReturnMethod::MultiValue(types) = self.storage.return_method {
+                    // Load each value from stack memory, and store it to its return variable
+                    let mut offset = from_offset;
+                    for (ty, ret_var) in types.iter().zip(self.storage.return_vars.iter()) {
+                        let size = multi_value_size(*ty);
+                        let align = Align::from(size.min(*alignment_bytes));
+                        self.code_builder.get_local(from_ptr);
+                        if *ty == ValueType::I64 {
+                            self.code_builder.i64_load(align, offset);
+                        } else {
+                            self.code_builder.i32_load(align, offset);
+                        }
+                        self.code_builder.set_local(*ret_var);
+                        offset += size;
+                    }
+                } else {
+                    copy_memory(
+                        &mut self.code_builder,
+                        CopyMemoryConfig {
+                            from_ptr,
+                            from_offset,
+                            to_ptr: LocalId(0),
+                            to_offset: 0,
+                            size: *size,
+                            alignment_bytes: *alignment_bytes,
+                        },
+                    );
+                }
             }
 
             _ => {
@@ -824,8 +912,8 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
                 // If we have a return value, store it to the return variable
                 // This avoids complications with block result types when returning from nested blocks
-                if let Some(ret_var) = self.storage.return_var {
-                    self.code_builder.set_local(ret_var);
+                if let Some(ret_var) = self.storage.return_vars.first() {
+                    self.code_builder.set_local(*ret_var);
                 }
             }
         }
@@ -881,6 +969,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                         self.code_builder.f64_const(f64::from_bits(*value));
                         self.code_builder.f64_eq();
                     }
+                    ValueType::V128 => internal_error!("Cannot switch on a v128"),
                 }
             }
 
@@ -1360,12 +1449,17 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             return self.expr_call_low_level(lowlevel, arguments, ret_sym, ret_layout, ret_storage);
         }
 
-        self.storage.load_symbols_for_call(
-            &mut self.code_builder,
-            arguments,
-            ret_sym,
-            &wasm_layout,
-        );
+        let ret_method = self.proc_return_method(func_sym, &wasm_layout);
+        if let ReturnMethod::MultiValue(_) = ret_method {
+            self.storage.load_symbols(&mut self.code_builder, arguments);
+        } else {
+            self.storage.load_symbols_for_call(
+                &mut self.code_builder,
+                arguments,
+                ret_sym,
+                &wasm_layout,
+            );
+        }
 
         let roc_proc_index = self
             .proc_lookup
@@ -1383,6 +1477,47 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         let wasm_fn_index = self.fn_index_offset + roc_proc_index as u32;
 
         self.code_builder.call(wasm_fn_index);
+
+        if let (
+            ReturnMethod::MultiValue(types),
+            StoredValue::StackMemory {
+                location,
+                alignment_bytes,
+                ..
+            },
+        ) = (ret_method, ret_storage)
+        {
+            let (to_ptr, to_offset) = location.local_and_offset(self.storage.stack_frame_pointer);
+            self.store_multi_value_result(types, to_ptr, to_offset, *alignment_bytes);
+        }
+    }
+
+    /// Store the results of a call that returned multiple values, from the VM stack to memory
+    fn store_multi_value_result(
+        &mut self,
+        types: &[ValueType],
+        to_ptr: LocalId,
+        to_offset: u32,
+        alignment_bytes: u32,
+    ) {
+        // The last value is on top of the VM stack, so store them in reverse order.
+        // Wasm stores need the address under the value, so go via a local variable.
+        let mut offset = to_offset + types.iter().copied().map(multi_value_size).sum::<u32>();
+        for ty in types.iter().rev() {
+            let size = multi_value_size(*ty);
+            let align = Align::from(size.min(alignment_bytes));
+            offset -= size;
+
+            let value = self.storage.create_anonymous_local(*ty);
+            self.code_builder.set_local(value);
+            self.code_builder.get_local(to_ptr);
+            self.code_builder.get_local(value);
+            if *ty == ValueType::I64 {
+                self.code_builder.i64_store(align, offset);
+            } else {
+                self.code_builder.i32_store(align, offset);
+            }
+        }
     }
 
     fn expr_call_low_level(
@@ -1458,6 +1593,38 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         );
     }
 
+    pub fn call_compare_specialized(
+        &mut self,
+        arguments: &'a [Symbol],
+        arg_layout: InLayout<'a>,
+        ret_symbol: Symbol,
+        ret_storage: &StoredValue,
+    ) {
+        let ident_ids = self
+            .interns
+            .all_ident_ids
+            .get_mut(&self.env.module_id)
+            .unwrap();
+
+        // Get an IR expression for the call to the specialized procedure
+        let (specialized_call_expr, new_specializations) = self
+            .helper_proc_gen
+            .call_specialized_compare(ident_ids, self.layout_interner, arg_layout, arguments);
+
+        // If any new specializations were created, register their symbol data
+        for (spec_sym, spec_layout) in new_specializations.into_iter() {
+            self.register_helper_proc(spec_sym, spec_layout, ProcSource::Helper);
+        }
+
+        // Generate Wasm code for the IR call expression
+        self.expr(
+            ret_symbol,
+            self.env.arena.alloc(specialized_call_expr),
+            Layout::U8,
+            ret_storage,
+        );
+    }
+
     /*******************************************************************
      * Structs
      *******************************************************************/
@@ -1623,12 +1790,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
             // Store 12 bytes of zeros { elements: null, length: 0, capacity: 0 }
             debug_assert_eq!(Builtin::LIST_WORDS, 3);
-            self.code_builder.get_local(local_id);
-            self.code_builder.i64_const(0);
-            self.code_builder.i64_store(Align::Bytes4, offset);
-            self.code_builder.get_local(local_id);
-            self.code_builder.i32_const(0);
-            self.code_builder.i32_store(Align::Bytes4, offset + 8);
+            zero_memory(&mut self.code_builder, local_id, offset, 12, PTR_SIZE);
         } else {
             internal_error!("Unexpected storage for {:?}", sym)
         }
@@ -2133,3 +2295,12 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         self.fn_index_offset + proc_index as u32
     }
 }
+
+/// Size in memory of one of the values in a multi-value return
+fn multi_value_size(ty: ValueType) -> u32 {
+    match ty {
+        ValueType::I32 => 4,
+        ValueType::I64 => 8,
+        _ => internal_error!("Cannot return {:?} as one of multiple values", ty),
+    }
+}
@@ -5,7 +5,8 @@ use core::panic;
 use roc_wasm_module::linking::IndexRelocType;
 
 use roc_error_macros::internal_error;
-use roc_wasm_module::opcodes::{OpCode, OpCode::*};
+use roc_wasm_module::dwarf::SourceLocation;
+use roc_wasm_module::opcodes::{MemoryInstruction, OpCode, OpCode::*, SimdInstruction};
 use roc_wasm_module::serialize::SerialBuffer;
 use roc_wasm_module::{
     round_up_to_alignment, Align, LocalId, RelocationEntry, ValueType, WasmModule,
@@ -13,7 +14,7 @@ use roc_wasm_module::{
 };
 use std::iter::repeat;
 
-use crate::DEBUG_SETTINGS;
+use crate::{WasmFeatures, DEBUG_SETTINGS};
 
 macro_rules! log_instruction {
     ($($x: expr),+) => {
@@ -37,6 +38,14 @@ macro_rules! instruction_no_args {
     };
 }
 
+macro_rules! instruction_simd {
+    ($method_name: ident, $op: expr) => {
+        pub fn $method_name(&mut self) {
+            self.inst_simd($op);
+        }
+    };
+}
+
 macro_rules! instruction_memargs {
     ($method_name: ident, $opcode: expr) => {
         pub fn $method_name(&mut self, align: Align, offset: u32) {
@@ -76,13 +85,20 @@ pub struct CodeBuilder<'a> {
 
     /// Keep track of which local variables have been set
     set_locals: BitVec<u32>,
+
+    /// Source locations of the code that follows each position in `code`, for debug info
+    source_locations: Vec<'a, (usize, SourceLocation)>,
+
+    /// Post-MVP instructions we're allowed to generate
+    features: WasmFeatures,
 }
 
 #[allow(clippy::new_without_default)]
 impl<'a> CodeBuilder<'a> {
-    pub fn new(arena: &'a Bump) -> Self {
+    pub fn new(arena: &'a Bump, features: WasmFeatures) -> Self {
         CodeBuilder {
             arena,
+            features,
             code: Vec::with_capacity_in(1024, arena),
             insertions: Vec::with_capacity_in(32, arena),
             insert_bytes: Vec::with_capacity_in(64, arena),
@@ -90,9 +106,14 @@ impl<'a> CodeBuilder<'a> {
             inner_length: Vec::with_capacity_in(5, arena),
             import_relocations: Vec::with_capacity_in(0, arena),
             set_locals: BitVec::with_capacity(64),
+            source_locations: Vec::with_capacity_in(0, arena),
         }
     }
 
+    pub fn features(&self) -> WasmFeatures {
+        self.features
+    }
+
     pub fn clear(&mut self) {
         self.code.clear();
         self.insertions.clear();
@@ -101,6 +122,7 @@ impl<'a> CodeBuilder<'a> {
         self.inner_length.clear();
         self.import_relocations.clear();
         self.set_locals.clear();
+        self.source_locations.clear();
     }
 
     /**********************************************************
@@ -257,6 +279,44 @@ impl<'a> CodeBuilder<'a> {
                 symbol_index,
             });
         }
+
+        // Map source locations to offsets from the start of the function body (after its length).
+        // Insertions at the same position store values computed earlier, so they go before the row.
+        // The location at the very start also covers the local declarations and stack frame setup.
+        if !self.source_locations.is_empty() {
+            let mut rows = Vec::with_capacity_in(self.source_locations.len(), self.arena);
+            let mut insertions = self.insertions.iter().peekable();
+            let mut insertion_bytes = 0;
+            for (code_pos, location) in self.source_locations.iter() {
+                while let Some(insertion) = insertions.next_if(|ins| ins.at <= *code_pos) {
+                    insertion_bytes += insertion.end - insertion.start;
+                }
+                let offset = if *code_pos == 0 {
+                    0
+                } else {
+                    self.preamble.len() + code_pos + insertion_bytes
+                };
+                rows.push((offset as u32, *location));
+            }
+
+            let fn_index = module.import.function_count() as u32
+                + module.code.dead_import_dummy_count
+                + module.code.function_count
+                - 1;
+            module.dwarf.append_function(fn_index, rows);
+        }
+    }
+
+    /// Record that the next instructions were generated from this source location
+    pub fn set_source_location(&mut self, location: SourceLocation) {
+        let code_pos = self.code.len();
+        match self.source_locations.last_mut() {
+            Some((_, last_location)) if *last_location == location => {}
+            Some((last_pos, last_location)) if *last_pos == code_pos => {
+                *last_location = location;
+            }
+            _ => self.source_locations.push((code_pos, location)),
+        }
     }
 
     /**********************************************************
@@ -301,6 +361,23 @@ impl<'a> CodeBuilder<'a> {
         log_instruction!("{:10} {:?} {}", format!("{opcode:?}"), align, offset);
     }
 
+    /// Instruction from the SIMD proposal, without immediates
+    fn inst_simd(&mut self, op: SimdInstruction) {
+        debug_assert!(self.features.simd, "SIMD instructions are not enabled");
+        self.inst_base(SIMD);
+        self.code.encode_u32(op as u32);
+        log_instruction!("{op:?}");
+    }
+
+    fn inst_simd_mem(&mut self, op: SimdInstruction, align: Align, offset: u32) {
+        debug_assert!(self.features.simd, "SIMD instructions are not enabled");
+        self.inst_base(SIMD);
+        self.code.encode_u32(op as u32);
+        self.code.push(align as u8);
+        self.code.encode_u32(offset);
+        log_instruction!("{:10} {:?} {}", format!("{op:?}"), align, offset);
+    }
+
     /**********************************************************
 
         INSTRUCTION METHODS
@@ -424,6 +501,21 @@ impl<'a> CodeBuilder<'a> {
         self.inst(GROWMEMORY);
         self.code.push(0);
     }
+    pub fn memory_copy(&mut self) {
+        debug_assert!(self.features.bulk_memory, "Bulk memory is not enabled");
+        self.inst_base(MEMORY);
+        self.code.encode_u32(MemoryInstruction::MemoryCopy as u32);
+        self.code.push(0); // destination memory index
+        self.code.push(0); // source memory index
+        log_instruction!("{:?}", MemoryInstruction::MemoryCopy);
+    }
+    pub fn memory_fill(&mut self) {
+        debug_assert!(self.features.bulk_memory, "Bulk memory is not enabled");
+        self.inst_base(MEMORY);
+        self.code.encode_u32(MemoryInstruction::MemoryFill as u32);
+        self.code.push(0); // memory index
+        log_instruction!("{:?}", MemoryInstruction::MemoryFill);
+    }
 
     fn log_const<T>(&self, opcode: OpCode, x: T)
     where
@@ -577,4 +669,22 @@ impl<'a> CodeBuilder<'a> {
     instruction_no_args!(i64_reinterpret_f64, I64REINTERPRETF64);
     instruction_no_args!(f32_reinterpret_i32, F32REINTERPRETI32);
     instruction_no_args!(f64_reinterpret_i64, F64REINTERPRETI64);
+
+    pub fn v128_load(&mut self, align: Align, offset: u32) {
+        self.inst_simd_mem(SimdInstruction::V128Load, align, offset);
+    }
+    pub fn v128_store(&mut self, align: Align, offset: u32) {
+        self.inst_simd_mem(SimdInstruction::V128Store, align, offset);
+    }
+
+    instruction_simd!(i8x16_splat, SimdInstruction::I8x16Splat);
+    instruction_simd!(i8x16_eq, SimdInstruction::I8x16Eq);
+    instruction_simd!(i8x16_ne, SimdInstruction::I8x16Ne);
+    instruction_simd!(v128_not, SimdInstruction::V128Not);
+    instruction_simd!(v128_and, SimdInstruction::V128And);
+    instruction_simd!(v128_or, SimdInstruction::V128Or);
+    instruction_simd!(v128_xor, SimdInstruction::V128Xor);
+    instruction_simd!(v128_any_true, SimdInstruction::V128AnyTrue);
+    instruction_simd!(i8x16_all_true, SimdInstruction::I8x16AllTrue);
+    instruction_simd!(i8x16_bitmask, SimdInstruction::I8x16Bitmask);
 }
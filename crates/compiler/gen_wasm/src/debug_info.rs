@@ -0,0 +1,58 @@
+//! Source locations for the DWARF line table of the Wasm module.
+//!
+//! Each Roc proc is mapped to the start of its body, and each named `let` in it to the line of
+//! its def. Procs that the compiler generates (refcounting, equality, etc.) have no regions,
+//! and neither does anything when the [DebugSources] of the [Env](crate::Env) are empty.
+use std::path::PathBuf;
+
+use roc_collections::all::MutMap;
+use roc_module::symbol::ModuleId;
+use roc_region::all::{LineColumn, LineInfo, Region};
+use roc_wasm_module::dwarf::SourceLocation;
+
+/// The files (and line tables) of the modules whose procs we emit debug locations for.
+#[derive(Default)]
+pub struct DebugSources {
+    files: MutMap<ModuleId, (u32, LineInfo)>,
+    paths: Vec<String>,
+}
+
+impl DebugSources {
+    pub fn new(sources: &MutMap<ModuleId, (PathBuf, Box<str>)>) -> Self {
+        // Sort by path, so that file indices don't depend on hash map order
+        let mut sorted: Vec<_> = sources.iter().collect();
+        sorted.sort_by(|(_, (a, _)), (_, (b, _))| a.cmp(b));
+
+        let mut files = MutMap::default();
+        let mut paths = Vec::with_capacity(sorted.len());
+
+        for (module_id, (path, src)) in sorted {
+            files.insert(*module_id, (paths.len() as u32, LineInfo::new(src)));
+            paths.push(path.to_string_lossy().into_owned());
+        }
+
+        Self { files, paths }
+    }
+
+    /// Paths of the source files, in the order of the file indices in a [SourceLocation]
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.paths.iter().map(String::as_str)
+    }
+
+    /// The file and 1-based line and column of a region in the given module, if we have its source.
+    pub(crate) fn location(&self, module_id: ModuleId, region: Region) -> Option<SourceLocation> {
+        if region.is_empty() {
+            // generated code, or a def we don't know the region of
+            return None;
+        }
+
+        let (file, line_info) = self.files.get(&module_id)?;
+        let LineColumn { line, column } = line_info.convert_region(region).start;
+
+        Some(SourceLocation {
+            file: *file,
+            line: line + 1,
+            column: column + 1,
+        })
+    }
+}
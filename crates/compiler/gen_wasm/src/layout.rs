@@ -1,5 +1,5 @@
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
-use roc_error_macros::todo_lambda_erasure;
+use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_mono::layout::{InLayout, LayoutInterner, LayoutRepr, STLayoutInterner, UnionLayout};
 
 use crate::{PTR_SIZE, PTR_TYPE};
@@ -13,6 +13,9 @@ pub enum ReturnMethod {
     WriteToPointerArg,
     /// This layout is empty and requires no return value or argument (e.g. refcount helpers)
     NoReturnValue,
+    /// This small struct is loaded from stack memory and returned as one or two numbers
+    /// (Only with the multi-value feature)
+    MultiValue(&'static [ValueType]),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -114,6 +117,7 @@ impl WasmLayout {
             Self::Primitive(I64, _) => &[I64],
             Self::Primitive(F32, _) => &[F32],
             Self::Primitive(F64, _) => &[F64],
+            Self::Primitive(V128, _) => internal_error!("No Roc layout is a Wasm v128"),
 
             // 1 Roc argument => 0-2 Wasm arguments (depending on size and calling convention)
             Self::StackMemory { size, format, .. } => stack_memory_arg_types(*size, *format),
@@ -126,6 +130,21 @@ impl WasmLayout {
             Self::StackMemory { size, format, .. } => stack_memory_return_method(*size, *format),
         }
     }
+
+    /// Like `return_method`, but returning small structs as values rather than writing to a pointer
+    pub fn multi_value_return_method(&self) -> ReturnMethod {
+        match self {
+            Self::StackMemory {
+                size,
+                format: StackMemoryFormat::DataStructure,
+                ..
+            } => match multi_value_return_types(*size) {
+                Some(types) => ReturnMethod::MultiValue(types),
+                None => self.return_method(),
+            },
+            _ => self.return_method(),
+        }
+    }
 }
 
 /// The Wasm argument types to use when passing structs or 128-bit numbers
@@ -147,6 +166,20 @@ pub fn stack_memory_arg_types(size: u32, format: StackMemoryFormat) -> &'static
     }
 }
 
+/// The values to return a struct as, when we have the multi-value feature.
+/// We load them from stack memory as whole words, so the size must be a multiple of 4.
+fn multi_value_return_types(size: u32) -> Option<&'static [ValueType]> {
+    use ValueType::*;
+
+    match size {
+        4 => Some(&[I32]),
+        8 => Some(&[I64]),
+        12 => Some(&[I64, I32]),
+        16 => Some(&[I64, I64]),
+        _ => None,
+    }
+}
+
 pub fn stack_memory_return_method(size: u32, format: StackMemoryFormat) -> ReturnMethod {
     use ReturnMethod::*;
     use StackMemoryFormat::*;
@@ -1,6 +1,7 @@
 //! Provides the WASM backend to generate Roc binaries.
 mod backend;
 mod code_builder;
+mod debug_info;
 mod layout;
 mod low_level;
 mod storage;
@@ -24,6 +25,7 @@ use roc_wasm_module::{Align, LocalId, ValueType, WasmModule};
 
 use crate::backend::{ProcLookupData, ProcSource, WasmBackend};
 use crate::code_builder::CodeBuilder;
+pub use crate::debug_info::DebugSources;
 
 const TARGET_INFO: TargetInfo = TargetInfo::default_wasm32();
 const PTR_SIZE: u32 = {
@@ -46,12 +48,35 @@ pub struct Env<'a> {
     pub module_id: ModuleId,
     pub exposed_to_host: MutSet<Symbol>,
     pub stack_bytes: u32,
+    pub debug_sources: DebugSources,
+    pub features: WasmFeatures,
 }
 
 impl Env<'_> {
     pub const DEFAULT_STACK_BYTES: u32 = 1024 * 1024;
 }
 
+/// Post-MVP Wasm features that the generated code may use. All of them are off by default,
+/// because not every runtime supports them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WasmFeatures {
+    /// Return small structs as multiple values, rather than through a pointer to stack memory.
+    /// Only applies to procs that are not exposed to the host.
+    pub multi_value: bool,
+    /// Use `memory.copy` and `memory.fill` for larger copies and zeroing
+    pub bulk_memory: bool,
+    /// Use 128-bit vectors to compare the bytes of strings
+    pub simd: bool,
+}
+
+impl WasmFeatures {
+    pub const ALL: Self = Self {
+        multi_value: true,
+        bulk_memory: true,
+        simd: true,
+    };
+}
+
 /// Parse the preprocessed host binary
 /// If successful, the module can be passed to build_app_binary
 pub fn parse_host<'a>(arena: &'a Bump, host_bytes: &[u8]) -> Result<WasmModule<'a>, ParseError> {
@@ -199,6 +224,10 @@ pub struct CopyMemoryConfig {
     alignment_bytes: u32,
 }
 
+/// Copies or zeroing at least this big use bulk memory instructions, if enabled.
+/// Below this, a few loads and stores are smaller than the pointer arithmetic.
+const BULK_MEMORY_MIN_BYTES: u32 = 32;
+
 pub fn copy_memory(code_builder: &mut CodeBuilder, config: CopyMemoryConfig) {
     if config.from_ptr == config.to_ptr && config.from_offset == config.to_offset {
         return;
@@ -207,6 +236,14 @@ pub fn copy_memory(code_builder: &mut CodeBuilder, config: CopyMemoryConfig) {
         return;
     }
 
+    if code_builder.features().bulk_memory && config.size >= BULK_MEMORY_MIN_BYTES {
+        push_address(code_builder, config.to_ptr, config.to_offset);
+        push_address(code_builder, config.from_ptr, config.from_offset);
+        code_builder.i32_const(config.size as i32);
+        code_builder.memory_copy();
+        return;
+    }
+
     let alignment = Align::from(config.alignment_bytes);
     let mut i = 0;
     while config.size - i >= 8 {
@@ -232,6 +269,53 @@ pub fn copy_memory(code_builder: &mut CodeBuilder, config: CopyMemoryConfig) {
     }
 }
 
+/// Write zeros to `size` bytes of memory at `ptr + offset`
+pub fn zero_memory(
+    code_builder: &mut CodeBuilder,
+    ptr: LocalId,
+    offset: u32,
+    size: u32,
+    alignment_bytes: u32,
+) {
+    if code_builder.features().bulk_memory && size >= BULK_MEMORY_MIN_BYTES {
+        push_address(code_builder, ptr, offset);
+        code_builder.i32_const(0);
+        code_builder.i32_const(size as i32);
+        code_builder.memory_fill();
+        return;
+    }
+
+    let alignment = Align::from(alignment_bytes);
+    let mut i = 0;
+    while size - i >= 8 {
+        code_builder.get_local(ptr);
+        code_builder.i64_const(0);
+        code_builder.i64_store(alignment, i + offset);
+        i += 8;
+    }
+    if size - i >= 4 {
+        code_builder.get_local(ptr);
+        code_builder.i32_const(0);
+        code_builder.i32_store(alignment, i + offset);
+        i += 4;
+    }
+    while size - i > 0 {
+        code_builder.get_local(ptr);
+        code_builder.i32_const(0);
+        code_builder.i32_store8(alignment, i + offset);
+        i += 1;
+    }
+}
+
+/// Bulk memory instructions have no offset immediate, so we have to add it to the pointer
+fn push_address(code_builder: &mut CodeBuilder, ptr: LocalId, offset: u32) {
+    code_builder.get_local(ptr);
+    if offset != 0 {
+        code_builder.i32_const(offset as i32);
+        code_builder.i32_add();
+    }
+}
+
 pub struct WasmDebugSettings {
     proc_start_end: bool,
     user_procs_ir: bool,
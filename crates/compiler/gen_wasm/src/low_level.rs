@@ -10,6 +10,7 @@ use roc_mono::layout::{Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, Un
 use roc_mono::low_level::HigherOrder;
 
 use crate::backend::{ProcLookupData, ProcSource, WasmBackend};
+use crate::code_builder::CodeBuilder;
 use crate::layout::{StackMemoryFormat, WasmLayout};
 use crate::storage::{AddressValue, StackMemoryLocation, StoredValue};
 use crate::PTR_TYPE;
@@ -74,6 +75,7 @@ impl From<ValueType> for CodeGenNumType {
             ValueType::I64 => CodeGenNumType::I64,
             ValueType::F32 => CodeGenNumType::F32,
             ValueType::F64 => CodeGenNumType::F64,
+            ValueType::V128 => internal_error!("Roc has no v128 numbers"),
         }
     }
 }
@@ -199,11 +201,23 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => internal_error!("invalid storage for Str"),
             },
-            StrStartsWith => self.load_args_and_call_zig(backend, bitcode::STR_STARTS_WITH),
+            StrStartsWith => {
+                if backend.code_builder.features().simd {
+                    str_compare_simd(backend, self.arguments, StrComparison::StartsWith);
+                } else {
+                    self.load_args_and_call_zig(backend, bitcode::STR_STARTS_WITH);
+                }
+            }
             StrStartsWithScalar => {
                 self.load_args_and_call_zig(backend, bitcode::STR_STARTS_WITH_SCALAR)
             }
-            StrEndsWith => self.load_args_and_call_zig(backend, bitcode::STR_ENDS_WITH),
+            StrEndsWith => {
+                if backend.code_builder.features().simd {
+                    str_compare_simd(backend, self.arguments, StrComparison::EndsWith);
+                } else {
+                    self.load_args_and_call_zig(backend, bitcode::STR_ENDS_WITH);
+                }
+            }
             StrSplit => self.load_args_and_call_zig(backend, bitcode::STR_SPLIT),
             StrCountGraphemes => {
                 self.load_args_and_call_zig(backend, bitcode::STR_COUNT_GRAPEHEME_CLUSTERS)
@@ -231,6 +245,25 @@ impl<'a> LowLevelCall<'a> {
 
                 self.load_args_and_call_zig(backend, intrinsic);
             }
+            StrToIntRadix => {
+                let number_layout = match backend.layout_interner.get_repr(self.ret_layout) {
+                    LayoutRepr::Struct(field_layouts) => field_layouts[0],
+                    _ => {
+                        internal_error!(
+                            "Unexpected mono layout {:?} for StrToIntRadix",
+                            self.ret_layout
+                        )
+                    }
+                };
+                let intrinsic = match backend.layout_interner.get_repr(number_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(int_width)) => {
+                        &bitcode::STR_TO_INT_RADIX[int_width]
+                    }
+                    rest => internal_error!("Unexpected layout {:?} for StrToIntRadix", rest),
+                };
+
+                self.load_args_and_call_zig(backend, intrinsic);
+            }
             StrFromInt => self.num_to_str(backend),
             StrFromFloat => self.num_to_str(backend),
             StrFromUtf8Range => {
@@ -273,6 +306,18 @@ impl<'a> LowLevelCall<'a> {
             }
             StrWithCapacity => self.load_args_and_call_zig(backend, bitcode::STR_WITH_CAPACITY),
             StrGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEMES),
+            StrToUpper => self.load_args_and_call_zig(backend, bitcode::STR_TO_UPPER),
+            StrToLower => self.load_args_and_call_zig(backend, bitcode::STR_TO_LOWER),
+            StrCaseInsensitiveEq => {
+                self.load_args_and_call_zig(backend, bitcode::STR_CASE_INSENSITIVE_EQ)
+            }
+            StrToNfc => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFC),
+            StrToNfd => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFD),
+            StrToUtf16 => self.load_args_and_call_zig(backend, bitcode::STR_TO_UTF16),
+            StrFromUtf16Lossy => {
+                self.load_args_and_call_zig(backend, bitcode::STR_FROM_UTF16_LOSSY)
+            }
+            StrCompare => self.load_args_and_call_zig(backend, bitcode::STR_COMPARE),
 
             // List
             ListLen => match backend.storage.get(&self.arguments[0]) {
@@ -825,6 +870,38 @@ impl<'a> LowLevelCall<'a> {
             },
 
             NumToStr => self.num_to_str(backend),
+            NumToStrRadix => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        self.load_args_and_call_zig(backend, &bitcode::STR_FROM_INT_RADIX[width])
+                    }
+                    x => internal_error!("NumToStrRadix is not defined for {:?}", x),
+                }
+            }
+            NumToStrDecimals | NumToStrScientific => {
+                let (float_intrinsic, dec_intrinsic) = match self.lowlevel {
+                    NumToStrDecimals => (
+                        &bitcode::STR_FROM_FLOAT_DECIMALS,
+                        bitcode::DEC_TO_STR_DECIMALS,
+                    ),
+                    _ => (
+                        &bitcode::STR_FROM_FLOAT_SCIENTIFIC,
+                        bitcode::DEC_TO_STR_SCIENTIFIC,
+                    ),
+                };
+
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Float(width)) => {
+                        self.load_args_and_call_zig(backend, &float_intrinsic[width])
+                    }
+                    LayoutRepr::Builtin(Builtin::Decimal) => {
+                        self.load_args_and_call_zig(backend, dec_intrinsic)
+                    }
+                    x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+                }
+            }
             NumAddChecked => {
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
                 match backend.layout_interner.get_repr(arg_layout) {
@@ -1183,59 +1260,7 @@ impl<'a> LowLevelCall<'a> {
                     }
                 }
             }
-            NumCompare => {
-                let layout = backend.storage.symbol_layouts[&self.arguments[0]];
-                let is_signed = layout_is_signed_int(layout);
-
-                // This implements the expression:
-                //            (x != y) as u8 + (x < y) as u8
-                // For x==y:  (false as u8)  + (false as u8) = 0 = RocOrder::Eq
-                // For x>y:   (true as u8)   + (false as u8) = 1 = RocOrder::Gt
-                // For x<y:   (true as u8)   + (true as u8)  = 2 = RocOrder::Lt
-                // u8 is represented in the stack machine as i32, but written to memory as 1 byte
-                match CodeGenNumType::from(layout) {
-                    I32 => {
-                        self.load_args(backend);
-                        backend.code_builder.i32_ne();
-                        self.load_args(backend);
-                        if is_signed {
-                            backend.code_builder.i32_lt_s()
-                        } else {
-                            backend.code_builder.i32_lt_u()
-                        }
-                        backend.code_builder.i32_add();
-                    }
-                    I64 => {
-                        self.load_args(backend);
-                        backend.code_builder.i64_ne();
-                        self.load_args(backend);
-                        if is_signed {
-                            backend.code_builder.i64_lt_s()
-                        } else {
-                            backend.code_builder.i64_lt_u()
-                        }
-                        backend.code_builder.i32_add();
-                    }
-                    F32 => {
-                        self.load_args(backend);
-                        backend.code_builder.f32_ne();
-                        self.load_args(backend);
-                        backend.code_builder.f32_lt();
-                        backend.code_builder.i32_add();
-                    }
-                    F64 => {
-                        self.load_args(backend);
-                        backend.code_builder.f64_ne();
-                        self.load_args(backend);
-                        backend.code_builder.f64_lt();
-                        backend.code_builder.i32_add();
-                    }
-                    I128 | Decimal => {
-                        self.load_args(backend);
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_COMPARE[IntWidth::I128]);
-                    }
-                }
-            }
+            NumCompare => self.num_compare(backend),
             NumDivFrac => {
                 self.load_args(backend);
                 match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
@@ -2068,6 +2093,7 @@ impl<'a> LowLevelCall<'a> {
             Hash => todo!("{:?}", self.lowlevel),
 
             Eq | NotEq => self.eq_or_neq(backend),
+            Compare => self.compare(backend),
 
             BoxExpr | UnboxExpr => {
                 unreachable!("The {:?} operation is turned into mono Expr", self.lowlevel)
@@ -2079,6 +2105,7 @@ impl<'a> LowLevelCall<'a> {
                     ValueType::I64 => backend.code_builder.i64_const(0),
                     ValueType::F32 => backend.code_builder.f32_const(0.0),
                     ValueType::F64 => backend.code_builder.f64_const(0.0),
+                    ValueType::V128 => internal_error!("Roc has no v128 values"),
                 },
                 StoredValue::StackMemory { .. } => { /* do nothing */ }
             },
@@ -2090,6 +2117,119 @@ impl<'a> LowLevelCall<'a> {
         }
     }
 
+    /// The `[LT, EQ, GT]` ordering of two numbers, as its tag id
+    fn num_compare(&self, backend: &mut WasmBackend<'a, '_>) {
+        use CodeGenNumType::*;
+
+        let layout = backend.storage.symbol_layouts[&self.arguments[0]];
+        let is_signed = layout_is_signed_int(layout);
+
+        // This implements the expression:
+        //            (x != y) as u8 + (x < y) as u8
+        // For x==y:  (false as u8)  + (false as u8) = 0 = RocOrder::Eq
+        // For x>y:   (true as u8)   + (false as u8) = 1 = RocOrder::Gt
+        // For x<y:   (true as u8)   + (true as u8)  = 2 = RocOrder::Lt
+        // u8 is represented in the stack machine as i32, but written to memory as 1 byte
+        match CodeGenNumType::from(layout) {
+            I32 => {
+                self.load_args(backend);
+                backend.code_builder.i32_ne();
+                self.load_args(backend);
+                if is_signed {
+                    backend.code_builder.i32_lt_s()
+                } else {
+                    backend.code_builder.i32_lt_u()
+                }
+                backend.code_builder.i32_add();
+            }
+            I64 => {
+                self.load_args(backend);
+                backend.code_builder.i64_ne();
+                self.load_args(backend);
+                if is_signed {
+                    backend.code_builder.i64_lt_s()
+                } else {
+                    backend.code_builder.i64_lt_u()
+                }
+                backend.code_builder.i32_add();
+            }
+            F32 => {
+                self.load_args(backend);
+                backend.code_builder.f32_ne();
+                self.load_args(backend);
+                backend.code_builder.f32_lt();
+                backend.code_builder.i32_add();
+            }
+            F64 => {
+                self.load_args(backend);
+                backend.code_builder.f64_ne();
+                self.load_args(backend);
+                backend.code_builder.f64_lt();
+                backend.code_builder.i32_add();
+            }
+            I128 | Decimal => {
+                self.load_args(backend);
+                self.load_args_and_call_zig(backend, &bitcode::NUM_COMPARE[IntWidth::I128]);
+            }
+        }
+    }
+
+    /// Structural ordering
+    /// Like equality, this can operate on any data type (except functions).
+    fn compare(&self, backend: &mut WasmBackend<'a, '_>) {
+        let arg_layout = backend
+            .layout_interner
+            .runtime_representation_in(backend.storage.symbol_layouts[&self.arguments[0]]);
+
+        match backend.layout_interner.get_repr(arg_layout) {
+            LayoutRepr::Builtin(
+                Builtin::Int(_) | Builtin::Float(_) | Builtin::Bool | Builtin::Decimal,
+            ) => self.num_compare(backend),
+
+            LayoutRepr::Builtin(Builtin::Str) => {
+                self.load_args_and_call_zig(backend, bitcode::STR_COMPARE)
+            }
+
+            // Empty records and void values are always equal, so just emit EQ (tag id 0).
+            LayoutRepr::Struct(field_layouts) if field_layouts.is_empty() => {
+                backend.code_builder.i32_const(0);
+            }
+            LayoutRepr::Union(UnionLayout::NonRecursive(tags)) if tags.is_empty() => {
+                backend.code_builder.i32_const(0);
+            }
+
+            LayoutRepr::Builtin(Builtin::List(_))
+            | LayoutRepr::Struct { .. }
+            | LayoutRepr::Union(_)
+            | LayoutRepr::Ptr(_) => {
+                // Don't want Zig calling convention here, we're calling internal Roc functions
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, self.arguments);
+
+                backend.call_compare_specialized(
+                    self.arguments,
+                    arg_layout,
+                    self.ret_symbol,
+                    &self.ret_storage,
+                );
+            }
+
+            LayoutRepr::LambdaSet(_) => {
+                internal_error!("Tried to compare functions {:?}", self.arguments)
+            }
+            LayoutRepr::RecursivePointer(_) => {
+                internal_error!(
+                    "Tried to compare RecursivePointer values {:?}",
+                    self.arguments,
+                )
+            }
+
+            LayoutRepr::FunctionPointer(_) => todo_lambda_erasure!(),
+            LayoutRepr::Erased(_) => todo_lambda_erasure!(),
+        }
+    }
+
     /// Equality and inequality
     /// These can operate on any data type (except functions) so they're more complex than other operators.
     fn eq_or_neq(&self, backend: &mut WasmBackend<'a, '_>) {
@@ -2113,7 +2253,11 @@ impl<'a> LowLevelCall<'a> {
             ) => self.eq_or_neq_number(backend),
 
             LayoutRepr::Builtin(Builtin::Str) => {
-                self.load_args_and_call_zig(backend, bitcode::STR_EQUAL);
+                if backend.code_builder.features().simd {
+                    str_compare_simd(backend, self.arguments, StrComparison::Equal);
+                } else {
+                    self.load_args_and_call_zig(backend, bitcode::STR_EQUAL);
+                }
                 if invert_result {
                     backend.code_builder.i32_eqz();
                 }
@@ -2177,12 +2321,14 @@ impl<'a> LowLevelCall<'a> {
                         ValueType::I64 => backend.code_builder.i64_eq(),
                         ValueType::F32 => backend.code_builder.f32_eq(),
                         ValueType::F64 => backend.code_builder.f64_eq(),
+                        ValueType::V128 => internal_error!("Roc has no v128 values"),
                     },
                     LowLevel::NotEq => match value_type {
                         ValueType::I32 => backend.code_builder.i32_ne(),
                         ValueType::I64 => backend.code_builder.i64_ne(),
                         ValueType::F32 => backend.code_builder.f32_ne(),
                         ValueType::F64 => backend.code_builder.f64_ne(),
+                        ValueType::V128 => internal_error!("Roc has no v128 values"),
                     },
                     _ => internal_error!("{:?} ended up in Equality code", self.lowlevel),
                 }
@@ -2274,6 +2420,168 @@ impl<'a> LowLevelCall<'a> {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StrComparison {
+    Equal,
+    StartsWith,
+    EndsWith,
+}
+
+/// Compare the bytes of two strings 16 at a time, using SIMD instructions.
+/// This is faster than calling the Zig builtins, which compare one byte at a time.
+fn str_compare_simd(
+    backend: &mut WasmBackend<'_, '_>,
+    arguments: &[Symbol],
+    comparison: StrComparison,
+) {
+    use Align::*;
+
+    let (a_ptr, a_len) = str_bytes_and_len(backend, arguments[0]);
+    let (b_ptr, b_len) = str_bytes_and_len(backend, arguments[1]);
+    let remaining = backend.storage.create_anonymous_local(ValueType::I32);
+    let result = backend.storage.create_anonymous_local(ValueType::I32);
+    let cb = &mut backend.code_builder;
+
+    cb.i32_const(0);
+    cb.set_local(result);
+
+    // On any mismatch, break out of this block with a false result
+    cb.block();
+
+    // We compare all the bytes of the second string, so it can't be longer than the first
+    cb.get_local(b_len);
+    cb.get_local(a_len);
+    match comparison {
+        StrComparison::Equal => cb.i32_ne(),
+        StrComparison::StartsWith | StrComparison::EndsWith => cb.i32_gt_u(),
+    }
+    cb.br_if(0);
+
+    if comparison == StrComparison::EndsWith {
+        cb.get_local(a_ptr);
+        cb.get_local(a_len);
+        cb.get_local(b_len);
+        cb.i32_sub();
+        cb.i32_add();
+        cb.set_local(a_ptr);
+    }
+
+    cb.get_local(b_len);
+    cb.set_local(remaining);
+
+    // 16 bytes at a time
+    cb.block();
+    cb.loop_();
+    cb.get_local(remaining);
+    cb.i32_const(16);
+    cb.i32_lt_u();
+    cb.br_if(1);
+    cb.get_local(a_ptr);
+    cb.v128_load(Bytes1, 0);
+    cb.get_local(b_ptr);
+    cb.v128_load(Bytes1, 0);
+    cb.i8x16_eq();
+    cb.i8x16_all_true();
+    cb.i32_eqz();
+    cb.br_if(2);
+    advance_str_pointers(cb, a_ptr, b_ptr, remaining, 16);
+    cb.br(0);
+    cb.end();
+    cb.end();
+
+    // Then the last few bytes, one at a time
+    cb.block();
+    cb.loop_();
+    cb.get_local(remaining);
+    cb.i32_eqz();
+    cb.br_if(1);
+    cb.get_local(a_ptr);
+    cb.i32_load8_u(Bytes1, 0);
+    cb.get_local(b_ptr);
+    cb.i32_load8_u(Bytes1, 0);
+    cb.i32_ne();
+    cb.br_if(2);
+    advance_str_pointers(cb, a_ptr, b_ptr, remaining, 1);
+    cb.br(0);
+    cb.end();
+    cb.end();
+
+    cb.i32_const(1);
+    cb.set_local(result);
+    cb.end();
+
+    cb.get_local(result);
+}
+
+fn advance_str_pointers(
+    cb: &mut CodeBuilder,
+    a_ptr: LocalId,
+    b_ptr: LocalId,
+    remaining: LocalId,
+    bytes: i32,
+) {
+    for ptr in [a_ptr, b_ptr] {
+        cb.get_local(ptr);
+        cb.i32_const(bytes);
+        cb.i32_add();
+        cb.set_local(ptr);
+    }
+    cb.get_local(remaining);
+    cb.i32_const(bytes);
+    cb.i32_sub();
+    cb.set_local(remaining);
+}
+
+/// Store a pointer to the bytes of a Str, and its length, in new local variables
+fn str_bytes_and_len(backend: &mut WasmBackend<'_, '_>, symbol: Symbol) -> (LocalId, LocalId) {
+    use Align::*;
+
+    let (local_id, offset) = match backend.storage.get(&symbol) {
+        StoredValue::StackMemory { location, .. } => {
+            location.local_and_offset(backend.storage.stack_frame_pointer)
+        }
+        _ => internal_error!("A Str should be in stack memory"),
+    };
+    let is_small = backend.storage.create_anonymous_local(ValueType::I32);
+    let ptr = backend.storage.create_anonymous_local(PTR_TYPE);
+    let len = backend.storage.create_anonymous_local(ValueType::I32);
+    let cb = &mut backend.code_builder;
+
+    // A small string has a negative capacity, and its bytes are inside the struct itself
+    cb.get_local(local_id);
+    cb.i32_load(Bytes4, offset + 8);
+    cb.i32_const(0);
+    cb.i32_lt_s();
+    cb.set_local(is_small);
+
+    cb.get_local(local_id);
+    if offset != 0 {
+        cb.i32_const(offset as i32);
+        cb.i32_add();
+    }
+    cb.get_local(local_id);
+    cb.i32_load(Bytes4, offset);
+    cb.get_local(is_small);
+    cb.select();
+    cb.set_local(ptr);
+
+    // A small string's length is in its last byte, with the top bit set.
+    // A big string's top length bit marks a seamless slice.
+    cb.get_local(local_id);
+    cb.i32_load8_u(Bytes1, offset + 11);
+    cb.i32_const(0x80);
+    cb.i32_xor();
+    cb.get_local(local_id);
+    cb.i32_load(Bytes4, offset + 4);
+    cb.i32_const(0x7fff_ffff);
+    cb.i32_and();
+    cb.get_local(is_small);
+    cb.select();
+    cb.set_local(len);
+
+    (ptr, len)
+}
+
 /// Helper for NumIsNan op
 fn num_is_nan(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
     use StoredValue::*;
@@ -2286,6 +2594,7 @@ fn num_is_nan(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
             match value_type {
                 // Integers are never NaN. Just return False.
                 ValueType::I32 | ValueType::I64 => backend.code_builder.i32_const(0),
+                ValueType::V128 => internal_error!("Roc has no v128 numbers"),
                 ValueType::F32 => {
                     backend.code_builder.i32_reinterpret_f32();
                     backend.code_builder.i32_const(0x7f80_0000);
@@ -2349,6 +2658,7 @@ fn num_is_infinite(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
             match value_type {
                 // Integers are never infinite. Just return False.
                 ValueType::I32 | ValueType::I64 => backend.code_builder.i32_const(0),
+                ValueType::V128 => internal_error!("Roc has no v128 numbers"),
                 ValueType::F32 => {
                     backend.code_builder.i32_reinterpret_f32();
                     backend.code_builder.i32_const(0x7fff_ffff);
@@ -2392,6 +2702,7 @@ fn num_is_finite(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
             match value_type {
                 // Integers are always finite. Just return True.
                 ValueType::I32 | ValueType::I64 => backend.code_builder.i32_const(1),
+                ValueType::V128 => internal_error!("Roc has no v128 numbers"),
                 ValueType::F32 => {
                     backend.code_builder.i32_reinterpret_f32();
                     backend.code_builder.i32_const(0x7f80_0000);
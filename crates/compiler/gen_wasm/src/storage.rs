@@ -60,7 +60,9 @@ pub enum AddressValue {
 /// including the VM stack, local variables, and linear memory
 #[derive(Debug)]
 pub struct Storage<'a> {
-    pub return_var: Option<LocalId>,
+    pub return_method: ReturnMethod,
+    /// Locals to hold the return value(s) until the end of the function
+    pub return_vars: Vec<'a, LocalId>,
     pub arg_types: Vec<'a, ValueType>,
     pub local_types: Vec<'a, ValueType>,
     pub symbol_layouts: MutMap<Symbol, InLayout<'a>>,
@@ -72,7 +74,8 @@ pub struct Storage<'a> {
 impl<'a> Storage<'a> {
     pub fn new(arena: &'a Bump) -> Self {
         Storage {
-            return_var: None,
+            return_method: ReturnMethod::NoReturnValue,
+            return_vars: Vec::with_capacity_in(2, arena),
             arg_types: Vec::with_capacity_in(8, arena),
             local_types: Vec::with_capacity_in(32, arena),
             symbol_layouts: MutMap::default(),
@@ -83,7 +86,8 @@ impl<'a> Storage<'a> {
     }
 
     pub fn clear(&mut self) {
-        self.return_var = None;
+        self.return_method = ReturnMethod::NoReturnValue;
+        self.return_vars.clear();
         self.arg_types.clear();
         self.local_types.clear();
         self.symbol_layouts.clear();
@@ -158,13 +162,14 @@ impl<'a> Storage<'a> {
                 alignment_bytes,
                 format,
             } => {
-                let location = match kind {
-                    StoredVarKind::Variable => {
+                let location = match (kind, self.return_method) {
+                    // Multiple return values are loaded from the stack frame, there's no pointer arg
+                    (StoredVarKind::Variable, _) | (_, ReturnMethod::MultiValue(_)) => {
                         let offset = self.allocate_stack_memory(size, alignment_bytes);
                         StackMemoryLocation::FrameOffset(offset)
                     }
 
-                    StoredVarKind::ReturnValue => StackMemoryLocation::PointerArg(LocalId(0)),
+                    (StoredVarKind::ReturnValue, _) => StackMemoryLocation::PointerArg(LocalId(0)),
                 };
 
                 StoredValue::StackMemory {
@@ -16,6 +16,7 @@ use roc_wasm_module::{
 
 use crate::code_builder::CodeBuilder;
 use crate::wasm32_sized::Wasm32Sized;
+use crate::WasmFeatures;
 
 /// Type-driven wrapper generation
 pub trait Wasm32Result {
@@ -26,7 +27,7 @@ pub trait Wasm32Result {
         main_function_index: u32,
     ) {
         insert_wrapper_metadata(arena, module, wrapper_name);
-        let mut code_builder = CodeBuilder::new(arena);
+        let mut code_builder = CodeBuilder::new(arena, WasmFeatures::default());
         Self::build_wrapper_body(&mut code_builder, main_function_index);
         code_builder.insert_into_module(module);
     }
@@ -49,7 +50,7 @@ pub fn insert_wrapper_for_layout<'a>(
             <() as Wasm32Result>::insert_wrapper(arena, module, wrapper_name, main_fn_index);
         } else {
             insert_wrapper_metadata(arena, module, wrapper_name);
-            let mut code_builder = CodeBuilder::new(arena);
+            let mut code_builder = CodeBuilder::new(arena, WasmFeatures::default());
             build_wrapper_body_stack_memory(&mut code_builder, main_fn_index, size as usize);
             code_builder.insert_into_module(module);
         }
@@ -96,7 +97,7 @@ fn insert_wrapper_metadata<'a>(
 
     module.add_function_signature(Signature {
         param_types: Vec::with_capacity_in(0, arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in arena; ValueType::I32],
     });
 
     module.export.append(Export {
@@ -24,6 +24,7 @@ pub use roc_load_internal::file::{
 pub use roc_load_internal::module::{
     CheckedModule, EntryPoint, Expectations, ExposedToHost, LoadedModule, MonomorphizedModule,
 };
+pub use roc_mono::ir::OptLevel;
 pub use roc_solve::FunctionKind;
 
 #[allow(clippy::too_many_arguments)]
@@ -57,6 +58,7 @@ pub fn load_single_threaded<'a>(
     palette: Palette,
     roc_cache_dir: RocCacheDir<'_>,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let cached_subs = read_cached_types();
     let exposed_types = ExposedByModule::default();
@@ -71,6 +73,7 @@ pub fn load_single_threaded<'a>(
         render,
         palette,
         exec_mode,
+        opt_level,
         roc_cache_dir,
     )
 }
@@ -194,6 +197,7 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         roc_cache_dir,
         ExecutionMode::Check,
+        OptLevel::Development,
     )? {
         Monomorphized(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
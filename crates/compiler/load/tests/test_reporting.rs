@@ -13,12 +13,15 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
-    use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+    use roc_load::{
+        self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading,
+    };
     use roc_module::symbol::{Interns, ModuleId};
     use roc_packaging::cache::RocCacheDir;
     use roc_parse::module::parse_header;
     use roc_parse::state::State;
     use roc_parse::test_helpers::parse_expr_with;
+    use roc_problem::code;
     use roc_problem::Severity;
     use roc_region::all::LineInfo;
     use roc_reporting::report::{
@@ -41,6 +44,7 @@ mod test_reporting {
     fn to_simple_report(doc: RocDocBuilder) -> Report {
         Report {
             title: "".to_string(),
+            code: code::COMPILER_BUG,
             doc,
             filename: filename_from_string(r"/code/proj/Main.roc"),
             severity: Severity::RuntimeError,
@@ -129,6 +133,7 @@ mod test_reporting {
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
                 function_kind: FunctionKind::LambdaSet,
+                opt_level: OptLevel::Development,
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -421,6 +426,31 @@ mod test_reporting {
         assert_eq!(readable, expected_rendering);
     }
 
+    fn json_report_problem_as(src: &str, expected_rendering: &str) {
+        let arena = Bump::new();
+        let (type_problems, can_problems, home, interns) =
+            infer_expr_help(&arena, src).expect("parse error");
+
+        let mut buf = String::new();
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let filename = filename_from_string(r"/code/proj/Main.roc");
+
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+
+        for problem in can_problems {
+            can_problem(&alloc, &lines, filename.clone(), problem).render_json(&mut buf);
+        }
+
+        for problem in type_problems {
+            if let Some(report) = type_problem(&alloc, &lines, filename.clone(), problem) {
+                report.render_json(&mut buf);
+            }
+        }
+
+        assert_eq!(buf, expected_rendering);
+    }
+
     /// Do not call this directly! Use the test_report macro below!
     fn __new_report_problem_as(test_name: &str, src: &str, check_render: impl FnOnce(&str)) {
         let arena = Bump::new();
@@ -480,7 +510,7 @@ mod test_reporting {
         "#
         ),
         @r###"
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E0109] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The List module does not expose `isempty`:
 
@@ -507,7 +537,7 @@ mod test_reporting {
         "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `y` is not used anywhere in your code.
 
@@ -532,7 +562,7 @@ mod test_reporting {
        "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `i` name is first defined here:
 
@@ -564,7 +594,7 @@ mod test_reporting {
        "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `Booly` name is first defined here:
 
@@ -596,7 +626,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0112] ──────────────────────────────── /code/proj/Main.roc ─
 
     Using != and == together requires parentheses, to clarify how they
     should be grouped.
@@ -625,7 +655,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
         Nothing is named `bar` in this scope.
 
@@ -649,7 +679,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `true` in this scope.
 
@@ -681,7 +711,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0112] ──────────────────────────────── /code/proj/Main.roc ─
 
     Using more than one == like this requires parentheses, to clarify how
     things should be grouped.
@@ -707,7 +737,7 @@ mod test_reporting {
          "#
         ),
         @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E0103] ─────────────────────────────── /code/proj/Main.roc ─
 
     `box` doesn't use `htmlChildren`.
 
@@ -720,7 +750,7 @@ mod test_reporting {
     at the start of a variable name is a way of saying that the variable
     is not used.
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `y` is not used anywhere in your code.
 
@@ -804,7 +834,7 @@ mod test_reporting {
             ),
             indoc!(
                 r#"
-                <cyan>── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─<reset>
+                <cyan>── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─<reset>
 
                 Nothing is named `theAdmin` in this scope.
 
@@ -822,6 +852,87 @@ mod test_reporting {
         );
     }
 
+    #[test]
+    fn report_as_json() {
+        json_report_problem_as(
+            indoc!(
+                r#"
+                    x = 1
+
+                    if "foo" then 2 else 3
+                "#
+            ),
+            concat!(
+                r#"{"code":"E0101","title":"UNUSED DEFINITION","severity":"warning","file":"/code/proj/Main.roc","region":{"start":{"line":1,"column":1},"end":{"line":1,"column":2}},"secondary_regions":[],"message":"`x` is not used anywhere in your code.\n\n1│  x = 1\n    ^\n\nIf you didn't intend on using `x` then remove it so future readers of\nyour code don't wonder why it is there."}"#,
+                "\n",
+                r#"{"code":"E0301","title":"TYPE MISMATCH","severity":"runtime_error","file":"/code/proj/Main.roc","region":{"start":{"line":3,"column":4},"end":{"line":3,"column":9}},"secondary_regions":[],"message":"This `if` condition needs to be a Bool:\n\n3│  if \"foo\" then 2 else 3\n       ^^^^^\n\nRight now it’s a string of type:\n\n    Str\n\nBut I need every `if` condition to evaluate to a Bool—either `Bool.true`\nor `Bool.false`."}"#,
+                "\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn every_error_code_has_an_explanation() {
+        for &error_code in code::ALL {
+            assert!(
+                roc_reporting::explain::explanation(error_code).is_some(),
+                "{error_code} has no explanation"
+            );
+        }
+    }
+
+    test_report!(
+        lint_denied_for_def,
+        indoc!(
+            r#"
+            # lint: deny unused-def
+            x = 1
+
+            y = 2
+
+            3
+            "#
+        ),
+        @r###"
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
+
+    `x` is not used anywhere in your code.
+
+    5│      x = 1
+            ^
+
+    If you didn't intend on using `x` then remove it so future readers of
+    your code don't wonder why it is there.
+
+    Note: This is an error because the `unused-def` lint is set to `deny`.
+
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
+
+    `y` is not used anywhere in your code.
+
+    7│      y = 2
+            ^
+
+    If you didn't intend on using `y` then remove it so future readers of
+    your code don't wonder why it is there.
+    "###
+    );
+
+    test_report!(
+        lint_allowed_for_def,
+        indoc!(
+            r#"
+            # lint: allow unused-def, unused-argument
+            f = \a ->
+                b = 1
+                2
+
+            f 3
+            "#
+        ),
+        @""
+    );
+
     test_report!(
         if_condition_not_bool,
         indoc!(
@@ -830,7 +941,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` condition needs to be a Bool:
 
@@ -856,7 +967,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` guard condition needs to be a Bool:
 
@@ -881,7 +992,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -908,7 +1019,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 3rd branch of this `if` does not match all the previous branches:
 
@@ -938,7 +1049,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd branch of this `when` does not match all the previous branches:
 
@@ -976,7 +1087,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -1020,7 +1131,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This list contains elements with different types:
 
@@ -1047,7 +1158,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This list contains elements with different types:
 
@@ -1080,7 +1191,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     I cannot update the `.foo` field like this:
 
@@ -1110,7 +1221,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1135,7 +1246,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1161,7 +1272,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1174,7 +1285,7 @@ mod test_reporting {
 
         List ∞ -> *
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1201,7 +1312,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1237,7 +1348,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1271,7 +1382,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1297,7 +1408,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1335,7 +1446,7 @@ mod test_reporting {
         // against that extra variable, rather than possibly having to translate a `Type`
         // again.
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1348,7 +1459,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1375,7 +1486,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1388,7 +1499,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1416,7 +1527,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1450,7 +1561,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1487,7 +1598,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1524,7 +1635,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -1558,7 +1669,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -1591,7 +1702,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -1623,7 +1734,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The `x` value is not a function, but it was given 1 argument:
 
@@ -1645,7 +1756,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The `f` function expects 1 argument, but it got 2 instead:
 
@@ -1667,7 +1778,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO FEW ARGS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW ARGS [E0301] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `f` function expects 2 arguments, but it got only 1:
 
@@ -1688,7 +1799,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1717,7 +1828,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -1743,7 +1854,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1771,7 +1882,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1800,7 +1911,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `foo` in this scope.
 
@@ -1856,7 +1967,7 @@ mod test_reporting {
         ),
         // Just putting this here. We should probably handle or-patterns better
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this branch does not match the previous ones:
 
@@ -1884,7 +1995,7 @@ mod test_reporting {
         ),
         // Maybe this should specifically say the pattern doesn't work?
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1912,7 +2023,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of this definition:
 
@@ -1943,7 +2054,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer pattern is malformed:
 
@@ -1964,7 +2075,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float pattern is malformed:
 
@@ -1985,7 +2096,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer pattern is malformed:
 
@@ -2006,7 +2117,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer pattern is malformed:
 
@@ -2027,7 +2138,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer pattern is malformed:
 
@@ -2049,7 +2160,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -2099,7 +2210,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -2133,7 +2244,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2168,7 +2279,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2204,7 +2315,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `ok` in this scope.
 
@@ -2235,7 +2346,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `ok` is not used anywhere in your code.
 
@@ -2245,7 +2356,7 @@ mod test_reporting {
     If you didn't intend on using `ok` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2277,7 +2388,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR DEFINITION [E0111] ─────────────────────────── /code/proj/Main.roc ─
 
     `f` is defined directly in terms of itself:
 
@@ -2304,7 +2415,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR DEFINITION [E0111] ─────────────────────────── /code/proj/Main.roc ─
 
     The `foo` definition is causing a very tricky infinite loop:
 
@@ -2332,7 +2443,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2354,7 +2465,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2387,7 +2498,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `r` record doesn’t have a `foo` field:
 
@@ -2415,7 +2526,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2445,7 +2556,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to + has an unexpected type:
 
@@ -2470,7 +2581,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to + has an unexpected type:
 
@@ -2498,7 +2609,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to + has an unexpected type:
 
@@ -2526,7 +2637,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2560,7 +2671,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2603,7 +2714,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This pattern does not cover all the possibilities:
 
@@ -2618,7 +2729,7 @@ mod test_reporting {
     matching in function arguments, put a `when` in the function body to
     account for all possibilities.
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2655,7 +2766,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -2686,7 +2797,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2713,7 +2824,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2741,7 +2852,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2770,7 +2881,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2799,7 +2910,7 @@ mod test_reporting {
         ),
         // Tip: Looks like a record field guard is not exhaustive. Learn more about record pattern matches at TODO.
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2828,7 +2939,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2854,7 +2965,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2881,7 +2992,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern is redundant:
 
@@ -2895,6 +3006,199 @@ mod test_reporting {
     "###
     );
 
+    test_report!(
+        patterns_u8_redundant,
+        indoc!(
+            r#"
+            x : U8
+            x = 3
+
+            when x is
+                2 -> 0x3
+                2 -> 0x4
+                _ -> 0x5
+            "#
+        ),
+        @r###"
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
+
+    The 2nd pattern is redundant:
+
+     7│       when x is
+     8│           2 -> 0x3
+     9│>          2 -> 0x4
+    10│           _ -> 0x5
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "###
+    );
+
+    test_report!(
+        patterns_u8_not_exhaustive,
+        indoc!(
+            r#"
+            x : U8
+            x = 3
+
+            when x is
+                0 -> 0x0
+                5 -> 0x1
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when x is
+    8│>          0 -> 0x0
+    9│>          5 -> 0x1
+
+    Other possibilities include:
+
+        1..=4
+        6..=255
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        patterns_i8_nested_not_exhaustive,
+        indoc!(
+            r#"
+            x : [Pair I8 Bool]
+            x = Pair 0 Bool.true
+
+            when x is
+                Pair 0 _ -> 0x0
+                Pair 1 _ -> 0x1
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when x is
+    8│>          Pair 0 _ -> 0x0
+    9│>          Pair 1 _ -> 0x1
+
+    Other possibilities include:
+
+        Pair -128..=-1 _
+        Pair 2..=127 _
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        patterns_u8_exhaustive,
+        indoc!(
+            r#"
+            x : U8
+            x = 3
+
+            when x is
+                0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 -> 0x0
+                8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 -> 0x0
+                16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 -> 0x0
+                24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 -> 0x0
+                32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 -> 0x0
+                40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 -> 0x0
+                48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 -> 0x0
+                56 | 57 | 58 | 59 | 60 | 61 | 62 | 63 -> 0x0
+                64 | 65 | 66 | 67 | 68 | 69 | 70 | 71 -> 0x0
+                72 | 73 | 74 | 75 | 76 | 77 | 78 | 79 -> 0x0
+                80 | 81 | 82 | 83 | 84 | 85 | 86 | 87 -> 0x0
+                88 | 89 | 90 | 91 | 92 | 93 | 94 | 95 -> 0x0
+                96 | 97 | 98 | 99 | 100 | 101 | 102 | 103 -> 0x0
+                104 | 105 | 106 | 107 | 108 | 109 | 110 | 111 -> 0x0
+                112 | 113 | 114 | 115 | 116 | 117 | 118 | 119 -> 0x0
+                120 | 121 | 122 | 123 | 124 | 125 | 126 | 127 -> 0x0
+                128 | 129 | 130 | 131 | 132 | 133 | 134 | 135 -> 0x0
+                136 | 137 | 138 | 139 | 140 | 141 | 142 | 143 -> 0x0
+                144 | 145 | 146 | 147 | 148 | 149 | 150 | 151 -> 0x0
+                152 | 153 | 154 | 155 | 156 | 157 | 158 | 159 -> 0x0
+                160 | 161 | 162 | 163 | 164 | 165 | 166 | 167 -> 0x0
+                168 | 169 | 170 | 171 | 172 | 173 | 174 | 175 -> 0x0
+                176 | 177 | 178 | 179 | 180 | 181 | 182 | 183 -> 0x0
+                184 | 185 | 186 | 187 | 188 | 189 | 190 | 191 -> 0x0
+                192 | 193 | 194 | 195 | 196 | 197 | 198 | 199 -> 0x0
+                200 | 201 | 202 | 203 | 204 | 205 | 206 | 207 -> 0x0
+                208 | 209 | 210 | 211 | 212 | 213 | 214 | 215 -> 0x0
+                216 | 217 | 218 | 219 | 220 | 221 | 222 | 223 -> 0x0
+                224 | 225 | 226 | 227 | 228 | 229 | 230 | 231 -> 0x0
+                232 | 233 | 234 | 235 | 236 | 237 | 238 | 239 -> 0x0
+                240 | 241 | 242 | 243 | 244 | 245 | 246 | 247 -> 0x0
+                248 | 249 | 250 | 251 | 252 | 253 | 254 | 255 -> 0x0
+            "#
+        ),
+        @""
+    );
+
+    test_report!(
+        patterns_i8_halves_exhaustive,
+        indoc!(
+            r#"
+            x : I8
+            x = -3
+
+            when x is
+                -128 | -127 | -126 | -125 | -124 | -123 | -122 | -121 -> 0x0
+                -120 | -119 | -118 | -117 | -116 | -115 | -114 | -113 -> 0x0
+                -112 | -111 | -110 | -109 | -108 | -107 | -106 | -105 -> 0x0
+                -104 | -103 | -102 | -101 | -100 | -99 | -98 | -97 -> 0x0
+                -96 | -95 | -94 | -93 | -92 | -91 | -90 | -89 -> 0x0
+                -88 | -87 | -86 | -85 | -84 | -83 | -82 | -81 -> 0x0
+                -80 | -79 | -78 | -77 | -76 | -75 | -74 | -73 -> 0x0
+                -72 | -71 | -70 | -69 | -68 | -67 | -66 | -65 -> 0x0
+                -64 | -63 | -62 | -61 | -60 | -59 | -58 | -57 -> 0x0
+                -56 | -55 | -54 | -53 | -52 | -51 | -50 | -49 -> 0x0
+                -48 | -47 | -46 | -45 | -44 | -43 | -42 | -41 -> 0x0
+                -40 | -39 | -38 | -37 | -36 | -35 | -34 | -33 -> 0x0
+                -32 | -31 | -30 | -29 | -28 | -27 | -26 | -25 -> 0x0
+                -24 | -23 | -22 | -21 | -20 | -19 | -18 | -17 -> 0x0
+                -16 | -15 | -14 | -13 | -12 | -11 | -10 | -9 -> 0x0
+                -8 | -7 | -6 | -5 | -4 | -3 | -2 | -1 -> 0x0
+                0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 -> 0x1
+                8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 -> 0x1
+                16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 -> 0x1
+                24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 -> 0x1
+                32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 -> 0x1
+                40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 -> 0x1
+                48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 -> 0x1
+                56 | 57 | 58 | 59 | 60 | 61 | 62 | 63 -> 0x1
+                64 | 65 | 66 | 67 | 68 | 69 | 70 | 71 -> 0x1
+                72 | 73 | 74 | 75 | 76 | 77 | 78 | 79 -> 0x1
+                80 | 81 | 82 | 83 | 84 | 85 | 86 | 87 -> 0x1
+                88 | 89 | 90 | 91 | 92 | 93 | 94 | 95 -> 0x1
+                96 | 97 | 98 | 99 | 100 | 101 | 102 | 103 -> 0x1
+                104 | 105 | 106 | 107 | 108 | 109 | 110 | 111 -> 0x1
+                112 | 113 | 114 | 115 | 116 | 117 | 118 | 119 -> 0x1
+                120 | 121 | 122 | 123 | 124 | 125 | 126 | 127 -> 0x1
+            "#
+        ),
+        @""
+    );
+
+    test_report!(
+        patterns_ranged_number_exhaustive,
+        indoc!(
+            r#"
+            x = -3
+
+            when x is
+                -3 -> 0x0
+                200 -> 0x1
+                _ -> 0x2
+            "#
+        ),
+        @""
+    );
+
     test_report!(
         unify_alias_other,
         indoc!(
@@ -2909,7 +3213,7 @@ mod test_reporting {
         ),
         // de-aliases the alias to give a better error message
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -2947,7 +3251,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2973,7 +3277,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2993,7 +3297,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E0120] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -3017,7 +3321,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E0120] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -3045,7 +3349,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E0120] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -3080,7 +3384,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E0120] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -3113,7 +3417,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E0120] ────────────────────────── /code/proj/Main.roc ─
 
     This record type defines the `.foo` field twice!
 
@@ -3141,7 +3445,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE TAG NAME ──────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE TAG NAME [E0204] ──────────────────────────── /code/proj/Main.roc ─
 
     This tag union type defines the `Foo` tag twice!
 
@@ -3170,7 +3474,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── NAMING PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NAMING PROBLEM [E0205] ──────────────────────────────── /code/proj/Main.roc ─
 
     This annotation does not match the definition immediately following
     it:
@@ -3206,7 +3510,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0206] ──────────────────────────────── /code/proj/Main.roc ─
 
     This definition of `MyAlias` has an unexpected pattern:
 
@@ -3215,7 +3519,7 @@ mod test_reporting {
 
     Only type variables like `a` or `value` can occur in this position.
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `MyAlias` is not used anywhere in your code.
 
@@ -3238,7 +3542,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0206] ──────────────────────────────── /code/proj/Main.roc ─
 
     This definition of `Age` has an unexpected pattern:
 
@@ -3260,7 +3564,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E0209] ─────────────────────── /code/proj/Main.roc ─
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3282,7 +3586,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E0209] ─────────────────────── /code/proj/Main.roc ─
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3306,7 +3610,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO FEW TYPE ARGUMENTS ──────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW TYPE ARGUMENTS [E0209] ──────────────────────── /code/proj/Main.roc ─
 
     The `Pair` alias expects 2 type arguments, but it got 1 instead:
 
@@ -3330,7 +3634,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E0209] ─────────────────────── /code/proj/Main.roc ─
 
     The `Pair` alias expects 2 type arguments, but it got 3 instead:
 
@@ -3353,7 +3657,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED TYPE ALIAS PARAMETER ─────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED TYPE ALIAS PARAMETER [E0202] ─────────────────── /code/proj/Main.roc ─
 
     The `a` type parameter is not used in the `Foo` alias definition:
 
@@ -3375,7 +3679,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── ARGUMENTS BEFORE EQUALS ────────────────── tmp/elm_function_syntax/Test.roc ─
+    ── ARGUMENTS BEFORE EQUALS [E0402] ────────── tmp/elm_function_syntax/Test.roc ─
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -3403,7 +3707,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -3452,7 +3756,7 @@ mod test_reporting {
         // TODO render tag unions across multiple lines
         // TODO do not show recursion var if the recursion var does not render on the surface of a type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -3506,7 +3810,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too big:
 
@@ -3518,7 +3822,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too small:
 
@@ -3530,7 +3834,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too big:
 
@@ -3542,7 +3846,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too small:
 
@@ -3554,7 +3858,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to + has an unexpected type:
 
@@ -3583,7 +3887,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal is too big:
 
@@ -3595,7 +3899,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal is too small:
 
@@ -3628,7 +3932,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -3640,7 +3944,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer literal contains an invalid digit:
 
@@ -3652,7 +3956,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer literal contains an invalid digit:
 
@@ -3664,7 +3968,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer literal contains an invalid digit:
 
@@ -3694,7 +3998,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer literal contains no digits:
 
@@ -3706,7 +4010,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer literal contains no digits:
 
@@ -3718,7 +4022,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer literal contains no digits:
 
@@ -3742,7 +4046,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal contains an invalid digit:
 
@@ -3773,7 +4077,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0122] ──────────────────────────────── /code/proj/Main.roc ─
 
     This expression cannot be updated:
 
@@ -3782,7 +4086,7 @@ mod test_reporting {
 
     Only variables can be updated with record update syntax.
 
-    ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+    ── MODULE NOT IMPORTED [E0110] ─────────────────────────── /code/proj/Main.roc ─
 
     The `Test` module is not imported:
 
@@ -3797,7 +4101,7 @@ mod test_reporting {
         Dict
         Hash
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0122] ──────────────────────────────── /code/proj/Main.roc ─
 
     This expression cannot be updated:
 
@@ -3816,7 +4120,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+    ── MODULE NOT IMPORTED [E0110] ─────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` module is not imported:
 
@@ -3841,7 +4145,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to + has an unexpected type:
 
@@ -3869,7 +4173,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -3898,7 +4202,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of this definition:
 
@@ -3929,7 +4233,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -3962,7 +4266,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -3995,7 +4299,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -4026,7 +4330,7 @@ mod test_reporting {
                 "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to this function has an unexpected type:
 
@@ -4060,7 +4364,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -4094,7 +4398,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -4122,7 +4426,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── BAD OPTIONAL VALUE ──────────────────────────────────── /code/proj/Main.roc ─
+    ── BAD OPTIONAL VALUE [E0121] ──────────────────────────── /code/proj/Main.roc ─
 
     This record uses an optional value for the `.y` field in an incorrect
     context!
@@ -4159,7 +4463,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern is redundant:
 
@@ -4204,7 +4508,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E0103] ─────────────────────────────── /code/proj/Main.roc ─
 
     `f` doesn't use `foo`.
 
@@ -4226,7 +4530,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -4247,7 +4551,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -4267,7 +4571,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a record field access here:
 
@@ -4289,7 +4593,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ──────────────── tmp/type_annotation_double_colon/Test.roc ─
+    ── UNKNOWN OPERATOR [E0402] ──────── tmp/type_annotation_double_colon/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -4321,7 +4625,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 3 arguments:
 
@@ -4340,7 +4644,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TAG UNION TYPE ───────────────────── tmp/tag_union_open/Test.roc ─
+    ── UNFINISHED TAG UNION TYPE [E0415] ───────────── tmp/tag_union_open/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4362,7 +4666,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TAG UNION TYPE ────────────────────── tmp/tag_union_end/Test.roc ─
+    ── UNFINISHED TAG UNION TYPE [E0415] ────────────── tmp/tag_union_end/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4384,7 +4688,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── WEIRD TAG NAME ────────────────── tmp/tag_union_lowercase_tag_name/Test.roc ─
+    ── WEIRD TAG NAME [E0415] ────────── tmp/tag_union_lowercase_tag_name/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4405,7 +4709,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── WEIRD TAG NAME ─────────── tmp/tag_union_second_lowercase_tag_name/Test.roc ─
+    ── WEIRD TAG NAME [E0415] ─── tmp/tag_union_second_lowercase_tag_name/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4426,7 +4730,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ────────────────────── tmp/record_type_open/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E0414] ────────────── tmp/record_type_open/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4449,7 +4753,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ─────────────── tmp/record_type_open_indent/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E0414] ─────── tmp/record_type_open_indent/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4472,7 +4776,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ─────────────────────── tmp/record_type_end/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E0414] ─────────────── tmp/record_type_end/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4494,7 +4798,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ──────── tmp/record_type_keyword_field_name/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E0414] ─ ...record_type_keyword_field_name/Test.roc ─
 
     I just started parsing a record type, but I got stuck on this field
     name:
@@ -4516,7 +4820,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ───────────── tmp/record_type_missing_comma/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E0414] ───── tmp/record_type_missing_comma/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4533,7 +4837,7 @@ mod test_reporting {
         record_type_tab,
         "f : { foo \t }",
         @r###"
-    ── TAB CHARACTER ──────────────────────────────── tmp/record_type_tab/Test.roc ─
+    ── TAB CHARACTER [E0418] ──────────────────────── tmp/record_type_tab/Test.roc ─
 
     I encountered a tab character:
 
@@ -4548,7 +4852,7 @@ mod test_reporting {
         comment_with_tab,
         "# comment with a \t\n4",
         @r###"
-    ── TAB CHARACTER ─────────────────────────────── tmp/comment_with_tab/Test.roc ─
+    ── TAB CHARACTER [E0418] ─────────────────────── tmp/comment_with_tab/Test.roc ─
 
     I encountered a tab character:
 
@@ -4563,7 +4867,7 @@ mod test_reporting {
         comment_with_control_character,
         "# comment with a \x07\n",
         @r###"
-    ── ASCII CONTROL CHARACTER ─────── tmp/comment_with_control_character/Test.roc ─
+    ── ASCII CONTROL CHARACTER [E0418] ─ ...omment_with_control_character/Test.roc ─
 
     I encountered an ASCII control character:
 
@@ -4578,7 +4882,7 @@ mod test_reporting {
         record_type_carriage_return,
         "f : { \r foo }",
         @r###"
-    ── MISPLACED CARRIAGE RETURN ──────── tmp/record_type_carriage_return/Test.roc ─
+    ── MISPLACED CARRIAGE RETURN [E0418] ─ ...record_type_carriage_return/Test.roc ─
 
     I encountered a stray carriage return (\r):
 
@@ -4598,7 +4902,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ────────────────── tmp/type_in_parens_start/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0413] ────────── tmp/type_in_parens_start/Test.roc ─
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4621,7 +4925,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ──────────────────── tmp/type_in_parens_end/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0413] ──────────── tmp/type_in_parens_end/Test.roc ─
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4646,7 +4950,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4676,7 +4980,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4705,7 +5009,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ───────────────────────── tmp/type_apply_stray_dot/Test.roc ─
+    ── UNFINISHED TYPE [E0413] ───────────────── tmp/type_apply_stray_dot/Test.roc ─
 
     I just started parsing a type, but I got stuck here:
 
@@ -4726,7 +5030,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4757,7 +5061,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4777,7 +5081,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MISSING FINAL EXPRESSION ──────── tmp/def_missing_final_expression/Test.roc ─
+    ── MISSING FINAL EXPRESSION [E0402] ─ ...def_missing_final_expression/Test.roc ─
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -4805,7 +5109,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── INDENT ENDS AFTER EXPRESSION ────── tmp/expression_indentation_end/Test.roc ─
+    ── INDENT ENDS AFTER EXPRESSION [E0402] ─ ...pression_indentation_end/Test.roc ─
 
     I am partway through parsing an expression, but I got stuck here:
 
@@ -4831,7 +5135,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED INLINE ALIAS ──────────────────── tmp/type_inline_alias/Test.roc ─
+    ── UNFINISHED INLINE ALIAS [E0413] ──────────── tmp/type_inline_alias/Test.roc ─
 
     I just started parsing an inline type alias, but I got stuck here:
 
@@ -4853,7 +5157,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DOUBLE COMMA ─────────────────────────────── tmp/type_double_comma/Test.roc ─
+    ── DOUBLE COMMA [E0413] ─────────────────────── tmp/type_double_comma/Test.roc ─
 
     I just started parsing a function argument type, but I encountered two
     commas in a row:
@@ -4876,7 +5180,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ─────────────────────── tmp/type_argument_no_arrow/Test.roc ─
+    ── UNFINISHED TYPE [E0413] ─────────────── tmp/type_argument_no_arrow/Test.roc ─
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -4899,7 +5203,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ───────────── tmp/type_argument_arrow_then_nothing/Test.roc ─
+    ── UNFINISHED TYPE [E0413] ───── tmp/type_argument_arrow_then_nothing/Test.roc ─
 
     I just started parsing a type, but I got stuck here:
 
@@ -4924,7 +5228,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `myDict` definition:
 
@@ -4959,7 +5263,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `myDict` definition:
 
@@ -4991,7 +5295,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── IF GUARD NO CONDITION ───────────── tmp/if_guard_without_condition/Test.roc ─
+    ── IF GUARD NO CONDITION [E0408] ───── tmp/if_guard_without_condition/Test.roc ─
 
     I just started parsing an if guard, but there is no guard condition:
 
@@ -5016,7 +5320,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PATTERN ────────────────────────── tmp/empty_or_pattern/Test.roc ─
+    ── UNFINISHED PATTERN [E0409] ────────────────── tmp/empty_or_pattern/Test.roc ─
 
     I just started parsing a pattern, but I got stuck here:
 
@@ -5041,7 +5345,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MISSING ARROW ────────────────────────── tmp/pattern_binds_keyword/Test.roc ─
+    ── MISSING ARROW [E0408] ────────────────── tmp/pattern_binds_keyword/Test.roc ─
 
     I am partway through parsing a `when` expression, but got stuck here:
 
@@ -5077,7 +5381,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED WHEN ─────────────────────────── tmp/when_missing_arrow/Test.roc ─
+    ── UNFINISHED WHEN [E0408] ─────────────────── tmp/when_missing_arrow/Test.roc ─
 
     I was partway through parsing a `when` expression, but I got stuck here:
 
@@ -5110,7 +5414,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED ARGUMENT LIST ───────────────── tmp/lambda_double_comma/Test.roc ─
+    ── UNFINISHED ARGUMENT LIST [E0403] ───────── tmp/lambda_double_comma/Test.roc ─
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5131,7 +5435,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED ARGUMENT LIST ──────────────── tmp/lambda_leading_comma/Test.roc ─
+    ── UNFINISHED ARGUMENT LIST [E0403] ──────── tmp/lambda_leading_comma/Test.roc ─
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5178,7 +5482,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── NOT END OF FILE ──────────────────────── tmp/when_outdented_branch/Test.roc ─
+    ── NOT END OF FILE [E0401] ──────────────── tmp/when_outdented_branch/Test.roc ─
 
     I expected to reach the end of the file, but got stuck here:
 
@@ -5197,7 +5501,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNEXPECTED ARROW ─────────────── tmp/when_over_indented_underscore/Test.roc ─
+    ── UNEXPECTED ARROW [E0408] ─────── tmp/when_over_indented_underscore/Test.roc ─
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5234,7 +5538,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNEXPECTED ARROW ────────────────────── tmp/when_over_indented_int/Test.roc ─
+    ── UNEXPECTED ARROW [E0408] ────────────── tmp/when_over_indented_int/Test.roc ─
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5274,7 +5578,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IF ────────────────────────────── tmp/if_outdented_then/Test.roc ─
+    ── UNFINISHED IF [E0407] ────────────────────── tmp/if_outdented_then/Test.roc ─
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5294,7 +5598,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IF ──────────────────────────────── tmp/if_missing_else/Test.roc ─
+    ── UNFINISHED IF [E0407] ──────────────────────── tmp/if_missing_else/Test.roc ─
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5313,7 +5617,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED LIST ──────────────────────────── tmp/list_double_comma/Test.roc ─
+    ── UNFINISHED LIST [E0406] ──────────────────── tmp/list_double_comma/Test.roc ─
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5333,7 +5637,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED LIST ───────────────────────────── tmp/list_without_end/Test.roc ─
+    ── UNFINISHED LIST [E0406] ───────────────────── tmp/list_without_end/Test.roc ─
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5359,7 +5663,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal contains an invalid digit:
 
@@ -5377,7 +5681,7 @@ mod test_reporting {
         unicode_not_hex,
         r#""abc\u(zzzz)def""#,
         @r###"
-    ── WEIRD CODE POINT ───────────────────────────── tmp/unicode_not_hex/Test.roc ─
+    ── WEIRD CODE POINT [E0404] ───────────────────── tmp/unicode_not_hex/Test.roc ─
 
     I am partway through parsing a unicode code point, but I got stuck
     here:
@@ -5395,7 +5699,7 @@ mod test_reporting {
         unicode_too_large,
         r#""abc\u(110000)def""#,
         @r###"
-    ── INVALID UNICODE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID UNICODE [E0124] ─────────────────────────────── /code/proj/Main.roc ─
 
     This unicode code point is invalid:
 
@@ -5410,7 +5714,7 @@ mod test_reporting {
         weird_escape,
         r#""abc\qdef""#,
         @r###"
-    ── WEIRD ESCAPE ──────────────────────────────────── tmp/weird_escape/Test.roc ─
+    ── WEIRD ESCAPE [E0404] ──────────────────────────── tmp/weird_escape/Test.roc ─
 
     I was partway through parsing a  string literal, but I got stuck here:
 
@@ -5434,7 +5738,7 @@ mod test_reporting {
         single_quote_too_long,
         r#"'abcdef'"#,
         @r###"
-    ── INVALID SCALAR ───────────────────────── tmp/single_quote_too_long/Test.roc ─
+    ── INVALID SCALAR [E0404] ───────────────── tmp/single_quote_too_long/Test.roc ─
 
     I am part way through parsing this scalar literal (character literal),
     but it's too long to fit in a U32 so it's not a valid scalar.
@@ -5451,7 +5755,7 @@ mod test_reporting {
         single_no_end,
         r#""there is no end"#,
         @r###"
-    ── ENDLESS STRING ───────────────────────────────── tmp/single_no_end/Test.roc ─
+    ── ENDLESS STRING [E0404] ───────────────────────── tmp/single_no_end/Test.roc ─
 
     I cannot find the end of this string:
 
@@ -5467,7 +5771,7 @@ mod test_reporting {
         multi_no_end,
         r#""""there is no end"#,
         @r###"
-    ── ENDLESS STRING ────────────────────────────────── tmp/multi_no_end/Test.roc ─
+    ── ENDLESS STRING [E0404] ────────────────────────── tmp/multi_no_end/Test.roc ─
 
     I cannot find the end of this block string:
 
@@ -5483,7 +5787,7 @@ mod test_reporting {
         multi_insufficient_indent,
         "    \"\"\"\n  testing\n    \"\"\"", // 4 space indent on the start, 2 space on the `testing` line
         @r###"
-    ── INSUFFICIENT INDENT IN MULTI-LINE STRING ─ ..._insufficient_indent/Test.roc ─
+    ── INSUFFICIENT INDENT IN MULTI-LINE STRING [E0404] ─ ...cient_indent/Test.roc ─
 
     This multiline string is not sufficiently indented:
 
@@ -5505,7 +5809,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── INDENT ENDS AFTER EXPRESSION ──── tmp/dbg_without_final_expression/Test.roc ─
+    ── INDENT ENDS AFTER EXPRESSION [E0402] ─ ...without_final_expression/Test.roc ─
 
     I am partway through parsing a dbg statement, but I got stuck here:
 
@@ -5527,7 +5831,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── INDENT ENDS AFTER EXPRESSION ─ tmp/expect_without_final_expression/Test.roc ─
+    ── INDENT ENDS AFTER EXPRESSION [E0402] ─ ...without_final_expression/Test.roc ─
 
     I am partway through parsing an expect statement, but I got stuck
     here:
@@ -5553,7 +5857,7 @@ mod test_reporting {
             "#,
             ),
             @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5579,7 +5883,7 @@ mod test_reporting {
                 $name,
                 &format!(r#"if Bool.true then "abc" else 1 {} 2"#, $op),
                 |golden| assert_eq!(golden, format!(
-r#"── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+r#"── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
 This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5622,7 +5926,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `foo` record doesn’t have a `if` field:
 
@@ -5641,7 +5945,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E0109] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The Num module does not expose `if`:
 
@@ -5665,7 +5969,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a record field access here:
 
@@ -5684,7 +5988,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am very confused by this field access:
 
@@ -5703,7 +6007,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am very confused by this field access
 
@@ -5724,7 +6028,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NAMING PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NAMING PROBLEM [E0114] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse an identifier here:
 
@@ -5758,7 +6062,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ───────────────────────────────────── tmp/case_of/Test.roc ─
+    ── UNKNOWN OPERATOR [E0402] ───────────────────────────── tmp/case_of/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5791,7 +6095,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `bar` in this scope.
 
@@ -5816,7 +6120,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ──────────────────────────── tmp/invalid_operator/Test.roc ─
+    ── UNKNOWN OPERATOR [E0402] ──────────────────── tmp/invalid_operator/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5841,7 +6145,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ───────────────────────────────── tmp/double_plus/Test.roc ─
+    ── UNKNOWN OPERATOR [E0402] ───────────────────────── tmp/double_plus/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5868,7 +6172,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ────────────────────────────── tmp/inline_hastype/Test.roc ─
+    ── UNKNOWN OPERATOR [E0402] ────────────────────── tmp/inline_hastype/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5899,7 +6203,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             &format!(
-                r###"── UNKNOWN OPERATOR ───────────────────────────── tmp/wild_case_arrow/Test.roc ─
+                r###"── UNKNOWN OPERATOR [E0402] ───────────────────── tmp/wild_case_arrow/Test.roc ─
 
 This looks like an operator, but it's not one I recognize!
 
@@ -5932,7 +6236,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD PROVIDES [E0417] ──────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a provides list, but I got stuck here:
 
@@ -5967,7 +6271,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD PROVIDES [E0417] ──────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6003,7 +6307,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── BAD REQUIRES ────────────────────────────────────────── /code/proj/Main.roc ─
+                ── BAD REQUIRES [E0417] ────────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6031,7 +6335,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD IMPORTS ───────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD IMPORTS [E0417] ───────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6058,7 +6362,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD EXPOSES ───────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD EXPOSES [E0417] ───────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -6086,7 +6390,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD MODULE NAME ───────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD MODULE NAME [E0417] ───────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -6112,7 +6416,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD APP NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD APP NAME [E0417] ──────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -6136,7 +6440,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6157,7 +6461,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6179,7 +6483,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -6205,7 +6509,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ──────────────── tmp/pattern_in_parens_open/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0409] ──────── tmp/pattern_in_parens_open/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6228,7 +6532,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ─────────── tmp/pattern_in_parens_end_comma/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0409] ─── tmp/pattern_in_parens_end_comma/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6251,7 +6555,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ───────────────── tmp/pattern_in_parens_end/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0409] ───────── tmp/pattern_in_parens_end/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6275,7 +6579,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED FUNCTION ───── tmp/unfinished_closure_pattern_in_parens/Test.roc ─
+    ── UNFINISHED FUNCTION [E0403] ─ ...inished_closure_pattern_in_parens/Test.roc ─
 
     I was partway through parsing a  function, but I got stuck here:
 
@@ -6295,7 +6599,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ───────── tmp/pattern_in_parens_indent_open/Test.roc ─
+    ── UNFINISHED PARENTHESES [E0409] ─ tmp/pattern_in_parens_indent_open/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6320,7 +6624,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -6348,7 +6652,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `expect` condition needs to be a Bool:
 
@@ -6375,7 +6679,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to * has an unexpected type:
 
@@ -6390,7 +6694,7 @@ In roc, functions are always written as a lambda, like{}
 
         Num *
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `mult` definition:
 
@@ -6419,7 +6723,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to * has an unexpected type:
 
@@ -6434,7 +6738,7 @@ In roc, functions are always written as a lambda, like{}
 
         Num a
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `mult` definition:
 
@@ -6469,7 +6773,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     This alias has the same name as a builtin:
 
@@ -6479,7 +6783,7 @@ In roc, functions are always written as a lambda, like{}
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO FEW TYPE ARGUMENTS ──────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW TYPE ARGUMENTS [E0209] ──────────────────────── /code/proj/Main.roc ─
 
     The `Result` alias expects 2 type arguments, but it got 1 instead:
 
@@ -6507,7 +6811,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     This alias has the same name as a builtin:
 
@@ -6517,7 +6821,7 @@ In roc, functions are always written as a lambda, like{}
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E0209] ─────────────────────── /code/proj/Main.roc ─
 
     The `Result` alias expects 2 type arguments, but it got 3 instead:
 
@@ -6539,7 +6843,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: We should tell the user that we inferred `_` as `a`
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6573,7 +6877,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6605,7 +6909,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6645,7 +6949,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `inner` definition:
 
@@ -6674,7 +6978,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── NOT AN INLINE ALIAS ────────── tmp/error_inline_alias_not_an_alias/Test.roc ─
+    ── NOT AN INLINE ALIAS [E0416] ── tmp/error_inline_alias_not_an_alias/Test.roc ─
 
     The inline type after this `as` is not a type alias:
 
@@ -6694,7 +6998,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── QUALIFIED ALIAS NAME ──────────── tmp/error_inline_alias_qualified/Test.roc ─
+    ── QUALIFIED ALIAS NAME [E0416] ──── tmp/error_inline_alias_qualified/Test.roc ─
 
     This type alias has a qualified name:
 
@@ -6714,7 +7018,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE ARGUMENT NOT LOWERCASE ─ ...r_inline_alias_argument_uppercase/Test.roc ─
+    ── TYPE ARGUMENT NOT LOWERCASE [E0416] ─ ..._alias_argument_uppercase/Test.roc ─
 
     This alias type argument is not lowercase:
 
@@ -6738,7 +7042,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `isEmpty` has an unexpected type:
 
@@ -6774,7 +7078,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `c` has an unexpected type:
 
@@ -6807,7 +7111,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6830,7 +7134,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6852,7 +7156,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6877,7 +7181,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `job` is weird:
 
@@ -6912,7 +7216,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `job` definition:
 
@@ -6943,7 +7247,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── NESTED DATATYPE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── NESTED DATATYPE [E0207] ─────────────────────────────── /code/proj/Main.roc ─
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -6971,7 +7275,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── NESTED DATATYPE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── NESTED DATATYPE [E0207] ─────────────────────────────── /code/proj/Main.roc ─
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -7019,7 +7323,7 @@ In roc, functions are always written as a lambda, like{}
 
                     let real = format!(indoc!(
                         r#"
-                        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+                        ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
                         This 1st argument to `use` has an unexpected type:
 
@@ -7086,7 +7390,7 @@ In roc, functions are always written as a lambda, like{}
 
                     let real = format!(indoc!(
                         r#"
-                        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+                        ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
                         The branches of this `when` expression don't match the condition:
 
@@ -7138,7 +7442,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: link to number suffixes
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -7161,7 +7465,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: link to number suffixes
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0123] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -7183,7 +7487,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CONFLICTING NUMBER SUFFIX ───────────────────────────── /code/proj/Main.roc ─
+    ── CONFLICTING NUMBER SUFFIX [E0123] ───────────────────── /code/proj/Main.roc ─
 
     This number literal is an integer, but it has a float suffix:
 
@@ -7200,7 +7504,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CONFLICTING NUMBER SUFFIX ───────────────────────────── /code/proj/Main.roc ─
+    ── CONFLICTING NUMBER SUFFIX [E0123] ───────────────────── /code/proj/Main.roc ─
 
     This number literal is a float, but it has an integer suffix:
 
@@ -7213,7 +7517,7 @@ In roc, functions are always written as a lambda, like{}
         u8_overflow,
         "256u8",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7229,7 +7533,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u8,
         "-1u8",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7245,7 +7549,7 @@ In roc, functions are always written as a lambda, like{}
         u16_overflow,
         "65536u16",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7261,7 +7565,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u16,
         "-1u16",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7277,7 +7581,7 @@ In roc, functions are always written as a lambda, like{}
         u32_overflow,
         "4_294_967_296u32",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7293,7 +7597,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u32,
         "-1u32",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7309,7 +7613,7 @@ In roc, functions are always written as a lambda, like{}
         u64_overflow,
         "18_446_744_073_709_551_616u64",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7325,7 +7629,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u64,
         "-1u64",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7341,7 +7645,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u128,
         "-1u128",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7357,7 +7661,7 @@ In roc, functions are always written as a lambda, like{}
         i8_overflow,
         "128i8",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7373,7 +7677,7 @@ In roc, functions are always written as a lambda, like{}
         i8_underflow,
         "-129i8",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7389,7 +7693,7 @@ In roc, functions are always written as a lambda, like{}
         i16_overflow,
         "32768i16",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7405,7 +7709,7 @@ In roc, functions are always written as a lambda, like{}
         i16_underflow,
         "-32769i16",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7421,7 +7725,7 @@ In roc, functions are always written as a lambda, like{}
         i32_overflow,
         "2_147_483_648i32",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7437,7 +7741,7 @@ In roc, functions are always written as a lambda, like{}
         i32_underflow,
         "-2_147_483_649i32",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7453,7 +7757,7 @@ In roc, functions are always written as a lambda, like{}
         i64_overflow,
         "9_223_372_036_854_775_808i64",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7469,7 +7773,7 @@ In roc, functions are always written as a lambda, like{}
         i64_underflow,
         "-9_223_372_036_854_775_809i64",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E0123] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7485,7 +7789,7 @@ In roc, functions are always written as a lambda, like{}
         i128_overflow,
         "170_141_183_460_469_231_731_687_303_715_884_105_728i128",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E0123] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7507,7 +7811,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO: this error message could be improved, e.g. something like "This argument can
         // be used as ... because of its literal value"
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7533,7 +7837,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7560,7 +7864,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7587,7 +7891,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -7618,7 +7922,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7641,7 +7945,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7665,7 +7969,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is recursive in an invalid way:
 
@@ -7700,7 +8004,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     This alias has the same name as a builtin:
 
@@ -7720,7 +8024,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E0210] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7741,7 +8045,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E0210] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7755,7 +8059,7 @@ In roc, functions are always written as a lambda, like{}
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `Age` is not used anywhere in your code.
 
@@ -7778,7 +8082,7 @@ In roc, functions are always written as a lambda, like{}
         // and checking it during can. The reason the error appears is because it is parsed as
         // Apply(Error(OtherModule), [@Age, 21])
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E0210] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7787,7 +8091,7 @@ In roc, functions are always written as a lambda, like{}
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -7814,7 +8118,7 @@ In roc, functions are always written as a lambda, like{}
         // `@Age` can be linked to the declaration of `Age` inside `age`, and a suggestion to
         // raise that declaration to the outer scope.
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `Age` is not used anywhere in your code.
 
@@ -7824,7 +8128,7 @@ In roc, functions are always written as a lambda, like{}
     If you didn't intend on using `Age` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E0210] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7845,7 +8149,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+    ── MODULE NOT IMPORTED [E0110] ─────────────────────────── /code/proj/Main.roc ─
 
     The `Task` module is not imported:
 
@@ -7877,7 +8181,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO(opaques): error could be improved by saying that the opaque definition demands
         // that the argument be a U8, and linking to the definitin!
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -7906,7 +8210,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -7936,7 +8240,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `v` definition:
 
@@ -7974,7 +8278,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO(opaques): error could be improved by saying that the user-provided pattern
         // probably wants to change "Age" to "@Age"!
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -8009,7 +8313,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -8040,7 +8344,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -8078,7 +8382,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -8111,7 +8415,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `y` has an unexpected type:
 
@@ -8144,7 +8448,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -8168,7 +8472,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INVALID_EXTENSION_TYPE ──────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID_EXTENSION_TYPE [E0208] ──────────────────────── /code/proj/Main.roc ─
 
     This record extension type is invalid:
 
@@ -8189,7 +8493,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INVALID_EXTENSION_TYPE ──────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID_EXTENSION_TYPE [E0208] ──────────────────────── /code/proj/Main.roc ─
 
     This tag union extension type is invalid:
 
@@ -8216,7 +8520,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8230,7 +8534,7 @@ In roc, functions are always written as a lambda, like{}
         Unsigned16
         Unsigned64
 
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8257,7 +8561,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED ABILITY ── tmp/ability_first_demand_not_indented_enough/Test.roc ─
+    ── UNFINISHED ABILITY [E0419] ─ ...y_first_demand_not_indented_enough/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8282,7 +8586,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── UNFINISHED ABILITY ─── tmp/ability_demands_not_indented_with_first/Test.roc ─
+        ── UNFINISHED ABILITY [E0419] ─ ...ty_demands_not_indented_with_first/Test.roc ─
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8305,7 +8609,7 @@ In roc, functions are always written as a lambda, like{}
                 "#
         ),
         @r#"
-        ── UNFINISHED ABILITY ───────────── tmp/ability_demand_value_has_args/Test.roc ─
+        ── UNFINISHED ABILITY [E0419] ───── tmp/ability_demand_value_has_args/Test.roc ─
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8329,7 +8633,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNFINISHED ABILITY ────────── tmp/ability_non_signature_expression/Test.roc ─
+    ── UNFINISHED ABILITY [E0419] ── tmp/ability_non_signature_expression/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8352,7 +8656,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E0203] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8374,7 +8678,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E0203] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8396,7 +8700,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E0203] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has 2 unbound type variables.
 
@@ -8420,7 +8724,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E0203] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8442,7 +8746,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E0203] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8465,7 +8769,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── ABILITY HAS TYPE VARIABLES ──────────────────────────── /code/proj/Main.roc ─
+    ── ABILITY HAS TYPE VARIABLES [E0220] ──────────────────── /code/proj/Main.roc ─
 
     The definition of the `MHash` ability includes type variables:
 
@@ -8475,7 +8779,7 @@ In roc, functions are always written as a lambda, like{}
     Abilities cannot depend on type variables, but their member values
     can!
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `MHash` is not used anywhere in your code.
 
@@ -8497,7 +8801,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY ─────────────────── /code/proj/Main.roc ─
+    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY [E0221] ─────────── /code/proj/Main.roc ─
 
     The type referenced in this "implements" clause is not an ability:
 
@@ -8516,7 +8820,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
         The `a` name is first defined here:
 
@@ -8545,7 +8849,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
         The `Ability` name is first defined here:
 
@@ -8572,7 +8876,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE ────────────── /code/proj/Main.roc ─
+        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [E0224] ────── /code/proj/Main.roc ─
 
         The definition of the ability member `ab` does not include an `implements`
         clause binding a type variable to the ability `Ability`:
@@ -8587,7 +8891,7 @@ In roc, functions are always written as a lambda, like{}
 
         Otherwise, the function does not need to be part of the ability!
 
-        ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
         `Ability` is not used anywhere in your code.
 
@@ -8609,7 +8913,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES ─────────────── /code/proj/Main.roc ─
+        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES [E0225] ─────── /code/proj/Main.roc ─
 
         The definition of the ability member `eq` includes multiple variables
         bound to the `MEq`` ability:`
@@ -8637,7 +8941,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── ILLEGAL IMPLEMENTS CLAUSE ───────────────────────────── /code/proj/Main.roc ─
+    ── ILLEGAL IMPLEMENTS CLAUSE [E0222] ───────────────────── /code/proj/Main.roc ─
 
     An `implements` clause is not allowed here:
 
@@ -8647,7 +8951,7 @@ In roc, functions are always written as a lambda, like{}
     `implements` clauses can only be specified on the top-level type
     annotations.
 
-    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE ────────────── /code/proj/Main.roc ─
+    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [E0224] ────── /code/proj/Main.roc ─
 
     The definition of the ability member `hash` does not include an
     `implements` clause binding a type variable to the ability `MHash`:
@@ -8678,7 +8982,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with this specialization of `hash`:
 
@@ -8711,7 +9015,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -8737,7 +9041,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `hash` is not used anywhere in your code.
 
@@ -8766,7 +9070,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: the error message here could be seriously improved!
         @r###"
-    ── OVERLOADED SPECIALIZATION ───────────────────────────── /code/proj/Main.roc ─
+    ── OVERLOADED SPECIALIZATION [E0239] ───────────────────── /code/proj/Main.roc ─
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -8779,7 +9083,7 @@ In roc, functions are always written as a lambda, like{}
     Ability specializations can only provide implementations for one
     opaque type, since all opaque types are different!
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This specialization of `hash` is overly general:
 
@@ -8819,7 +9123,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── OVERLOADED SPECIALIZATION ───────────────────────────── /code/proj/Main.roc ─
+    ── OVERLOADED SPECIALIZATION [E0239] ───────────────────── /code/proj/Main.roc ─
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -8850,7 +9154,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with this specialization of `eq`:
 
@@ -8885,7 +9189,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `hash` definition:
 
@@ -8926,7 +9230,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -8939,7 +9243,7 @@ In roc, functions are always written as a lambda, like{}
 
     Only builtin abilities can have generated implementations!
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -8964,7 +9268,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── ABILITY NOT ON TOP-LEVEL ────────────────────────────── /code/proj/Main.roc ─
+        ── ABILITY NOT ON TOP-LEVEL [E0226] ────────────────────── /code/proj/Main.roc ─
 
         This ability definition is not on the top-level of a module:
 
@@ -8992,7 +9296,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `hashable` definition:
 
@@ -9038,7 +9342,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
+    ── ABILITY USED AS TYPE [E0227] ────────────────────────── /code/proj/Main.roc ─
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9052,7 +9356,7 @@ In roc, functions are always written as a lambda, like{}
 
         a implements MHash
 
-    ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
+    ── ABILITY USED AS TYPE [E0227] ────────────────────────── /code/proj/Main.roc ─
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9082,7 +9386,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -9135,7 +9439,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── WEIRD IMPORTS ────────────────────────── tmp/imports_missing_comma/Test.roc ─
+        ── WEIRD IMPORTS [E0417] ────────────────── tmp/imports_missing_comma/Test.roc ─
 
         I am partway through parsing a imports list, but I got stuck here:
 
@@ -9160,7 +9464,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
         This `when` does not cover all the possibilities:
 
@@ -9207,7 +9511,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── SPECIALIZATION NOT ON TOP-LEVEL ─────────────────────── /code/proj/Main.roc ─
+        ── SPECIALIZATION NOT ON TOP-LEVEL [E0228] ─────────────── /code/proj/Main.roc ─
 
         This specialization of the `default` ability member is in a nested
         scope:
@@ -9232,7 +9536,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to == has an unexpected type:
 
@@ -9268,7 +9572,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `remove` has an unexpected type:
 
@@ -9289,7 +9593,7 @@ In roc, functions are always written as a lambda, like{}
     change the type annotation to be more specific? Maybe change the code
     to be more general?
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `new`:
 
@@ -9302,7 +9606,7 @@ In roc, functions are always written as a lambda, like{}
 
         { set : Set ∞ }
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E0303] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `goal`:
 
@@ -9332,7 +9636,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR DEFINITION [E0111] ─────────────────────────── /code/proj/Main.roc ─
 
         The `t1` definition is causing a very tricky infinite loop:
 
@@ -9360,7 +9664,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9388,7 +9692,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO: this error message is quite unfortunate. We should remove the duplication, and
         // also support regions that point to things in other modules. See also https://github.com/roc-lang/roc/issues/3056.
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9425,7 +9729,7 @@ In roc, functions are always written as a lambda, like{}
                 "#
         ),
         @r#"
-            ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+            ── CIRCULAR DEFINITION [E0111] ─────────────────────────── /code/proj/Main.roc ─
 
             The `t1` definition is causing a very tricky infinite loop:
 
@@ -9455,7 +9759,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── IMPLEMENTATION NOT FOUND ────────────────────────────── /code/proj/Main.roc ─
+    ── IMPLEMENTATION NOT FOUND [E0230] ────────────────────── /code/proj/Main.roc ─
 
     An implementation of `eq` could not be found in this scope:
 
@@ -9466,7 +9770,7 @@ In roc, functions are always written as a lambda, like{}
     another variable that implements this ability member, like
     { eq: myeq }
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -9493,7 +9797,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `aMEq` in this scope.
 
@@ -9507,7 +9811,7 @@ In roc, functions are always written as a lambda, like{}
         myMEq
         eq
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -9534,7 +9838,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── OPTIONAL ABILITY IMPLEMENTATION ─────────────────────── /code/proj/Main.roc ─
+    ── OPTIONAL ABILITY IMPLEMENTATION [E0232] ─────────────── /code/proj/Main.roc ─
 
     Ability implementations cannot be optional:
 
@@ -9545,7 +9849,7 @@ In roc, functions are always written as a lambda, like{}
 
 
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -9572,7 +9876,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── OPTIONAL ABILITY IMPLEMENTATION ─────────────────────── /code/proj/Main.roc ─
+    ── OPTIONAL ABILITY IMPLEMENTATION [E0232] ─────────────── /code/proj/Main.roc ─
 
     Ability implementations cannot be optional:
 
@@ -9585,7 +9889,7 @@ In roc, functions are always written as a lambda, like{}
     record of implementations. For example,    implements [Encoding] will
     attempt to derive `Encoding`
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Encoding` ability:
 
@@ -9610,7 +9914,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── QUALIFIED ABILITY IMPLEMENTATION ────────────────────── /code/proj/Main.roc ─
+    ── QUALIFIED ABILITY IMPLEMENTATION [E0233] ────────────── /code/proj/Main.roc ─
 
     This ability implementation is qualified:
 
@@ -9620,7 +9924,7 @@ In roc, functions are always written as a lambda, like{}
     Custom implementations must be defined in the local scope, and
     unqualified.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -9645,7 +9949,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── ABILITY IMPLEMENTATION NOT IDENTIFIER ───────────────── /code/proj/Main.roc ─
+    ── ABILITY IMPLEMENTATION NOT IDENTIFIER [E0234] ───────── /code/proj/Main.roc ─
 
     This ability implementation is not an identifier:
 
@@ -9657,7 +9961,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: consider defining this expression as a variable.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0238] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `MEq` ability:
 
@@ -9684,7 +9988,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── DUPLICATE IMPLEMENTATION ────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE IMPLEMENTATION [E0235] ────────────────────── /code/proj/Main.roc ─
 
     This ability member implementation is duplicate:
 
@@ -9712,7 +10016,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── NOT AN ABILITY ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT AN ABILITY [E0236] ──────────────────────────────── /code/proj/Main.roc ─
 
     This identifier is not an ability in scope:
 
@@ -9735,7 +10039,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── ILLEGAL DERIVE ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── ILLEGAL DERIVE [E0229] ──────────────────────────────── /code/proj/Main.roc ─
 
     This ability cannot be derived:
 
@@ -9758,7 +10062,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -9783,7 +10087,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -9835,7 +10139,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `main` name is first defined here:
 
@@ -9850,7 +10154,7 @@ In roc, functions are always written as a lambda, like{}
     Since these variables have the same name, it's easy to use the wrong
     one by accident. Give one of them a new name.
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -9884,7 +10188,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with the body of the `withOpen` definition:
 
@@ -9919,7 +10223,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -9966,7 +10270,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `foo` has an unexpected type:
 
@@ -10011,7 +10315,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to == has an unexpected type:
 
@@ -10036,7 +10340,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to == has an unexpected type:
 
@@ -10073,7 +10377,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the 2nd branch of this `when` expression:
 
@@ -10110,7 +10414,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -10136,7 +10440,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-        ── NAME NOT BOUND IN ALL PATTERNS ──────────────────────── /code/proj/Main.roc ─
+        ── NAME NOT BOUND IN ALL PATTERNS [E0115] ──────────────── /code/proj/Main.roc ─
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -10147,7 +10451,7 @@ In roc, functions are always written as a lambda, like{}
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── NAME NOT BOUND IN ALL PATTERNS ──────────────────────── /code/proj/Main.roc ─
+        ── NAME NOT BOUND IN ALL PATTERNS [E0115] ──────────────── /code/proj/Main.roc ─
 
         `y` is not bound in all patterns of this `when` branch
 
@@ -10158,7 +10462,7 @@ In roc, functions are always written as a lambda, like{}
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
         `y` is not used in this `when` branch.
 
@@ -10180,7 +10484,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -10206,7 +10510,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r###"── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+                r###"── UNRECOGNIZED NAME [E0108] ───────────────────────────── /code/proj/Main.roc ─
 
                 Nothing is named `foo` in this scope.
 
@@ -10243,7 +10547,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r###"── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+                r###"── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
                 An underscore is being used as a variable here:
 
@@ -10269,7 +10573,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r###"── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+                r###"── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
                 This variable's name starts with an underscore:
 
@@ -10302,7 +10606,7 @@ In roc, functions are always written as a lambda, like{}
             golden,
             indoc!(
                 r###"
-                ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
                 This variable's name starts with an underscore:
 
@@ -10329,7 +10633,7 @@ In roc, functions are always written as a lambda, like{}
             golden,
             indoc!(
                 r###"
-                ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── SYNTAX PROBLEM [E0118] ──────────────────────────────── /code/proj/Main.roc ─
 
                 Underscores are not allowed in identifier names:
 
@@ -10356,7 +10660,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── BAD RECORD BUILDER ────────── tmp/optional_field_in_record_builder/Test.roc ─
+    ── BAD RECORD BUILDER [E0402] ── tmp/optional_field_in_record_builder/Test.roc ─
 
     I am partway through parsing a record builder, and I found an optional
     field:
@@ -10385,7 +10689,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── BAD RECORD UPDATE ────────────────────── tmp/record_update_builder/Test.roc ─
+    ── BAD RECORD UPDATE [E0402] ────────────── tmp/record_update_builder/Test.roc ─
 
     I am partway through parsing a record update, and I found a record
     builder field:
@@ -10411,7 +10715,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── MULTIPLE RECORD BUILDERS ────────────────────────────── /code/proj/Main.roc ─
+    ── MULTIPLE RECORD BUILDERS [E0127] ────────────────────── /code/proj/Main.roc ─
 
     This function is applied to multiple record builders:
 
@@ -10434,7 +10738,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNAPPLIED RECORD BUILDER ────────────────────────────── /code/proj/Main.roc ─
+    ── UNAPPLIED RECORD BUILDER [E0128] ────────────────────── /code/proj/Main.roc ─
 
     This record builder was not applied to a function:
 
@@ -10459,7 +10763,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 1 argument:
 
@@ -10518,7 +10822,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10530,7 +10834,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10542,7 +10846,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10554,7 +10858,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10584,7 +10888,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10596,7 +10900,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10608,7 +10912,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10620,7 +10924,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E0116] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10648,7 +10952,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `hash` is not used anywhere in your code.
 
@@ -10675,7 +10979,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── WRONG SPECIALIZATION TYPE ───────────────────────────── /code/proj/Main.roc ─
+    ── WRONG SPECIALIZATION TYPE [E0309] ───────────────────── /code/proj/Main.roc ─
 
     This specialization of `hash` is not for the expected type:
 
@@ -10698,7 +11002,7 @@ In roc, functions are always written as a lambda, like{}
                 "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -10728,7 +11032,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E0201] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Recursive` opaque is self-recursive in an invalid way:
 
@@ -10750,7 +11054,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -10775,7 +11079,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -10830,7 +11134,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -10861,7 +11165,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -10891,7 +11195,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E0103] ─────────────────────────────── /code/proj/Main.roc ─
 
     This function doesn't use `x`.
 
@@ -10923,7 +11227,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -10956,7 +11260,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -10993,7 +11297,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -11025,7 +11329,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E0101] ───────────────────────────── /code/proj/Main.roc ─
 
     `foo` is not used in this `when` branch.
 
@@ -11051,7 +11355,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11080,7 +11384,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11146,7 +11450,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -11174,7 +11478,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -11201,7 +11505,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNMATCHABLE PATTERN ─────────────────────────────────── /code/proj/Main.roc ─
+    ── UNMATCHABLE PATTERN [E0307] ─────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern will never be matched:
 
@@ -11228,7 +11532,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNMATCHABLE PATTERN ─────────────────────────────────── /code/proj/Main.roc ─
+    ── UNMATCHABLE PATTERN [E0307] ─────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern will never be matched:
 
@@ -11240,7 +11544,7 @@ In roc, functions are always written as a lambda, like{}
     It's impossible to create a value of this shape, so this pattern can
     be safely removed!
 
-    ── UNMATCHABLE PATTERN ─────────────────────────────────── /code/proj/Main.roc ─
+    ── UNMATCHABLE PATTERN [E0307] ─────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern will never be matched:
 
@@ -11265,7 +11569,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E0107] ──────────────────────────────── /code/proj/Main.roc ─
 
     This opaque type has the same name as a builtin:
 
@@ -11287,7 +11591,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNUSED IMPORT ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED IMPORT [E0102] ───────────────────────────────── /code/proj/Main.roc ─
 
     `List.concat` is not used in this module.
 
@@ -11339,7 +11643,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR DEFINITION [E0111] ─────────────────────────── /code/proj/Main.roc ─
 
     `main` is defined directly in terms of itself:
 
@@ -11361,7 +11665,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` condition needs to be a Bool:
 
@@ -11387,7 +11691,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` condition needs to be a Bool:
 
@@ -11415,7 +11719,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -11440,7 +11744,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -11523,7 +11827,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11550,7 +11854,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11596,7 +11900,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11632,7 +11936,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `shiftRightZfBy` has an unexpected type:
 
@@ -11647,7 +11951,7 @@ In roc, functions are always written as a lambda, like{}
 
         U8
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `shiftRightBy` has an unexpected type:
 
@@ -11662,7 +11966,7 @@ In roc, functions are always written as a lambda, like{}
 
         U8
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `shiftLeftBy` has an unexpected type:
 
@@ -11690,7 +11994,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `contains` has an unexpected type:
 
@@ -11717,7 +12021,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -11742,7 +12046,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -11772,7 +12076,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -11798,7 +12102,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -11826,7 +12130,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -11909,7 +12213,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11936,7 +12240,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11965,7 +12269,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11988,7 +12292,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12028,7 +12332,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12066,7 +12370,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E0109] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The Bool module does not expose `structuralEq`:
 
@@ -12080,7 +12384,7 @@ In roc, functions are always written as a lambda, like{}
         Bool.false
         Bool.isEq
 
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E0109] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The Bool module does not expose `structuralNotEq`:
 
@@ -12108,7 +12412,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12133,7 +12437,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `foo` is weird:
 
@@ -12162,7 +12466,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Hash` ability for `F`:
 
@@ -12173,7 +12477,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: You can define a custom implementation of `Hash` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Eq` ability for `F`:
 
@@ -12184,7 +12488,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: You can define a custom implementation of `Eq` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0304] ───────────── /code/proj/Main.roc ─
 
     I can't derive an implementation of the `Encoding` ability for `F`:
 
@@ -12207,7 +12511,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DUPLICATE BOUND ABILITY ─────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE BOUND ABILITY [E0223] ─────────────────────── /code/proj/Main.roc ─
 
     I already saw that this type variable is bound to the `Hash` ability
     once before:
@@ -12233,7 +12537,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12270,7 +12574,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12308,7 +12612,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12341,7 +12645,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNFINISHED LIST PATTERN ────────── tmp/list_pattern_not_terminated/Test.roc ─
+    ── UNFINISHED LIST PATTERN [E0411] ── tmp/list_pattern_not_terminated/Test.roc ─
 
     I am partway through parsing a list pattern, but I got stuck here:
 
@@ -12362,7 +12666,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── INCORRECT REST PATTERN ─────── tmp/list_pattern_weird_rest_pattern/Test.roc ─
+    ── INCORRECT REST PATTERN [E0411] ─ ...ist_pattern_weird_rest_pattern/Test.roc ─
 
     It looks like you may trying to write a list rest pattern, but it's
     not the form I expect:
@@ -12384,7 +12688,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNNECESSARY WILDCARD ────────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY WILDCARD [E0214] ────────────────────────── /code/proj/Main.roc ─
 
     This type annotation has a wildcard type variable (`*`) that isn't
     needed.
@@ -12411,7 +12715,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── MULTIPLE LIST REST PATTERNS ─────────────────────────── /code/proj/Main.roc ─
+    ── MULTIPLE LIST REST PATTERNS [E0117] ─────────────────── /code/proj/Main.roc ─
 
     This list pattern match has multiple rest patterns:
 
@@ -12421,7 +12725,7 @@ In roc, functions are always written as a lambda, like{}
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12445,7 +12749,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── MULTIPLE LIST REST PATTERNS ─────────────────────────── /code/proj/Main.roc ─
+    ── MULTIPLE LIST REST PATTERNS [E0117] ─────────────────── /code/proj/Main.roc ─
 
     This list pattern match has multiple rest patterns:
 
@@ -12455,7 +12759,7 @@ In roc, functions are always written as a lambda, like{}
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12479,7 +12783,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0302] ───────────────────────────────── /code/proj/Main.roc ─
 
     This list element doesn't match the types of other elements in the
     pattern:
@@ -12506,7 +12810,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -12539,7 +12843,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12579,7 +12883,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12623,7 +12927,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
     
     The 2nd pattern is redundant:
     
@@ -12689,7 +12993,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12716,7 +13020,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12759,7 +13063,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12786,7 +13090,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12835,7 +13139,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12866,7 +13170,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12898,7 +13202,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12947,7 +13251,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -12981,7 +13285,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern is redundant:
 
@@ -13009,7 +13313,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern is redundant:
 
@@ -13037,7 +13341,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern is redundant:
 
@@ -13065,7 +13369,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E0306] ───────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern is redundant:
 
@@ -13104,7 +13408,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13135,7 +13439,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value passed to `crash` is not a string:
 
@@ -13160,7 +13464,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNAPPLIED CRASH ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNAPPLIED CRASH [E0129] ─────────────────────────────── /code/proj/Main.roc ─
 
     This `crash` doesn't have a message given to it:
 
@@ -13181,7 +13485,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── OVERAPPLIED CRASH ───────────────────────────────────── /code/proj/Main.roc ─
+    ── OVERAPPLIED CRASH [E0129] ───────────────────────────── /code/proj/Main.roc ─
 
     This `crash` has too many values given to it:
 
@@ -13217,7 +13521,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13259,7 +13563,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DEFINITION ONLY USED IN RECURSION ───────────────────── /code/proj/Main.roc ─
+    ── DEFINITION ONLY USED IN RECURSION [E0104] ───────────── /code/proj/Main.roc ─
 
     This definition is only used in recursion with itself:
 
@@ -13292,7 +13596,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DEFINITIONs ONLY USED IN RECURSION ──────────────────── /code/proj/Main.roc ─
+    ── DEFINITIONs ONLY USED IN RECURSION [E0104] ──────────── /code/proj/Main.roc ─
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13328,7 +13632,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DEFINITION ONLY USED IN RECURSION ───────────────────── /code/proj/Main.roc ─
+    ── DEFINITION ONLY USED IN RECURSION [E0104] ───────────── /code/proj/Main.roc ─
 
     This definition is only used in recursion with itself:
 
@@ -13364,7 +13668,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── DEFINITIONs ONLY USED IN RECURSION ──────────────────── /code/proj/Main.roc ─
+    ── DEFINITIONs ONLY USED IN RECURSION [E0104] ──────────── /code/proj/Main.roc ─
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13403,7 +13707,7 @@ In roc, functions are always written as a lambda, like{}
             {one, str}
         "#),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `concat` has an unexpected type:
 
@@ -13434,7 +13738,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -13471,7 +13775,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -13507,7 +13811,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `main` definition:
 
@@ -13545,7 +13849,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `main` definition:
 
@@ -13596,7 +13900,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13625,7 +13929,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13675,7 +13979,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13715,7 +14019,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0304] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13755,7 +14059,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E0305] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -13803,7 +14107,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     The `parser` value is an opaque type, so it cannot be called with an
     argument:
@@ -13827,7 +14131,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -13858,7 +14162,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -13892,7 +14196,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -13926,7 +14230,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E0301] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -13954,7 +14258,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO FEW ARGS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW ARGS [E0301] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -13974,7 +14278,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TOO FEW ARGS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW ARGS [E0301] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -251,6 +251,10 @@ fn generate_entry_docs(
                 ValueDef::ExpectFx { .. } => {
                     // Don't generate docs for `expect-fx`s
                 }
+
+                ValueDef::Malformed { .. } => {
+                    // Don't generate docs for defs that didn't parse
+                }
             },
             Ok(type_index) => match &defs.type_defs[type_index.index()] {
                 TypeDef::Alias {
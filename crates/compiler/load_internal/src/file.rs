@@ -1,12 +1,14 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::docs::ModuleDocumentation;
+use crate::lint::{apply_lint_levels, LintDirectives, LintProblem};
 use crate::module::{
     CheckedModule, ConstrainedModule, EntryPoint, Expectations, ExposedToHost,
     FoundSpecializationsModule, LateSpecializationsModule, LoadedModule, ModuleHeader,
     ModuleTiming, MonomorphizedModule, ParsedModule, ToplevelExpects, TypeCheckedModule,
 };
 use crate::module_cache::ModuleCache;
+use crate::type_cache::{self, TypeCache};
 use bumpalo::{collections::CollectIn, Bump};
 use crossbeam::channel::{bounded, Sender};
 use crossbeam::deque::{Injector, Stealer, Worker};
@@ -25,9 +27,9 @@ use roc_constrain::module::constrain_module;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION, ROC_PRINT_IR_AFTER_REFCOUNT,
-    ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_IR_AFTER_TRMC,
-    ROC_PRINT_LOAD_LOG,
+    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION, ROC_PRINT_IR_AFTER_OPTIMIZATION,
+    ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION,
+    ROC_PRINT_IR_AFTER_TRMC, ROC_PRINT_LOAD_LOG,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
@@ -38,14 +40,14 @@ use roc_module::symbol::{
     PackageQualified, Symbol,
 };
 use roc_mono::ir::{
-    CapturedSymbols, ExternalSpecializations, GlueLayouts, HostExposedLambdaSets, PartialProc,
-    Proc, ProcLayout, Procs, ProcsBase, UpdateModeIds, UsageTrackingMap,
+    CapturedSymbols, ExternalSpecializations, GlueLayouts, HostExposedLambdaSets, OptLevel,
+    PartialProc, Proc, ProcLayout, Procs, ProcsBase, UpdateModeIds, UsageTrackingMap,
 };
 use roc_mono::layout::{
     GlobalLayoutInterner, LambdaName, Layout, LayoutCache, LayoutProblem, Niche, STLayoutInterner,
 };
 use roc_mono::reset_reuse;
-use roc_mono::{drop_specialization, inc_dec};
+use roc_mono::{drop_specialization, inc_dec, optimize};
 use roc_packaging::cache::RocCacheDir;
 use roc_parse::ast::{
     self, CommentOrNewline, Expr, ExtractSpaces, Pattern, Spaced, StrLiteral, ValueDef,
@@ -54,8 +56,9 @@ use roc_parse::header::{
     ExposedName, HeaderType, ImportsEntry, PackageEntry, PackageHeader, PlatformHeader, To,
     TypedIdent,
 };
-use roc_parse::module::module_defs;
-use roc_parse::parser::{FileError, Parser, SourceError, SyntaxError};
+use roc_parse::module::module_defs_recovering;
+use roc_parse::parser::{FileError, SourceError, SyntaxError};
+use roc_problem::code;
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Loc, Region};
 #[cfg(not(target_family = "wasm"))]
@@ -110,6 +113,8 @@ pub struct LoadConfig {
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
     pub function_kind: FunctionKind,
+    /// Mono IR optimizations (inlining, constant folding, etc.) only run when optimizing
+    pub opt_level: OptLevel,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -326,6 +331,28 @@ fn start_phase<'a>(
 
                 let derived_module = SharedDerivedModule::clone(&state.derived_module);
 
+                let type_cache = state.type_cache_dir.as_ref().and_then(|cache_dir| {
+                    let key = type_cache::module_key(
+                        &state.module_cache,
+                        module_id,
+                        imported_modules.keys().copied(),
+                        state.target_info,
+                        state.function_kind,
+                    )?;
+
+                    state.module_cache.type_cache_keys.insert(module_id, key);
+
+                    // Builtins have their own cache, built into the compiler
+                    (!module_id.is_builtin()).then(|| {
+                        TypeCache::new(
+                            cache_dir,
+                            key,
+                            Arc::clone(&state.arc_modules),
+                            Arc::clone(&state.solved_ident_ids),
+                        )
+                    })
+                });
+
                 #[cfg(debug_assertions)]
                 let checkmate = if roc_checkmate::is_checkmate_enabled() {
                     Some(roc_checkmate::Collector::new())
@@ -348,6 +375,7 @@ fn start_phase<'a>(
                     dep_idents,
                     declarations,
                     state.cached_types.clone(),
+                    type_cache,
                     derived_module,
                     //
                     #[cfg(debug_assertions)]
@@ -735,6 +763,7 @@ struct State<'a> {
     pub render: RenderTarget,
     pub palette: Palette,
     pub exec_mode: ExecutionMode,
+    pub opt_level: OptLevel,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -744,6 +773,11 @@ struct State<'a> {
     // cached types (used for builtin modules, could include packages in the future too)
     cached_types: CachedTypeState,
 
+    /// Where to cache the solved types of user modules on disk, if anywhere
+    type_cache_dir: Option<PathBuf>,
+    /// The final IdentIds of every solved module, for translating symbols in the type cache
+    solved_ident_ids: SharedIdentIdsByModule,
+
     layout_interner: GlobalLayoutInterner<'a>,
 }
 
@@ -767,6 +801,8 @@ impl<'a> State<'a> {
         palette: Palette,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        opt_level: OptLevel,
+        type_cache_dir: Option<PathBuf>,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
         let cache_dir = roc_packaging::cache::roc_cache_dir();
@@ -799,9 +835,12 @@ impl<'a> State<'a> {
             timings: MutMap::default(),
             layout_caches: std::vec::Vec::with_capacity(number_of_workers),
             cached_types: Arc::new(Mutex::new(cached_types)),
+            type_cache_dir,
+            solved_ident_ids: Default::default(),
             render,
             palette,
             exec_mode,
+            opt_level,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalLayoutInterner::with_capacity(128, target_info),
@@ -891,6 +930,7 @@ enum BuildTask<'a> {
         declarations: Declarations,
         dep_idents: IdentIdsByModule,
         cached_subs: CachedTypeState,
+        type_cache: Option<TypeCache<'a>>,
         derived_module: SharedDerivedModule,
 
         #[cfg(debug_assertions)]
@@ -1056,6 +1096,7 @@ pub fn load_and_typecheck_str<'a>(
         threading,
         exec_mode: ExecutionMode::Check,
         function_kind,
+        opt_level: OptLevel::Development,
     };
 
     match load(
@@ -1321,6 +1362,7 @@ pub fn load<'a>(
             load_config.render,
             load_config.palette,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
@@ -1334,6 +1376,7 @@ pub fn load<'a>(
             load_config.palette,
             threads,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
     }
@@ -1350,6 +1393,7 @@ pub fn load_single_threaded<'a>(
     render: RenderTarget,
     palette: Palette,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1382,6 +1426,8 @@ pub fn load_single_threaded<'a>(
         palette,
         number_of_workers,
         exec_mode,
+        opt_level,
+        type_cache::type_cache_dir(roc_cache_dir),
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1464,6 +1510,15 @@ fn state_thread_step<'a>(
                     // We're done! There should be no more messages pending.
                     debug_assert!(msg_rx.is_empty());
 
+                    // Every module got checked despite its syntax errors, so all of them can be
+                    // reported at once; but loading still fails.
+                    if state.module_cache.has_syntax_errors() {
+                        let mut state = state;
+                        let buf = to_syntax_errors_report(&mut state);
+
+                        return Err(LoadingProblem::FormattedReport(buf));
+                    }
+
                     let exposed_aliases_by_symbol = exposed_aliases_by_symbol
                         .into_iter()
                         .map(|(k, (_, v))| (k, v))
@@ -1507,7 +1562,7 @@ fn state_thread_step<'a>(
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
-                    let buf = to_file_problem_report_string(&filename, error);
+                    let buf = to_file_problem_report_string(&filename, error, state.render);
                     Err(LoadingProblem::FormattedReport(buf))
                 }
 
@@ -1658,7 +1713,7 @@ pub fn report_loading_problem(
         }
         LoadingProblem::FormattedReport(report) => report,
         LoadingProblem::FileProblem { filename, error } => {
-            to_file_problem_report_string(&filename, error)
+            to_file_problem_report_string(&filename, error, render)
         }
         err => todo!("Loading error: {:?}", err),
     }
@@ -1675,6 +1730,7 @@ fn load_multi_threaded<'a>(
     palette: Palette,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1722,6 +1778,8 @@ fn load_multi_threaded<'a>(
         palette,
         num_workers,
         exec_mode,
+        opt_level,
+        type_cache::type_cache_dir(roc_cache_dir),
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2168,6 +2226,24 @@ fn report_unused_imported_modules(
     }
 }
 
+fn apply_module_lint_levels<P: LintProblem>(
+    state: &State<'_>,
+    module_id: ModuleId,
+    problems: Vec<P>,
+) -> Vec<P> {
+    if module_id.is_builtin() {
+        return problems;
+    }
+
+    let lint_directives = &state.module_cache.lint_directives;
+
+    apply_lint_levels(
+        problems,
+        lint_directives.get(&module_id),
+        lint_directives.get(&state.root_id),
+    )
+}
+
 fn extend_header_with_builtin(header: &mut ModuleHeader, module: ModuleId) {
     header
         .package_qualified_imported_modules
@@ -2209,6 +2285,13 @@ fn update<'a>(
             log!("loaded header for {:?}", header.module_id);
             let home = header.module_id;
 
+            if !home.is_builtin() {
+                state.module_cache.lint_directives.insert(
+                    home,
+                    LintDirectives::from_header_comments(header.header_comments),
+                );
+            }
+
             let mut work = MutSet::default();
 
             // Register the package's path under its shorthand
@@ -2461,14 +2544,58 @@ fn update<'a>(
 
             Ok(state)
         }
-        Parsed(parsed) => {
+        Parsed(mut parsed) => {
             state
                 .module_cache
                 .sources
                 .insert(parsed.module_id, (parsed.module_path.clone(), parsed.src));
 
+            if !parsed.syntax_errors.is_empty() {
+                let syntax_errors = std::mem::take(&mut parsed.syntax_errors);
+
+                state
+                    .module_cache
+                    .syntax_errors
+                    .insert(parsed.module_id, syntax_errors);
+
+                // Malformed defs can be checked, since they're just missing their bodies, but
+                // there's nothing to generate code from.
+                if state.goal_phase() > Phase::SolveTypes && !state.exec_mode.build_if_checks() {
+                    return Err(LoadingProblem::FormattedReport(to_syntax_errors_report(
+                        &mut state,
+                    )));
+                }
+            }
+
+            if state.type_cache_dir.is_some() {
+                if let Some(hash) =
+                    type_cache::source_hash(&parsed.header_type, parsed.src, &parsed.ingested_files)
+                {
+                    state
+                        .module_cache
+                        .source_hashes
+                        .insert(parsed.module_id, hash);
+                }
+            }
+
             let module_id = parsed.module_id;
 
+            if !module_id.is_builtin() {
+                state
+                    .module_cache
+                    .lint_directives
+                    .entry(module_id)
+                    .or_default()
+                    .add_defs(&parsed.parsed_defs);
+            }
+
+            if !parsed.ingested_files.is_empty() {
+                state
+                    .module_cache
+                    .ingested_files
+                    .insert(module_id, parsed.ingested_files.clone());
+            }
+
             state.module_cache.parsed.insert(module_id, parsed);
 
             let work = state.dependencies.notify(module_id, Phase::Parse);
@@ -2496,6 +2623,11 @@ fn update<'a>(
 
             report_unused_imported_modules(&mut state, module_id, &constrained_module);
 
+            if let Some(problems) = state.module_cache.can_problems.remove(&module_id) {
+                let problems = apply_module_lint_levels(&state, module_id, problems);
+                state.module_cache.can_problems.insert(module_id, problems);
+            }
+
             state
                 .module_cache
                 .aliases
@@ -2537,10 +2669,18 @@ fn update<'a>(
             log!("solved types for {:?}", module_id);
             module_timing.end_time = Instant::now();
 
+            if state.type_cache_dir.is_some() {
+                state
+                    .solved_ident_ids
+                    .lock()
+                    .insert(module_id, ident_ids.clone());
+            }
+
+            let type_problems = apply_module_lint_levels(&state, module_id, solved_module.problems);
             state
                 .module_cache
                 .type_problems
-                .insert(module_id, solved_module.problems);
+                .insert(module_id, type_problems);
 
             let should_include_expects = (!loc_expects.is_empty() || !loc_dbgs.is_empty()) && {
                 let modules = state.arc_modules.lock();
@@ -2916,6 +3056,20 @@ fn update<'a>(
 
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
+                    optimize::optimize_procs(
+                        arena,
+                        &layout_interner,
+                        module_id,
+                        ident_ids,
+                        state.opt_level,
+                        &mut state.procedures,
+                    );
+
+                    debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_OPTIMIZATION);
+                    debug_check_ir!(state, arena, layout_interner, ROC_CHECK_MONO_IR);
+
+                    let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
+
                     roc_mono::tail_recursion::apply_trmc(
                         arena,
                         &mut layout_interner,
@@ -3165,7 +3319,7 @@ fn finish_specialization<'a>(
                     }
                     Valid(To::NewPackage(p_or_p)) => PathBuf::from(p_or_p.as_str()),
                     other => {
-                        let buf = to_missing_platform_report(state.root_id, other);
+                        let buf = to_missing_platform_report(state.root_id, other, state.render);
                         return Err(LoadingProblem::FormattedReport(buf));
                     }
                 };
@@ -3228,6 +3382,7 @@ fn finish_specialization<'a>(
         type_problems,
         can_problems,
         sources,
+        ingested_files,
         ..
     } = module_cache;
 
@@ -3257,6 +3412,7 @@ fn finish_specialization<'a>(
         host_exposed_lambda_sets,
         entry_point,
         sources,
+        ingested_files,
         timings: state.timings,
         toplevel_expects,
         glue_layouts: GlueLayouts { getters: vec![] },
@@ -3357,6 +3513,7 @@ fn finish(
         exposed_types_storage,
         resolved_implementations,
         sources,
+        ingested_files: state.module_cache.ingested_files,
         timings: state.timings,
         docs_by_module,
         abilities_store,
@@ -4577,6 +4734,7 @@ impl<'a> BuildTask<'a> {
         dep_idents: IdentIdsByModule,
         declarations: Declarations,
         cached_subs: CachedTypeState,
+        type_cache: Option<TypeCache<'a>>,
         derived_module: SharedDerivedModule,
 
         #[cfg(debug_assertions)] checkmate: Option<roc_checkmate::Collector>,
@@ -4601,6 +4759,7 @@ impl<'a> BuildTask<'a> {
             dep_idents,
             module_timing,
             cached_subs,
+            type_cache,
             derived_module,
 
             #[cfg(debug_assertions)]
@@ -4990,6 +5149,54 @@ fn run_solve_solve(
     }
 }
 
+/// Stores the types of a module that solved without problems in the on-disk type cache.
+/// If that fails, the module just gets solved again next time.
+fn write_type_cache(
+    type_cache: &TypeCache,
+    module_id: ModuleId,
+    ident_ids: &IdentIds,
+    fingerprint: type_cache::TypeCacheKey,
+    solve_result: SolveResult,
+) -> SolveResult {
+    let SolveResult {
+        solved,
+        solved_implementations,
+        exposed_vars_by_symbol,
+        problems,
+        abilities_store,
+
+        #[cfg(debug_assertions)]
+        checkmate,
+    } = solve_result;
+
+    let mut type_state = TypeState {
+        subs: solved.into_inner(),
+        exposed_vars_by_symbol,
+        abilities: abilities_store,
+        solved_implementations,
+    };
+
+    let _ = type_cache.write(module_id, ident_ids, fingerprint, &mut type_state);
+
+    let TypeState {
+        subs,
+        exposed_vars_by_symbol,
+        abilities,
+        solved_implementations,
+    } = type_state;
+
+    SolveResult {
+        solved: Solved(subs),
+        solved_implementations,
+        exposed_vars_by_symbol,
+        problems,
+        abilities_store: abilities,
+
+        #[cfg(debug_assertions)]
+        checkmate,
+    }
+}
+
 fn run_solve<'a>(
     module: Module,
     ident_ids: IdentIds,
@@ -5000,10 +5207,11 @@ fn run_solve<'a>(
     constraint: ConstraintSoa,
     function_kind: FunctionKind,
     pending_derives: PendingDerives,
-    var_store: VarStore,
+    mut var_store: VarStore,
     decls: Declarations,
     dep_idents: IdentIdsByModule,
     cached_types: CachedTypeState,
+    type_cache: Option<TypeCache<'a>>,
     derived_module: SharedDerivedModule,
 
     #[cfg(debug_assertions)] checkmate: Option<roc_checkmate::Collector>,
@@ -5020,41 +5228,22 @@ fn run_solve<'a>(
     let loc_dbgs = std::mem::take(&mut module.loc_dbgs);
     let module = module;
 
-    let solve_result = {
-        if module_id.is_builtin() {
-            match cached_types.lock().remove(&module_id) {
-                None => run_solve_solve(
-                    exposed_for_module,
-                    types,
-                    constraints,
-                    constraint,
-                    function_kind,
-                    pending_derives,
-                    var_store,
-                    module,
-                    derived_module,
-                    //
-                    #[cfg(debug_assertions)]
-                    checkmate,
-                ),
-                Some(TypeState {
-                    subs,
-                    exposed_vars_by_symbol,
-                    abilities,
-                    solved_implementations,
-                }) => SolveResult {
-                    solved: Solved(subs),
-                    solved_implementations,
-                    exposed_vars_by_symbol,
-                    problems: vec![],
-                    abilities_store: abilities,
+    let type_cache = type_cache.map(|type_cache| {
+        let fingerprint = type_cache::fingerprint(&mut var_store, &decls, &ident_ids);
+        (type_cache, fingerprint)
+    });
 
-                    #[cfg(debug_assertions)]
-                    checkmate: None,
-                },
-            }
-        } else {
-            run_solve_solve(
+    let cached_type_state = if module_id.is_builtin() {
+        cached_types.lock().remove(&module_id)
+    } else {
+        type_cache.as_ref().and_then(|(type_cache, fingerprint)| {
+            type_cache.read(module_id, &ident_ids, *fingerprint)
+        })
+    };
+
+    let solve_result = match cached_type_state {
+        None => {
+            let solve_result = run_solve_solve(
                 exposed_for_module,
                 types,
                 constraints,
@@ -5067,8 +5256,36 @@ fn run_solve<'a>(
                 //
                 #[cfg(debug_assertions)]
                 checkmate,
-            )
+            );
+
+            match &type_cache {
+                Some((type_cache, fingerprint)) if solve_result.problems.is_empty() => {
+                    write_type_cache(
+                        type_cache,
+                        module_id,
+                        &ident_ids,
+                        *fingerprint,
+                        solve_result,
+                    )
+                }
+                _ => solve_result,
+            }
         }
+        Some(TypeState {
+            subs,
+            exposed_vars_by_symbol,
+            abilities,
+            solved_implementations,
+        }) => SolveResult {
+            solved: Solved(subs),
+            solved_implementations,
+            exposed_vars_by_symbol,
+            problems: vec![],
+            abilities_store: abilities,
+
+            #[cfg(debug_assertions)]
+            checkmate: None,
+        },
     };
 
     let SolveResult {
@@ -5444,15 +5661,21 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     let parse_start = Instant::now();
     let source = header.parse_state.original_bytes();
     let parse_state = header.parse_state;
-    let mut parsed_defs = match module_defs().parse(arena, parse_state.clone(), 0) {
-        Ok((_, success, _state)) => success,
-        Err((_, fail)) => {
-            return Err(LoadingProblem::ParsingFailed(
-                fail.into_file_error(header.module_path, &parse_state),
-            ));
-        }
-    };
+    // Keep going past syntax errors, so that all of them get reported at once, and the defs
+    // that did parse still get checked.
+    let (mut parsed_defs, syntax_errors) = module_defs_recovering(arena, parse_state.clone());
+    let syntax_errors = syntax_errors
+        .into_iter()
+        .map(|fail| fail.into_file_error(header.module_path.clone(), &parse_state))
+        .collect();
+    let mut ingested_files = Vec::new();
     for value in header.defined_values.into_iter() {
+        if let ValueDef::AnnotatedBody { body_expr, .. } = &value {
+            if let Expr::IngestedFile(path, _) = body_expr.value {
+                ingested_files.push(path.to_path_buf());
+            }
+        }
+
         // TODO: should these have a region?
         parsed_defs.push_value_def(value, Region::zero(), &[], &[]);
     }
@@ -5495,6 +5718,8 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         symbols_from_requires,
         header_type,
         header_comments: header_docs,
+        ingested_files,
+        syntax_errors,
     };
 
     Ok(Msg::Parsed(parsed))
@@ -5821,9 +6046,11 @@ fn build_pending_specializations<'a>(
                             annotation: expr_var,
                             // This is a 0-arity thunk, so it has no arguments.
                             pattern_symbols: &[],
+                            pattern_regions: &[],
                             // This is a top-level definition, so it cannot capture anything
                             captured_symbols: CapturedSymbols::None,
                             body: body.value,
+                            body_region: body.region,
                             body_var: expr_var,
                             // This is a 0-arity thunk, so it cannot be recursive
                             is_self_recursive: false,
@@ -5957,9 +6184,11 @@ fn build_pending_specializations<'a>(
                     annotation: expr_var,
                     // This is a 0-arity thunk, so it has no arguments.
                     pattern_symbols: &[],
+                    pattern_regions: &[],
                     // This is a top-level definition, so it cannot capture anything
                     captured_symbols: CapturedSymbols::None,
                     body: body.value,
+                    body_region: body.region,
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
@@ -6025,9 +6254,11 @@ fn build_pending_specializations<'a>(
                     annotation: expr_var,
                     // This is a 0-arity thunk, so it has no arguments.
                     pattern_symbols: &[],
+                    pattern_regions: &[],
                     // This is a top-level definition, so it cannot capture anything
                     captured_symbols: CapturedSymbols::None,
                     body: body.value,
+                    body_region: body.region,
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
@@ -6097,9 +6328,11 @@ fn build_pending_specializations<'a>(
                     annotation: expr_var,
                     // This is a 0-arity thunk, so it has no arguments.
                     pattern_symbols: &[],
+                    pattern_regions: &[],
                     // This is a top-level definition, so it cannot capture anything
                     captured_symbols: CapturedSymbols::None,
                     body: body.value,
+                    body_region: body.region,
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
@@ -6249,9 +6482,11 @@ fn load_derived_partial_procs<'a>(
                     annotation: derived_expr_var,
                     // This is a 0-arity thunk, so it has no arguments.
                     pattern_symbols: &[],
+                    pattern_regions: &[],
                     // This is a top-level definition, so it cannot capture anything
                     captured_symbols: CapturedSymbols::None,
                     body: derived_expr,
+                    body_region: Region::zero(),
                     body_var: derived_expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
@@ -6340,6 +6575,7 @@ fn run_task<'a>(
             declarations,
             dep_idents,
             cached_subs,
+            type_cache,
             derived_module,
 
             #[cfg(debug_assertions)]
@@ -6358,6 +6594,7 @@ fn run_task<'a>(
             declarations,
             dep_idents,
             cached_subs,
+            type_cache,
             derived_module,
             //
             #[cfg(debug_assertions)]
@@ -6480,6 +6717,7 @@ fn to_import_cycle_report(
         filename,
         doc,
         title: "IMPORT CYCLE".to_string(),
+        code: code::IMPORT_CYCLE,
         severity: Severity::RuntimeError,
     };
 
@@ -6529,6 +6767,7 @@ fn to_incorrect_module_name_report<'a>(
         filename,
         doc,
         title: "INCORRECT MODULE NAME".to_string(),
+        code: code::INCORRECT_MODULE_NAME,
         severity: Severity::RuntimeError,
     };
 
@@ -6582,7 +6821,44 @@ fn to_parse_problem_report<'a>(
     buf
 }
 
-fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> String {
+/// Renders the syntax errors of every module that parsing recovered from, file by file.
+fn to_syntax_errors_report(state: &mut State) -> String {
+    let module_ids = state.arc_modules.lock().clone().into_module_ids();
+
+    let mut syntax_errors: Vec<_> = state
+        .module_cache
+        .syntax_errors
+        .drain()
+        .flat_map(|(_, errors)| errors)
+        .collect();
+
+    // The errors of each module are in order already; keep the modules in a stable order too.
+    syntax_errors.sort_by_key(|problem| problem.filename.clone());
+
+    let reports: Vec<String> = syntax_errors
+        .into_iter()
+        .map(|problem| {
+            // if parsing failed, this module did not add anything to IdentIds
+            let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
+
+            to_parse_problem_report(
+                problem,
+                module_ids.clone(),
+                root_exposed_ident_ids,
+                state.render,
+                state.palette,
+            )
+        })
+        .collect();
+
+    reports.join("\n")
+}
+
+fn to_missing_platform_report(
+    module_id: ModuleId,
+    other: &PlatformPath,
+    render: RenderTarget,
+) -> String {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
     use PlatformPath::*;
@@ -6608,6 +6884,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> Stri
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: code::NO_PLATFORM,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6623,6 +6900,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> Stri
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: code::NO_PLATFORM,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6638,6 +6916,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> Stri
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: code::NO_PLATFORM,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6653,6 +6932,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> Stri
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: code::NO_PLATFORM,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6661,7 +6941,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: &PlatformPath) -> Stri
 
     let palette = DEFAULT_PALETTE;
     let mut buf = String::new();
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(render, &mut buf, &alloc, &palette);
 
     buf
 }
@@ -6,8 +6,10 @@
 use roc_module::symbol::ModuleId;
 pub mod docs;
 pub mod file;
+mod lint;
 pub mod module;
 mod module_cache;
+mod type_cache;
 mod work;
 
 #[cfg(target_family = "wasm")]
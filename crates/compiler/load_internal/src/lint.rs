@@ -0,0 +1,462 @@
+//! Applying the `# lint: allow ...` and `# lint: deny ...` comments of a module to its problems.
+use roc_parse::ast::{
+    AssignedField, CommentOrNewline, Defs, Expr, RecordBuilderField, StrLiteral, StrSegment,
+    ValueDef,
+};
+use roc_problem::can::Problem;
+use roc_problem::lint::{Lint, LintLevel, LintLevels};
+use roc_region::all::Region;
+use roc_solve_problem::TypeError;
+
+/// The lint levels set by the comments in one module's source.
+#[derive(Debug, Default)]
+pub(crate) struct LintDirectives {
+    /// Set by comments above the module's header
+    module: LintLevels,
+    /// Set by comments directly above a def, which apply to that def's byte range
+    defs: Vec<DefDirectives>,
+}
+
+#[derive(Debug)]
+struct DefDirectives {
+    start: u32,
+    end: u32,
+    levels: LintLevels,
+}
+
+impl LintDirectives {
+    /// The lint levels set by the comments the parser found above the module's header.
+    pub(crate) fn from_header_comments(comments: &[CommentOrNewline]) -> Self {
+        let mut module = LintLevels::default();
+
+        for comment in comments {
+            if let CommentOrNewline::LineComment(text) = comment {
+                module.set_from_comment(text);
+            }
+        }
+
+        LintDirectives {
+            module,
+            defs: Vec::new(),
+        }
+    }
+
+    /// Adds the lint levels set by the comments above these defs, and above the defs nested in
+    /// them. These come from the parser's comments, so a `# lint:` line in a string isn't one.
+    pub(crate) fn add_defs(&mut self, defs: &Defs) {
+        self.add_defs_help(defs, &[]);
+    }
+
+    /// `leading` are the spaces before the first def, when the parser kept them outside of `defs`.
+    fn add_defs_help<'a>(&mut self, defs: &Defs<'a>, leading: &[CommentOrNewline<'a>]) {
+        for (index, def) in defs.defs().enumerate() {
+            let space_before = &defs.spaces[defs.space_before[index].indices()];
+            let spaces = if index == 0 { leading } else { &[] };
+            let levels = directive_levels(spaces.iter().chain(space_before));
+
+            if !levels.is_empty() {
+                let region = defs.regions[index];
+
+                self.defs.push(DefDirectives {
+                    start: region.start().offset,
+                    end: region.end().offset,
+                    levels,
+                });
+            }
+
+            if let Err(value_def) = def {
+                self.add_value_def(value_def);
+            }
+        }
+    }
+
+    fn add_value_def(&mut self, value_def: &ValueDef) {
+        match value_def {
+            ValueDef::Body(_, expr) => self.add_expr(&expr.value),
+            ValueDef::AnnotatedBody { body_expr, .. } => self.add_expr(&body_expr.value),
+            ValueDef::Dbg { condition, .. }
+            | ValueDef::Expect { condition, .. }
+            | ValueDef::ExpectFx { condition, .. } => self.add_expr(&condition.value),
+            ValueDef::Annotation(_, _) | ValueDef::Malformed { .. } => {}
+        }
+    }
+
+    fn add_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Defs(defs, body) => {
+                self.add_defs_help(defs, &[]);
+                self.add_expr(&body.value);
+            }
+            // The comments above the first def of a block are kept around the whole block
+            Expr::SpaceBefore(Expr::Defs(defs, body), spaces) => {
+                self.add_defs_help(defs, spaces);
+                self.add_expr(&body.value);
+            }
+            Expr::SpaceBefore(expr, _) | Expr::SpaceAfter(expr, _) | Expr::ParensAround(expr) => {
+                self.add_expr(expr)
+            }
+            Expr::Str(literal) => self.add_str(literal),
+            Expr::RecordAccess(expr, _) | Expr::TupleAccess(expr, _) => self.add_expr(expr),
+            Expr::List(items) | Expr::Tuple(items) => {
+                for item in items.iter() {
+                    self.add_expr(&item.value);
+                }
+            }
+            Expr::RecordUpdate { update, fields } => {
+                self.add_expr(&update.value);
+
+                for field in fields.iter() {
+                    self.add_field(&field.value);
+                }
+            }
+            Expr::Record(fields) => {
+                for field in fields.iter() {
+                    self.add_field(&field.value);
+                }
+            }
+            Expr::RecordBuilder(fields) => {
+                for field in fields.iter() {
+                    self.add_builder_field(&field.value);
+                }
+            }
+            Expr::Closure(_, body) => self.add_expr(&body.value),
+            Expr::Backpassing(_, call, body) => {
+                self.add_expr(&call.value);
+                self.add_expr(&body.value);
+            }
+            Expr::Expect(condition, continuation) | Expr::Dbg(condition, continuation) => {
+                self.add_expr(&condition.value);
+                self.add_expr(&continuation.value);
+            }
+            Expr::Apply(function, args, _) => {
+                self.add_expr(&function.value);
+
+                for arg in args.iter() {
+                    self.add_expr(&arg.value);
+                }
+            }
+            Expr::BinOps(firsts, last) => {
+                for (expr, _) in firsts.iter() {
+                    self.add_expr(&expr.value);
+                }
+
+                self.add_expr(&last.value);
+            }
+            Expr::UnaryOp(expr, _) => self.add_expr(&expr.value),
+            Expr::If(branches, final_else) => {
+                for (condition, then) in branches.iter() {
+                    self.add_expr(&condition.value);
+                    self.add_expr(&then.value);
+                }
+
+                self.add_expr(&final_else.value);
+            }
+            Expr::When(condition, branches) => {
+                self.add_expr(&condition.value);
+
+                for branch in branches.iter() {
+                    if let Some(guard) = &branch.guard {
+                        self.add_expr(&guard.value);
+                    }
+
+                    self.add_expr(&branch.value.value);
+                }
+            }
+            Expr::Float(_)
+            | Expr::Num(_)
+            | Expr::NonBase10Int { .. }
+            | Expr::SingleQuote(_)
+            | Expr::AccessorFunction(_)
+            | Expr::IngestedFile(_, _)
+            | Expr::Var { .. }
+            | Expr::Underscore(_)
+            | Expr::Crash
+            | Expr::Tag(_)
+            | Expr::OpaqueRef(_)
+            | Expr::MalformedIdent(_, _)
+            | Expr::MalformedClosure
+            | Expr::PrecedenceConflict(_)
+            | Expr::MultipleRecordBuilders(_)
+            | Expr::UnappliedRecordBuilder(_) => {}
+        }
+    }
+
+    fn add_str(&mut self, literal: &StrLiteral) {
+        match literal {
+            StrLiteral::PlainLine(_) => {}
+            StrLiteral::Line(segments) => self.add_segments(segments),
+            StrLiteral::Block(lines) => {
+                for segments in lines.iter() {
+                    self.add_segments(segments);
+                }
+            }
+        }
+    }
+
+    fn add_segments(&mut self, segments: &[StrSegment]) {
+        for segment in segments {
+            if let StrSegment::Interpolated(expr) = segment {
+                self.add_expr(&expr.value);
+            }
+        }
+    }
+
+    fn add_field(&mut self, field: &AssignedField<Expr>) {
+        match field {
+            AssignedField::RequiredValue(_, _, value)
+            | AssignedField::OptionalValue(_, _, value) => self.add_expr(&value.value),
+            AssignedField::SpaceBefore(field, _) | AssignedField::SpaceAfter(field, _) => {
+                self.add_field(field)
+            }
+            AssignedField::LabelOnly(_) | AssignedField::Malformed(_) => {}
+        }
+    }
+
+    fn add_builder_field(&mut self, field: &RecordBuilderField) {
+        match field {
+            RecordBuilderField::Value(_, _, value)
+            | RecordBuilderField::ApplyValue(_, _, _, value) => self.add_expr(&value.value),
+            RecordBuilderField::SpaceBefore(field, _)
+            | RecordBuilderField::SpaceAfter(field, _) => self.add_builder_field(field),
+            RecordBuilderField::LabelOnly(_) | RecordBuilderField::Malformed(_) => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.module.is_empty() && self.defs.is_empty()
+    }
+
+    /// The level of a lint at a position in this module, if any comment set it.
+    fn get(&self, lint: Lint, position: u32) -> Option<LintLevel> {
+        // The innermost def's comments take precedence
+        let mut containing: Vec<&DefDirectives> = self
+            .defs
+            .iter()
+            .filter(|def| def.start <= position && position < def.end)
+            .collect();
+
+        containing.sort_by_key(|def| std::cmp::Reverse(def.start));
+
+        containing
+            .into_iter()
+            .find_map(|def| def.levels.get(lint))
+            .or_else(|| self.module.get(lint))
+    }
+}
+
+/// The lint levels set by the comments in the spaces before a def. A comment only counts if it's
+/// on a line of its own, and a blank line between it and the def detaches it.
+fn directive_levels<'a, 'b: 'a>(
+    spaces: impl Iterator<Item = &'a CommentOrNewline<'b>>,
+) -> LintLevels {
+    let mut levels = LintLevels::default();
+    // The spaces start at the end of the line before the def, which may have code on it.
+    let mut at_line_start = false;
+
+    for space in spaces {
+        match space {
+            CommentOrNewline::Newline => {
+                if at_line_start {
+                    levels = LintLevels::default();
+                }
+            }
+            CommentOrNewline::LineComment(text) => {
+                if at_line_start {
+                    levels.set_from_comment(text);
+                }
+            }
+            CommentOrNewline::DocComment(_) => {}
+        }
+
+        // Comments end with their newline
+        at_line_start = true;
+    }
+
+    levels
+}
+
+/// A problem that may belong to a lint, and so can be allowed or denied.
+pub(crate) trait LintProblem: Sized {
+    fn lint(&self) -> Option<Lint>;
+
+    fn region(&self) -> Option<Region>;
+
+    fn deny(self, lint: Lint) -> Self;
+}
+
+impl LintProblem for Problem {
+    fn lint(&self) -> Option<Lint> {
+        Problem::lint(self)
+    }
+
+    fn region(&self) -> Option<Region> {
+        Problem::region(self)
+    }
+
+    fn deny(self, lint: Lint) -> Self {
+        Problem::Denied(lint, Box::new(self))
+    }
+}
+
+impl LintProblem for TypeError {
+    fn lint(&self) -> Option<Lint> {
+        TypeError::lint(self)
+    }
+
+    fn region(&self) -> Option<Region> {
+        TypeError::region(self)
+    }
+
+    fn deny(self, lint: Lint) -> Self {
+        TypeError::Denied(lint, Box::new(self))
+    }
+}
+
+/// Drops the problems whose lint is allowed, and turns the ones whose lint is denied into errors.
+/// The module's own comments take precedence over the ones in the root module.
+pub(crate) fn apply_lint_levels<P: LintProblem>(
+    problems: Vec<P>,
+    module: Option<&LintDirectives>,
+    root: Option<&LintDirectives>,
+) -> Vec<P> {
+    let module = module.filter(|directives| !directives.is_empty());
+    let root = root.filter(|directives| !directives.is_empty());
+
+    if module.is_none() && root.is_none() {
+        return problems;
+    }
+
+    problems
+        .into_iter()
+        .filter_map(|problem| {
+            let lint = match problem.lint() {
+                Some(lint) => lint,
+                None => return Some(problem),
+            };
+
+            let position = problem.region().map(|region| region.start().offset);
+            let module_level =
+                module.and_then(|directives| directives.get(lint, position.unwrap_or(0)));
+            let root_level = root.and_then(|directives| directives.module.get(lint));
+
+            match module_level.or(root_level).unwrap_or(LintLevel::Warn) {
+                LintLevel::Allow => None,
+                LintLevel::Warn => Some(problem),
+                LintLevel::Deny => Some(problem.deny(lint)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::LintDirectives;
+    use bumpalo::Bump;
+    use indoc::indoc;
+    use roc_parse::module::{module_defs, parse_header};
+    use roc_parse::parser::Parser;
+    use roc_parse::state::State;
+    use roc_problem::lint::{Lint, LintLevel};
+
+    fn level_at(src: &str, needle: &str, lint: Lint) -> Option<LintLevel> {
+        let arena = Bump::new();
+        let (module, state) = parse_header(&arena, State::new(src.as_bytes())).unwrap();
+        let (_, defs, _) = module_defs().parse(&arena, state, 0).unwrap();
+
+        let mut directives = LintDirectives::from_header_comments(module.comments);
+        directives.add_defs(&defs);
+
+        let position = src.find(needle).unwrap() as u32;
+
+        directives.get(lint, position)
+    }
+
+    #[test]
+    fn module_and_def_levels() {
+        let src = indoc!(
+            r#"
+            # lint: deny unused-def
+
+            interface Foo
+                exposes [main]
+                imports []
+
+            # Just a regular comment
+            # lint: allow unused-def, unused-argument
+            helper : Str -> Str
+            helper = \arg ->
+                unused = 1
+
+                "hi"
+
+            main =
+                # lint: allow redundant-branch
+                other = 2
+                helper "x"
+            "#
+        );
+
+        assert_eq!(
+            level_at(src, "unused = 1", Lint::UnusedDef),
+            Some(LintLevel::Allow)
+        );
+        assert_eq!(
+            level_at(src, "arg ->", Lint::UnusedArgument),
+            Some(LintLevel::Allow)
+        );
+        assert_eq!(
+            level_at(src, "other = 2", Lint::UnusedDef),
+            Some(LintLevel::Deny)
+        );
+        assert_eq!(
+            level_at(src, "other = 2", Lint::RedundantBranch),
+            Some(LintLevel::Allow)
+        );
+        assert_eq!(level_at(src, "other = 2", Lint::UnusedImport), None);
+    }
+
+    #[test]
+    fn blank_line_detaches_directive() {
+        let src = indoc!(
+            r#"
+            interface Foo
+                exposes [main]
+                imports []
+
+            # lint: allow unused-def
+
+            main =
+                unused = 1
+                2
+            "#
+        );
+
+        assert_eq!(level_at(src, "unused = 1", Lint::UnusedDef), None);
+    }
+
+    #[test]
+    fn directives_are_comments() {
+        let src = indoc!(
+            r#"
+            interface Foo
+                exposes [main, other]
+                imports []
+
+            main =
+                x = 1 # lint: allow unused-def
+                y = 2
+                "hi"
+
+            other =
+                s =
+                    """
+                    # lint: allow unused-def"""
+                unused = 1
+                s
+            "#
+        );
+
+        assert_eq!(level_at(src, "y = 2", Lint::UnusedDef), None);
+        assert_eq!(level_at(src, "unused = 1", Lint::UnusedDef), None);
+    }
+}
@@ -15,6 +15,7 @@ use roc_mono::ir::{GlueLayouts, HostExposedLambdaSets, LambdaSetId, Proc, ProcLa
 use roc_mono::layout::{LayoutCache, STLayoutInterner};
 use roc_parse::ast::{CommentOrNewline, Defs, TypeAnnotation, ValueDef};
 use roc_parse::header::{HeaderType, PackageName};
+use roc_parse::parser::{FileError, SyntaxError};
 use roc_region::all::{Loc, Region};
 use roc_solve::module::Solved;
 use roc_solve_problem::TypeError;
@@ -42,6 +43,7 @@ pub struct LoadedModule {
     pub exposed_types_storage: ExposedTypesStorageSubs,
     pub resolved_implementations: ResolvedImplementations,
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
+    pub ingested_files: MutMap<ModuleId, Vec<PathBuf>>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub docs_by_module: Vec<(ModuleId, ModuleDocumentation)>,
     pub abilities_store: AbilitiesStore,
@@ -63,6 +65,12 @@ impl LoadedModule {
         total
     }
 
+    /// Every file that was read to load this module: the sources of all modules, along with
+    /// the files they ingest.
+    pub fn source_paths(&self) -> Vec<PathBuf> {
+        source_paths(&self.sources, &self.ingested_files)
+    }
+
     pub fn exposed_values_str(&self) -> Vec<&str> {
         self.exposed_values
             .iter()
@@ -178,12 +186,33 @@ pub struct MonomorphizedModule<'a> {
     pub entry_point: EntryPoint<'a>,
     pub exposed_to_host: ExposedToHost,
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
+    pub ingested_files: MutMap<ModuleId, Vec<PathBuf>>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub expectations: VecMap<ModuleId, Expectations>,
     pub uses_prebuilt_platform: bool,
     pub glue_layouts: GlueLayouts<'a>,
 }
 
+impl<'a> MonomorphizedModule<'a> {
+    /// Every file that was read to load this module: the sources of all modules, along with
+    /// the files they ingest.
+    pub fn source_paths(&self) -> Vec<PathBuf> {
+        source_paths(&self.sources, &self.ingested_files)
+    }
+}
+
+fn source_paths(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    ingested_files: &MutMap<ModuleId, Vec<PathBuf>>,
+) -> Vec<PathBuf> {
+    let module_paths = sources.values().map(|(path, _)| path);
+
+    module_paths
+        .chain(ingested_files.values().flatten())
+        .cloned()
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct ParsedModule<'a> {
     pub module_id: ModuleId,
@@ -198,6 +227,10 @@ pub struct ParsedModule<'a> {
     pub symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     pub header_type: HeaderType<'a>,
     pub header_comments: &'a [CommentOrNewline<'a>],
+    /// The files this module imports as bytes or a string
+    pub ingested_files: Vec<PathBuf>,
+    /// The syntax errors of the defs that didn't parse, which are malformed in `parsed_defs`
+    pub syntax_errors: Vec<FileError<'a, SyntaxError<'a>>>,
 }
 
 #[derive(Debug)]
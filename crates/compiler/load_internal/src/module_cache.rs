@@ -1,13 +1,16 @@
 use crate::docs::ModuleDocumentation;
+use crate::lint::LintDirectives;
 use crate::module::{
     CheckedModule, ConstrainedModule, FoundSpecializationsModule, LateSpecializationsModule,
     ModuleHeader, ParsedModule, TypeCheckedModule,
 };
+use crate::type_cache::TypeCacheKey;
 use roc_can::abilities::PendingAbilitiesStore;
 use roc_collections::{MutMap, MutSet, VecMap};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{ModuleId, PQModuleName, Symbol};
 use roc_mono::ir::ExternalSpecializations;
+use roc_parse::parser::{FileError, SyntaxError};
 use roc_problem::Severity;
 use roc_solve_problem::TypeError;
 use roc_types::types::Alias;
@@ -37,8 +40,18 @@ pub(crate) struct ModuleCache<'a> {
     pub(crate) documentation: VecMap<ModuleId, ModuleDocumentation>,
     pub(crate) can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub(crate) type_problems: MutMap<ModuleId, Vec<TypeError>>,
+    /// Syntax errors that parsing recovered from; loading still fails once they're reported.
+    pub(crate) syntax_errors: MutMap<ModuleId, Vec<FileError<'a, SyntaxError<'a>>>>,
+    pub(crate) lint_directives: MutMap<ModuleId, LintDirectives>,
 
     pub(crate) sources: MutMap<ModuleId, (PathBuf, &'a str)>,
+    pub(crate) ingested_files: MutMap<ModuleId, Vec<PathBuf>>,
+
+    /// Hashes of the sources of modules whose solved types can be cached on disk.
+    /// Only populated when the type cache is enabled.
+    pub(crate) source_hashes: MutMap<ModuleId, TypeCacheKey>,
+    /// Keys into the type cache, which cover a module's source and all of its imports
+    pub(crate) type_cache_keys: MutMap<ModuleId, TypeCacheKey>,
 }
 
 impl<'a> ModuleCache<'a> {
@@ -56,8 +69,12 @@ impl<'a> ModuleCache<'a> {
             .any(|problem| problem.severity() == Severity::RuntimeError)
     }
 
+    pub(crate) fn has_syntax_errors(&self) -> bool {
+        self.syntax_errors.values().any(|errors| !errors.is_empty())
+    }
+
     pub fn has_errors(&self) -> bool {
-        self.has_can_errors() || self.has_type_errors()
+        self.has_syntax_errors() || self.has_can_errors() || self.has_type_errors()
     }
 }
 
@@ -107,7 +124,12 @@ impl Default for ModuleCache<'_> {
             documentation: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
+            syntax_errors: Default::default(),
+            lint_directives: Default::default(),
             sources: Default::default(),
+            ingested_files: Default::default(),
+            source_hashes: Default::default(),
+            type_cache_keys: Default::default(),
         }
     }
 }
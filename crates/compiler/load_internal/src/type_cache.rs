@@ -0,0 +1,524 @@
+//! An on-disk cache of the solved types of user modules.
+//!
+//! Builtins have their types serialized at build time (see `roc_load`'s build script). For other
+//! modules we do the same at runtime: after a module solves without problems, its [TypeState] is
+//! written to `<roc cache dir>/types/<key>.dat`, where the key is a hash of the module's source,
+//! the keys of everything it imports, the target, and the compiler version. On the next run, a
+//! module with the same key loads its solved types from disk instead of solving them again.
+//!
+//! Canonicalization and constraint generation still run for cached modules, since later phases
+//! need their declarations. A fingerprint of the canonicalized module is stored alongside the
+//! types, so that if the variables or symbols it produced don't line up with the cached types,
+//! we fall back to solving.
+//!
+//! `ModuleId`s of user modules differ between runs, because headers are loaded in parallel, so
+//! every symbol in a cached [TypeState] is stored along with its module and ident names, and is
+//! translated to the current run's `ModuleId` on load. A cached entry that refers to a symbol
+//! that no longer exists is treated as a cache miss.
+//!
+//! Entries this process already read or wrote are also kept in memory, so that the runs of
+//! `--watch`, which load mostly the same modules over and over, don't read them from disk again.
+use crate::module_cache::ModuleCache;
+use parking_lot::Mutex;
+use roc_can::expr::Declarations;
+use roc_can::module::TypeState;
+use roc_collections::MutMap;
+use roc_debug_flags::ROC_SKIP_MODULE_CACHE;
+use roc_module::ident::ModuleName;
+use roc_module::symbol::{
+    IdentIds, IdentIdsByModule, ModuleId, PQModuleName, PackageModuleIds, Symbol,
+};
+use roc_packaging::cache::RocCacheDir;
+use roc_parse::header::HeaderType;
+use roc_serialize::bytes;
+use roc_solve::FunctionKind;
+use roc_target::TargetInfo;
+use roc_types::subs::VarStore;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+pub(crate) type TypeCacheKey = blake3::Hash;
+
+const COMPILER_VERSION: &str = include_str!("../../../../version.txt");
+
+/// Bump this whenever the serialized format of a [TypeState] changes.
+const FORMAT_VERSION: u64 = 1;
+const MAGIC: u64 = u64::from_le_bytes(*b"roctypes");
+
+/// The header is the magic number and format version, followed by a checksum of the rest of
+/// the file, so that a truncated or corrupted file is never deserialized.
+const HEADER_SIZE: usize = 2 * std::mem::size_of::<u64>() + blake3::OUT_LEN;
+
+/// Where to keep cached types, if we should cache them at all.
+/// Set `ROC_SKIP_MODULE_CACHE` to always solve every module from scratch.
+pub(crate) fn type_cache_dir(roc_cache_dir: RocCacheDir<'_>) -> Option<PathBuf> {
+    match roc_cache_dir {
+        RocCacheDir::Persistent(cache_dir) if std::env::var_os(ROC_SKIP_MODULE_CACHE).is_none() => {
+            Some(cache_dir.join("types"))
+        }
+        _ => None,
+    }
+}
+
+/// The part of a module's key that only depends on the module itself. Only modules whose types
+/// are fully determined by their source, ingested files and imports can be cached; in particular,
+/// a platform's types depend on the app that requires it.
+pub(crate) fn source_hash(
+    header_type: &HeaderType<'_>,
+    src: &str,
+    ingested_files: &[PathBuf],
+) -> Option<TypeCacheKey> {
+    match header_type {
+        HeaderType::Interface { .. } | HeaderType::Hosted { .. } | HeaderType::Builtin { .. } => {
+            let mut hasher = blake3::Hasher::new();
+            hasher.update(src.as_bytes());
+
+            // Whether an ingested file typechecks as a `Str` depends on its contents
+            for path in ingested_files {
+                let bytes = std::fs::read(path).ok()?;
+                hasher.update(&(bytes.len() as u64).to_le_bytes());
+                hasher.update(&bytes);
+            }
+
+            Some(hasher.finalize())
+        }
+        HeaderType::App { .. } | HeaderType::Package { .. } | HeaderType::Platform { .. } => None,
+    }
+}
+
+/// A key for the solved types of a module, which changes whenever the types could have changed.
+/// Returns `None` if the module or any of its imports can't be cached.
+pub(crate) fn module_key(
+    module_cache: &ModuleCache<'_>,
+    module_id: ModuleId,
+    imported_modules: impl Iterator<Item = ModuleId>,
+    target_info: TargetInfo,
+    function_kind: FunctionKind,
+) -> Option<TypeCacheKey> {
+    let source_hash = module_cache.source_hashes.get(&module_id)?;
+
+    // ModuleIds change from run to run, so sort the imports by name
+    let mut imports = Vec::new();
+    for dep_id in imported_modules {
+        let name = module_cache.module_names.get(&dep_id)?;
+        let key = module_cache.type_cache_keys.get(&dep_id)?;
+        imports.push((module_name_parts(name), key));
+    }
+    imports.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut hasher = compiler_hasher(COMPILER_VERSION, target_info, function_kind);
+    hash_module_name(&mut hasher, module_cache.module_names.get(&module_id)?);
+    hasher.update(source_hash.as_bytes());
+
+    for ((qualifier, name), key) in imports {
+        hash_name_parts(&mut hasher, qualifier, name);
+        hasher.update(key.as_bytes());
+    }
+
+    Some(hasher.finalize())
+}
+
+/// Starts every key with what the compiler and the target are, since either can change the
+/// types that solving produces.
+fn compiler_hasher(
+    compiler_version: &str,
+    target_info: TargetInfo,
+    function_kind: FunctionKind,
+) -> blake3::Hasher {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(compiler_version.as_bytes());
+    hasher.update(&FORMAT_VERSION.to_le_bytes());
+    hasher.update(format!("{target_info:?} {function_kind:?}").as_bytes());
+
+    hasher
+}
+
+/// A hash of what canonicalization and constraint generation produced for a module, which the
+/// cached types must agree with: the number of type variables, and the variable and symbol of
+/// each top-level declaration.
+pub(crate) fn fingerprint(
+    var_store: &mut VarStore,
+    decls: &Declarations,
+    ident_ids: &IdentIds,
+) -> TypeCacheKey {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&var_store.peek().to_le_bytes());
+
+    for (var, symbol) in decls.variables.iter().zip(decls.symbols.iter()) {
+        hasher.update(&var.index().to_le_bytes());
+        hasher.update(
+            ident_ids
+                .get_name(symbol.value.ident_id())
+                .unwrap_or("")
+                .as_bytes(),
+        );
+        hasher.update(&[0]);
+    }
+
+    hasher.finalize()
+}
+
+/// `(package shorthand, module name)`
+type NameParts<'b> = (Option<&'b str>, &'b str);
+
+fn module_name_parts<'b>(name: &'b PQModuleName<'_>) -> NameParts<'b> {
+    match name {
+        PQModuleName::Unqualified(name) => (None, name.as_str()),
+        PQModuleName::Qualified(shorthand, name) => (Some(*shorthand), name.as_str()),
+    }
+}
+
+fn hash_module_name(hasher: &mut blake3::Hasher, name: &PQModuleName<'_>) {
+    let (qualifier, name) = module_name_parts(name);
+    hash_name_parts(hasher, qualifier, name);
+}
+
+fn hash_name_parts(hasher: &mut blake3::Hasher, qualifier: Option<&str>, name: &str) {
+    match qualifier {
+        None => hasher.update(&[0]),
+        Some(qualifier) => hasher
+            .update(&[1])
+            .update(qualifier.as_bytes())
+            .update(&[0]),
+    };
+    hasher.update(name.as_bytes()).update(&[0]);
+}
+
+/// Everything a worker thread needs to read or write the cached types of one module
+#[derive(Debug)]
+pub(crate) struct TypeCache<'a> {
+    path: PathBuf,
+    module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
+    /// The final `IdentIds` of every module that has been solved so far, which includes all
+    /// (transitive) imports of the module being solved
+    solved_ident_ids: Arc<Mutex<IdentIdsByModule>>,
+}
+
+impl<'a> TypeCache<'a> {
+    pub(crate) fn new(
+        cache_dir: &Path,
+        key: TypeCacheKey,
+        module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
+        solved_ident_ids: Arc<Mutex<IdentIdsByModule>>,
+    ) -> Self {
+        Self {
+            path: cache_dir.join(format!("{}.dat", key.to_hex())),
+            module_ids,
+            solved_ident_ids,
+        }
+    }
+
+    /// Loads the cached types, if there are any and they match the module we're solving.
+    pub(crate) fn read(
+        &self,
+        home: ModuleId,
+        home_ident_ids: &IdentIds,
+        fingerprint: TypeCacheKey,
+    ) -> Option<TypeState> {
+        let buffer = match in_memory_entry(&self.path) {
+            Some(buffer) => buffer,
+            None => {
+                let buffer = Arc::new(read_aligned(&self.path).ok()?);
+                keep_in_memory(&self.path, Arc::clone(&buffer));
+
+                buffer
+            }
+        };
+        let bytes = buffer.as_bytes();
+
+        if bytes.len() < HEADER_SIZE {
+            return None;
+        }
+
+        let (header, offset) = bytes::deserialize_slice::<u64>(bytes, 2, 0);
+        let (checksum, offset) = bytes::deserialize_slice::<u8>(bytes, blake3::OUT_LEN, offset);
+
+        if header != [MAGIC, FORMAT_VERSION]
+            || checksum != blake3::hash(&bytes[HEADER_SIZE..]).as_bytes()
+        {
+            return None;
+        }
+
+        let (cached_fingerprint, offset) =
+            bytes::deserialize_slice::<u8>(bytes, blake3::OUT_LEN, offset);
+
+        if cached_fingerprint != fingerprint.as_bytes() {
+            return None;
+        }
+
+        let (lengths, offset) = bytes::deserialize_slice::<u64>(bytes, 2, offset);
+        let (module_count, symbol_count) = (lengths[0] as usize, lengths[1] as usize);
+
+        let (qualified, offset) = bytes::deserialize_slice::<u8>(bytes, module_count, offset);
+        let (qualifiers, offset) = bytes::deserialize_strs(bytes, module_count, offset);
+        let (module_names, offset) = bytes::deserialize_strs(bytes, module_count, offset);
+
+        let (old_symbols, offset) = bytes::deserialize_slice::<Symbol>(bytes, symbol_count, offset);
+        let (symbol_modules, offset) = bytes::deserialize_slice::<u32>(bytes, symbol_count, offset);
+        let (ident_names, offset) = bytes::deserialize_strs(bytes, symbol_count, offset);
+
+        // Find the current ModuleId of every module the cached types refer to
+        let mut module_ids = Vec::with_capacity(module_count);
+        {
+            let package_module_ids: &PackageModuleIds = &self.module_ids.lock();
+
+            for ((qualified, qualifier), name) in qualified.iter().zip(qualifiers).zip(module_names)
+            {
+                let name = ModuleName::from(name);
+                let pq_name = if *qualified != 0 {
+                    PQModuleName::Qualified(qualifier, name)
+                } else {
+                    PQModuleName::Unqualified(name)
+                };
+
+                module_ids.push(package_module_ids.get_id(&pq_name)?);
+            }
+        }
+
+        // Symbols keep their IdentId, but only if it still refers to the same name
+        let mut symbols = MutMap::default();
+        {
+            let solved_ident_ids = self.solved_ident_ids.lock();
+
+            for ((old_symbol, module_index), ident_name) in
+                old_symbols.iter().zip(symbol_modules).zip(ident_names)
+            {
+                let module_id = *module_ids.get(*module_index as usize)?;
+                let ident_ids = if module_id == home {
+                    home_ident_ids
+                } else {
+                    solved_ident_ids.get(&module_id)?
+                };
+
+                if ident_ids.get_name(old_symbol.ident_id()) != Some(ident_name) {
+                    return None;
+                }
+
+                symbols.insert(*old_symbol, Symbol::new(module_id, old_symbol.ident_id()));
+            }
+        }
+
+        let type_state_offset = bytes::next_multiple_of(offset, TYPE_STATE_ALIGNMENT);
+        let (mut type_state, _) = TypeState::deserialize(&bytes[type_state_offset..]);
+
+        let mut all_found = true;
+        type_state.map_symbols(&mut |symbol| match symbols.get(&symbol) {
+            Some(new_symbol) => *new_symbol,
+            None => {
+                all_found = false;
+                symbol
+            }
+        });
+
+        all_found.then_some(type_state)
+    }
+
+    /// Stores the types of a module that solved without problems. Modules whose types can't be
+    /// cached, e.g. because they refer to derived implementations, are skipped.
+    ///
+    /// This only walks the type state to find its symbols; they aren't changed.
+    pub(crate) fn write(
+        &self,
+        home: ModuleId,
+        home_ident_ids: &IdentIds,
+        fingerprint: TypeCacheKey,
+        type_state: &mut TypeState,
+    ) -> io::Result<()> {
+        let mut symbols: Vec<Symbol> = Vec::new();
+        let mut symbol_indices: MutMap<Symbol, usize> = MutMap::default();
+        type_state.map_symbols(&mut |symbol| {
+            symbol_indices.entry(symbol).or_insert_with(|| {
+                symbols.push(symbol);
+                symbols.len() - 1
+            });
+            symbol
+        });
+
+        let mut module_indices: MutMap<ModuleId, u32> = MutMap::default();
+        let mut qualified: Vec<u8> = Vec::new();
+        let mut qualifiers: Vec<String> = Vec::new();
+        let mut module_names: Vec<String> = Vec::new();
+        let mut symbol_modules: Vec<u32> = Vec::with_capacity(symbols.len());
+        let mut ident_names: Vec<String> = Vec::with_capacity(symbols.len());
+        {
+            let package_module_ids = self.module_ids.lock();
+            let solved_ident_ids = self.solved_ident_ids.lock();
+
+            for symbol in symbols.iter() {
+                let module_id = symbol.module_id();
+
+                let ident_ids = if module_id == home {
+                    Some(home_ident_ids)
+                } else {
+                    solved_ident_ids.get(&module_id)
+                };
+                let (ident_ids, pq_name) = match (ident_ids, package_module_ids.get_name(module_id))
+                {
+                    (Some(ident_ids), Some(pq_name)) => (ident_ids, pq_name),
+                    _ => return Ok(()),
+                };
+                let ident_name = match ident_ids.get_name(symbol.ident_id()) {
+                    Some(ident_name) => ident_name,
+                    None => return Ok(()),
+                };
+
+                let module_index = *module_indices.entry(module_id).or_insert_with(|| {
+                    let (qualifier, name) = module_name_parts(pq_name);
+                    qualified.push(qualifier.is_some() as u8);
+                    qualifiers.push(qualifier.unwrap_or_default().to_string());
+                    module_names.push(name.to_string());
+                    module_names.len() as u32 - 1
+                });
+
+                symbol_modules.push(module_index);
+                ident_names.push(ident_name.to_string());
+            }
+        }
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let written = bytes::serialize_slice(&[MAGIC, FORMAT_VERSION], &mut buffer, 0)?;
+        // Filled in with the checksum once everything else is written
+        let written = bytes::serialize_slice(&[0u8; blake3::OUT_LEN], &mut buffer, written)?;
+        debug_assert_eq!(written, HEADER_SIZE);
+
+        let written = bytes::serialize_slice(fingerprint.as_bytes(), &mut buffer, written)?;
+        let lengths = [module_names.len() as u64, symbols.len() as u64];
+        let written = bytes::serialize_slice(&lengths, &mut buffer, written)?;
+
+        let written = bytes::serialize_slice(&qualified, &mut buffer, written)?;
+        let written =
+            bytes::serialize_strs(qualifiers.iter().map(|s| s.as_str()), &mut buffer, written)?;
+        let written = bytes::serialize_strs(
+            module_names.iter().map(|s| s.as_str()),
+            &mut buffer,
+            written,
+        )?;
+
+        let written = bytes::serialize_slice(&symbols, &mut buffer, written)?;
+        let written = bytes::serialize_slice(&symbol_modules, &mut buffer, written)?;
+        let written =
+            bytes::serialize_strs(ident_names.iter().map(|s| s.as_str()), &mut buffer, written)?;
+
+        let type_state_offset = bytes::next_multiple_of(written, TYPE_STATE_ALIGNMENT);
+        buffer.resize(type_state_offset, 0);
+        type_state.serialize(&mut buffer)?;
+
+        let checksum = blake3::hash(&buffer[HEADER_SIZE..]);
+        buffer[HEADER_SIZE - blake3::OUT_LEN..HEADER_SIZE].copy_from_slice(checksum.as_bytes());
+
+        // Write to a temporary file first, so other compiler processes never see a partial file
+        let dir = self.path.parent().unwrap();
+        std::fs::create_dir_all(dir)?;
+
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        file.write_all(&buffer)?;
+        file.persist(&self.path).map_err(|err| err.error)?;
+
+        keep_in_memory(&self.path, Arc::new(AlignedBuffer::from_bytes(&buffer)));
+
+        Ok(())
+    }
+}
+
+/// Once the entries kept in memory add up to this many bytes, we start over.
+const IN_MEMORY_LIMIT: usize = 256 * 1024 * 1024;
+
+#[derive(Default)]
+struct InMemory {
+    entries: MutMap<PathBuf, Arc<AlignedBuffer>>,
+    total_len: usize,
+}
+
+fn in_memory() -> &'static Mutex<InMemory> {
+    static IN_MEMORY: OnceLock<Mutex<InMemory>> = OnceLock::new();
+
+    IN_MEMORY.get_or_init(Default::default)
+}
+
+fn in_memory_entry(path: &Path) -> Option<Arc<AlignedBuffer>> {
+    in_memory().lock().entries.get(path).cloned()
+}
+
+/// Entries are named after their key, so an entry never changes once it's written.
+fn keep_in_memory(path: &Path, buffer: Arc<AlignedBuffer>) {
+    let mut in_memory = in_memory().lock();
+
+    if in_memory.total_len + buffer.len > IN_MEMORY_LIMIT {
+        *in_memory = InMemory::default();
+    }
+
+    in_memory.total_len += buffer.len;
+    if let Some(previous) = in_memory.entries.insert(path.to_path_buf(), buffer) {
+        in_memory.total_len -= previous.len;
+    }
+}
+
+/// The serialized [TypeState] is read with unaligned pointer casts, so it must be at least as
+/// aligned as anything in it - the same as the builtins' `include_bytes_align_as!(u128, ...)`.
+const TYPE_STATE_ALIGNMENT: usize = std::mem::align_of::<AlignedBlock>();
+
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct AlignedBlock([u8; 16]);
+
+struct AlignedBuffer {
+    blocks: Vec<AlignedBlock>,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let len = bytes.len();
+        let mut buffer = Self {
+            blocks: vec![AlignedBlock([0; 16]); (len + 15) / 16],
+            len,
+        };
+
+        // Safety: AlignedBlock is plain bytes with no padding, and the blocks cover `len` bytes
+        unsafe { std::slice::from_raw_parts_mut(buffer.blocks.as_mut_ptr() as *mut u8, len) }
+            .copy_from_slice(bytes);
+
+        buffer
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        // Safety: AlignedBlock is plain bytes with no padding, and `len` is in bounds
+        unsafe { std::slice::from_raw_parts(self.blocks.as_ptr() as *const u8, self.len) }
+    }
+}
+
+fn read_aligned(path: &Path) -> io::Result<AlignedBuffer> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len() as usize;
+
+    let mut blocks = vec![AlignedBlock([0; 16]); (len + 15) / 16];
+    // Safety: AlignedBlock is plain bytes with no padding, and the blocks cover `len` bytes
+    let bytes = unsafe { std::slice::from_raw_parts_mut(blocks.as_mut_ptr() as *mut u8, len) };
+    file.read_exact(bytes)?;
+
+    Ok(AlignedBuffer { blocks, len })
+}
+
+#[cfg(test)]
+mod test {
+    use super::compiler_hasher;
+    use roc_solve::FunctionKind;
+    use roc_target::TargetInfo;
+
+    #[test]
+    fn keys_depend_on_compiler_and_target() {
+        let key = |version, target_info, function_kind| {
+            compiler_hasher(version, target_info, function_kind).finalize()
+        };
+
+        let x86_64 = TargetInfo::default_x86_64();
+        let wasm32 = TargetInfo::default_wasm32();
+        let original = key("0.0.1", x86_64, FunctionKind::LambdaSet);
+
+        assert_eq!(original, key("0.0.1", x86_64, FunctionKind::LambdaSet));
+        assert_ne!(original, key("0.0.2", x86_64, FunctionKind::LambdaSet));
+        assert_ne!(original, key("0.0.1", x86_64, FunctionKind::Erased));
+        assert_ne!(original, key("0.0.1", wasm32, FunctionKind::LambdaSet));
+    }
+}
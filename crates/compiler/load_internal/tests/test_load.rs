@@ -30,26 +30,29 @@ use roc_reporting::report::RenderTarget;
 use roc_reporting::report::RocDocAllocator;
 use roc_reporting::report::{can_problem, DEFAULT_PALETTE};
 use roc_solve::FunctionKind;
+use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
 use roc_types::pretty_print::name_and_print_var;
 use roc_types::pretty_print::DebugPrint;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-fn load_and_typecheck(
-    arena: &Bump,
+fn load_and_typecheck<'a>(
+    arena: &'a Bump,
     filename: PathBuf,
     exposed_types: ExposedByModule,
     target_info: TargetInfo,
     function_kind: FunctionKind,
-) -> Result<LoadedModule, LoadingProblem> {
+    roc_cache_dir: RocCacheDir<'_>,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
     use LoadResult::*;
 
     let load_start = LoadStart::from_path(
         arena,
         filename,
         RenderTarget::Generic,
-        RocCacheDir::Disallowed,
+        roc_cache_dir,
         DEFAULT_PALETTE,
     )?;
     let load_config = LoadConfig {
@@ -59,6 +62,7 @@ fn load_and_typecheck(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        opt_level: roc_mono::ir::OptLevel::Development,
     };
 
     match roc_load_internal::file::load(
@@ -66,7 +70,7 @@ fn load_and_typecheck(
         load_start,
         exposed_types,
         Default::default(), // these tests will re-compile the builtins
-        RocCacheDir::Disallowed,
+        roc_cache_dir,
         load_config,
     )? {
         Monomorphized(_) => unreachable!(""),
@@ -185,6 +189,7 @@ fn multiple_modules_help<'a>(
             Default::default(),
             TARGET_INFO,
             FunctionKind::LambdaSet,
+            RocCacheDir::Disallowed,
         )
     };
 
@@ -205,6 +210,7 @@ fn load_fixture(
         subs_by_module,
         TARGET_INFO,
         FunctionKind::LambdaSet,
+        RocCacheDir::Disallowed,
     );
     let mut loaded_module = match loaded {
         Ok(x) => x,
@@ -368,6 +374,7 @@ fn interface_with_deps() {
         subs_by_module,
         TARGET_INFO,
         FunctionKind::LambdaSet,
+        RocCacheDir::Disallowed,
     );
 
     let mut loaded_module = loaded.expect("Test module failed to load");
@@ -582,6 +589,244 @@ fn app_dep_types() {
     );
 }
 
+#[test]
+fn app_dep_types_from_type_cache() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let filename = fixtures_dir().join("app_with_deps").join("Primary.roc");
+    let type_cache_dir = cache_dir.path().join("types");
+
+    let load = || {
+        let arena = Bump::new();
+        let loaded_module = load_and_typecheck(
+            &arena,
+            filename.clone(),
+            Default::default(),
+            TARGET_INFO,
+            FunctionKind::LambdaSet,
+            RocCacheDir::Persistent(cache_dir.path()),
+        )
+        .unwrap();
+
+        expect_types(
+            loaded_module,
+            hashmap! {
+                "blah2" => "Frac *",
+                "blah3" => "Str",
+                "str" => "Str",
+                "alwaysThree" => "* -> Frac *",
+                "identity" => "a -> a",
+                "z" => "Frac *",
+                "w" => "Dep1.Identity {}",
+                "succeed" => "a -> Dep1.Identity a",
+                "yay" => "Res.Res {} err",
+                "withDefault" => "Res.Res a err, a -> a",
+            },
+        );
+    };
+
+    let cached_files = || {
+        std::fs::read_dir(&type_cache_dir)
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                (entry.path(), entry.metadata().unwrap().modified().unwrap())
+            })
+            .collect::<HashMap<_, _>>()
+    };
+
+    // The first load solves every module, and caches the types of the interfaces
+    load();
+    let cached = cached_files();
+    assert!(!cached.is_empty());
+
+    // The second load reads those types back from the cache, so nothing gets rewritten
+    load();
+    assert_eq!(cached, cached_files());
+}
+
+/// Loads `Main.roc` from `dir`, keeping solved types in `cache_dir` between loads
+fn load_with_type_cache(dir: &Path, cache_dir: &Path, target_info: TargetInfo) -> LoadedModule {
+    let arena = Bump::new();
+
+    load_and_typecheck(
+        &arena,
+        dir.join("Main.roc"),
+        Default::default(),
+        target_info,
+        FunctionKind::LambdaSet,
+        RocCacheDir::Persistent(cache_dir),
+    )
+    .unwrap()
+}
+
+fn main_type_problems(loaded_module: &mut LoadedModule) -> Vec<TypeError> {
+    let home = loaded_module.module_id;
+
+    loaded_module
+        .type_problems
+        .remove(&home)
+        .unwrap_or_default()
+}
+
+const DEP_WITH_STR: &str = indoc!(
+    r#"
+    interface Dep
+        exposes [value]
+        imports []
+
+    value = "hello"
+    "#
+);
+
+const DEP_WITH_NUM: &str = indoc!(
+    r#"
+    interface Dep
+        exposes [value]
+        imports []
+
+    value = 42
+    "#
+);
+
+#[test]
+fn type_cache_invalidated_by_dependency_types() {
+    let project = tempfile::tempdir().unwrap();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let write = |name: &str, src: &str| fs::write(project.path().join(name), src).unwrap();
+
+    write("Dep.roc", DEP_WITH_STR);
+    write(
+        "Main.roc",
+        indoc!(
+            r#"
+            interface Main
+                exposes [main]
+                imports [Dep]
+
+            main = Dep.value
+            "#
+        ),
+    );
+
+    for _ in 0..2 {
+        let loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+        expect_types(loaded_module, hashmap! { "main" => "Str" });
+    }
+
+    // Main hasn't changed, but what it imports has
+    write("Dep.roc", DEP_WITH_NUM);
+    let loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+    expect_types(loaded_module, hashmap! { "main" => "Num *" });
+}
+
+#[test]
+fn type_cache_invalidated_by_dependency_errors() {
+    let project = tempfile::tempdir().unwrap();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let write = |name: &str, src: &str| fs::write(project.path().join(name), src).unwrap();
+
+    write("Dep.roc", DEP_WITH_STR);
+    write(
+        "Main.roc",
+        indoc!(
+            r#"
+            interface Main
+                exposes [main]
+                imports [Dep]
+
+            main = Str.concat Dep.value "!"
+            "#
+        ),
+    );
+
+    for _ in 0..2 {
+        let mut loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+        let problems = main_type_problems(&mut loaded_module);
+        assert!(problems.is_empty(), "{problems:#?}");
+    }
+
+    write("Dep.roc", DEP_WITH_NUM);
+    let mut loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+    let problems = main_type_problems(&mut loaded_module);
+    assert!(
+        matches!(problems.as_slice(), [TypeError::BadExpr(..)]),
+        "{problems:#?}"
+    );
+}
+
+#[test]
+fn type_cache_invalidated_by_ingested_file() {
+    let project = tempfile::tempdir().unwrap();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let data_path = project.path().join("data.txt");
+
+    fs::write(&data_path, "hello").unwrap();
+    fs::write(
+        project.path().join("Main.roc"),
+        indoc!(
+            r#"
+            interface Main
+                exposes [main]
+                imports ["data.txt" as data : Str]
+
+            main = data
+            "#
+        ),
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        let loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+        expect_types(loaded_module, hashmap! { "main" => "Str" });
+    }
+
+    // The source of Main hasn't changed, but the file is no longer valid UTF-8
+    fs::write(&data_path, [0xff, 0xfe, 0xfd]).unwrap();
+    let mut loaded_module = load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+    let problems = main_type_problems(&mut loaded_module);
+    assert!(
+        matches!(problems.as_slice(), [TypeError::IngestedFileBadUtf8(..)]),
+        "{problems:#?}"
+    );
+}
+
+#[test]
+fn type_cache_is_per_target() {
+    let project = tempfile::tempdir().unwrap();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let type_cache_dir = cache_dir.path().join("types");
+
+    fs::write(project.path().join("Dep.roc"), DEP_WITH_STR).unwrap();
+    fs::write(
+        project.path().join("Main.roc"),
+        indoc!(
+            r#"
+            interface Main
+                exposes [main]
+                imports [Dep]
+
+            main = Dep.value
+            "#
+        ),
+    )
+    .unwrap();
+
+    let cache_entries = || fs::read_dir(&type_cache_dir).unwrap().count();
+
+    load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+    let entries_per_target = cache_entries();
+    assert!(entries_per_target > 0);
+
+    // Types solved for one target are never reused for another
+    let wasm32 = TargetInfo::default_wasm32();
+    let loaded_module = load_with_type_cache(project.path(), cache_dir.path(), wasm32);
+    expect_types(loaded_module, hashmap! { "main" => "Str" });
+    assert_eq!(cache_entries(), 2 * entries_per_target);
+
+    load_with_type_cache(project.path(), cache_dir.path(), TARGET_INFO);
+    assert_eq!(cache_entries(), 2 * entries_per_target);
+}
+
 #[test]
 fn imported_dep_regression() {
     let subs_by_module = Default::default();
@@ -641,7 +886,7 @@ fn parse_problem() {
             report,
             indoc!(
                 "
-                    ── UNFINISHED LIST ──────────────────────────────────── tmp/parse_problem/Main ─
+                    ── UNFINISHED LIST [E0406] ──────────────────────────── tmp/parse_problem/Main ─
 
                     I am partway through started parsing a list, but I got stuck here:
 
@@ -662,6 +907,29 @@ fn parse_problem() {
     }
 }
 
+#[test]
+fn parse_problems_are_reported_together() {
+    let modules = vec![(
+        "Main",
+        indoc!(
+            r#"
+                interface Main exposes [main, other] imports []
+
+                main = [
+
+                helper = List.len other
+
+                other = [
+                "#
+        ),
+    )];
+
+    match multiple_modules("parse_problems_are_reported_together", modules) {
+        Err(report) => assert_eq!(report.matches("UNFINISHED LIST").count(), 2),
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
+
 #[test]
 #[should_panic(expected = "FILE NOT FOUND")]
 fn file_not_found() {
@@ -847,7 +1115,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
         err,
         indoc!(
             r#"
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE ─ ...rapped_outside_defining_module/Main ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E0211] ─ ...utside_defining_module/Main ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -861,7 +1129,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE ─ ...rapped_outside_defining_module/Main ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E0211] ─ ...utside_defining_module/Main ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -875,7 +1143,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── UNUSED IMPORT ─── tmp/opaque_wrapped_unwrapped_outside_defining_module/Main ─
+                ── UNUSED IMPORT [E0102] ─ ...e_wrapped_unwrapped_outside_defining_module/Main ─
 
                 Nothing from Age is used in this module.
 
@@ -930,7 +1198,7 @@ fn issue_2863_module_type_does_not_exist() {
                 report,
                 indoc!(
                     "
-                        ── UNRECOGNIZED NAME ────────── tmp/issue_2863_module_type_does_not_exist/Main ─
+                        ── UNRECOGNIZED NAME [E0108] ── tmp/issue_2863_module_type_does_not_exist/Main ─
 
                         Nothing is named `DoesNotExist` in this scope.
 
@@ -1006,7 +1274,7 @@ fn module_doesnt_match_file_path() {
         err,
         indoc!(
             r#"
-            ── WEIRD MODULE NAME ─────────────────── tmp/module_doesnt_match_file_path/Age ─
+            ── WEIRD MODULE NAME [E0417] ─────────── tmp/module_doesnt_match_file_path/Age ─
 
             This module name does not correspond with the file path it is defined
             in:
@@ -1039,7 +1307,7 @@ fn module_cyclic_import_itself() {
         err,
         indoc!(
             r#"
-            ── IMPORT CYCLE ────────────────────────── tmp/module_cyclic_import_itself/Age ─
+            ── IMPORT CYCLE [E0504] ────────────────── tmp/module_cyclic_import_itself/Age ─
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -1085,7 +1353,7 @@ fn module_cyclic_import_transitive() {
         err,
         indoc!(
             r#"
-            ── IMPORT CYCLE ────────────────── tmp/module_cyclic_import_transitive/Age.roc ─
+            ── IMPORT CYCLE [E0504] ────────── tmp/module_cyclic_import_transitive/Age.roc ─
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -1133,7 +1401,7 @@ fn nested_module_has_incorrect_name() {
         err,
         indoc!(
             r#"
-            ── INCORRECT MODULE NAME ──── tmp/nested_module_has_incorrect_name/Dep/Foo.roc ─
+            ── INCORRECT MODULE NAME [E0505] ─ ...ed_module_has_incorrect_name/Dep/Foo.roc ─
 
             This module has a different name than I expected:
 
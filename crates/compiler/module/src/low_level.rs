@@ -23,6 +23,7 @@ pub enum LowLevel {
     StrTrimStart,
     StrTrimEnd,
     StrToNum,
+    StrToIntRadix,
     StrToScalars,
     StrGetUnsafe,
     StrSubstringUnsafe,
@@ -33,6 +34,14 @@ pub enum LowLevel {
     StrWithCapacity,
     StrGraphemes,
     StrReleaseExcessCapacity,
+    StrToUpper,
+    StrToLower,
+    StrCaseInsensitiveEq,
+    StrToNfc,
+    StrToNfd,
+    StrToUtf16,
+    StrFromUtf16Lossy,
+    StrCompare,
     ListLen,
     ListWithCapacity,
     ListReserve,
@@ -108,12 +117,16 @@ pub enum LowLevel {
     NumToIntChecked,
     NumToFloatChecked,
     NumToStr,
+    NumToStrRadix,
+    NumToStrDecimals,
+    NumToStrScientific,
     NumCountLeadingZeroBits,
     NumCountTrailingZeroBits,
     NumCountOneBits,
     I128OfDec,
     Eq,
     NotEq,
+    Compare,
     And,
     Or,
     Not,
@@ -281,10 +294,19 @@ map_symbol_to_lowlevel! {
     StrAppendScalar <= STR_APPEND_SCALAR_UNSAFE,
     StrGetScalarUnsafe <= STR_GET_SCALAR_UNSAFE,
     StrToNum <= STR_TO_NUM,
+    StrToIntRadix <= STR_TO_INT_RADIX_LOWLEVEL,
     StrGetCapacity <= STR_CAPACITY,
     StrWithCapacity <= STR_WITH_CAPACITY,
     StrGraphemes <= STR_GRAPHEMES,
     StrReleaseExcessCapacity <= STR_RELEASE_EXCESS_CAPACITY,
+    StrToUpper <= STR_TO_UPPER,
+    StrToLower <= STR_TO_LOWER,
+    StrCaseInsensitiveEq <= STR_CASE_INSENSITIVE_EQ,
+    StrToNfc <= STR_TO_NFC,
+    StrToNfd <= STR_TO_NFD,
+    StrToUtf16 <= STR_TO_UTF16,
+    StrFromUtf16Lossy <= STR_FROM_UTF16_LOSSY,
+    StrCompare <= STR_COMPARE_UTF8,
     ListLen <= LIST_LEN,
     ListGetCapacity <= LIST_CAPACITY,
     ListWithCapacity <= LIST_WITH_CAPACITY,
@@ -351,12 +373,16 @@ map_symbol_to_lowlevel! {
     NumShiftRightBy <= NUM_SHIFT_RIGHT,
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL,
     NumToStr <= NUM_TO_STR,
+    NumToStrRadix <= NUM_TO_STR_RADIX_PADDED,
+    NumToStrDecimals <= NUM_TO_STR_DECIMALS,
+    NumToStrScientific <= NUM_TO_STR_SCIENTIFIC,
     NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS,
     NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS,
     NumCountOneBits <= NUM_COUNT_ONE_BITS,
     I128OfDec <= I128_OF_DEC,
     Eq <= BOOL_STRUCTURAL_EQ,
     NotEq <= BOOL_STRUCTURAL_NOT_EQ,
+    Compare <= BOOL_STRUCTURAL_COMPARE,
     And <= BOOL_AND,
     Or <= BOOL_OR,
     Not <= BOOL_NOT,
@@ -53,6 +53,11 @@ pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
     (Symbol::DECODE_DECODING, &[Symbol::DECODE_DECODER]),
     (Symbol::HASH_HASH_ABILITY, &[Symbol::HASH_HASH]),
     (Symbol::BOOL_EQ, &[Symbol::BOOL_IS_EQ]),
+    (Symbol::BOOL_ORD, &[Symbol::BOOL_COMPARE]),
+    (
+        Symbol::INSPECT_INSPECT_ABILITY,
+        &[Symbol::INSPECT_TO_INSPECTOR],
+    ),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -108,6 +113,9 @@ impl Symbol {
             // The `structuralEq` call used deriving structural equality, which will wrap the `Eq`
             // low-level implementation.
             &Self::BOOL_STRUCTURAL_EQ
+            // The `structuralCompare` call used deriving structural ordering, which will wrap
+            // the `Compare` low-level implementation.
+            | &Self::BOOL_STRUCTURAL_COMPARE
         )
     }
 
@@ -805,8 +813,8 @@ macro_rules! define_builtins {
                     $(exposed_apply_type=$exposed_apply_type:literal)?
                     $(exposed_type=$exposed_type:literal)?
                     $(in_scope_for_hints=$in_scope_for_hints:literal)?
+                    $(unexposed=$unexposed:literal)?
                 )*
-                $(unexposed $u_ident_id:literal $u_ident_const:ident: $u_ident_name:literal)*
             }
         )+
         num_modules: $total:literal
@@ -955,11 +963,24 @@ macro_rules! define_builtins {
                     $(#[$ident_meta])*
                     pub const $ident_const: Symbol = Symbol::new(ModuleId::$module_const, IdentId($ident_id));
                 )*
-                $(
-                    pub const $u_ident_const: Symbol = Symbol::new(ModuleId::$module_const, IdentId($u_ident_id));
-                )*
             )+
 
+            /// Whether this builtin is only for use in its own module (and by the compiler), so
+            /// other modules can't look it up.
+            pub fn is_unexposed(self) -> bool {
+                $(
+                    $(
+                        $(
+                            if $unexposed && self == Symbol::new(ModuleId::$module_const, IdentId($ident_id)) {
+                                return true;
+                            }
+                        )?
+                    )*
+                )+
+
+                false
+            }
+
             /// The default `Apply` types that should be in scope,
             /// and what symbols they should resolve to.
             ///
@@ -1270,6 +1291,10 @@ define_builtins! {
         162 NUM_PI: "pi"
         163 NUM_TAU: "tau"
         164 NUM_BITWISE_NOT: "bitwiseNot"
+        165 NUM_TO_STR_RADIX: "toStrRadix"
+        166 NUM_TO_STR_RADIX_PADDED: "toStrRadixPadded"
+        167 NUM_TO_STR_DECIMALS: "toStrDecimals"
+        168 NUM_TO_STR_SCIENTIFIC: "toStrScientific"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1283,8 +1308,11 @@ define_builtins! {
         8 BOOL_EQ: "Eq" exposed_type=true
         9 BOOL_IS_EQ: "isEq"
         10 BOOL_IS_EQ_IMPL: "boolIsEq"
-        unexposed 11 BOOL_STRUCTURAL_EQ: "structuralEq"
-        unexposed 12 BOOL_STRUCTURAL_NOT_EQ: "structuralNotEq"
+        11 BOOL_STRUCTURAL_EQ: "structuralEq" unexposed=true
+        12 BOOL_STRUCTURAL_NOT_EQ: "structuralNotEq" unexposed=true
+        13 BOOL_ORD: "Ord" exposed_type=true
+        14 BOOL_COMPARE: "compare"
+        15 BOOL_STRUCTURAL_COMPARE: "structuralCompare" unexposed=true
     }
     5 STR: "Str" => {
         0 STR_STR: "Str" exposed_apply_type=true // the Str.Str type alias
@@ -1347,6 +1375,17 @@ define_builtins! {
         57 STR_RELEASE_EXCESS_CAPACITY: "releaseExcessCapacity"
         58 STR_WALK_UTF8: "walkUtf8"
         59 STR_CONTAINS: "contains"
+        60 STR_COMPARE_UTF8: "compareUtf8"
+        61 STR_TO_UPPER: "toUpper"
+        62 STR_TO_LOWER: "toLower"
+        63 STR_CASE_INSENSITIVE_EQ: "caseInsensitiveEq"
+        64 STR_TO_NFC: "toNfc"
+        65 STR_TO_NFD: "toNfd"
+        66 STR_TO_UTF16: "toUtf16"
+        67 STR_FROM_UTF16: "fromUtf16"
+        68 STR_FROM_UTF16_LOSSY: "fromUtf16Lossy"
+        69 STR_TO_INT_RADIX: "toIntRadix"
+        70 STR_TO_INT_RADIX_LOWLEVEL: "strToIntRadix"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1567,6 +1606,7 @@ define_builtins! {
         26 DECODE_FROM_BYTES_PARTIAL: "fromBytesPartial"
         27 DECODE_FROM_BYTES: "fromBytes"
         28 DECODE_MAP_RESULT: "mapResult"
+        29 DECODE_TAG: "tag"
     }
     13 HASH: "Hash" => {
         0 HASH_HASH_ABILITY: "Hash" exposed_type=true
@@ -1625,6 +1665,7 @@ define_builtins! {
         29 INSPECT_CUSTOM: "custom"
         30 INSPECT_APPLY: "apply"
         31 INSPECT_TO_INSPECTOR: "toInspector"
+        32 INSPECT_NAT: "nat"
     }
     15 JSON: "TotallyNotJson" => {
         0 JSON_JSON: "TotallyNotJson"
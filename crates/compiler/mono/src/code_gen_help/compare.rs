@@ -0,0 +1,734 @@
+use bumpalo::collections::vec::Vec;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
+
+use crate::ir::{BranchInfo, Expr, JoinPointId, Literal, Param, Stmt};
+use crate::layout::{
+    InLayout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType, UnionLayout,
+};
+
+use super::equality::NullableId;
+use super::{let_lowlevel, CodeGenHelp, Context, LAYOUT_BOOL, LAYOUT_ORDERING};
+
+const ARG_1: Symbol = Symbol::ARG_1;
+const ARG_2: Symbol = Symbol::ARG_2;
+
+// The tag ids of `[LT, EQ, GT]`
+const EQ: u8 = 0;
+const GT: u8 = 1;
+
+pub fn compare_generic<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> Stmt<'a> {
+    use crate::layout::Builtin::*;
+    use LayoutRepr::*;
+    match layout_interner.get_repr(layout) {
+        Builtin(Int(_) | Float(_) | Bool | Decimal) | FunctionPointer(_) => {
+            unreachable!(
+                "No generated proc for `compare`. Use direct code gen for {:?}",
+                layout
+            )
+        }
+        Builtin(Str) => {
+            unreachable!("No generated helper proc for `compare` on Str. Use Zig function.")
+        }
+        Builtin(List(elem_layout)) => {
+            compare_list(root, ident_ids, ctx, layout_interner, elem_layout)
+        }
+        Struct(field_layouts) => {
+            compare_struct(root, ident_ids, ctx, layout_interner, layout, field_layouts)
+        }
+        Union(union_layout) => {
+            compare_tag_union(root, ident_ids, ctx, layout_interner, union_layout)
+        }
+        Ptr(inner_layout) => compare_boxed(root, ident_ids, ctx, layout_interner, inner_layout),
+        LambdaSet(_) => unreachable!("`compare` is not defined on functions"),
+        Erased(_) => unreachable!("`compare` is not defined on erased types"),
+        RecursivePointer(_) => {
+            unreachable!(
+                "Can't perform `compare` on RecursivePointer. Should have been replaced by a tag union."
+            )
+        }
+    }
+}
+
+fn return_ordering<'a>(root: &CodeGenHelp<'a>, ident_ids: &mut IdentIds, tag_id: u8) -> Stmt<'a> {
+    let ordering = root.create_symbol(ident_ids, "ordering");
+
+    Stmt::Let(
+        ordering,
+        Expr::Literal(Literal::Byte(tag_id)),
+        LAYOUT_ORDERING,
+        root.arena.alloc(Stmt::Ret(ordering, Region::zero())),
+    )
+}
+
+fn if_not_equal_return<'a>(
+    root: &CodeGenHelp<'a>,
+    ordering: Symbol,
+    following: Stmt<'a>,
+) -> Stmt<'a> {
+    Stmt::Switch {
+        cond_symbol: ordering,
+        cond_layout: LAYOUT_ORDERING,
+        branches: root.arena.alloc([(EQ as u64, BranchInfo::None, following)]),
+        default_branch: (
+            BranchInfo::None,
+            root.arena.alloc(Stmt::Ret(ordering, Region::zero())),
+        ),
+        ret_layout: LAYOUT_ORDERING,
+        region: Region::zero(),
+    }
+}
+
+fn compare_struct<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    struct_layout: InLayout<'a>,
+    field_layouts: &'a [InLayout<'a>],
+) -> Stmt<'a> {
+    // The struct's fields are sorted by alignment, but records are ordered by their field names
+    // and tuples by their element indices.
+    let field_order = layout_interner.struct_field_order(struct_layout);
+
+    let mut else_stmt = return_ordering(root, ident_ids, EQ);
+    for i in field_order.into_iter().rev() {
+        let layout = field_layouts[i];
+
+        let field1_sym = root.create_symbol(ident_ids, &format!("field_1_{i}"));
+        let field1_expr = Expr::StructAtIndex {
+            index: i as u64,
+            field_layouts,
+            structure: ARG_1,
+        };
+        let field1_stmt = |next| Stmt::Let(field1_sym, field1_expr, layout, next);
+
+        let field2_sym = root.create_symbol(ident_ids, &format!("field_2_{i}"));
+        let field2_expr = Expr::StructAtIndex {
+            index: i as u64,
+            field_layouts,
+            structure: ARG_2,
+        };
+        let field2_stmt = |next| Stmt::Let(field2_sym, field2_expr, layout, next);
+
+        let compare_call_expr = root
+            .call_specialized_op(
+                ident_ids,
+                ctx,
+                layout_interner,
+                layout,
+                root.arena.alloc([field1_sym, field2_sym]),
+            )
+            .unwrap();
+
+        let compare_call_sym = root.create_symbol(ident_ids, &format!("compare_call_{i}"));
+        let compare_call_stmt =
+            |next| Stmt::Let(compare_call_sym, compare_call_expr, LAYOUT_ORDERING, next);
+
+        else_stmt = field1_stmt(root.arena.alloc(
+            //
+            field2_stmt(root.arena.alloc(
+                //
+                compare_call_stmt(root.arena.alloc(
+                    //
+                    if_not_equal_return(root, compare_call_sym, else_stmt),
+                )),
+            )),
+        ))
+    }
+
+    else_stmt
+}
+
+fn compare_tag_union<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+) -> Stmt<'a> {
+    use UnionLayout::*;
+
+    let parent_rec_ptr_layout = ctx.recursive_union;
+    if !matches!(union_layout, NonRecursive(_)) {
+        ctx.recursive_union = Some(union_layout);
+    }
+
+    let body = match union_layout {
+        NonRecursive(&[]) => {
+            // cannot be reached at runtime, but we need to generate valid code
+            return_ordering(root, ident_ids, EQ)
+        }
+        NonRecursive(tags) | Recursive(tags) => compare_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            union_layout,
+            tags,
+            NullableId::None,
+        ),
+
+        NonNullableUnwrapped(field_layouts) => compare_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            union_layout,
+            root.arena.alloc([field_layouts]),
+            NullableId::None,
+        ),
+
+        NullableWrapped {
+            other_tags,
+            nullable_id,
+        } => compare_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            union_layout,
+            other_tags,
+            NullableId::Wrapped(nullable_id),
+        ),
+
+        NullableUnwrapped {
+            other_fields,
+            nullable_id,
+        } => compare_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            union_layout,
+            root.arena.alloc([other_fields]),
+            NullableId::Unwrapped(nullable_id),
+        ),
+    };
+
+    ctx.recursive_union = parent_rec_ptr_layout;
+
+    body
+}
+
+fn compare_tag_union_help<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+    tag_layouts: &'a [&'a [InLayout<'a>]],
+    nullable_id: NullableId,
+) -> Stmt<'a> {
+    let arena = root.arena;
+    let tag_id_layout = union_layout.tag_id_layout();
+
+    let tag_id_a = root.create_symbol(ident_ids, "tag_id_a");
+    let tag_id_a_stmt = |next| {
+        Stmt::Let(
+            tag_id_a,
+            Expr::GetTagId {
+                structure: ARG_1,
+                union_layout,
+            },
+            tag_id_layout,
+            next,
+        )
+    };
+
+    let tag_id_b = root.create_symbol(ident_ids, "tag_id_b");
+    let tag_id_b_stmt = |next| {
+        Stmt::Let(
+            tag_id_b,
+            Expr::GetTagId {
+                structure: ARG_2,
+                union_layout,
+            },
+            tag_id_layout,
+            next,
+        )
+    };
+
+    // Tag ids are assigned in alphabetical order of the tag names,
+    // so comparing them orders values with different tags by name.
+    let compare_tag_ids = root.create_symbol(ident_ids, "compare_tag_ids");
+    let compare_tag_ids_stmt = |next| {
+        let_lowlevel(
+            arena,
+            LAYOUT_ORDERING,
+            compare_tag_ids,
+            LowLevel::NumCompare,
+            &[tag_id_a, tag_id_b],
+            next,
+        )
+    };
+
+    //
+    // Switch statement by tag ID
+    //
+
+    let mut tag_branches = Vec::with_capacity_in(tag_layouts.len(), root.arena);
+
+    // If there's a null tag, check it first. We might not need to load any data from memory.
+    match nullable_id {
+        NullableId::Wrapped(id) => tag_branches.push((
+            id as u64,
+            BranchInfo::None,
+            return_ordering(root, ident_ids, EQ),
+        )),
+        NullableId::Unwrapped(id) => tag_branches.push((
+            id as TagIdIntType as u64,
+            BranchInfo::None,
+            return_ordering(root, ident_ids, EQ),
+        )),
+        NullableId::None => (),
+    }
+
+    let default_tag = if let NullableId::Unwrapped(tag_id) = nullable_id {
+        (!tag_id) as TagIdIntType
+    } else {
+        let mut tag_id: TagIdIntType = 0;
+
+        for field_layouts in tag_layouts.iter().take(tag_layouts.len() - 1) {
+            if let NullableId::Wrapped(null_id) = nullable_id {
+                if tag_id == null_id as TagIdIntType {
+                    tag_id += 1;
+                }
+            }
+
+            let tag_stmt = compare_tag_fields(
+                root,
+                ident_ids,
+                ctx,
+                layout_interner,
+                union_layout,
+                field_layouts,
+                tag_id,
+            );
+            tag_branches.push((tag_id as u64, BranchInfo::None, tag_stmt));
+
+            tag_id += 1;
+        }
+
+        tag_id
+    };
+
+    let tag_switch_stmt = Stmt::Switch {
+        cond_symbol: tag_id_a,
+        cond_layout: tag_id_layout,
+        branches: tag_branches.into_bump_slice(),
+        default_branch: (
+            BranchInfo::None,
+            root.arena.alloc(compare_tag_fields(
+                root,
+                ident_ids,
+                ctx,
+                layout_interner,
+                union_layout,
+                tag_layouts.last().unwrap(),
+                default_tag,
+            )),
+        ),
+        ret_layout: LAYOUT_ORDERING,
+        region: Region::zero(),
+    };
+
+    //
+    // combine all the statments
+    //
+    tag_id_a_stmt(root.arena.alloc(
+        //
+        tag_id_b_stmt(root.arena.alloc(
+            //
+            compare_tag_ids_stmt(root.arena.alloc(
+                //
+                if_not_equal_return(root, compare_tag_ids, tag_switch_stmt),
+            )),
+        )),
+    ))
+}
+
+fn compare_tag_fields<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+    field_layouts: &'a [InLayout<'a>],
+    tag_id: TagIdIntType,
+) -> Stmt<'a> {
+    let mut stmt = return_ordering(root, ident_ids, EQ);
+    for (i, layout) in field_layouts.iter().enumerate().rev() {
+        let field1_sym = root.create_symbol(ident_ids, &format!("field_1_{tag_id}_{i}"));
+        let field2_sym = root.create_symbol(ident_ids, &format!("field_2_{tag_id}_{i}"));
+
+        let field1_expr = Expr::UnionAtIndex {
+            union_layout,
+            tag_id,
+            index: i as u64,
+            structure: ARG_1,
+        };
+
+        let field2_expr = Expr::UnionAtIndex {
+            union_layout,
+            tag_id,
+            index: i as u64,
+            structure: ARG_2,
+        };
+
+        let compare_call_expr = root
+            .call_specialized_op(
+                ident_ids,
+                ctx,
+                layout_interner,
+                *layout,
+                root.arena.alloc([field1_sym, field2_sym]),
+            )
+            .unwrap();
+
+        let compare_call_sym = root.create_symbol(ident_ids, &format!("compare_call_{i}"));
+
+        stmt = Stmt::Let(
+            field1_sym,
+            field1_expr,
+            *layout,
+            root.arena.alloc(
+                //
+                Stmt::Let(
+                    field2_sym,
+                    field2_expr,
+                    *layout,
+                    root.arena.alloc(
+                        //
+                        Stmt::Let(
+                            compare_call_sym,
+                            compare_call_expr,
+                            LAYOUT_ORDERING,
+                            root.arena.alloc(
+                                //
+                                if_not_equal_return(root, compare_call_sym, stmt),
+                            ),
+                        ),
+                    ),
+                ),
+            ),
+        )
+    }
+    stmt
+}
+
+fn compare_boxed<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    inner_layout: InLayout<'a>,
+) -> Stmt<'a> {
+    let a = root.create_symbol(ident_ids, "a");
+    let b = root.create_symbol(ident_ids, "b");
+    let result = root.create_symbol(ident_ids, "result");
+
+    let a_expr = Expr::ptr_load(&ARG_1);
+    let b_expr = Expr::ptr_load(&ARG_2);
+    let compare_call_expr = root
+        .call_specialized_op(
+            ident_ids,
+            ctx,
+            layout_interner,
+            inner_layout,
+            root.arena.alloc([a, b]),
+        )
+        .unwrap();
+
+    Stmt::Let(
+        a,
+        a_expr,
+        inner_layout,
+        root.arena.alloc(
+            //
+            Stmt::Let(
+                b,
+                b_expr,
+                inner_layout,
+                root.arena.alloc(
+                    //
+                    Stmt::Let(
+                        result,
+                        compare_call_expr,
+                        LAYOUT_ORDERING,
+                        root.arena.alloc(Stmt::Ret(result, Region::zero())),
+                    ),
+                ),
+            ),
+        ),
+    )
+}
+
+/// List comparison
+/// Like list equality, this walks the elements through pointers rather than with `ListGetUnsafe`.
+/// The first pair of elements that differ decides the ordering. If one list runs out of elements
+/// first, it's a prefix of the other, and the shorter list sorts first.
+fn compare_list<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    elem_layout: InLayout<'a>,
+) -> Stmt<'a> {
+    use LowLevel::*;
+    let layout_isize = root.layout_isize;
+    let arena = root.arena;
+
+    // A pointer layout (heap pointer to a single list element)
+    let ptr_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Ptr(elem_layout));
+
+    let len_1 = root.create_symbol(ident_ids, "len_1");
+    let len_2 = root.create_symbol(ident_ids, "len_2");
+    let len_1_stmt = |next| let_lowlevel(arena, layout_isize, len_1, ListLen, &[ARG_1], next);
+    let len_2_stmt = |next| let_lowlevel(arena, layout_isize, len_2, ListLen, &[ARG_2], next);
+
+    // get element pointers
+    let elements_1 = root.create_symbol(ident_ids, "elements_1");
+    let elements_2 = root.create_symbol(ident_ids, "elements_2");
+    let elements_1_expr = Expr::StructAtIndex {
+        index: 0,
+        field_layouts: root.arena.alloc([ptr_layout, layout_isize]),
+        structure: ARG_1,
+    };
+    let elements_2_expr = Expr::StructAtIndex {
+        index: 0,
+        field_layouts: root.arena.alloc([ptr_layout, layout_isize]),
+        structure: ARG_2,
+    };
+    let elements_1_stmt = |next| Stmt::Let(elements_1, elements_1_expr, ptr_layout, next);
+    let elements_2_stmt = |next| Stmt::Let(elements_2, elements_2_expr, ptr_layout, next);
+
+    // Cast to integers
+    let start_1 = root.create_symbol(ident_ids, "start_1");
+    let start_2 = root.create_symbol(ident_ids, "start_2");
+    let start_1_stmt =
+        |next| let_lowlevel(arena, layout_isize, start_1, PtrCast, &[elements_1], next);
+    let start_2_stmt =
+        |next| let_lowlevel(arena, layout_isize, start_2, PtrCast, &[elements_2], next);
+
+    //
+    // Loop initialisation
+    //
+
+    let int_literal = |n: i128| Expr::Literal(Literal::Int(n.to_ne_bytes()));
+
+    // let size = literal int
+    let size = root.create_symbol(ident_ids, "size");
+    let size_expr = int_literal(
+        layout_interner
+            .get_repr(elem_layout)
+            .stack_size(layout_interner) as i128,
+    );
+    let size_stmt = |next| Stmt::Let(size, size_expr, layout_isize, next);
+
+    let zero = root.create_symbol(ident_ids, "zero");
+    let zero_stmt = |next| Stmt::Let(zero, int_literal(0), layout_isize, next);
+
+    let one = root.create_symbol(ident_ids, "one");
+    let one_stmt = |next| Stmt::Let(one, int_literal(1), layout_isize, next);
+
+    //
+    // Loop name & parameters
+    //
+
+    let elems_loop = JoinPointId(root.create_symbol(ident_ids, "elems_loop"));
+    let index = root.create_symbol(ident_ids, "index");
+
+    let param_index = Param {
+        symbol: index,
+        layout: layout_isize,
+    };
+
+    //
+    // if neither list has reached its end yet...
+    //
+
+    // Find the addresses of the current elements
+    let offset = root.create_symbol(ident_ids, "offset");
+    let offset_stmt =
+        |next| let_lowlevel(arena, layout_isize, offset, NumMul, &[index, size], next);
+
+    let addr1 = root.create_symbol(ident_ids, "addr1");
+    let addr2 = root.create_symbol(ident_ids, "addr2");
+    let addr1_stmt =
+        |next| let_lowlevel(arena, layout_isize, addr1, NumAdd, &[start_1, offset], next);
+    let addr2_stmt =
+        |next| let_lowlevel(arena, layout_isize, addr2, NumAdd, &[start_2, offset], next);
+
+    // Cast integers to pointers
+    let ptr1 = root.create_symbol(ident_ids, "ptr1");
+    let ptr2 = root.create_symbol(ident_ids, "ptr2");
+    let ptr1_stmt = |next| let_lowlevel(arena, ptr_layout, ptr1, PtrCast, &[addr1], next);
+    let ptr2_stmt = |next| let_lowlevel(arena, ptr_layout, ptr2, PtrCast, &[addr2], next);
+
+    // Dereference the pointers to get the current elements
+    let elem1 = root.create_symbol(ident_ids, "elem1");
+    let elem2 = root.create_symbol(ident_ids, "elem2");
+    let elem1_expr = Expr::ptr_load(arena.alloc(ptr1));
+    let elem2_expr = Expr::ptr_load(arena.alloc(ptr2));
+    let elem1_stmt = |next| Stmt::Let(elem1, elem1_expr, elem_layout, next);
+    let elem2_stmt = |next| Stmt::Let(elem2, elem2_expr, elem_layout, next);
+
+    // Compare the two current elements
+    let compare_elems = root.create_symbol(ident_ids, "compare_elems");
+    let compare_elems_args = root.arena.alloc([elem1, elem2]);
+    let compare_elems_expr = root
+        .call_specialized_op(
+            ident_ids,
+            ctx,
+            layout_interner,
+            elem_layout,
+            compare_elems_args,
+        )
+        .unwrap();
+
+    let compare_elems_stmt =
+        |next| Stmt::Let(compare_elems, compare_elems_expr, LAYOUT_ORDERING, next);
+
+    // If current elements are equal, loop back again
+    let next_index = root.create_symbol(ident_ids, "next_index");
+    let next_index_stmt =
+        |next| let_lowlevel(arena, layout_isize, next_index, NumAdd, &[index, one], next);
+
+    let jump_back = Stmt::Jump(elems_loop, root.arena.alloc([next_index]), Region::zero());
+
+    //
+    // Control flow
+    //
+
+    let is_end_1 = root.create_symbol(ident_ids, "is_end_1");
+    let is_end_1_stmt =
+        |next| let_lowlevel(arena, LAYOUT_BOOL, is_end_1, NumGte, &[index, len_1], next);
+
+    let is_end_2 = root.create_symbol(ident_ids, "is_end_2");
+    let is_end_2_stmt =
+        |next| let_lowlevel(arena, LAYOUT_BOOL, is_end_2, NumGte, &[index, len_2], next);
+
+    // The first list is a prefix of the second, so the shorter one sorts first
+    let compare_lens = root.create_symbol(ident_ids, "compare_lens");
+    let compare_lens_stmt = |next| {
+        let_lowlevel(
+            arena,
+            LAYOUT_ORDERING,
+            compare_lens,
+            NumCompare,
+            &[len_1, len_2],
+            next,
+        )
+    };
+
+    let if_elems_not_equal = if_not_equal_return(
+        root,
+        compare_elems,
+        // else
+        next_index_stmt(root.arena.alloc(
+            //
+            jump_back,
+        )),
+    );
+
+    let compare_current_elems = offset_stmt(root.arena.alloc(
+        //
+        addr1_stmt(root.arena.alloc(
+            //
+            addr2_stmt(root.arena.alloc(
+                //
+                ptr1_stmt(root.arena.alloc(
+                    //
+                    ptr2_stmt(root.arena.alloc(
+                        //
+                        elem1_stmt(root.arena.alloc(
+                            //
+                            elem2_stmt(root.arena.alloc(
+                                //
+                                compare_elems_stmt(root.arena.alloc(
+                                    //
+                                    if_elems_not_equal,
+                                )),
+                            )),
+                        )),
+                    )),
+                )),
+            )),
+        )),
+    ));
+
+    // The second list is a proper prefix of the first, so the first one sorts last
+    let if_end_of_list_2 = is_end_2_stmt(root.arena.alloc(
+        //
+        Stmt::if_then_else(
+            arena,
+            is_end_2,
+            LAYOUT_ORDERING,
+            return_ordering(root, ident_ids, GT),
+            root.arena.alloc(compare_current_elems),
+        ),
+    ));
+
+    let if_end_of_list_1 = Stmt::if_then_else(
+        arena,
+        is_end_1,
+        LAYOUT_ORDERING,
+        compare_lens_stmt(root.arena.alloc(Stmt::Ret(compare_lens, Region::zero()))),
+        root.arena.alloc(if_end_of_list_2),
+    );
+
+    let joinpoint_loop = Stmt::Join {
+        id: elems_loop,
+        parameters: root.arena.alloc([param_index]),
+        body: root.arena.alloc(
+            //
+            is_end_1_stmt(
+                //
+                root.arena.alloc(if_end_of_list_1),
+            ),
+        ),
+        remainder: root.arena.alloc(Stmt::Jump(
+            elems_loop,
+            root.arena.alloc([zero]),
+            Region::zero(),
+        )),
+        region: Region::zero(),
+    };
+
+    len_1_stmt(root.arena.alloc(
+        //
+        len_2_stmt(root.arena.alloc(
+            //
+            elements_1_stmt(root.arena.alloc(
+                //
+                elements_2_stmt(root.arena.alloc(
+                    //
+                    start_1_stmt(root.arena.alloc(
+                        //
+                        start_2_stmt(root.arena.alloc(
+                            //
+                            size_stmt(root.arena.alloc(
+                                //
+                                zero_stmt(root.arena.alloc(
+                                    //
+                                    one_stmt(root.arena.alloc(
+                                        //
+                                        joinpoint_loop,
+                                    )),
+                                )),
+                            )),
+                        )),
+                    )),
+                )),
+            )),
+        )),
+    ))
+}
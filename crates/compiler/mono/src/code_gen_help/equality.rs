@@ -1,6 +1,7 @@
 use bumpalo::collections::vec::Vec;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
 
 use crate::ir::{
     BranchInfo, Call, CallType, Expr, JoinPointId, Literal, Param, Stmt, UpdateModeId,
@@ -105,10 +106,11 @@ fn if_pointers_equal_return_true<'a>(
                     branches: root.arena.alloc([(
                         1,
                         BranchInfo::None,
-                        Stmt::Ret(Symbol::BOOL_TRUE),
+                        Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
                     )]),
                     default_branch: (BranchInfo::None, following),
                     ret_layout: LAYOUT_BOOL,
+                    region: Region::zero(),
                 }),
             )),
         )),
@@ -125,7 +127,8 @@ fn if_false_return_false<'a>(
         symbol,
         Layout::BOOL,
         following,
-        root.arena.alloc(Stmt::Ret(Symbol::BOOL_FALSE)),
+        root.arena
+            .alloc(Stmt::Ret(Symbol::BOOL_FALSE, Region::zero())),
     )
 }
 
@@ -136,7 +139,7 @@ fn eq_struct<'a>(
     layout_interner: &mut STLayoutInterner<'a>,
     field_layouts: &'a [InLayout<'a>],
 ) -> Stmt<'a> {
-    let mut else_stmt = Stmt::Ret(Symbol::BOOL_TRUE);
+    let mut else_stmt = Stmt::Ret(Symbol::BOOL_TRUE, Region::zero());
     for (i, layout) in field_layouts.iter().enumerate().rev() {
         let field1_sym = root.create_symbol(ident_ids, &format!("field_1_{i}"));
         let field1_expr = Expr::StructAtIndex {
@@ -200,7 +203,7 @@ fn eq_tag_union<'a>(
     let body = match union_layout {
         NonRecursive(&[]) => {
             // cannot be reached at runtime, but we need to generate valid code
-            Stmt::Ret(Symbol::BOOL_TRUE)
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero())
         }
         NonRecursive(tags) => eq_tag_union_help(
             root,
@@ -267,7 +270,7 @@ fn eq_tag_union<'a>(
     body
 }
 
-enum NullableId {
+pub(super) enum NullableId {
     None,
     Wrapped(TagIdIntType),
     Unwrapped(bool),
@@ -331,9 +334,11 @@ fn eq_tag_union_help<'a>(
     });
     let tag_ids_eq_stmt = |next| Stmt::Let(tag_ids_eq, tag_ids_expr, LAYOUT_BOOL, next);
 
-    let if_equal_ids_branches =
-        root.arena
-            .alloc([(0, BranchInfo::None, Stmt::Ret(Symbol::BOOL_FALSE))]);
+    let if_equal_ids_branches = root.arena.alloc([(
+        0,
+        BranchInfo::None,
+        Stmt::Ret(Symbol::BOOL_FALSE, Region::zero()),
+    )]);
 
     //
     // Switch statement by tag ID
@@ -343,13 +348,15 @@ fn eq_tag_union_help<'a>(
 
     // If there's a null tag, check it first. We might not need to load any data from memory.
     match nullable_id {
-        NullableId::Wrapped(id) => {
-            tag_branches.push((id as u64, BranchInfo::None, Stmt::Ret(Symbol::BOOL_TRUE)))
-        }
+        NullableId::Wrapped(id) => tag_branches.push((
+            id as u64,
+            BranchInfo::None,
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
+        )),
         NullableId::Unwrapped(id) => tag_branches.push((
             id as TagIdIntType as u64,
             BranchInfo::None,
-            Stmt::Ret(Symbol::BOOL_TRUE),
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
         )),
         _ => (),
     }
@@ -404,6 +411,7 @@ fn eq_tag_union_help<'a>(
             )),
         ),
         ret_layout: LAYOUT_BOOL,
+        region: Region::zero(),
     };
 
     let if_equal_ids_stmt = Stmt::Switch {
@@ -412,6 +420,7 @@ fn eq_tag_union_help<'a>(
         branches: if_equal_ids_branches,
         default_branch: (BranchInfo::None, root.arena.alloc(tag_switch_stmt)),
         ret_layout: LAYOUT_BOOL,
+        region: Region::zero(),
     };
 
     //
@@ -446,13 +455,18 @@ fn eq_tag_union_help<'a>(
             layout: union_layout,
         });
 
-        let loop_start = Stmt::Jump(tailrec_loop, root.arena.alloc([ARG_1, ARG_2]));
+        let loop_start = Stmt::Jump(
+            tailrec_loop,
+            root.arena.alloc([ARG_1, ARG_2]),
+            Region::zero(),
+        );
 
         Stmt::Join {
             id: tailrec_loop,
             parameters: root.arena.alloc_slice_fill_iter(loop_params_iter),
             body: root.arena.alloc(compare_ptr_or_value),
             remainder: root.arena.alloc(loop_start),
+            region: Region::zero(),
         }
     }
 }
@@ -481,7 +495,10 @@ fn eq_tag_fields<'a>(
     let (tailrec_index, innermost_stmt) = match rec_ptr_index {
         None => {
             // This tag has no RecursivePointers. Set tailrec_index out of range.
-            (field_layouts.len(), Stmt::Ret(Symbol::BOOL_TRUE))
+            (
+                field_layouts.len(),
+                Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
+            )
         }
 
         Some(i) => {
@@ -516,7 +533,11 @@ fn eq_tag_fields<'a>(
                         field_layouts[i],
                         root.arena.alloc(
                             //
-                            Stmt::Jump(tailrec_loop, root.arena.alloc([field1_sym, field2_sym])),
+                            Stmt::Jump(
+                                tailrec_loop,
+                                root.arena.alloc([field1_sym, field2_sym]),
+                                Region::zero(),
+                            ),
                         ),
                     ),
                 ),
@@ -635,7 +656,7 @@ fn eq_boxed<'a>(
                         result,
                         eq_call_expr,
                         LAYOUT_BOOL,
-                        root.arena.alloc(Stmt::Ret(result)),
+                        root.arena.alloc(Stmt::Ret(result, Region::zero())),
                     ),
                 ),
             ),
@@ -785,7 +806,11 @@ fn eq_list<'a>(
     let next_2_stmt =
         |next| let_lowlevel(arena, layout_isize, next_2, NumAdd, &[addr2, size], next);
 
-    let jump_back = Stmt::Jump(elems_loop, root.arena.alloc([next_1, next_2]));
+    let jump_back = Stmt::Jump(
+        elems_loop,
+        root.arena.alloc([next_1, next_2]),
+        Region::zero(),
+    );
 
     //
     // Control flow
@@ -812,7 +837,7 @@ fn eq_list<'a>(
         arena,
         is_end,
         Layout::BOOL,
-        Stmt::Ret(Symbol::BOOL_TRUE),
+        Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
         root.arena.alloc(
             //
             ptr1_stmt(root.arena.alloc(
@@ -844,9 +869,12 @@ fn eq_list<'a>(
                 root.arena.alloc(if_end_of_list),
             ),
         ),
-        remainder: root
-            .arena
-            .alloc(Stmt::Jump(elems_loop, root.arena.alloc([start_1, start_2]))),
+        remainder: root.arena.alloc(Stmt::Jump(
+            elems_loop,
+            root.arena.alloc([start_1, start_2]),
+            Region::zero(),
+        )),
+        region: Region::zero(),
     };
 
     let if_different_lengths = if_false_return_false(
@@ -3,23 +3,29 @@ use bumpalo::collections::CollectIn;
 use bumpalo::Bump;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 use roc_target::TargetInfo;
 
 use crate::ir::{
     BranchInfo, Call, CallSpecId, CallType, Expr, JoinPointId, Literal, ModifyRc, PassedFunction,
-    Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeId,
+    Proc, ProcLayout, ProcRegions, SelfRecursive, Stmt, UpdateModeId,
 };
 use crate::layout::{
     Builtin, InLayout, LambdaName, Layout, LayoutInterner, LayoutRepr, LayoutWrapper, Niche,
     STLayoutInterner, UnionLayout,
 };
 
+mod compare;
 mod equality;
 mod refcount;
 
 const LAYOUT_BOOL: InLayout = Layout::BOOL;
 const LAYOUT_UNIT: InLayout = Layout::UNIT;
 
+/// `[LT, EQ, GT]` is represented by its tag id, and tag ids are assigned in alphabetical order,
+/// so `EQ` is 0, `GT` is 1 and `LT` is 2.
+const LAYOUT_ORDERING: InLayout = Layout::U8;
+
 const ARG_1: Symbol = Symbol::ARG_1;
 const ARG_2: Symbol = Symbol::ARG_2;
 const ARG_3: Symbol = Symbol::ARG_3;
@@ -41,6 +47,7 @@ pub enum HelperOp {
     Reset,
     ResetRef,
     Eq,
+    Compare,
 }
 
 impl HelperOp {
@@ -76,7 +83,7 @@ pub struct Context<'a> {
 /// ----------------------------------------------
 ///
 /// Some low level operations need specialized helper procs to traverse data structures at runtime.
-/// This includes refcounting, hashing, and equality checks.
+/// This includes refcounting, hashing, equality checks and comparisons.
 ///
 /// For example, when checking List equality, we need to visit each element and compare them.
 /// Depending on the type of the list elements, we may need to recurse deeper into each element.
@@ -270,6 +277,28 @@ impl<'a> CodeGenHelp<'a> {
         (expr, ctx.new_linker_data)
     }
 
+    /// Replace a generic `Lowlevel::Compare` call with a specialized helper proc.
+    /// The helper procs themselves are to be generated later with `generate_procs`
+    pub fn call_specialized_compare(
+        &mut self,
+        ident_ids: &mut IdentIds,
+        layout_interner: &mut STLayoutInterner<'a>,
+        layout: InLayout<'a>,
+        arguments: &'a [Symbol],
+    ) -> (Expr<'a>, Vec<'a, (Symbol, ProcLayout<'a>)>) {
+        let mut ctx = Context {
+            new_linker_data: Vec::new_in(self.arena),
+            recursive_union: None,
+            op: HelperOp::Compare,
+        };
+
+        let expr = self
+            .call_specialized_op(ident_ids, &mut ctx, layout_interner, layout, arguments)
+            .unwrap();
+
+        (expr, ctx.new_linker_data)
+    }
+
     // ============================================================================
     //
     //              CALL SPECIALIZED OP
@@ -314,6 +343,7 @@ impl<'a> CodeGenHelp<'a> {
                     IndirectDec => (LAYOUT_UNIT, arena.alloc([ptr_arg])),
                     IndirectInc => (LAYOUT_UNIT, arena.alloc([ptr_arg, self.layout_isize])),
                     Eq => (LAYOUT_BOOL, self.arena.alloc([arg, arg])),
+                    Compare => (LAYOUT_ORDERING, self.arena.alloc([arg, arg])),
                 }
             };
 
@@ -334,6 +364,23 @@ impl<'a> CodeGenHelp<'a> {
                 },
                 arguments,
             }))
+        } else if ctx.op == HelperOp::Compare {
+            let op = match layout_interner.get_repr(layout) {
+                LayoutRepr::Builtin(Builtin::Str) => LowLevel::StrCompare,
+                LayoutRepr::Builtin(Builtin::Int(_) | Builtin::Float(_) | Builtin::Decimal) => {
+                    LowLevel::NumCompare
+                }
+                // Bools are compared inline by the backends
+                _ => LowLevel::Compare,
+            };
+
+            Some(Expr::Call(Call {
+                call_type: CallType::LowLevel {
+                    op,
+                    update_mode: UpdateModeId::BACKEND_DUMMY,
+                },
+                arguments,
+            }))
         } else {
             None
         }
@@ -423,6 +470,10 @@ impl<'a> CodeGenHelp<'a> {
                 LAYOUT_BOOL,
                 equality::eq_generic(self, ident_ids, ctx, layout_interner, layout),
             ),
+            Compare => (
+                LAYOUT_ORDERING,
+                compare::compare_generic(self, ident_ids, ctx, layout_interner, layout),
+            ),
         };
 
         let args: &'a [(InLayout<'a>, Symbol)] = {
@@ -444,7 +495,7 @@ impl<'a> CodeGenHelp<'a> {
                         layout_interner.insert_direct_no_semantic(LayoutRepr::Ptr(layout));
                     self.arena.alloc([(ptr_layout, ARG_1)])
                 }
-                Eq => self.arena.alloc([roc_value, (layout, ARG_2)]),
+                Eq | Compare => self.arena.alloc([roc_value, (layout, ARG_2)]),
             }
         };
 
@@ -456,6 +507,7 @@ impl<'a> CodeGenHelp<'a> {
             ret_layout,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            regions: ProcRegions::default(),
         });
 
         proc_symbol
@@ -517,6 +569,11 @@ impl<'a> CodeGenHelp<'a> {
                 result: LAYOUT_BOOL,
                 niche: Niche::NONE,
             },
+            HelperOp::Compare => ProcLayout {
+                arguments: self.arena.alloc([layout, layout]),
+                result: LAYOUT_ORDERING,
+                niche: Niche::NONE,
+            },
         };
 
         (proc_symbol, proc_layout)
@@ -539,7 +596,9 @@ impl<'a> CodeGenHelp<'a> {
         layout: InLayout<'a>,
     ) -> InLayout<'a> {
         let lay = layout_interner.get_repr(layout);
-        let semantic = layout_interner.get_semantic(layout);
+        // The repr is rebuilt below, so keep the semantic that describes it, rather than that of
+        // a newtype wrapping it. Comparisons use it to order the fields of records and tuples.
+        let semantic = layout_interner.get_semantic(layout_interner.unwrap_newtype(layout));
         let repr = match lay {
             LayoutRepr::Builtin(Builtin::List(v)) => {
                 let v = self.replace_rec_ptr(ctx, layout_interner, v);
@@ -763,7 +822,7 @@ impl<'a> CallerProc<'a> {
                     unit_symbol,
                     Expr::Struct(&[]),
                     Layout::UNIT,
-                    arena.alloc(Stmt::Ret(unit_symbol)),
+                    arena.alloc(Stmt::Ret(unit_symbol, Region::zero())),
                 )),
             )),
         );
@@ -811,6 +870,7 @@ impl<'a> CallerProc<'a> {
             ret_layout: Layout::UNIT,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            regions: ProcRegions::default(),
         };
 
         if false {
@@ -908,7 +968,7 @@ impl<'a> CallerProc<'a> {
             call_result,
             call,
             passed_function.return_layout,
-            arena.alloc(Stmt::Ret(call_result)),
+            arena.alloc(Stmt::Ret(call_result, Region::zero())),
         );
 
         let it = loaded_arguments
@@ -953,6 +1013,7 @@ impl<'a> CallerProc<'a> {
             ret_layout: Layout::BOOL,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            regions: ProcRegions::default(),
         };
 
         if false {
@@ -1011,7 +1072,8 @@ fn layout_needs_helper_proc<'a>(
         LayoutRepr::Union(_) => true,
         LayoutRepr::LambdaSet(_) => true,
         LayoutRepr::RecursivePointer(_) => false,
-        LayoutRepr::Ptr(_) => false,
+        // Box comparisons load the boxed values, which the backends don't do inline.
+        LayoutRepr::Ptr(_) => matches!(op, HelperOp::Compare),
         LayoutRepr::FunctionPointer(_) => false,
         LayoutRepr::Erased(_) => true,
     }
@@ -1057,6 +1119,7 @@ pub fn test_helper<'a>(
         ret_layout: output_layout,
         is_self_recursive: main_proc.is_self_recursive,
         is_erased: false,
+        regions: ProcRegions::default(),
     }
 }
 
@@ -1148,7 +1211,7 @@ fn test_helper_body<'a>(
                     //
                     output(arena.alloc(
                         //
-                        Stmt::Ret(output_symbol),
+                        Stmt::Ret(output_symbol, Region::zero()),
                     )),
                 )),
             )),
@@ -1188,7 +1251,7 @@ fn test_helper_body<'a>(
                 //
                 output(arena.alloc(
                     //
-                    Stmt::Ret(output_symbol),
+                    Stmt::Ret(output_symbol, Region::zero()),
                 )),
             )),
         )))
@@ -1257,6 +1320,7 @@ pub fn repl_helper<'a>(
         ret_layout: Layout::UNIT,
         is_self_recursive: main_proc.is_self_recursive,
         is_erased: false,
+        regions: ProcRegions::default(),
     }
 }
 
@@ -1354,7 +1418,7 @@ fn repl_helper_body<'a>(
                         //
                         unit(arena.alloc(
                             //
-                            Stmt::Ret(unit_symbol),
+                            Stmt::Ret(unit_symbol, Region::zero()),
                         )),
                     )),
                 )),
@@ -1401,7 +1465,7 @@ fn repl_helper_body<'a>(
                     //
                     unit(arena.alloc(
                         //
-                        Stmt::Ret(unit_symbol),
+                        Stmt::Ret(unit_symbol, Region::zero()),
                     )),
                 )),
             )),
@@ -1445,5 +1509,6 @@ fn switch_if_zero_else<'a>(
         branches: &*arena.alloc([then_branch]),
         default_branch: else_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     }
 }
@@ -5,6 +5,7 @@ use bumpalo::collections::CollectIn;
 use roc_error_macros::todo_lambda_erasure;
 use roc_module::low_level::{LowLevel, LowLevel::*};
 use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
 use roc_target::PtrWidth;
 
 use crate::code_gen_help::let_lowlevel;
@@ -111,6 +112,7 @@ pub fn refcount_stmt<'a>(
                             parameters: &[],
                             body: following,
                             remainder: arena.alloc(rc_stmt),
+                            region: Region::zero(),
                         };
                         arena.alloc(join)
                     }
@@ -167,7 +169,7 @@ pub fn refcount_indirect<'a>(
                     Layout::UNIT,
                     arena.alloc(
                         //
-                        Stmt::Ret(unit),
+                        Stmt::Ret(unit, Region::zero()),
                     ),
                 ),
             ),
@@ -326,7 +328,7 @@ pub fn refcount_reset_proc_body<'a>(
             structure,
             tag_id_sym,
             tag_id_layout,
-            Stmt::Ret(addr),
+            Stmt::Ret(addr, Region::zero()),
         );
 
         tag_id_stmt(root.arena.alloc(
@@ -357,7 +359,7 @@ pub fn refcount_reset_proc_body<'a>(
             //
             null_stmt(root.arena.alloc(
                 //
-                Stmt::Ret(null),
+                Stmt::Ret(null, Region::zero()),
             )),
         ))
     };
@@ -368,6 +370,7 @@ pub fn refcount_reset_proc_body<'a>(
         branches: root.arena.alloc([(1, BranchInfo::None, then_stmt)]),
         default_branch: (BranchInfo::None, root.arena.alloc(else_stmt)),
         ret_layout: layout,
+        region: Region::zero(),
     };
 
     // Uniqueness test
@@ -454,7 +457,7 @@ pub fn refcount_resetref_proc_body<'a>(
         layout_interner.insert_direct_no_semantic(LayoutRepr::RecursivePointer(layout));
 
     // Reset structure is unique. Return a pointer to the allocation.
-    let then_stmt = Stmt::Ret(addr);
+    let then_stmt = Stmt::Ret(addr, Region::zero());
 
     // Reset structure is not unique. Decrement it and return a NULL pointer.
     let else_stmt = {
@@ -478,10 +481,11 @@ pub fn refcount_resetref_proc_body<'a>(
                 //
                 null_stmt(root.arena.alloc(
                     //
-                    Stmt::Ret(null),
+                    Stmt::Ret(null, Region::zero()),
                 )),
             )),
             remainder: root.arena.alloc(rc_stmt),
+            region: Region::zero(),
         };
 
         root.arena.alloc(join)
@@ -559,10 +563,10 @@ fn rc_return_stmt<'a>(
     ctx: &mut Context<'a>,
 ) -> Stmt<'a> {
     if let HelperOp::DecRef(jp_decref) = ctx.op {
-        Stmt::Jump(jp_decref, &[])
+        Stmt::Jump(jp_decref, &[], Region::zero())
     } else {
         let unit = root.create_symbol(ident_ids, "unit");
-        let ret_stmt = root.arena.alloc(Stmt::Ret(unit));
+        let ret_stmt = root.arena.alloc(Stmt::Ret(unit, Region::zero()));
         Stmt::Let(unit, Expr::Struct(&[]), LAYOUT_UNIT, ret_stmt)
     }
 }
@@ -1007,12 +1011,17 @@ fn refcount_list<'a>(
         Stmt::Jump(
             jp_elements,
             arena.alloc([slice_data_pointer, first_element]),
+            Region::zero(),
         ),
     );
 
     let list_branch = arena.alloc(
         //
-        Stmt::Jump(jp_elements, arena.alloc([first_element, first_element])),
+        Stmt::Jump(
+            jp_elements,
+            arena.alloc([first_element, first_element]),
+            Region::zero(),
+        ),
     );
 
     let switch_slice_list = arena.alloc(first_element_stmt(arena.alloc(
@@ -1069,6 +1078,7 @@ fn refcount_list<'a>(
         parameters: arena.alloc([param_data_pointer, param_first_element_pointer]),
         body: arena.alloc(modify_elems_and_list),
         remainder: arena.alloc(switch_slice_list),
+        region: Region::zero(),
     };
 
     //
@@ -1227,7 +1237,7 @@ fn refcount_list_elems<'a>(
                     //
                     next_addr_stmt(arena.alloc(
                         //
-                        Stmt::Jump(elems_loop, arena.alloc([next_addr])),
+                        Stmt::Jump(elems_loop, arena.alloc([next_addr]), Region::zero()),
                     )),
                 )),
             )),
@@ -1244,9 +1254,12 @@ fn refcount_list_elems<'a>(
                 arena.alloc(if_end_of_list),
             ),
         ),
-        remainder: root
-            .arena
-            .alloc(Stmt::Jump(elems_loop, arena.alloc([start, end]))),
+        remainder: root.arena.alloc(Stmt::Jump(
+            elems_loop,
+            arena.alloc([start, end]),
+            Region::zero(),
+        )),
+        region: Region::zero(),
     };
 
     start_stmt(arena.alloc(
@@ -1525,7 +1538,7 @@ fn refcount_union_contents<'a>(
     {
         // After refcounting the fields, jump to modify the union itself
         // (Order is important, to avoid use-after-free for Dec)
-        let following = Stmt::Jump(jp_contents_modified, &[]);
+        let following = Stmt::Jump(jp_contents_modified, &[], Region::zero());
 
         let field_layouts = field_layouts
             .iter()
@@ -1557,6 +1570,7 @@ fn refcount_union_contents<'a>(
         branches: tag_branches.into_bump_slice(),
         default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
         ret_layout: LAYOUT_UNIT,
+        region: Region::zero(),
     };
 
     if let UnionLayout::NonRecursive(_) = union_layout {
@@ -1565,6 +1579,7 @@ fn refcount_union_contents<'a>(
             parameters: &[],
             body: root.arena.alloc(next_stmt),
             remainder: root.arena.alloc(tag_id_switch),
+            region: Region::zero(),
         }
     } else {
         let is_unique = root.create_symbol(ident_ids, "is_unique");
@@ -1574,7 +1589,8 @@ fn refcount_union_contents<'a>(
             is_unique,
             Layout::UNIT,
             tag_id_switch,
-            root.arena.alloc(Stmt::Jump(jp_contents_modified, &[])),
+            root.arena
+                .alloc(Stmt::Jump(jp_contents_modified, &[], Region::zero())),
         );
 
         let switch_with_unique_check_and_let = let_lowlevel(
@@ -1591,6 +1607,7 @@ fn refcount_union_contents<'a>(
             parameters: &[],
             body: root.arena.alloc(next_stmt),
             remainder: root.arena.alloc(switch_with_unique_check_and_let),
+            region: Region::zero(),
         }
     }
 }
@@ -1701,7 +1718,7 @@ fn refcount_union_tailrec<'a>(
         let next_addr = root.create_symbol(ident_ids, "next_addr");
 
         let exit_stmt = rc_return_stmt(root, ident_ids, ctx);
-        let jump_to_loop = Stmt::Jump(tailrec_loop, root.arena.alloc([next_ptr]));
+        let jump_to_loop = Stmt::Jump(tailrec_loop, root.arena.alloc([next_ptr]), Region::zero());
 
         let loop_or_exit = Stmt::Switch {
             cond_symbol: next_addr,
@@ -1709,6 +1726,7 @@ fn refcount_union_tailrec<'a>(
             branches: root.arena.alloc([(0, BranchInfo::None, exit_stmt)]),
             default_branch: (BranchInfo::None, root.arena.alloc(jump_to_loop)),
             ret_layout: LAYOUT_UNIT,
+            region: Region::zero(),
         };
         let loop_or_exit_based_on_next_addr = {
             let_lowlevel(
@@ -1766,7 +1784,11 @@ fn refcount_union_tailrec<'a>(
                                 structure: current,
                             };
                             let jump_params = root.arena.alloc([field_val]);
-                            let jump = root.arena.alloc(Stmt::Jump(jp_modify_union, jump_params));
+                            let jump = root.arena.alloc(Stmt::Jump(
+                                jp_modify_union,
+                                jump_params,
+                                Region::zero(),
+                            ));
                             tail_stmt = Some(Stmt::Let(field_val, field_val_expr, *field, jump));
                         }
                     }
@@ -1778,7 +1800,7 @@ fn refcount_union_tailrec<'a>(
 
                     let tail_stmt = null_stmt(root.arena.alloc(
                         //
-                        Stmt::Jump(jp_modify_union, root.arena.alloc([null])),
+                        Stmt::Jump(jp_modify_union, root.arena.alloc([null]), Region::zero()),
                     ));
 
                     let field_layouts = field_layouts
@@ -1814,6 +1836,7 @@ fn refcount_union_tailrec<'a>(
             branches: tag_branches.into_bump_slice(),
             default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
             ret_layout: LAYOUT_UNIT,
+            region: Region::zero(),
         };
 
         let is_unique = root.create_symbol(ident_ids, "is_unique");
@@ -1826,6 +1849,7 @@ fn refcount_union_tailrec<'a>(
             root.arena.alloc(Stmt::Jump(
                 jp_modify_union,
                 root.arena.alloc([null_pointer]),
+                Region::zero(),
             )),
         );
 
@@ -1856,6 +1880,7 @@ fn refcount_union_tailrec<'a>(
             parameters: root.arena.alloc([jp_param]),
             body: root.arena.alloc(rc_structure_stmt),
             remainder: root.arena.alloc(switch_with_unique_check_and_let),
+            region: Region::zero(),
         }
     };
 
@@ -1864,7 +1889,11 @@ fn refcount_union_tailrec<'a>(
         rc_contents_then_structure,
     ));
 
-    let loop_init = Stmt::Jump(tailrec_loop, root.arena.alloc([initial_structure]));
+    let loop_init = Stmt::Jump(
+        tailrec_loop,
+        root.arena.alloc([initial_structure]),
+        Region::zero(),
+    );
     let union_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Union(union_layout));
     let loop_param = Param {
         symbol: current,
@@ -1876,6 +1905,7 @@ fn refcount_union_tailrec<'a>(
         parameters: root.arena.alloc([loop_param]),
         body: root.arena.alloc(loop_body),
         remainder: root.arena.alloc(loop_init),
+        region: Region::zero(),
     }
 }
 
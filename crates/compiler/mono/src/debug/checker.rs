@@ -314,6 +314,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 branches,
                 default_branch,
                 ret_layout: _,
+                region: _,
             } => {
                 self.check_sym_layout(*cond_symbol, *cond_layout, UseKind::SwitchCond);
                 let layout = self.resolve(*cond_layout);
@@ -337,7 +338,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 let (_branch_info, default_branch) = default_branch;
                 self.in_scope(|ctx| ctx.check_stmt(default_branch));
             }
-            &Stmt::Ret(sym) => self.check_sym_layout(sym, self.ret_layout, UseKind::Ret),
+            &Stmt::Ret(sym, _) => self.check_sym_layout(sym, self.ret_layout, UseKind::Ret),
             &Stmt::Refcounting(rc, rest) => {
                 self.check_modify_rc(rc);
                 self.check_stmt(rest);
@@ -370,6 +371,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 parameters,
                 body,
                 remainder,
+                region: _,
             } => {
                 if let Some((old_line, _)) = self.joinpoints.insert(id, (self.line, parameters)) {
                     self.problem(ProblemKind::RedefinedJoinPoint { id, old_line })
@@ -383,7 +385,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 self.line += 1; // `in` line
                 self.check_stmt(remainder);
             }
-            &Stmt::Jump(id, symbols) => {
+            &Stmt::Jump(id, symbols, _) => {
                 if let Some(&(def_line, parameters)) = self.joinpoints.get(&id) {
                     if symbols.len() != parameters.len() {
                         self.problem(ProblemKind::JumpArityMismatch {
@@ -15,6 +15,7 @@ use bumpalo::collections::CollectIn;
 
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 
 use crate::ir::{
     BranchInfo, Call, CallType, ErasedField, Expr, JoinPointId, ListLiteralElement, Literal,
@@ -270,6 +271,7 @@ fn specialize_drops_stmt<'a, 'i>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             macro_rules! insert_branch_info {
                 ($branch_env:expr,$info:expr ) => {
@@ -434,9 +436,10 @@ fn specialize_drops_stmt<'a, 'i>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
-        Stmt::Ret(symbol) => arena.alloc(Stmt::Ret(*symbol)),
+        Stmt::Ret(symbol, region) => arena.alloc(Stmt::Ret(*symbol, *region)),
         Stmt::Refcounting(rc, continuation) => match rc {
             ModifyRc::Inc(symbol, count) => {
                 let inc_before = environment.incremented_symbols.contains(symbol);
@@ -650,6 +653,7 @@ fn specialize_drops_stmt<'a, 'i>(
             parameters,
             body,
             remainder,
+            region,
         } => {
             // We cannot perform this optimization if the joinpoint is recursive.
             // E.g. if the body of a recursive joinpoint contains an increment, we do not want to move that increment up to the remainder.
@@ -730,9 +734,10 @@ fn specialize_drops_stmt<'a, 'i>(
                 parameters,
                 body: newer_body,
                 remainder: newer_remainder,
+                region: *region,
             })
         }
-        Stmt::Jump(joinpoint_id, arguments) => {
+        Stmt::Jump(joinpoint_id, arguments, region) => {
             match environment.join_incremented_symbols.get(joinpoint_id) {
                 Some(JoinUsage {
                     join_consumes,
@@ -761,7 +766,7 @@ fn specialize_drops_stmt<'a, 'i>(
                         .insert(*joinpoint_id, environment.incremented_symbols.clone());
                 }
             }
-            arena.alloc(Stmt::Jump(*joinpoint_id, arguments))
+            arena.alloc(Stmt::Jump(*joinpoint_id, arguments, *region))
         }
         Stmt::Crash(symbol, crash_tag) => arena.alloc(Stmt::Crash(*symbol, *crash_tag)),
     }
@@ -1278,7 +1283,7 @@ where
 {
     match continutation {
         // The continuation is a single stmt. So we can insert it inline and skip creating a joinpoint.
-        Stmt::Ret(_) | Stmt::Jump(_, _) => {
+        Stmt::Ret(..) | Stmt::Jump(..) => {
             let u = unique(layout_interner, ident_ids, continutation);
             let n = not_unique(layout_interner, ident_ids, continutation);
 
@@ -1289,6 +1294,7 @@ where
                     branches: &*arena.alloc([(1, BranchInfo::None, u.clone())]),
                     default_branch: (BranchInfo::None, n),
                     ret_layout: environment.layout,
+                    region: Region::zero(),
                 })
             };
 
@@ -1298,7 +1304,7 @@ where
         _ => {
             let join_id = JoinPointId(environment.create_symbol(ident_ids, "uniqueness_join"));
 
-            let jump = arena.alloc(Stmt::Jump(join_id, arena.alloc([])));
+            let jump = arena.alloc(Stmt::Jump(join_id, arena.alloc([]), Region::zero()));
 
             let u = unique(layout_interner, ident_ids, jump);
             let n = not_unique(layout_interner, ident_ids, jump);
@@ -1323,6 +1329,7 @@ where
                         n,
                     ),
                     ret_layout: environment.layout,
+                    region: Region::zero(),
                 })
             };
 
@@ -1333,6 +1340,7 @@ where
                 parameters: arena.alloc([]),
                 body: continutation,
                 remainder: unique,
+                region: Region::zero(),
             })
         }
     }
@@ -1545,8 +1553,11 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         StrTrimEnd => RC::Rc,
         StrSplit => RC::NoRc,
         StrToNum => RC::NoRc,
+        StrToIntRadix => RC::NoRc,
         ListPrepend => RC::Rc,
         StrJoinWith => RC::NoRc,
+        StrToUpper | StrToLower | StrCaseInsensitiveEq | StrToNfc | StrToNfd | StrToUtf16
+        | StrFromUtf16Lossy | StrCompare => RC::NoRc,
         ListMap | ListMap2 | ListMap3 | ListMap4 | ListSortWith => RC::Rc,
 
         ListAppendUnsafe
@@ -1557,7 +1568,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         | ListReleaseExcessCapacity
         | StrReleaseExcessCapacity => RC::Rc,
 
-        Eq | NotEq => RC::NoRc,
+        Eq | NotEq | Compare => RC::NoRc,
 
         And | Or | NumAdd | NumAddWrap | NumAddChecked | NumAddSaturated | NumSub | NumSubWrap
         | NumSubChecked | NumSubSaturated | NumMul | NumMulWrap | NumMulSaturated
@@ -1567,6 +1578,9 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         | NumShiftRightBy | NumShiftRightZfBy => RC::NoRc,
 
         NumToStr
+        | NumToStrRadix
+        | NumToStrDecimals
+        | NumToStrScientific
         | NumAbs
         | NumNeg
         | NumSin
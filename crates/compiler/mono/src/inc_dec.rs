@@ -155,6 +155,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                 branches,
                 default_branch,
                 ret_layout: _,
+                region: _,
             } => {
                 // Collect the types of the symbols in all the branches, including the default one.
                 for (info, stmt) in branches
@@ -174,7 +175,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                     self.insert_symbols_rc_type_stmt(stmt);
                 }
             }
-            Stmt::Ret(_symbol) => {
+            Stmt::Ret(_symbol, _) => {
                 // The return does not introduce new symbols.
             }
             Stmt::Refcounting(_, _) => unreachable!(
@@ -190,6 +191,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                 parameters,
                 body,
                 remainder: continuation,
+                region: _,
             } => {
                 for parameter in parameters.iter() {
                     self.insert_symbol_layout_rc_type(&parameter.symbol, &parameter.layout);
@@ -198,7 +200,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                 self.insert_symbols_rc_type_stmt(body);
                 self.insert_symbols_rc_type_stmt(continuation);
             }
-            Stmt::Jump(_, _) => {
+            Stmt::Jump(_, _, _) => {
                 // A join point does not introduce new symbols.
             }
             Stmt::Crash(_, _) => {
@@ -513,6 +515,7 @@ fn insert_refcount_operations_stmt<'v, 'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let new_branches = branches
                 .iter()
@@ -632,12 +635,13 @@ fn insert_refcount_operations_stmt<'v, 'a>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
-        Stmt::Ret(s) => {
+        Stmt::Ret(s, region) => {
             let ownership = environment.consume_symbol(s);
             debug_assert!(matches!(ownership, None | Some(Ownership::Owned))); // the return value should be owned or not reference counted at the return.
-            return arena.alloc(Stmt::Ret(*s));
+            return arena.alloc(Stmt::Ret(*s, *region));
         }
         Stmt::Refcounting(_, _) => unreachable!("refcounting should not be in the AST yet"),
         Stmt::Expect {
@@ -713,6 +717,7 @@ fn insert_refcount_operations_stmt<'v, 'a>(
             parameters,
             body,
             remainder,
+            region,
         } => {
             // Assuming that the values in the closure of the body of this jointpoint are already bound.
             // Assuming that all symbols are still owned. (So that we can determine what symbols got consumed in the join point.)
@@ -815,15 +820,16 @@ fn insert_refcount_operations_stmt<'v, 'a>(
                 parameters,
                 body: newer_body,
                 remainder: new_remainder,
+                region: *region,
             })
         }
-        Stmt::Jump(joinpoint_id, arguments) => {
+        Stmt::Jump(joinpoint_id, arguments, region) => {
             let consumed_symbols = environment.get_joinpoint_consumption(*joinpoint_id);
             for consumed_symbol in consumed_symbols.clone().iter() {
                 environment.consume_symbol(consumed_symbol);
             }
 
-            let new_jump = arena.alloc(Stmt::Jump(*joinpoint_id, arguments));
+            let new_jump = arena.alloc(Stmt::Jump(*joinpoint_id, arguments, *region));
 
             // Note that this should only insert increments if a later join point has a current parameter as consumed closure.
             consume_and_insert_inc_stmts(
@@ -1297,6 +1303,7 @@ fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[Ownership] {
         StrTrimEnd => arena.alloc_slice_copy(&[owned]),
         StrSplit => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrToNum => arena.alloc_slice_copy(&[borrowed]),
+        StrToIntRadix => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         ListPrepend => arena.alloc_slice_copy(&[owned, owned]),
         StrJoinWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
         ListMap => arena.alloc_slice_copy(&[owned, function, closure_data]),
@@ -1312,15 +1319,23 @@ fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[Ownership] {
         ListSwap => arena.alloc_slice_copy(&[owned, irrelevant, irrelevant]),
         ListReleaseExcessCapacity => arena.alloc_slice_copy(&[owned]),
         StrReleaseExcessCapacity => arena.alloc_slice_copy(&[owned]),
+        StrToUpper | StrToLower | StrToNfc | StrToNfd | StrToUtf16 | StrFromUtf16Lossy => {
+            arena.alloc_slice_copy(&[borrowed])
+        }
+        StrCaseInsensitiveEq | StrCompare => arena.alloc_slice_copy(&[borrowed, borrowed]),
 
-        Eq | NotEq => arena.alloc_slice_copy(&[borrowed, borrowed]),
+        Eq | NotEq | Compare => arena.alloc_slice_copy(&[borrowed, borrowed]),
 
         And | Or | NumAdd | NumAddWrap | NumAddChecked | NumAddSaturated | NumSub | NumSubWrap
         | NumSubChecked | NumSubSaturated | NumMul | NumMulWrap | NumMulSaturated
         | NumMulChecked | NumGt | NumGte | NumLt | NumLte | NumCompare | NumDivFrac
         | NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked | NumIsMultipleOf
         | NumPow | NumPowInt | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy
-        | NumShiftRightBy | NumShiftRightZfBy => arena.alloc_slice_copy(&[irrelevant, irrelevant]),
+        | NumShiftRightBy | NumShiftRightZfBy | NumToStrDecimals | NumToStrScientific => {
+            arena.alloc_slice_copy(&[irrelevant, irrelevant])
+        }
+
+        NumToStrRadix => arena.alloc_slice_copy(&[irrelevant, irrelevant, irrelevant]),
 
         NumToStr
         | NumAbs
@@ -206,8 +206,11 @@ impl<'a> PartialProcs<'a> {
 pub struct PartialProc<'a> {
     pub annotation: Variable,
     pub pattern_symbols: &'a [Symbol],
+    /// The regions of the argument patterns, for debug info
+    pub pattern_regions: &'a [Region],
     pub captured_symbols: CapturedSymbols<'a>,
     pub body: roc_can::expr::Expr,
+    pub body_region: Region,
     pub body_var: Variable,
     pub is_self_recursive: bool,
 }
@@ -226,7 +229,7 @@ impl<'a> PartialProc<'a> {
         let number_of_arguments = loc_args.len();
 
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
-            Ok((_, pattern_symbols, body)) => {
+            Ok((_, pattern_symbols, pattern_regions, body)) => {
                 // a named closure. Since these aren't specialized by the surrounding
                 // context, we can't add pending specializations for them yet.
                 // (If we did, all named polymorphic functions would immediately error
@@ -235,8 +238,10 @@ impl<'a> PartialProc<'a> {
                 PartialProc {
                     annotation,
                     pattern_symbols,
+                    pattern_regions: pattern_regions.into_bump_slice(),
                     captured_symbols,
                     body: body.value,
+                    body_region: body.region,
                     body_var: ret_var,
                     is_self_recursive,
                 }
@@ -252,8 +257,10 @@ impl<'a> PartialProc<'a> {
                 PartialProc {
                     annotation,
                     pattern_symbols: pattern_symbols.into_bump_slice(),
+                    pattern_regions: &[],
                     captured_symbols: CapturedSymbols::None,
                     body: roc_can::expr::Expr::RuntimeError(error.value),
+                    body_region: error.region,
                     body_var: ret_var,
                     is_self_recursive: false,
                 }
@@ -308,6 +315,44 @@ pub struct Proc<'a> {
     pub ret_layout: InLayout<'a>,
     pub is_self_recursive: SelfRecursive,
     pub is_erased: bool,
+    pub regions: ProcRegions<'a>,
+}
+
+/// Where a [Proc] and the named symbols it binds were defined in the source.
+/// Used by the backends to emit debug info; procs that the compiler generates
+/// (e.g. refcounting and equality helpers) use the default, which has no regions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcRegions<'a> {
+    /// The region of the body this proc was specialized from.
+    pub body: Region,
+    /// The regions of the argument patterns, in the order of the arguments. The closure
+    /// argument that the compiler adds has no region.
+    pub args: &'a [Region],
+    /// The regions of the defs that bound named symbols in the body, sorted by symbol.
+    pub symbols: &'a [(Symbol, Region)],
+    /// The regions of the calls whose results the body binds, sorted by symbol.
+    pub calls: &'a [(Symbol, Region)],
+}
+
+impl<'a> ProcRegions<'a> {
+    pub fn arg_region(&self, index: usize) -> Option<Region> {
+        self.args.get(index).copied()
+    }
+
+    pub fn symbol_region(&self, symbol: Symbol) -> Option<Region> {
+        lookup_region(self.symbols, symbol)
+    }
+
+    pub fn call_region(&self, symbol: Symbol) -> Option<Region> {
+        lookup_region(self.calls, symbol)
+    }
+}
+
+fn lookup_region(regions: &[(Symbol, Region)], symbol: Symbol) -> Option<Region> {
+    regions
+        .binary_search_by_key(&symbol, |(s, _)| *s)
+        .ok()
+        .map(|index| regions[index].1)
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -909,6 +954,10 @@ pub struct Procs<'a> {
     pub externals_we_need: BumpMap<ModuleId, ExternalSpecializations<'a>>,
     symbol_specializations: SymbolSpecializations<'a>,
     specialization_stack: SpecializationStack<'a>,
+    /// The regions of the defs that bound named symbols, for debug info
+    symbol_regions: BumpMap<Symbol, Region>,
+    /// The regions of the calls whose results were bound to a symbol, for debug info
+    call_regions: BumpMap<Symbol, Region>,
 
     pub imported_module_thunks: &'a [Symbol],
     pub module_thunks: &'a [Symbol],
@@ -927,6 +976,8 @@ impl<'a> Procs<'a> {
             host_exposed_lambda_sets: std::vec::Vec::new(),
             symbol_specializations: Default::default(),
             specialization_stack: SpecializationStack(Vec::with_capacity_in(16, arena)),
+            symbol_regions: BumpMap::new_in(arena),
+            call_regions: BumpMap::new_in(arena),
 
             imported_module_thunks: &[],
             module_thunks: &[],
@@ -1050,7 +1101,7 @@ impl<'a> Procs<'a> {
         };
 
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
-            Ok((_, pattern_symbols, body)) => {
+            Ok((_, pattern_symbols, pattern_regions, body)) => {
                 // an anonymous closure. These will always be specialized already
                 // by the surrounding context, so we can add pending specializations
                 // for them immediately.
@@ -1097,8 +1148,10 @@ impl<'a> Procs<'a> {
                                     let partial_proc = PartialProc {
                                         annotation,
                                         pattern_symbols,
+                                        pattern_regions: pattern_regions.into_bump_slice(),
                                         captured_symbols,
                                         body: body.value,
+                                        body_region: body.region,
                                         body_var: ret_var,
                                         is_self_recursive,
                                     };
@@ -1125,8 +1178,10 @@ impl<'a> Procs<'a> {
                                 let partial_proc = PartialProc {
                                     annotation,
                                     pattern_symbols,
+                                    pattern_regions: pattern_regions.into_bump_slice(),
                                     captured_symbols,
                                     body: body.value,
+                                    body_region: body.region,
                                     body_var: ret_var,
                                     is_self_recursive,
                                 };
@@ -1479,6 +1534,7 @@ pub fn cond<'a>(
     pass: Stmt<'a>,
     fail: Stmt<'a>,
     ret_layout: InLayout<'a>,
+    region: Region,
 ) -> Stmt<'a> {
     let branches = env.arena.alloc([(1u64, BranchInfo::None, pass)]);
     let default_branch = (BranchInfo::None, &*env.arena.alloc(fail));
@@ -1489,6 +1545,7 @@ pub fn cond<'a>(
         ret_layout,
         branches,
         default_branch,
+        region,
     }
 }
 
@@ -1511,8 +1568,12 @@ pub enum Stmt<'a> {
         default_branch: (BranchInfo<'a>, &'a Stmt<'a>),
         /// Each branch must return a value of this type.
         ret_layout: InLayout<'a>,
+        /// The condition of the `when` or `if` this switch was lowered from, for debug info
+        region: Region,
     },
-    Ret(Symbol),
+    /// Return a value. The region is that of the returned expression, for debug info.
+    /// Statements that the compiler generates use `Region::zero()`.
+    Ret(Symbol, Region),
     Refcounting(ModifyRc, &'a Stmt<'a>),
     Expect {
         condition: Symbol,
@@ -1547,8 +1608,11 @@ pub enum Stmt<'a> {
         body: &'a Stmt<'a>,
         /// what happens after _defining_ the join point
         remainder: &'a Stmt<'a>,
+        /// The `when` or `if` whose branches join here, for debug info
+        region: Region,
     },
-    Jump(JoinPointId, &'a [Symbol]),
+    /// Jump to a join point. The region is that of the branch we jump from, for debug info
+    Jump(JoinPointId, &'a [Symbol], Region),
     Crash(Symbol, CrashTag),
 }
 
@@ -2208,11 +2272,12 @@ impl<'a> Stmt<'a> {
     pub fn new(
         env: &mut Env<'a, '_>,
         can_expr: roc_can::expr::Expr,
+        region: Region,
         var: Variable,
         procs: &mut Procs<'a>,
         layout_cache: &mut LayoutCache<'a>,
     ) -> Self {
-        from_can(env, var, can_expr, procs, layout_cache)
+        from_can(env, var, can_expr, region, procs, layout_cache)
     }
 
     pub fn to_doc<'b, D, A, I>(
@@ -2277,7 +2342,7 @@ impl<'a> Stmt<'a> {
                 .append(alloc.hardline())
                 .append(remainder.to_doc(alloc, interner, pretty)),
 
-            Ret(symbol) => alloc
+            Ret(symbol, _) => alloc
                 .text("ret ")
                 .append(symbol_to_doc(alloc, *symbol, pretty))
                 .append(";"),
@@ -2345,6 +2410,7 @@ impl<'a> Stmt<'a> {
                 parameters,
                 body: continuation,
                 remainder,
+                ..
             } => {
                 let it = parameters
                     .iter()
@@ -2365,7 +2431,7 @@ impl<'a> Stmt<'a> {
                     alloc.hardline(),
                 )
             }
-            Jump(id, arguments) => {
+            Jump(id, arguments, _) => {
                 let it = arguments.iter().map(|s| symbol_to_doc(alloc, *s, pretty));
 
                 alloc
@@ -2419,6 +2485,7 @@ impl<'a> Stmt<'a> {
             branches: &*arena.alloc([then_branch]),
             default_branch: else_branch,
             ret_layout: return_layout,
+            region: Region::zero(),
         }
     }
 }
@@ -2434,13 +2501,15 @@ fn from_can_let<'a>(
 ) -> Stmt<'a> {
     use roc_can::expr::Expr::*;
 
+    let cont_region = cont.region;
+
     macro_rules! lower_rest {
         ($variable:expr, $expr:expr) => {
             lower_rest!(env, procs, layout_cache, $variable, $expr)
         };
         ($env:expr, $procs:expr, $layout_cache:expr, $variable:expr, $expr:expr) => {
             match opt_assigned_and_hole {
-                None => from_can($env, $variable, $expr, $procs, $layout_cache),
+                None => from_can($env, $variable, $expr, cont_region, $procs, $layout_cache),
                 Some((assigned, hole)) => with_hole(
                     $env,
                     $expr,
@@ -2454,6 +2523,10 @@ fn from_can_let<'a>(
         };
     }
 
+    for symbol in def.pattern_vars.keys() {
+        procs.symbol_regions.insert(*symbol, def.loc_pattern.region);
+    }
+
     if let roc_can::pattern::Pattern::Identifier(symbol) = &def.loc_pattern.value {
         return match def.loc_expr.value {
             Closure(closure_data) => {
@@ -2786,9 +2859,18 @@ fn patterns_to_when<'a>(
     patterns: std::vec::Vec<(Variable, AnnotatedMark, Loc<roc_can::pattern::Pattern>)>,
     body_var: Variable,
     body: Loc<roc_can::expr::Expr>,
-) -> Result<(Vec<'a, Variable>, Vec<'a, Symbol>, Loc<roc_can::expr::Expr>), Loc<RuntimeError>> {
+) -> Result<
+    (
+        Vec<'a, Variable>,
+        Vec<'a, Symbol>,
+        Vec<'a, Region>,
+        Loc<roc_can::expr::Expr>,
+    ),
+    Loc<RuntimeError>,
+> {
     let mut arg_vars = Vec::with_capacity_in(patterns.len(), env.arena);
     let mut symbols = Vec::with_capacity_in(patterns.len(), env.arena);
+    let mut regions = Vec::with_capacity_in(patterns.len(), env.arena);
     let mut body = Ok(body);
 
     // patterns that are not yet in a when (e.g. in let or function arguments) must be irrefutable
@@ -2811,10 +2893,12 @@ fn patterns_to_when<'a>(
                 })
             });
         } else if let Ok(unwrapped_body) = body {
+            let pattern_region = pattern.region;
             let (new_symbol, new_body) =
                 pattern_to_when(env, pattern_var, pattern, body_var, unwrapped_body);
 
             symbols.push(new_symbol);
+            regions.push(pattern_region);
             arg_vars.push(pattern_var);
 
             body = Ok(new_body)
@@ -2822,7 +2906,7 @@ fn patterns_to_when<'a>(
     }
 
     match body {
-        Ok(body) => Ok((arg_vars, symbols, body)),
+        Ok(body) => Ok((arg_vars, symbols, regions, body)),
         Err(loc_error) => Err(loc_error),
     }
 }
@@ -3312,6 +3396,7 @@ fn generate_runtime_error_function<'a>(
         ret_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased,
+        regions: ProcRegions::default(),
     }
 }
 
@@ -3395,7 +3480,7 @@ fn generate_host_exposed_function<'a>(
         }
         RawFunctionLayout::ZeroArgumentThunk(result) => {
             let assigned = env.unique_symbol();
-            let hole = env.arena.alloc(Stmt::Ret(assigned));
+            let hole = env.arena.alloc(Stmt::Ret(assigned, Region::zero()));
             let forced = force_thunk(env, function_name, result, assigned, hole);
 
             let lambda_name = LambdaName::no_niche(function_name);
@@ -3407,6 +3492,7 @@ fn generate_host_exposed_function<'a>(
                 ret_layout: result,
                 is_self_recursive: SelfRecursive::NotSelfRecursive,
                 is_erased: false,
+                regions: ProcRegions::default(),
             };
 
             let top_level = ProcLayout::from_raw_named(env.arena, lambda_name, layout);
@@ -3448,7 +3534,7 @@ fn generate_host_exposed_lambda_set<'a>(
     // this should also be reflected in the TopLevel signature
     top_level_arguments.push(lambda_set_layout);
 
-    let hole = env.arena.alloc(Stmt::Ret(assigned));
+    let hole = env.arena.alloc(Stmt::Ret(assigned, Region::zero()));
 
     let body = match_on_lambda_set(
         env,
@@ -3471,6 +3557,7 @@ fn generate_host_exposed_lambda_set<'a>(
         ret_layout: return_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased: false,
+        regions: ProcRegions::default(),
     };
 
     let top_level = ProcLayout::new(
@@ -3531,9 +3618,11 @@ fn specialize_proc_help<'a>(
     };
 
     let body = partial_proc.body.clone();
+    let body_region = partial_proc.body_region;
+    let pattern_regions = partial_proc.pattern_regions;
     let body_var = partial_proc.body_var;
 
-    let mut specialized_body = from_can(env, body_var, body, procs, layout_cache);
+    let mut specialized_body = from_can(env, body_var, body, body_region, procs, layout_cache);
 
     let specialized_proc = match specialized {
         SpecializedLayout::FunctionPointerBody {
@@ -3557,6 +3646,13 @@ fn specialize_proc_help<'a>(
             // I'm not sure how to handle the closure case, does it ever occur?
             debug_assert!(matches!(captured_symbols, CapturedSymbols::None));
 
+            let regions = ProcRegions {
+                body: body_region,
+                args: &[],
+                symbols: let_symbol_regions(env.arena, &procs.symbol_regions, &specialized_body),
+                calls: let_symbol_regions(env.arena, &procs.call_regions, &specialized_body),
+            };
+
             Proc {
                 name: lambda_name,
                 args: &[],
@@ -3565,6 +3661,7 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                regions,
             }
         }
         SpecializedLayout::FunctionBody {
@@ -3772,6 +3869,13 @@ fn specialize_proc_help<'a>(
 
             let closure_data_layout = opt_closure_layout.map(|clos| clos.data_layout());
 
+            let regions = ProcRegions {
+                body: body_region,
+                args: pattern_regions,
+                symbols: let_symbol_regions(env.arena, &procs.symbol_regions, &specialized_body),
+                calls: let_symbol_regions(env.arena, &procs.call_regions, &specialized_body),
+            };
+
             Proc {
                 name: lambda_name,
                 args: proc_args.into_bump_slice(),
@@ -3780,6 +3884,7 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                regions,
             }
         }
     };
@@ -3787,6 +3892,58 @@ fn specialize_proc_help<'a>(
     Ok(specialized_proc)
 }
 
+/// Finds the regions of the symbols that a proc body binds with a `Let`.
+fn let_symbol_regions<'a>(
+    arena: &'a Bump,
+    symbol_regions: &BumpMap<Symbol, Region>,
+    body: &Stmt<'a>,
+) -> &'a [(Symbol, Region)] {
+    let mut regions = Vec::new_in(arena);
+    let mut stack = std::vec![body];
+
+    while let Some(stmt) = stack.pop() {
+        match stmt {
+            Stmt::Let(symbol, _, _, cont) => {
+                if let Some(region) = symbol_regions.get(symbol) {
+                    regions.push((*symbol, *region));
+                }
+
+                stack.push(cont);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Stmt::Refcounting(_, cont)
+            | Stmt::Expect {
+                remainder: cont, ..
+            }
+            | Stmt::ExpectFx {
+                remainder: cont, ..
+            }
+            | Stmt::Dbg {
+                remainder: cont, ..
+            } => stack.push(cont),
+            Stmt::Ret(..) | Stmt::Jump(..) | Stmt::Crash(..) => {}
+        }
+    }
+
+    regions.sort_unstable_by_key(|(symbol, _)| *symbol);
+    regions.dedup_by_key(|(symbol, _)| *symbol);
+
+    regions.into_bump_slice()
+}
+
 #[derive(Debug)]
 enum SpecializedLayout<'a> {
     /// A body like `foo = \a,b,c -> ...`
@@ -4641,7 +4798,7 @@ pub fn with_hole<'a>(
                 (Ok(ret_layout), Ok(cond_layout)) => {
                     // if the hole is a return, then we don't need to merge the two
                     // branches together again, we can just immediately return
-                    let is_terminated = matches!(hole, Stmt::Ret(_));
+                    let is_terminated = matches!(hole, Stmt::Ret(..));
 
                     if is_terminated {
                         let terminator = hole;
@@ -4669,7 +4826,15 @@ pub fn with_hole<'a>(
                                 terminator,
                             );
 
-                            stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
+                            stmt = cond(
+                                env,
+                                branching_symbol,
+                                cond_layout,
+                                then,
+                                stmt,
+                                ret_layout,
+                                loc_cond.region,
+                            );
 
                             // add condition
                             stmt = with_hole(
@@ -4686,10 +4851,17 @@ pub fn with_hole<'a>(
                     } else {
                         let assigned_in_jump = env.unique_symbol();
                         let id = JoinPointId(env.unique_symbol());
+                        let if_region = match branches.first() {
+                            Some((loc_cond, _)) => {
+                                Region::span_across(&loc_cond.region, &final_else.region)
+                            }
+                            None => final_else.region,
+                        };
 
-                        let terminator = env
-                            .arena
-                            .alloc(Stmt::Jump(id, env.arena.alloc([assigned_in_jump])));
+                        let arena = env.arena;
+                        let terminator = |region: Region| -> &'a Stmt<'a> {
+                            arena.alloc(Stmt::Jump(id, arena.alloc([assigned_in_jump]), region))
+                        };
 
                         let mut stmt = with_hole(
                             env,
@@ -4698,7 +4870,7 @@ pub fn with_hole<'a>(
                             procs,
                             layout_cache,
                             assigned_in_jump,
-                            terminator,
+                            terminator(final_else.region),
                         );
 
                         for (loc_cond, loc_then) in branches.into_iter().rev() {
@@ -4717,10 +4889,18 @@ pub fn with_hole<'a>(
                                 procs,
                                 layout_cache,
                                 assigned_in_jump,
-                                terminator,
+                                terminator(loc_then.region),
                             );
 
-                            stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
+                            stmt = cond(
+                                env,
+                                branching_symbol,
+                                cond_layout,
+                                then,
+                                stmt,
+                                ret_layout,
+                                loc_cond.region,
+                            );
 
                             // add condition
                             stmt = assign_to_symbol(
@@ -4750,6 +4930,7 @@ pub fn with_hole<'a>(
                             parameters: env.arena.alloc([param]),
                             remainder: env.arena.alloc(stmt),
                             body: hole,
+                            region: if_region,
                         }
                     }
                 }
@@ -4761,7 +4942,7 @@ pub fn with_hole<'a>(
         When {
             cond_var,
             expr_var,
-            region: _,
+            region,
             loc_cond,
             branches,
             branches_cond_var: _,
@@ -4782,6 +4963,7 @@ pub fn with_hole<'a>(
                 cond_var,
                 expr_var,
                 cond_symbol,
+                loc_cond.region,
                 branches,
                 exhaustive,
                 layout_cache,
@@ -4814,6 +4996,7 @@ pub fn with_hole<'a>(
                 parameters: env.arena.alloc([param]),
                 remainder: env.arena.alloc(stmt),
                 body: env.arena.alloc(hole),
+                region,
             }
         }
 
@@ -5404,6 +5587,8 @@ pub fn with_hole<'a>(
         Call(boxed, loc_args, _) => {
             let (fn_var, loc_expr, _lambda_set_var, _ret_var) = *boxed;
 
+            procs.call_regions.insert(assigned, loc_expr.region);
+
             // even if a call looks like it's by name, it may in fact be by-pointer.
             // E.g. in `(\f, x -> f x)` the call is in fact by pointer.
             // So we check the function name against the list of partial procedures,
@@ -6904,6 +7089,7 @@ pub fn from_can<'a>(
     env: &mut Env<'a, '_>,
     variable: Variable,
     can_expr: roc_can::expr::Expr,
+    region: Region,
     procs: &mut Procs<'a>,
     layout_cache: &mut LayoutCache<'a>,
 ) -> Stmt<'a> {
@@ -6932,6 +7118,7 @@ pub fn from_can<'a>(
                 cond_var,
                 expr_var,
                 cond_symbol,
+                loc_cond.region,
                 branches,
                 exhaustive,
                 layout_cache,
@@ -6967,7 +7154,14 @@ pub fn from_can<'a>(
                 "invalid condition type in if expression"
             );
 
-            let mut stmt = from_can(env, branch_var, final_else.value, procs, layout_cache);
+            let mut stmt = from_can(
+                env,
+                branch_var,
+                final_else.value,
+                final_else.region,
+                procs,
+                layout_cache,
+            );
 
             for (loc_cond, loc_then) in branches.into_iter().rev() {
                 let branching_symbol = possible_reuse_symbol_or_specialize(
@@ -6977,9 +7171,24 @@ pub fn from_can<'a>(
                     &loc_cond.value,
                     cond_var,
                 );
-                let then = from_can(env, branch_var, loc_then.value, procs, layout_cache);
+                let then = from_can(
+                    env,
+                    branch_var,
+                    loc_then.value,
+                    loc_then.region,
+                    procs,
+                    layout_cache,
+                );
 
-                stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
+                stmt = cond(
+                    env,
+                    branching_symbol,
+                    cond_layout,
+                    then,
+                    stmt,
+                    ret_layout,
+                    loc_cond.region,
+                );
 
                 stmt = assign_to_symbol(
                     env,
@@ -7000,7 +7209,14 @@ pub fn from_can<'a>(
             loc_continuation,
             lookups_in_cond,
         } => {
-            let rest = from_can(env, variable, loc_continuation.value, procs, layout_cache);
+            let rest = from_can(
+                env,
+                variable,
+                loc_continuation.value,
+                loc_continuation.region,
+                procs,
+                layout_cache,
+            );
             let cond_symbol = env.unique_symbol();
 
             let mut lookups = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
@@ -7069,7 +7285,14 @@ pub fn from_can<'a>(
             loc_continuation,
             lookups_in_cond,
         } => {
-            let rest = from_can(env, variable, loc_continuation.value, procs, layout_cache);
+            let rest = from_can(
+                env,
+                variable,
+                loc_continuation.value,
+                loc_continuation.region,
+                procs,
+                layout_cache,
+            );
             let cond_symbol = env.unique_symbol();
 
             let mut lookups = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
@@ -7137,7 +7360,14 @@ pub fn from_can<'a>(
             variable: cond_variable,
             symbol: dbg_symbol,
         } => {
-            let rest = from_can(env, variable, loc_continuation.value, procs, layout_cache);
+            let rest = from_can(
+                env,
+                variable,
+                loc_continuation.value,
+                loc_continuation.region,
+                procs,
+                layout_cache,
+            );
 
             compile_dbg(
                 env,
@@ -7174,12 +7404,12 @@ pub fn from_can<'a>(
                 unreachable!("recursive value does not have Identifier pattern")
             }
 
-            from_can(env, variable, cont.value, procs, layout_cache)
+            from_can(env, variable, cont.value, cont.region, procs, layout_cache)
         }
         LetNonRec(def, cont) => from_can_let(env, procs, layout_cache, def, cont, variable, None),
         _ => {
             let symbol = env.unique_symbol();
-            let hole = env.arena.alloc(Stmt::Ret(symbol));
+            let hole = env.arena.alloc(Stmt::Ret(symbol, region));
             with_hole(env, can_expr, variable, procs, layout_cache, symbol, hole)
         }
     }
@@ -7209,7 +7439,7 @@ fn to_opt_branches<'a>(
 ) -> std::vec::Vec<(
     Pattern<'a>,
     Option<Loc<roc_can::expr::Expr>>,
-    roc_can::expr::Expr,
+    Loc<roc_can::expr::Expr>,
 )> {
     debug_assert!(!branches.is_empty());
 
@@ -7253,15 +7483,21 @@ fn to_opt_branches<'a>(
                         ))
                     };
 
+                    // the bindings are placed at the pattern, but the branch is still its body
+                    let loc_expr = Loc::at(when_branch.value.region, loc_expr.value);
+
                     // TODO remove clone?
-                    opt_branches.push((mono_pattern, when_branch.guard.clone(), loc_expr.value));
+                    opt_branches.push((mono_pattern, when_branch.guard.clone(), loc_expr));
                 }
                 Err(runtime_error) => {
                     // TODO remove clone?
                     opt_branches.push((
                         Pattern::Underscore,
                         when_branch.guard.clone(),
-                        roc_can::expr::Expr::RuntimeError(runtime_error),
+                        Loc::at(
+                            when_branch.value.region,
+                            roc_can::expr::Expr::RuntimeError(runtime_error),
+                        ),
                     ));
                 }
             }
@@ -7274,7 +7510,9 @@ fn to_opt_branches<'a>(
         opt_branches.push((
             Pattern::Underscore,
             None,
-            roc_can::expr::Expr::RuntimeError(roc_problem::can::RuntimeError::NonExhaustivePattern),
+            Loc::at_zero(roc_can::expr::Expr::RuntimeError(
+                roc_problem::can::RuntimeError::NonExhaustivePattern,
+            )),
         ));
     }
 
@@ -7287,6 +7525,7 @@ fn from_can_when<'a>(
     cond_var: Variable,
     expr_var: Variable,
     cond_symbol: Symbol,
+    cond_region: Region,
     branches: std::vec::Vec<roc_can::expr::WhenBranch>,
     exhaustive_mark: ExhaustiveMark,
     layout_cache: &mut LayoutCache<'a>,
@@ -7315,7 +7554,7 @@ fn from_can_when<'a>(
     let arena = env.arena;
     let it = opt_branches
         .into_iter()
-        .filter_map(|(pattern, opt_guard, can_expr)| {
+        .filter_map(|(pattern, opt_guard, loc_expr)| {
             // If the pattern has a void layout we can drop it; however, we must still perform the
             // work of building the body, because that may contain specializations we must
             // discover for use elsewhere. See
@@ -7332,12 +7571,17 @@ fn from_can_when<'a>(
                 None
             };
 
+            let Loc {
+                region,
+                value: can_expr,
+            } = loc_expr;
+
             let branch_stmt = match join_point {
-                None => from_can(env, expr_var, can_expr, procs, layout_cache),
+                None => from_can(env, expr_var, can_expr, region, procs, layout_cache),
                 Some(id) => {
                     let symbol = env.unique_symbol();
                     let arguments = bumpalo::vec![in env.arena; symbol].into_bump_slice();
-                    let jump = env.arena.alloc(Stmt::Jump(id, arguments));
+                    let jump = env.arena.alloc(Stmt::Jump(id, arguments, region));
 
                     with_hole(env, can_expr, expr_var, procs, layout_cache, symbol, jump)
                 }
@@ -7347,6 +7591,7 @@ fn from_can_when<'a>(
             let result = if let Some(loc_expr) = opt_guard {
                 let guard_spec = GuardStmtSpec {
                     guard_expr: loc_expr.value,
+                    guard_region: loc_expr.region,
                     identity: env.next_call_specialization_id(),
                 };
 
@@ -7378,6 +7623,7 @@ fn from_can_when<'a>(
         cond_symbol,
         cond_layout,
         ret_layout,
+        cond_region,
         mono_branches,
     )
 }
@@ -7393,6 +7639,7 @@ fn from_can_when<'a>(
 #[derive(Debug, Clone)]
 pub(crate) struct GuardStmtSpec {
     guard_expr: roc_can::expr::Expr,
+    guard_region: Region,
 
     /// Unique id to indentity identical guard statements, even across clones.
     /// Needed so that we can implement [PartialEq] on this type. Re-uses call specialization IDs,
@@ -7430,14 +7677,17 @@ impl GuardStmtSpec {
     ) -> CompiledGuardStmt<'a> {
         let Self {
             guard_expr,
+            guard_region,
             identity: _,
         } = self;
 
         let join_point_id = JoinPointId(env.unique_symbol());
         let symbol = env.unique_symbol();
-        let jump = env
-            .arena
-            .alloc(Stmt::Jump(join_point_id, env.arena.alloc([symbol])));
+        let jump = env.arena.alloc(Stmt::Jump(
+            join_point_id,
+            env.arena.alloc([symbol]),
+            guard_region,
+        ));
 
         let stmt = with_hole(
             env,
@@ -7520,6 +7770,7 @@ fn substitute_in_stmt_help<'a>(
             parameters,
             remainder,
             body: continuation,
+            region,
         } => {
             let opt_remainder = substitute_in_stmt_help(arena, remainder, subs);
             let opt_continuation = substitute_in_stmt_help(arena, continuation, subs);
@@ -7533,6 +7784,7 @@ fn substitute_in_stmt_help<'a>(
                     parameters,
                     remainder,
                     body: continuation,
+                    region: *region,
                 }))
             } else {
                 None
@@ -7544,6 +7796,7 @@ fn substitute_in_stmt_help<'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let mut did_change = false;
 
@@ -7598,13 +7851,14 @@ fn substitute_in_stmt_help<'a>(
                     default_branch,
                     branches,
                     ret_layout: *ret_layout,
+                    region: *region,
                 }))
             } else {
                 None
             }
         }
-        Ret(s) => match substitute(subs, *s) {
-            Some(s) => Some(arena.alloc(Ret(s))),
+        Ret(s, region) => match substitute(subs, *s) {
+            Some(s) => Some(arena.alloc(Ret(s, *region))),
             None => None,
         },
         Refcounting(modify, cont) => {
@@ -7684,7 +7938,7 @@ fn substitute_in_stmt_help<'a>(
             Some(arena.alloc(expect))
         }
 
-        Jump(id, args) => {
+        Jump(id, args, region) => {
             let mut did_change = false;
             let new_args = Vec::from_iter_in(
                 args.iter().map(|s| match substitute(subs, *s) {
@@ -7700,7 +7954,7 @@ fn substitute_in_stmt_help<'a>(
             if did_change {
                 let args = new_args.into_bump_slice();
 
-                Some(arena.alloc(Jump(*id, args)))
+                Some(arena.alloc(Jump(*id, args, *region)))
             } else {
                 None
             }
@@ -9449,7 +9703,7 @@ where
     for (i, lambda_name) in lambda_set.into_iter().enumerate() {
         let assigned = env.unique_symbol();
 
-        let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]));
+        let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]), Region::zero());
 
         let call_spec_id = env.next_call_specialization_id();
         let update_mode = env.next_update_mode_id();
@@ -9477,6 +9731,7 @@ where
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     };
 
     let param = Param {
@@ -9489,6 +9744,7 @@ where
         parameters: &*env.arena.alloc([param]),
         body: hole,
         remainder: env.arena.alloc(switch),
+        region: Region::zero(),
     }
 }
 
@@ -9660,7 +9916,7 @@ fn union_lambda_set_to_switch<'a>(
     }
 
     let (opt_join, branch_assigned, branch_hole) = match hole {
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // No need to jump to a joinpoint, inline the return in each statement as-is.
             // This makes further analyses, like TCO, easier as well.
             (None, assigned, hole)
@@ -9668,7 +9924,7 @@ fn union_lambda_set_to_switch<'a>(
         _ => {
             let join_point_id = JoinPointId(env.unique_symbol());
             let assigned = env.unique_symbol();
-            let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]));
+            let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]), Region::zero());
 
             (Some(join_point_id), assigned, &*env.arena.alloc(hole))
         }
@@ -9711,6 +9967,7 @@ fn union_lambda_set_to_switch<'a>(
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     };
 
     match opt_join {
@@ -9726,6 +9983,7 @@ fn union_lambda_set_to_switch<'a>(
                 parameters: &*env.arena.alloc([param]),
                 body: hole,
                 remainder: env.arena.alloc(switch),
+                region: Region::zero(),
             }
         }
     }
@@ -9836,7 +10094,7 @@ fn enum_lambda_set_to_switch<'a>(
     debug_assert_ne!(lambda_set.len(), 0);
 
     let (opt_join, branch_assigned, branch_hole) = match hole {
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // No need to jump to a joinpoint, inline the return in each statement as-is.
             // This makes further analyses, like TCO, easier as well.
             (None, assigned, hole)
@@ -9844,7 +10102,7 @@ fn enum_lambda_set_to_switch<'a>(
         _ => {
             let join_point_id = JoinPointId(env.unique_symbol());
             let assigned = env.unique_symbol();
-            let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]));
+            let hole = Stmt::Jump(join_point_id, env.arena.alloc([assigned]), Region::zero());
 
             (Some(join_point_id), assigned, &*env.arena.alloc(hole))
         }
@@ -9877,6 +10135,7 @@ fn enum_lambda_set_to_switch<'a>(
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     };
 
     match opt_join {
@@ -9892,6 +10151,7 @@ fn enum_lambda_set_to_switch<'a>(
                 parameters: &*env.arena.alloc([param]),
                 body: hole,
                 remainder: env.arena.alloc(switch),
+                region: Region::zero(),
             }
         }
     }
@@ -9945,7 +10205,11 @@ where
     for (i, function_symbol) in lambda_set.into_iter().enumerate() {
         let result_symbol = env.unique_symbol();
 
-        let hole = Stmt::Jump(join_point_id, env.arena.alloc([result_symbol]));
+        let hole = Stmt::Jump(
+            join_point_id,
+            env.arena.alloc([result_symbol]),
+            Region::zero(),
+        );
 
         let call_spec_id = env.next_call_specialization_id();
         let update_mode = env.next_update_mode_id();
@@ -9979,6 +10243,7 @@ where
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     };
 
     let param = Param {
@@ -9991,6 +10256,7 @@ where
         parameters: &*env.arena.alloc([param]),
         body: hole,
         remainder: env.arena.alloc(switch),
+        region: Region::zero(),
     }
 }
 
@@ -10323,7 +10589,7 @@ where
 
         home.register_debug_idents(ident_ids);
 
-        let ret_stmt = arena.alloc(Stmt::Ret(result));
+        let ret_stmt = arena.alloc(Stmt::Ret(result, Region::zero()));
 
         let field_get_expr = Expr::StructAtIndex {
             index: index as u64,
@@ -10350,6 +10616,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            regions: ProcRegions::default(),
         };
 
         answer.push(GlueProc {
@@ -10423,7 +10690,7 @@ where
 
         home.register_debug_idents(ident_ids);
 
-        let ret_stmt = arena.alloc(Stmt::Ret(result));
+        let ret_stmt = arena.alloc(Stmt::Ret(result, Region::zero()));
 
         let field_get_expr = Expr::UnionAtIndex {
             structure: unboxed,
@@ -10445,6 +10712,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            regions: ProcRegions::default(),
         };
 
         answer.push(GlueProc {
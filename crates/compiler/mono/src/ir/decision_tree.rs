@@ -15,6 +15,7 @@ use roc_exhaustive::{Ctor, CtorName, ListArity, RenderAs, TagId, Union};
 use roc_module::ident::TagName;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
+use roc_region::all::Region;
 
 /// COMPILE CASES
 
@@ -1408,6 +1409,7 @@ pub(crate) fn optimize_when<'a>(
     cond_symbol: Symbol,
     cond_layout: InLayout<'a>,
     ret_layout: InLayout<'a>,
+    cond_region: Region,
     opt_branches: bumpalo::collections::Vec<'a, (Pattern<'a>, Guard<'a>, Stmt<'a>)>,
 ) -> Stmt<'a> {
     let (patterns, indexed_branches): (_, Vec<_>) = opt_branches
@@ -1523,6 +1525,7 @@ pub(crate) fn optimize_when<'a>(
         cond_symbol,
         cond_layout,
         ret_layout,
+        cond_region,
         choice_decider,
         &jumps,
     );
@@ -1540,6 +1543,7 @@ pub(crate) fn optimize_when<'a>(
             parameters: join_params,
             body: env.arena.alloc(join_body),
             remainder: env.arena.alloc(stmt),
+            region: cond_region,
         };
     }
 
@@ -1842,6 +1846,7 @@ fn stores_and_condition<'a>(
 fn compile_test<'a>(
     env: &mut Env<'a, '_>,
     ret_layout: InLayout<'a>,
+    region: Region,
     stores: bumpalo::collections::Vec<'a, (Symbol, InLayout<'a>, Expr<'a>)>,
     lhs: Symbol,
     cmp: Comparator,
@@ -1853,6 +1858,7 @@ fn compile_test<'a>(
         env,
         ConstructorKnown::None,
         ret_layout,
+        region,
         stores,
         lhs,
         cmp,
@@ -1867,6 +1873,7 @@ fn compile_test_help<'a>(
     env: &mut Env<'a, '_>,
     branch_info: ConstructorKnown<'a>,
     ret_layout: InLayout<'a>,
+    region: Region,
     stores: bumpalo::collections::Vec<'a, (Symbol, InLayout<'a>, Expr<'a>)>,
     lhs: Symbol,
     cmp: Comparator,
@@ -1934,6 +1941,7 @@ fn compile_test_help<'a>(
         ret_layout,
         branches,
         default_branch,
+        region,
     };
 
     let op = match cmp {
@@ -1962,6 +1970,7 @@ fn compile_test_help<'a>(
 fn compile_tests<'a>(
     env: &mut Env<'a, '_>,
     ret_layout: InLayout<'a>,
+    region: Region,
     tests: Tests<'a>,
     fail: &'a Stmt<'a>,
     mut cond: Stmt<'a>,
@@ -1969,11 +1978,13 @@ fn compile_tests<'a>(
     for (new_stores, (lhs, cmp, rhs), opt_constructor_info) in tests.into_iter() {
         match opt_constructor_info {
             None => {
-                cond = compile_test(env, ret_layout, new_stores, lhs, cmp, rhs, fail, cond);
+                cond = compile_test(
+                    env, ret_layout, region, new_stores, lhs, cmp, rhs, fail, cond,
+                );
             }
             Some(cinfo) => {
                 cond = compile_test_help(
-                    env, cinfo, ret_layout, new_stores, lhs, cmp, rhs, fail, cond,
+                    env, cinfo, ret_layout, region, new_stores, lhs, cmp, rhs, fail, cond,
                 );
             }
         }
@@ -2051,6 +2062,7 @@ fn decide_to_branching<'a>(
     cond_symbol: Symbol,
     cond_layout: InLayout<'a>,
     ret_layout: InLayout<'a>,
+    region: Region,
     decider: Decider<'a, Choice<'a>>,
     jumps: &[JumpSpec<'a>],
 ) -> Stmt<'a> {
@@ -2065,7 +2077,11 @@ fn decide_to_branching<'a>(
                 .binary_search_by_key(&label, |r| r.target_index)
                 .expect("jump not in list of jumps");
 
-            Stmt::Jump(jumps[index].id, jumps[index].jump_pattern_param_symbols)
+            Stmt::Jump(
+                jumps[index].id,
+                jumps[index].jump_pattern_param_symbols,
+                region,
+            )
         }
         Leaf(Inline(expr)) => expr,
         Guarded {
@@ -2085,6 +2101,7 @@ fn decide_to_branching<'a>(
                 cond_symbol,
                 cond_layout,
                 ret_layout,
+                region,
                 *success,
                 jumps,
             );
@@ -2096,6 +2113,7 @@ fn decide_to_branching<'a>(
                 cond_symbol,
                 cond_layout,
                 ret_layout,
+                region,
                 *failure,
                 jumps,
             );
@@ -2107,6 +2125,7 @@ fn decide_to_branching<'a>(
                 pass_expr,
                 fail_expr,
                 ret_layout,
+                region,
             );
 
             // calculate the guard value
@@ -2125,6 +2144,7 @@ fn decide_to_branching<'a>(
                 parameters: arena.alloc([param]),
                 body: arena.alloc(decide),
                 remainder: arena.alloc(stmt),
+                region,
             };
 
             store_pattern(env, procs, layout_cache, &pattern, cond_symbol, join)
@@ -2143,6 +2163,7 @@ fn decide_to_branching<'a>(
                 cond_symbol,
                 cond_layout,
                 ret_layout,
+                region,
                 *success,
                 jumps,
             );
@@ -2154,6 +2175,7 @@ fn decide_to_branching<'a>(
                 cond_symbol,
                 cond_layout,
                 ret_layout,
+                region,
                 *failure,
                 jumps,
             );
@@ -2183,6 +2205,7 @@ fn decide_to_branching<'a>(
                     env,
                     chain_branch_info,
                     ret_layout,
+                    region,
                     new_stores,
                     lhs,
                     cmp,
@@ -2194,15 +2217,16 @@ fn decide_to_branching<'a>(
                 // otherwise, we use a join point so the code for the `else` case
                 // is only generated once.
                 let fail_jp_id = JoinPointId(env.unique_symbol());
-                let jump = arena.alloc(Stmt::Jump(fail_jp_id, &[]));
+                let jump = arena.alloc(Stmt::Jump(fail_jp_id, &[], region));
 
-                let test_stmt = compile_tests(env, ret_layout, tests, jump, pass_expr);
+                let test_stmt = compile_tests(env, ret_layout, region, tests, jump, pass_expr);
 
                 Stmt::Join {
                     id: fail_jp_id,
                     parameters: &[],
                     body: fail,
                     remainder: arena.alloc(test_stmt),
+                    region,
                 }
             }
         }
@@ -2229,6 +2253,7 @@ fn decide_to_branching<'a>(
                 cond_symbol,
                 cond_layout,
                 ret_layout,
+                region,
                 *fallback,
                 jumps,
             );
@@ -2246,6 +2271,7 @@ fn decide_to_branching<'a>(
                     cond_symbol,
                     cond_layout,
                     ret_layout,
+                    region,
                     decider,
                     jumps,
                 );
@@ -2320,6 +2346,7 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region,
                 };
 
                 let expr = Expr::GetTagId {
@@ -2342,6 +2369,7 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region,
                 };
 
                 let len_expr = Expr::Call(Call {
@@ -2365,6 +2393,7 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region,
                 }
             };
 
@@ -1,5 +1,6 @@
 use bumpalo::{collections::Vec as AVec, Bump};
 use roc_module::{low_level::LowLevel, symbol::Symbol};
+use roc_region::all::Region;
 use roc_types::subs::Variable;
 
 use crate::layout::{FunctionPointer, InLayout, LambdaName, Layout, LayoutCache, LayoutRepr};
@@ -202,7 +203,7 @@ pub fn call_erased_function<'a>(
             //
             let_result(
                 //
-                Stmt::Jump(join_point_id, arena.alloc([result])),
+                Stmt::Jump(join_point_id, arena.alloc([result]), Region::zero()),
             ),
         )
     };
@@ -223,6 +224,7 @@ pub fn call_erased_function<'a>(
                 arena.alloc(build_closure_data_branch(env, true)),
             ),
             ret_layout: hole_layout,
+            region: Region::zero(),
         },
     );
 
@@ -243,6 +245,7 @@ pub fn call_erased_function<'a>(
             parameters: env.arena.alloc([param]),
             body: hole,
             remainder: arena.alloc(remainder),
+            region: Region::zero(),
         }
     };
 
@@ -3445,11 +3445,14 @@ fn layout_from_flat_type<'a>(
                 // unwrap it.
                 Ok(sortables.pop().unwrap().1)
             } else {
+                let ordered_indices =
+                    Vec::from_iter_in(sortables.iter().map(|(index, _)| *index), arena)
+                        .into_bump_slice();
                 let field_layouts =
                     Vec::from_iter_in(sortables.into_iter().map(|t| t.1), arena).into_bump_slice();
                 let struct_layout = Layout {
                     repr: LayoutRepr::Struct(field_layouts).direct(),
-                    semantic: SemanticRepr::tuple(field_layouts.len()),
+                    semantic: SemanticRepr::tuple(ordered_indices),
                 };
 
                 Ok(env.cache.put_in(struct_layout))
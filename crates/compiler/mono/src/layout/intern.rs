@@ -197,6 +197,30 @@ pub trait LayoutInterner<'a>: Sized {
         self.get(key).semantic
     }
 
+    /// Follows [LayoutWrapper::Newtype]s to the layout that holds the representation directly.
+    /// Unlike a newtype wrapper, whose semantic is that of the wrapping type, that layout's
+    /// semantic describes its own representation.
+    fn unwrap_newtype(&self, mut key: InLayout<'a>) -> InLayout<'a> {
+        loop {
+            match self.get(key).repr {
+                LayoutWrapper::Direct(_) => return key,
+                LayoutWrapper::Newtype(inner) => key = inner,
+            }
+        }
+    }
+
+    /// The positions of the fields of a struct layout, in the order of the record or tuple type
+    /// the layout was generated for. See [SemanticRepr::field_order].
+    fn struct_field_order(&self, key: InLayout<'a>) -> Vec<usize> {
+        let key = self.unwrap_newtype(key);
+        match self.get_repr(key) {
+            LayoutRepr::Struct(field_layouts) => {
+                self.get_semantic(key).field_order(field_layouts.len())
+            }
+            _ => roc_error_macros::internal_error!("not a struct layout!"),
+        }
+    }
+
     fn eq_repr(&self, a: InLayout<'a>, b: InLayout<'a>) -> bool {
         self.get_repr(a) == self.get_repr(b)
     }
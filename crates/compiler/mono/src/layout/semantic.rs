@@ -25,7 +25,7 @@ impl<'a> SemanticRepr<'a> {
 enum Inner<'a> {
     None,
     Record(SemaRecord<'a>),
-    Tuple(SemaTuple),
+    Tuple(SemaTuple<'a>),
     TagUnion(SemaTagUnion<'a>),
     Lambdas(SemaLambdas<'a>),
 }
@@ -51,8 +51,8 @@ impl<'a> SemanticRepr<'a> {
         Self(Inner::Record(SemaRecord { fields }))
     }
 
-    pub(super) fn tuple(size: usize) -> Self {
-        Self(Inner::Tuple(SemaTuple { size }))
+    pub(super) fn tuple(indices: &'a [usize]) -> Self {
+        Self(Inner::Tuple(SemaTuple { indices }))
     }
 
     pub(super) fn tag_union(tags: &'a [&'a str]) -> Self {
@@ -62,6 +62,28 @@ impl<'a> SemanticRepr<'a> {
     pub(super) fn lambdas(lambdas: &'a [Symbol]) -> Self {
         Self(Inner::Lambdas(SemaLambdas { lambdas }))
     }
+
+    /// The positions of the `field_count` fields of a struct layout with this semantic
+    /// representation, in the order of the type it was generated for: records by field name, and
+    /// tuples by element index. Layouts order fields by alignment, so this is the order to
+    /// visit them in when the result depends on it, like when comparing two values.
+    pub fn field_order(&self, field_count: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..field_count).collect();
+
+        match self.0 {
+            Inner::Record(SemaRecord { fields }) => {
+                debug_assert_eq!(fields.len(), field_count);
+                order.sort_by_key(|&i| fields[i]);
+            }
+            Inner::Tuple(SemaTuple { indices }) => {
+                debug_assert_eq!(indices.len(), field_count);
+                order.sort_by_key(|&i| indices[i]);
+            }
+            Inner::None | Inner::TagUnion(_) | Inner::Lambdas(_) => {}
+        }
+
+        order
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -70,8 +92,8 @@ struct SemaRecord<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct SemaTuple {
-    size: usize,
+struct SemaTuple<'a> {
+    indices: &'a [usize],
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
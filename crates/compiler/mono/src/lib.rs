@@ -15,6 +15,7 @@ pub mod inc_dec;
 pub mod ir;
 pub mod layout;
 pub mod low_level;
+pub mod optimize;
 pub mod reset_reuse;
 pub mod tail_recursion;
 
@@ -0,0 +1,1227 @@
+//! Optimizations on the mono IR, for the backends that don't get them from elsewhere.
+//!
+//! LLVM optimizes the code we give it, but the dev and wasm backends emit more or less exactly
+//! what mono hands them. So when compiling with an optimizing [OptLevel], we run a couple of
+//! classic simplifications right after specialization (before refcounts are inserted):
+//!
+//! - small leaf procs (procs that don't call other procs) are inlined into their callers
+//! - numeric and boolean lowlevels with constant arguments are folded
+//! - a `Switch` whose condition is known (a constant, or the tag id of a tag we just built) is
+//!   replaced by the branch that will be taken, and fields of such tags and structs are read
+//!   directly from the symbols that they were built from
+//! - `let`s of pure expressions that are never used are removed, as are join points that are
+//!   never jumped to
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_builtins::bitcode::IntWidth;
+use roc_collections::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
+
+use crate::ir::{
+    BranchInfo, Call, CallType, Expr, HigherOrderLowLevel, JoinPointId, ListLiteralElement,
+    Literal, ModifyRc, OptLevel, Param, Proc, ProcLayout, SelfRecursive, Stmt,
+};
+use crate::layout::{
+    Builtin, InLayout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType,
+};
+
+/// Run the mono optimizations on all procs. Does nothing for [OptLevel::Development].
+pub fn optimize_procs<'a>(
+    arena: &'a Bump,
+    layout_interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    opt_level: OptLevel,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let inline_threshold = match opt_level {
+        OptLevel::Development => return,
+        OptLevel::Size => 4,
+        OptLevel::Normal => 12,
+        OptLevel::Optimize => 24,
+    };
+
+    let inline_candidates: MutMap<_, _> = procs
+        .iter()
+        .filter(|(_, proc)| is_inline_candidate(proc, inline_threshold))
+        .map(|(key, proc)| {
+            let candidate = InlineCandidate {
+                args: proc.args,
+                body: &*arena.alloc(proc.body.clone()),
+            };
+
+            (*key, candidate)
+        })
+        .collect();
+
+    for proc in procs.values_mut() {
+        let mut env = Env {
+            arena,
+            layout_interner,
+            home,
+            ident_ids: &mut *ident_ids,
+            inline_candidates: &inline_candidates,
+            substitutions: MutMap::default(),
+            layouts: MutMap::default(),
+            known: MutMap::default(),
+        };
+
+        for (layout, symbol) in proc.args.iter() {
+            env.layouts.insert(*symbol, *layout);
+        }
+
+        let body = arena.alloc(proc.body.clone());
+        let body = simplify_stmt(&mut env, body);
+        let body = remove_dead_lets(arena, body, &mut MutSet::default());
+
+        proc.body = body.clone();
+    }
+}
+
+#[derive(Clone, Copy)]
+struct InlineCandidate<'a> {
+    args: &'a [(InLayout<'a>, Symbol)],
+    body: &'a Stmt<'a>,
+}
+
+/// What we know about the value of a symbol.
+#[derive(Clone, Copy)]
+enum Known<'a> {
+    Literal(Literal<'a>),
+    Tag {
+        tag_id: TagIdIntType,
+        arguments: &'a [Symbol],
+    },
+    Struct(&'a [Symbol]),
+}
+
+struct Env<'a, 'r> {
+    arena: &'a Bump,
+    layout_interner: &'r STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    inline_candidates: &'r MutMap<(Symbol, ProcLayout<'a>), InlineCandidate<'a>>,
+    /// Symbols that were removed in favor of another symbol with the same value and layout.
+    /// Symbols are unique within a proc, so these don't need to be scoped.
+    substitutions: MutMap<Symbol, Symbol>,
+    layouts: MutMap<Symbol, InLayout<'a>>,
+    known: MutMap<Symbol, Known<'a>>,
+}
+
+impl<'a, 'r> Env<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn substitute(&self, symbol: Symbol) -> Symbol {
+        self.substitutions.get(&symbol).copied().unwrap_or(symbol)
+    }
+
+    /// Replace `symbol` by `replacement` in the rest of the proc.
+    fn add_substitution(&mut self, symbol: Symbol, replacement: Symbol) {
+        let replacement = self.substitute(replacement);
+        self.substitutions.insert(symbol, replacement);
+    }
+
+    /// Use `replacement` instead of `symbol` if that does not change the layout of any value.
+    fn try_substitute(
+        &mut self,
+        symbol: Symbol,
+        layout: InLayout<'a>,
+        replacement: Symbol,
+    ) -> bool {
+        if self.layouts.get(&replacement) == Some(&layout) {
+            self.add_substitution(symbol, replacement);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn literal(&self, symbol: Symbol) -> Option<Literal<'a>> {
+        match self.known.get(&symbol) {
+            Some(Known::Literal(literal)) => Some(*literal),
+            _ => None,
+        }
+    }
+}
+
+fn is_inline_candidate(proc: &Proc, threshold: usize) -> bool {
+    matches!(proc.is_self_recursive, SelfRecursive::NotSelfRecursive)
+        && !proc.is_erased
+        && is_leaf(&proc.body)
+        && stmt_size(&proc.body) <= threshold
+}
+
+/// A leaf proc does not call other procs, so inlining it can not (indirectly) inline a proc into
+/// itself. It must also not have side tables tied to its own symbols (like expects and dbgs do).
+fn is_leaf(stmt: &Stmt) -> bool {
+    use Stmt::*;
+
+    match stmt {
+        Let(_, expr, _, cont) => {
+            let is_leaf_expr = match expr {
+                Expr::Call(Call {
+                    call_type: CallType::ByName { .. } | CallType::HigherOrder(_),
+                    ..
+                }) => false,
+                Expr::Reset { .. } | Expr::ResetRef { .. } => false,
+                _ => true,
+            };
+
+            is_leaf_expr && is_leaf(cont)
+        }
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => branches.iter().all(|(_, _, branch)| is_leaf(branch)) && is_leaf(default_branch.1),
+        Join {
+            body, remainder, ..
+        } => is_leaf(body) && is_leaf(remainder),
+        Ret(..) | Jump(..) | Crash(..) => true,
+        Refcounting(..) | Expect { .. } | ExpectFx { .. } | Dbg { .. } => false,
+    }
+}
+
+fn stmt_size(stmt: &Stmt) -> usize {
+    use Stmt::*;
+
+    match stmt {
+        Let(_, _, _, cont) => 1 + stmt_size(cont),
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            let branches_size: usize = branches
+                .iter()
+                .map(|(_, _, branch)| stmt_size(branch))
+                .sum();
+
+            1 + branches_size + stmt_size(default_branch.1)
+        }
+        Join {
+            body, remainder, ..
+        } => 1 + stmt_size(body) + stmt_size(remainder),
+        Refcounting(_, cont) => 1 + stmt_size(cont),
+        Expect { remainder, .. } | ExpectFx { remainder, .. } | Dbg { remainder, .. } => {
+            1 + stmt_size(remainder)
+        }
+        Ret(..) | Jump(..) | Crash(..) => 1,
+    }
+}
+
+fn simplify_stmt<'a>(env: &mut Env<'a, '_>, stmt: &'a Stmt<'a>) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let expr = map_expr_symbols(arena, expr, &mut |s| env.substitute(s));
+            env.layouts.insert(*symbol, *layout);
+
+            let expr = match expr {
+                Expr::Call(Call {
+                    call_type:
+                        CallType::ByName {
+                            name,
+                            ret_layout,
+                            arg_layouts,
+                            ..
+                        },
+                    arguments,
+                }) => {
+                    let proc_layout = ProcLayout {
+                        arguments: arg_layouts,
+                        result: ret_layout,
+                        niche: name.niche(),
+                    };
+
+                    let key = (name.name(), proc_layout);
+                    if let Some(candidate) = env.inline_candidates.get(&key).copied() {
+                        let inlined =
+                            inline_call(env, candidate, *symbol, *layout, arguments, cont);
+
+                        return simplify_stmt(env, inlined);
+                    }
+
+                    expr
+                }
+                Expr::Call(Call {
+                    call_type: CallType::LowLevel { op, .. },
+                    arguments,
+                }) => {
+                    let constant_arguments: Option<std::vec::Vec<_>> =
+                        arguments.iter().map(|arg| env.literal(*arg)).collect();
+
+                    match constant_arguments
+                        .and_then(|args| fold_lowlevel(env.layout_interner, op, &args, *layout))
+                    {
+                        Some(literal) => Expr::Literal(literal),
+                        None => expr,
+                    }
+                }
+                Expr::GetTagId { structure, .. } => match env.known.get(&structure) {
+                    Some(Known::Tag { tag_id, .. }) => {
+                        Expr::Literal(Literal::Int((*tag_id as i128).to_ne_bytes()))
+                    }
+                    _ => expr,
+                },
+                Expr::UnionAtIndex {
+                    structure,
+                    tag_id,
+                    index,
+                    ..
+                } => match env.known.get(&structure).copied() {
+                    Some(Known::Tag {
+                        tag_id: known_tag_id,
+                        arguments,
+                    }) if known_tag_id == tag_id => {
+                        if env.try_substitute(*symbol, *layout, arguments[index as usize]) {
+                            return simplify_stmt(env, cont);
+                        }
+
+                        expr
+                    }
+                    _ => expr,
+                },
+                Expr::StructAtIndex {
+                    structure, index, ..
+                } => match env.known.get(&structure).copied() {
+                    Some(Known::Struct(fields)) => {
+                        if env.try_substitute(*symbol, *layout, fields[index as usize]) {
+                            return simplify_stmt(env, cont);
+                        }
+
+                        expr
+                    }
+                    _ => expr,
+                },
+                _ => expr,
+            };
+
+            match &expr {
+                Expr::Literal(literal) => {
+                    env.known.insert(*symbol, Known::Literal(*literal));
+                }
+                Expr::Tag {
+                    tag_id,
+                    arguments,
+                    reuse: None,
+                    ..
+                } => {
+                    let known = Known::Tag {
+                        tag_id: *tag_id,
+                        arguments: *arguments,
+                    };
+                    env.known.insert(*symbol, known);
+                }
+                Expr::Struct(fields) => {
+                    env.known.insert(*symbol, Known::Struct(*fields));
+                }
+                _ => {}
+            }
+
+            let cont = simplify_stmt(env, cont);
+
+            arena.alloc(Let(*symbol, expr, *layout, cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+            region,
+        } => {
+            let cond_symbol = env.substitute(*cond_symbol);
+
+            if let Some(value) = env.literal(cond_symbol).and_then(switch_value) {
+                let taken = branches
+                    .iter()
+                    .find(|(branch_value, _, _)| *branch_value == value)
+                    .map(|(_, _, branch)| branch)
+                    .unwrap_or(default_branch.1);
+
+                return simplify_stmt(env, taken);
+            }
+
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+            for (value, info, branch) in branches.iter() {
+                let info = map_branch_info(info, &mut |s| env.substitute(s));
+                let branch = simplify_stmt(env, branch);
+
+                new_branches.push((*value, info, branch.clone()));
+            }
+
+            let default_info = map_branch_info(&default_branch.0, &mut |s| env.substitute(s));
+            let default_stmt = simplify_stmt(env, default_branch.1);
+
+            arena.alloc(Switch {
+                cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch: (default_info, default_stmt),
+                ret_layout: *ret_layout,
+                region: *region,
+            })
+        }
+        Ret(symbol, region) => arena.alloc(Ret(env.substitute(*symbol), *region)),
+        Refcounting(modify, cont) => {
+            let modify = map_modify_rc(*modify, &mut |s| env.substitute(s));
+            let cont = simplify_stmt(env, cont);
+
+            arena.alloc(Refcounting(modify, cont))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let condition = env.substitute(*condition);
+            let lookups = map_symbols(arena, lookups, &mut |s| env.substitute(s));
+            let remainder = simplify_stmt(env, remainder);
+
+            arena.alloc(Expect {
+                condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let condition = env.substitute(*condition);
+            let lookups = map_symbols(arena, lookups, &mut |s| env.substitute(s));
+            let remainder = simplify_stmt(env, remainder);
+
+            arena.alloc(ExpectFx {
+                condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => {
+            let symbol = env.substitute(*symbol);
+            let remainder = simplify_stmt(env, remainder);
+
+            arena.alloc(Dbg {
+                symbol,
+                variable: *variable,
+                remainder,
+            })
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+            region,
+        } => {
+            for param in parameters.iter() {
+                env.layouts.insert(param.symbol, param.layout);
+            }
+
+            let body = simplify_stmt(env, body);
+            let remainder = simplify_stmt(env, remainder);
+
+            arena.alloc(Join {
+                id: *id,
+                parameters,
+                body,
+                remainder,
+                region: *region,
+            })
+        }
+        Jump(id, arguments, region) => {
+            let arguments = map_symbols(arena, arguments, &mut |s| env.substitute(s));
+
+            arena.alloc(Jump(*id, arguments, *region))
+        }
+        Crash(message, tag) => arena.alloc(Crash(env.substitute(*message), *tag)),
+    }
+}
+
+/// Where control flow goes when an inlined proc body returns.
+enum InlinedReturn<'a> {
+    /// The body is a straight line of `let`s; its result is bound to the symbol of the call, and
+    /// this continuation follows it directly.
+    Continue(&'a Stmt<'a>),
+    /// The body branches; it returns by jumping to this join point.
+    Jump(JoinPointId),
+}
+
+/// Replace `let symbol = callee arguments in cont` with the body of `callee`, with fresh symbols
+/// for everything that the body binds.
+fn inline_call<'a>(
+    env: &mut Env<'a, '_>,
+    callee: InlineCandidate<'a>,
+    symbol: Symbol,
+    layout: InLayout<'a>,
+    arguments: &'a [Symbol],
+    cont: &'a Stmt<'a>,
+) -> &'a Stmt<'a> {
+    let mut renames = MutMap::default();
+
+    for ((_, param), argument) in callee.args.iter().zip(arguments.iter()) {
+        renames.insert(*param, *argument);
+    }
+
+    match returned_symbol(callee.body) {
+        Some(returned) => {
+            match renames.get(&returned) {
+                // the proc returns one of its arguments
+                Some(argument) => env.add_substitution(symbol, *argument),
+                None => {
+                    renames.insert(returned, symbol);
+                }
+            }
+
+            let returns = InlinedReturn::Continue(cont);
+            rename_stmt(env, callee.body, &mut renames, &returns)
+        }
+        None => {
+            let join_id = JoinPointId(env.unique_symbol());
+            let returns = InlinedReturn::Jump(join_id);
+            let remainder = rename_stmt(env, callee.body, &mut renames, &returns);
+
+            let parameters = env.arena.alloc([Param { symbol, layout }]);
+
+            env.arena.alloc(Stmt::Join {
+                id: join_id,
+                parameters,
+                body: cont,
+                remainder,
+                region: Region::zero(),
+            })
+        }
+    }
+}
+
+/// The symbol that a straight line of `let`s returns.
+fn returned_symbol(stmt: &Stmt) -> Option<Symbol> {
+    match stmt {
+        Stmt::Let(_, _, _, cont) => returned_symbol(cont),
+        Stmt::Ret(symbol, _) => Some(*symbol),
+        _ => None,
+    }
+}
+
+/// Copy the body of an inlined proc with fresh symbols. Its regions are dropped, because they
+/// point into the source of the callee, which may be in a different module.
+fn rename_stmt<'a>(
+    env: &mut Env<'a, '_>,
+    stmt: &'a Stmt<'a>,
+    renames: &mut MutMap<Symbol, Symbol>,
+    returns: &InlinedReturn<'a>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    macro_rules! bind {
+        ($symbol:expr) => {{
+            match renames.get(&$symbol) {
+                Some(renamed) => *renamed,
+                None => {
+                    let fresh = env.unique_symbol();
+                    renames.insert($symbol, fresh);
+                    fresh
+                }
+            }
+        }};
+    }
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let expr = map_expr_symbols(arena, expr, &mut |s| rename(renames, s));
+            let symbol = bind!(*symbol);
+            let cont = rename_stmt(env, cont, renames, returns);
+
+            arena.alloc(Let(symbol, expr, *layout, cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+            region: _,
+        } => {
+            let cond_symbol = rename(renames, *cond_symbol);
+
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+            for (value, info, branch) in branches.iter() {
+                let info = map_branch_info(info, &mut |s| rename(renames, s));
+                let branch = rename_stmt(env, branch, renames, returns);
+
+                new_branches.push((*value, info, branch.clone()));
+            }
+
+            let default_info = map_branch_info(&default_branch.0, &mut |s| rename(renames, s));
+            let default_stmt = rename_stmt(env, default_branch.1, renames, returns);
+
+            arena.alloc(Switch {
+                cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch: (default_info, default_stmt),
+                ret_layout: *ret_layout,
+                region: Region::zero(),
+            })
+        }
+        Ret(symbol, _) => match returns {
+            InlinedReturn::Continue(cont) => *cont,
+            InlinedReturn::Jump(id) => {
+                let arguments = arena.alloc([rename(renames, *symbol)]);
+
+                arena.alloc(Jump(*id, arguments, Region::zero()))
+            }
+        },
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+            region: _,
+        } => {
+            let id = JoinPointId(bind!(id.0));
+
+            let mut new_parameters = Vec::with_capacity_in(parameters.len(), arena);
+            for param in parameters.iter() {
+                new_parameters.push(Param {
+                    symbol: bind!(param.symbol),
+                    layout: param.layout,
+                });
+            }
+
+            let body = rename_stmt(env, body, renames, returns);
+            let remainder = rename_stmt(env, remainder, renames, returns);
+
+            arena.alloc(Join {
+                id,
+                parameters: new_parameters.into_bump_slice(),
+                body,
+                remainder,
+                region: Region::zero(),
+            })
+        }
+        Jump(id, arguments, _) => {
+            let id = JoinPointId(rename(renames, id.0));
+            let arguments = map_symbols(arena, arguments, &mut |s| rename(renames, s));
+
+            arena.alloc(Jump(id, arguments, Region::zero()))
+        }
+        Crash(message, tag) => arena.alloc(Crash(rename(renames, *message), *tag)),
+        Refcounting(..) | Expect { .. } | ExpectFx { .. } | Dbg { .. } => {
+            unreachable!("only leaf procs are inlined")
+        }
+    }
+}
+
+fn rename(renames: &MutMap<Symbol, Symbol>, symbol: Symbol) -> Symbol {
+    renames.get(&symbol).copied().unwrap_or(symbol)
+}
+
+/// The value that a `Switch` compares the literal against.
+fn switch_value(literal: Literal) -> Option<u64> {
+    match literal {
+        Literal::Int(bytes) => Some(i128::from_ne_bytes(bytes) as u64),
+        Literal::Bool(b) => Some(b as u64),
+        Literal::Byte(b) => Some(b as u64),
+        Literal::U128(_) | Literal::Float(_) | Literal::Decimal(_) | Literal::Str(_) => None,
+    }
+}
+
+/// Evaluate a lowlevel on constant arguments. Returns `None` when we can't, or when the
+/// operation would crash at runtime (e.g. because it overflows); that must still happen.
+fn fold_lowlevel<'a>(
+    layout_interner: &STLayoutInterner<'a>,
+    op: LowLevel,
+    arguments: &[Literal<'a>],
+    ret_layout: InLayout<'a>,
+) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    match (op, arguments) {
+        (And, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(*a && *b)),
+        (Or, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(*a || *b)),
+        (Not, [Literal::Bool(a)]) => Some(Literal::Bool(!*a)),
+        (Eq, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(a == b)),
+        (NotEq, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(a != b)),
+        (Eq, [Literal::Byte(a), Literal::Byte(b)]) => Some(Literal::Bool(a == b)),
+        (NotEq, [Literal::Byte(a), Literal::Byte(b)]) => Some(Literal::Bool(a != b)),
+        (_, [Literal::Int(a), Literal::Int(b)]) => {
+            let a = i128::from_ne_bytes(*a);
+            let b = i128::from_ne_bytes(*b);
+
+            let compared = match op {
+                Eq => Some(a == b),
+                NotEq => Some(a != b),
+                NumGt => Some(a > b),
+                NumGte => Some(a >= b),
+                NumLt => Some(a < b),
+                NumLte => Some(a <= b),
+                _ => None,
+            };
+
+            if let Some(result) = compared {
+                return Some(Literal::Bool(result));
+            }
+
+            let width = small_int_width(layout_interner, ret_layout)?;
+
+            let result = match op {
+                NumAdd => in_range(width, a + b)?,
+                NumSub => in_range(width, a - b)?,
+                NumMul => in_range(width, a.checked_mul(b)?)?,
+                NumAddWrap => wrap(width, a + b),
+                NumSubWrap => wrap(width, a - b),
+                NumMulWrap => wrap(width, a.wrapping_mul(b)),
+                NumAddSaturated => saturate(width, a + b),
+                NumSubSaturated => saturate(width, a - b),
+                NumMulSaturated => saturate(width, a.saturating_mul(b)),
+                NumDivTruncUnchecked if b != 0 => in_range(width, a / b)?,
+                NumRemUnchecked if b != 0 => a % b,
+                NumBitwiseAnd => a & b,
+                NumBitwiseOr => a | b,
+                NumBitwiseXor => a ^ b,
+                _ => return None,
+            };
+
+            Some(Literal::Int(result.to_ne_bytes()))
+        }
+        (_, [Literal::Int(a)]) => {
+            let a = i128::from_ne_bytes(*a);
+            let width = small_int_width(layout_interner, ret_layout)?;
+
+            let result = match op {
+                NumNeg => in_range(width, -a)?,
+                NumAbs => in_range(width, a.abs())?,
+                NumIntCast => wrap(width, a),
+                _ => return None,
+            };
+
+            Some(Literal::Int(result.to_ne_bytes()))
+        }
+        _ => None,
+    }
+}
+
+/// The width of an integer layout that fits in 64 bits. Values of these widths are stored in
+/// [Literal::Int] as an `i128`, so any arithmetic on two of them can be done without overflow.
+fn small_int_width<'a>(
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> Option<IntWidth> {
+    match layout_interner.get_repr(layout) {
+        LayoutRepr::Builtin(Builtin::Int(width)) if width.stack_size() <= 8 => Some(width),
+        _ => None,
+    }
+}
+
+fn int_bounds(width: IntWidth) -> (i128, i128) {
+    let bits = width.stack_size() * 8;
+
+    if width.is_signed() {
+        (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    } else {
+        (0, (1 << bits) - 1)
+    }
+}
+
+fn in_range(width: IntWidth, value: i128) -> Option<i128> {
+    let (min, max) = int_bounds(width);
+
+    (min..=max).contains(&value).then_some(value)
+}
+
+fn saturate(width: IntWidth, value: i128) -> i128 {
+    let (min, max) = int_bounds(width);
+
+    value.clamp(min, max)
+}
+
+fn wrap(width: IntWidth, value: i128) -> i128 {
+    let unused_bits = 128 - width.stack_size() * 8;
+
+    if width.is_signed() {
+        (value << unused_bits) >> unused_bits
+    } else {
+        ((value as u128) << unused_bits >> unused_bits) as i128
+    }
+}
+
+/// Remove `let`s of pure expressions whose symbol is never used, and join points that are never
+/// jumped to. `used` collects the symbols that are used by the returned statement.
+fn remove_dead_lets<'a>(
+    arena: &'a Bump,
+    stmt: &'a Stmt<'a>,
+    used: &mut MutSet<Symbol>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let cont = remove_dead_lets(arena, cont, used);
+
+            if !used.contains(symbol) && is_pure(expr) {
+                return cont;
+            }
+
+            for_each_expr_symbol(expr, &mut |s| {
+                used.insert(s);
+            });
+
+            arena.alloc(Let(*symbol, expr.clone(), *layout, cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+            region,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+            for (value, info, branch) in branches.iter() {
+                let branch = remove_dead_lets(arena, branch, used);
+
+                new_branches.push((*value, info.clone(), branch.clone()));
+            }
+
+            let default_stmt = remove_dead_lets(arena, default_branch.1, used);
+
+            used.insert(*cond_symbol);
+            for info in branches
+                .iter()
+                .map(|(_, info, _)| info)
+                .chain([&default_branch.0])
+            {
+                used.extend(branch_info_scrutinee(info));
+            }
+
+            arena.alloc(Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch: (default_branch.0.clone(), default_stmt),
+                ret_layout: *ret_layout,
+                region: *region,
+            })
+        }
+        Ret(symbol, _) => {
+            used.insert(*symbol);
+
+            stmt
+        }
+        Refcounting(modify, cont) => {
+            let cont = remove_dead_lets(arena, cont, used);
+            used.insert(modify.get_symbol());
+
+            arena.alloc(Refcounting(*modify, cont))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+            used.insert(*condition);
+            used.extend(lookups.iter().copied());
+
+            arena.alloc(Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+            used.insert(*condition);
+            used.extend(lookups.iter().copied());
+
+            arena.alloc(ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+            used.insert(*symbol);
+
+            arena.alloc(Dbg {
+                symbol: *symbol,
+                variable: *variable,
+                remainder,
+            })
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+            region,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+
+            if !used.contains(&id.0) {
+                // jumps in the body itself don't count, it can only be entered from the remainder
+                return remainder;
+            }
+
+            let body = remove_dead_lets(arena, body, used);
+
+            arena.alloc(Join {
+                id: *id,
+                parameters,
+                body,
+                remainder,
+                region: *region,
+            })
+        }
+        Jump(id, arguments, _) => {
+            used.insert(id.0);
+            used.extend(arguments.iter().copied());
+
+            stmt
+        }
+        Crash(message, _) => {
+            used.insert(*message);
+
+            stmt
+        }
+    }
+}
+
+/// An expression that can be removed when its value is not used: it has no side effects and can't
+/// crash.
+fn is_pure(expr: &Expr) -> bool {
+    use LowLevel::*;
+
+    match expr {
+        Expr::Literal(_)
+        | Expr::Struct(_)
+        | Expr::NullPointer
+        | Expr::StructAtIndex { .. }
+        | Expr::GetTagId { .. }
+        | Expr::UnionAtIndex { .. }
+        | Expr::GetElementPointer { .. }
+        | Expr::Array { .. }
+        | Expr::EmptyArray
+        | Expr::ErasedMake { .. }
+        | Expr::ErasedLoad { .. }
+        | Expr::FunctionPointer { .. } => true,
+        Expr::Tag { reuse, .. } => reuse.is_none(),
+        Expr::Call(Call {
+            call_type: CallType::LowLevel { op, .. },
+            ..
+        }) => matches!(
+            op,
+            NumAddWrap
+                | NumSubWrap
+                | NumMulWrap
+                | NumAddSaturated
+                | NumSubSaturated
+                | NumMulSaturated
+                | NumAddChecked
+                | NumSubChecked
+                | NumMulChecked
+                | NumGt
+                | NumGte
+                | NumLt
+                | NumLte
+                | NumCompare
+                | NumBitwiseAnd
+                | NumBitwiseOr
+                | NumBitwiseXor
+                | NumIntCast
+                | NumToFrac
+                | Eq
+                | NotEq
+                | And
+                | Or
+                | Not
+                | ListLen
+                | StrIsEmpty
+                | StrCountUtf8Bytes
+        ),
+        Expr::Call(_)
+        | Expr::Alloca { .. }
+        | Expr::Reset { .. }
+        | Expr::ResetRef { .. }
+        | Expr::RuntimeErrorFunction(_) => false,
+    }
+}
+
+fn map_symbols<'a>(
+    arena: &'a Bump,
+    symbols: &[Symbol],
+    f: &mut impl FnMut(Symbol) -> Symbol,
+) -> &'a [Symbol] {
+    Vec::from_iter_in(symbols.iter().map(|s| f(*s)), arena).into_bump_slice()
+}
+
+fn map_branch_info<'a>(
+    info: &BranchInfo<'a>,
+    f: &mut impl FnMut(Symbol) -> Symbol,
+) -> BranchInfo<'a> {
+    match info {
+        BranchInfo::None => BranchInfo::None,
+        BranchInfo::Constructor {
+            scrutinee,
+            layout,
+            tag_id,
+        } => BranchInfo::Constructor {
+            scrutinee: f(*scrutinee),
+            layout: *layout,
+            tag_id: *tag_id,
+        },
+        BranchInfo::List { scrutinee, len } => BranchInfo::List {
+            scrutinee: f(*scrutinee),
+            len: *len,
+        },
+        BranchInfo::Unique { scrutinee, unique } => BranchInfo::Unique {
+            scrutinee: f(*scrutinee),
+            unique: *unique,
+        },
+    }
+}
+
+fn branch_info_scrutinee(info: &BranchInfo) -> Option<Symbol> {
+    match info {
+        BranchInfo::None => None,
+        BranchInfo::Constructor { scrutinee, .. }
+        | BranchInfo::List { scrutinee, .. }
+        | BranchInfo::Unique { scrutinee, .. } => Some(*scrutinee),
+    }
+}
+
+fn map_modify_rc(modify: ModifyRc, f: &mut impl FnMut(Symbol) -> Symbol) -> ModifyRc {
+    match modify {
+        ModifyRc::Inc(symbol, n) => ModifyRc::Inc(f(symbol), n),
+        ModifyRc::Dec(symbol) => ModifyRc::Dec(f(symbol)),
+        ModifyRc::DecRef(symbol) => ModifyRc::DecRef(f(symbol)),
+        ModifyRc::Free(symbol) => ModifyRc::Free(f(symbol)),
+    }
+}
+
+/// Apply `f` to all symbols that an expression uses.
+fn map_expr_symbols<'a>(
+    arena: &'a Bump,
+    expr: &Expr<'a>,
+    f: &mut impl FnMut(Symbol) -> Symbol,
+) -> Expr<'a> {
+    match expr {
+        Expr::Literal(_)
+        | Expr::EmptyArray
+        | Expr::NullPointer
+        | Expr::FunctionPointer { .. }
+        | Expr::RuntimeErrorFunction(_) => expr.clone(),
+        Expr::Call(call) => {
+            let call_type = match &call.call_type {
+                CallType::ByPointer {
+                    pointer,
+                    ret_layout,
+                    arg_layouts,
+                } => CallType::ByPointer {
+                    pointer: f(*pointer),
+                    ret_layout: *ret_layout,
+                    arg_layouts,
+                },
+                CallType::HigherOrder(higher_order) => {
+                    let mut higher_order: HigherOrderLowLevel = (*higher_order).clone();
+                    let captured = higher_order.passed_function.captured_environment;
+                    higher_order.passed_function.captured_environment = f(captured);
+
+                    CallType::HigherOrder(arena.alloc(higher_order))
+                }
+                other => other.clone(),
+            };
+
+            Expr::Call(Call {
+                call_type,
+                arguments: map_symbols(arena, call.arguments, f),
+            })
+        }
+        Expr::Tag {
+            tag_layout,
+            tag_id,
+            arguments,
+            reuse,
+        } => Expr::Tag {
+            tag_layout: *tag_layout,
+            tag_id: *tag_id,
+            arguments: map_symbols(arena, arguments, &mut *f),
+            reuse: reuse.map(|mut token| {
+                token.symbol = f(token.symbol);
+                token
+            }),
+        },
+        Expr::Struct(fields) => Expr::Struct(map_symbols(arena, fields, f)),
+        Expr::StructAtIndex {
+            index,
+            field_layouts,
+            structure,
+        } => Expr::StructAtIndex {
+            index: *index,
+            field_layouts,
+            structure: f(*structure),
+        },
+        Expr::GetTagId {
+            structure,
+            union_layout,
+        } => Expr::GetTagId {
+            structure: f(*structure),
+            union_layout: *union_layout,
+        },
+        Expr::UnionAtIndex {
+            structure,
+            tag_id,
+            union_layout,
+            index,
+        } => Expr::UnionAtIndex {
+            structure: f(*structure),
+            tag_id: *tag_id,
+            union_layout: *union_layout,
+            index: *index,
+        },
+        Expr::GetElementPointer {
+            structure,
+            union_layout,
+            indices,
+        } => Expr::GetElementPointer {
+            structure: f(*structure),
+            union_layout: *union_layout,
+            indices,
+        },
+        Expr::Array { elem_layout, elems } => {
+            let elems = Vec::from_iter_in(
+                elems.iter().map(|elem| match elem {
+                    ListLiteralElement::Symbol(s) => ListLiteralElement::Symbol(f(*s)),
+                    ListLiteralElement::Literal(_) => *elem,
+                }),
+                arena,
+            );
+
+            Expr::Array {
+                elem_layout: *elem_layout,
+                elems: elems.into_bump_slice(),
+            }
+        }
+        Expr::ErasedMake { value, callee } => Expr::ErasedMake {
+            value: value.map(&mut *f),
+            callee: f(*callee),
+        },
+        Expr::ErasedLoad { symbol, field } => Expr::ErasedLoad {
+            symbol: f(*symbol),
+            field: *field,
+        },
+        Expr::Alloca {
+            element_layout,
+            initializer,
+        } => Expr::Alloca {
+            element_layout: *element_layout,
+            initializer: initializer.map(&mut *f),
+        },
+        Expr::Reset {
+            symbol,
+            update_mode,
+        } => Expr::Reset {
+            symbol: f(*symbol),
+            update_mode: *update_mode,
+        },
+        Expr::ResetRef {
+            symbol,
+            update_mode,
+        } => Expr::ResetRef {
+            symbol: f(*symbol),
+            update_mode: *update_mode,
+        },
+    }
+}
+
+/// Call `f` on all symbols that an expression uses.
+fn for_each_expr_symbol(expr: &Expr, f: &mut impl FnMut(Symbol)) {
+    match expr {
+        Expr::Literal(_)
+        | Expr::EmptyArray
+        | Expr::NullPointer
+        | Expr::FunctionPointer { .. }
+        | Expr::RuntimeErrorFunction(_) => {}
+        Expr::Call(call) => {
+            match &call.call_type {
+                CallType::ByPointer { pointer, .. } => f(*pointer),
+                CallType::HigherOrder(higher_order) => {
+                    f(higher_order.passed_function.captured_environment)
+                }
+                CallType::ByName { .. } | CallType::Foreign { .. } | CallType::LowLevel { .. } => {}
+            }
+
+            call.arguments.iter().copied().for_each(f);
+        }
+        Expr::Tag {
+            arguments, reuse, ..
+        } => {
+            arguments.iter().copied().for_each(&mut *f);
+
+            if let Some(token) = reuse {
+                f(token.symbol);
+            }
+        }
+        Expr::Struct(fields) => fields.iter().copied().for_each(f),
+        Expr::StructAtIndex { structure, .. }
+        | Expr::GetTagId { structure, .. }
+        | Expr::UnionAtIndex { structure, .. }
+        | Expr::GetElementPointer { structure, .. } => f(*structure),
+        Expr::Array { elems, .. } => elems
+            .iter()
+            .filter_map(ListLiteralElement::to_symbol)
+            .for_each(f),
+        Expr::ErasedMake { value, callee } => {
+            value.iter().copied().for_each(&mut *f);
+            f(*callee);
+        }
+        Expr::ErasedLoad { symbol, .. }
+        | Expr::Reset { symbol, .. }
+        | Expr::ResetRef { symbol, .. } => f(*symbol),
+        Expr::Alloca { initializer, .. } => initializer.iter().copied().for_each(f),
+    }
+}
@@ -240,6 +240,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             macro_rules! update_env_with_constructor {
                 ($branch_env:expr, $info:expr) => {{
@@ -403,6 +404,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
         Stmt::Refcounting(rc, continuation) => {
@@ -596,7 +598,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
 
             arena.alloc(Stmt::Refcounting(*rc, new_continuation))
         }
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // The return statement just doesn't consume any tokens. Dropping these tokens will be handled before.
             stmt
         }
@@ -676,6 +678,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
             parameters,
             body,
             remainder,
+            region,
         } => {
             // First we evaluate the remainder, to see what reuse tokens are available at each jump. We generate code as if no reuse tokens are used.
             // Then we evaluate the body, to see what reuse tokens are consumed by the body.
@@ -843,6 +846,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                     parameters,
                     body: first_pass_body,
                     remainder: first_pass_remainder,
+                    region: *region,
                 });
             }
 
@@ -914,6 +918,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                     parameters: extended_parameters,
                     body: first_pass_body,
                     remainder: second_pass_remainder,
+                    region: *region,
                 });
             }
 
@@ -962,9 +967,10 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 parameters: extended_parameters,
                 body: second_pass_body,
                 remainder: second_pass_remainder,
+                region: *region,
             })
         }
-        Stmt::Jump(id, arguments) => {
+        Stmt::Jump(id, arguments, region) => {
             // TODO make sure that the reuse tokens that are provided by most jumps are the tokens that are used in most paths.
             let joinpoint_tokens = environment.get_joinpoint_reuse_tokens(*id);
 
@@ -972,12 +978,12 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 JoinPointReuseTokens::RemainderFirst | JoinPointReuseTokens::BodyFirst => {
                     // For both the first pass of the continuation and the body, act as if there are no tokens to reuse.
                     environment.add_jump_reuse_tokens(*id, environment.reuse_tokens.clone());
-                    arena.alloc(Stmt::Jump(*id, arguments))
+                    arena.alloc(Stmt::Jump(*id, arguments, *region))
                 }
                 JoinPointReuseTokens::RemainderSecond(token_layouts) => {
                     // If there are no tokens to reuse, we can just jump.
                     if token_layouts.is_empty() {
-                        return arena.alloc(Stmt::Jump(*id, arguments));
+                        return arena.alloc(Stmt::Jump(*id, arguments, *region));
                     }
 
                     let token_layouts_clone = token_layouts.clone();
@@ -1034,7 +1040,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                     .into_bump_slice();
 
                     let casted_tokens = reuse_tokens_to_cast.into_iter().fold(
-                        arena.alloc(Stmt::Jump(*id, extended_arguments)),
+                        arena.alloc(Stmt::Jump(*id, extended_arguments, *region)),
                         |child, (layout, old_symbol, new_symbol)| {
                             arena.alloc(Stmt::Let(
                                 new_symbol,
@@ -1056,7 +1062,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 JoinPointReuseTokens::BodySecond(token_layouts) => {
                     // If there are no tokens to reuse, we can just jump.
                     if token_layouts.is_empty() {
-                        return arena.alloc(Stmt::Jump(*id, arguments));
+                        return arena.alloc(Stmt::Jump(*id, arguments, *region));
                     }
 
                     // We currently don't pass any reuse tokens to recursive jumps.
@@ -1085,7 +1091,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
 
                     // Wrap the jump in a let statement for each void pointer token layout.
                     void_pointer_layout_symbols.into_iter().fold(
-                        arena.alloc(Stmt::Jump(*id, extended_arguments)),
+                        arena.alloc(Stmt::Jump(*id, extended_arguments, *region)),
                         |child, (layout, symbol)| {
                             arena.alloc(Stmt::Let(symbol, Expr::NullPointer, *layout, child))
                         },
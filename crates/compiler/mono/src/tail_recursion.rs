@@ -12,6 +12,7 @@ use bumpalo::Bump;
 use roc_collections::{MutMap, VecMap};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 
 pub struct Env<'a, 'i> {
     arena: &'a Bump,
@@ -135,13 +136,14 @@ fn make_tail_recursive<'a>(
     // TODO could this be &[]?
     let args = Vec::from_iter_in(args.iter().map(|t| t.2), arena).into_bump_slice();
 
-    let jump = arena.alloc(Stmt::Jump(id, args));
+    let jump = arena.alloc(Stmt::Jump(id, args, Region::zero()));
 
     let join = Stmt::Join {
         id,
         remainder: jump,
         parameters: params,
         body: new_stmt,
+        region: Region::zero(),
     };
 
     Some(join)
@@ -179,11 +181,11 @@ fn insert_jumps<'a>(
                 arguments,
             }),
             _,
-            Stmt::Ret(rsym),
+            Stmt::Ret(rsym, region),
         ) if symbol == rsym && is_equal_function(*fsym, arg_layouts, *ret_layout) => {
             // replace the call and return with a jump
 
-            let jump = Stmt::Jump(goal_id, arguments);
+            let jump = Stmt::Jump(goal_id, arguments, *region);
 
             Some(arena.alloc(jump))
         }
@@ -212,6 +214,7 @@ fn insert_jumps<'a>(
             parameters,
             remainder,
             body: continuation,
+            region,
         } => {
             let opt_remainder = insert_jumps(
                 arena,
@@ -239,6 +242,7 @@ fn insert_jumps<'a>(
                     parameters,
                     remainder,
                     body: continuation,
+                    region: *region,
                 }))
             } else {
                 None
@@ -250,6 +254,7 @@ fn insert_jumps<'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let opt_default = insert_jumps(
                 arena,
@@ -310,6 +315,7 @@ fn insert_jumps<'a>(
                     default_branch,
                     branches,
                     ret_layout: *ret_layout,
+                    region: *region,
                 }))
             } else {
                 None
@@ -397,8 +403,8 @@ fn insert_jumps<'a>(
             None => None,
         },
 
-        Ret(_) => None,
-        Jump(_, _) => None,
+        Ret(..) => None,
+        Jump(..) => None,
         Crash(..) => None,
     }
 }
@@ -556,7 +562,7 @@ fn trmc_candidates_help(
             trmc_candidates_help(function_name, body, candidates);
             trmc_candidates_help(function_name, remainder, candidates);
         }
-        Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => { /* terminal */ }
+        Stmt::Ret(..) | Stmt::Jump(..) | Stmt::Crash(_, _) => { /* terminal */ }
     }
 }
 
@@ -624,7 +630,7 @@ impl<'a> TrmcEnv<'a> {
     #[inline(always)]
     fn is_terminal_constructor(stmt: &Stmt<'a>) -> Option<ConstructorInfo<'a>> {
         match stmt {
-            Stmt::Let(s1, expr, _layout, Stmt::Ret(s2)) if s1 == s2 => {
+            Stmt::Let(s1, expr, _layout, Stmt::Ret(s2, _)) if s1 == s2 => {
                 Self::get_contructor_info(expr)
             }
 
@@ -682,7 +688,7 @@ impl<'a> TrmcEnv<'a> {
         next: &Stmt<'a>,
     ) -> Option<Call<'a>> {
         match next {
-            Stmt::Ret(s) if *s == symbol => Self::is_recursive_expr(expr, lambda_name),
+            Stmt::Ret(s, _) if *s == symbol => Self::is_recursive_expr(expr, lambda_name),
             _ => None,
         }
     }
@@ -751,7 +757,11 @@ impl<'a> TrmcEnv<'a> {
         let hole_symbol = env.named_unique_symbol("hole");
         let head_symbol = env.named_unique_symbol("head");
 
-        let jump_stmt = Stmt::Jump(joinpoint_id, jump_arguments.into_bump_slice());
+        let jump_stmt = Stmt::Jump(
+            joinpoint_id,
+            jump_arguments.into_bump_slice(),
+            Region::zero(),
+        );
 
         let trmc_calls = trmc_calls.confirmed().map(|s| (s, None)).collect();
 
@@ -782,6 +792,7 @@ impl<'a> TrmcEnv<'a> {
             parameters: joinpoint_parameters.into_bump_slice(),
             body: arena.alloc(this.walk_stmt(env, &proc.body)),
             remainder: arena.alloc(jump_stmt),
+            region: Region::zero(),
         };
 
         let body = let_null(arena.alloc(
@@ -803,6 +814,7 @@ impl<'a> TrmcEnv<'a> {
             ret_layout: proc.ret_layout,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: proc.is_erased,
+            regions: proc.regions,
         }
     }
 
@@ -829,6 +841,12 @@ impl<'a> TrmcEnv<'a> {
                     return self.walk_stmt(env, next);
                 }
 
+                // the region of the return that the rewritten calls below stand in for
+                let ret_region = match next {
+                    Stmt::Ret(_, region) => *region,
+                    _ => Region::zero(),
+                };
+
                 if let Some(call) =
                     Self::is_tail_recursive_call(self.lambda_name, *symbol, expr, next)
                 {
@@ -838,7 +856,8 @@ impl<'a> TrmcEnv<'a> {
                     arguments.push(self.hole_symbol);
                     arguments.push(self.head_symbol);
 
-                    let jump = Stmt::Jump(self.joinpoint_id, arguments.into_bump_slice());
+                    let jump =
+                        Stmt::Jump(self.joinpoint_id, arguments.into_bump_slice(), ret_region);
 
                     return jump;
                 }
@@ -861,7 +880,7 @@ impl<'a> TrmcEnv<'a> {
 
                             let output = define_tag(arena.alloc(
                                 //
-                                self.non_trmc_return(env, *symbol),
+                                self.non_trmc_return(env, *symbol, ret_region),
                             ));
 
                             return output;
@@ -932,8 +951,11 @@ impl<'a> TrmcEnv<'a> {
                             jump_arguments.push(new_hole_symbol);
                             jump_arguments.push(self.head_symbol);
 
-                            let jump =
-                                Stmt::Jump(self.joinpoint_id, jump_arguments.into_bump_slice());
+                            let jump = Stmt::Jump(
+                                self.joinpoint_id,
+                                jump_arguments.into_bump_slice(),
+                                ret_region,
+                            );
 
                             let output = let_tag_arg_null(arena.alloc(
                                 //
@@ -965,6 +987,7 @@ impl<'a> TrmcEnv<'a> {
                 branches,
                 default_branch,
                 ret_layout,
+                region,
             } => {
                 let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
 
@@ -982,12 +1005,13 @@ impl<'a> TrmcEnv<'a> {
                     branches: arena.alloc(new_branches.into_bump_slice()),
                     default_branch: (default_branch.0.clone(), new_default_branch),
                     ret_layout: *ret_layout,
+                    region: *region,
                 }
             }
-            Stmt::Ret(symbol) => {
+            Stmt::Ret(symbol, region) => {
                 // write the symbol we're supposed to return into the hole
                 // then read initial_symbol and return its contents
-                self.non_trmc_return(env, *symbol)
+                self.non_trmc_return(env, *symbol, *region)
             }
             Stmt::Refcounting(op, next) => {
                 let new_next = self.walk_stmt(env, next);
@@ -1033,6 +1057,7 @@ impl<'a> TrmcEnv<'a> {
                 parameters,
                 body,
                 remainder,
+                region,
             } => {
                 let new_body = self.walk_stmt(env, body);
                 let new_remainder = self.walk_stmt(env, remainder);
@@ -1042,14 +1067,20 @@ impl<'a> TrmcEnv<'a> {
                     parameters,
                     body: arena.alloc(new_body),
                     remainder: arena.alloc(new_remainder),
+                    region: *region,
                 }
             }
-            Stmt::Jump(id, arguments) => Stmt::Jump(*id, arguments),
+            Stmt::Jump(id, arguments, region) => Stmt::Jump(*id, arguments, *region),
             Stmt::Crash(symbol, crash_tag) => Stmt::Crash(*symbol, *crash_tag),
         }
     }
 
-    fn non_trmc_return(&mut self, env: &mut Env<'a, '_>, value_symbol: Symbol) -> Stmt<'a> {
+    fn non_trmc_return(
+        &mut self,
+        env: &mut Env<'a, '_>,
+        value_symbol: Symbol,
+        region: Region,
+    ) -> Stmt<'a> {
         let arena = env.arena;
         let layout = self.return_layout;
 
@@ -1071,7 +1102,7 @@ impl<'a> TrmcEnv<'a> {
             value_symbol,
             arena.alloc(
                 //
-                ptr_load(arena.alloc(Stmt::Ret(final_symbol))),
+                ptr_load(arena.alloc(Stmt::Ret(final_symbol, region))),
             ),
         )
     }
@@ -1114,7 +1145,7 @@ fn stmt_contains_symbol_nonrec(stmt: &Stmt, needle: Symbol) -> bool {
     match stmt {
         Stmt::Let(_, expr, _, _) => expr_contains_symbol(expr, needle),
         Stmt::Switch { cond_symbol, .. } => needle == *cond_symbol,
-        Stmt::Ret(symbol) => needle == *symbol,
+        Stmt::Ret(symbol, _) => needle == *symbol,
         Stmt::Refcounting(modify, _) => {
             matches!( modify, Inc(symbol, _) | Dec(symbol) | DecRef(symbol)  if needle == *symbol  )
         }
@@ -1126,7 +1157,7 @@ fn stmt_contains_symbol_nonrec(stmt: &Stmt, needle: Symbol) -> bool {
         } => needle == *condition || lookups.contains(&needle),
         Stmt::Dbg { symbol, .. } => needle == *symbol,
         Stmt::Join { .. } => false,
-        Stmt::Jump(_, arguments) => arguments.contains(&needle),
+        Stmt::Jump(_, arguments, _) => arguments.contains(&needle),
         Stmt::Crash(symbol, _) => needle == *symbol,
     }
 }
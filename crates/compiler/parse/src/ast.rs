@@ -448,6 +448,14 @@ pub enum ValueDef<'a> {
         condition: &'a Loc<Expr<'a>>,
         preceding_comment: Region,
     },
+
+    /// A top-level def that didn't parse, kept as its source so the defs around it stay usable.
+    /// Only parsing that recovers from syntax errors produces these.
+    Malformed {
+        source: &'a str,
+        /// The name being defined, when the def starts out like `name = `
+        name: Option<Loc<&'a str>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -1782,6 +1790,7 @@ impl<'a> Malformed for ValueDef<'a> {
                 condition,
                 preceding_comment: _,
             } => condition.is_malformed(),
+            ValueDef::Malformed { .. } => true,
         }
     }
 }
@@ -942,83 +942,77 @@ fn parse_defs_end<'a>(
 
         global_state = match parse_single_def(options, min_indent, arena, state) {
             Ok((_, Some(single_def), next_state)) => {
-                let region = single_def.region;
-                let spaces_before_current = single_def.spaces_before;
+                push_single_def(arena, &mut defs, single_def);
 
-                match single_def.type_or_value {
-                    Either::First(type_def) => {
-                        defs.push_type_def(type_def, region, spaces_before_current, &[]);
-                    }
-                    Either::Second(value_def) => {
-                        // If we got a ValueDef::Body, check if a type annotation preceded it.
-                        // If so, we may need to combine them into an AnnotatedBody.
-                        let joined = match value_def {
-                            ValueDef::Body(loc_pattern, loc_def_expr)
-                                if spaces_before_current.len() <= 1 =>
-                            {
-                                let region =
-                                    Region::span_across(&loc_pattern.region, &loc_def_expr.region);
-
-                                match defs.last() {
-                                    Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
-                                        let (value_def, region) = join_ann_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            ann_pattern,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
-
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
-
-                                        true
-                                    }
-                                    Some(Ok(TypeDef::Alias {
-                                        header,
-                                        ann: ann_type,
-                                    })) => {
-                                        let (value_def, region) = join_alias_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            header,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
-
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
-
-                                        true
-                                    }
-                                    _ => false,
-                                }
-                            }
-                            _ => false,
-                        };
+                next_state
+            }
+            Ok((progress, None, s)) => return Ok((progress, defs, s)),
+            Err((progress, err)) => return Err((progress, err)),
+        };
+    }
+}
+
+/// Adds a def from [parse_single_def] to `defs`, joining a body to the annotation right before it.
+pub fn push_single_def<'a>(arena: &'a Bump, defs: &mut Defs<'a>, single_def: SingleDef<'a>) {
+    let region = single_def.region;
+    let spaces_before_current = single_def.spaces_before;
 
-                        if !joined {
-                            // the previous and current def can't be joined up
-                            defs.push_value_def(value_def, region, spaces_before_current, &[]);
+    match single_def.type_or_value {
+        Either::First(type_def) => {
+            defs.push_type_def(type_def, region, spaces_before_current, &[]);
+        }
+        Either::Second(value_def) => {
+            // If we got a ValueDef::Body, check if a type annotation preceded it.
+            // If so, we may need to combine them into an AnnotatedBody.
+            let joined = match value_def {
+                ValueDef::Body(loc_pattern, loc_def_expr) if spaces_before_current.len() <= 1 => {
+                    let region = Region::span_across(&loc_pattern.region, &loc_def_expr.region);
+
+                    match defs.last() {
+                        Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
+                            let (value_def, region) = join_ann_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                ann_pattern,
+                                ann_type,
+                                spaces_before_current,
+                                region
+                            );
+
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
+
+                            true
+                        }
+                        Some(Ok(TypeDef::Alias {
+                            header,
+                            ann: ann_type,
+                        })) => {
+                            let (value_def, region) = join_alias_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                header,
+                                ann_type,
+                                spaces_before_current,
+                                region
+                            );
+
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
+
+                            true
                         }
+                        _ => false,
                     }
                 }
+                _ => false,
+            };
 
-                next_state
+            if !joined {
+                // the previous and current def can't be joined up
+                defs.push_value_def(value_def, region, spaces_before_current, &[]);
             }
-            Ok((progress, None, s)) => return Ok((progress, defs, s)),
-            Err((progress, err)) => return Err((progress, err)),
-        };
+        }
     }
 }
 
@@ -1,5 +1,6 @@
-use crate::ast::{Collection, Defs, Header, Module, Spaced, Spaces};
+use crate::ast::{Collection, Defs, Header, Module, Pattern, Spaced, Spaces, ValueDef};
 use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
+use crate::expr::{parse_single_def, push_single_def, ExprParseOptions};
 use crate::header::{
     package_entry, package_name, AppHeader, ExposedName, ExposesKeyword, GeneratesKeyword,
     HostedHeader, ImportsEntry, ImportsKeyword, InterfaceHeader, Keyword, KeywordItem, ModuleName,
@@ -10,13 +11,13 @@ use crate::ident::{self, lowercase_ident, unqualified_ident, uppercase, Uppercas
 use crate::parser::Progress::{self, *};
 use crate::parser::{
     backtrackable, increment_min_indent, optional, reset_min_indent, specialize, word1, word2,
-    EExposes, EGenerates, EGeneratesWith, EHeader, EImports, EPackages, EProvides, ERequires,
-    ETypedIdent, Parser, SourceError, SpaceProblem, SyntaxError,
+    EExposes, EExpr, EGenerates, EGeneratesWith, EHeader, EImports, EPackages, EProvides,
+    ERequires, ETypedIdent, Parser, SourceError, SpaceProblem, SyntaxError,
 };
 use crate::state::State;
 use crate::string_literal::{self, parse_str_literal};
 use crate::type_annotation;
-use roc_region::all::{Loc, Position};
+use roc_region::all::{Loc, Position, Region};
 
 fn end_of_file<'a>() -> impl Parser<'a, (), SyntaxError<'a>> {
     |_arena, state: State<'a>, _min_indent: u32| {
@@ -36,6 +37,196 @@ pub fn module_defs<'a>() -> impl Parser<'a, Defs<'a>, SyntaxError<'a>> {
     )
 }
 
+/// Parses the defs of a module body like [module_defs], but recovers from syntax errors. A
+/// top-level def that doesn't parse becomes a [ValueDef::Malformed], and parsing carries on with
+/// the defs after it.
+/// Returns the defs, and the error of each top-level def that didn't parse.
+///
+/// Defs are parsed one at a time. When one doesn't parse, we skip to the next line that starts
+/// a top-level def: one that starts with something other than whitespace or a comment, and isn't
+/// part of a multi-line string.
+pub fn module_defs_recovering<'a>(
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
+) -> (Defs<'a>, Vec<SyntaxError<'a>>) {
+    if let Ok((_, defs, _)) = module_defs().parse(arena, state.clone(), 0) {
+        return (defs, Vec::new());
+    }
+
+    let bytes = state.original_bytes();
+    let def_starts = toplevel_def_starts(bytes, state.pos().offset as usize);
+    let options = ExprParseOptions {
+        accept_multi_backpassing: true,
+        check_for_arrow: true,
+    };
+
+    let mut defs = Defs::default();
+    let mut errors = Vec::new();
+    let mut state = state;
+
+    loop {
+        let def_start = match space0_e(EExpr::IndentStart).parse(arena, state.clone(), 0) {
+            Ok((_, _, after_spaces)) if after_spaces.has_reached_end() => break,
+            Ok((_, _, after_spaces)) => after_spaces.pos(),
+            Err(_) => state.pos(),
+        };
+
+        let error = match parse_single_def(options, 0, arena, state.clone()) {
+            Ok((_, Some(single_def), next_state)) => {
+                // Like in `module_defs`, the next def has to start on a line of its own
+                match space0_e(EExpr::IndentEnd).parse(arena, next_state.clone(), 0) {
+                    Ok((_, _, after)) if after.has_reached_end() || after.column() == 0 => {
+                        push_single_def(arena, &mut defs, single_def);
+                        state = next_state;
+
+                        continue;
+                    }
+                    Ok((_, _, after)) => SyntaxError::NotEndOfFile(after.pos()),
+                    Err((_, fail)) => SyntaxError::Expr(fail, next_state.pos()),
+                }
+            }
+            Ok((_, None, _)) => SyntaxError::NotEndOfFile(def_start),
+            Err((_, fail)) => SyntaxError::Expr(fail, def_start),
+        };
+
+        errors.push(error);
+
+        let start = def_start.offset as usize;
+        let end = def_starts
+            .iter()
+            .copied()
+            .find(|def_start| *def_start > start)
+            .unwrap_or(bytes.len());
+
+        push_malformed_def(&mut defs, bytes, start..end);
+        state = skip_to(state, end);
+    }
+
+    (defs, errors)
+}
+
+fn push_malformed_def<'a>(defs: &mut Defs<'a>, bytes: &'a [u8], range: std::ops::Range<usize>) {
+    let Ok(source) = std::str::from_utf8(&bytes[range.clone()]) else {
+        return;
+    };
+    let source = source.trim_end();
+    let region = Region::new(
+        Position::new(range.start as u32),
+        Position::new((range.start + source.len()) as u32),
+    );
+
+    // An annotation that parsed still defines the name, so the malformed def doesn't also.
+    let name = malformed_def_name(source, range.start).filter(|name| {
+        !defs.value_defs.iter().any(|def| {
+            matches!(
+                def,
+                ValueDef::Annotation(Loc { value: Pattern::Identifier(ident), .. }, _)
+                    if *ident == name.value
+            )
+        })
+    });
+
+    defs.push_value_def(ValueDef::Malformed { source, name }, region, &[], &[]);
+}
+
+/// Moves `state` forward to `offset`, which is either the start of a line or the end of the input.
+fn skip_to(state: State<'_>, offset: usize) -> State<'_> {
+    let current = state.pos().offset as usize;
+
+    if offset == state.original_bytes().len() {
+        state.advance(offset - current)
+    } else {
+        state
+            .advance(offset - 1 - current)
+            .advance_newline()
+            .mark_current_indent()
+    }
+}
+
+/// The name a malformed def at `offset` was defining, if it starts out like `name = `.
+fn malformed_def_name(source: &str, offset: usize) -> Option<Loc<&str>> {
+    let name_len = source
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(source.len());
+    let name = &source[..name_len];
+    let rest = source[name_len..].trim_start_matches([' ', '\t']);
+
+    let is_name = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && !crate::keyword::KEYWORDS.contains(&name);
+
+    if is_name && rest.starts_with('=') && !rest.starts_with("==") {
+        let region = Region::new(
+            Position::new(offset as u32),
+            Position::new((offset + name_len) as u32),
+        );
+
+        Some(Loc::at(region, name))
+    } else {
+        None
+    }
+}
+
+/// The offsets of the lines in a module body that could start a top-level def: ones that start
+/// with something other than whitespace or a comment. Lines inside a multi-line string don't
+/// count, even if they aren't indented.
+fn toplevel_def_starts(bytes: &[u8], body_start: usize) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut in_block_string = false;
+    let mut offset = body_start;
+
+    while offset < bytes.len() {
+        let rest = &bytes[offset..];
+
+        if in_block_string {
+            match rest {
+                [b'"', b'"', b'"', ..] => {
+                    in_block_string = false;
+                    offset += 3;
+                }
+                [b'\\', _, ..] => offset += 2,
+                _ => offset += 1,
+            }
+
+            continue;
+        }
+
+        let starts_line = offset > body_start && bytes[offset - 1] == b'\n';
+
+        if starts_line && !matches!(rest[0], b' ' | b'\t' | b'\r' | b'\n' | b'#') {
+            starts.push(offset);
+        }
+
+        match rest {
+            [b'#', ..] => {
+                offset += rest.iter().position(|b| *b == b'\n').unwrap_or(rest.len());
+            }
+            [b'"', b'"', b'"', ..] => {
+                in_block_string = true;
+                offset += 3;
+            }
+            [quote @ (b'"' | b'\''), ..] => {
+                // Single-line string and character literals end at the line at the latest
+                offset += 1;
+
+                while let Some(byte) = bytes.get(offset) {
+                    match byte {
+                        b'\n' => break,
+                        b'\\' => offset += 2,
+                        _ if byte == quote => {
+                            offset += 1;
+                            break;
+                        }
+                        _ => offset += 1,
+                    }
+                }
+            }
+            _ => offset += 1,
+        }
+    }
+
+    starts
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
@@ -22,7 +22,7 @@ mod test_parse {
     use roc_parse::ast::StrSegment::*;
     use roc_parse::ast::{self, EscapedChar};
     use roc_parse::ast::{CommentOrNewline, StrLiteral::*};
-    use roc_parse::module::module_defs;
+    use roc_parse::module::{module_defs, module_defs_recovering};
     use roc_parse::parser::{Parser, SyntaxError};
     use roc_parse::state::State;
     use roc_parse::test_helpers::parse_expr_with;
@@ -317,6 +317,121 @@ mod test_parse {
         }
     }
 
+    #[test]
+    fn module_defs_recover_from_malformed_def() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            x = 1
+
+            y = (
+
+            z = 2
+            "#
+        );
+
+        let (defs, errors) = module_defs_recovering(arena, State::new(src.as_bytes()));
+
+        assert_eq!(defs.len(), 3);
+        assert_eq!(errors.len(), 1);
+
+        match defs.defs().nth(1) {
+            Some(Err(ast::ValueDef::Malformed { source, name })) => {
+                assert_eq!(*source, "y = (");
+                assert_eq!(name.map(|name| name.value), Some("y"));
+            }
+            other => panic!("expected the second def to be malformed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn module_defs_recovering_reports_every_malformed_def() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            a = [
+
+            b = 2
+
+            c = {
+
+            d = b
+            "#
+        );
+
+        let (defs, errors) = module_defs_recovering(arena, State::new(src.as_bytes()));
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(defs.len(), 4);
+        assert_eq!(
+            defs.value_defs
+                .iter()
+                .filter(|def| matches!(def, ast::ValueDef::Malformed { .. }))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn module_defs_recovering_keeps_annotations_with_their_bodies() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            x : I64
+            x = 1
+
+            y = ]
+
+            z : Str
+            z = "z"
+            "#
+        );
+
+        let (defs, errors) = module_defs_recovering(arena, State::new(src.as_bytes()));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(defs.len(), 3);
+        assert!(defs.value_defs.iter().all(|def| matches!(
+            def,
+            ast::ValueDef::AnnotatedBody { .. } | ast::ValueDef::Malformed { .. }
+        )));
+    }
+
+    #[test]
+    fn module_defs_recovering_skips_multiline_strings() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            greeting =
+                """
+            Hello,
+            name = "Roc"
+                """
+
+            x = 1
+            "#
+        );
+
+        let (defs, errors) = module_defs_recovering(arena, State::new(src.as_bytes()));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(defs.len(), 2);
+
+        match defs.defs().next() {
+            Some(Err(ast::ValueDef::Malformed { source, name })) => {
+                assert!(source.starts_with("greeting ="));
+                assert!(source.ends_with("\"\"\""));
+                assert_eq!(name.map(|name| name.value), Some("greeting"));
+            }
+            other => panic!("expected the first def to be malformed, got {other:?}"),
+        }
+
+        assert!(matches!(
+            defs.defs().nth(1),
+            Some(Err(ast::ValueDef::Body(..)))
+        ));
+    }
+
     #[test]
     fn parse_expr_size() {
         assert_eq!(std::mem::size_of::<roc_parse::ast::Expr>(), 40);
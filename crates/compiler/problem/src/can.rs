@@ -10,6 +10,8 @@ use roc_parse::pattern::PatternType;
 use roc_region::all::{Loc, Region};
 use roc_types::types::AliasKind;
 
+use crate::code::{self, ErrorCode};
+use crate::lint::Lint;
 use crate::Severity;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -211,6 +213,8 @@ pub enum Problem {
         filename: PathBuf,
         error: io::ErrorKind,
     },
+    /// A warning that a `# lint: deny` comment turned into an error
+    Denied(Lint, Box<Problem>),
 }
 
 impl Problem {
@@ -277,6 +281,136 @@ impl Problem {
             Problem::OverAppliedCrash { .. } => RuntimeError,
             Problem::DefsOnlyUsedInRecursion(_, _) => Warning,
             Problem::FileProblem { .. } => Fatal,
+            Problem::Denied(_, _) => RuntimeError,
+        }
+    }
+
+    /// The lint that this problem belongs to. Every warning is a lint.
+    pub fn lint(&self) -> Option<Lint> {
+        match self {
+            Problem::UnusedDef(_, _) => Some(Lint::UnusedDef),
+            Problem::UnusedImport(_, _) => Some(Lint::UnusedImport),
+            Problem::UnusedModuleImport(_, _) => Some(Lint::UnusedImport),
+            Problem::UnusedArgument(_, _, _, _) => Some(Lint::UnusedArgument),
+            Problem::UnusedBranchDef(_, _) => Some(Lint::UnusedDef),
+            Problem::PhantomTypeArgument { .. } => Some(Lint::UnusedAliasParameter),
+            Problem::DuplicateRecordFieldValue { .. } => Some(Lint::DuplicateRecordField),
+            Problem::DuplicateImplementsAbility { .. } => Some(Lint::DuplicateBoundAbility),
+            Problem::DuplicateImpl { .. } => Some(Lint::DuplicateImplementation),
+            Problem::NotAnAbility(_) => Some(Lint::NotAnAbility),
+            Problem::ImplementsNonRequired { .. } => Some(Lint::UnnecessaryImplementation),
+            Problem::NoIdentifiersIntroduced(_) => Some(Lint::NoIdentifiersIntroduced),
+            Problem::OverloadedSpecialization { .. } => Some(Lint::OverloadedSpecialization),
+            Problem::UnnecessaryOutputWildcard { .. } => Some(Lint::UnnecessaryOutputWildcard),
+            Problem::DefsOnlyUsedInRecursion(_, _) => Some(Lint::DefsOnlyUsedInRecursion),
+            Problem::ExposedButNotDefined(_)
+            | Problem::UnknownGeneratesWith(_)
+            | Problem::PrecedenceProblem(_)
+            | Problem::UnsupportedPattern(_, _)
+            | Problem::Shadowing { .. }
+            | Problem::CyclicAlias(..)
+            | Problem::BadRecursion(_)
+            | Problem::UnboundTypeVariable { .. }
+            | Problem::DuplicateRecordFieldType { .. }
+            | Problem::InvalidOptionalValue { .. }
+            | Problem::DuplicateTag { .. }
+            | Problem::RuntimeError(_)
+            | Problem::SignatureDefMismatch { .. }
+            | Problem::InvalidAliasRigid { .. }
+            | Problem::InvalidInterpolation(_)
+            | Problem::InvalidHexadecimal(_)
+            | Problem::InvalidUnicodeCodePt(_)
+            | Problem::NestedDatatype { .. }
+            | Problem::InvalidExtensionType { .. }
+            | Problem::AbilityHasTypeVariables { .. }
+            | Problem::ImplementsClauseIsNotAbility { .. }
+            | Problem::IllegalImplementsClause { .. }
+            | Problem::AbilityMemberMissingImplementsClause { .. }
+            | Problem::AbilityMemberMultipleBoundVars { .. }
+            | Problem::AbilityNotOnToplevel { .. }
+            | Problem::AbilityUsedAsType(_, _, _)
+            | Problem::NestedSpecialization(_, _)
+            | Problem::IllegalDerivedAbility(_)
+            | Problem::ImplementationNotFound { .. }
+            | Problem::NotAnAbilityMember { .. }
+            | Problem::OptionalAbilityImpl { .. }
+            | Problem::QualifiedAbilityImpl { .. }
+            | Problem::AbilityImplNotIdent { .. }
+            | Problem::DoesNotImplementAbility { .. }
+            | Problem::NotBoundInAllPatterns { .. }
+            | Problem::MultipleListRestPattern { .. }
+            | Problem::BadTypeArguments { .. }
+            | Problem::UnappliedCrash { .. }
+            | Problem::OverAppliedCrash { .. }
+            | Problem::FileProblem { .. }
+            | Problem::Denied(_, _) => None,
+        }
+    }
+
+    /// The stable code that this problem is reported with.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Problem::UnusedDef(_, _) | Problem::UnusedBranchDef(_, _) => code::UNUSED_DEF,
+            Problem::UnusedImport(_, _) | Problem::UnusedModuleImport(_, _) => code::UNUSED_IMPORT,
+            Problem::UnusedArgument(_, _, _, _) => code::UNUSED_ARGUMENT,
+            Problem::DefsOnlyUsedInRecursion(_, _) => code::DEFS_ONLY_USED_IN_RECURSION,
+            Problem::ExposedButNotDefined(_) => code::MISSING_DEFINITION,
+            Problem::UnknownGeneratesWith(_) => code::UNKNOWN_GENERATES_WITH,
+            Problem::PrecedenceProblem(_) => code::OPERATOR_PRECEDENCE,
+            Problem::UnsupportedPattern(_, _) => code::UNSUPPORTED_PATTERN,
+            Problem::Shadowing { .. } => code::DUPLICATE_NAME,
+            Problem::CyclicAlias(..) => code::CYCLIC_ALIAS,
+            Problem::BadRecursion(_) => code::CIRCULAR_DEF,
+            Problem::PhantomTypeArgument { .. } => code::UNUSED_ALIAS_PARAMETER,
+            Problem::UnboundTypeVariable { .. } => code::UNBOUND_TYPE_VARIABLE,
+            Problem::DuplicateRecordFieldValue { .. }
+            | Problem::DuplicateRecordFieldType { .. } => code::DUPLICATE_FIELD_NAME,
+            Problem::InvalidOptionalValue { .. } => code::BAD_OPTIONAL_VALUE,
+            Problem::DuplicateTag { .. } => code::DUPLICATE_TAG_NAME,
+            Problem::RuntimeError(runtime_error) => runtime_error.code(),
+            Problem::SignatureDefMismatch { .. } => code::SIGNATURE_DEF_MISMATCH,
+            Problem::InvalidAliasRigid { .. } => code::INVALID_ALIAS_RIGID,
+            Problem::InvalidInterpolation(_)
+            | Problem::InvalidHexadecimal(_)
+            | Problem::InvalidUnicodeCodePt(_) => code::INVALID_STRING_ESCAPE,
+            Problem::NestedDatatype { .. } => code::NESTED_DATATYPE,
+            Problem::InvalidExtensionType { .. } => code::INVALID_EXTENSION_TYPE,
+            Problem::AbilityHasTypeVariables { .. } => code::ABILITY_HAS_TYPE_VARIABLES,
+            Problem::ImplementsClauseIsNotAbility { .. } => {
+                code::IMPLEMENTS_CLAUSE_IS_NOT_AN_ABILITY
+            }
+            Problem::IllegalImplementsClause { .. } => code::ILLEGAL_IMPLEMENTS_CLAUSE,
+            Problem::DuplicateImplementsAbility { .. } => code::DUPLICATE_BOUND_ABILITY,
+            Problem::AbilityMemberMissingImplementsClause { .. } => {
+                code::ABILITY_MEMBER_MISSING_IMPLEMENTS_CLAUSE
+            }
+            Problem::AbilityMemberMultipleBoundVars { .. } => {
+                code::ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES
+            }
+            Problem::AbilityNotOnToplevel { .. } => code::ABILITY_NOT_ON_TOPLEVEL,
+            Problem::AbilityUsedAsType(_, _, _) => code::ABILITY_USED_AS_TYPE,
+            Problem::NestedSpecialization(_, _) => code::SPECIALIZATION_NOT_ON_TOPLEVEL,
+            Problem::IllegalDerivedAbility(_) => code::ILLEGAL_DERIVE,
+            Problem::ImplementationNotFound { .. } => code::IMPLEMENTATION_NOT_FOUND,
+            Problem::NotAnAbilityMember { .. } => code::NOT_AN_ABILITY_MEMBER,
+            Problem::OptionalAbilityImpl { .. } => code::OPTIONAL_ABILITY_IMPLEMENTATION,
+            Problem::QualifiedAbilityImpl { .. } => code::QUALIFIED_ABILITY_IMPLEMENTATION,
+            Problem::AbilityImplNotIdent { .. } => code::ABILITY_IMPLEMENTATION_NOT_IDENTIFIER,
+            Problem::DuplicateImpl { .. } => code::DUPLICATE_IMPLEMENTATION,
+            Problem::NotAnAbility(_) => code::NOT_AN_ABILITY,
+            Problem::ImplementsNonRequired { .. } => code::UNNECESSARY_IMPLEMENTATIONS,
+            Problem::DoesNotImplementAbility { .. } => code::INCOMPLETE_ABILITY_IMPLEMENTATION,
+            Problem::NotBoundInAllPatterns { .. } => code::NOT_BOUND_IN_ALL_PATTERNS,
+            Problem::NoIdentifiersIntroduced(_) => code::NO_IDENTIFIERS_INTRODUCED,
+            Problem::OverloadedSpecialization { .. } => code::OVERLOADED_SPECIALIZATION,
+            Problem::UnnecessaryOutputWildcard { .. } => code::UNNECESSARY_OUTPUT_WILDCARD,
+            Problem::MultipleListRestPattern { .. } => code::MULTIPLE_LIST_REST_PATTERNS,
+            Problem::BadTypeArguments { .. } => code::BAD_TYPE_ARGUMENTS,
+            Problem::UnappliedCrash { .. } | Problem::OverAppliedCrash { .. } => {
+                code::CRASH_ARGUMENTS
+            }
+            Problem::FileProblem { error, .. } => code::file_problem(*error),
+            Problem::Denied(_, problem) => problem.code(),
         }
     }
 
@@ -430,6 +564,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::NoImplementationNamed { .. })
             | Problem::FileProblem { .. }
             | Problem::ExposedButNotDefined(_) => None,
+            Problem::Denied(_, problem) => problem.region(),
         }
     }
 }
@@ -628,6 +763,50 @@ impl RuntimeError {
         }
     }
 
+    /// The stable code that this error is reported with.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            RuntimeError::Shadowing { .. } => code::DUPLICATE_NAME,
+            RuntimeError::InvalidOptionalValue { .. } => code::BAD_OPTIONAL_VALUE,
+            RuntimeError::UnsupportedPattern(_) => code::UNSUPPORTED_PATTERN,
+            RuntimeError::MalformedPattern(_, _) => code::MALFORMED_PATTERN,
+            RuntimeError::LookupNotInScope { .. } => code::UNRECOGNIZED_NAME,
+            RuntimeError::OpaqueNotDefined { .. } => code::OPAQUE_NOT_DEFINED,
+            RuntimeError::OpaqueOutsideScope { .. } => code::OPAQUE_DECLARED_OUTSIDE_SCOPE,
+            RuntimeError::OpaqueNotApplied(_) => code::OPAQUE_NOT_APPLIED,
+            RuntimeError::OpaqueAppliedToMultipleArgs(_) => code::OPAQUE_OVER_APPLIED,
+            RuntimeError::ValueNotExposed { .. } => code::VALUE_NOT_EXPOSED,
+            RuntimeError::ModuleNotImported { .. } => code::MODULE_NOT_IMPORTED,
+            RuntimeError::InvalidPrecedence(_, _) => code::OPERATOR_PRECEDENCE,
+            RuntimeError::MalformedIdentifier(_, _, _) | RuntimeError::MalformedTypeName(_, _) => {
+                code::MALFORMED_IDENTIFIER
+            }
+            RuntimeError::MalformedClosure(_) => code::MALFORMED_FUNCTION,
+            RuntimeError::InvalidRecordUpdate { .. } => code::BAD_RECORD_UPDATE,
+            RuntimeError::InvalidFloat(_, _, _) | RuntimeError::InvalidInt(_, _, _, _) => {
+                code::INVALID_NUMBER
+            }
+            RuntimeError::CircularDef(_) => code::CIRCULAR_DEF,
+            RuntimeError::InvalidInterpolation(_)
+            | RuntimeError::InvalidHexadecimal(_)
+            | RuntimeError::InvalidUnicodeCodePt(_) => code::INVALID_STRING_ESCAPE,
+            RuntimeError::ExposedButNotDefined(_) => code::MISSING_DEFINITION,
+            RuntimeError::EmptySingleQuote(_) | RuntimeError::MultipleCharsInSingleQuote(_) => {
+                code::INVALID_SINGLE_QUOTE
+            }
+            RuntimeError::DegenerateBranch(_) => code::DEGENERATE_BRANCH,
+            RuntimeError::MultipleRecordBuilders(_) => code::MULTIPLE_RECORD_BUILDERS,
+            RuntimeError::UnappliedRecordBuilder(_) => code::UNAPPLIED_RECORD_BUILDER,
+            // These are never reported to users
+            RuntimeError::UnresolvedTypeVar
+            | RuntimeError::ErroneousType
+            | RuntimeError::NonExhaustivePattern
+            | RuntimeError::NoImplementationNamed { .. }
+            | RuntimeError::NoImplementation
+            | RuntimeError::VoidValue => code::COMPILER_BUG,
+        }
+    }
+
     pub fn region(&self) -> Region {
         match self {
             RuntimeError::Shadowing { shadow, .. } => shadow.region,
@@ -0,0 +1,199 @@
+//! Stable codes for the kinds of problems the compiler reports, like `E0301`.
+//!
+//! Every report carries one of these, so that a diagnostic can be searched for, linked to, and
+//! explained at length with `roc explain`. A code must never be reused for a different kind of
+//! problem once it has been released; retired codes should be left as gaps in the numbering.
+//!
+//! The hundreds are grouped by the phase of the compiler that finds the problem:
+//!
+//! - `E00xx` bugs in the compiler itself
+//! - `E01xx` names, definitions, patterns and literals (canonicalization)
+//! - `E02xx` type annotations, aliases, opaque types and abilities (canonicalization)
+//! - `E03xx` type checking
+//! - `E04xx` syntax
+//! - `E05xx` modules, packages and files
+//! - `E06xx` `expect`s that fail at runtime
+use std::{fmt, io};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode(u16);
+
+impl ErrorCode {
+    pub const fn number(self) -> u16 {
+        self.0
+    }
+
+    /// Parses a code the way people are likely to type it: `E0301`, `e0301` or just `301`.
+    /// Returns `None` if that isn't one of the codes in [`ALL`].
+    pub fn parse(string: &str) -> Option<ErrorCode> {
+        let string = string.trim();
+        let digits = string
+            .strip_prefix('E')
+            .or_else(|| string.strip_prefix('e'))
+            .unwrap_or(string);
+
+        let number = digits.parse::<u16>().ok()?;
+
+        ALL.iter().copied().find(|code| code.0 == number)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "E{:04}", self.0)
+    }
+}
+
+/// The code for a problem reading a file, which depends on what went wrong
+pub fn file_problem(error: io::ErrorKind) -> ErrorCode {
+    match error {
+        io::ErrorKind::NotFound => FILE_NOT_FOUND,
+        io::ErrorKind::PermissionDenied => FILE_PERMISSION_DENIED,
+        _ => FILE_PROBLEM,
+    }
+}
+
+macro_rules! error_codes {
+    ($($name:ident = $number:literal,)*) => {
+        $(pub const $name: ErrorCode = ErrorCode($number);)*
+
+        /// Every code, in numeric order
+        pub const ALL: &[ErrorCode] = &[$($name,)*];
+    };
+}
+
+error_codes! {
+    COMPILER_BUG = 1,
+
+    UNUSED_DEF = 101,
+    UNUSED_IMPORT = 102,
+    UNUSED_ARGUMENT = 103,
+    DEFS_ONLY_USED_IN_RECURSION = 104,
+    MISSING_DEFINITION = 105,
+    UNKNOWN_GENERATES_WITH = 106,
+    DUPLICATE_NAME = 107,
+    UNRECOGNIZED_NAME = 108,
+    VALUE_NOT_EXPOSED = 109,
+    MODULE_NOT_IMPORTED = 110,
+    CIRCULAR_DEF = 111,
+    OPERATOR_PRECEDENCE = 112,
+    UNSUPPORTED_PATTERN = 113,
+    MALFORMED_PATTERN = 114,
+    NOT_BOUND_IN_ALL_PATTERNS = 115,
+    NO_IDENTIFIERS_INTRODUCED = 116,
+    MULTIPLE_LIST_REST_PATTERNS = 117,
+    MALFORMED_IDENTIFIER = 118,
+    MALFORMED_FUNCTION = 119,
+    DUPLICATE_FIELD_NAME = 120,
+    BAD_OPTIONAL_VALUE = 121,
+    BAD_RECORD_UPDATE = 122,
+    INVALID_NUMBER = 123,
+    INVALID_STRING_ESCAPE = 124,
+    INVALID_SINGLE_QUOTE = 125,
+    DEGENERATE_BRANCH = 126,
+    MULTIPLE_RECORD_BUILDERS = 127,
+    UNAPPLIED_RECORD_BUILDER = 128,
+    CRASH_ARGUMENTS = 129,
+
+    CYCLIC_ALIAS = 201,
+    UNUSED_ALIAS_PARAMETER = 202,
+    UNBOUND_TYPE_VARIABLE = 203,
+    DUPLICATE_TAG_NAME = 204,
+    SIGNATURE_DEF_MISMATCH = 205,
+    INVALID_ALIAS_RIGID = 206,
+    NESTED_DATATYPE = 207,
+    INVALID_EXTENSION_TYPE = 208,
+    BAD_TYPE_ARGUMENTS = 209,
+    OPAQUE_NOT_DEFINED = 210,
+    OPAQUE_DECLARED_OUTSIDE_SCOPE = 211,
+    OPAQUE_NOT_APPLIED = 212,
+    OPAQUE_OVER_APPLIED = 213,
+    UNNECESSARY_OUTPUT_WILDCARD = 214,
+    ABILITY_HAS_TYPE_VARIABLES = 220,
+    IMPLEMENTS_CLAUSE_IS_NOT_AN_ABILITY = 221,
+    ILLEGAL_IMPLEMENTS_CLAUSE = 222,
+    DUPLICATE_BOUND_ABILITY = 223,
+    ABILITY_MEMBER_MISSING_IMPLEMENTS_CLAUSE = 224,
+    ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES = 225,
+    ABILITY_NOT_ON_TOPLEVEL = 226,
+    ABILITY_USED_AS_TYPE = 227,
+    SPECIALIZATION_NOT_ON_TOPLEVEL = 228,
+    ILLEGAL_DERIVE = 229,
+    IMPLEMENTATION_NOT_FOUND = 230,
+    NOT_AN_ABILITY_MEMBER = 231,
+    OPTIONAL_ABILITY_IMPLEMENTATION = 232,
+    QUALIFIED_ABILITY_IMPLEMENTATION = 233,
+    ABILITY_IMPLEMENTATION_NOT_IDENTIFIER = 234,
+    DUPLICATE_IMPLEMENTATION = 235,
+    NOT_AN_ABILITY = 236,
+    UNNECESSARY_IMPLEMENTATIONS = 237,
+    INCOMPLETE_ABILITY_IMPLEMENTATION = 238,
+    OVERLOADED_SPECIALIZATION = 239,
+
+    // Calls with too many or too few arguments are reported as "TOO MANY ARGS" and "TOO FEW
+    // ARGS", but share this code: the type checker finds them as a mismatch between the type of
+    // the function and the type of the call, like any other.
+    TYPE_MISMATCH = 301,
+    PATTERN_TYPE_MISMATCH = 302,
+    CIRCULAR_TYPE = 303,
+    MISSING_ABILITY = 304,
+    UNSAFE_PATTERN = 305,
+    REDUNDANT_PATTERN = 306,
+    UNMATCHABLE_PATTERN = 307,
+    ILLEGAL_SPECIALIZATION = 308,
+    WRONG_SPECIALIZATION_TYPE = 309,
+    INGESTED_FILE_BAD_UTF8 = 310,
+    INVALID_INGESTED_FILE_TYPE = 311,
+
+    SYNTAX_PROBLEM = 401,
+    EXPRESSION_SYNTAX = 402,
+    FUNCTION_SYNTAX = 403,
+    STRING_SYNTAX = 404,
+    PARENTHESES_SYNTAX = 405,
+    LIST_SYNTAX = 406,
+    IF_SYNTAX = 407,
+    WHEN_SYNTAX = 408,
+    PATTERN_SYNTAX = 409,
+    RECORD_PATTERN_SYNTAX = 410,
+    LIST_PATTERN_SYNTAX = 411,
+    NUMBER_LITERAL_SYNTAX = 412,
+    TYPE_SYNTAX = 413,
+    RECORD_TYPE_SYNTAX = 414,
+    TAG_UNION_TYPE_SYNTAX = 415,
+    INLINE_ALIAS_SYNTAX = 416,
+    HEADER_SYNTAX = 417,
+    WHITESPACE_SYNTAX = 418,
+    ABILITY_SYNTAX = 419,
+
+    FILE_NOT_FOUND = 501,
+    FILE_PERMISSION_DENIED = 502,
+    FILE_PROBLEM = 503,
+    IMPORT_CYCLE = 504,
+    INCORRECT_MODULE_NAME = 505,
+    NO_PLATFORM = 506,
+    PACKAGE_DOWNLOAD_FAILED = 507,
+    INVALID_PACKAGE_URL = 508,
+
+    EXPECT_FAILED = 601,
+    EXPECT_PANICKED = 602,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ErrorCode, ALL, TYPE_MISMATCH};
+
+    #[test]
+    fn codes_are_unique_and_sorted() {
+        assert!(ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn display_and_parse() {
+        assert_eq!(TYPE_MISMATCH.to_string(), "E0301");
+        assert_eq!(ErrorCode::parse("E0301"), Some(TYPE_MISMATCH));
+        assert_eq!(ErrorCode::parse("e301"), Some(TYPE_MISMATCH));
+        assert_eq!(ErrorCode::parse("301"), Some(TYPE_MISMATCH));
+        assert_eq!(ErrorCode::parse("E9999"), None);
+        assert_eq!(ErrorCode::parse("TYPE MISMATCH"), None);
+    }
+}
@@ -0,0 +1,209 @@
+//! Lints are the kinds of problems that are only warnings by default. A project can allow them,
+//! so they aren't reported at all, or deny them, so they are reported as errors.
+//!
+//! Levels are set with comments like this one:
+//!
+//! ```text
+//! # lint: deny unused-def, unused-import
+//! ```
+//!
+//! Above a module's header, such a comment applies to the whole module (and, in the root module,
+//! to every module that is loaded). Directly above a def, it applies to just that def.
+
+/// Every kind of warning is a lint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A def (or a def in a `when` branch pattern) that is never used
+    UnusedDef,
+    /// Defs that are only used by each other, recursively
+    DefsOnlyUsedInRecursion,
+    /// An import, or an imported module, that is never used
+    UnusedImport,
+    /// A function argument that is never used
+    UnusedArgument,
+    /// A def whose pattern doesn't introduce any identifiers, like `_ = 1`
+    NoIdentifiersIntroduced,
+    /// A record literal that gives the same field a value more than once
+    DuplicateRecordField,
+    /// A type alias parameter that isn't used in the alias
+    UnusedAliasParameter,
+    /// A `*` in the output of a function type that isn't needed
+    UnnecessaryOutputWildcard,
+    /// An ability that appears more than once in the same `implements` clause
+    DuplicateBoundAbility,
+    /// An ability member that is implemented more than once
+    DuplicateImplementation,
+    /// A name in an `implements` list that isn't an ability
+    NotAnAbility,
+    /// Implementations of an ability that the type doesn't declare it implements
+    UnnecessaryImplementation,
+    /// An ability member specialized for the same type more than once
+    OverloadedSpecialization,
+    /// A `when` branch that can never be reached because earlier branches match everything it does
+    RedundantBranch,
+    /// A pattern that can never match, because of the type of what it's matched against
+    UnmatchablePattern,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 15] = [
+        Lint::UnusedDef,
+        Lint::DefsOnlyUsedInRecursion,
+        Lint::UnusedImport,
+        Lint::UnusedArgument,
+        Lint::NoIdentifiersIntroduced,
+        Lint::DuplicateRecordField,
+        Lint::UnusedAliasParameter,
+        Lint::UnnecessaryOutputWildcard,
+        Lint::DuplicateBoundAbility,
+        Lint::DuplicateImplementation,
+        Lint::NotAnAbility,
+        Lint::UnnecessaryImplementation,
+        Lint::OverloadedSpecialization,
+        Lint::RedundantBranch,
+        Lint::UnmatchablePattern,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedDef => "unused-def",
+            Lint::DefsOnlyUsedInRecursion => "defs-only-used-in-recursion",
+            Lint::UnusedImport => "unused-import",
+            Lint::UnusedArgument => "unused-argument",
+            Lint::NoIdentifiersIntroduced => "no-identifiers-introduced",
+            Lint::DuplicateRecordField => "duplicate-record-field",
+            Lint::UnusedAliasParameter => "unused-alias-parameter",
+            Lint::UnnecessaryOutputWildcard => "unnecessary-output-wildcard",
+            Lint::DuplicateBoundAbility => "duplicate-bound-ability",
+            Lint::DuplicateImplementation => "duplicate-implementation",
+            Lint::NotAnAbility => "not-an-ability",
+            Lint::UnnecessaryImplementation => "unnecessary-implementation",
+            Lint::OverloadedSpecialization => "overloaded-specialization",
+            Lint::RedundantBranch => "redundant-branch",
+            Lint::UnmatchablePattern => "unmatchable-pattern",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintLevel {
+    /// Don't report the problem at all
+    Allow,
+    /// Report the problem as a warning, which is the default
+    Warn,
+    /// Report the problem as an error
+    Deny,
+}
+
+impl LintLevel {
+    pub fn name(self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<LintLevel> {
+        [LintLevel::Allow, LintLevel::Warn, LintLevel::Deny]
+            .into_iter()
+            .find(|level| level.name() == name)
+    }
+}
+
+/// The levels some lints were set to; lints that weren't set fall back to an outer scope.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LintLevels {
+    levels: [Option<LintLevel>; Lint::ALL.len()],
+}
+
+impl LintLevels {
+    pub fn get(&self, lint: Lint) -> Option<LintLevel> {
+        self.levels[lint.index()]
+    }
+
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        self.levels[lint.index()] = Some(level);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.iter().all(Option::is_none)
+    }
+
+    /// Applies a `# lint: deny unused-def, unused-import` comment, given its text after the `#`.
+    /// Returns `false`, changing nothing, if it isn't such a comment.
+    pub fn set_from_comment(&mut self, comment: &str) -> bool {
+        match parse_lint_comment(comment) {
+            Some((level, lints)) => {
+                for lint in lints {
+                    self.set(lint, level);
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn parse_lint_comment(comment: &str) -> Option<(LintLevel, Vec<Lint>)> {
+    let directive = comment.trim_start().strip_prefix("lint:")?;
+
+    let mut words = directive
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|word| !word.is_empty());
+
+    let level = LintLevel::from_name(words.next()?)?;
+    let lints = words.map(Lint::from_name).collect::<Option<Vec<_>>>()?;
+
+    if lints.is_empty() {
+        None
+    } else {
+        Some((level, lints))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Lint, LintLevel, LintLevels};
+
+    #[test]
+    fn lint_comment() {
+        let mut levels = LintLevels::default();
+
+        assert!(levels.set_from_comment(" lint: deny unused-def, unused-import"));
+        assert!(levels.set_from_comment("lint: allow redundant-branch"));
+
+        assert_eq!(levels.get(Lint::UnusedDef), Some(LintLevel::Deny));
+        assert_eq!(levels.get(Lint::UnusedImport), Some(LintLevel::Deny));
+        assert_eq!(levels.get(Lint::UnusedArgument), None);
+        assert_eq!(levels.get(Lint::RedundantBranch), Some(LintLevel::Allow));
+    }
+
+    #[test]
+    fn not_a_lint_comment() {
+        let mut levels = LintLevels::default();
+
+        assert!(!levels.set_from_comment(" lint: allow users to log in"));
+        assert!(!levels.set_from_comment(" lint: deny"));
+        assert!(!levels.set_from_comment(" see lint: deny unused-def"));
+
+        assert!(levels.is_empty());
+    }
+
+    #[test]
+    fn lint_names() {
+        for lint in Lint::ALL {
+            assert_eq!(Lint::from_name(lint.name()), Some(lint));
+            assert_eq!(Lint::ALL[lint.index()], lint);
+        }
+    }
+}
@@ -135,6 +135,7 @@ pub enum PtrWidth {
 pub enum Architecture {
     Aarch32,
     Aarch64,
+    Riscv64,
     Wasm32,
     X86_32,
     X86_64,
@@ -145,7 +146,7 @@ impl Architecture {
         use Architecture::*;
 
         match self {
-            X86_64 | Aarch64 => PtrWidth::Bytes8,
+            X86_64 | Aarch64 | Riscv64 => PtrWidth::Bytes8,
             X86_32 | Aarch32 | Wasm32 => PtrWidth::Bytes4,
         }
     }
@@ -162,6 +163,7 @@ impl From<target_lexicon::Architecture> for Architecture {
             target_lexicon::Architecture::X86_32(_) => Architecture::X86_32,
             target_lexicon::Architecture::Aarch64(_) => Architecture::Aarch64,
             target_lexicon::Architecture::Arm(_) => Architecture::Aarch32,
+            target_lexicon::Architecture::Riscv64(_) => Architecture::Riscv64,
             target_lexicon::Architecture::Wasm32 => Architecture::Wasm32,
             _ => unreachable!("unsupported architecture"),
         }
@@ -179,6 +181,14 @@ pub enum Target {
     LinuxX64,
     #[strum(serialize = "linux-arm64")]
     LinuxArm64,
+    #[strum(serialize = "linux-riscv64")]
+    LinuxRiscv64,
+    #[strum(serialize = "linux-x64-musl")]
+    LinuxX64Musl,
+    #[strum(serialize = "linux-arm64-musl")]
+    LinuxArm64Musl,
+    #[strum(serialize = "linux-riscv64-musl")]
+    LinuxRiscv64Musl,
     #[strum(serialize = "macos-x64")]
     MacX64,
     #[strum(serialize = "macos-arm64")]
@@ -226,6 +236,34 @@ impl Target {
                 environment: Environment::Unknown,
                 binary_format: BinaryFormat::Elf,
             },
+            Target::LinuxRiscv64 => Triple {
+                architecture: Architecture::Riscv64(Riscv64Architecture::Riscv64gc),
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Linux,
+                environment: Environment::Unknown,
+                binary_format: BinaryFormat::Elf,
+            },
+            Target::LinuxX64Musl => Triple {
+                architecture: Architecture::X86_64,
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Linux,
+                environment: Environment::Musl,
+                binary_format: BinaryFormat::Elf,
+            },
+            Target::LinuxArm64Musl => Triple {
+                architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Linux,
+                environment: Environment::Musl,
+                binary_format: BinaryFormat::Elf,
+            },
+            Target::LinuxRiscv64Musl => Triple {
+                architecture: Architecture::Riscv64(Riscv64Architecture::Riscv64gc),
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Linux,
+                environment: Environment::Musl,
+                binary_format: BinaryFormat::Elf,
+            },
             Target::WinX32 => Triple {
                 architecture: Architecture::X86_32(X86_32Architecture::I386),
                 vendor: Vendor::Unknown,
@@ -290,16 +328,39 @@ pub fn get_target_triple_str(target: &target_lexicon::Triple) -> Option<&'static
             architecture: target_lexicon::Architecture::Wasm32,
             ..
         } => Some(Target::Wasm32.into()),
+        target_lexicon::Triple {
+            operating_system: target_lexicon::OperatingSystem::Linux,
+            architecture: target_lexicon::Architecture::X86_64,
+            environment: target_lexicon::Environment::Musl,
+            ..
+        } => Some(Target::LinuxX64Musl.into()),
         target_lexicon::Triple {
             operating_system: target_lexicon::OperatingSystem::Linux,
             architecture: target_lexicon::Architecture::X86_64,
             ..
         } => Some(Target::LinuxX64.into()),
+        target_lexicon::Triple {
+            operating_system: target_lexicon::OperatingSystem::Linux,
+            architecture: target_lexicon::Architecture::Aarch64(_),
+            environment: target_lexicon::Environment::Musl,
+            ..
+        } => Some(Target::LinuxArm64Musl.into()),
         target_lexicon::Triple {
             operating_system: target_lexicon::OperatingSystem::Linux,
             architecture: target_lexicon::Architecture::Aarch64(_),
             ..
         } => Some(Target::LinuxArm64.into()),
+        target_lexicon::Triple {
+            operating_system: target_lexicon::OperatingSystem::Linux,
+            architecture: target_lexicon::Architecture::Riscv64(_),
+            environment: target_lexicon::Environment::Musl,
+            ..
+        } => Some(Target::LinuxRiscv64Musl.into()),
+        target_lexicon::Triple {
+            operating_system: target_lexicon::OperatingSystem::Linux,
+            architecture: target_lexicon::Architecture::Riscv64(_),
+            ..
+        } => Some(Target::LinuxRiscv64.into()),
         target_lexicon::Triple {
             operating_system: target_lexicon::OperatingSystem::Darwin,
             architecture: target_lexicon::Architecture::Aarch64(_),
@@ -192,6 +192,38 @@ where
     (unsafe { VecMap::zip(keys, values) }, offset)
 }
 
+/// Strings are stored as a slice of their lengths, followed by all of their bytes
+pub fn serialize_strs<'a>(
+    strs: impl IntoIterator<Item = &'a str>,
+    writer: &mut impl Write,
+    written: usize,
+) -> io::Result<usize> {
+    let mut lengths: Vec<u32> = Vec::new();
+    let mut buf: Vec<u8> = Vec::new();
+
+    for string in strs {
+        lengths.push(string.len() as u32);
+        buf.extend_from_slice(string.as_bytes());
+    }
+
+    let written = serialize_slice(&lengths, writer, written)?;
+    serialize_slice(&buf, writer, written)
+}
+
+pub fn deserialize_strs(bytes: &[u8], length: usize, offset: usize) -> (Vec<&str>, usize) {
+    let (lengths, mut offset) = deserialize_slice::<u32>(bytes, length, offset);
+
+    let mut strs = Vec::with_capacity(length);
+    for string_length in lengths {
+        let string_bytes = &bytes[offset..][..*string_length as usize];
+        offset += string_bytes.len();
+
+        strs.push(std::str::from_utf8(string_bytes).unwrap());
+    }
+
+    (strs, offset)
+}
+
 unsafe fn slice_as_bytes<T>(slice: &[T]) -> &[u8] {
     let ptr = slice.as_ptr();
     let byte_length = std::mem::size_of_val(slice);
@@ -212,9 +244,9 @@ mod test {
     use roc_collections::{MutMap, VecMap, VecSet};
 
     use super::{
-        deserialize_map, deserialize_slice, deserialize_slice_of_slices, deserialize_vec,
-        deserialize_vec_map, serialize_map, serialize_slice, serialize_slice_of_slices,
-        serialize_vec_map,
+        deserialize_map, deserialize_slice, deserialize_slice_of_slices, deserialize_strs,
+        deserialize_vec, deserialize_vec_map, serialize_map, serialize_slice,
+        serialize_slice_of_slices, serialize_strs, serialize_vec_map,
     };
 
     #[test]
@@ -382,4 +414,17 @@ mod test {
         assert_eq!(out.unzip_slices(), input.unzip_slices());
         assert_eq!(size, buf.len());
     }
+
+    #[test]
+    fn serde_strs() {
+        let input = ["Str", "", "withCapacity", "🦅"];
+
+        let mut buf = vec![];
+        serialize_strs(input, &mut buf, 0).unwrap();
+        assert!(!buf.is_empty());
+
+        let (out, size) = deserialize_strs(&buf, 4, 0);
+        assert_eq!(out, input);
+        assert_eq!(size, buf.len());
+    }
 }
@@ -3,7 +3,12 @@ use std::{path::PathBuf, str::Utf8Error};
 
 use roc_can::expected::{Expected, PExpected};
 use roc_module::{ident::Lowercase, symbol::Symbol};
-use roc_problem::{can::CycleEntry, Severity};
+use roc_problem::{
+    can::CycleEntry,
+    code::{self, ErrorCode},
+    lint::Lint,
+    Severity,
+};
 use roc_region::all::Region;
 
 use roc_types::types::{Category, ErrorType, PatternCategory};
@@ -33,6 +38,8 @@ pub enum TypeError {
     },
     IngestedFileBadUtf8(Box<PathBuf>, Utf8Error),
     IngestedFileUnsupportedType(Box<PathBuf>, ErrorType),
+    /// A warning that a `# lint: deny` comment turned into an error
+    Denied(Lint, Box<TypeError>),
 }
 
 impl TypeError {
@@ -54,6 +61,55 @@ impl TypeError {
             TypeError::WrongSpecialization { .. } => RuntimeError,
             TypeError::IngestedFileBadUtf8(..) => Fatal,
             TypeError::IngestedFileUnsupportedType(..) => Fatal,
+            TypeError::Denied(..) => RuntimeError,
+        }
+    }
+
+    /// The lint that this problem belongs to. Every warning is a lint.
+    pub fn lint(&self) -> Option<Lint> {
+        use roc_exhaustive::Error;
+
+        match self {
+            TypeError::Exhaustive(Error::Redundant { .. }) => Some(Lint::RedundantBranch),
+            TypeError::Exhaustive(Error::Unmatchable { .. }) => Some(Lint::UnmatchablePattern),
+            TypeError::BadExpr(..)
+            | TypeError::BadPattern(..)
+            | TypeError::CircularType(..)
+            | TypeError::CircularDef(_)
+            | TypeError::UnexposedLookup(..)
+            | TypeError::UnfulfilledAbility(_)
+            | TypeError::BadExprMissingAbility(..)
+            | TypeError::BadPatternMissingAbility(..)
+            | TypeError::Exhaustive(Error::Incomplete(..))
+            | TypeError::StructuralSpecialization { .. }
+            | TypeError::WrongSpecialization { .. }
+            | TypeError::IngestedFileBadUtf8(..)
+            | TypeError::IngestedFileUnsupportedType(..)
+            | TypeError::Denied(..) => None,
+        }
+    }
+
+    /// The stable code that this problem is reported with.
+    pub fn code(&self) -> ErrorCode {
+        use roc_exhaustive::Error;
+
+        match self {
+            TypeError::BadExpr(..) => code::TYPE_MISMATCH,
+            TypeError::BadPattern(..) => code::PATTERN_TYPE_MISMATCH,
+            TypeError::CircularType(..) => code::CIRCULAR_TYPE,
+            TypeError::CircularDef(_) => code::CIRCULAR_DEF,
+            TypeError::UnexposedLookup(..) => code::VALUE_NOT_EXPOSED,
+            TypeError::UnfulfilledAbility(_)
+            | TypeError::BadExprMissingAbility(..)
+            | TypeError::BadPatternMissingAbility(..) => code::MISSING_ABILITY,
+            TypeError::Exhaustive(Error::Incomplete(..)) => code::UNSAFE_PATTERN,
+            TypeError::Exhaustive(Error::Redundant { .. }) => code::REDUNDANT_PATTERN,
+            TypeError::Exhaustive(Error::Unmatchable { .. }) => code::UNMATCHABLE_PATTERN,
+            TypeError::StructuralSpecialization { .. } => code::ILLEGAL_SPECIALIZATION,
+            TypeError::WrongSpecialization { .. } => code::WRONG_SPECIALIZATION_TYPE,
+            TypeError::IngestedFileBadUtf8(..) => code::INGESTED_FILE_BAD_UTF8,
+            TypeError::IngestedFileUnsupportedType(..) => code::INVALID_INGESTED_FILE_TYPE,
+            TypeError::Denied(_, e) => e.code(),
         }
     }
 
@@ -72,6 +128,7 @@ impl TypeError {
             TypeError::CircularDef(c) => c.first().map(|ce| ce.symbol_region),
             TypeError::IngestedFileBadUtf8(_, _) => None,
             TypeError::IngestedFileUnsupportedType(_, _) => None,
+            TypeError::Denied(_, e) => e.region(),
         }
     }
 }
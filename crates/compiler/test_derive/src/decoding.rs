@@ -33,6 +33,18 @@ test_key_eq! {
     same_tuple_fields_diff_types:
         v!((v!(U8), v!(U16),)), v!((v!(U32), v!(U64),))
 
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+
     list_list_diff_types:
         v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
     str_str:
@@ -49,6 +61,13 @@ test_key_neq! {
 
     different_tuple_arities:
         v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    different_tag_union_payload_sizes:
+        v!([ A v!(U8) ]), v!([ A v!(U8) v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
 }
 
 #[test]
@@ -101,6 +120,41 @@ fn derivable_record_with_record_ext() {
     );
 }
 
+#[test]
+fn empty_tag_union_derive_error() {
+    check_underivable(Decoder, v!(EMPTY_TAG_UNION), DeriveError::Underivable);
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        Decoder,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::Decoder(FlatDecodableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_able_var() {
+    check_derivable(
+        Decoder,
+        v!([ A v!(STR) ]a implements Symbol::DECODE_DECODER),
+        DeriveKey::Decoder(FlatDecodableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Decoder,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Decoder(FlatDecodableKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
 #[test]
 fn list() {
     derive_test(Decoder, v!(Symbol::LIST_LIST v!(STR)), |golden| {
@@ -231,3 +285,41 @@ fn tuple_2_fields() {
         )
     })
 }
+
+#[test]
+fn tag_two_labels_zero_args() {
+    derive_test(Decoder, v!([A, B]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A, B]
+        # Decoder [A, B] fmt where fmt implements DecoderFormatting
+        # List U8, fmt -[[custom(15)]]-> { rest : List U8, result : [Err [TooShort], Ok [A, B]] } where fmt implements DecoderFormatting
+        # Specialization lambda sets:
+        #   @<1>: [[custom(15)]]
+        #Derived.decoder_[A 0,B 0] =
+          custom
+            \#Derived.bytes, #Derived.fmt ->
+              decodeWith
+                #Derived.bytes
+                (tag
+                  \#Derived.tagName ->
+                    when #Derived.tagName is
+                      "A" ->
+                        Known (tuple
+                          { }
+                          \#Derived.stateRecord2, #Derived.index ->
+                            when #Derived.index is
+                              _ -> TooLong
+                          \#Derived.stateRecord -> Ok A)
+                      "B" ->
+                        Known (tuple
+                          { }
+                          \#Derived.stateRecord4, #Derived.index2 ->
+                            when #Derived.index2 is
+                              _ -> TooLong
+                          \#Derived.stateRecord3 -> Ok B)
+                      _ -> UnknownTag)
+                #Derived.fmt
+        "###
+        )
+    })
+}
@@ -0,0 +1,332 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use insta::assert_snapshot;
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_immediate, derive_test},
+    v,
+};
+use roc_derive_key::{inspect::FlatInspectableKey, DeriveBuiltin::ToInspector, DeriveKey};
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+// {{{ hash tests
+
+test_key_eq! {
+    ToInspector,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tuple:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16),))
+    same_tuple_fields_diff_types:
+        v!((v!(U8), v!(U16),)), v!((v!(U32), v!(U64),))
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+
+    list_list_diff_types:
+        v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
+    set_set_diff_types:
+        v!(Symbol::SET_SET v!(STR)), v!(Symbol::SET_SET v!(U8))
+    dict_dict_diff_types:
+        v!(Symbol::DICT_DICT v!(STR) v!(STR)), v!(Symbol::DICT_DICT v!(U8) v!(U8))
+    str_str:
+        v!(Symbol::STR_STR), v!(Symbol::STR_STR)
+
+    alias_eq_real_type:
+        v!(Symbol::ATTR_ATTR => v!([ True, False ])), v!([False, True])
+    diff_alias_same_real_type:
+        v!(Symbol::ATTR_ATTR => v!([ True, False ])), v!(Symbol::UNDERSCORE => v!([False, True]))
+
+    opaque_eq_real_type:
+        v!(@Symbol::ATTR_ATTR => v!([ True, False ])), v!([False, True])
+    diff_opaque_same_real_type:
+        v!(@Symbol::ATTR_ATTR => v!([ True, False ])), v!(@Symbol::UNDERSCORE => v!([False, True]))
+
+    opaque_real_type_eq_alias_real_type:
+        v!(@Symbol::ATTR_ATTR => v!([ True, False ])), v!(Symbol::UNDERSCORE => v!([False, True]))
+}
+
+test_key_neq! {
+    ToInspector,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tuple_arities:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
+    tuple_empty_vs_nonempty:
+        v!(EMPTY_TUPLE), v!((v!(U8),))
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+
+    list_vs_set:
+        v!(Symbol::LIST_LIST v!(U8)), v!(Symbol::SET_SET v!(U8))
+
+    same_alias_diff_real_type:
+        v!(Symbol::ATTR_ATTR => v!([ True, False ])), v!(Symbol::ATTR_ATTR => v!([ False, True, Maybe ]))
+    diff_alias_diff_real_type:
+        v!(Symbol::ATTR_ATTR => v!([ True, False ])), v!(Symbol::UNDERSCORE => v!([ False, True, Maybe ]))
+
+    same_opaque_diff_real_type:
+        v!(@Symbol::ATTR_ATTR => v!([ True, False ])), v!(@Symbol::ATTR_ATTR => v!([ False, True, Maybe ]))
+    diff_opaque_diff_real_type:
+        v!(@Symbol::ATTR_ATTR => v!([ True, False ])), v!(@Symbol::UNDERSCORE => v!([ False, True, Maybe ]))
+}
+
+// }}} hash tests
+
+// {{{ deriver tests
+
+#[test]
+fn immediates() {
+    check_immediate(ToInspector, v!(U8), Symbol::INSPECT_U8);
+    check_immediate(ToInspector, v!(U16), Symbol::INSPECT_U16);
+    check_immediate(ToInspector, v!(U32), Symbol::INSPECT_U32);
+    check_immediate(ToInspector, v!(U64), Symbol::INSPECT_U64);
+    check_immediate(ToInspector, v!(U128), Symbol::INSPECT_U128);
+    check_immediate(ToInspector, v!(I8), Symbol::INSPECT_I8);
+    check_immediate(ToInspector, v!(I16), Symbol::INSPECT_I16);
+    check_immediate(ToInspector, v!(I32), Symbol::INSPECT_I32);
+    check_immediate(ToInspector, v!(I64), Symbol::INSPECT_I64);
+    check_immediate(ToInspector, v!(I128), Symbol::INSPECT_I128);
+    check_immediate(ToInspector, v!(DEC), Symbol::INSPECT_DEC);
+    check_immediate(ToInspector, v!(F32), Symbol::INSPECT_F32);
+    check_immediate(ToInspector, v!(F64), Symbol::INSPECT_F64);
+    check_immediate(ToInspector, v!(NAT), Symbol::INSPECT_NAT);
+    check_immediate(ToInspector, v!(BOOL), Symbol::INSPECT_BOOL);
+    check_immediate(ToInspector, v!(STR), Symbol::INSPECT_STR);
+}
+
+#[test]
+fn collections() {
+    check_derivable(
+        ToInspector,
+        v!(Symbol::LIST_LIST v!(U8)),
+        DeriveKey::ToInspector(FlatInspectableKey::List()),
+    );
+    check_derivable(
+        ToInspector,
+        v!(Symbol::SET_SET v!(STR)),
+        DeriveKey::ToInspector(FlatInspectableKey::Set()),
+    );
+    check_derivable(
+        ToInspector,
+        v!(Symbol::DICT_DICT v!(STR) v!(U8)),
+        DeriveKey::ToInspector(FlatInspectableKey::Dict()),
+    );
+}
+
+#[test]
+fn derivable_empty_tuple() {
+    check_derivable(
+        ToInspector,
+        v!(EMPTY_TUPLE),
+        DeriveKey::ToInspector(FlatInspectableKey::Tuple(0)),
+    );
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        ToInspector,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::ToInspector(FlatInspectableKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_ext_flex_able_var() {
+    check_derivable(
+        ToInspector,
+        v!({ a: v!(STR), }a implements Symbol::INSPECT_TO_INSPECTOR),
+        DeriveKey::ToInspector(FlatInspectableKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        ToInspector,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::ToInspector(FlatInspectableKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        ToInspector,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::ToInspector(FlatInspectableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_able_var() {
+    check_derivable(
+        ToInspector,
+        v!([ A v!(STR) ]a implements Symbol::INSPECT_TO_INSPECTOR),
+        DeriveKey::ToInspector(FlatInspectableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        ToInspector,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::ToInspector(FlatInspectableKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(ToInspector, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # {} -[[toInspector_{}(0)]]-> Inspector f where f implements InspectFormatter
+        # {} -[[toInspector_{}(0)]]-> (f -[[custom(2) {}]]-> f) where f implements InspectFormatter
+        # Specialization lambda sets:
+        #   @<1>: [[toInspector_{}(0)]]
+        #   @<2>: [[custom(2) {}]]
+        #Derived.toInspector_{} =
+          \#Derived.rcd -> custom \#Derived.fmt -> apply (record []) #Derived.fmt
+        "###
+        )
+    })
+}
+
+#[test]
+fn one_field_record() {
+    derive_test(ToInspector, v!({ a: v!(U8), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8 }
+        # { a : val } -[[toInspector_{a}(0)]]-> Inspector f where f implements InspectFormatter, val implements Inspect
+        # { a : val } -[[toInspector_{a}(0)]]-> (f -[[custom(2) { a : val }]]-> f) where f implements InspectFormatter, val implements Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[toInspector_{a}(0)]]
+        #   @<2>: [[custom(2) { a : val }]] where val implements Inspect
+        #Derived.toInspector_{a} =
+          \#Derived.rcd ->
+            custom
+              \#Derived.fmt ->
+                apply
+                  (record [{ value: toInspector #Derived.rcd.a, key: "a" }])
+                  #Derived.fmt
+        "###
+        )
+    })
+}
+
+#[test]
+fn two_field_tuple() {
+    derive_test(ToInspector, v!((v!(U8), v!(STR),)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for ( U8, Str )*
+        # ( val, val1 )* -[[toInspector_(arity:2)(0)]]-> Inspector f where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+        # ( val, val1 )a -[[toInspector_(arity:2)(0)]]-> (f -[[custom(2) ( val, val1 )a]]-> f) where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[toInspector_(arity:2)(0)]]
+        #   @<2>: [[custom(2) ( val, val1 )*]] where val implements Inspect, val1 implements Inspect
+        #Derived.toInspector_(arity:2) =
+          \#Derived.tup ->
+            custom
+              \#Derived.fmt ->
+                apply
+                  (tuple [toInspector #Derived.tup.0, toInspector #Derived.tup.1])
+                  #Derived.fmt
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_two_args() {
+    derive_test(ToInspector, v!([A v!(U8) v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str]
+        # [A val val1] -[[toInspector_[A 2](0)]]-> Inspector f where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+        # [A val val1] -[[toInspector_[A 2](0)]]-> (f -[[custom(4) [A val val1]]]-> f) where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[toInspector_[A 2](0)]]
+        #   @<2>: [[custom(4) [A val val1]]] where val implements Inspect, val1 implements Inspect
+        #Derived.toInspector_[A 2] =
+          \#Derived.tag ->
+            custom
+              \#Derived.fmt ->
+                apply
+                  (when #Derived.tag is
+                    A #Derived.2 #Derived.3 ->
+                      tag "A" [toInspector #Derived.2, toInspector #Derived.3])
+                  #Derived.fmt
+        "###
+        )
+    })
+}
+
+#[test]
+fn recursive_tag_union() {
+    derive_test(
+        ToInspector,
+        v!([Nil, Cons v!(U8) v!(^lst) ] as lst),
+        |golden| {
+            assert_snapshot!(golden, @r###"
+            # derived for [Cons U8 $rec, Nil] as $rec
+            # [Cons val val1, Nil] -[[toInspector_[Cons 2,Nil 0](0)]]-> Inspector f where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+            # [Cons val val1, Nil] -[[toInspector_[Cons 2,Nil 0](0)]]-> (f -[[custom(4) [Cons val val1, Nil]]]-> f) where f implements InspectFormatter, val implements Inspect, val1 implements Inspect
+            # Specialization lambda sets:
+            #   @<1>: [[toInspector_[Cons 2,Nil 0](0)]]
+            #   @<2>: [[custom(4) [Cons val val1, Nil]]] where val implements Inspect, val1 implements Inspect
+            #Derived.toInspector_[Cons 2,Nil 0] =
+              \#Derived.tag ->
+                custom
+                  \#Derived.fmt ->
+                    apply
+                      (when #Derived.tag is
+                        Cons #Derived.2 #Derived.3 ->
+                          tag "Cons" [toInspector #Derived.2, toInspector #Derived.3]
+                        Nil -> tag "Nil" [])
+                      #Derived.fmt
+            "###
+            )
+        },
+    )
+}
+
+// }}} deriver tests
@@ -0,0 +1,59 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use crate::{util::check_single_lset_immediate, v};
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use roc_derive_key::DeriveBuiltin::Compare;
+
+#[test]
+fn immediates() {
+    // Everything is an immediate for `Ord`.
+    check_single_lset_immediate(Compare, v!(U8), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(U16), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(U32), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(U64), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(U128), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(I8), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(I16), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(I32), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(I64), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(I128), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(F64), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(Compare, v!(STR), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(
+        Compare,
+        v!(Symbol::LIST_LIST v!(U8)),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+    check_single_lset_immediate(
+        Compare,
+        v!(Symbol::LIST_LIST v!(STR)),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+    check_single_lset_immediate(
+        Compare,
+        v!({ b: v!(STR), a: v!(U8), }),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+    check_single_lset_immediate(Compare, v!(EMPTY_RECORD), Symbol::BOOL_STRUCTURAL_COMPARE);
+    check_single_lset_immediate(
+        Compare,
+        v!((v!(U8), v!(STR),)),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+    check_single_lset_immediate(
+        Compare,
+        v!([ A v!(U8) v!(STR), B v!(STR) ]),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+    check_single_lset_immediate(
+        Compare,
+        v!([ Nil, Cons v!(^lst)] as lst),
+        Symbol::BOOL_STRUCTURAL_COMPARE,
+    );
+}
@@ -4,5 +4,7 @@ mod decoding;
 mod encoding;
 mod eq;
 mod hash;
+mod inspect;
+mod ord;
 
 mod util;
@@ -1447,6 +1447,127 @@ fn decode_tuple_of_tuples() {
     )
 }
 
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tag_two_payloads() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [TotallyNotJson] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "{\"A\":[\"ab\",10]}" |> Decode.fromBytes TotallyNotJson.json is
+                    Ok (A "ab" 10u8) -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tags_without_payloads() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [TotallyNotJson] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "[{\"B\":[]},{\"A\":[]}]" |> Decode.fromBytes TotallyNotJson.json is
+                    Ok [B, A] -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_unknown_tag() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [TotallyNotJson] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "{\"C\":[]}" |> Decode.fromBytes TotallyNotJson.json is
+                    Ok (A "ab") -> "something went wrong"
+                    Ok B -> "something went wrong"
+                    Err (Leftover _) -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn encode_then_decode_tag_union() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Encode, TotallyNotJson] provides [main] to "./platform"
+
+            main =
+                tags = [A "ab" 10u8, B, C ("cd", 25u8)]
+                decoded = Encode.toBytes tags TotallyNotJson.json |> Decode.fromBytes TotallyNotJson.json
+
+                when decoded is
+                    Ok decodedTags if decodedTags == tags -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn encode_then_decode_recursive_tag_union() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Encode, TotallyNotJson] provides [main] to "./platform"
+
+            LinkedList : [Nil, Cons U8 LinkedList]
+
+            main =
+                list : LinkedList
+                list = Cons 1 (Cons 2 Nil)
+                decoded = Encode.toBytes list TotallyNotJson.json |> Decode.fromBytes TotallyNotJson.json
+
+                when decoded is
+                    Ok decodedList if decodedList == list -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
 #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
 mod hash {
     #[cfg(feature = "gen-llvm")]
@@ -2146,6 +2267,506 @@ mod eq {
     }
 }
 
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+mod ord {
+    #[cfg(feature = "gen-llvm")]
+    use crate::helpers::llvm::assert_evals_to;
+
+    #[cfg(feature = "gen-wasm")]
+    use crate::helpers::wasm::assert_evals_to;
+
+    use indoc::indoc;
+    use roc_std::{RocList, RocStr};
+
+    #[test]
+    fn compare_numbers() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    when (Bool.compare 1u8 2u8, Bool.compare 2.5f64 2.5f64, Bool.compare 3i64 2i64) is
+                        (LT, EQ, GT) -> "okay"
+                        _ -> "fail"
+                "#
+            ),
+            RocStr::from("okay"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn compare_strings_by_utf8_bytes() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    when (Bool.compare "abc" "abd", Bool.compare "ab" "abc", Bool.compare "b" "abc", Bool.compare "" "") is
+                        (LT, LT, GT, EQ) -> "okay"
+                        _ -> "fail"
+                "#
+            ),
+            RocStr::from("okay"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn compare_lists_lexicographically() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    when (Bool.compare [1u8, 2] [1, 3], Bool.compare [1u8, 2] [1], Bool.compare [] [1u8]) is
+                        (LT, GT, LT) -> "okay"
+                        _ -> "fail"
+                "#
+            ),
+            RocStr::from("okay"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn sort_records_by_field_name() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    [{ b: 1u64, a: 2u8 }, { b: 3, a: 1 }, { b: 0, a: 2 }]
+                    |> List.sortAsc
+                    |> List.map .b
+                "#
+            ),
+            RocList::from_slice(&[3u64, 0, 1]),
+            RocList<u64>
+        )
+    }
+
+    #[test]
+    fn sort_tuples() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    [(2u8, "b"), (1, "z"), (2, "a")]
+                    |> List.sortAsc
+                    |> List.map \(_, s) -> s
+                    |> Str.joinWith ""
+                "#
+            ),
+            RocStr::from("zab"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn sort_tags_by_name_then_payload() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                toU8 = \tag ->
+                    when tag is
+                        Apple n -> n
+                        Banana n -> 10 + n
+                        Cherry -> 20
+
+                main =
+                    [Cherry, Banana 2u8, Apple 3, Banana 1, Apple 1]
+                    |> List.sortAsc
+                    |> List.map toU8
+                "#
+            ),
+            RocList::from_slice(&[1u8, 3, 11, 12, 20]),
+            RocList<u8>
+        )
+    }
+
+    #[test]
+    fn sort_desc() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = List.sortDesc ["pear", "apple", "fig"] |> Str.joinWith ","
+                "#
+            ),
+            RocStr::from("pear,fig,apple"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn derive_ord_for_opaque() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Version := { major : U8, minor : U8 } implements [Eq, Ord]
+
+                main =
+                    [@Version { major: 1, minor: 2 }, @Version { major: 0, minor: 9 }, @Version { major: 1, minor: 0 }]
+                    |> List.sortAsc
+                    |> List.map \@Version { minor } -> minor
+                "#
+            ),
+            RocList::from_slice(&[9u8, 0, 2]),
+            RocList<u8>
+        )
+    }
+
+    #[test]
+    fn custom_ord_impl() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Reversed := U8 implements [Ord { compare: compareReversed }]
+
+                compareReversed = \@Reversed a, @Reversed b -> Num.compare b a
+
+                main =
+                    [@Reversed 1, @Reversed 3, @Reversed 2]
+                    |> List.sortAsc
+                    |> List.map \@Reversed n -> n
+                "#
+            ),
+            RocList::from_slice(&[3u8, 2, 1]),
+            RocList<u8>
+        )
+    }
+}
+
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+mod inspect {
+    #[cfg(feature = "gen-llvm")]
+    use crate::helpers::llvm::assert_evals_to;
+
+    #[cfg(feature = "gen-wasm")]
+    use crate::helpers::wasm::assert_evals_to;
+
+    use indoc::indoc;
+
+    const TEST_FORMATTER: &str = indoc!(
+        r#"
+        TFormatter := Str implements [InspectFormatter {
+            init: tInit,
+            list: tList,
+            set: tSet,
+            dict: tDict,
+            tag: tTag,
+            tuple: tTuple,
+            record: tRecord,
+            bool: tBool,
+            str: tStr,
+            opaque: tOpaque,
+            u8: tU8,
+            i8: tI8,
+            u16: tU16,
+            i16: tI16,
+            u32: tU32,
+            i32: tI32,
+            u64: tU64,
+            i64: tI64,
+            u128: tU128,
+            i128: tI128,
+            f32: tF32,
+            f64: tF64,
+            dec: tDec,
+            nat: tNat,
+        }]
+
+        write = \@TFormatter data, added -> @TFormatter (Str.concat data added)
+
+        # writes each of `elems` with `writeElem`, separated by ", "
+        writeSep = \f, elems, walkFn, writeElem ->
+            (f1, prependSep), elem <- walkFn elems (f, Bool.false)
+            f2 = if prependSep then write f1 ", " else f1
+            (writeElem f2 elem, Bool.true)
+
+        tInit = \{} -> @TFormatter ""
+
+        tList = \content, walkFn, toInspector ->
+            f0 <- Inspect.custom
+            write f0 "["
+            |> writeSep content walkFn (\f, elem -> Inspect.apply (toInspector elem) f)
+            |> .0
+            |> write "]"
+
+        tSet = \content, walkFn, toInspector ->
+            f0 <- Inspect.custom
+            write f0 "{"
+            |> writeSep content walkFn (\f, elem -> Inspect.apply (toInspector elem) f)
+            |> .0
+            |> write "}"
+
+        tDict = \content, walkFn, keyToInspector, valueToInspector ->
+            f0 <- Inspect.custom
+            write f0 "{"
+            |> \f1 ->
+                (f2, prependSep), key, value <- walkFn content (f1, Bool.false)
+                f3 = if prependSep then write f2 ", " else f2
+                Inspect.apply (keyToInspector key) f3
+                |> write ": "
+                |> \f4 -> (Inspect.apply (valueToInspector value) f4, Bool.true)
+            |> .0
+            |> write "}"
+
+        tTag = \name, fields ->
+            f0 <- Inspect.custom
+            if List.isEmpty fields then
+                write f0 name
+            else
+                write f0 "("
+                |> write name
+                |> \f1 ->
+                    f2, inspector <- List.walk fields f1
+                    Inspect.apply inspector (write f2 " ")
+                |> write ")"
+
+        tTuple = \fields ->
+            f0 <- Inspect.custom
+            write f0 "("
+            |> writeSep fields List.walk (\f, inspector -> Inspect.apply inspector f)
+            |> .0
+            |> write ")"
+
+        tRecord = \fields ->
+            f0 <- Inspect.custom
+            write f0 "{"
+            |> writeSep fields List.walk (\f, { key, value } -> Inspect.apply value (write f "\(key): "))
+            |> .0
+            |> write "}"
+
+        tBool = \b ->
+            f0 <- Inspect.custom
+            write f0 (if b then "true" else "false")
+
+        tStr = \s ->
+            f0 <- Inspect.custom
+            write f0 "\"\(s)\""
+
+        tOpaque = \s ->
+            f0 <- Inspect.custom
+            write f0 "<\(s)>"
+
+        tNum = \n ->
+            f0 <- Inspect.custom
+            write f0 (Num.toStr n)
+
+        tU8 = \n -> tNum n
+        tI8 = \n -> tNum n
+        tU16 = \n -> tNum n
+        tI16 = \n -> tNum n
+        tU32 = \n -> tNum n
+        tI32 = \n -> tNum n
+        tU64 = \n -> tNum n
+        tI64 = \n -> tNum n
+        tU128 = \n -> tNum n
+        tI128 = \n -> tNum n
+        tF32 = \n -> tNum n
+        tF64 = \n -> tNum n
+        tDec = \n -> tNum n
+        tNat = \n -> tNum n
+
+        tToStr = \@TFormatter data -> data
+        "#
+    );
+
+    fn build_test(input: &str) -> String {
+        format!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                {}
+
+                main =
+                    Inspect.inspect ({})
+                    |> tToStr
+                "#
+            ),
+            TEST_FORMATTER, input,
+        )
+    }
+
+    mod immediate {
+        use super::{assert_evals_to, build_test};
+        use roc_std::RocStr;
+
+        #[test]
+        fn bool() {
+            assert_evals_to!(&build_test("Bool.true"), RocStr::from("true"), RocStr)
+        }
+
+        #[test]
+        fn u8() {
+            assert_evals_to!(&build_test("254u8"), RocStr::from("254"), RocStr)
+        }
+
+        #[test]
+        fn i64() {
+            assert_evals_to!(&build_test("-2i64"), RocStr::from("-2"), RocStr)
+        }
+
+        #[test]
+        fn nat() {
+            assert_evals_to!(&build_test("15nat"), RocStr::from("15"), RocStr)
+        }
+
+        #[test]
+        fn f64() {
+            assert_evals_to!(&build_test("1.5f64"), RocStr::from("1.5"), RocStr)
+        }
+
+        #[test]
+        fn dec() {
+            assert_evals_to!(&build_test("1.5dec"), RocStr::from("1.5"), RocStr)
+        }
+
+        #[test]
+        fn string() {
+            assert_evals_to!(&build_test(r#""roc""#), RocStr::from(r#""roc""#), RocStr)
+        }
+    }
+
+    mod derived {
+        use super::{assert_evals_to, build_test, indoc, TEST_FORMATTER};
+        use roc_std::RocStr;
+
+        #[test]
+        fn empty_record() {
+            assert_evals_to!(&build_test("{}"), RocStr::from("{}"), RocStr)
+        }
+
+        #[test]
+        fn record_of_u8_and_str() {
+            assert_evals_to!(
+                &build_test(r#"{ a: 15u8, b: "bc" }"#),
+                RocStr::from(r#"{a: 15, b: "bc"}"#),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn nested_record() {
+            assert_evals_to!(
+                &build_test(r#"{ a: { b: 15u8 }, c: Bool.false }"#),
+                RocStr::from(r#"{a: {b: 15}, c: false}"#),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn tuple_of_u8_and_str() {
+            assert_evals_to!(
+                &build_test(r#"(15u8, "bc")"#),
+                RocStr::from(r#"(15, "bc")"#),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn tag_without_payload() {
+            assert_evals_to!(&build_test("A"), RocStr::from("A"), RocStr)
+        }
+
+        #[test]
+        fn tag_with_payloads() {
+            assert_evals_to!(
+                &build_test(r#"A 15u8 "bc""#),
+                RocStr::from(r#"(A 15 "bc")"#),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn list_of_u8() {
+            assert_evals_to!(
+                &build_test("[15u8, 23u8, 37u8]"),
+                RocStr::from("[15, 23, 37]"),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn list_of_records() {
+            assert_evals_to!(
+                &build_test(r#"[{ a: "x" }, { a: "y" }]"#),
+                RocStr::from(r#"[{a: "x"}, {a: "y"}]"#),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn recursive_tag_union() {
+            assert_evals_to!(
+                &format!(
+                    indoc!(
+                        r#"
+                        app "test" provides [main] to "./platform"
+
+                        {}
+
+                        ConsList : [Cons U8 ConsList, Nil]
+
+                        c : ConsList
+                        c = Cons 1 (Cons 2 Nil)
+
+                        main =
+                            Inspect.inspect c
+                            |> tToStr
+                        "#
+                    ),
+                    TEST_FORMATTER,
+                ),
+                RocStr::from("(Cons 1 (Cons 2 Nil))"),
+                RocStr
+            )
+        }
+
+        #[test]
+        fn derived_inspect_for_opaque_record() {
+            assert_evals_to!(
+                &format!(
+                    indoc!(
+                        r#"
+                        app "test" provides [main] to "./platform"
+
+                        {}
+
+                        Q := {{ a: U8, b: Str }} implements [Inspect]
+
+                        q = @Q {{ a: 15, b: "bc" }}
+
+                        main =
+                            Inspect.inspect q
+                            |> tToStr
+                        "#
+                    ),
+                    TEST_FORMATTER,
+                ),
+                RocStr::from(r#"{a: 15, b: "bc"}"#),
+                RocStr
+            )
+        }
+    }
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn issue_4772_weakened_monomorphic_destructure() {
@@ -3002,6 +3002,70 @@ fn list_sort_desc() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_sort_with_sorted_input() {
+    let expected: Vec<i64> = (0..2000).collect();
+
+    assert_evals_to!(
+        "List.range { start: At 0i64, end: Before 2000 } |> List.sortWith Num.compare",
+        RocList::from_slice(&expected),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_sort_with_reversed_input() {
+    let expected: Vec<i64> = (0..2000).collect();
+
+    assert_evals_to!(
+        "List.range { start: At 0i64, end: Before 2000 } |> List.reverse |> List.sortWith Num.compare",
+        RocList::from_slice(&expected),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_sort_with_is_stable() {
+    // Sort (key, position) pairs by key only; equal keys must keep their original positions.
+    let mut expected: Vec<(i64, i64)> = (0..500).map(|i| ((i * 7) % 3, i)).collect();
+    expected.sort_by_key(|(key, _)| *key);
+    let expected: Vec<i64> = expected
+        .into_iter()
+        .map(|(key, i)| key * 1000 + i)
+        .collect();
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+            List.range { start: At 0i64, end: Before 500 }
+            |> List.map \i -> (Num.rem (i * 7) 3, i)
+            |> List.sortWith \(a, _), (b, _) -> Num.compare a b
+            |> List.map \(key, i) -> key * 1000 + i
+            "#
+        ),
+        RocList::from_slice(&expected),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_sort_asc_and_desc_duplicate_heavy() {
+    assert_evals_to!(
+        "List.sortAsc [3, 1, 2, 3, 1, 2, 3, 1, 2, 2, 1, 3, 3, 2, 1, 1, 2, 3, 3, 1]",
+        RocList::from_slice(&[1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3]),
+        RocList<i64>
+    );
+    assert_evals_to!(
+        "List.sortDesc [3, 1, 2, 3, 1, 2, 3, 1, 2, 2, 1, 3, 3, 2, 1, 1, 2, 3, 3, 1]",
+        RocList::from_slice(&[3, 3, 3, 3, 3, 3, 3, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1]),
+        RocList<i64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_any() {
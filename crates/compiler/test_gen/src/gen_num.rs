@@ -3244,6 +3244,114 @@ fn num_to_str_dec() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_radix() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(r#"Num.toStrRadix 255u8 16"#, RocStr::from("ff"), RocStr);
+    assert_evals_to!(r#"Num.toStrRadix -10i32 2"#, RocStr::from("-1010"), RocStr);
+    assert_evals_to!(r#"Num.toStrRadix 0u64 8"#, RocStr::from("0"), RocStr);
+    assert_evals_to!(r#"Num.toStrRadix 1295 36"#, RocStr::from("zz"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrRadix Num.minI64 16"#,
+        RocStr::from("-8000000000000000"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrRadix Num.maxU128 16"#,
+        RocStr::from("ffffffffffffffffffffffffffffffff"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_radix_padded() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.toStrRadixPadded 10u8 2 8"#,
+        RocStr::from("00001010"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrRadixPadded -42i16 10 5"#,
+        RocStr::from("-00042"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrRadixPadded 255u32 16 1"#,
+        RocStr::from("ff"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_decimals() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.toStrDecimals 3.14159f64 2"#,
+        RocStr::from("3.14"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrDecimals -2.5f32 3"#,
+        RocStr::from("-2.500"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrDecimals 1234.75f64 0"#,
+        RocStr::from("1235"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrDecimals 3.14159dec 3"#,
+        RocStr::from("3.142"),
+        RocStr
+    );
+    assert_evals_to!(r#"Num.toStrDecimals -2.5dec 0"#, RocStr::from("-3"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrDecimals 0.1dec 20"#,
+        RocStr::from("0.10000000000000000000"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_scientific() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.toStrScientific 1234.5f64 2"#,
+        RocStr::from("1.23e3"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific 0.000123f32 1"#,
+        RocStr::from("1.2e-4"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific 1234.5dec 2"#,
+        RocStr::from("1.23e3"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific -0.00125dec 1"#,
+        RocStr::from("-1.3e-3"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific 9999dec 1"#,
+        RocStr::from("1.0e4"),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn u8_addition_greater_than_i8() {
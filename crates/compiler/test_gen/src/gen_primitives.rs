@@ -4630,3 +4630,47 @@ fn many_arguments() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn inline_branching_function() {
+    // small non-recursive functions like this get inlined when optimizing
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            clamp : I64 -> I64
+            clamp = \x -> if x > 10 then 10 else x
+
+            main : I64
+            main = clamp 4 + clamp 42
+            "#
+        ),
+        14,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn inline_known_tag() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            unwrapOr : [Ok I64, Err {}], I64 -> I64
+            unwrapOr = \result, default ->
+                when result is
+                    Ok x -> x
+                    Err _ -> default
+
+            main : I64
+            main = unwrapOr (Ok 3) 0 + unwrapOr (Err {}) 7
+            "#
+        ),
+        10,
+        i64
+    );
+}
@@ -1609,6 +1609,76 @@ fn str_to_dec() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_int_radix() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            result : Result U8 [InvalidNumStr]
+            result = Str.toIntRadix "ff" 16
+
+            result
+            "#
+        ),
+        RocResult::ok(255),
+        RocResult<u8, ()>
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            result : Result I32 [InvalidNumStr]
+            result = Str.toIntRadix "-1010" 2
+
+            result
+            "#
+        ),
+        RocResult::ok(-10),
+        RocResult<i32, ()>
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            result : Result U64 [InvalidNumStr]
+            result = Str.toIntRadix "ZZ" 36
+
+            result
+            "#
+        ),
+        RocResult::ok(1295),
+        RocResult<u64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_int_radix_fail() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            result : Result U64 [InvalidNumStr]
+            result = Str.toIntRadix "12" 2
+
+            result
+            "#
+        ),
+        RocResult::err(()),
+        RocResult<u64, ()>
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            result : Result U8 [InvalidNumStr]
+            result = Str.toIntRadix "1ff" 16
+
+            result
+            "#
+        ),
+        RocResult::err(()),
+        RocResult<u8, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn issue_2811() {
@@ -2204,3 +2274,94 @@ fn str_contains_self() {
         bool
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_upper() {
+    assert_evals_to!(
+        r#"Str.toUpper "Hello, straße!""#,
+        RocStr::from("HELLO, STRASSE!"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_lower_final_sigma() {
+    assert_evals_to!(
+        r#"Str.toLower "ΟΔΥΣΣΕΥΣ ΣΕ ΣΑΣ""#,
+        RocStr::from("οδυσσευς σε σας"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_lower_big_str() {
+    assert_evals_to!(
+        r#"Str.toLower "THIS STRING IS LONGER THAN ANY SMALL STRING COULD BE""#,
+        RocStr::from("this string is longer than any small string could be"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_case_insensitive_eq() {
+    assert_evals_to!(r#"Str.caseInsensitiveEq "Straße" "STRASSE""#, true, bool);
+    assert_evals_to!(r#"Str.caseInsensitiveEq "Roc" "Rock""#, false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_nfc_and_nfd() {
+    assert_evals_to!(r#"Str.toNfc "e\u(301)""#, RocStr::from("é"), RocStr);
+    assert_evals_to!(r#"Str.toNfd "é""#, RocStr::from("e\u{301}"), RocStr);
+    assert_evals_to!(
+        r#"Str.toNfd "ệ" == Str.toNfd "e\u(323)\u(302)""#,
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_utf16() {
+    assert_evals_to!(
+        r#"Str.toUtf16 "a鹏🐦""#,
+        RocList::from_slice(&[97u16, 40527, 55357, 56358]),
+        RocList<u16>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_from_utf16() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.fromUtf16 [97, 40527, 55357, 56358] is
+                Ok str -> str
+                Err _ -> ""
+            "#
+        ),
+        RocStr::from("a鹏🐦"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_from_utf16_unpaired_surrogate() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.fromUtf16 [97, 55357, 98] is
+                Ok _ -> 999
+                Err (BadUtf16 index) -> index
+            "#
+        ),
+        1,
+        usize
+    );
+}
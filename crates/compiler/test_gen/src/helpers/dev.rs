@@ -3,6 +3,7 @@ use roc_build::link::{link, LinkType};
 use roc_builtins::bitcode;
 use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
 use roc_mono::ir::CrashTag;
+use roc_mono::ir::OptLevel;
 use roc_mono::ir::SingleEntryPoint;
 use roc_packaging::cache::RocCacheDir;
 use roc_region::all::LineInfo;
@@ -17,6 +18,10 @@ use roc_collections::all::MutMap;
 #[allow(unused_imports)]
 use roc_mono::ir::pretty_print_ir_symbols;
 
+/// The dev backend emits mono IR more or less as is, so compile tests with the mono
+/// optimizations on, like the LLVM tests do (see `llvm::OPT_LEVEL`)
+const OPT_LEVEL: OptLevel = OptLevel::Normal;
+
 #[allow(dead_code)]
 fn promote_expr_to_module(src: &str) -> String {
     let mut buffer = String::from("app \"test\" provides [main] to \"./platform\"\n\nmain =\n");
@@ -63,6 +68,7 @@ pub fn helper(
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
         function_kind: FunctionKind::LambdaSet,
+        opt_level: OPT_LEVEL,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -3,13 +3,15 @@ use crate::helpers::from_wasm32_memory::FromWasm32Memory;
 use bumpalo::Bump;
 use roc_collections::all::MutSet;
 use roc_gen_wasm::wasm32_result::Wasm32Result;
-use roc_gen_wasm::DEBUG_SETTINGS;
+use roc_gen_wasm::{WasmFeatures, DEBUG_SETTINGS};
 use roc_load::{ExecutionMode, LoadConfig, Threading};
+use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::DEFAULT_PALETTE_HTML;
 use roc_solve::FunctionKind;
 use roc_std::RocStr;
 use roc_wasm_interp::{wasi, ImportDispatcher, Instance, WasiDispatcher};
+use roc_wasm_module::parse::ParseError;
 use roc_wasm_module::{Export, ExportType, Value, WasmModule};
 use std::marker::PhantomData;
 use std::path::PathBuf;
@@ -17,6 +19,11 @@ use std::path::PathBuf;
 const TEST_WRAPPER_NAME: &str = "test_wrapper";
 const INIT_REFCOUNT_NAME: &str = "init_refcount_test";
 
+/// The Wasm backend emits mono IR more or less as is, so `assert_evals_to` compiles with the mono
+/// optimizations on, like the LLVM tests do (see `llvm::OPT_LEVEL`). Tests that look at the
+/// generated code or count refcounts compile without them, so that the code matches the source.
+const OPT_LEVEL: OptLevel = OptLevel::Normal;
+
 macro_rules! host_bytes_path {
     () => {
         // Should manually match build.rs. include_bytes! requires a string literal.
@@ -47,21 +54,78 @@ fn write_final_wasm() -> bool {
     DEBUG_SETTINGS.keep_test_binary
 }
 
+/// Validation is on by default in debug builds, and off in release builds.
+/// Set `ROC_VALIDATE_WASM=0` to skip it, or to any other value to force it.
+fn validate_wasm() -> bool {
+    match std::env::var(roc_debug_flags::ROC_VALIDATE_WASM) {
+        Ok(value) => value != "0",
+        Err(_) => cfg!(debug_assertions),
+    }
+}
+
+/// Write a WebAssembly text version of the final module, next to the file from `save_wasm_file`
+fn save_wat_file(compiled_bytes: &[u8], build_dir_hash: u64) -> Result<(), ParseError> {
+    let arena = Bump::new();
+    let require_relocatable = false;
+    let module = WasmModule::preload(&arena, compiled_bytes, require_relocatable)?;
+
+    let final_wat_path = format!("/tmp/roc/gen_wasm/{build_dir_hash:016x}/final.wat");
+    std::fs::write(&final_wat_path, module.to_wat(&arena)).unwrap();
+    println!("Wrote text format to\n\t{final_wat_path}\n");
+
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub fn compile_to_wasm_bytes<'a, T: Wasm32Result>(
     arena: &'a bumpalo::Bump,
     src: &str,
     test_wrapper_type_info: PhantomData<T>,
+) -> Vec<u8> {
+    compile_to_wasm_bytes_with_features(arena, src, test_wrapper_type_info, WasmFeatures::default())
+}
+
+#[allow(dead_code)]
+pub fn compile_to_wasm_bytes_with_features<'a, T: Wasm32Result>(
+    arena: &'a bumpalo::Bump,
+    src: &str,
+    test_wrapper_type_info: PhantomData<T>,
+    features: WasmFeatures,
+) -> Vec<u8> {
+    compile_to_wasm_bytes_help(
+        arena,
+        src,
+        test_wrapper_type_info,
+        features,
+        OptLevel::Development,
+    )
+}
+
+fn compile_to_wasm_bytes_help<'a, T: Wasm32Result>(
+    arena: &'a bumpalo::Bump,
+    src: &str,
+    test_wrapper_type_info: PhantomData<T>,
+    features: WasmFeatures,
+    opt_level: OptLevel,
 ) -> Vec<u8> {
     let platform_bytes = include_bytes!(host_bytes_path!());
     println!("Loading test host {}", host_bytes_path!());
 
-    let compiled_bytes =
-        compile_roc_to_wasm_bytes(arena, platform_bytes, src, test_wrapper_type_info);
+    let compiled_bytes = compile_roc_to_wasm_bytes(
+        arena,
+        platform_bytes,
+        src,
+        test_wrapper_type_info,
+        features,
+        opt_level,
+    );
 
     if write_final_wasm() {
         let build_dir_hash = crate::helpers::src_hash(src);
-        crate::helpers::save_wasm_file(&compiled_bytes, build_dir_hash)
+        crate::helpers::save_wasm_file(&compiled_bytes, build_dir_hash);
+        if let Err(e) = save_wat_file(&compiled_bytes, build_dir_hash) {
+            panic!("Failed to parse final.wasm for printing: {e:?}");
+        }
     };
 
     compiled_bytes
@@ -72,6 +136,8 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
     host_bytes: &[u8],
     src: &str,
     _test_wrapper_type_info: PhantomData<T>,
+    features: WasmFeatures,
+    opt_level: OptLevel,
 ) -> Vec<u8> {
     let filename = PathBuf::from("Test.roc");
     let src_dir = PathBuf::from("fake/test/path");
@@ -94,6 +160,7 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
         function_kind: FunctionKind::LambdaSet,
+        opt_level,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -113,6 +180,7 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
         mut interns,
         exposed_to_host,
         mut layout_interner,
+        sources,
         ..
     } = loaded;
 
@@ -129,6 +197,8 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
         module_id,
         exposed_to_host,
         stack_bytes: roc_gen_wasm::Env::DEFAULT_STACK_BYTES,
+        debug_sources: roc_gen_wasm::DebugSources::new(&sources),
+        features,
     };
 
     let host_module = roc_gen_wasm::parse_host(env.arena, host_bytes).unwrap_or_else(|e| {
@@ -168,6 +238,23 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
 
     module.eliminate_dead_code(env.arena, called_fns);
 
+    if validate_wasm() {
+        if let Err(e) = module.validate(env.arena) {
+            let fn_name = e.fn_index.and_then(|fn_index| {
+                module
+                    .names
+                    .function_names
+                    .iter()
+                    .find(|(i, _)| *i == fn_index)
+                    .map(|(_, name)| *name)
+            });
+            match fn_name {
+                Some(name) => panic!("{e}\nin function {name}"),
+                None => panic!("{e}"),
+            }
+        }
+    }
+
     let mut app_module_bytes = std::vec::Vec::with_capacity(module.size());
     module.serialize(&mut app_module_bytes);
 
@@ -181,7 +268,24 @@ where
 {
     let arena = bumpalo::Bump::new();
 
-    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom);
+    let wasm_bytes =
+        compile_to_wasm_bytes_help(&arena, src, phantom, WasmFeatures::default(), OPT_LEVEL);
+
+    run_wasm_test_bytes::<T>(TEST_WRAPPER_NAME, wasm_bytes)
+}
+
+#[allow(dead_code)]
+pub fn assert_evals_to_with_features_help<T>(
+    src: &str,
+    phantom: PhantomData<T>,
+    features: WasmFeatures,
+) -> Result<T, String>
+where
+    T: FromWasm32Memory + Wasm32Result,
+{
+    let arena = bumpalo::Bump::new();
+
+    let wasm_bytes = compile_to_wasm_bytes_with_features(&arena, src, phantom, features);
 
     run_wasm_test_bytes::<T>(TEST_WRAPPER_NAME, wasm_bytes)
 }
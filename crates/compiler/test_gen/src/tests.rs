@@ -23,6 +23,10 @@ pub mod gen_tuples;
 mod helpers;
 pub mod wasm_str;
 
+#[cfg(feature = "gen-wasm")]
+pub mod wasm_debug_info;
+#[cfg(feature = "gen-wasm")]
+pub mod wasm_features;
 #[cfg(feature = "gen-wasm")]
 pub mod wasm_linking;
 
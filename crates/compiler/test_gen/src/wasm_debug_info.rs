@@ -0,0 +1,111 @@
+#![cfg(feature = "gen-wasm")]
+//! Checks the DWARF that the Wasm backend writes, mapping code back to the Roc source.
+
+use std::marker::PhantomData;
+
+use gimli::{EndianSlice, LittleEndian};
+use indoc::indoc;
+use roc_wasm_module::parse::Parse;
+
+use crate::helpers::wasm::compile_to_wasm_bytes;
+
+/// The contents of a custom section, after its name
+fn custom_section<'b>(module_bytes: &'b [u8], name: &str) -> &'b [u8] {
+    let mut cursor = 8; // skip the header
+    while cursor < module_bytes.len() {
+        let id = module_bytes[cursor];
+        cursor += 1;
+        let size = u32::parse((), module_bytes, &mut cursor).unwrap() as usize;
+        let end = cursor + size;
+        if id == 0 {
+            let name_len = u32::parse((), module_bytes, &mut cursor).unwrap() as usize;
+            let section_name = &module_bytes[cursor..cursor + name_len];
+            if section_name == name.as_bytes() {
+                return &module_bytes[cursor + name_len..end];
+            }
+        }
+        cursor = end;
+    }
+    &[]
+}
+
+/// (file, line) of each row in the line table, and (name, line) of each subprogram
+fn read_debug_info(module_bytes: &[u8]) -> (Vec<(String, u64)>, Vec<(String, u64)>) {
+    let load = |id: gimli::SectionId| {
+        let section = custom_section(module_bytes, id.name());
+        Ok::<_, gimli::Error>(EndianSlice::new(section, LittleEndian))
+    };
+    let dwarf = gimli::Dwarf::load(load).unwrap();
+
+    let mut lines = vec![];
+    let mut subprograms = vec![];
+    let mut units = dwarf.units();
+
+    while let Some(header) = units.next().unwrap() {
+        let unit = dwarf.unit(header).unwrap();
+
+        if let Some(program) = unit.line_program.clone() {
+            let mut rows = program.rows();
+            while let Some((header, row)) = rows.next_row().unwrap() {
+                if let (Some(file), Some(line)) = (row.file(header), row.line()) {
+                    let path = dwarf.attr_string(&unit, file.path_name()).unwrap();
+                    lines.push((path.to_string_lossy().into_owned(), line.get()));
+                }
+            }
+        }
+
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() != gimli::DW_TAG_subprogram {
+                continue;
+            }
+            let name = entry.attr_value(gimli::DW_AT_name).unwrap().unwrap();
+            let name = dwarf.attr_string(&unit, name).unwrap();
+            let line = entry
+                .attr_value(gimli::DW_AT_decl_line)
+                .unwrap()
+                .and_then(|value| value.udata_value())
+                .unwrap_or_default();
+            subprograms.push((name.to_string_lossy().into_owned(), line));
+        }
+    }
+
+    (lines, subprograms)
+}
+
+#[test]
+fn lets_and_functions_point_at_their_source_lines() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        addThree = \start ->
+            oneMore = start + 1
+            twoMore = oneMore + 2
+            twoMore
+
+        main = addThree 39
+        "#
+    );
+
+    let arena = bumpalo::Bump::new();
+    let module_bytes = compile_to_wasm_bytes(&arena, src, PhantomData::<i64>);
+    let (lines, subprograms) = read_debug_info(&module_bytes);
+
+    // DWARF lines are 1-based
+    let has_line = |line| {
+        lines
+            .iter()
+            .any(|(file, l)| file.ends_with("Test.roc") && *l == line)
+    };
+
+    assert!(has_line(4), "no line info for `oneMore`: {lines:#?}");
+    assert!(has_line(5), "no line info for `twoMore`: {lines:#?}");
+
+    assert!(
+        subprograms
+            .iter()
+            .any(|(name, line)| name.contains("addThree") && (3..=4).contains(line)),
+        "no subprogram for `addThree`: {subprograms:#?}"
+    );
+}
@@ -0,0 +1,280 @@
+#![cfg(feature = "gen-wasm")]
+//! Runs code generated with the optional Wasm features, and checks that the backend uses them.
+//! Each test also runs without the features, to check that both versions agree.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use bumpalo::Bump;
+use indoc::indoc;
+use roc_gen_wasm::wasm32_result::Wasm32Result;
+use roc_gen_wasm::WasmFeatures;
+use roc_std::RocStr;
+use roc_wasm_module::WasmModule;
+
+use crate::helpers::from_wasm32_memory::FromWasm32Memory;
+use crate::helpers::wasm::{
+    assert_evals_to_with_features_help, compile_to_wasm_bytes_with_features,
+};
+
+const MULTI_VALUE: WasmFeatures = WasmFeatures {
+    multi_value: true,
+    bulk_memory: false,
+    simd: false,
+};
+
+const BULK_MEMORY: WasmFeatures = WasmFeatures {
+    multi_value: false,
+    bulk_memory: true,
+    simd: false,
+};
+
+const SIMD: WasmFeatures = WasmFeatures {
+    multi_value: false,
+    bulk_memory: false,
+    simd: true,
+};
+
+fn assert_evals_to_with<T>(src: &str, expected: T, features: WasmFeatures)
+where
+    T: FromWasm32Memory + Wasm32Result + PartialEq + Debug,
+{
+    for features in [WasmFeatures::default(), features, WasmFeatures::ALL] {
+        match assert_evals_to_with_features_help::<T>(src, PhantomData, features) {
+            Err(msg) => panic!("{msg}\nwith {features:?}"),
+            Ok(actual) => assert_eq!(actual, expected, "with {features:?}"),
+        }
+    }
+}
+
+/// The text format of the whole module, including the test host
+fn compile_to_wat<T: Wasm32Result>(src: &str, features: WasmFeatures) -> String {
+    let arena = Bump::new();
+    let bytes = compile_to_wasm_bytes_with_features(&arena, src, PhantomData::<T>, features);
+    let module = WasmModule::preload(&arena, &bytes, false).unwrap();
+    module.to_wat(&arena)
+}
+
+#[test]
+fn multi_value_record() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        pair : I64, I64 -> { a : I64, b : I64 }
+        pair = \a, b -> { a, b }
+
+        swap : { a : I64, b : I64 } -> { a : I64, b : I64 }
+        swap = \{ a, b } -> { a: b, b: a }
+
+        main =
+            r = swap (pair 1 2)
+            r.a * 10 + r.b
+        "#
+    );
+
+    assert_evals_to_with(src, 21i64, MULTI_VALUE);
+
+    let wat = compile_to_wat::<i64>(src, MULTI_VALUE);
+    assert!(wat.contains("(result i64 i64)"), "{wat}");
+}
+
+#[test]
+fn multi_value_tuple() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        triple : U32 -> (U32, U32, U32)
+        triple = \x -> (x, x + 1, x + 2)
+
+        main =
+            t = triple 10
+            t.0 * 100 + t.1 * 10 + t.2
+        "#
+    );
+
+    assert_evals_to_with(src, 1122u32, MULTI_VALUE);
+
+    let wat = compile_to_wat::<u32>(src, MULTI_VALUE);
+    assert!(wat.contains("(result i64 i32)"), "{wat}");
+}
+
+#[test]
+fn multi_value_str() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        greet : Str -> Str
+        greet = \name -> Str.concat "Hello, " name
+
+        main = Str.concat (greet "Roc") (greet ", and everyone else who is reading this!")
+        "#
+    );
+
+    assert_evals_to_with(
+        src,
+        RocStr::from("Hello, RocHello, , and everyone else who is reading this!"),
+        MULTI_VALUE,
+    );
+}
+
+#[test]
+fn multi_value_from_higher_order_wrapper() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        nums : List I64
+        nums = [1, 2, 3]
+
+        main =
+            strs =
+                ["a", "b", "a long string that is not small"]
+                |> List.map \s -> Str.concat s "!"
+
+            sum =
+                nums
+                |> List.map \x -> { x, double: x * 2 }
+                |> List.map \{ x, double } -> x + double
+                |> List.sum
+
+            Str.concat (Str.joinWith strs ",") (Num.toStr sum)
+        "#
+    );
+
+    assert_evals_to_with(
+        src,
+        RocStr::from("a!,b!,a long string that is not small!18"),
+        MULTI_VALUE,
+    );
+}
+
+#[test]
+fn bulk_memory_copy() {
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Big : { a : I64, b : I64, c : I64, d : I64, e : I64 }
+
+        identity : Big -> Big
+        identity = \big -> big
+
+        main =
+            big = identity { a: 1, b: 2, c: 3, d: 4, e: 5 }
+            big.a + big.b * 10 + big.c * 100 + big.d * 1000 + big.e * 10000
+        "#
+    );
+
+    assert_evals_to_with(src, 54321i64, BULK_MEMORY);
+
+    let wat = compile_to_wat::<i64>(src, BULK_MEMORY);
+    assert!(wat.contains("memory.copy"), "{wat}");
+}
+
+#[test]
+fn simd_str_equal() {
+    let long = "The quick brown fox jumps over the lazy dog";
+
+    let cases = [
+        (r#""hello" == "hello""#.to_string(), true),
+        (r#""hello" == "hellO""#.to_string(), false),
+        (r#""hello" != "hello""#.to_string(), false),
+        (r#""" == """#.to_string(), true),
+        (
+            r#""hello" == "hello there, how are you?""#.to_string(),
+            false,
+        ),
+        (
+            format!(r#"Str.concat "The quick " "brown fox jumps over the lazy dog" == "{long}""#),
+            true,
+        ),
+        (
+            format!(r#""The quick brown fox jumps over the lazy cat" == "{long}""#),
+            false,
+        ),
+        (
+            format!(r#""The quick brown cat jumps over the lazy dog" == "{long}""#),
+            false,
+        ),
+        (
+            format!(r#""The quick brown cat jumps over the lazy dog" != "{long}""#),
+            true,
+        ),
+        (format!(r#"Str.trim "  {long}  " == "{long}""#), true),
+    ];
+
+    for (src, expected) in cases {
+        assert_evals_to_with(&src, expected, SIMD);
+    }
+
+    let src = format!(r#""The quick brown cat jumps over the lazy dog" == "{long}""#);
+    let wat = compile_to_wat::<bool>(&src, SIMD);
+    assert!(wat.contains("i8x16.all_true"), "{wat}");
+}
+
+#[test]
+fn simd_str_starts_with() {
+    let long = "The quick brown fox jumps over the lazy dog";
+
+    let cases = [
+        (r#"Str.startsWith "hello" "he""#.to_string(), true),
+        (r#"Str.startsWith "hello" "hE""#.to_string(), false),
+        (r#"Str.startsWith "hello" """#.to_string(), true),
+        (r#"Str.startsWith "he" "hello""#.to_string(), false),
+        (
+            format!(r#"Str.startsWith "{long}" "The quick brown fox""#),
+            true,
+        ),
+        (
+            format!(r#"Str.startsWith "{long}" "The quick brown fix""#),
+            false,
+        ),
+        (
+            format!(r#"Str.startsWith "{long}" "The quack brown fox""#),
+            false,
+        ),
+        (format!(r#"Str.startsWith "{long}" "{long}""#), true),
+        (format!(r#"Str.startsWith "The quick" "{long}""#), false),
+    ];
+
+    for (src, expected) in cases {
+        assert_evals_to_with(&src, expected, SIMD);
+    }
+}
+
+#[test]
+fn simd_str_ends_with() {
+    let long = "The quick brown fox jumps over the lazy dog";
+
+    let cases = [
+        (r#"Str.endsWith "hello" "llo""#.to_string(), true),
+        (r#"Str.endsWith "hello" "lo!""#.to_string(), false),
+        (r#"Str.endsWith "hello" """#.to_string(), true),
+        (r#"Str.endsWith "lo" "hello""#.to_string(), false),
+        (
+            format!(r#"Str.endsWith "{long}" "jumps over the lazy dog""#),
+            true,
+        ),
+        (
+            format!(r#"Str.endsWith "{long}" "jumps over the lazy cat""#),
+            false,
+        ),
+        (
+            format!(r#"Str.endsWith "{long}" "jumps ovar the lazy dog""#),
+            false,
+        ),
+        (format!(r#"Str.endsWith "{long}" "{long}""#), true),
+        (format!(r#"Str.endsWith "lazy dog" "{long}""#), false),
+    ];
+
+    for (src, expected) in cases {
+        assert_evals_to_with(&src, expected, SIMD);
+    }
+
+    let src = format!(r#"Str.endsWith "{long}" "jumps over the lazy dog""#);
+    let wat = compile_to_wat::<bool>(&src, SIMD);
+    assert!(wat.contains("i8x16.all_true"), "{wat}");
+}
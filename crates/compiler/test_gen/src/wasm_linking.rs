@@ -1,7 +1,7 @@
 #![cfg(feature = "gen-wasm")]
 
 use bumpalo::Bump;
-use roc_gen_wasm::Env;
+use roc_gen_wasm::{DebugSources, Env, WasmFeatures};
 use roc_target::TargetInfo;
 use std::fs;
 use std::process::Command;
@@ -14,9 +14,10 @@ use roc_module::symbol::{
     Symbol,
 };
 use roc_mono::ir::{
-    Call, CallType, Expr, Literal, Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeId,
+    Call, CallType, Expr, Literal, Proc, ProcLayout, ProcRegions, SelfRecursive, Stmt, UpdateModeId,
 };
 use roc_mono::layout::{LambdaName, Layout, Niche, STLayoutInterner};
+use roc_region::all::Region;
 use roc_wasm_interp::{wasi, ImportDispatcher, Instance, WasiDispatcher};
 use roc_wasm_module::{Value, WasmModule};
 
@@ -101,7 +102,7 @@ fn build_app_mono<'a>(
                         or2_expr,
                         int_layout,
                         //
-                        arena.alloc(Stmt::Ret(or2)),
+                        arena.alloc(Stmt::Ret(or2, Region::zero())),
                     )),
                 )),
             )),
@@ -116,6 +117,7 @@ fn build_app_mono<'a>(
         ret_layout: int_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased: false,
+        regions: ProcRegions::default(),
     };
 
     let proc_layout = ProcLayout {
@@ -159,6 +161,8 @@ impl<'a> BackendInputs<'a> {
             module_id,
             exposed_to_host,
             stack_bytes: Env::DEFAULT_STACK_BYTES,
+            debug_sources: DebugSources::default(),
+            features: WasmFeatures::default(),
         };
 
         // Identifier stuff for the backend
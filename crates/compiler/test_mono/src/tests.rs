@@ -19,6 +19,7 @@ use roc_load::ExecutionMode;
 use roc_load::FunctionKind;
 use roc_load::LoadConfig;
 use roc_load::LoadMonomorphizedError;
+use roc_load::OptLevel;
 use roc_load::Threading;
 use roc_module::symbol::Interns;
 use roc_module::symbol::Symbol;
@@ -77,7 +78,14 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: bool, no_check: bool) {
+fn compiles_to_ir(
+    test_name: &str,
+    src: &str,
+    mode: &str,
+    allow_type_errors: bool,
+    no_check: bool,
+    optimize: bool,
+) {
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
@@ -111,6 +119,11 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         exec_mode,
+        opt_level: if optimize {
+            OptLevel::Normal
+        } else {
+            OptLevel::Development
+        },
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -3258,3 +3271,55 @@ fn non_nullable_unwrapped_instead_of_nullable_wrapped() {
         "#
     )
 }
+
+#[mono_test(optimize = "true")]
+fn optimize_inline_and_fold_constants() {
+    r#"
+    1 + 2
+    "#
+}
+
+#[mono_test(optimize = "true")]
+fn optimize_known_tag_switch() {
+    r#"
+    x : [Nothing, Just I64]
+    x = Just 41
+
+    when x is
+        Just v -> v + 0x1
+        Nothing -> 0x1
+    "#
+}
+
+#[mono_test(optimize = "true")]
+fn optimize_known_struct_and_dead_lets() {
+    r#"
+    x = 5
+
+    answer =
+        1337
+
+    unused =
+        nested = 17
+
+        i = 1
+
+        nested
+
+    { answer, unused }.answer
+    "#
+}
+
+#[mono_test(optimize = "true")]
+fn optimize_known_tuple_switch() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = when (1, 2) is
+            (1, _) -> "A"
+            (_, 2) -> "B"
+            (_, _) -> "C"
+        "#
+    )
+}
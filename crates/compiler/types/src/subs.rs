@@ -298,6 +298,27 @@ impl Subs {
 
         (tag_names, offset)
     }
+
+    /// Replace every symbol stored in these subs, e.g. to translate the `ModuleId`s of a
+    /// serialized `Subs` from the compiler run that wrote it to the current one.
+    pub fn map_symbols(&mut self, f: &mut impl FnMut(Symbol) -> Symbol) {
+        for symbol in self.symbol_names.iter_mut() {
+            *symbol = f(*symbol);
+        }
+
+        for Uls(_, symbol, _) in self.unspecialized_lambda_sets.iter_mut() {
+            *symbol = f(*symbol);
+        }
+
+        for content in self.utable.contents_mut() {
+            match content {
+                Content::Alias(symbol, ..) | Content::Structure(FlatType::Apply(symbol, _)) => {
+                    *symbol = f(*symbol);
+                }
+                _ => {}
+            }
+        }
+    }
 }
 
 /// Mapping of variables to [Content::LambdaSet]s containing unspecialized lambda sets depending on
@@ -1726,6 +1747,10 @@ impl Subs {
     pub const AB_HASH: SubsSlice<Symbol>            = SubsSlice::new(3, 1);
     #[rustfmt::skip]
     pub const AB_EQ: SubsSlice<Symbol>              = SubsSlice::new(4, 1);
+    #[rustfmt::skip]
+    pub const AB_INSPECT: SubsSlice<Symbol>         = SubsSlice::new(5, 1);
+    #[rustfmt::skip]
+    pub const AB_ORD: SubsSlice<Symbol>             = SubsSlice::new(6, 1);
     // END INIT-SymbolSubsSlice
 
     pub fn new() -> Self {
@@ -1754,6 +1779,8 @@ impl Subs {
         symbol_names.push(Symbol::HASH_HASHER);
         symbol_names.push(Symbol::HASH_HASH_ABILITY);
         symbol_names.push(Symbol::BOOL_EQ);
+        symbol_names.push(Symbol::INSPECT_INSPECT_ABILITY);
+        symbol_names.push(Symbol::BOOL_ORD);
         // END INIT-SymbolNames
 
         // IFTTT INIT-VariableSubsSlice
@@ -372,6 +372,11 @@ impl UnificationTable {
         self.set(key, desc.content, desc.rank, desc.mark, desc.copy);
     }
 
+    /// The contents of every variable, including redirected ones
+    pub(crate) fn contents_mut(&mut self) -> &mut [Content] {
+        &mut self.contents
+    }
+
     pub(crate) fn serialize(
         &self,
         writer: &mut impl std::io::Write,
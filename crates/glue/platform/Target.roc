@@ -10,6 +10,7 @@ Target : {
 Architecture : [
     Aarch32,
     Aarch64,
+    Riscv64,
     Wasm32,
     X86x32,
     X86x64,
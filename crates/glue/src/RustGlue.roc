@@ -2032,6 +2032,9 @@ archName = \arch ->
         Aarch64 ->
             "aarch64"
 
+        Riscv64 ->
+            "riscv64"
+
         Wasm32 ->
             "wasm32"
 
@@ -35,6 +35,7 @@ pub struct Types {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -117,6 +118,7 @@ pub struct R15 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -131,6 +133,7 @@ pub struct R12 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -145,6 +148,7 @@ pub struct R10 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -171,6 +175,7 @@ pub struct R3 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -185,6 +190,7 @@ pub struct Target {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -210,6 +216,7 @@ impl core::fmt::Debug for OperatingSystem {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -219,9 +226,10 @@ impl core::fmt::Debug for OperatingSystem {
 pub enum Architecture {
     Aarch32 = 0,
     Aarch64 = 1,
-    Wasm32 = 2,
-    X86x32 = 3,
-    X86x64 = 4,
+    Riscv64 = 2,
+    Wasm32 = 3,
+    X86x32 = 4,
+    X86x64 = 5,
 }
 
 impl core::fmt::Debug for Architecture {
@@ -229,6 +237,7 @@ impl core::fmt::Debug for Architecture {
         match self {
             Self::Aarch32 => f.write_str("Architecture::Aarch32"),
             Self::Aarch64 => f.write_str("Architecture::Aarch64"),
+            Self::Riscv64 => f.write_str("Architecture::Riscv64"),
             Self::Wasm32 => f.write_str("Architecture::Wasm32"),
             Self::X86x32 => f.write_str("Architecture::X86x32"),
             Self::X86x64 => f.write_str("Architecture::X86x64"),
@@ -239,6 +248,7 @@ impl core::fmt::Debug for Architecture {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -253,6 +263,7 @@ pub struct R14 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -330,6 +341,7 @@ pub struct R11 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -379,6 +391,7 @@ pub struct R9 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -428,6 +441,7 @@ pub struct R8 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -465,6 +479,7 @@ pub struct R6 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -525,6 +540,7 @@ pub struct R4 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -563,6 +579,7 @@ struct RocType_RocDict {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -620,6 +637,7 @@ pub struct R1 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -635,6 +653,7 @@ pub struct Types {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[repr(C)]
@@ -655,6 +674,7 @@ pub union RocType {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -666,6 +686,7 @@ pub struct R15 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -677,6 +698,7 @@ pub struct R3 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[repr(C)]
@@ -693,6 +715,7 @@ pub union RocTagUnion {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -705,6 +728,7 @@ pub struct R13 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -718,6 +742,7 @@ pub struct R11 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[repr(C)]
@@ -728,6 +753,7 @@ pub union U4 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -742,6 +768,7 @@ pub struct R9 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[repr(C)]
@@ -752,6 +779,7 @@ pub union U3 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -766,6 +794,7 @@ pub struct R8 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -779,6 +808,7 @@ pub struct R6 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[repr(C)]
@@ -789,6 +819,7 @@ pub union U1 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -801,6 +832,7 @@ pub struct R5 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -813,6 +845,7 @@ pub struct R4 {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -824,6 +857,7 @@ struct RocType_RocResult {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -835,6 +869,7 @@ struct RocType_RocDict {
 
 #[cfg(any(
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
@@ -891,6 +926,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -904,6 +940,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -931,6 +968,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -944,6 +982,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -957,6 +996,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -975,6 +1015,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1005,6 +1046,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1023,6 +1065,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1041,6 +1084,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1058,6 +1102,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1383,6 +1428,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1396,6 +1442,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1409,6 +1456,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1427,6 +1475,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1457,6 +1506,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1475,6 +1525,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1493,6 +1544,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1522,6 +1574,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1539,6 +1592,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1557,6 +1611,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1587,6 +1642,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1619,6 +1675,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1632,6 +1689,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1644,6 +1702,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
@@ -1657,6 +1716,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Internal helper
@@ -1670,6 +1730,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named Bool, which has no payload.
@@ -1683,6 +1744,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named EmptyTagUnion, which has no payload.
@@ -1696,6 +1758,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RecursivePointer`, with the appropriate payload
@@ -1711,6 +1774,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RecursivePointer` and convert it to `RecursivePointer`'s payload.
@@ -1725,6 +1789,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RecursivePointer` and return its payload.
@@ -1739,6 +1804,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RocBox`, with the appropriate payload
@@ -1754,6 +1820,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocBox` and convert it to `RocBox`'s payload.
@@ -1768,6 +1835,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocBox` and return its payload.
@@ -1782,6 +1850,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RocDict`, with the appropriate payload
@@ -1800,6 +1869,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocDict` and convert it to `RocDict`'s payload.
@@ -1817,6 +1887,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocDict` and return its payload.
@@ -1834,6 +1905,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RocList`, with the appropriate payload
@@ -1849,6 +1921,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocList` and convert it to `RocList`'s payload.
@@ -1863,6 +1936,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocList` and return its payload.
@@ -1877,6 +1951,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RocResult`, with the appropriate payload
@@ -1895,6 +1970,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocResult` and convert it to `RocResult`'s payload.
@@ -1912,6 +1988,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocResult` and return its payload.
@@ -1929,6 +2006,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `RocSet`, with the appropriate payload
@@ -1944,6 +2022,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocSet` and convert it to `RocSet`'s payload.
@@ -1958,6 +2037,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocSet` and return its payload.
@@ -1972,6 +2052,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named RocStr, which has no payload.
@@ -1985,6 +2066,7 @@ impl RocType {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named Unit, which has no payload.
@@ -2001,6 +2083,7 @@ impl Drop for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2034,6 +2117,7 @@ impl PartialEq for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2069,6 +2153,7 @@ impl PartialOrd for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2105,6 +2190,7 @@ impl Ord for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2141,6 +2227,7 @@ impl Clone for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2211,6 +2298,7 @@ impl core::hash::Hash for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2272,6 +2360,7 @@ impl core::fmt::Debug for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2357,6 +2446,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2375,6 +2465,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2405,6 +2496,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2423,6 +2515,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2441,6 +2534,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2471,6 +2565,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2489,6 +2584,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2507,6 +2603,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2537,6 +2634,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2555,6 +2653,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2573,6 +2672,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2603,6 +2703,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2621,6 +2722,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2639,6 +2741,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2669,6 +2772,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2687,6 +2791,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2705,6 +2810,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2735,6 +2841,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2753,6 +2860,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2771,6 +2879,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2801,6 +2910,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2818,6 +2928,7 @@ impl RocTagUnion {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
@@ -2831,6 +2942,7 @@ impl RocTagUnion {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Internal helper
@@ -2847,6 +2959,7 @@ impl Drop for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2872,6 +2985,7 @@ impl PartialEq for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2899,6 +3013,7 @@ impl PartialOrd for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2927,6 +3042,7 @@ impl Ord for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2955,6 +3071,7 @@ impl Clone for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2997,6 +3114,7 @@ impl core::hash::Hash for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3038,6 +3156,7 @@ impl core::fmt::Debug for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3119,6 +3238,7 @@ impl U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3132,6 +3252,7 @@ impl U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3190,6 +3311,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
@@ -3203,6 +3325,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Internal helper
@@ -3216,6 +3339,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named None, which has no payload.
@@ -3229,6 +3353,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `Some`, with the appropriate payload
@@ -3244,6 +3369,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
@@ -3258,6 +3384,7 @@ impl U4 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and return its payload.
@@ -3277,6 +3404,7 @@ impl PartialEq for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3299,6 +3427,7 @@ impl PartialOrd for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3322,6 +3451,7 @@ impl Ord for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3347,6 +3477,7 @@ impl Clone for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3375,6 +3506,7 @@ impl core::hash::Hash for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3393,6 +3525,7 @@ impl core::fmt::Debug for U4 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3457,6 +3590,7 @@ impl U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3470,6 +3604,7 @@ impl U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3528,6 +3663,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
@@ -3541,6 +3677,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Internal helper
@@ -3554,6 +3691,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named None, which has no payload.
@@ -3567,6 +3705,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `Some`, with the appropriate payload
@@ -3582,6 +3721,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
@@ -3596,6 +3736,7 @@ impl U3 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and return its payload.
@@ -3615,6 +3756,7 @@ impl PartialEq for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3637,6 +3779,7 @@ impl PartialOrd for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3660,6 +3803,7 @@ impl Ord for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3685,6 +3829,7 @@ impl Clone for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3713,6 +3858,7 @@ impl core::hash::Hash for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3731,6 +3877,7 @@ impl core::fmt::Debug for U3 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3795,6 +3942,7 @@ impl U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3808,6 +3956,7 @@ impl U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3866,6 +4015,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
@@ -3879,6 +4029,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Internal helper
@@ -3892,6 +4043,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// A tag named None, which has no payload.
@@ -3905,6 +4057,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `Some`, with the appropriate payload
@@ -3920,6 +4073,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U1` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
@@ -3934,6 +4088,7 @@ impl U1 {
 
     #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "x86_64"
     ))]
     /// Unsafely assume the given `U1` has a `.discriminant()` of `Some` and return its payload.
@@ -3953,6 +4108,7 @@ impl PartialEq for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3975,6 +4131,7 @@ impl PartialOrd for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3998,6 +4155,7 @@ impl Ord for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -4023,6 +4181,7 @@ impl Clone for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -4051,6 +4210,7 @@ impl core::hash::Hash for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -4069,6 +4229,7 @@ impl core::fmt::Debug for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
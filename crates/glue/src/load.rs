@@ -61,6 +61,7 @@ pub fn generate(
                 &triple,
                 BuildOrdering::BuildIfChecks,
                 Threading::AllAvailable,
+                code_gen_options.opt_level,
             );
 
             let arena = ManuallyDrop::new(Bump::new());
@@ -100,6 +101,7 @@ pub fn generate(
                     problems,
                     total_time,
                     expect_metadata: _,
+                    source_paths: _,
                 }) => {
                     // TODO: Should binary_path be update to deal with extensions?
                     use target_lexicon::OperatingSystem;
@@ -201,9 +203,13 @@ pub fn generate(
 
                     Ok(0)
                 }
-                Err(BuildFileError::ErrorModule { module, total_time }) => {
-                    handle_error_module(module, total_time, spec_path.as_os_str(), true)
-                }
+                Err(BuildFileError::ErrorModule { module, total_time }) => handle_error_module(
+                    module,
+                    total_time,
+                    spec_path.as_os_str(),
+                    true,
+                    RenderTarget::ColorTerminal,
+                ),
                 Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
             };
 
@@ -374,6 +380,7 @@ pub fn load_types(
             palette: DEFAULT_PALETTE,
             threading,
             exec_mode: ExecutionMode::Check,
+            opt_level: OptLevel::Development,
         },
     )
     .unwrap_or_else(|problem| match problem {
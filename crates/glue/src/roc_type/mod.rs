@@ -19,6 +19,7 @@
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -33,6 +34,7 @@ pub struct File {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -60,6 +62,7 @@ pub struct Tuple1 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -135,6 +138,7 @@ pub struct R16 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -174,6 +178,7 @@ pub struct Tuple2 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -188,6 +193,7 @@ pub struct Target {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -213,6 +219,7 @@ impl core::fmt::Debug for OperatingSystem {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -222,9 +229,10 @@ impl core::fmt::Debug for OperatingSystem {
 pub enum Architecture {
     Aarch32 = 0,
     Aarch64 = 1,
-    Wasm32 = 2,
-    X86x32 = 3,
-    X86x64 = 4,
+    Riscv64 = 2,
+    Wasm32 = 3,
+    X86x32 = 4,
+    X86x64 = 5,
 }
 
 impl core::fmt::Debug for Architecture {
@@ -232,6 +240,7 @@ impl core::fmt::Debug for Architecture {
         match self {
             Self::Aarch32 => f.write_str("Architecture::Aarch32"),
             Self::Aarch64 => f.write_str("Architecture::Aarch64"),
+            Self::Riscv64 => f.write_str("Architecture::Riscv64"),
             Self::Wasm32 => f.write_str("Architecture::Wasm32"),
             Self::X86x32 => f.write_str("Architecture::X86x32"),
             Self::X86x64 => f.write_str("Architecture::X86x64"),
@@ -242,6 +251,7 @@ impl core::fmt::Debug for Architecture {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -290,6 +300,7 @@ pub union RocTagUnion {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -305,6 +316,7 @@ pub struct R14 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -328,6 +340,7 @@ impl core::fmt::Debug for discriminant_RocSingleTagPayload {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -364,6 +377,7 @@ pub struct R9 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -397,6 +411,7 @@ pub struct R7 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -445,6 +460,7 @@ pub struct R5 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -459,6 +475,7 @@ pub struct R1 {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -482,6 +499,7 @@ impl core::fmt::Debug for discriminant_RocStructFields {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -496,6 +514,7 @@ pub union RocStructFields {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -517,6 +536,7 @@ struct RocType_RocDict {
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "riscv64",
     target_arch = "wasm32",
     target_arch = "x86",
     target_arch = "x86_64"
@@ -571,7 +591,11 @@ pub struct RocFn {
     pub is_toplevel: bool,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[repr(C)]
 #[derive(Clone, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 pub struct Tuple1 {
@@ -579,7 +603,11 @@ pub struct Tuple1 {
     f1: u64,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[repr(C)]
 pub union RocType {
     Function: core::mem::ManuallyDrop<RocFn>,
@@ -596,14 +624,22 @@ pub union RocType {
     _sizer: [u8; 104],
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(transparent)]
 pub struct R16 {
     pub id: u64,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R4 {
@@ -611,7 +647,11 @@ pub struct R4 {
     pub name: roc_std::RocStr,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R2 {
@@ -620,7 +660,11 @@ pub struct R2 {
     pub name: roc_std::RocStr,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[repr(C)]
 #[derive(Clone, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 pub struct Tuple2 {
@@ -628,7 +672,11 @@ pub struct Tuple2 {
     f1: roc_std::RocList<u64>,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[repr(C)]
 pub union RocTagUnion {
     Enumeration: core::mem::ManuallyDrop<R5>,
@@ -641,7 +689,11 @@ pub union RocTagUnion {
     _sizer: [u8; 96],
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R10 {
@@ -652,7 +704,11 @@ pub struct R10 {
     pub indexOfNullTag: u16,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R9 {
@@ -663,7 +719,11 @@ pub struct R9 {
     pub whichTagIsNull: U2,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R7 {
@@ -673,14 +733,22 @@ pub struct R7 {
     pub discriminantSize: u32,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[repr(C)]
 pub union U1 {
     Some: u64,
     _sizer: [u8; 16],
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R6 {
@@ -689,7 +757,11 @@ pub struct R6 {
     pub tagName: roc_std::RocStr,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct R5 {
@@ -698,7 +770,11 @@ pub struct R5 {
     pub size: u32,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 struct RocType_RocDict {
@@ -706,7 +782,11 @@ struct RocType_RocDict {
     pub f1: u64,
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "x86_64"
+))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct RocFn {
@@ -739,20 +819,32 @@ impl Tuple1 {
         (&self.f0, &self.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named T, with the given payload.
     pub fn T(f0: roc_std::RocStr, f1: u64) -> Self {
         Self { f0, f1 }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Since `T` only has one tag (namely, `T`),
     /// convert it to `T`'s payload.
     pub fn into_T(self) -> (roc_std::RocStr, u64) {
         (self.f0, self.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Since `T` only has one tag (namely, `T`),
     /// convert it to `T`'s payload.
     pub fn as_T(&self) -> (&roc_std::RocStr, &u64) {
@@ -764,6 +856,7 @@ impl core::fmt::Debug for Tuple1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -810,6 +903,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -823,6 +917,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -846,6 +941,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -859,6 +955,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -872,6 +969,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -890,6 +988,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -919,6 +1018,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -936,6 +1036,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -952,6 +1053,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -969,6 +1071,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1194,6 +1297,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1207,6 +1311,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1220,6 +1325,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1238,6 +1344,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1267,6 +1374,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1284,6 +1392,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1302,6 +1411,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1331,6 +1441,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1348,6 +1459,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1366,6 +1478,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1395,6 +1508,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1422,6 +1536,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1435,6 +1550,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1458,6 +1574,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1471,6 +1588,7 @@ impl RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1481,7 +1599,11 @@ impl RocType {
         ()
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
     pub fn discriminant(&self) -> discriminant_RocType {
         unsafe {
@@ -1491,7 +1613,11 @@ impl RocType {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Internal helper
     fn set_discriminant(&mut self, discriminant: discriminant_RocType) {
         let discriminant_ptr: *mut discriminant_RocType = (self as *mut RocType).cast();
@@ -1501,7 +1627,11 @@ impl RocType {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named Bool, which has no payload.
     pub const Bool: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<RocType>()];
@@ -1511,7 +1641,11 @@ impl RocType {
         core::mem::transmute::<[u8; core::mem::size_of::<RocType>()], RocType>(bytes)
     };
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named EmptyTagUnion, which has no payload.
     pub const EmptyTagUnion: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<RocType>()];
@@ -1521,7 +1655,11 @@ impl RocType {
         core::mem::transmute::<[u8; core::mem::size_of::<RocType>()], RocType>(bytes)
     };
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RecursivePointer`, with the appropriate payload
     pub fn RecursivePointer(arg: u64) -> Self {
         let mut answer = Self {
@@ -1533,7 +1671,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RecursivePointer` and convert it to `RecursivePointer`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RecursivePointer`.
@@ -1544,7 +1686,11 @@ impl RocType {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RecursivePointer` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RecursivePointer`.
@@ -1555,7 +1701,11 @@ impl RocType {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RocBox`, with the appropriate payload
     pub fn RocBox(arg: u64) -> Self {
         let mut answer = Self { RocBox: arg };
@@ -1565,7 +1715,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocBox` and convert it to `RocBox`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocBox`.
@@ -1576,7 +1730,11 @@ impl RocType {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocBox` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocBox`.
@@ -1587,7 +1745,11 @@ impl RocType {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RocDict`, with the appropriate payload
     pub fn RocDict(arg0: u64, arg1: u64) -> Self {
         let mut answer = Self {
@@ -1599,7 +1761,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocDict` and convert it to `RocDict`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocDict`.
@@ -1610,7 +1776,11 @@ impl RocType {
         (payload.f0, payload.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocDict` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocDict`.
@@ -1621,7 +1791,11 @@ impl RocType {
         (&payload.f0, &payload.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RocList`, with the appropriate payload
     pub fn RocList(arg: u64) -> Self {
         let mut answer = Self { RocList: arg };
@@ -1631,7 +1805,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocList` and convert it to `RocList`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocList`.
@@ -1642,7 +1820,11 @@ impl RocType {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocList` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocList`.
@@ -1653,7 +1835,11 @@ impl RocType {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RocResult`, with the appropriate payload
     pub fn RocResult(arg0: u64, arg1: u64) -> Self {
         let mut answer = Self {
@@ -1665,7 +1851,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocResult` and convert it to `RocResult`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocResult`.
@@ -1676,7 +1866,11 @@ impl RocType {
         (payload.f0, payload.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocResult` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocResult`.
@@ -1687,7 +1881,11 @@ impl RocType {
         (&payload.f0, &payload.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `RocSet`, with the appropriate payload
     pub fn RocSet(arg: u64) -> Self {
         let mut answer = Self { RocSet: arg };
@@ -1697,7 +1895,11 @@ impl RocType {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocSet` and convert it to `RocSet`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocSet`.
@@ -1708,7 +1910,11 @@ impl RocType {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `RocType` has a `.discriminant()` of `RocSet` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `RocSet`.
@@ -1719,7 +1925,11 @@ impl RocType {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named RocStr, which has no payload.
     pub const RocStr: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<RocType>()];
@@ -1729,7 +1939,11 @@ impl RocType {
         core::mem::transmute::<[u8; core::mem::size_of::<RocType>()], RocType>(bytes)
     };
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named Unit, which has no payload.
     pub const Unit: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<RocType>()];
@@ -1739,7 +1953,11 @@ impl RocType {
         core::mem::transmute::<[u8; core::mem::size_of::<RocType>()], RocType>(bytes)
     };
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named Unsized, which has no payload.
     pub const Unsized: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<RocType>()];
@@ -1754,6 +1972,7 @@ impl Drop for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1795,6 +2014,7 @@ impl PartialEq for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1835,6 +2055,7 @@ impl PartialOrd for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1876,6 +2097,7 @@ impl Ord for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1917,6 +2139,7 @@ impl Clone for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -1991,6 +2214,7 @@ impl core::hash::Hash for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2054,6 +2278,7 @@ impl core::fmt::Debug for RocType {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2131,20 +2356,32 @@ impl Tuple2 {
         (&self.f0, &self.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named T, with the given payload.
     pub fn T(f0: u64, f1: roc_std::RocList<u64>) -> Self {
         Self { f0, f1 }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Since `T` only has one tag (namely, `T`),
     /// convert it to `T`'s payload.
     pub fn into_T(self) -> (u64, roc_std::RocList<u64>) {
         (self.f0, self.f1)
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Since `T` only has one tag (namely, `T`),
     /// convert it to `T`'s payload.
     pub fn as_T(&self) -> (&u64, &roc_std::RocList<u64>) {
@@ -2156,6 +2393,7 @@ impl core::fmt::Debug for Tuple2 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2192,6 +2430,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2210,6 +2449,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2239,6 +2479,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2256,6 +2497,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2274,6 +2516,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2306,6 +2549,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2326,6 +2570,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2344,6 +2589,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2373,6 +2619,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2390,6 +2637,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2408,6 +2656,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2440,6 +2689,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2460,6 +2710,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2478,6 +2729,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2510,6 +2762,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2530,6 +2783,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2548,6 +2802,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2577,6 +2832,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2594,6 +2850,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2612,6 +2869,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2644,6 +2902,7 @@ impl RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2661,7 +2920,11 @@ impl RocTagUnion {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
     pub fn discriminant(&self) -> discriminant_RocTagUnion {
         unsafe {
@@ -2671,7 +2934,11 @@ impl RocTagUnion {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Internal helper
     fn set_discriminant(&mut self, discriminant: discriminant_RocTagUnion) {
         let discriminant_ptr: *mut discriminant_RocTagUnion = (self as *mut RocTagUnion).cast();
@@ -2686,6 +2953,7 @@ impl Drop for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2724,6 +2992,7 @@ impl PartialEq for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2759,6 +3028,7 @@ impl PartialOrd for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2799,6 +3069,7 @@ impl Ord for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2837,6 +3108,7 @@ impl Clone for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2878,6 +3150,7 @@ impl core::hash::Hash for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2920,6 +3193,7 @@ impl core::fmt::Debug for RocTagUnion {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -2986,6 +3260,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3004,6 +3279,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3036,6 +3312,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3056,6 +3333,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3074,6 +3352,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3106,6 +3385,7 @@ impl RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3123,7 +3403,11 @@ impl RocSingleTagPayload {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
     pub fn discriminant(&self) -> discriminant_RocSingleTagPayload {
         unsafe {
@@ -3133,7 +3417,11 @@ impl RocSingleTagPayload {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Internal helper
     fn set_discriminant(&mut self, discriminant: discriminant_RocSingleTagPayload) {
         let discriminant_ptr: *mut discriminant_RocSingleTagPayload =
@@ -3149,6 +3437,7 @@ impl Drop for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3172,6 +3461,7 @@ impl PartialEq for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3196,6 +3486,7 @@ impl PartialOrd for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3223,6 +3514,7 @@ impl Ord for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3250,6 +3542,7 @@ impl Clone for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3276,6 +3569,7 @@ impl core::hash::Hash for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3298,6 +3592,7 @@ impl core::fmt::Debug for RocSingleTagPayload {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3354,6 +3649,7 @@ impl U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3367,6 +3663,7 @@ impl U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3409,7 +3706,11 @@ impl U1 {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
     pub fn discriminant(&self) -> discriminant_U1 {
         unsafe {
@@ -3419,7 +3720,11 @@ impl U1 {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Internal helper
     fn set_discriminant(&mut self, discriminant: discriminant_U1) {
         let discriminant_ptr: *mut discriminant_U1 = (self as *mut U1).cast();
@@ -3429,7 +3734,11 @@ impl U1 {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// A tag named None, which has no payload.
     pub const None: Self = unsafe {
         let mut bytes = [0; core::mem::size_of::<U1>()];
@@ -3439,7 +3748,11 @@ impl U1 {
         core::mem::transmute::<[u8; core::mem::size_of::<U1>()], U1>(bytes)
     };
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Construct a tag named `Some`, with the appropriate payload
     pub fn Some(arg: u64) -> Self {
         let mut answer = Self { Some: arg };
@@ -3449,7 +3762,11 @@ impl U1 {
         answer
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `U1` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
@@ -3460,7 +3777,11 @@ impl U1 {
         payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Unsafely assume the given `U1` has a `.discriminant()` of `Some` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
@@ -3478,6 +3799,7 @@ impl PartialEq for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3500,6 +3822,7 @@ impl PartialOrd for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3523,6 +3846,7 @@ impl Ord for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3548,6 +3872,7 @@ impl Clone for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3574,6 +3899,7 @@ impl core::hash::Hash for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3593,6 +3919,7 @@ impl core::fmt::Debug for U1 {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3634,6 +3961,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3652,6 +3980,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3684,6 +4013,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3704,6 +4034,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3722,6 +4053,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3754,6 +4086,7 @@ impl RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3771,7 +4104,11 @@ impl RocStructFields {
         &payload
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
     pub fn discriminant(&self) -> discriminant_RocStructFields {
         unsafe {
@@ -3781,7 +4118,11 @@ impl RocStructFields {
         }
     }
 
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86_64"
+    ))]
     /// Internal helper
     fn set_discriminant(&mut self, discriminant: discriminant_RocStructFields) {
         let discriminant_ptr: *mut discriminant_RocStructFields =
@@ -3797,6 +4138,7 @@ impl Drop for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3820,6 +4162,7 @@ impl PartialEq for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3844,6 +4187,7 @@ impl PartialOrd for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3871,6 +4215,7 @@ impl Ord for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3896,6 +4241,7 @@ impl Clone for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3922,6 +4268,7 @@ impl core::hash::Hash for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
@@ -3944,6 +4291,7 @@ impl core::fmt::Debug for RocStructFields {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "riscv64",
         target_arch = "wasm32",
         target_arch = "x86",
         target_arch = "x86_64"
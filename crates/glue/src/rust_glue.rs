@@ -2572,6 +2572,7 @@ fn arch_to_str(architecture: Architecture) -> &'static str {
         Architecture::X86_32 => "x86",
         Architecture::Aarch64 => "aarch64",
         Architecture::Aarch32 => "arm",
+        Architecture::Riscv64 => "riscv64",
         Architecture::Wasm32 => "wasm32",
     }
 }
@@ -2585,7 +2586,7 @@ fn write_indents(indentations: usize, buf: &mut String) {
 fn max_pointer_tagged_variants(architecture: Architecture) -> usize {
     match architecture {
         // On a 64-bit system, pointers have 3 bits that are unused, so return 2^3 = 8
-        Architecture::X86_64 | Architecture::Aarch64 => 8,
+        Architecture::X86_64 | Architecture::Aarch64 | Architecture::Riscv64 => 8,
         // On a 32-bit system, pointers have 2 bits that are unused, so return 2^4 = 4
         Architecture::X86_32 | Architecture::Aarch32 | Architecture::Wasm32 => 4,
     }
@@ -2595,7 +2596,7 @@ fn max_pointer_tagged_variants(architecture: Architecture) -> usize {
 fn tagged_pointer_bitmask(architecture: Architecture) -> u8 {
     match architecture {
         // On a 64-bit system, pointers have 3 bits that are unused
-        Architecture::X86_64 | Architecture::Aarch64 => 0b0000_0111,
+        Architecture::X86_64 | Architecture::Aarch64 | Architecture::Riscv64 => 0b0000_0111,
         // On a 32-bit system, pointers have 2 bits that are unused
         Architecture::X86_32 | Architecture::Aarch32 | Architecture::Wasm32 => 0b0000_0011,
     }
@@ -7,27 +7,47 @@ use bumpalo::Bump;
 use roc_can::{abilities::AbilitiesStore, expr::Declarations};
 use roc_collections::MutMap;
 use roc_load::{CheckedModule, LoadedModule};
-use roc_module::symbol::{Interns, ModuleId, Symbol};
+use roc_module::symbol::{Interns, ModuleId, ModuleIds, Symbol};
 use roc_packaging::cache::{self, RocCacheDir};
-use roc_region::all::LineInfo;
+use roc_parse::state::State;
+use roc_region::all::{LineInfo, Region};
 use roc_reporting::report::RocDocAllocator;
 use roc_solve_problem::TypeError;
 use roc_types::subs::Subs;
 use tower_lsp::lsp_types::{
-    Diagnostic, GotoDefinitionResponse, Hover, HoverContents, Location, MarkedString, Position,
-    Range, SemanticTokenType, SemanticTokens, SemanticTokensResult, TextEdit, Url,
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Diagnostic,
+    DocumentSymbolResponse, FoldingRange, GotoDefinitionResponse, Hover, HoverContents, InlayHint,
+    Location, MarkedString, Position, Range, SemanticTokenType, SemanticTokens,
+    SemanticTokensResult, SignatureHelp, SymbolInformation, TextEdit, Url, WorkspaceEdit,
 };
 
 use crate::convert::{
-    diag::{IntoLspDiagnostic, ProblemFmt},
-    ToRange, ToRocPosition,
+    diag::{IntoLspDiagnostic, ProblemFmt, SyntaxProblem},
+    ToRange, ToRegion, ToRocPosition,
 };
 
+mod folding;
+mod inlay_hints;
 mod parse_ast;
+mod quick_fix;
 mod semantic_tokens;
+mod signature_help;
+mod source_diff;
+mod symbols;
 mod tokens;
-
-use self::{parse_ast::Ast, semantic_tokens::arrange_semantic_tokens, tokens::Token};
+mod walk;
+
+use self::{
+    folding::folding_ranges,
+    inlay_hints::{type_hint_sites, TypeHintSite},
+    parse_ast::Ast,
+    quick_fix::{FixContext, FixableDiagnostic, QuickFix},
+    semantic_tokens::arrange_semantic_tokens,
+    signature_help::Call,
+    source_diff::SourceDiff,
+    symbols::{document_symbols, workspace_symbols},
+    tokens::Token,
+};
 pub const HIGHLIGHT_TOKENS_LEGEND: &[SemanticTokenType] = Token::LEGEND;
 
 pub(crate) struct GlobalAnalysis {
@@ -57,10 +77,13 @@ impl GlobalAnalysis {
         let module = match loaded {
             Ok(module) => module,
             Err(problem) => {
-                let all_problems = problem
-                    .into_lsp_diagnostic(&())
-                    .into_iter()
-                    .collect::<Vec<_>>();
+                let mut all_problems = syntax_diagnostics(&fi, &source, &line_info);
+
+                // Loading fails with a single report of every syntax error, so only fall back on
+                // it when the errors aren't in this module.
+                if all_problems.is_empty() {
+                    all_problems.extend(problem.into_lsp_diagnostic(&()));
+                }
 
                 let analyzed_document = AnalyzedDocument {
                     url: source_url,
@@ -68,6 +91,8 @@ impl GlobalAnalysis {
                     source,
                     module: None,
                     diagnostics: all_problems,
+                    fixable_diagnostics: Vec::new(),
+                    last_good: None,
                 };
 
                 return GlobalAnalysis {
@@ -113,6 +138,44 @@ impl GlobalAnalysis {
     }
 }
 
+/// Reports each syntax error in the top-level defs of a module at the def it's in.
+fn syntax_diagnostics(path: &Path, source: &str, line_info: &LineInfo) -> Vec<Diagnostic> {
+    let arena = Bump::new();
+
+    let Ok(ast) = Ast::parse_recovering(&arena, source) else {
+        return Vec::new();
+    };
+
+    let lines: Vec<_> = source.lines().collect();
+    let mut module_ids = ModuleIds::default();
+    let home = module_ids.get_or_insert(&"find module name somehow?".into());
+    let interns = Interns {
+        module_ids,
+        ..Interns::default()
+    };
+    let alloc = RocDocAllocator::new(&lines, home, &interns);
+
+    let fmt = ProblemFmt {
+        alloc: &alloc,
+        line_info,
+        path,
+    };
+
+    ast.syntax_errors()
+        .filter_map(|(region, error)| {
+            let problem = SyntaxProblem {
+                region,
+                error: error
+                    .clone()
+                    .into_source_error(&State::new(source.as_bytes()))
+                    .into_file_error(path.to_path_buf()),
+            };
+
+            problem.into_lsp_diagnostic(&fmt)
+        })
+        .collect()
+}
+
 fn find_src_dir(path: &Path) -> &Path {
     path.parent().unwrap_or(path)
 }
@@ -195,7 +258,13 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
         };
 
         let line_info = LineInfo::new(&source);
-        let diagnostics = self.build_diagnostics(&path, &source, &line_info, module_id);
+        let (diagnostics, fixable_diagnostics) = self.build_diagnostics(
+            &path,
+            &source,
+            &line_info,
+            module_id,
+            &analyzed_module.declarations,
+        );
 
         AnalyzedDocument {
             url: path_to_url(&path),
@@ -203,6 +272,8 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
             source: source.into(),
             module: Some(analyzed_module),
             diagnostics,
+            fixable_diagnostics,
+            last_good: None,
         }
     }
 
@@ -212,36 +283,64 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
         source: &str,
         line_info: &LineInfo,
         module_id: ModuleId,
-    ) -> Vec<Diagnostic> {
+        declarations: &Declarations,
+    ) -> (Vec<Diagnostic>, Vec<FixableDiagnostic>) {
         let lines: Vec<_> = source.lines().collect();
 
         let alloc = RocDocAllocator::new(&lines, module_id, self.interns);
 
         let mut all_problems = Vec::new();
+        let mut fixable_problems = Vec::new();
         let fmt = ProblemFmt {
             alloc: &alloc,
             line_info,
             path: source_path,
         };
+        let fix_ctx = FixContext {
+            home: module_id,
+            interns: self.interns,
+            declarations,
+            alloc: &alloc,
+        };
 
         let can_problems = self.can_problems.remove(&module_id).unwrap_or_default();
 
         let type_problems = self.type_problems.remove(&module_id).unwrap_or_default();
 
         for can_problem in can_problems {
+            let fixes = QuickFix::for_can_problem(&can_problem, &fix_ctx);
+
             if let Some(diag) = can_problem.into_lsp_diagnostic(&fmt) {
-                all_problems.push(diag);
+                push_diagnostic(&mut all_problems, &mut fixable_problems, diag, fixes);
             }
         }
 
         for type_problem in type_problems {
+            let fixes = QuickFix::for_type_problem(&type_problem, &fix_ctx);
+
             if let Some(diag) = type_problem.into_lsp_diagnostic(&fmt) {
-                all_problems.push(diag);
+                push_diagnostic(&mut all_problems, &mut fixable_problems, diag, fixes);
             }
         }
 
-        all_problems
+        (all_problems, fixable_problems)
+    }
+}
+
+fn push_diagnostic(
+    diagnostics: &mut Vec<Diagnostic>,
+    fixable_diagnostics: &mut Vec<FixableDiagnostic>,
+    diagnostic: Diagnostic,
+    fixes: Vec<QuickFix>,
+) {
+    if !fixes.is_empty() {
+        fixable_diagnostics.push(FixableDiagnostic {
+            diagnostic: diagnostic.clone(),
+            fixes,
+        });
     }
+
+    diagnostics.push(diagnostic);
 }
 
 type ModuleIdToUrl = HashMap<ModuleId, Url>;
@@ -258,6 +357,33 @@ struct AnalyzedModule {
     module_id_to_url: ModuleIdToUrl,
 }
 
+impl AnalyzedModule {
+    /// The type of the narrowest expression or pattern at `position`, and its region.
+    fn type_at(&mut self, position: roc_region::all::Position) -> Option<(Region, String)> {
+        let AnalyzedModule {
+            subs,
+            declarations,
+            module_id,
+            interns,
+            ..
+        } = self;
+
+        let (region, var) = roc_can::traverse::find_closest_type_at(position, declarations)?;
+
+        let snapshot = subs.snapshot();
+        let type_str = roc_types::pretty_print::name_and_print_var(
+            var,
+            subs,
+            *module_id,
+            interns,
+            roc_types::pretty_print::DebugPrint::NOTHING,
+        );
+        subs.rollback_to(snapshot);
+
+        Some((region, type_str))
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct AnalyzedDocument {
     url: Url,
@@ -265,6 +391,33 @@ pub(crate) struct AnalyzedDocument {
     source: String,
     module: Option<AnalyzedModule>,
     diagnostics: Vec<Diagnostic>,
+    fixable_diagnostics: Vec<FixableDiagnostic>,
+    /// The last version of this document that was analyzed, if this one couldn't be.
+    last_good: Option<LastGoodAnalysis>,
+}
+
+/// An earlier version of a document that was analyzed, and how the document changed since.
+#[derive(Debug)]
+struct LastGoodAnalysis {
+    document: Box<AnalyzedDocument>,
+    diff: SourceDiff,
+}
+
+impl LastGoodAnalysis {
+    /// Maps a position in the current document to the analyzed version.
+    fn to_old_position(&self, position: Position, line_info: &LineInfo) -> Option<Position> {
+        let old_position = self.diff.to_old(position.to_roc_position(line_info))?;
+        let lc = self.document.line_info.convert_pos(old_position);
+
+        Some(Position::new(lc.line, lc.column))
+    }
+
+    /// Maps a range in the analyzed version to the current document.
+    fn to_new_range(&self, range: Range, line_info: &LineInfo) -> Option<Range> {
+        let old_region = range.to_region(&self.document.line_info);
+
+        Some(self.diff.to_new_region(old_region)?.to_range(line_info))
+    }
 }
 
 impl AnalyzedDocument {
@@ -302,7 +455,44 @@ impl AnalyzedDocument {
         self.diagnostics.clone()
     }
 
+    /// If this document couldn't be analyzed, keeps answering queries about it from the last
+    /// version of it that could be, with positions mapped through the edits made since.
+    pub fn keep_last_good(&mut self, previous: AnalyzedDocument) {
+        if self.module.is_some() {
+            return;
+        }
+
+        let document = if previous.module.is_some() {
+            previous
+        } else if let Some(last_good) = previous.last_good {
+            *last_good.document
+        } else {
+            return;
+        };
+
+        let diff = SourceDiff::new(&document.source, &self.source);
+
+        self.last_good = Some(LastGoodAnalysis {
+            document: Box::new(document),
+            diff,
+        });
+    }
+
+    /// The last good analysis to answer queries from, if this document couldn't be analyzed.
+    fn stale_analysis(&self) -> Option<&LastGoodAnalysis> {
+        match self.module {
+            Some(_) => None,
+            None => self.last_good.as_ref(),
+        }
+    }
+
     pub fn symbol_at(&self, position: Position) -> Option<Symbol> {
+        if let Some(last_good) = self.stale_analysis() {
+            let old_position = last_good.to_old_position(position, &self.line_info)?;
+
+            return last_good.document.symbol_at(old_position);
+        }
+
         let line_info = self.line_info();
 
         let position = position.to_roc_position(line_info);
@@ -320,29 +510,30 @@ impl AnalyzedDocument {
     }
 
     pub fn hover(&mut self, position: Position) -> Option<Hover> {
+        if self.module.is_none() {
+            let AnalyzedDocument {
+                line_info,
+                last_good,
+                ..
+            } = self;
+            let last_good = last_good.as_mut()?;
+
+            let old_position = last_good.to_old_position(position, line_info)?;
+            let hover = last_good.document.hover(old_position)?;
+
+            return Some(Hover {
+                range: hover
+                    .range
+                    .and_then(|range| last_good.to_new_range(range, line_info)),
+                ..hover
+            });
+        }
+
         let line_info = self.line_info();
 
         let pos = position.to_roc_position(line_info);
 
-        let AnalyzedModule {
-            subs,
-            declarations,
-            module_id,
-            interns,
-            ..
-        } = self.module_mut()?;
-
-        let (region, var) = roc_can::traverse::find_closest_type_at(pos, declarations)?;
-
-        let snapshot = subs.snapshot();
-        let type_str = roc_types::pretty_print::name_and_print_var(
-            var,
-            subs,
-            *module_id,
-            interns,
-            roc_types::pretty_print::DebugPrint::NOTHING,
-        );
-        subs.rollback_to(snapshot);
+        let (region, type_str) = self.module_mut()?.type_at(pos)?;
 
         let range = region.to_range(self.line_info());
 
@@ -353,6 +544,17 @@ impl AnalyzedDocument {
     }
 
     pub fn definition(&self, symbol: Symbol) -> Option<GotoDefinitionResponse> {
+        if let Some(last_good) = self.stale_analysis() {
+            let GotoDefinitionResponse::Scalar(location) =
+                last_good.document.definition(symbol)?
+            else {
+                return None;
+            };
+            let range = last_good.to_new_range(location.range, &self.line_info)?;
+
+            return Some(GotoDefinitionResponse::Scalar(self.location(range)));
+        }
+
         let AnalyzedModule { declarations, .. } = self.module()?;
 
         let found_declaration = roc_can::traverse::find_declaration(symbol, declarations)?;
@@ -382,7 +584,7 @@ impl AnalyzedDocument {
         let source = &self.source;
         let arena = &Bump::new();
 
-        let ast = Ast::parse(arena, source).ok()?;
+        let ast = Ast::parse_recovering(arena, source).ok()?;
         let tokens = ast.semantic_tokens();
 
         let data = arrange_semantic_tokens(tokens, &self.line_info);
@@ -393,7 +595,189 @@ impl AnalyzedDocument {
         }))
     }
 
+    pub fn document_symbols(&mut self) -> Option<DocumentSymbolResponse> {
+        let AnalyzedDocument {
+            source,
+            line_info,
+            module,
+            ..
+        } = self;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse_recovering(arena, source).ok()?;
+
+        let mut type_at = |position| Some(module.as_mut()?.type_at(position)?.1);
+        let symbols = document_symbols(ast.defs(), source, line_info, &mut type_at);
+
+        Some(DocumentSymbolResponse::Nested(symbols))
+    }
+
+    /// The symbols of this document whose names fuzzily match `query`, along with the score of
+    /// each match.
+    pub fn workspace_symbols(&self, query: &str) -> Vec<(usize, SymbolInformation)> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        let Ok(ast) = Ast::parse_recovering(arena, source) else {
+            return vec![];
+        };
+
+        // Types aren't shown in workspace symbols, so there's no need to print them.
+        let symbols = document_symbols(ast.defs(), source, &self.line_info, &mut |_| None);
+
+        workspace_symbols(symbols, &self.url, query)
+    }
+
+    pub fn folding_ranges(&self) -> Option<Vec<FoldingRange>> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse_recovering(arena, source).ok()?;
+
+        Some(folding_ranges(ast.defs(), &self.line_info))
+    }
+
+    /// The inferred types of the unannotated defs and lambda parameters in `range`.
+    pub fn inlay_hints(&mut self, range: Range) -> Option<Vec<InlayHint>> {
+        let AnalyzedDocument {
+            source,
+            line_info,
+            module,
+            ..
+        } = self;
+        let module = module.as_mut()?;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse_recovering(arena, source).ok()?;
+
+        let hints = type_hint_sites(ast.defs())
+            .into_iter()
+            .filter(|site| ranges_overlap(site.name_region().to_range(line_info), range))
+            .filter_map(|site| {
+                let (_, typ) = module.type_at(site.name_region().start())?;
+
+                Some(site.inlay_hint(&typ, line_info))
+            })
+            .collect();
+
+        Some(hints)
+    }
+
+    /// The type of the function being applied at `position`, with the argument `position` is in
+    /// highlighted.
+    pub fn signature_help(&mut self, position: Position) -> Option<SignatureHelp> {
+        let AnalyzedDocument {
+            source,
+            line_info,
+            module,
+            ..
+        } = self;
+        let module = module.as_mut()?;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse_recovering(arena, source).ok()?;
+
+        let call = Call::at(ast.defs(), position.to_roc_position(line_info))?;
+        let (_, function_type) = module.type_at(call.function.region.start())?;
+
+        Some(call.signature_help(&function_type))
+    }
+
+    pub fn code_actions(&mut self, range: Range) -> Option<CodeActionResponse> {
+        let mut actions: CodeActionResponse = self
+            .fixable_diagnostics
+            .iter()
+            .filter(|fixable| ranges_overlap(fixable.diagnostic.range, range))
+            .flat_map(|FixableDiagnostic { diagnostic, fixes }| {
+                fixes.iter().filter_map(move |fix| {
+                    let fixed = fix.apply(&self.source)?;
+
+                    Some(CodeActionOrCommand::CodeAction(CodeAction {
+                        title: fix.title(),
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        diagnostics: Some(vec![diagnostic.clone()]),
+                        edit: Some(self.replace_source(fixed)),
+                        ..CodeAction::default()
+                    }))
+                })
+            })
+            .collect();
+
+        actions.extend(self.annotation_actions(range));
+
+        if actions.is_empty() {
+            None
+        } else {
+            Some(actions)
+        }
+    }
+
+    /// Offers to annotate the unannotated defs whose names are in `range` with their inferred
+    /// types.
+    fn annotation_actions(&mut self, range: Range) -> Vec<CodeActionOrCommand> {
+        let AnalyzedDocument {
+            url,
+            source,
+            line_info,
+            module,
+            ..
+        } = self;
+        let Some(module) = module.as_mut() else {
+            return vec![];
+        };
+        let arena = &Bump::new();
+
+        let Ok(ast) = Ast::parse(arena, source) else {
+            return vec![];
+        };
+
+        type_hint_sites(ast.defs())
+            .into_iter()
+            .filter(|site| matches!(site, TypeHintSite::Def { .. }))
+            .filter(|site| ranges_overlap(site.name_region().to_range(line_info), range))
+            .filter_map(|site| {
+                let (_, typ) = module.type_at(site.name_region().start())?;
+                let text_edit = site.insert_annotation(&typ, line_info)?;
+
+                Some(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("Add annotation `: {typ}`"),
+                    kind: Some(CodeActionKind::REFACTOR_REWRITE),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(url.clone(), vec![text_edit])])),
+                        ..WorkspaceEdit::default()
+                    }),
+                    ..CodeAction::default()
+                }))
+            })
+            .collect()
+    }
+
+    /// An edit that replaces the whole document with `source`, formatted if it parses.
+    fn replace_source(&self, source: String) -> WorkspaceEdit {
+        let arena = &Bump::new();
+
+        let formatted = Ast::parse(arena, &source)
+            .ok()
+            .map(|ast| ast.fmt().to_string());
+        let new_text = formatted.unwrap_or(source);
+
+        let text_edit = TextEdit::new(self.whole_document_range(), new_text);
+
+        WorkspaceEdit {
+            changes: Some(HashMap::from([(self.url.clone(), vec![text_edit])])),
+            ..WorkspaceEdit::default()
+        }
+    }
+
     pub(crate) fn module_url(&self, module_id: ModuleId) -> Option<Url> {
+        if let Some(last_good) = self.stale_analysis() {
+            return last_good.document.module_url(module_id);
+        }
+
         self.module()?.module_id_to_url.get(&module_id).cloned()
     }
 }
+
+fn ranges_overlap(a: Range, b: Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
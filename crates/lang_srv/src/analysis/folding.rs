@@ -0,0 +1,112 @@
+use roc_parse::ast::{Defs, Expr};
+use roc_region::all::{LineInfo, Region};
+use tower_lsp::lsp_types::FoldingRange;
+
+use super::walk::{walk_defs, Visitor};
+
+/// Folds multi-line defs, `when` branches, records and lists.
+pub(super) fn folding_ranges<'a>(defs: &'a Defs<'a>, line_info: &LineInfo) -> Vec<FoldingRange> {
+    let mut collector = FoldingRangeCollector {
+        line_info,
+        ranges: Vec::new(),
+    };
+
+    walk_defs(&mut collector, defs);
+
+    collector.ranges
+}
+
+struct FoldingRangeCollector<'l> {
+    line_info: &'l LineInfo,
+    ranges: Vec<FoldingRange>,
+}
+
+impl FoldingRangeCollector<'_> {
+    fn fold(&mut self, region: Region) {
+        let region = self.line_info.convert_region(region);
+
+        if region.start.line < region.end.line {
+            self.ranges.push(FoldingRange {
+                start_line: region.start.line,
+                end_line: region.end.line,
+                ..FoldingRange::default()
+            });
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for FoldingRangeCollector<'_> {
+    fn visit_defs(&mut self, defs: &'a Defs<'a>) -> bool {
+        for region in defs.regions.iter() {
+            self.fold(*region);
+        }
+
+        true
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr<'a>, region: Region) -> bool {
+        match expr {
+            Expr::When(_, branches) => {
+                for branch in branches.iter() {
+                    if let Some(first_pattern) = branch.patterns.first() {
+                        self.fold(Region::span_across(
+                            &first_pattern.region,
+                            &branch.value.region,
+                        ));
+                    }
+                }
+            }
+            Expr::Record(_)
+            | Expr::RecordUpdate { .. }
+            | Expr::RecordBuilder(_)
+            | Expr::List(_)
+            | Expr::Tuple(_) => self.fold(region),
+            _ => {}
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::folding_ranges;
+    use crate::analysis::parse_ast::Ast;
+    use bumpalo::Bump;
+    use roc_region::all::LineInfo;
+
+    const HEADER: &str = "interface Test\n    exposes []\n    imports []\n\n";
+
+    /// The first and last lines of each fold, counting the header's.
+    fn fold_lines(body: &str) -> Vec<(u32, u32)> {
+        let source = format!("{HEADER}{body}");
+        let arena = Bump::new();
+        let ast = Ast::parse(&arena, &source).unwrap();
+
+        folding_ranges(ast.defs(), &LineInfo::new(&source))
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line))
+            .collect()
+    }
+
+    #[test]
+    fn single_lines_dont_fold() {
+        assert_eq!(fold_lines("x = [1, 2]\n\ny = { a: 1 }\n"), vec![]);
+    }
+
+    #[test]
+    fn multi_line_when() {
+        let body = "single = 1\n\nmulti =\n    when x is\n        A ->\n            [\n                1,\n            ]\n\n        B -> 2\n";
+
+        // The def, the branch for `A` and the list in it. The branch for `B` is on one line.
+        assert_eq!(fold_lines(body), vec![(6, 13), (8, 11), (9, 11)]);
+    }
+
+    #[test]
+    fn nested_multi_line_defs() {
+        let body = "main =\n    point = {\n        x: 1,\n    }\n\n    point\n";
+
+        // The outer def, the nested def and the record it is defined as.
+        assert_eq!(fold_lines(body), vec![(4, 9), (5, 7), (5, 7)]);
+    }
+}
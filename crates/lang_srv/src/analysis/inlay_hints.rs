@@ -0,0 +1,173 @@
+use roc_parse::ast::{Defs, Expr, ValueDef};
+use roc_region::all::{LineInfo, Region};
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range, TextEdit};
+
+use crate::convert::ToRange;
+
+use super::walk::{pattern_ident, walk_defs, Visitor};
+
+/// A name whose inferred type can be shown next to it.
+pub(super) enum TypeHintSite<'a> {
+    /// The name of a def without an annotation.
+    Def { name: &'a str, name_region: Region },
+    /// A parameter of a lambda.
+    Parameter { name_region: Region },
+}
+
+impl<'a> TypeHintSite<'a> {
+    pub fn name_region(&self) -> Region {
+        match self {
+            TypeHintSite::Def { name_region, .. } | TypeHintSite::Parameter { name_region } => {
+                *name_region
+            }
+        }
+    }
+
+    /// A hint showing `typ` after the name. Double-clicking the hint of a def annotates it.
+    pub fn inlay_hint(&self, typ: &str, line_info: &LineInfo) -> InlayHint {
+        InlayHint {
+            position: self.name_region().to_range(line_info).end,
+            label: InlayHintLabel::String(format!(": {typ}")),
+            kind: Some(InlayHintKind::TYPE),
+            text_edits: self
+                .insert_annotation(typ, line_info)
+                .map(|edit| vec![edit]),
+            tooltip: None,
+            padding_left: None,
+            padding_right: None,
+            data: None,
+        }
+    }
+
+    /// An edit that adds an annotation with type `typ` on the line before a def. Parameters
+    /// can't be annotated.
+    pub fn insert_annotation(&self, typ: &str, line_info: &LineInfo) -> Option<TextEdit> {
+        let TypeHintSite::Def { name, name_region } = self else {
+            return None;
+        };
+
+        let start = name_region.to_range(line_info).start;
+        let indent = " ".repeat(start.character as usize);
+
+        Some(TextEdit::new(
+            Range::new(start, start),
+            format!("{name} : {typ}\n{indent}"),
+        ))
+    }
+}
+
+/// Finds the unannotated defs and lambda parameters in a module, at any depth.
+pub(super) fn type_hint_sites<'a>(defs: &'a Defs<'a>) -> Vec<TypeHintSite<'a>> {
+    let mut collector = TypeHintSiteCollector { sites: Vec::new() };
+
+    walk_defs(&mut collector, defs);
+
+    collector.sites
+}
+
+struct TypeHintSiteCollector<'a> {
+    sites: Vec<TypeHintSite<'a>>,
+}
+
+impl<'a> Visitor<'a> for TypeHintSiteCollector<'a> {
+    fn visit_defs(&mut self, defs: &'a Defs<'a>) -> bool {
+        for def in defs.value_defs.iter() {
+            // Annotated defs are parsed as `AnnotatedBody`, so a `Body` has no annotation.
+            if let ValueDef::Body(pattern, _) = def {
+                if let Some(name) = pattern_ident(&pattern.value) {
+                    self.sites.push(TypeHintSite::Def {
+                        name,
+                        name_region: pattern.region,
+                    });
+                }
+            }
+        }
+
+        true
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr<'a>, _region: Region) -> bool {
+        if let Expr::Closure(parameters, _) = expr {
+            for parameter in parameters.iter() {
+                if pattern_ident(&parameter.value).is_some() {
+                    self.sites.push(TypeHintSite::Parameter {
+                        name_region: parameter.region,
+                    });
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{type_hint_sites, TypeHintSite};
+    use crate::analysis::parse_ast::Ast;
+    use bumpalo::Bump;
+    use roc_region::all::LineInfo;
+    use tower_lsp::lsp_types::{InlayHintLabel, Position, Range, TextEdit};
+
+    const SOURCE: &str = "interface Test\n    exposes []\n    imports []\n\nannotated : U8\nannotated = 1\n\nplain = \\x, { y } -> x\n\nnested =\n    inner = 2\n\n    inner\n";
+
+    fn describe(site: &TypeHintSite) -> String {
+        match site {
+            TypeHintSite::Def { name, .. } => format!("def {name}"),
+            TypeHintSite::Parameter { name_region } => {
+                let start = name_region.start().offset as usize;
+                let end = name_region.end().offset as usize;
+
+                format!("parameter {}", &SOURCE[start..end])
+            }
+        }
+    }
+
+    #[test]
+    fn unannotated_defs_and_parameters() {
+        let arena = Bump::new();
+        let ast = Ast::parse(&arena, SOURCE).unwrap();
+        let sites: Vec<_> = type_hint_sites(ast.defs()).iter().map(describe).collect();
+
+        // Each block of defs comes before the expressions in it. `y` is destructured, so it
+        // has no name of its own to hint.
+        assert_eq!(
+            sites,
+            vec!["def plain", "def nested", "parameter x", "def inner"]
+        );
+    }
+
+    #[test]
+    fn hints_and_annotations() {
+        let arena = Bump::new();
+        let ast = Ast::parse(&arena, SOURCE).unwrap();
+        let line_info = LineInfo::new(SOURCE);
+        let sites = type_hint_sites(ast.defs());
+
+        let inner = sites
+            .iter()
+            .find(|site| describe(site) == "def inner")
+            .unwrap();
+        let hint = inner.inlay_hint("Num *", &line_info);
+
+        assert_eq!(hint.position, Position::new(10, 9));
+        assert!(matches!(hint.label, InlayHintLabel::String(label) if label == ": Num *"));
+        assert_eq!(
+            hint.text_edits,
+            Some(vec![TextEdit::new(
+                Range::new(Position::new(10, 4), Position::new(10, 4)),
+                "inner : Num *\n    ".to_string(),
+            )])
+        );
+
+        // Parameters can't be annotated, so their hints have no edit.
+        let parameter = sites
+            .iter()
+            .find(|site| describe(site) == "parameter x")
+            .unwrap();
+        let hint = parameter.inlay_hint("a", &line_info);
+
+        assert_eq!(hint.position, Position::new(7, 10));
+        assert_eq!(hint.text_edits, None);
+    }
+}
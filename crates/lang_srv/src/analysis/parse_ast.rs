@@ -1,10 +1,10 @@
 use bumpalo::Bump;
 use roc_fmt::Buf;
 use roc_parse::{
-    ast::{Defs, Module},
+    ast::{Defs, Module, ValueDef},
     parser::SyntaxError,
 };
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Region};
 
 use self::format::FormattedAst;
 
@@ -16,6 +16,7 @@ pub struct Ast<'a> {
     arena: &'a Bump,
     module: Module<'a>,
     defs: Defs<'a>,
+    syntax_errors: Vec<SyntaxError<'a>>,
 }
 
 impl<'a> Ast<'a> {
@@ -35,9 +36,49 @@ impl<'a> Ast<'a> {
             module,
             defs,
             arena,
+            syntax_errors: Vec::new(),
         })
     }
 
+    /// Parses like [Ast::parse], but keeps the top-level defs that don't parse as malformed defs
+    /// instead of failing, so that an outline, highlighting and so on are still available while
+    /// editing. Only a malformed header fails.
+    pub fn parse_recovering(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+        use roc_parse::{
+            module::{module_defs_recovering, parse_header},
+            state::State,
+        };
+
+        let (module, state) = parse_header(arena, State::new(src.as_bytes()))
+            .map_err(|e| SyntaxError::Header(e.problem))?;
+
+        let (defs, syntax_errors) = module_defs_recovering(arena, state);
+
+        Ok(Ast {
+            module,
+            defs,
+            arena,
+            syntax_errors,
+        })
+    }
+
+    pub fn defs(&self) -> &Defs<'a> {
+        &self.defs
+    }
+
+    /// The syntax errors that [Ast::parse_recovering] recovered from, each with the region of
+    /// the malformed def it's in.
+    pub fn syntax_errors(&self) -> impl Iterator<Item = (Region, &SyntaxError<'a>)> {
+        let malformed_regions = self
+            .defs
+            .defs()
+            .zip(self.defs.regions.iter())
+            .filter(|(def, _)| matches!(def, Err(ValueDef::Malformed { .. })))
+            .map(|(_, region)| *region);
+
+        malformed_regions.zip(self.syntax_errors.iter())
+    }
+
     pub fn fmt(&self) -> FormattedAst<'a> {
         let mut buf = Buf::new_in(self.arena);
 
@@ -0,0 +1,581 @@
+use std::ops::Range;
+
+use bumpalo::Bump;
+use roc_can::{
+    expr::Declarations,
+    traverse::{find_declaration, FoundDeclaration},
+};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_parse::ast::TypeDef;
+use roc_problem::can::Problem;
+use roc_region::all::Region;
+use roc_reporting::{error::r#type::unhandled_patterns_to_source, report::RocDocAllocator};
+use roc_solve_problem::{TypeError, Unfulfilled};
+use tower_lsp::lsp_types::Diagnostic;
+
+use super::parse_ast::Ast;
+
+/// What a quick fix needs to know about the module its problem was found in.
+pub(crate) struct FixContext<'a> {
+    pub home: ModuleId,
+    pub interns: &'a Interns,
+    pub declarations: &'a Declarations,
+    pub alloc: &'a RocDocAllocator<'a>,
+}
+
+/// A diagnostic, and the fixes that can be offered for it.
+#[derive(Debug)]
+pub(crate) struct FixableDiagnostic {
+    pub diagnostic: Diagnostic,
+    pub fixes: Vec<QuickFix>,
+}
+
+/// An edit that resolves a problem. Edits are computed against the source they were found in,
+/// and are re-formatted afterwards, so they don't need to care about whitespace.
+#[derive(Debug)]
+pub(crate) enum QuickFix {
+    /// Removes an entry from a module header's `imports`.
+    RemoveImport { region: Region },
+    /// Removes a definition, along with its annotation.
+    RemoveDef { name: String, site: DefSite },
+    /// Prefixes a definition's name with `_`, in its annotation too.
+    IgnoreDef { name: String, site: DefSite },
+    /// Prefixes a function argument's name with `_`.
+    IgnoreArgument { name: String, region: Region },
+    /// Adds a builtin ability to the `implements` list of an opaque type in this module.
+    DeriveAbility { opaque: String, ability: String },
+    /// Adds a branch that crashes to a `when`, for each pattern it doesn't cover.
+    AddMissingBranches {
+        region: Region,
+        patterns: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum DefSite {
+    /// A top-level def, which is looked up in the parsed module to find its annotation.
+    TopLevel { symbol_region: Region },
+    /// A def inside an expression. Its region starts at the type of its annotation, if it has
+    /// one, so the annotated name is found by looking just before that.
+    Nested {
+        symbol_region: Region,
+        def_region: Region,
+    },
+}
+
+impl QuickFix {
+    pub fn for_can_problem(problem: &Problem, ctx: &FixContext) -> Vec<QuickFix> {
+        match problem {
+            Problem::UnusedImport(_, region) | Problem::UnusedModuleImport(_, region) => {
+                vec![QuickFix::RemoveImport { region: *region }]
+            }
+            Problem::UnusedDef(symbol, region) => {
+                let site = match find_declaration(*symbol, ctx.declarations) {
+                    Some(FoundDeclaration::Decl(_)) => DefSite::TopLevel {
+                        symbol_region: *region,
+                    },
+                    Some(FoundDeclaration::Def(def)) => DefSite::Nested {
+                        symbol_region: *region,
+                        def_region: def.region(),
+                    },
+                    // Destructured defs can't be removed or renamed on their own.
+                    None => return vec![],
+                };
+                let name = symbol.as_str(ctx.interns).to_string();
+
+                vec![
+                    QuickFix::IgnoreDef {
+                        name: name.clone(),
+                        site: site.clone(),
+                    },
+                    QuickFix::RemoveDef { name, site },
+                ]
+            }
+            Problem::UnusedArgument(_, _, argument, region) => {
+                vec![QuickFix::IgnoreArgument {
+                    name: argument.as_str(ctx.interns).to_string(),
+                    region: *region,
+                }]
+            }
+            Problem::Denied(_, problem) => QuickFix::for_can_problem(problem, ctx),
+            _ => vec![],
+        }
+    }
+
+    pub fn for_type_problem(problem: &TypeError, ctx: &FixContext) -> Vec<QuickFix> {
+        match problem {
+            TypeError::UnfulfilledAbility(unfulfilled) => {
+                derive_fixes(std::slice::from_ref(unfulfilled), ctx)
+            }
+            TypeError::BadExprMissingAbility(_, _, _, unfulfilled)
+            | TypeError::BadPatternMissingAbility(_, _, _, unfulfilled) => {
+                derive_fixes(unfulfilled, ctx)
+            }
+            TypeError::Exhaustive(roc_exhaustive::Error::Incomplete(
+                region,
+                roc_exhaustive::Context::BadCase,
+                missing,
+            )) => {
+                vec![QuickFix::AddMissingBranches {
+                    region: *region,
+                    patterns: unhandled_patterns_to_source(ctx.alloc, missing),
+                }]
+            }
+            TypeError::Denied(_, problem) => QuickFix::for_type_problem(problem, ctx),
+            _ => vec![],
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self {
+            QuickFix::RemoveImport { .. } => "Remove unused import".to_string(),
+            QuickFix::RemoveDef { name, .. } => format!("Remove unused definition `{name}`"),
+            QuickFix::IgnoreDef { name, .. } | QuickFix::IgnoreArgument { name, .. } => {
+                format!("Rename `{name}` to `_{name}`")
+            }
+            QuickFix::DeriveAbility { opaque, ability } => {
+                format!("Add `{ability}` to the abilities `{opaque}` implements")
+            }
+            QuickFix::AddMissingBranches { patterns, .. } if patterns.len() == 1 => {
+                "Add the missing branch".to_string()
+            }
+            QuickFix::AddMissingBranches { .. } => "Add the missing branches".to_string(),
+        }
+    }
+
+    /// The source with this fix applied, before formatting. Returns `None` if the fix no
+    /// longer applies to the source.
+    pub fn apply(&self, source: &str) -> Option<String> {
+        let arena = Bump::new();
+
+        let edits = match self {
+            QuickFix::RemoveImport { region } => {
+                vec![(remove_list_item(source, byte_range(*region)), String::new())]
+            }
+            QuickFix::RemoveDef { name, site } => {
+                let ast = Ast::parse(&arena, source).ok()?;
+                let (_, mut range) = site.locate(source, name, &ast)?;
+
+                if source[range.end..].starts_with('\n') {
+                    range.end += 1;
+                }
+
+                vec![(range, String::new())]
+            }
+            QuickFix::IgnoreDef { name, site } => {
+                let ast = Ast::parse(&arena, source).ok()?;
+                let (names, _) = site.locate(source, name, &ast)?;
+
+                names
+                    .into_iter()
+                    .map(|offset| (offset..offset, "_".to_string()))
+                    .collect()
+            }
+            QuickFix::IgnoreArgument { name, region } => {
+                let range = byte_range(*region);
+
+                if source.get(range.clone())? != name {
+                    return None;
+                }
+
+                // `{ x }` destructures the field `x`, so it has to become `{ x: _x }`.
+                let replacement = if in_record_pattern(source, range.start) {
+                    format!("{name}: _{name}")
+                } else {
+                    format!("_{name}")
+                };
+
+                vec![(range, replacement)]
+            }
+            QuickFix::DeriveAbility { opaque, ability } => {
+                let ast = Ast::parse(&arena, source).ok()?;
+
+                vec![derive_ability_edit(source, &ast, opaque, ability)?]
+            }
+            QuickFix::AddMissingBranches { region, patterns } => {
+                let range = byte_range(*region);
+                let indent = branch_indent(source.get(range.clone())?);
+
+                let branches: String = patterns
+                    .iter()
+                    .map(|pattern| format!("\n{indent}{pattern} -> crash \"TODO\""))
+                    .collect();
+
+                vec![(range.end..range.end, branches)]
+            }
+        };
+
+        Some(apply_edits(source, edits))
+    }
+}
+
+impl DefSite {
+    /// The offsets at which the def's name starts (in its annotation, if it has one, and in its
+    /// body), and the byte range of the whole def.
+    fn locate(&self, source: &str, name: &str, ast: &Ast) -> Option<(Vec<usize>, Range<usize>)> {
+        let (symbol_region, def_range) = match self {
+            DefSite::TopLevel { symbol_region } => {
+                let def_region = ast
+                    .defs()
+                    .regions
+                    .iter()
+                    .find(|region| region.contains(symbol_region))?;
+
+                (symbol_region, byte_range(*def_region))
+            }
+            DefSite::Nested {
+                symbol_region,
+                def_region,
+            } => {
+                let mut range = byte_range(*def_region);
+
+                if range.start < symbol_region.start().offset as usize {
+                    let before_type = source[..range.start].trim_end().strip_suffix(':')?;
+                    range.start = before_type.trim_end().strip_suffix(name)?.len();
+                }
+
+                (symbol_region, range)
+            }
+        };
+
+        let symbol_start = symbol_region.start().offset as usize;
+        let names = if def_range.start < symbol_start {
+            vec![def_range.start, symbol_start]
+        } else {
+            vec![symbol_start]
+        };
+
+        Some((names, def_range))
+    }
+}
+
+fn derive_fixes(unfulfilled: &[Unfulfilled], ctx: &FixContext) -> Vec<QuickFix> {
+    unfulfilled
+        .iter()
+        .filter_map(|unfulfilled| match unfulfilled {
+            // Only builtin abilities can be derived.
+            Unfulfilled::OpaqueDoesNotImplement { typ, ability }
+                if typ.module_id() == ctx.home && ability.module_id().is_builtin() =>
+            {
+                Some(QuickFix::DeriveAbility {
+                    opaque: typ.as_str(ctx.interns).to_string(),
+                    ability: ability.as_str(ctx.interns).to_string(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn derive_ability_edit(
+    source: &str,
+    ast: &Ast,
+    opaque: &str,
+    ability: &str,
+) -> Option<(Range<usize>, String)> {
+    let (typ, derived) = ast.defs().defs().find_map(|def| match def {
+        Ok(TypeDef::Opaque {
+            header,
+            typ,
+            derived,
+        }) if header.name.value == opaque => Some((typ, derived)),
+        _ => None,
+    })?;
+
+    match derived {
+        None => {
+            let end = typ.region.end().offset as usize;
+
+            Some((end..end, format!(" implements [{ability}]")))
+        }
+        Some(derived) => match derived.value.collection().items.last() {
+            Some(last) => {
+                let end = last.region.end().offset as usize;
+
+                Some((end..end, format!(", {ability}")))
+            }
+            None => {
+                let range = byte_range(derived.region);
+                let bracket = range.start + source.get(range)?.rfind(']')?;
+
+                Some((bracket..bracket, ability.to_string()))
+            }
+        },
+    }
+}
+
+fn byte_range(region: Region) -> Range<usize> {
+    region.start().offset as usize..region.end().offset as usize
+}
+
+/// Extends the range of an item in a comma-separated list to include the comma that separates
+/// it from its neighbours.
+fn remove_list_item(source: &str, range: Range<usize>) -> Range<usize> {
+    let after = &source[range.end..];
+    let after_trimmed = after.trim_start();
+
+    if let Some(rest) = after_trimmed.strip_prefix(',') {
+        return range.start..source.len() - rest.len();
+    }
+
+    match source[..range.start].trim_end().strip_suffix(',') {
+        Some(before) => before.len()..range.end,
+        None => range,
+    }
+}
+
+/// Whether the argument starting at `offset` is a field of a record pattern, rather than an
+/// argument of its own. Looks back for an unclosed bracket before reaching the `\` that starts
+/// the arguments.
+fn in_record_pattern(source: &str, offset: usize) -> bool {
+    let mut depth = 0;
+
+    for c in source[..offset].chars().rev() {
+        match c {
+            ')' | ']' | '}' => depth += 1,
+            '(' | '[' if depth > 0 => depth -= 1,
+            '{' if depth > 0 => depth -= 1,
+            '{' => return true,
+            '(' | '[' | '\\' => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The indentation of the first branch of a `when`, given its source from the condition on.
+fn branch_indent(when_source: &str) -> &str {
+    when_source
+        .lines()
+        .skip(1)
+        .find(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .unwrap_or("    ")
+}
+
+fn apply_edits(source: &str, mut edits: Vec<(Range<usize>, String)>) -> String {
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+    let mut result = source.to_string();
+
+    for (range, replacement) in edits {
+        result.replace_range(range, &replacement);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{in_record_pattern, remove_list_item, DefSite, QuickFix};
+    use crate::analysis::parse_ast::Ast;
+    use bumpalo::Bump;
+    use roc_region::all::{Position, Region};
+
+    const HEADER: &str = "interface Test\n    exposes []\n    imports []\n\n";
+
+    /// The region of the `nth` occurrence of `needle` in `source`.
+    fn region_of(source: &str, needle: &str, nth: usize) -> Region {
+        let (start, _) = source.match_indices(needle).nth(nth).unwrap();
+        let end = start + needle.len();
+
+        Region::new(Position::new(start as u32), Position::new(end as u32))
+    }
+
+    fn remove(source: &str, item: &str) -> String {
+        let start = source.find(item).unwrap();
+        let range = remove_list_item(source, start..start + item.len());
+
+        format!("{}{}", &source[..range.start], &source[range.end..])
+    }
+
+    #[test]
+    fn remove_middle_list_item() {
+        assert_eq!(remove("[A, B, C]", "B"), "[A,  C]");
+    }
+
+    #[test]
+    fn remove_last_list_item() {
+        assert_eq!(remove("[A, B, C]", "C"), "[A, B]");
+        assert_eq!(remove("[\n    A,\n    B,\n]", "B"), "[\n    A,\n    \n]");
+    }
+
+    #[test]
+    fn remove_only_list_item() {
+        assert_eq!(remove("[A]", "A"), "[]");
+    }
+
+    #[test]
+    fn record_pattern_fields() {
+        let in_record =
+            |source: &str, name: &str| in_record_pattern(source, source.find(name).unwrap());
+
+        assert!(in_record(r"\{ x } -> x", "x"));
+        assert!(!in_record(r"\x, y -> x", "y"));
+
+        // A field after a nested record is still in the outer record.
+        assert!(in_record(r"\{ a: { b }, c } -> c", "c"));
+        assert!(in_record(r"\{ a: { b } } -> b", "b"));
+
+        // An argument after a record argument, or after a record in a tuple, is not.
+        assert!(!in_record(r"\{ a }, b -> b", "b"));
+        assert!(!in_record(r"\({ a }, b) -> b", "b"));
+    }
+
+    #[test]
+    fn remove_import() {
+        let source = "interface Test\n    exposes []\n    imports [Dep1, Dep2]\n";
+        let fix = QuickFix::RemoveImport {
+            region: region_of(source, "Dep2", 0),
+        };
+
+        assert_eq!(
+            fix.apply(source).unwrap(),
+            "interface Test\n    exposes []\n    imports [Dep1]\n"
+        );
+    }
+
+    #[test]
+    fn ignore_argument() {
+        let source = format!("{HEADER}f = \\z, {{ y }} -> 1\n");
+        let ignore = |name: &str| QuickFix::IgnoreArgument {
+            name: name.to_string(),
+            region: region_of(&source, name, 0),
+        };
+
+        assert_eq!(
+            ignore("z").apply(&source).unwrap(),
+            format!("{HEADER}f = \\_z, {{ y }} -> 1\n")
+        );
+        assert_eq!(
+            ignore("y").apply(&source).unwrap(),
+            format!("{HEADER}f = \\z, {{ y: _y }} -> 1\n")
+        );
+
+        // The source changed since the problem was found.
+        let stale = QuickFix::IgnoreArgument {
+            name: "w".to_string(),
+            region: region_of(&source, "z", 0),
+        };
+        assert_eq!(stale.apply(&source), None);
+    }
+
+    #[test]
+    fn locate_top_level_def() {
+        let source = format!("{HEADER}unused : U8\nunused = 1\n\nplain = 2\n");
+        let arena = Bump::new();
+        let ast = Ast::parse(&arena, &source).unwrap();
+
+        let annotated = DefSite::TopLevel {
+            symbol_region: region_of(&source, "unused", 1),
+        };
+        let (names, range) = annotated.locate(&source, "unused", &ast).unwrap();
+        let annotation = source.find("unused :").unwrap();
+        let body = source.find("unused =").unwrap();
+
+        assert_eq!(names, vec![annotation, body]);
+        assert_eq!(&source[range], "unused : U8\nunused = 1");
+
+        let plain = DefSite::TopLevel {
+            symbol_region: region_of(&source, "plain", 0),
+        };
+        let (names, range) = plain.locate(&source, "plain", &ast).unwrap();
+
+        assert_eq!(names, vec![source.find("plain").unwrap()]);
+        assert_eq!(&source[range], "plain = 2");
+    }
+
+    #[test]
+    fn remove_and_ignore_top_level_def() {
+        let source = format!("{HEADER}unused : U8\nunused = 1\n\nmain = 2\n");
+        let site = DefSite::TopLevel {
+            symbol_region: region_of(&source, "unused", 1),
+        };
+
+        let remove = QuickFix::RemoveDef {
+            name: "unused".to_string(),
+            site: site.clone(),
+        };
+        assert_eq!(
+            remove.apply(&source).unwrap(),
+            format!("{HEADER}\nmain = 2\n")
+        );
+
+        let ignore = QuickFix::IgnoreDef {
+            name: "unused".to_string(),
+            site,
+        };
+        assert_eq!(
+            ignore.apply(&source).unwrap(),
+            format!("{HEADER}_unused : U8\n_unused = 1\n\nmain = 2\n")
+        );
+    }
+
+    #[test]
+    fn ignore_nested_def() {
+        let source = format!("{HEADER}main =\n    y : U8\n    y = 1\n\n    2\n");
+        // The region of a nested annotated def starts at the type of its annotation.
+        let type_start = region_of(&source, "U8", 0).start();
+        let body_end = region_of(&source, "y = 1", 0).end();
+        let site = DefSite::Nested {
+            symbol_region: region_of(&source, "y", 1),
+            def_region: Region::new(type_start, body_end),
+        };
+
+        let ignore = QuickFix::IgnoreDef {
+            name: "y".to_string(),
+            site: site.clone(),
+        };
+        assert_eq!(
+            ignore.apply(&source).unwrap(),
+            format!("{HEADER}main =\n    _y : U8\n    _y = 1\n\n    2\n")
+        );
+
+        let remove = QuickFix::RemoveDef {
+            name: "y".to_string(),
+            site,
+        };
+        assert_eq!(
+            remove.apply(&source).unwrap(),
+            format!("{HEADER}main =\n    \n    2\n")
+        );
+    }
+
+    #[test]
+    fn derive_ability() {
+        let derive = QuickFix::DeriveAbility {
+            opaque: "Id".to_string(),
+            ability: "Eq".to_string(),
+        };
+
+        let source = format!("{HEADER}Id := U64\n");
+        assert_eq!(
+            derive.apply(&source).unwrap(),
+            format!("{HEADER}Id := U64 implements [Eq]\n")
+        );
+
+        let source = format!("{HEADER}Id := U64 implements [Hash]\n");
+        assert_eq!(
+            derive.apply(&source).unwrap(),
+            format!("{HEADER}Id := U64 implements [Hash, Eq]\n")
+        );
+    }
+
+    #[test]
+    fn add_missing_branches() {
+        let source = "main =\n    when x is\n        A -> 1\n";
+        let fix = QuickFix::AddMissingBranches {
+            region: Region::new(
+                region_of(source, "when", 0).start(),
+                region_of(source, "1", 0).end(),
+            ),
+            patterns: vec!["B".to_string(), "C _".to_string()],
+        };
+
+        assert_eq!(
+            fix.apply(source).unwrap(),
+            "main =\n    when x is\n        A -> 1\n        B -> crash \"TODO\"\n        C _ -> crash \"TODO\"\n"
+        );
+    }
+}
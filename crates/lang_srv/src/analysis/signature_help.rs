@@ -0,0 +1,145 @@
+use std::ops::Range;
+
+use roc_parse::ast::{Defs, Expr};
+use roc_region::all::{Loc, Position, Region};
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+use super::walk::{walk_defs, without_spaces, Visitor};
+
+/// The innermost function application around a position.
+pub(super) struct Call<'a> {
+    pub function: &'a Loc<Expr<'a>>,
+    arguments: &'a [&'a Loc<Expr<'a>>],
+    position: Position,
+}
+
+impl<'a> Call<'a> {
+    /// Finds the innermost application whose arguments `position` is among.
+    pub fn at(defs: &'a Defs<'a>, position: Position) -> Option<Call<'a>> {
+        let mut finder = CallFinder {
+            position,
+            found: None,
+        };
+
+        walk_defs(&mut finder, defs);
+
+        finder.found
+    }
+
+    /// The index of the argument `position` is in, or follows.
+    fn active_argument(&self) -> usize {
+        self.arguments
+            .iter()
+            .take_while(|argument| argument.region.end() < self.position)
+            .count()
+            .min(self.arguments.len().saturating_sub(1))
+    }
+
+    /// Describes the call, given the type of the function being called.
+    pub fn signature_help(&self, function_type: &str) -> SignatureHelp {
+        let label = match without_spaces(&self.function.value) {
+            Expr::Var { module_name, ident } if module_name.is_empty() => {
+                format!("{ident} : {function_type}")
+            }
+            Expr::Var { module_name, ident } => {
+                format!("{module_name}.{ident} : {function_type}")
+            }
+            _ => function_type.to_string(),
+        };
+        let type_start = label.len() - function_type.len();
+
+        let parameters = parameter_ranges(function_type)
+            .into_iter()
+            .map(|range| ParameterInformation {
+                label: ParameterLabel::LabelOffsets([
+                    utf16_offset(&label, type_start + range.start),
+                    utf16_offset(&label, type_start + range.end),
+                ]),
+                documentation: None,
+            })
+            .collect();
+
+        let active_parameter = Some(self.active_argument() as u32);
+
+        SignatureHelp {
+            signatures: vec![SignatureInformation {
+                label,
+                documentation: None,
+                parameters: Some(parameters),
+                active_parameter,
+            }],
+            active_signature: Some(0),
+            active_parameter,
+        }
+    }
+}
+
+struct CallFinder<'a> {
+    position: Position,
+    found: Option<Call<'a>>,
+}
+
+impl<'a> Visitor<'a> for CallFinder<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr<'a>, region: Region) -> bool {
+        if !region.contains_pos(self.position) {
+            return false;
+        }
+
+        if let Expr::Apply(function, arguments, _) = expr {
+            if function.region.end() < self.position {
+                self.found = Some(Call {
+                    function,
+                    arguments,
+                    position: self.position,
+                });
+            }
+        }
+
+        true
+    }
+}
+
+/// The byte ranges of the parameters of a printed function type, like `a, (a -> b) -> b`.
+/// Commas and arrows inside parentheses, records and tag unions don't count.
+fn parameter_ranges(function_type: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, c) in function_type.char_indices() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' | '}' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                ranges.push(start..index);
+                start = index + 1;
+            }
+            '-' if depth == 0 && function_type[index..].starts_with("->") => {
+                ranges.push(start..index);
+
+                return ranges
+                    .into_iter()
+                    .map(|range| trim_range(function_type, range))
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    // Not a function, so it has no parameters.
+    Vec::new()
+}
+
+fn trim_range(source: &str, range: Range<usize>) -> Range<usize> {
+    let text = &source[range.clone()];
+    let start = range.start + (text.len() - text.trim_start().len());
+
+    start..start + text.trim().len()
+}
+
+/// Parameter offsets are counted in UTF-16 code units.
+fn utf16_offset(text: &str, byte_offset: usize) -> u32 {
+    text[..byte_offset].encode_utf16().count() as u32
+}
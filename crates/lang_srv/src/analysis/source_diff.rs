@@ -0,0 +1,264 @@
+use std::ops::Range;
+
+use roc_region::all::{Position, Region};
+
+/// How a source changed since it was last analyzed, as the spans of text that are the same in
+/// both versions. Positions in those spans can be mapped from one version of the source to the
+/// other; positions in text that was replaced can't.
+///
+/// We only get the full text of each version from the editor, and the last good version may be
+/// several edits old, so the spans are found by diffing: the text both versions start and end
+/// with, and the lines that are the same in between.
+#[derive(Debug)]
+pub(super) struct SourceDiff {
+    /// In order, and not overlapping, in both versions.
+    unchanged: Vec<Unchanged>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Unchanged {
+    old_start: u32,
+    new_start: u32,
+    len: u32,
+}
+
+/// Diffing the lines between the first and the last change takes time and memory proportional
+/// to the product of their line counts, so past this many pairs of lines we treat everything
+/// between them as replaced.
+const MAX_LINE_PAIRS: usize = 1 << 20;
+
+impl SourceDiff {
+    pub fn new(old: &str, new: &str) -> SourceDiff {
+        let (old, new) = (old.as_bytes(), new.as_bytes());
+
+        let prefix_len = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+
+        // The prefix and suffix can't overlap, in either version.
+        let max_suffix_len = old.len().min(new.len()) - prefix_len;
+        let suffix_len = old
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .take(max_suffix_len)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let old_suffix_start = old.len() - suffix_len;
+        let new_suffix_start = new.len() - suffix_len;
+
+        let mut unchanged = vec![Unchanged {
+            old_start: 0,
+            new_start: 0,
+            len: prefix_len as u32,
+        }];
+
+        unchanged.extend(unchanged_lines(
+            old,
+            prefix_len..old_suffix_start,
+            new,
+            prefix_len..new_suffix_start,
+        ));
+
+        unchanged.push(Unchanged {
+            old_start: old_suffix_start as u32,
+            new_start: new_suffix_start as u32,
+            len: suffix_len as u32,
+        });
+
+        SourceDiff { unchanged }
+    }
+
+    /// Maps a position in the new version to the old one, unless it's in replaced text.
+    pub fn to_old(&self, position: Position) -> Option<Position> {
+        self.unchanged.iter().find_map(|span| {
+            let offset = map(position.offset, span.new_start, span.len)?;
+
+            Some(Position::new(offset - span.new_start + span.old_start))
+        })
+    }
+
+    /// Maps a region of the old version to the new one, unless it starts or ends in replaced
+    /// text.
+    pub fn to_new_region(&self, region: Region) -> Option<Region> {
+        let start = self.to_new(region.start())?;
+        let end = self.to_new(region.end())?;
+
+        Some(Region::new(start, end))
+    }
+
+    fn to_new(&self, position: Position) -> Option<Position> {
+        self.unchanged.iter().find_map(|span| {
+            let offset = map(position.offset, span.old_start, span.len)?;
+
+            Some(Position::new(offset - span.old_start + span.new_start))
+        })
+    }
+}
+
+/// Both ends of an unchanged span are positions in it, so an insertion right after a prefix
+/// doesn't stop its end from mapping.
+fn map(offset: u32, start: u32, len: u32) -> Option<u32> {
+    (start..=start + len).contains(&offset).then_some(offset)
+}
+
+/// The lines that are the same in `old[old_range]` and `new[new_range]`, as found by a longest
+/// common subsequence of their lines.
+fn unchanged_lines(
+    old: &[u8],
+    old_range: Range<usize>,
+    new: &[u8],
+    new_range: Range<usize>,
+) -> Vec<Unchanged> {
+    let old_lines = lines(old, old_range);
+    let new_lines = lines(new, new_range);
+
+    if old_lines.len().saturating_mul(new_lines.len()) > MAX_LINE_PAIRS {
+        return Vec::new();
+    }
+
+    let same = |i: usize, j: usize| old[old_lines[i].clone()] == new[new_lines[j].clone()];
+
+    // `lcs[i * width + j]` is how many lines `old_lines[i..]` and `new_lines[j..]` have in common
+    let width = new_lines.len() + 1;
+    let mut lcs = vec![0u32; (old_lines.len() + 1) * width];
+
+    for i in (0..old_lines.len()).rev() {
+        for j in (0..new_lines.len()).rev() {
+            lcs[i * width + j] = if same(i, j) {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut unchanged: Vec<Unchanged> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old_lines.len() && j < new_lines.len() {
+        if same(i, j) {
+            let old_start = old_lines[i].start as u32;
+            let new_start = new_lines[j].start as u32;
+            let len = old_lines[i].len() as u32;
+
+            match unchanged.last_mut() {
+                Some(last)
+                    if last.old_start + last.len == old_start
+                        && last.new_start + last.len == new_start =>
+                {
+                    last.len += len;
+                }
+                _ => unchanged.push(Unchanged {
+                    old_start,
+                    new_start,
+                    len,
+                }),
+            }
+
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    unchanged
+}
+
+/// The lines of `bytes[range]`, each with its newline.
+fn lines(bytes: &[u8], range: Range<usize>) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = range.start;
+
+    for offset in range.clone() {
+        if bytes[offset] == b'\n' {
+            lines.push(start..offset + 1);
+            start = offset + 1;
+        }
+    }
+
+    if start < range.end {
+        lines.push(start..range.end);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::SourceDiff;
+    use roc_region::all::{Position, Region};
+
+    fn to_old(diff: &SourceDiff, offset: u32) -> Option<u32> {
+        diff.to_old(Position::new(offset))
+            .map(|position| position.offset)
+    }
+
+    fn to_new_region(diff: &SourceDiff, start: u32, end: u32) -> Option<(u32, u32)> {
+        let region = Region::new(Position::new(start), Position::new(end));
+
+        diff.to_new_region(region)
+            .map(|region| (region.start().offset, region.end().offset))
+    }
+
+    #[test]
+    fn unchanged() {
+        let source = "x = 1\n";
+        let diff = SourceDiff::new(source, source);
+
+        for offset in 0..=source.len() as u32 {
+            assert_eq!(to_old(&diff, offset), Some(offset));
+        }
+        assert_eq!(to_new_region(&diff, 0, 5), Some((0, 5)));
+    }
+
+    #[test]
+    fn insertion_boundaries() {
+        let diff = SourceDiff::new("x = 1\n", "x = 123\n");
+
+        // "x = 1" is the prefix and "\n" the suffix; "23" was inserted between them.
+        assert_eq!(to_old(&diff, 5), Some(5));
+        assert_eq!(to_old(&diff, 6), None);
+        assert_eq!(to_old(&diff, 7), Some(5));
+        assert_eq!(to_old(&diff, 8), Some(6));
+
+        assert_eq!(to_new_region(&diff, 0, 5), Some((0, 5)));
+        assert_eq!(to_new_region(&diff, 5, 6), Some((5, 8)));
+    }
+
+    #[test]
+    fn deletion_boundaries() {
+        let diff = SourceDiff::new("x = 123\n", "x = 1\n");
+
+        assert_eq!(to_old(&diff, 5), Some(5));
+        assert_eq!(to_old(&diff, 6), Some(8));
+
+        assert_eq!(to_new_region(&diff, 4, 5), Some((4, 5)));
+        assert_eq!(to_new_region(&diff, 4, 6), None);
+        assert_eq!(to_new_region(&diff, 6, 7), None);
+        assert_eq!(to_new_region(&diff, 7, 8), Some((5, 6)));
+    }
+
+    #[test]
+    fn two_edits() {
+        let old = "a = 1\nb = 2\nc = 3\n";
+        let new = "a = 10\nb = 2\nc = 30\n";
+        let diff = SourceDiff::new(old, new);
+
+        // `b = 2` is between the edits, and moved by one
+        assert_eq!(to_old(&diff, 7), Some(6));
+        assert_eq!(to_old(&diff, 11), Some(10));
+        assert_eq!(to_new_region(&diff, 6, 11), Some((7, 12)));
+
+        // The edits themselves
+        assert_eq!(to_old(&diff, 6), None);
+        assert_eq!(to_old(&diff, 18), None);
+        assert_eq!(to_new_region(&diff, 16, 17), None);
+
+        // Before the first edit and after the last
+        assert_eq!(to_old(&diff, 0), Some(0));
+        assert_eq!(to_old(&diff, 20), Some(18));
+    }
+}
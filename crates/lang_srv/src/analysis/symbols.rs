@@ -0,0 +1,373 @@
+use roc_parse::ast::{Defs, Expr, Pattern, TypeAnnotation, TypeDef, ValueDef};
+use roc_region::all::{LineInfo, Loc, Position, Region};
+use tower_lsp::lsp_types::{DocumentSymbol, Location, SymbolInformation, SymbolKind, Url};
+
+use crate::convert::ToRange;
+
+use super::walk::{pattern_ident, walk_expr, without_spaces, Visitor};
+
+/// Builds the outline of a module from its parsed defs. Values are described by their type, as
+/// given by `type_at` for the position of their name, or by their annotation if there's no type.
+pub(super) fn document_symbols<'a>(
+    defs: &'a Defs<'a>,
+    source: &str,
+    line_info: &LineInfo,
+    type_at: &mut dyn FnMut(Position) -> Option<String>,
+) -> Vec<DocumentSymbol> {
+    let mut builder = SymbolBuilder {
+        source,
+        line_info,
+        type_at,
+    };
+
+    builder.def_symbols(defs, false)
+}
+
+/// Finds the symbols, among those of a module, whose names fuzzily match `query`. Each comes with
+/// the score of its match; lower scores are better matches.
+pub(super) fn workspace_symbols(
+    symbols: Vec<DocumentSymbol>,
+    url: &Url,
+    query: &str,
+) -> Vec<(usize, SymbolInformation)> {
+    let mut matches = Vec::new();
+
+    collect_matches(symbols, None, url, query, &mut matches);
+
+    matches
+}
+
+#[allow(deprecated)] // `deprecated` has to be given, even though it's deprecated in favor of tags
+fn collect_matches(
+    symbols: Vec<DocumentSymbol>,
+    container_name: Option<&str>,
+    url: &Url,
+    query: &str,
+    matches: &mut Vec<(usize, SymbolInformation)>,
+) {
+    for symbol in symbols {
+        if let Some(children) = symbol.children {
+            collect_matches(children, Some(&symbol.name), url, query, matches);
+        }
+
+        if let Some(score) = fuzzy_match(query, &symbol.name) {
+            let information = SymbolInformation {
+                name: symbol.name,
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri: url.clone(),
+                    range: symbol.selection_range,
+                },
+                container_name: container_name.map(str::to_string),
+            };
+
+            matches.push((score, information));
+        }
+    }
+}
+
+/// Whether the characters of `query` appear in `name` in order, ignoring case. If they do, returns
+/// how many characters of `name` come before and between them.
+fn fuzzy_match(query: &str, name: &str) -> Option<usize> {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    let mut skipped = 0;
+
+    for query_char in query.chars().flat_map(char::to_lowercase) {
+        loop {
+            let name_char = name_chars.next()?;
+
+            if name_char == query_char {
+                break;
+            }
+
+            skipped += 1;
+        }
+    }
+
+    Some(skipped)
+}
+
+struct SymbolBuilder<'s, 't> {
+    source: &'s str,
+    line_info: &'s LineInfo,
+    type_at: &'t mut dyn FnMut(Position) -> Option<String>,
+}
+
+impl SymbolBuilder<'_, '_> {
+    fn def_symbols<'a>(&mut self, defs: &'a Defs<'a>, nested: bool) -> Vec<DocumentSymbol> {
+        let mut symbols = Vec::new();
+
+        for (def, region) in defs.defs().zip(defs.regions.iter().copied()) {
+            let symbol = match def {
+                Ok(TypeDef::Alias { header, ann }) => Some(self.symbol(
+                    header.name.value,
+                    SymbolKind::STRUCT,
+                    Some(self.source_detail(ann.region)),
+                    region,
+                    header.name.region,
+                    Vec::new(),
+                )),
+                Ok(TypeDef::Opaque { header, typ, .. }) => Some(self.symbol(
+                    header.name.value,
+                    SymbolKind::CLASS,
+                    Some(self.source_detail(typ.region)),
+                    region,
+                    header.name.region,
+                    Vec::new(),
+                )),
+                Ok(TypeDef::Ability {
+                    header, members, ..
+                }) => {
+                    let members = members
+                        .iter()
+                        .map(|member| {
+                            self.symbol(
+                                member.name.value.item(),
+                                SymbolKind::METHOD,
+                                Some(self.source_detail(member.typ.region)),
+                                member.region(),
+                                member.name.region,
+                                Vec::new(),
+                            )
+                        })
+                        .collect();
+
+                    Some(self.symbol(
+                        header.name.value,
+                        SymbolKind::INTERFACE,
+                        None,
+                        region,
+                        header.name.region,
+                        members,
+                    ))
+                }
+                Err(ValueDef::Annotation(pattern, ann)) => {
+                    self.value_symbol(pattern, Some(ann), None, region, nested)
+                }
+                Err(ValueDef::Body(pattern, body)) => {
+                    self.value_symbol(pattern, None, Some(*body), region, nested)
+                }
+                Err(ValueDef::AnnotatedBody {
+                    ann_type,
+                    body_pattern,
+                    body_expr,
+                    ..
+                }) => self.value_symbol(
+                    body_pattern,
+                    Some(*ann_type),
+                    Some(*body_expr),
+                    region,
+                    nested,
+                ),
+                Err(ValueDef::Malformed {
+                    name: Some(name), ..
+                }) => Some(self.symbol(
+                    name.value,
+                    SymbolKind::VARIABLE,
+                    None,
+                    region,
+                    name.region,
+                    Vec::new(),
+                )),
+                Err(
+                    ValueDef::Dbg { .. }
+                    | ValueDef::Expect { .. }
+                    | ValueDef::ExpectFx { .. }
+                    | ValueDef::Malformed { name: None, .. },
+                ) => None,
+            };
+
+            symbols.extend(symbol);
+        }
+
+        symbols
+    }
+
+    fn value_symbol<'a>(
+        &mut self,
+        pattern: &Loc<Pattern<'a>>,
+        annotation: Option<&Loc<TypeAnnotation<'a>>>,
+        body: Option<&'a Loc<Expr<'a>>>,
+        region: Region,
+        nested: bool,
+    ) -> Option<DocumentSymbol> {
+        let name = pattern_ident(&pattern.value)?;
+
+        let is_function = match (body, annotation) {
+            (Some(body), _) if matches!(without_spaces(&body.value), Expr::Closure(..)) => true,
+            (_, Some(ann)) => is_function_annotation(&ann.value),
+            _ => false,
+        };
+        let kind = if is_function {
+            SymbolKind::FUNCTION
+        } else if nested {
+            SymbolKind::VARIABLE
+        } else {
+            SymbolKind::CONSTANT
+        };
+
+        let detail = (self.type_at)(pattern.region.start())
+            .or_else(|| annotation.map(|ann| self.source_detail(ann.region)));
+
+        let children = match body {
+            Some(body) => {
+                let mut nested_defs = NestedDefs { found: Vec::new() };
+                walk_expr(&mut nested_defs, &body.value, body.region);
+
+                nested_defs
+                    .found
+                    .into_iter()
+                    .flat_map(|defs| self.def_symbols(defs, true))
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        Some(self.symbol(name, kind, detail, region, pattern.region, children))
+    }
+
+    #[allow(deprecated)] // `deprecated` has to be given, even though it's deprecated in favor of tags
+    fn symbol(
+        &self,
+        name: &str,
+        kind: SymbolKind,
+        detail: Option<String>,
+        region: Region,
+        name_region: Region,
+        children: Vec<DocumentSymbol>,
+    ) -> DocumentSymbol {
+        DocumentSymbol {
+            name: name.to_string(),
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: region.to_range(self.line_info),
+            selection_range: name_region.to_range(self.line_info),
+            children: if children.is_empty() {
+                None
+            } else {
+                Some(children)
+            },
+        }
+    }
+
+    /// The source of a type, on one line.
+    fn source_detail(&self, region: Region) -> String {
+        let start = region.start().offset as usize;
+        let end = region.end().offset as usize;
+
+        self.source[start..end]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn is_function_annotation(ann: &TypeAnnotation) -> bool {
+    match ann {
+        TypeAnnotation::Function(_, _) => true,
+        TypeAnnotation::Where(ann, _) => is_function_annotation(&ann.value),
+        TypeAnnotation::SpaceBefore(ann, _) | TypeAnnotation::SpaceAfter(ann, _) => {
+            is_function_annotation(ann)
+        }
+        _ => false,
+    }
+}
+
+/// Finds the blocks of defs in an expression, without looking inside them; the defs within those
+/// belong to the defs that contain them.
+struct NestedDefs<'a> {
+    found: Vec<&'a Defs<'a>>,
+}
+
+impl<'a> Visitor<'a> for NestedDefs<'a> {
+    fn visit_defs(&mut self, defs: &'a Defs<'a>) -> bool {
+        self.found.push(defs);
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{document_symbols, fuzzy_match, workspace_symbols};
+    use crate::analysis::parse_ast::Ast;
+    use bumpalo::Bump;
+    use roc_region::all::LineInfo;
+    use tower_lsp::lsp_types::{DocumentSymbol, SymbolKind, Url};
+
+    const SOURCE: &str = "interface Test\n    exposes []\n    imports []\n\nId := U64\n\nShape : [Circle, Square]\n\narea : Shape -> U64\narea = \\shape ->\n    scale = 2\n\n    scale\n\nmain = 1\n";
+
+    fn symbols() -> Vec<DocumentSymbol> {
+        let arena = Bump::new();
+        let ast = Ast::parse(&arena, SOURCE).unwrap();
+
+        document_symbols(ast.defs(), SOURCE, &LineInfo::new(SOURCE), &mut |_| None)
+    }
+
+    fn outline(symbols: &[DocumentSymbol]) -> Vec<(&str, SymbolKind, Option<&str>, usize)> {
+        symbols
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.name.as_str(),
+                    symbol.kind,
+                    symbol.detail.as_deref(),
+                    symbol.children.as_ref().map_or(0, Vec::len),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn outline_with_nested_defs() {
+        let symbols = symbols();
+
+        assert_eq!(
+            outline(&symbols),
+            vec![
+                ("Id", SymbolKind::CLASS, Some("U64"), 0),
+                ("Shape", SymbolKind::STRUCT, Some("[Circle, Square]"), 0),
+                ("area", SymbolKind::FUNCTION, Some("Shape -> U64"), 1),
+                ("main", SymbolKind::CONSTANT, None, 0),
+            ]
+        );
+
+        let area = symbols[2].children.as_ref().unwrap();
+        assert_eq!(
+            outline(area),
+            vec![("scale", SymbolKind::VARIABLE, None, 0)]
+        );
+
+        // The whole def folds; its name is selected.
+        assert_eq!(symbols[2].range.start.line, 8);
+        assert_eq!(symbols[2].range.end.line, 12);
+        assert_eq!(symbols[2].selection_range.start.line, 9);
+    }
+
+    #[test]
+    fn fuzzy_matches() {
+        assert_eq!(fuzzy_match("ar", "area"), Some(0));
+        assert_eq!(fuzzy_match("AREA", "area"), Some(0));
+        assert_eq!(fuzzy_match("aa", "area"), Some(2));
+        assert_eq!(fuzzy_match("", "area"), Some(0));
+        assert_eq!(fuzzy_match("ra", "r"), None);
+        assert_eq!(fuzzy_match("z", "area"), None);
+    }
+
+    #[test]
+    fn workspace_symbols_are_contained_in_their_defs() {
+        let url = Url::parse("file:///Test.roc").unwrap();
+        let matches = workspace_symbols(symbols(), &url, "sc");
+
+        let names: Vec<_> = matches
+            .iter()
+            .map(|(score, info)| (*score, info.name.as_str(), info.container_name.as_deref()))
+            .collect();
+
+        assert_eq!(names, vec![(0, "scale", Some("area"))]);
+    }
+}
@@ -0,0 +1,191 @@
+use roc_parse::ast::{AssignedField, Defs, Expr, Pattern, RecordBuilderField, ValueDef};
+use roc_region::all::{Loc, Region};
+
+/// A walk over the expressions of a parsed module. Spaces around expressions are looked through,
+/// so each expression is visited once, with the region of the nearest [Loc] around it.
+pub(super) trait Visitor<'a> {
+    /// Called for each block of defs. Returns whether the defs' expressions should be walked.
+    fn visit_defs(&mut self, _defs: &'a Defs<'a>) -> bool {
+        true
+    }
+
+    /// Called for each expression, outermost first. Returns whether the expressions within it
+    /// should be walked.
+    fn visit_expr(&mut self, _expr: &'a Expr<'a>, _region: Region) -> bool {
+        true
+    }
+}
+
+pub(super) fn walk_defs<'a>(visitor: &mut impl Visitor<'a>, defs: &'a Defs<'a>) {
+    if !visitor.visit_defs(defs) {
+        return;
+    }
+
+    for def in defs.value_defs.iter() {
+        if let Some(expr) = value_def_expr(def) {
+            walk_expr(visitor, &expr.value, expr.region);
+        }
+    }
+}
+
+pub(super) fn walk_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Expr<'a>, region: Region) {
+    let expr = without_spaces(expr);
+
+    if !visitor.visit_expr(expr, region) {
+        return;
+    }
+
+    match expr {
+        Expr::RecordAccess(inner, _) | Expr::TupleAccess(inner, _) | Expr::ParensAround(inner) => {
+            walk_expr(visitor, inner, region)
+        }
+        Expr::List(items) | Expr::Tuple(items) => {
+            for item in items.iter() {
+                walk_loc_expr(visitor, item);
+            }
+        }
+        Expr::RecordUpdate { update, fields } => {
+            walk_loc_expr(visitor, update);
+
+            for field in fields.iter() {
+                walk_assigned_field(visitor, &field.value);
+            }
+        }
+        Expr::Record(fields) => {
+            for field in fields.iter() {
+                walk_assigned_field(visitor, &field.value);
+            }
+        }
+        Expr::RecordBuilder(fields) => {
+            for field in fields.iter() {
+                walk_record_builder_field(visitor, &field.value);
+            }
+        }
+        Expr::Closure(_, body) => walk_loc_expr(visitor, body),
+        Expr::Defs(defs, final_expr) => {
+            walk_defs(visitor, defs);
+            walk_loc_expr(visitor, final_expr);
+        }
+        Expr::Backpassing(_, first, second)
+        | Expr::Expect(first, second)
+        | Expr::Dbg(first, second) => {
+            walk_loc_expr(visitor, first);
+            walk_loc_expr(visitor, second);
+        }
+        Expr::Apply(function, arguments, _) => {
+            walk_loc_expr(visitor, function);
+
+            for argument in arguments.iter() {
+                walk_loc_expr(visitor, argument);
+            }
+        }
+        Expr::BinOps(operands, last) => {
+            for (operand, _) in operands.iter() {
+                walk_loc_expr(visitor, operand);
+            }
+
+            walk_loc_expr(visitor, last);
+        }
+        Expr::UnaryOp(inner, _)
+        | Expr::MultipleRecordBuilders(inner)
+        | Expr::UnappliedRecordBuilder(inner) => walk_loc_expr(visitor, inner),
+        Expr::If(branches, final_else) => {
+            for (condition, then) in branches.iter() {
+                walk_loc_expr(visitor, condition);
+                walk_loc_expr(visitor, then);
+            }
+
+            walk_loc_expr(visitor, final_else);
+        }
+        Expr::When(condition, branches) => {
+            walk_loc_expr(visitor, condition);
+
+            for branch in branches.iter() {
+                if let Some(guard) = &branch.guard {
+                    walk_loc_expr(visitor, guard);
+                }
+
+                walk_loc_expr(visitor, &branch.value);
+            }
+        }
+        Expr::PrecedenceConflict(conflict) => walk_loc_expr(visitor, conflict.expr),
+        Expr::Float(_)
+        | Expr::Num(_)
+        | Expr::NonBase10Int { .. }
+        | Expr::Str(_)
+        | Expr::SingleQuote(_)
+        | Expr::AccessorFunction(_)
+        | Expr::IngestedFile(_, _)
+        | Expr::Var { .. }
+        | Expr::Underscore(_)
+        | Expr::Crash
+        | Expr::Tag(_)
+        | Expr::OpaqueRef(_)
+        | Expr::MalformedIdent(_, _)
+        | Expr::MalformedClosure
+        | Expr::SpaceBefore(_, _)
+        | Expr::SpaceAfter(_, _) => {}
+    }
+}
+
+fn walk_loc_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Loc<Expr<'a>>) {
+    walk_expr(visitor, &expr.value, expr.region)
+}
+
+fn walk_assigned_field<'a>(visitor: &mut impl Visitor<'a>, field: &'a AssignedField<'a, Expr<'a>>) {
+    match field {
+        AssignedField::RequiredValue(_, _, value) | AssignedField::OptionalValue(_, _, value) => {
+            walk_loc_expr(visitor, value)
+        }
+        AssignedField::SpaceBefore(field, _) | AssignedField::SpaceAfter(field, _) => {
+            walk_assigned_field(visitor, field)
+        }
+        AssignedField::LabelOnly(_) | AssignedField::Malformed(_) => {}
+    }
+}
+
+fn walk_record_builder_field<'a>(
+    visitor: &mut impl Visitor<'a>,
+    field: &'a RecordBuilderField<'a>,
+) {
+    match field {
+        RecordBuilderField::Value(_, _, value) | RecordBuilderField::ApplyValue(_, _, _, value) => {
+            walk_loc_expr(visitor, value)
+        }
+        RecordBuilderField::SpaceBefore(field, _) | RecordBuilderField::SpaceAfter(field, _) => {
+            walk_record_builder_field(visitor, field)
+        }
+        RecordBuilderField::LabelOnly(_) | RecordBuilderField::Malformed(_) => {}
+    }
+}
+
+/// The expression a value def evaluates, if it has one.
+pub(super) fn value_def_expr<'a>(def: &ValueDef<'a>) -> Option<&'a Loc<Expr<'a>>> {
+    match def {
+        ValueDef::Body(_, expr) => Some(*expr),
+        ValueDef::AnnotatedBody { body_expr, .. } => Some(*body_expr),
+        ValueDef::Dbg { condition, .. }
+        | ValueDef::Expect { condition, .. }
+        | ValueDef::ExpectFx { condition, .. } => Some(*condition),
+        ValueDef::Annotation(_, _) | ValueDef::Malformed { .. } => None,
+    }
+}
+
+pub(super) fn without_spaces<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    while let Expr::SpaceBefore(inner, _) | Expr::SpaceAfter(inner, _) = expr {
+        expr = *inner;
+    }
+
+    expr
+}
+
+/// The name a pattern binds, if it binds exactly one name.
+pub(super) fn pattern_ident<'a>(mut pattern: &Pattern<'a>) -> Option<&'a str> {
+    loop {
+        match pattern {
+            Pattern::Identifier(ident) => return Some(*ident),
+            Pattern::SpaceBefore(inner, _) | Pattern::SpaceAfter(inner, _) => pattern = *inner,
+            _ => return None,
+        }
+    }
+}
@@ -72,12 +72,13 @@ pub(crate) mod diag {
     use std::path::Path;
 
     use roc_load::LoadingProblem;
+    use roc_parse::parser::{FileError, SyntaxError};
     use roc_region::all::{LineInfo, Region};
     use roc_solve_problem::TypeError;
 
     use roc_problem::Severity;
     use roc_reporting::report::RocDocAllocator;
-    use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+    use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
 
     use super::ToRange;
 
@@ -197,7 +198,46 @@ pub(crate) mod diag {
             Some(Diagnostic {
                 range,
                 severity: Some(severity),
-                code: None,
+                code: Some(NumberOrString::String(report.code.to_string())),
+                code_description: None,
+                source: None,
+                message: msg,
+                related_information: None,
+                tags: None,
+                data: None,
+            })
+        }
+    }
+
+    /// A syntax error that parsing recovered from, in the malformed def at `region`.
+    pub struct SyntaxProblem<'a> {
+        pub region: Region,
+        pub error: FileError<'a, SyntaxError<'a>>,
+    }
+
+    impl<'a> IntoLspDiagnostic<'a> for SyntaxProblem<'a> {
+        type Feed = ProblemFmt<'a>;
+
+        fn into_lsp_diagnostic(self, fmt: &'a ProblemFmt<'a>) -> Option<Diagnostic> {
+            let range = self.region.to_range(fmt.line_info);
+
+            let report = roc_reporting::report::parse_problem(
+                fmt.alloc,
+                fmt.line_info,
+                fmt.path.to_path_buf(),
+                0,
+                self.error,
+            );
+
+            let severity = report.severity.into_lsp_severity();
+
+            let mut msg = String::new();
+            report.render_ci(&mut msg, fmt.alloc);
+
+            Some(Diagnostic {
+                range,
+                severity: Some(severity),
+                code: Some(NumberOrString::String(report.code.to_string())),
                 code_description: None,
                 source: None,
                 message: msg,
@@ -232,7 +272,7 @@ pub(crate) mod diag {
             Some(Diagnostic {
                 range,
                 severity: Some(severity),
-                code: None,
+                code: Some(NumberOrString::String(report.code.to_string())),
                 code_description: None,
                 source: None,
                 message: msg,
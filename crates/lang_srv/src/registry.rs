@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use tower_lsp::lsp_types::{
-    Diagnostic, GotoDefinitionResponse, Hover, Position, SemanticTokensResult, TextEdit, Url,
+    CodeActionResponse, Diagnostic, DocumentSymbolResponse, FoldingRange, GotoDefinitionResponse,
+    Hover, InlayHint, Position, Range, SemanticTokensResult, SignatureHelp, SymbolInformation,
+    TextEdit, Url,
 };
 
 use crate::analysis::{AnalyzedDocument, GlobalAnalysis};
@@ -25,9 +27,14 @@ impl Registry {
                 // Only replace the set of documents and all dependencies that were re-analyzed.
                 // Note that this is actually the opposite of what we want - in truth we want to
                 // re-evaluate all dependents!
-                for document in documents {
+                for mut document in documents {
                     let url = document.url().clone();
-                    self.documents.insert(url.clone(), document);
+
+                    if let Some(previous) = self.documents.remove(&url) {
+                        document.keep_last_good(previous);
+                    }
+
+                    self.documents.insert(url, document);
                 }
             }
             DocumentChange::Closed(_url) => {
@@ -72,4 +79,46 @@ impl Registry {
         let document = self.document_by_url(url)?;
         document.semantic_tokens()
     }
+
+    pub fn document_symbols(&mut self, url: &Url) -> Option<DocumentSymbolResponse> {
+        let document = self.document_by_url(url)?;
+        document.document_symbols()
+    }
+
+    /// Searches the symbols of every analyzed module, best matches first.
+    pub fn workspace_symbols(&mut self, query: &str) -> Option<Vec<SymbolInformation>> {
+        let mut matches: Vec<_> = self
+            .documents
+            .values()
+            .flat_map(|document| document.workspace_symbols(query))
+            .collect();
+
+        matches.sort_by(|(score1, symbol1), (score2, symbol2)| {
+            score1
+                .cmp(score2)
+                .then_with(|| symbol1.name.cmp(&symbol2.name))
+        });
+
+        Some(matches.into_iter().map(|(_, symbol)| symbol).collect())
+    }
+
+    pub fn folding_ranges(&mut self, url: &Url) -> Option<Vec<FoldingRange>> {
+        let document = self.document_by_url(url)?;
+        document.folding_ranges()
+    }
+
+    pub fn inlay_hints(&mut self, url: &Url, range: Range) -> Option<Vec<InlayHint>> {
+        let document = self.document_by_url(url)?;
+        document.inlay_hints(range)
+    }
+
+    pub fn signature_help(&mut self, url: &Url, position: Position) -> Option<SignatureHelp> {
+        let document = self.document_by_url(url)?;
+        document.signature_help(position)
+    }
+
+    pub fn code_actions(&mut self, url: &Url, range: Range) -> Option<CodeActionResponse> {
+        let document = self.document_by_url(url)?;
+        document.code_actions(range)
+    }
 }
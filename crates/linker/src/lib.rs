@@ -6,7 +6,7 @@
 use memmap2::{Mmap, MmapMut};
 use object::Object;
 use roc_error_macros::internal_error;
-use roc_load::{EntryPoint, ExecutionMode, ExposedToHost, LoadConfig, Threading};
+use roc_load::{EntryPoint, ExecutionMode, ExposedToHost, LoadConfig, OptLevel, Threading};
 use roc_module::symbol::Interns;
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
@@ -38,8 +38,13 @@ pub fn supported(link_type: LinkType, target: &Triple) -> bool {
                 architecture: target_lexicon::Architecture::X86_64,
                 operating_system: target_lexicon::OperatingSystem::Linux,
                 binary_format: target_lexicon::BinaryFormat::Elf,
+                environment,
                 ..
-            } => true,
+            } => {
+                // musl executables are linked statically, so there is no dynamic section to
+                // surgically link against
+                *environment != target_lexicon::Environment::Musl
+            }
 
             // macho support is incomplete
             Triple {
@@ -108,6 +113,7 @@ pub fn generate_stub_lib(
             palette: DEFAULT_PALETTE,
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Executable,
+            opt_level: OptLevel::Development,
         },
     )
     .unwrap_or_else(|problem| todo!("{:?}", problem));
@@ -354,6 +360,7 @@ fn object_matches_target<'a>(target: &Triple, object: &object::File<'a, &'a [u8]
             object.format() == target_format
         }
         TLA::Aarch64(_) => object.architecture() == object::Architecture::Aarch64,
+        TLA::Riscv64(_) => object.architecture() == object::Architecture::Riscv64,
         _ => todo!("surgical linker does not support target {:?}", target),
     }
 }
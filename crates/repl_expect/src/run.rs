@@ -14,7 +14,7 @@ use roc_can::expr::ExpectLookup;
 use roc_collections::{MutSet, VecMap};
 use roc_error_macros::internal_error;
 use roc_gen_llvm::{
-    llvm::{build::LlvmBackendMode, externs::add_default_roc_externs},
+    llvm::{build::LlvmBackendMode, debug_info::DebugSources, externs::add_default_roc_externs},
     run_roc::RocCallResult,
     run_roc_dylib,
 };
@@ -747,6 +747,7 @@ pub fn expect_mono_module_to_dylib<'a>(
         mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        debug_sources: DebugSources::default(),
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
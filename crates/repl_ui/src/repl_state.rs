@@ -131,6 +131,9 @@ impl ReplState {
                     ValueDef::ExpectFx { .. } => {
                         todo!("handle receiving an `expect-fx` - what should the repl do for that?")
                     }
+                    ValueDef::Malformed { .. } => {
+                        unreachable!("the repl doesn't recover from syntax errors in defs")
+                    }
                 }
             }
             ParseOutcome::TypeDef(TypeDef::Alias {
@@ -255,6 +255,8 @@ async fn eval_wasm<'a>(
                 .keys()
                 .copied()
                 .collect::<MutSet<_>>(),
+            debug_sources: roc_gen_wasm::DebugSources::default(),
+            features: roc_gen_wasm::WasmFeatures::default(),
         };
 
         let (mut module, mut called_fns, main_fn_index) = {
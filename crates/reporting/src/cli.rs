@@ -5,6 +5,8 @@ use roc_module::symbol::{Interns, ModuleId};
 use roc_region::all::LineInfo;
 use roc_solve_problem::TypeError;
 
+use crate::report::RenderTarget;
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Problems {
     pub fatally_errored: bool,
@@ -23,10 +25,19 @@ impl Problems {
     }
 
     pub fn print_to_stdout(&self, total_time: std::time::Duration) {
+        print!("{}", self.summary(total_time));
+    }
+
+    /// Like `print_to_stdout`, for when stdout is reserved for JSON reports.
+    pub fn print_to_stderr(&self, total_time: std::time::Duration) {
+        eprint!("{}", self.summary(total_time));
+    }
+
+    fn summary(&self, total_time: std::time::Duration) -> String {
         const GREEN: usize = 32;
         const YELLOW: usize = 33;
 
-        print!(
+        format!(
             "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms",
             match self.errors {
                 0 => GREEN,
@@ -47,7 +58,7 @@ impl Problems {
                 _ => "warnings",
             },
             total_time.as_millis()
-        );
+        )
     }
 }
 
@@ -56,6 +67,7 @@ pub fn report_problems(
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    render: RenderTarget,
 ) -> Problems {
     use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
     use roc_problem::Severity::*;
@@ -94,7 +106,7 @@ pub fn report_problems(
             let severity = report.severity;
             let mut buf = String::new();
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            report.render(render, &mut buf, &alloc, &palette);
 
             match severity {
                 Warning => {
@@ -117,7 +129,7 @@ pub fn report_problems(
                 let severity = report.severity;
                 let mut buf = String::new();
 
-                report.render_color_terminal(&mut buf, &alloc, &palette);
+                report.render(render, &mut buf, &alloc, &palette);
 
                 match severity {
                     Warning => {
@@ -140,6 +152,19 @@ pub fn report_problems(
 
     let problems_reported;
 
+    if let RenderTarget::Json = render {
+        // Tools reading JSON get every problem, one per line, and can filter them by severity.
+        for report in errors.iter().chain(warnings.iter()) {
+            print!("{report}");
+        }
+
+        return Problems {
+            fatally_errored,
+            errors: errors.len(),
+            warnings: warnings.len(),
+        };
+    }
+
     // Only print warnings if there are no errors
     if errors.is_empty() {
         problems_reported = warnings.len();
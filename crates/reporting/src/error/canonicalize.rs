@@ -6,6 +6,8 @@ use roc_problem::can::{
     BadPattern, CycleEntry, ExtensionTypeKind, FloatErrorKind, IntErrorKind, Problem, RuntimeError,
     ShadowKind,
 };
+use roc_problem::code;
+use roc_problem::lint::Lint;
 use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Region};
 use roc_types::types::AliasKind;
@@ -69,6 +71,7 @@ pub fn can_problem<'b>(
     let doc;
     let title;
     let severity = problem.severity();
+    let error_code = problem.code();
 
     match problem {
         Problem::UnusedDef(symbol, region) => {
@@ -1094,16 +1097,40 @@ pub fn can_problem<'b>(
             doc = report.doc;
             title = report.title;
         }
+        Problem::Denied(lint, problem) => {
+            let report = can_problem(alloc, lines, filename.clone(), *problem);
+            doc = to_denied_lint_doc(alloc, report.doc, lint);
+            title = report.title;
+        }
     };
 
     Report {
         title,
+        code: error_code,
         filename,
         doc,
         severity,
     }
 }
 
+/// Explains that a warning is reported as an error because of a `# lint: deny` comment.
+pub fn to_denied_lint_doc<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    doc: RocDocBuilder<'b>,
+    lint: Lint,
+) -> RocDocBuilder<'b> {
+    alloc.stack([
+        doc,
+        alloc.note("").append(alloc.concat([
+            alloc.reflow("This is an error because the "),
+            alloc.keyword(lint.name()),
+            alloc.reflow(" lint is set to "),
+            alloc.keyword("deny"),
+            alloc.reflow("."),
+        ])),
+    ])
+}
+
 fn list_builtin_abilities<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<'a> {
     alloc.intersperse(
         DERIVABLE_ABILITIES
@@ -1131,6 +1158,7 @@ fn to_invalid_optional_value_report<'b>(
 
     Report {
         title: "BAD OPTIONAL VALUE".to_string(),
+        code: code::BAD_OPTIONAL_VALUE,
         filename,
         doc,
         severity: Severity::RuntimeError,
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use bumpalo::Bump;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_parse::ast::Expr;
+use roc_problem::code;
 use roc_problem::Severity;
 use roc_region::all::{LineColumnRegion, LineInfo, Region};
 use roc_types::{
@@ -153,6 +154,7 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT FAILED".into(),
+            code: code::EXPECT_FAILED,
             doc,
             filename: self.filename.clone(),
             severity: Severity::RuntimeError,
@@ -224,6 +226,7 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT PANICKED".into(),
+            code: code::EXPECT_PANICKED,
             doc,
             filename: self.filename.clone(),
             severity: Severity::RuntimeError,
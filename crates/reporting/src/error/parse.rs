@@ -1,4 +1,5 @@
 use roc_parse::parser::{ENumber, ESingleQuote, FileError, PList, SyntaxError};
+use roc_problem::code;
 use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Position, Region};
 use std::path::PathBuf;
@@ -64,6 +65,7 @@ fn to_syntax_report<'a>(
         filename: filename.clone(),
         doc,
         title: "PARSE PROBLEM".to_string(),
+        code: code::SYNTAX_PROBLEM,
         severity: Severity::RuntimeError,
     };
 
@@ -78,6 +80,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: code::SYNTAX_PROBLEM,
                 severity: Severity::RuntimeError,
             }
         }
@@ -110,6 +113,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "NOT END OF FILE".to_string(),
+                code: code::SYNTAX_PROBLEM,
                 severity: Severity::RuntimeError,
             }
         }
@@ -123,6 +127,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: code::SYNTAX_PROBLEM,
                 severity: Severity::RuntimeError,
             }
         }
@@ -133,6 +138,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: code::SYNTAX_PROBLEM,
                 severity: Severity::RuntimeError,
             }
         }
@@ -214,6 +220,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -328,6 +335,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNKNOWN OPERATOR".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -354,6 +362,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IDENTIFIER".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -436,6 +445,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: title.to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -464,6 +474,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "MISSING FINAL EXPRESSION".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -495,6 +506,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "SYNTAX PROBLEM".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -518,6 +530,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -536,6 +549,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD BACKPASSING ARROW".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -554,6 +568,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "RECORD PARSE PROBLEM".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -574,6 +589,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD RECORD BUILDER".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -594,6 +610,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD RECORD UPDATE".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -660,6 +677,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "INDENT ENDS AFTER EXPRESSION".to_string(),
+                code: code::EXPRESSION_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -705,6 +723,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -727,6 +746,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -752,6 +772,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -774,6 +795,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -798,6 +820,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED ARGUMENT LIST".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -819,6 +842,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: code::FUNCTION_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -900,6 +924,7 @@ fn to_unfinished_lambda_report<'a>(
         filename,
         doc,
         title: "UNFINISHED FUNCTION".to_string(),
+        code: code::FUNCTION_SYNTAX,
         severity: Severity::RuntimeError,
     }
 }
@@ -966,6 +991,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD ESCAPE".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -992,6 +1018,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD CODE POINT".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1013,6 +1040,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS FORMAT".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1036,6 +1064,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS SCALAR".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1102,6 +1131,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INVALID SCALAR".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1125,6 +1155,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1149,6 +1180,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "EXPECTED STRING".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1172,6 +1204,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1193,6 +1226,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INSUFFICIENT INDENT IN MULTI-LINE STRING".to_string(),
+                code: code::STRING_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1235,6 +1269,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: code::PARENTHESES_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1259,6 +1294,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: code::PARENTHESES_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1284,6 +1320,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: code::PARENTHESES_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1334,6 +1371,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: code::LIST_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1367,6 +1405,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: code::LIST_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1514,6 +1553,7 @@ fn to_unfinished_if_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IF".to_string(),
+        code: code::IF_SYNTAX,
         severity: Severity::RuntimeError,
     }
 }
@@ -1547,6 +1587,7 @@ fn to_when_report<'a>(
                         filename,
                         doc,
                         title: "IF GUARD NO CONDITION".to_string(),
+                        code: code::WHEN_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1579,6 +1620,7 @@ fn to_when_report<'a>(
                 filename,
                 doc,
                 title: "MISSING ARROW".to_string(),
+                code: code::WHEN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1734,6 +1776,7 @@ fn to_unfinished_when_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED WHEN".to_string(),
+                code: code::WHEN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1774,6 +1817,7 @@ fn to_unexpected_arrow_report<'a>(
         filename,
         doc,
         title: "UNEXPECTED ARROW".to_string(),
+        code: code::WHEN_SYNTAX,
         severity: Severity::RuntimeError,
     }
 }
@@ -1850,6 +1894,7 @@ fn to_pattern_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PATTERN".to_string(),
+                code: code::PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -1894,6 +1939,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: code::RECORD_PATTERN_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1911,6 +1957,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: code::RECORD_PATTERN_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1936,6 +1983,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: code::RECORD_PATTERN_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1956,6 +2004,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: code::RECORD_PATTERN_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1981,6 +2030,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: code::RECORD_PATTERN_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2006,6 +2056,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD PATTERN".to_string(),
+                    code: code::RECORD_PATTERN_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2059,6 +2110,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                code: code::LIST_PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2081,6 +2133,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                code: code::LIST_PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2103,6 +2156,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "INCORRECT REST PATTERN".to_string(),
+                code: code::LIST_PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2146,6 +2200,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: code::PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2167,6 +2222,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: code::PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2191,6 +2247,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: code::PATTERN_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2219,6 +2276,7 @@ fn to_malformed_number_literal_report<'a>(
         filename,
         doc,
         title: "INVALID NUMBER LITERAL".to_string(),
+        code: code::NUMBER_LITERAL_SYNTAX,
         severity: Severity::RuntimeError,
     }
 }
@@ -2259,6 +2317,7 @@ fn to_type_report<'a>(
                         filename,
                         doc,
                         title: "DOUBLE COMMA".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2286,6 +2345,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2304,6 +2364,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2322,6 +2383,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2340,6 +2402,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED INLINE ALIAS".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2357,6 +2420,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "BAD TYPE VARIABLE".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2394,6 +2458,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: code::RECORD_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2415,6 +2480,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: code::RECORD_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2440,6 +2506,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: code::RECORD_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2460,6 +2527,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: code::RECORD_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2485,6 +2553,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: code::RECORD_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2510,6 +2579,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD TYPE".to_string(),
+                    code: code::RECORD_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2543,6 +2613,7 @@ fn to_trecord_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED RECORD TYPE".to_string(),
+                code: code::RECORD_TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2567,6 +2638,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: code::RECORD_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2592,6 +2664,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: code::RECORD_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2639,6 +2712,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: code::TAG_UNION_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2661,6 +2735,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD TAG NAME".to_string(),
+                    code: code::TAG_UNION_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2682,6 +2757,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: code::TAG_UNION_TYPE_SYNTAX,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2708,6 +2784,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: code::TAG_UNION_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2728,6 +2805,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
+                        code: code::TAG_UNION_TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2771,6 +2849,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2793,6 +2872,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2816,6 +2896,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2839,6 +2920,7 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2865,6 +2947,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2885,6 +2968,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2913,6 +2997,7 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -2937,6 +3022,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2962,6 +3048,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: code::TYPE_SYNTAX,
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2995,6 +3082,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "DOUBLE DOT".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3017,6 +3105,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING DOT".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3039,6 +3128,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3061,6 +3151,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3079,6 +3170,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "END OF FILE".to_string(),
+                code: code::TYPE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3119,6 +3211,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "NOT AN INLINE ALIAS".to_string(),
+                code: code::INLINE_ALIAS_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3135,6 +3228,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "QUALIFIED ALIAS NAME".to_string(),
+                code: code::INLINE_ALIAS_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3151,6 +3245,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "TYPE ARGUMENT NOT LOWERCASE".to_string(),
+                code: code::INLINE_ALIAS_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3197,6 +3292,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INCOMPLETE HEADER".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3235,6 +3331,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "MISSING HEADER".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3259,6 +3356,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3286,6 +3384,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3310,6 +3409,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD APP NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3332,6 +3432,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PACKAGE NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3355,6 +3456,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PLATFORM NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3378,6 +3480,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATED TYPE NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3418,6 +3521,7 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3443,6 +3547,7 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3484,6 +3589,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3509,6 +3615,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3549,6 +3656,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3574,6 +3682,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3613,6 +3722,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3638,6 +3748,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3664,6 +3775,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3683,6 +3795,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3722,6 +3835,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3749,6 +3863,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3778,6 +3893,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES RIGIDS".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3807,6 +3923,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3844,6 +3961,7 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "MISSING PACKAGES".to_string(),
+                code: code::HEADER_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3877,6 +3995,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "TAB CHARACTER".to_string(),
+                code: code::WHITESPACE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3894,6 +4013,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "ASCII CONTROL CHARACTER".to_string(),
+                code: code::WHITESPACE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3911,6 +4031,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "MISPLACED CARRIAGE RETURN".to_string(),
+                code: code::WHITESPACE_SYNTAX,
                 severity: Severity::RuntimeError,
             }
         }
@@ -3992,6 +4113,7 @@ fn to_unfinished_ability_report<'a>(
         filename,
         doc,
         title: "UNFINISHED ABILITY".to_string(),
+        code: code::ABILITY_SYNTAX,
         severity: Severity::RuntimeError,
     }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::too_many_arguments)]
 
-use crate::error::canonicalize::{to_circular_def_doc, CIRCULAR_DEF};
-use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder};
+use crate::error::canonicalize::{to_circular_def_doc, to_denied_lint_doc, CIRCULAR_DEF};
+use crate::report::{Annotation, CiWrite, Report, RocDocAllocator, RocDocBuilder};
 use itertools::EitherOrBoth;
 use itertools::Itertools;
 use roc_can::expected::{Expected, PExpected};
@@ -12,6 +12,7 @@ use roc_exhaustive::{CtorName, ListArity};
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::{IdentStr, Lowercase, TagName};
 use roc_module::symbol::Symbol;
+use roc_problem::code;
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::{
@@ -44,11 +45,13 @@ pub fn type_problem<'b>(
     use TypeError::*;
 
     let severity = problem.severity();
+    let error_code = problem.code();
 
     let report =
         move |title: String, doc: RocDocBuilder<'b>, filename: PathBuf| -> Option<Report<'b>> {
             Some(Report {
                 title,
+                code: error_code,
                 filename,
                 doc,
                 severity,
@@ -110,6 +113,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -132,6 +136,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -145,6 +150,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title,
+                code: error_code,
                 filename,
                 doc,
                 severity,
@@ -175,6 +181,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "ILLEGAL SPECIALIZATION".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -204,6 +211,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "WRONG SPECIALIZATION TYPE".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -220,6 +228,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID UTF-8".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -243,11 +252,19 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID TYPE FOR INGESTED FILE".to_string(),
+                code: error_code,
                 filename,
                 doc: alloc.stack(stack),
                 severity,
             })
         }
+        Denied(lint, problem) => {
+            type_problem(alloc, lines, filename, *problem).map(|report| Report {
+                doc: to_denied_lint_doc(alloc, report.doc, lint),
+                severity,
+                ..report
+            })
+        }
     }
 }
 
@@ -560,6 +577,7 @@ fn report_mismatch<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: code::TYPE_MISMATCH,
         filename,
         doc: alloc.stack(lines),
         severity,
@@ -603,6 +621,7 @@ fn report_bad_type<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: code::TYPE_MISMATCH,
         filename,
         doc: alloc.stack(lines),
         severity,
@@ -687,6 +706,7 @@ fn to_expr_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: code::TYPE_MISMATCH,
                 doc: alloc.stack([
                     alloc.text("This expression is used in an unexpected way:"),
                     alloc.region(lines.convert_region(expr_region)),
@@ -801,6 +821,7 @@ fn to_expr_report<'b>(
 
             Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: code::TYPE_MISMATCH,
                 filename,
                 doc: alloc.stack([
                     alloc.text("Something is off with the ").append(thing),
@@ -1205,6 +1226,7 @@ fn to_expr_report<'b>(
                     Report {
                         filename,
                         title: "TOO MANY ARGS".to_string(),
+                        code: code::TYPE_MISMATCH,
                         doc,
                         severity,
                     }
@@ -1240,6 +1262,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO MANY ARGS".to_string(),
+                            code: code::TYPE_MISMATCH,
                             doc: alloc.stack(lines),
                             severity,
                         }
@@ -1267,6 +1290,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO FEW ARGS".to_string(),
+                            code: code::TYPE_MISMATCH,
                             doc: alloc.stack(lines),
                             severity,
                         }
@@ -1467,6 +1491,7 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::TYPE_MISMATCH,
                     filename,
                     doc: alloc.stack(lines),
                     severity,
@@ -1505,6 +1530,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::TYPE_MISMATCH,
                     doc,
                     severity,
                 }
@@ -1541,6 +1567,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::TYPE_MISMATCH,
                     doc: alloc.stack(lines),
                     severity,
                 }
@@ -1954,6 +1981,7 @@ fn to_pattern_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: code::PATTERN_TYPE_MISMATCH,
                 doc,
                 severity,
             }
@@ -1997,6 +2025,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::PATTERN_TYPE_MISMATCH,
                     doc,
                     severity,
                 }
@@ -2077,6 +2106,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::PATTERN_TYPE_MISMATCH,
                     doc,
                     severity,
                 }
@@ -2104,6 +2134,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: code::PATTERN_TYPE_MISMATCH,
                     doc,
                     severity,
                 }
@@ -2188,6 +2219,7 @@ fn to_circular_report<'b>(
 ) -> Report<'b> {
     Report {
         title: "CIRCULAR TYPE".to_string(),
+        code: code::CIRCULAR_TYPE,
         filename,
         doc: {
             alloc.stack([
@@ -4879,6 +4911,7 @@ fn report_record_field_typo<'b>(
     Report {
         filename,
         title: "TYPE MISMATCH".to_string(),
+        code: code::TYPE_MISMATCH,
         doc,
         severity,
     }
@@ -4916,6 +4949,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: code::UNSAFE_PATTERN,
                     doc,
                     severity,
                 }
@@ -4940,6 +4974,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: code::UNSAFE_PATTERN,
                     doc,
                     severity,
                 }
@@ -4964,6 +4999,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: code::UNSAFE_PATTERN,
                     doc,
                     severity,
                 }
@@ -4993,6 +5029,7 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "REDUNDANT PATTERN".to_string(),
+                code: code::REDUNDANT_PATTERN,
                 doc,
                 severity,
             }
@@ -5021,6 +5058,7 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "UNMATCHABLE PATTERN".to_string(),
+                code: code::UNMATCHABLE_PATTERN,
                 doc,
                 severity,
             }
@@ -5042,6 +5080,59 @@ pub fn unhandled_patterns_to_doc_block<'b>(
         .annotate(Annotation::TypeBlock)
 }
 
+/// Renders patterns that a `when` doesn't cover as Roc source, for an editor to insert as new
+/// branches. A pattern that is only missing because its branch has an `if` guard is rendered
+/// without the guard, and a missing range of integers is rendered as `_`.
+pub fn unhandled_patterns_to_source<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    patterns: &[roc_exhaustive::Pattern],
+) -> Vec<String> {
+    use roc_exhaustive::{Pattern::Ctor, RenderAs};
+
+    let mut sources: Vec<String> = Vec::with_capacity(patterns.len());
+
+    for pattern in patterns {
+        let pattern = match pattern {
+            Ctor(union, _, args) if matches!(union.render_as, RenderAs::Guard) => args[1].clone(),
+            _ => pattern.clone(),
+        };
+
+        let mut buf = String::new();
+
+        exhaustive_pattern_to_doc(alloc, int_ranges_to_wildcards(pattern))
+            .annotate(Annotation::TypeBlock)
+            .1
+            .render_raw(1000, &mut CiWrite::new(&mut buf))
+            .expect("<buffer is not a utf-8 encoded string>");
+
+        // Distinct ranges can become the same pattern once they're wildcards.
+        if !sources.contains(&buf) {
+            sources.push(buf);
+        }
+    }
+
+    sources
+}
+
+/// Roc has no range patterns, so replaces every range that isn't a single integer with `_`.
+fn int_ranges_to_wildcards(pattern: roc_exhaustive::Pattern) -> roc_exhaustive::Pattern {
+    use roc_exhaustive::Pattern::*;
+
+    match pattern {
+        IntRange(range) if !range.is_singleton() => Anything,
+        Ctor(union, tag_id, args) => Ctor(
+            union,
+            tag_id,
+            args.into_iter().map(int_ranges_to_wildcards).collect(),
+        ),
+        List(arity, patterns) => List(
+            arity,
+            patterns.into_iter().map(int_ranges_to_wildcards).collect(),
+        ),
+        Anything | Literal(_) | IntRange(_) => pattern,
+    }
+}
+
 fn exhaustive_pattern_to_doc<'b>(
     alloc: &'b RocDocAllocator<'b>,
     pattern: roc_exhaustive::Pattern,
@@ -5075,6 +5166,13 @@ fn pattern_to_doc_help<'b>(
             Decimal(d) => alloc.text(RocDec::from_ne_bytes(d).to_string()),
             Str(s) => alloc.string(s.into()),
         },
+        IntRange(range) => {
+            if range.is_singleton() {
+                alloc.text(range.start.to_string())
+            } else {
+                alloc.text(format!("{}..={}", range.start, range.end))
+            }
+        }
         List(arity, patterns) => {
             let inner = match arity {
                 ListArity::Exact(_) => alloc.intersperse(
@@ -5131,7 +5229,7 @@ fn pattern_to_doc_help<'b>(
                             Anything => {
                                 arg_docs.push(alloc.text(label.to_string()));
                             }
-                            Literal(_) | Ctor(_, _, _) | List(..) => {
+                            Literal(_) | IntRange(_) | Ctor(_, _, _) | List(..) => {
                                 arg_docs.push(
                                     alloc
                                         .text(label.to_string())
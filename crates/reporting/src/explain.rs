@@ -0,0 +1,1005 @@
+//! Longer explanations of the problems behind each [`ErrorCode`], printed by `roc explain`.
+use roc_problem::code::{self, ErrorCode};
+
+/// The explanation for a code, or `None` if that code hasn't been given one yet.
+pub fn explanation(error_code: ErrorCode) -> Option<&'static str> {
+    let text = match error_code {
+        code::COMPILER_BUG => {
+            r#"The compiler ran into a problem that should never happen.
+
+This is a bug in the Roc compiler rather than in your code. Please report it at
+https://github.com/roc-lang/roc/issues along with the code that caused it,
+trimmed down as much as you can.
+"#
+        }
+
+        code::UNUSED_DEF => {
+            r#"A definition is never used.
+
+    main =
+        x = 1
+        "Hello"
+
+Here `x` is defined but nothing refers to it. Remove the definition, or use it.
+If it's something you are still working on, you can ignore the warning for now.
+"#
+        }
+        code::UNUSED_IMPORT => {
+            r#"A module or a value is imported but never used.
+
+    app "example"
+        packages { pf: "platform/main.roc" }
+        imports [pf.Stdout, Dict]
+        provides [main] to pf
+
+    main = Stdout.line "Hi!"
+
+Nothing here uses `Dict`, so it can be removed from the `imports` list.
+"#
+        }
+        code::UNUSED_ARGUMENT => {
+            r#"A function argument is never used in the function's body.
+
+    greet = \name, greeting -> "Hello, \(name)!"
+
+If the argument is needed to match a type, or to be passed in by callers, prefix
+its name with an underscore (`_greeting`), or use `_` on its own, to make it clear
+that it's ignored on purpose.
+"#
+        }
+        code::DEFS_ONLY_USED_IN_RECURSION => {
+            r#"A definition is only ever used by itself (or by a group of definitions that
+call each other), and nothing else uses it.
+
+    countdown = \n -> if n == 0 then [] else List.prepend (countdown (n - 1)) n
+
+    main = "Hello"
+
+Because `main` never calls `countdown`, it is dead code.
+"#
+        }
+        code::MISSING_DEFINITION => {
+            r#"A module's header exposes a name that the module never defines.
+
+    interface Shapes
+        exposes [area, perimeter]
+        imports []
+
+    area = \w, h -> w * h
+
+Either define `perimeter` in the module, or remove it from `exposes`.
+"#
+        }
+        code::UNKNOWN_GENERATES_WITH => {
+            r#"A platform's `generates ... with` clause names a function that effects can't
+be given.
+
+    generates Effect with [after, map, always, foobar]
+
+Only `after`, `map`, `always`, `forever` and `loop` can be generated.
+"#
+        }
+        code::DUPLICATE_NAME => {
+            r#"A name is defined more than once in the same scope.
+
+    x = 1
+
+    main =
+        x = 2
+        x
+
+Roc doesn't allow shadowing, because it makes it easy to confuse one value for
+another. Give the inner definition a different name.
+"#
+        }
+        code::UNRECOGNIZED_NAME => {
+            r#"Nothing with this name is in scope.
+
+    main = Str.concat greeting "!"
+
+This usually means a typo, a definition that hasn't been written yet, or a value
+from another module that hasn't been qualified or imported. The report lists
+similar names that are in scope.
+"#
+        }
+        code::VALUE_NOT_EXPOSED => {
+            r#"A module is used for a value that it does not expose.
+
+    main = List.isempty []
+
+Only the names in a module's `exposes` list can be used from outside it. Check
+the spelling (here, `List.isEmpty`), or expose the value from its module.
+"#
+        }
+        code::MODULE_NOT_IMPORTED => {
+            r#"A qualified name refers to a module that hasn't been imported.
+
+    main = Json.encode {}
+
+Add the module to the `imports` list in the header.
+"#
+        }
+        code::CIRCULAR_DEF => {
+            r#"A value is defined in terms of itself without going through a function.
+
+    x = y + 1
+    y = x + 1
+
+Values are computed once, so there is no way to compute `x` here. Only functions
+can refer to themselves, directly or through other definitions.
+"#
+        }
+        code::OPERATOR_PRECEDENCE => {
+            r#"Operators are combined in a way that needs parentheses.
+
+    a == b != c
+
+Comparison operators don't chain, because it's unclear how they should be
+grouped. Add parentheses to say which comes first: `(a == b) != c`.
+"#
+        }
+        code::UNSUPPORTED_PATTERN => {
+            r#"A kind of pattern is used where it isn't allowed.
+
+    f = \1 -> "one"
+
+Function arguments and plain definitions can only use patterns that always
+match. Patterns that can fail, like number literals or tags of a union with more
+than one tag, belong in a `when` expression.
+"#
+        }
+        code::MALFORMED_PATTERN => {
+            r#"A pattern can't be understood.
+
+    when n is
+        0x -> "zero"
+        _ -> "other"
+
+This usually comes from a literal in a pattern that isn't valid, like a number
+with no digits or one that's too large for its type.
+"#
+        }
+        code::NOT_BOUND_IN_ALL_PATTERNS => {
+            r#"The alternatives of a `when` branch don't all bind the same names.
+
+    when result is
+        Ok x | Err _ -> x
+
+When `result` is an `Err`, there is no `x` to use. Every pattern separated by
+`|` must bind the same names, or the branch must be split in two.
+"#
+        }
+        code::NO_IDENTIFIERS_INTRODUCED => {
+            r#"A destructuring definition doesn't bind any names.
+
+    {} = doSomething
+
+Since nothing is bound, the definition has no effect. Remove it, or bind the
+value to a name with `_` in front if you want to keep it for later.
+"#
+        }
+        code::MULTIPLE_LIST_REST_PATTERNS => {
+            r#"A list pattern uses `..` more than once.
+
+    when list is
+        [.., x, ..] -> x
+        _ -> 0
+
+It's ambiguous which elements each `..` would match. A list pattern may contain
+at most one rest pattern.
+"#
+        }
+        code::MALFORMED_IDENTIFIER => {
+            r#"A name is written in a way that isn't allowed.
+
+    my_value = 1
+
+Roc names use camelCase without underscores. A leading underscore is only
+allowed for values that are never used, and type names must start with a
+capital letter.
+"#
+        }
+        code::MALFORMED_FUNCTION => {
+            r#"A function definition is malformed.
+
+    f = \ -> 1
+
+A function needs at least one argument pattern between `\` and `->`. Use `\{}`
+for a function that doesn't need any input.
+"#
+        }
+        code::DUPLICATE_FIELD_NAME => {
+            r#"A record or record type mentions the same field more than once.
+
+    point = { x: 1, y: 2, x: 3 }
+
+Each field name can only appear once. Remove or rename one of them.
+"#
+        }
+        code::BAD_OPTIONAL_VALUE => {
+            r#"A default value (`? value`) is used where it isn't allowed.
+
+    config = { size ? 12 }
+
+Default values for optional fields can only appear in record destructuring in
+function arguments, like `\{ size ? 12 } -> size`. Use `:` to give a field a
+value.
+"#
+        }
+        code::BAD_RECORD_UPDATE => {
+            r#"A record update is applied to something other than a plain name.
+
+    { makeConfig {} & size: 12 }
+
+Only a name can come before `&`. Bind the record to a name first:
+
+    config = makeConfig {}
+    { config & size: 12 }
+"#
+        }
+        code::INVALID_NUMBER => {
+            r#"A number literal isn't valid.
+
+    x = 300u8
+    y = 1e
+
+The number may be out of range for its type, have a suffix that doesn't exist,
+or contain characters that can't appear in a number.
+"#
+        }
+        code::INVALID_STRING_ESCAPE => {
+            r#"A string contains an invalid escape or interpolation.
+
+    "\u(110000)"
+    "foo \(Str.joinWith ["a\(x)"] "")"
+
+Unicode escapes must name a valid code point in hexadecimal, and string
+interpolations can't contain newlines or other interpolations. Move complicated
+expressions into their own definition and interpolate that.
+"#
+        }
+        code::INVALID_SINGLE_QUOTE => {
+            r#"A single-quoted character literal is empty or has more than one character.
+
+    a = ''
+    b = 'ab'
+
+Single quotes hold exactly one character. Use double quotes for strings.
+"#
+        }
+        code::DEGENERATE_BRANCH => {
+            r#"A `when` branch's body needs a name that its pattern doesn't always bind.
+
+The pattern of every branch has to introduce all of the names its body uses,
+whichever of its alternatives matched. Split the branch up so each alternative
+gets a body that only uses what it binds.
+"#
+        }
+        code::MULTIPLE_RECORD_BUILDERS => {
+            r#"A function is applied to more than one record builder.
+
+    succeed { a: <- foo } { b: <- bar }
+
+Combine the fields into a single record builder, or apply each one separately.
+"#
+        }
+        code::UNAPPLIED_RECORD_BUILDER => {
+            r#"A record builder isn't given to a function.
+
+    { a: <- foo, b: <- bar }
+
+Record builders have to be passed as an argument, usually to a function that
+starts the chain, like `succeed { a: <- foo, b: <- bar }`.
+"#
+        }
+        code::CRASH_ARGUMENTS => {
+            r#"`crash` is used without a message, or with more than one.
+
+    crash
+    crash "oops" "again"
+
+`crash` takes exactly one `Str` message, like `crash "unreachable"`.
+"#
+        }
+
+        code::CYCLIC_ALIAS => {
+            r#"A type alias refers to itself in a way that would make it infinitely large.
+
+    Foo : { x : Bar }
+    Bar : { y : Foo }
+
+Aliases are expanded where they are used, so they can't be recursive. Recursion
+is only allowed through a tag union, like `Tree : [Leaf, Node Tree Tree]`, or by
+making the type opaque.
+"#
+        }
+        code::UNUSED_ALIAS_PARAMETER => {
+            r#"A type alias has a type parameter that its definition doesn't use.
+
+    Pair a b : { first : a, second : a }
+
+Every parameter must appear in the definition. Remove `b`, or use it.
+"#
+        }
+        code::UNBOUND_TYPE_VARIABLE => {
+            r#"A type alias or opaque type uses a type variable that it doesn't declare.
+
+    Result2 ok : [Ok ok, Err err]
+
+Add the variable to the parameters, like `Result2 ok err : ...`.
+"#
+        }
+        code::DUPLICATE_TAG_NAME => {
+            r#"A tag union type mentions the same tag more than once.
+
+    Color : [Red, Green, Red]
+
+Each tag can only appear once in a tag union.
+"#
+        }
+        code::SIGNATURE_DEF_MISMATCH => {
+            r#"A type annotation and the definition below it don't have the same pattern.
+
+    x : I64
+    y = 4
+
+An annotation must be immediately followed by the definition it describes, with
+the same name.
+"#
+        }
+        code::INVALID_ALIAS_RIGID => {
+            r#"A type alias is declared with something other than type variables as its
+parameters.
+
+    Pair (List a) : { first : a }
+
+Only lowercase type variables, like `a` or `elem`, can be parameters.
+"#
+        }
+        code::NESTED_DATATYPE => {
+            r#"A recursive type uses itself with different type arguments than its definition.
+
+    Nested a : [Chain a (Nested (List a)), Term]
+
+Recursive uses of a type must pass along the same type variables it was
+defined with. Nested datatypes like this one aren't supported.
+"#
+        }
+        code::INVALID_EXTENSION_TYPE => {
+            r#"A record or tag union is extended with a type that isn't a record or tag union.
+
+    f : { x : Str }U64
+
+Only a type variable, `*`, or another record (or tag union, for tag unions)
+can come after the closing brace or bracket.
+"#
+        }
+        code::BAD_TYPE_ARGUMENTS => {
+            r#"A type is given the wrong number of type arguments.
+
+    x : List
+    y : Str U8
+
+Give the type exactly as many arguments as its definition has parameters.
+"#
+        }
+        code::OPAQUE_NOT_DEFINED => {
+            r#"An opaque type is referenced with `@` but no opaque type by that name is in
+scope.
+
+    age = @Age 21
+
+Define it with `:=` in this module, like `Age := U32`. If a type alias with
+that name exists, it can't be used with `@`.
+"#
+        }
+        code::OPAQUE_DECLARED_OUTSIDE_SCOPE => {
+            r#"An opaque type is wrapped or unwrapped outside of the module that defines it.
+
+    age = @Age 21
+
+Only the defining module can see inside an opaque type. Other modules should use
+the functions it exposes to create and inspect values of that type.
+"#
+        }
+        code::OPAQUE_NOT_APPLIED => {
+            r#"An opaque type is referenced without the value it wraps.
+
+    age = @Age
+
+An opaque type constructor must be applied to exactly one argument, like
+`@Age 21`.
+"#
+        }
+        code::OPAQUE_OVER_APPLIED => {
+            r#"An opaque type is applied to more than one value.
+
+    point = @Point 1 2
+
+An opaque type wraps exactly one value. Wrap a record or tuple instead, like
+`@Point { x: 1, y: 2 }`.
+"#
+        }
+        code::UNNECESSARY_OUTPUT_WILDCARD => {
+            r#"A type annotation has a `*` that doesn't change what it means.
+
+    f : Str -> [A, B]*
+
+Tag unions that are returned from functions, or that are constants, work the
+same with or without a `*` at the end. Remove it. (A `*` on a tag union that is a
+function argument does mean something different.)
+"#
+        }
+        code::ABILITY_HAS_TYPE_VARIABLES => {
+            r#"An ability is declared with type variables.
+
+    MHash a implements hash : a -> U64 where a implements MHash
+
+Abilities don't take type parameters. Remove them from the name:
+`MHash implements hash : a -> U64 where a implements MHash`.
+"#
+        }
+        code::IMPLEMENTS_CLAUSE_IS_NOT_AN_ABILITY => {
+            r#"An `implements` clause names something that isn't an ability.
+
+    f : a -> U64 where a implements Str
+
+Only abilities, like `Hash` or `Eq`, can appear after `implements`.
+"#
+        }
+        code::ILLEGAL_IMPLEMENTS_CLAUSE => {
+            r#"An `implements` clause appears where it isn't allowed.
+
+    Foo a : a where a implements Hash
+
+Ability constraints with `where ... implements` are only allowed on type
+annotations of values and on the members of an ability.
+"#
+        }
+        code::DUPLICATE_BOUND_ABILITY => {
+            r#"A type variable is bound to the same ability more than once.
+
+    f : a -> a where a implements Hash & Hash
+
+Each ability only needs to be listed once.
+"#
+        }
+        code::ABILITY_MEMBER_MISSING_IMPLEMENTS_CLAUSE => {
+            r#"An ability member's type doesn't mention the ability it belongs to.
+
+    MHash implements hash : a -> U64
+
+Every member needs a type variable bound to its ability, so the compiler can
+tell which implementation to use: `hash : a -> U64 where a implements MHash`.
+"#
+        }
+        code::ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES => {
+            r#"An ability member binds more than one type variable to its ability.
+
+    MEq implements eq : a, b -> Bool where a implements MEq, b implements MEq
+
+Only one type variable can be bound to the parent ability. Use it everywhere
+that type should appear: `eq : a, a -> Bool where a implements MEq`.
+"#
+        }
+        code::ABILITY_NOT_ON_TOPLEVEL => {
+            r#"An ability is defined inside another definition.
+
+    main =
+        MHash implements hash : a -> U64 where a implements MHash
+        1
+
+Abilities can only be defined at the top level of a module.
+"#
+        }
+        code::ABILITY_USED_AS_TYPE => {
+            r#"An ability is used where a type is expected.
+
+    f : MHash -> U64
+
+Abilities aren't types. Use a type variable bound to the ability instead:
+`f : a -> U64 where a implements MHash`.
+"#
+        }
+        code::SPECIALIZATION_NOT_ON_TOPLEVEL => {
+            r#"An implementation of an ability member is defined inside another definition.
+
+Ability implementations must be defined at the top level of the module that
+defines the opaque type they are for.
+"#
+        }
+        code::ILLEGAL_DERIVE => {
+            r#"An `implements` list asks to derive an ability that can't be derived.
+
+    Id := U64 implements [MHash]
+
+Only builtin abilities, like `Eq`, `Hash`, `Encoding` and `Decoding`, can be
+derived. Custom abilities need an explicit implementation, like
+`implements [MHash { hash: hashId }]`.
+"#
+        }
+        code::IMPLEMENTATION_NOT_FOUND => {
+            r#"An ability implementation refers to a function that isn't in scope.
+
+    Id := U64 implements [MHash { hash }]
+
+Either define `hash` in this module, or point at the function that implements
+it, like `MHash { hash: hashId }`.
+"#
+        }
+        code::NOT_AN_ABILITY_MEMBER => {
+            r#"An ability implementation provides something that isn't one of the ability's
+members.
+
+    Id := U64 implements [MEq { eq: idEq, ne: idNe }]
+
+Only implement the members the ability declares.
+"#
+        }
+        code::OPTIONAL_ABILITY_IMPLEMENTATION => {
+            r#"An ability implementation uses `?`, like an optional record field.
+
+    Id := U64 implements [MEq { eq ? idEq }]
+
+All implementations are required. Use `:` instead, as in `MEq { eq: idEq }`.
+"#
+        }
+        code::QUALIFIED_ABILITY_IMPLEMENTATION => {
+            r#"An ability implementation is given as a qualified name.
+
+    Id := U64 implements [MEq { eq: Other.eq }]
+
+Implementations must be defined in the same module as the opaque type. Define a
+local function, even if it only calls `Other.eq`.
+"#
+        }
+        code::ABILITY_IMPLEMENTATION_NOT_IDENTIFIER => {
+            r#"An ability implementation is an expression rather than a name.
+
+    Id := U64 implements [MEq { eq: \a, b -> a == b }]
+
+Define the implementation as a top-level function and refer to it by name.
+"#
+        }
+        code::DUPLICATE_IMPLEMENTATION => {
+            r#"An ability member is implemented more than once for the same type.
+
+    Id := U64 implements [MEq { eq: idEq, eq: otherEq }]
+
+Keep only one of the implementations.
+"#
+        }
+        code::NOT_AN_ABILITY => {
+            r#"An `implements` list names something that isn't an ability.
+
+    Id := U64 implements [Str]
+
+Only abilities can appear in the list.
+"#
+        }
+        code::UNNECESSARY_IMPLEMENTATIONS => {
+            r#"An ability is given an explicit implementation for a type that derives it.
+
+    Id := U64 implements [Eq { isEq: idEq }]
+
+Some builtin abilities have implementations the compiler always provides.
+Remove the explicit implementation and just list the ability.
+"#
+        }
+        code::INCOMPLETE_ABILITY_IMPLEMENTATION => {
+            r#"An opaque type says it implements an ability, but doesn't implement all of its
+members.
+
+    MEq implements
+        eq : a, a -> Bool where a implements MEq
+        ne : a, a -> Bool where a implements MEq
+
+    Id := U64 implements [MEq { eq: idEq }]
+
+Every member must be implemented. Add an implementation for `ne`.
+"#
+        }
+        code::OVERLOADED_SPECIALIZATION => {
+            r#"A single function is used to implement an ability member for more than one
+type.
+
+Each implementation is specific to one opaque type. Define a separate function
+for each type, even if they share a helper.
+"#
+        }
+
+        code::TYPE_MISMATCH => {
+            r#"An expression's type doesn't match what the surrounding code expects.
+
+    if "foo" then 2 else 3
+
+Here the `if` condition is a `Str` but must be a `Bool`. The report shows both
+the type that was found and the type that was needed. This is also the code for
+functions called with too many or too few arguments, and for typos in record
+field names.
+"#
+        }
+        code::PATTERN_TYPE_MISMATCH => {
+            r#"A pattern's type doesn't match the value it's matched against.
+
+    when "text" is
+        1 -> "one"
+        _ -> "other"
+
+The patterns of a `when` must have the same type as the value being matched.
+"#
+        }
+        code::CIRCULAR_TYPE => {
+            r#"A value's inferred type would contain itself, making it infinitely large.
+
+    f = \x -> f [x]
+
+Each call wraps the argument in another list, so the type of `x` would be
+`List (List (List ...))`. This usually comes from a mistake in a recursive
+function; a type annotation helps narrow down where.
+"#
+        }
+        code::MISSING_ABILITY => {
+            r#"A value is used in a way that requires an ability its type doesn't have.
+
+    f = \x -> x
+    main = f == f
+
+Functions can't be compared with `==`, because they don't implement `Eq`. The
+report lists which ability is missing and, for opaque types, how to add it with
+an `implements` list.
+"#
+        }
+        code::UNSAFE_PATTERN => {
+            r#"A `when` doesn't cover every possible value, or a destructuring pattern might
+not match.
+
+    when color is
+        Red -> "red"
+        Green -> "green"
+
+If `color` is `Blue`, nothing matches and the program would crash. Add branches
+for the missing cases listed in the report, or a `_` branch to catch the rest.
+"#
+        }
+        code::REDUNDANT_PATTERN => {
+            r#"A `when` branch can never be reached, because earlier branches already cover
+everything it would match.
+
+    when n is
+        _ -> "anything"
+        0 -> "zero"
+
+Remove the unreachable branch, or move it before the branch that covers it.
+"#
+        }
+        code::UNMATCHABLE_PATTERN => {
+            r#"A pattern can never match, because its type has no values.
+
+    when result is
+        Ok x -> x
+        Err e -> crash "impossible"
+
+If the error type of `result` is an empty tag union `[]`, the `Err` branch can
+never run and can be removed.
+"#
+        }
+        code::ILLEGAL_SPECIALIZATION => {
+            r#"An ability is implemented for a structural type, like a record or a tag union.
+
+Abilities can only be implemented for opaque types, which are defined with `:=`
+and list the ability in their `implements` list.
+"#
+        }
+        code::WRONG_SPECIALIZATION_TYPE => {
+            r#"An ability member's implementation has a type that doesn't match the member.
+
+    MHash implements hash : a -> U64 where a implements MHash
+
+    Id := U64 implements [MHash { hash: hashId }]
+
+    hashId : Str -> U64
+
+`hashId` must take an `Id`, because it's the implementation for `Id`.
+"#
+        }
+        code::INGESTED_FILE_BAD_UTF8 => {
+            r#"A file is imported as a `Str`, but its contents aren't valid UTF-8.
+
+Import the file as `List U8` to get its raw bytes instead, and decode them
+yourself.
+"#
+        }
+        code::INVALID_INGESTED_FILE_TYPE => {
+            r#"A file is imported with a type other than `Str` or `List U8`.
+
+    imports ["data.json" as data : U64]
+
+Ingested files can only be read as text (`Str`) or bytes (`List U8`).
+"#
+        }
+
+        code::SYNTAX_PROBLEM => {
+            r#"The parser couldn't understand part of the file.
+
+The report points to where the parser got stuck and, usually, what it was
+expecting to find. The mistake is often just before that spot, such as an
+unclosed bracket or a missing operator.
+"#
+        }
+        code::EXPRESSION_SYNTAX => {
+            r#"An expression or definition isn't written correctly.
+
+    main =
+        x = 1
+
+A definition needs a final expression after its nested definitions. Other
+common causes are unknown operators like `=>` or `===`, arguments before the `=`
+of a definition, and badly formed records or backpassing arrows.
+"#
+        }
+        code::FUNCTION_SYNTAX => {
+            r#"A function (lambda) isn't written correctly.
+
+    f = \x => x + 1
+
+Functions are written with a backslash, the arguments separated by commas, and
+a thin arrow: `\x, y -> x + y`.
+"#
+        }
+        code::STRING_SYNTAX => {
+            r#"A string literal isn't written correctly.
+
+    greeting = "Hello
+
+Strings must be closed on the same line with `"`, or use `"""` for multi-line
+strings. Only `\\`, `\"`, `\n`, `\r`, `\t`, `\$`, `\u(...)` and `\(...)` are valid
+escapes.
+"#
+        }
+        code::PARENTHESES_SYNTAX => {
+            r#"A parenthesized expression or tuple isn't closed.
+
+    x = (1 + 2
+
+Add the missing `)`.
+"#
+        }
+        code::LIST_SYNTAX => {
+            r#"A list literal isn't written correctly.
+
+    numbers = [1, 2 3]
+
+Elements are separated by commas and the list is closed with `]`.
+"#
+        }
+        code::IF_SYNTAX => {
+            r#"An `if` expression isn't written correctly.
+
+    x = if ready then 1
+
+Every `if` needs both a `then` and an `else` branch, because it always has to
+produce a value.
+"#
+        }
+        code::WHEN_SYNTAX => {
+            r#"A `when` expression isn't written correctly.
+
+    when x
+        1 -> "one"
+
+A `when` is written `when value is` followed by indented branches, each with a
+pattern, an optional `if` guard, `->` and an expression. All branches must be
+indented the same amount.
+"#
+        }
+        code::PATTERN_SYNTAX => {
+            r#"A pattern isn't written correctly.
+
+    when x is
+        1 + 1 -> "two"
+
+Patterns can be names, literals, tags, records, lists, tuples, `_` and
+alternatives separated by `|`, but not arbitrary expressions.
+"#
+        }
+        code::RECORD_PATTERN_SYNTAX => {
+            r#"A record pattern isn't written correctly.
+
+    f = \{ x y } -> x
+
+Fields in a record pattern are separated by commas, and can have a nested
+pattern after `:` or a default value after `?`.
+"#
+        }
+        code::LIST_PATTERN_SYNTAX => {
+            r#"A list pattern isn't written correctly.
+
+    when list is
+        [first, .. -> first
+
+Close the list pattern with `]`, and separate the elements with commas.
+"#
+        }
+        code::NUMBER_LITERAL_SYNTAX => {
+            r#"A number literal, or a character literal, isn't written correctly.
+
+    x = 0xZZ
+
+Hexadecimal, octal and binary literals must only contain digits of their base,
+and character literals contain exactly one character between single quotes.
+"#
+        }
+        code::TYPE_SYNTAX => {
+            r#"A type annotation isn't written correctly.
+
+    f : Str, -> Str
+
+Check for stray commas, unclosed parentheses, and type names that don't start
+with a capital letter.
+"#
+        }
+        code::RECORD_TYPE_SYNTAX => {
+            r#"A record type isn't written correctly.
+
+    Point : { x : F64 y : F64 }
+
+Fields are written `name : Type` and separated by commas.
+"#
+        }
+        code::TAG_UNION_TYPE_SYNTAX => {
+            r#"A tag union type isn't written correctly.
+
+    Color : [Red, Green, blue]
+
+Tags start with a capital letter, are separated by commas, and the union is
+closed with `]`.
+"#
+        }
+        code::INLINE_ALIAS_SYNTAX => {
+            r#"An inline type alias (`as`) isn't written correctly.
+
+    f : [Nil, Cons a list] as
+
+After `as` comes the name of the alias and its type variables, like
+`as LinkedList a`.
+"#
+        }
+        code::HEADER_SYNTAX => {
+            r#"A module header isn't written correctly.
+
+    app "example"
+        packages { pf: "platform/main.roc" }
+        imports pf.Stdout
+        provides [main] to pf
+
+The `imports`, `exposes`, `provides` and `requires` lists are written in square
+brackets, and module names must match the file they are in.
+"#
+        }
+        code::WHITESPACE_SYNTAX => {
+            r#"Whitespace is used in a way that isn't allowed.
+
+Roc doesn't allow tab characters or other unusual whitespace for indentation.
+Use spaces. Comments may also not contain certain control characters.
+"#
+        }
+        code::ABILITY_SYNTAX => {
+            r#"An ability definition isn't written correctly.
+
+    MHash implements
+        hash = \x -> 0
+
+An ability lists the names and types of its members, like
+`hash : a -> U64 where a implements MHash`. It doesn't contain implementations.
+"#
+        }
+
+        code::FILE_NOT_FOUND => {
+            r#"A file that the compiler needed to read doesn't exist.
+
+Check the path given on the command line and in module headers. Module names
+have to match their file names, so `Parser.Json` is found at `Parser/Json.roc`.
+"#
+        }
+        code::FILE_PERMISSION_DENIED => {
+            r#"A file that the compiler needed to read can't be opened, because of missing
+permissions.
+
+Check the file's permissions, or run the compiler as a user who can read it.
+"#
+        }
+        code::FILE_PROBLEM => {
+            r#"Something went wrong when reading a file.
+
+The report includes the error given by the operating system.
+"#
+        }
+        code::IMPORT_CYCLE => {
+            r#"Modules import each other in a cycle.
+
+    interface A exposes [] imports [B]
+    interface B exposes [] imports [A]
+
+Modules must form a tree of imports. Move the definitions that both need into
+a third module that each can import.
+"#
+        }
+        code::INCORRECT_MODULE_NAME => {
+            r#"A module's name in its header doesn't match its file path.
+
+A file at `Dep/Foo.roc` must start with `interface Dep.Foo`. Rename either the
+module or the file so that they agree.
+"#
+        }
+        code::NO_PLATFORM => {
+            r#"An app doesn't say which platform it runs on.
+
+    app "example"
+        packages { pf: "https://.../platform.tar.br" }
+        imports []
+        provides [main] to pf
+
+The `provides ... to` clause must name a package from `packages` that is a
+platform.
+"#
+        }
+        code::PACKAGE_DOWNLOAD_FAILED => {
+            r#"A package couldn't be downloaded or unpacked.
+
+This can be a network or HTTP error, an archive with an unsupported or
+unexpected encoding, a file that is too large, or an archive whose contents
+don't match the hash in its URL. Check your connection and the URL, and try
+again.
+"#
+        }
+        code::INVALID_PACKAGE_URL => {
+            r#"A package URL isn't in a form the compiler accepts.
+
+    packages { pf: "http://example.com/platform.tar" }
+
+Package URLs must use https, end in a content hash followed by a supported
+archive extension (like `.tar.br`), and can't contain characters that could be
+mistaken for others.
+"#
+        }
+
+        code::EXPECT_FAILED => {
+            r#"An `expect` evaluated to `Bool.false`.
+
+    expect List.len [1, 2] == 3
+
+The report shows the values of the names used in the expectation, to help find
+out why it didn't hold.
+"#
+        }
+        code::EXPECT_PANICKED => {
+            r#"The code under an `expect` crashed before the expectation could be checked.
+
+    expect
+        x = crash "not done yet"
+        x == 1
+
+The report shows the crash message. Fix whatever caused the crash, then the
+expectation itself will be checked.
+"#
+        }
+
+        _ => return None,
+    };
+
+    Some(text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::explanation;
+    use roc_problem::code::ALL;
+
+    #[test]
+    fn every_code_is_explained() {
+        for &code in ALL {
+            assert!(explanation(code).is_some(), "{code} has no explanation");
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use roc_module::ident::Ident;
 use roc_module::ident::{Lowercase, ModuleName, TagName, Uppercase};
 use roc_module::symbol::{Interns, ModuleId, ModuleIds, PQModuleName, PackageQualified, Symbol};
+use roc_problem::code::{self, ErrorCode};
 use roc_problem::Severity;
 use roc_region::all::LineColumnRegion;
+use serde::Serialize;
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::{fmt, io};
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
@@ -109,11 +112,15 @@ pub fn pretty_header_with_path(title: &str, path: &Path) -> String {
 pub enum RenderTarget {
     ColorTerminal,
     Generic,
+    /// One JSON object per report, on a single line, for editors and CI tools.
+    Json,
 }
 
 /// A textual report.
 pub struct Report<'b> {
     pub title: String,
+    /// The stable code for this kind of problem, which `roc explain` can tell more about
+    pub code: ErrorCode,
     pub filename: PathBuf,
     pub doc: RocDocBuilder<'b>,
     pub severity: Severity,
@@ -123,13 +130,14 @@ impl<'b> Report<'b> {
     pub fn render(
         self,
         target: RenderTarget,
-        buf: &'b mut String,
+        buf: &mut String,
         alloc: &'b RocDocAllocator<'b>,
         palette: &'b Palette,
     ) {
         match target {
             RenderTarget::Generic => self.render_ci(buf, alloc),
             RenderTarget::ColorTerminal => self.render_color_terminal(buf, alloc, palette),
+            RenderTarget::Json => self.render_json(buf),
         }
     }
 
@@ -159,14 +167,46 @@ impl<'b> Report<'b> {
             .expect(err_msg);
     }
 
+    /// Render as a single line of JSON, ending in a newline. The message is the report without its
+    /// header, rendered like `render_ci`; the region is the first code snippet in it, and the
+    /// secondary regions are the others.
+    pub fn render_json(self, buf: &mut String) {
+        let err_msg = "<buffer is not a utf-8 encoded string>";
+
+        let mut message = String::new();
+        let mut regions = Vec::new();
+
+        self.doc
+            .1
+            .render_raw(70, &mut SnippetWrite::new(&mut message, &mut regions))
+            .expect(err_msg);
+
+        let mut regions = regions.into_iter().map(JsonRegion::from);
+        let region = regions.next();
+
+        let json_report = JsonReport {
+            code: self.code.to_string(),
+            title: &self.title,
+            severity: self.severity,
+            file: self.filename.to_string_lossy(),
+            region,
+            secondary_regions: regions.collect(),
+            message: message.trim(),
+        };
+
+        buf.push_str(&serde_json::to_string(&json_report).expect("reports serialize to JSON"));
+        buf.push('\n');
+    }
+
     pub fn pretty(self, alloc: &'b RocDocAllocator<'b>) -> RocDocBuilder<'b> {
         if self.title.is_empty() {
             self.doc
         } else {
+            let title = format!("{} [{}]", self.title, self.code);
             let header = if self.filename == PathBuf::from("") {
-                crate::report::pretty_header(&self.title)
+                crate::report::pretty_header(&title)
             } else {
-                crate::report::pretty_header_with_path(&self.title, &self.filename)
+                crate::report::pretty_header_with_path(&title, &self.filename)
             };
 
             alloc.stack([alloc.text(header).annotate(Annotation::Header), self.doc])
@@ -178,6 +218,61 @@ impl<'b> Report<'b> {
     }
 }
 
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    code: String,
+    title: &'a str,
+    #[serde(serialize_with = "serialize_severity")]
+    severity: Severity,
+    file: Cow<'a, str>,
+    region: Option<JsonRegion>,
+    secondary_regions: Vec<JsonRegion>,
+    message: &'a str,
+}
+
+fn serialize_severity<S: serde::Serializer>(
+    severity: &Severity,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(match severity {
+        Severity::Fatal => "fatal",
+        Severity::RuntimeError => "runtime_error",
+        Severity::Warning => "warning",
+    })
+}
+
+/// A region of source code, with lines and columns counted from 1 like editors do.
+/// The end is just past the last character of the region.
+#[derive(Serialize)]
+struct JsonRegion {
+    start: JsonPosition,
+    end: JsonPosition,
+}
+
+#[derive(Serialize)]
+struct JsonPosition {
+    line: u32,
+    column: u32,
+}
+
+impl From<LineColumnRegion> for JsonRegion {
+    fn from(region: LineColumnRegion) -> Self {
+        let start = region.start();
+        let end = region.end();
+
+        JsonRegion {
+            start: JsonPosition {
+                line: start.line + 1,
+                column: start.column + 1,
+            },
+            end: JsonPosition {
+                line: end.line + 1,
+                column: end.column + 1,
+            },
+        }
+    }
+}
+
 /// This struct is a combination of several things
 /// 1. A set of StyleCodes suitable for the environment we're running in (web or terminal)
 /// 2. A set of colors we decided to use
@@ -648,7 +743,10 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result.annotate(Annotation::CodeBlock)
+        result
+            .annotate(Annotation::CodeBlock)
+            .annotate(Annotation::Snippet(sub_region2))
+            .annotate(Annotation::Snippet(sub_region1))
     }
 
     pub fn region_with_subregion(
@@ -755,7 +853,7 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result
+        result.annotate(Annotation::Snippet(sub_region))
     }
 
     pub fn region(&'a self, region: LineColumnRegion) -> DocBuilder<'a, Self, Annotation> {
@@ -864,6 +962,8 @@ pub enum Annotation {
     Tip,
     Header,
     ParserSuggestion,
+    /// A code snippet pointing at this region of the source.
+    Snippet(LineColumnRegion),
 }
 
 /// Render with minimal formatting
@@ -885,6 +985,21 @@ impl<W> CiWrite<W> {
     }
 }
 
+/// Render like `CiWrite`, while recording the regions that code snippets point at.
+struct SnippetWrite<'a, W> {
+    ci_write: CiWrite<W>,
+    regions: &'a mut Vec<LineColumnRegion>,
+}
+
+impl<'a, W> SnippetWrite<'a, W> {
+    fn new(upstream: W, regions: &'a mut Vec<LineColumnRegion>) -> SnippetWrite<'a, W> {
+        SnippetWrite {
+            ci_write: CiWrite::new(upstream),
+            regions,
+        }
+    }
+}
+
 /// Render with fancy formatting
 pub struct ColorWrite<'a, W> {
     style_stack: Vec<Annotation>,
@@ -989,6 +1104,40 @@ where
     }
 }
 
+impl<'a, W> Render for SnippetWrite<'a, W>
+where
+    W: fmt::Write,
+{
+    type Error = fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<usize, fmt::Error> {
+        self.ci_write.write_str(s)
+    }
+
+    fn write_str_all(&mut self, s: &str) -> fmt::Result {
+        self.ci_write.write_str_all(s)
+    }
+}
+
+impl<'a, W> RenderAnnotated<Annotation> for SnippetWrite<'a, W>
+where
+    W: fmt::Write,
+{
+    fn push_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        if let Annotation::Snippet(region) = annotation {
+            if !self.regions.contains(region) {
+                self.regions.push(*region);
+            }
+        }
+
+        self.ci_write.push_annotation(annotation)
+    }
+
+    fn pop_annotation(&mut self) -> Result<(), Self::Error> {
+        self.ci_write.pop_annotation()
+    }
+}
+
 impl<'a, W> Render for ColorWrite<'a, W>
 where
     W: fmt::Write,
@@ -1074,7 +1223,8 @@ where
             ParserSuggestion => {
                 self.write_str(self.palette.parser_suggestion)?;
             }
-            TypeBlock | InlineTypeBlock | Tag | RecordField | TupleElem => { /* nothing yet */ }
+            TypeBlock | InlineTypeBlock | Tag | RecordField | TupleElem | Snippet(_) => { /* nothing yet */
+            }
         }
         self.style_stack.push(*annotation);
         Ok(())
@@ -1092,8 +1242,8 @@ where
                     self.write_str(self.palette.reset)?;
                 }
 
-                TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField | TupleElem => { /* nothing yet */
-                }
+                TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField | TupleElem
+                | Snippet(_) => { /* nothing yet */ }
             },
         }
         Ok(())
@@ -1157,6 +1307,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "UNSUPPORTED ENCODING".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1192,6 +1343,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "MULTIPLE ENCODINGS".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1227,6 +1379,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "INVALID CONTENT HASH".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1254,6 +1407,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "IO ERROR".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1281,6 +1435,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "IO ERROR".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1310,6 +1465,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "HTTP ERROR".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
@@ -1351,6 +1507,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "INVALID EXTENSION SUFFIX".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1384,6 +1541,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "INVALID EXTENSION".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1422,6 +1580,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "INVALID FRAGMENT".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1460,6 +1619,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "MISSING PACKAGE HASH".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1486,6 +1646,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "HTTPS MANDATORY".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1529,6 +1690,7 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "MISLEADING CHARACTERS".to_string(),
+                code: code::INVALID_PACKAGE_URL,
                 severity: Severity::Fatal,
             }
         }
@@ -1559,13 +1721,18 @@ pub fn to_https_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE TOO LARGE".to_string(),
+                code: code::PACKAGE_DOWNLOAD_FAILED,
                 severity: Severity::Fatal,
             }
         }
     }
 }
 
-pub fn to_file_problem_report_string(filename: &Path, error: io::ErrorKind) -> String {
+pub fn to_file_problem_report_string(
+    filename: &Path,
+    error: io::ErrorKind,
+    render: RenderTarget,
+) -> String {
     let src_lines: Vec<&str> = Vec::new();
 
     let mut module_ids = ModuleIds::default();
@@ -1580,7 +1747,7 @@ pub fn to_file_problem_report_string(filename: &Path, error: io::ErrorKind) -> S
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     let report = to_file_problem_report(&alloc, filename, error);
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(render, &mut buf, &alloc, &palette);
 
     buf
 }
@@ -1609,6 +1776,7 @@ pub fn to_file_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE NOT FOUND".to_string(),
+                code: code::FILE_NOT_FOUND,
                 severity: Severity::Fatal,
             }
         }
@@ -1627,6 +1795,7 @@ pub fn to_file_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
+                code: code::FILE_PERMISSION_DENIED,
                 severity: Severity::Fatal,
             }
         }
@@ -1644,6 +1813,7 @@ pub fn to_file_problem_report<'b>(
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE PROBLEM".to_string(),
+                code: code::FILE_PROBLEM,
                 severity: Severity::Fatal,
             }
         }
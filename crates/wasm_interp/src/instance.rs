@@ -2,13 +2,14 @@ use bumpalo::{collections::Vec, Bump};
 use std::fmt::{self, Write};
 use std::iter::{self, once, Iterator};
 
-use roc_wasm_module::opcodes::{MemoryInstruction, OpCode};
+use roc_wasm_module::opcodes::{MemoryInstruction, OpCode, SimdInstruction};
 use roc_wasm_module::parse::{Parse, SkipBytes};
 use roc_wasm_module::sections::{ImportDesc, MemorySection, SignatureParamsIter};
 use roc_wasm_module::{ExportType, WasmModule};
 use roc_wasm_module::{Value, ValueType};
 
 use crate::frame::Frame;
+use crate::profiler::Profiler;
 use crate::value_store::ValueStore;
 use crate::{Error, ImportDispatcher};
 
@@ -18,6 +19,18 @@ pub enum Action {
     Break,
 }
 
+/// Why `Instance::step` or `Instance::resume` handed control back to the caller
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    /// Executed one instruction
+    Step,
+    /// Called a function that has a breakpoint on it. For Wasm functions, we stop before
+    /// the first instruction of the function body. For imports, we stop after the host returns.
+    Breakpoint(u32),
+    /// The exported function returned, with this return value
+    Finished(Option<Value>),
+}
+
 #[derive(Debug, Clone, Copy)]
 enum BlockType {
     Loop(usize),         // Loop block, with start address to loop back to
@@ -66,6 +79,14 @@ pub struct Instance<'a, I: ImportDispatcher> {
     import_arguments: Vec<'a, Value>,
     /// temporary storage for output using the --debug option
     debug_string: Option<String>,
+    /// Function indices to stop at, when running with `step` or `resume`
+    breakpoints: Vec<'a, u32>,
+    /// Set when we call a function that has a breakpoint on it
+    breakpoint_hit: Option<u32>,
+    /// Whether a call started by `start_export` is still running
+    call_in_progress: bool,
+    /// Instruction counts and timings, if profiling is enabled
+    profiler: Option<Profiler>,
 }
 
 impl<'a, I: ImportDispatcher> Instance<'a, I> {
@@ -95,6 +116,10 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             import_dispatcher,
             import_arguments: Vec::new_in(arena),
             debug_string: Some(String::new()),
+            breakpoints: Vec::new_in(arena),
+            breakpoint_hit: None,
+            call_in_progress: false,
+            profiler: None,
         }
     }
 
@@ -163,6 +188,10 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             import_dispatcher,
             import_arguments: Vec::new_in(arena),
             debug_string,
+            breakpoints: Vec::new_in(arena),
+            breakpoint_hit: None,
+            call_in_progress: false,
+            profiler: None,
         })
     }
 
@@ -170,8 +199,38 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
     where
         A: IntoIterator<Item = Value>,
     {
-        let (fn_index, param_type_iter, ret_type) =
-            self.call_export_help_before_arg_load(self.module, fn_name)?;
+        let (fn_index, n_args, return_count) = self.push_export_args(fn_name, arg_values, false)?;
+        self.call_export_help_after_arg_load(self.module, fn_index, n_args, return_count)?;
+        Ok(self.finish_export())
+    }
+
+    /// Like `call_export`, but for functions that can return more than one value
+    pub fn call_export_multi_value<A>(
+        &mut self,
+        fn_name: &str,
+        arg_values: A,
+    ) -> Result<std::vec::Vec<Value>, String>
+    where
+        A: IntoIterator<Item = Value>,
+    {
+        let (fn_index, n_args, return_count) = self.push_export_args(fn_name, arg_values, true)?;
+        self.call_export_help_after_arg_load(self.module, fn_index, n_args, return_count)?;
+        Ok(self.finish_export_multi_value())
+    }
+
+    /// Look up an exported function and push its arguments onto the stack, checking their types.
+    /// Returns the function index and the numbers of arguments and return values.
+    fn push_export_args<A>(
+        &mut self,
+        fn_name: &str,
+        arg_values: A,
+        multi_value: bool,
+    ) -> Result<(usize, usize, usize), String>
+    where
+        A: IntoIterator<Item = Value>,
+    {
+        let (fn_index, param_type_iter, return_count) =
+            self.call_export_help_before_arg_load(self.module, fn_name, multi_value)?;
         let n_args = param_type_iter.len();
 
         for (i, (value, expected_type)) in arg_values.into_iter().zip(param_type_iter).enumerate() {
@@ -184,7 +243,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             self.value_store.push(value);
         }
 
-        self.call_export_help_after_arg_load(self.module, fn_index, n_args, ret_type)
+        Ok((fn_index, n_args, return_count))
     }
 
     pub fn call_export_from_cli(
@@ -206,8 +265,8 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
 
         // Implement the "basic numbers" CLI
         // Check if the called Wasm function takes numeric arguments, and if so, try to parse them from the CLI.
-        let (fn_index, param_type_iter, ret_type) =
-            self.call_export_help_before_arg_load(module, fn_name)?;
+        let (fn_index, param_type_iter, return_count) =
+            self.call_export_help_before_arg_load(module, fn_name, false)?;
         let n_args = param_type_iter.len();
         for (value_bytes, value_type) in arg_strings
             .iter()
@@ -221,48 +280,24 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 I64 => Value::I64(value_str.parse::<i64>().map_err(|e| e.to_string())?),
                 F32 => Value::F32(value_str.parse::<f32>().map_err(|e| e.to_string())?),
                 F64 => Value::F64(value_str.parse::<f64>().map_err(|e| e.to_string())?),
+                V128 => Value::V128(value_str.parse::<u128>().map_err(|e| e.to_string())?),
             };
             self.value_store.push(value);
         }
 
-        self.call_export_help_after_arg_load(module, fn_index, n_args, ret_type)
+        self.call_export_help_after_arg_load(module, fn_index, n_args, return_count)?;
+        Ok(self.finish_export())
     }
 
     fn call_export_help_before_arg_load<'m>(
         &mut self,
         module: &'m WasmModule<'a>,
         fn_name: &str,
-    ) -> Result<(usize, SignatureParamsIter<'m>, Option<ValueType>), String> {
-        let fn_index = {
-            let mut export_iter = module.export.exports.iter();
-            export_iter
-                // First look up the name in exports
-                .find_map(|ex| {
-                    if ex.ty == ExportType::Func && ex.name == fn_name {
-                        Some(ex.index)
-                    } else {
-                        None
-                    }
-                })
-                .or_else(|| {
-                    // Then look it up in the debug info!
-                    // This is non-spec behaviour that Wasm3 seems to implement,
-                    // and that our wasm_linking tests accidentally rely on!
-                    let mut names = module.names.function_names.iter();
-                    names.find_map(
-                        |(index, name)| {
-                            if *name == fn_name {
-                                Some(*index)
-                            } else {
-                                None
-                            }
-                        },
-                    )
-                })
-                .ok_or_else(|| {
-                    format!("I couldn't find a function '{fn_name}' in this WebAssembly module")
-                })? as usize
-        };
+        multi_value: bool,
+    ) -> Result<(usize, SignatureParamsIter<'m>, usize), String> {
+        let fn_index = find_function(module, fn_name).ok_or_else(|| {
+            format!("I couldn't find a function '{fn_name}' in this WebAssembly module")
+        })? as usize;
 
         let internal_fn_index = fn_index - self.import_count;
 
@@ -272,10 +307,16 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             cursor
         };
 
-        let (param_type_iter, return_type) = {
+        let (param_type_iter, return_type_iter) = {
             let signature_index = module.function.signatures[internal_fn_index];
             module.types.look_up(signature_index)
         };
+        let return_count = return_type_iter.len();
+        if return_count > 1 && !multi_value {
+            return Err(format!(
+                "'{fn_name}' returns {return_count} values. Use call_export_multi_value to call it."
+            ));
+        }
 
         if self.debug_string.is_some() {
             println!(
@@ -286,7 +327,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             );
         }
 
-        Ok((fn_index, param_type_iter, return_type))
+        Ok((fn_index, param_type_iter, return_count))
     }
 
     fn call_export_help_after_arg_load(
@@ -294,8 +335,29 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         module: &WasmModule<'a>,
         fn_index: usize,
         n_args: usize,
-        return_type: Option<ValueType>,
-    ) -> Result<Option<Value>, String> {
+        return_count: usize,
+    ) -> Result<(), String> {
+        self.enter_export(module, fn_index, n_args, return_count);
+
+        loop {
+            match self.execute_next_instruction_or_trace(module)? {
+                Action::Continue => {}
+                Action::Break => {
+                    break;
+                }
+            };
+        }
+
+        Ok(())
+    }
+
+    fn enter_export(
+        &mut self,
+        module: &WasmModule<'a>,
+        fn_index: usize,
+        n_args: usize,
+        return_count: usize,
+    ) {
         self.previous_frames.clear();
         self.blocks.clear();
         self.blocks.push(Block {
@@ -307,7 +369,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             0, // return_addr
             self.blocks.len(),
             n_args,
-            return_type,
+            return_count,
             &module.code.bytes,
             &mut self.value_store,
             &mut self.program_counter,
@@ -317,28 +379,182 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             vstack: self.value_store.depth(),
         });
 
-        loop {
-            match self.execute_next_instruction(module) {
-                Ok(Action::Continue) => {}
-                Ok(Action::Break) => {
-                    break;
-                }
-                Err(e) => {
-                    let file_offset = self.program_counter + module.code.section_offset as usize;
-                    let mut message = e.to_string_at(file_offset);
-                    self.debug_stack_trace(&mut message).unwrap();
-                    return Err(message);
-                }
-            };
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.call(fn_index);
         }
+    }
 
-        let return_value = if !self.value_store.is_empty() {
+    fn execute_next_instruction_or_trace(
+        &mut self,
+        module: &WasmModule<'a>,
+    ) -> Result<Action, String> {
+        self.execute_next_instruction(module).map_err(|e| {
+            let file_offset = self.program_counter + module.code.section_offset as usize;
+            let mut message = e.to_string_at(file_offset);
+            self.debug_stack_trace(&mut message).unwrap();
+            message
+        })
+    }
+
+    fn finish_export(&mut self) -> Option<Value> {
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.stop();
+        }
+
+        if !self.value_store.is_empty() {
             Some(self.value_store.pop())
         } else {
             None
-        };
+        }
+    }
+
+    fn finish_export_multi_value(&mut self) -> std::vec::Vec<Value> {
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.stop();
+        }
 
-        Ok(return_value)
+        // The exported function's frame is still the current one after it returns
+        let results_start = self.value_store.depth() - self.current_frame.return_count;
+        let results = self.value_store.get_slice(results_start).to_vec();
+        self.value_store.truncate(results_start);
+        results
+    }
+
+    /// Set up a call to an exported function, without running it.
+    /// Then use `step` and `resume` to run it, and inspect the state of the program in between.
+    pub fn start_export<A>(&mut self, fn_name: &str, arg_values: A) -> Result<(), String>
+    where
+        A: IntoIterator<Item = Value>,
+    {
+        let (fn_index, n_args, return_count) = self.push_export_args(fn_name, arg_values, false)?;
+        self.enter_export(self.module, fn_index, n_args, return_count);
+        self.breakpoint_hit = None;
+        self.call_in_progress = true;
+
+        Ok(())
+    }
+
+    /// Execute one instruction of the call started by `start_export`
+    pub fn step(&mut self) -> Result<StopReason, String> {
+        if !self.call_in_progress {
+            return Err("There's no function call to step through. Use start_export first.".into());
+        }
+
+        match self.execute_next_instruction_or_trace(self.module) {
+            Ok(Action::Continue) => match self.breakpoint_hit.take() {
+                Some(fn_index) => Ok(StopReason::Breakpoint(fn_index)),
+                None => Ok(StopReason::Step),
+            },
+            Ok(Action::Break) => {
+                self.call_in_progress = false;
+                Ok(StopReason::Finished(self.finish_export()))
+            }
+            Err(message) => {
+                self.call_in_progress = false;
+                Err(message)
+            }
+        }
+    }
+
+    /// Keep running the call started by `start_export`, until we hit a breakpoint or it returns
+    pub fn resume(&mut self) -> Result<StopReason, String> {
+        loop {
+            match self.step()? {
+                StopReason::Step => {}
+                stop => return Ok(stop),
+            }
+        }
+    }
+
+    /// Stop whenever the function with this index is called
+    pub fn add_breakpoint(&mut self, fn_index: u32) {
+        if !self.breakpoints.contains(&fn_index) {
+            self.breakpoints.push(fn_index);
+        }
+    }
+
+    /// Stop whenever the function with this name is called. Returns its function index.
+    /// Like `call_export`, this looks up exports first and then the name section.
+    pub fn add_breakpoint_by_name(&mut self, fn_name: &str) -> Result<u32, String> {
+        let fn_index = find_function(self.module, fn_name).ok_or_else(|| {
+            format!("I couldn't find a function '{fn_name}' in this WebAssembly module")
+        })?;
+        self.add_breakpoint(fn_index);
+        Ok(fn_index)
+    }
+
+    /// Returns whether there was a breakpoint on this function
+    pub fn remove_breakpoint(&mut self, fn_index: u32) -> bool {
+        let len = self.breakpoints.len();
+        self.breakpoints.retain(|b| *b != fn_index);
+        self.breakpoints.len() != len
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    /// Index of the function that's currently executing
+    pub fn current_function(&self) -> u32 {
+        self.current_frame.fn_index as u32
+    }
+
+    /// Name of a function, from the name section
+    pub fn function_name(&self, fn_index: u32) -> Option<&'a str> {
+        self.module
+            .names
+            .function_names
+            .iter()
+            .find(|(idx, _)| *idx == fn_index)
+            .map(|(_, name)| *name)
+    }
+
+    /// Function indices of all the frames on the call stack, starting with the exported function
+    pub fn call_stack(&self) -> std::vec::Vec<u32> {
+        self.previous_frames
+            .iter()
+            .chain(once(&self.current_frame))
+            .map(|frame| frame.fn_index as u32)
+            .collect()
+    }
+
+    /// File offset of the next instruction to be executed, matching wasm-objdump addresses
+    pub fn file_offset(&self) -> usize {
+        self.program_counter + self.module.code.section_offset as usize
+    }
+
+    /// Arguments and local variables of the current function, in order of local index
+    pub fn locals(&self) -> &[Value] {
+        let Frame {
+            locals_start,
+            locals_count,
+            ..
+        } = self.current_frame;
+        self.value_store
+            .get_range(locals_start..locals_start + locals_count)
+    }
+
+    /// The current function's value stack, with the top of the stack last
+    pub fn value_stack(&self) -> &[Value] {
+        let base = self.current_frame.locals_start + self.current_frame.locals_count;
+        self.value_store.get_slice(base)
+    }
+
+    /// A range of bytes from memory, or None if it's out of bounds
+    pub fn read_memory(&self, addr: u32, len: u32) -> Option<&[u8]> {
+        let start = addr as usize;
+        self.memory.get(start..start.checked_add(len as usize)?)
+    }
+
+    /// Start counting instructions and measuring time in each function
+    pub fn enable_profiler(&mut self) {
+        let function_count = self.import_count + self.module.code.function_count as usize;
+        self.profiler = Some(Profiler::new(function_count));
+    }
+
+    /// The results of profiling so far, if `enable_profiler` was called
+    pub fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
     }
 
     fn fetch_immediate_u32(&mut self, module: &WasmModule<'a>) -> u32 {
@@ -355,21 +571,15 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         let Frame {
             return_addr,
             body_block_index,
-            return_type,
+            return_count,
             ..
         } = self.current_frame;
 
-        // Throw away all locals and values except the return value
+        // Throw away all locals and values except the return values
         let locals_block_index = body_block_index - 1;
         let locals_block = &self.blocks[locals_block_index];
-        let new_stack_depth = if return_type.is_some() {
-            self.value_store
-                .set(locals_block.vstack, self.value_store.peek());
-            locals_block.vstack + 1
-        } else {
-            locals_block.vstack
-        };
-        self.value_store.truncate(new_stack_depth);
+        self.value_store
+            .truncate_keeping_top(locals_block.vstack, return_count);
 
         // Resume executing at the next instruction in the caller function
         let new_block_len = locals_block_index; // don't need a -1 because one is a length and the other is an index!
@@ -379,6 +589,9 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         // self.debug_values_and_blocks("end do_return");
 
         if let Some(caller_frame) = self.previous_frames.pop() {
+            if let Some(profiler) = self.profiler.as_mut() {
+                profiler.switch_to(caller_frame.fn_index);
+            }
             self.current_frame = caller_frame;
             Action::Continue
         } else {
@@ -512,10 +725,17 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             );
         }
 
-        let (arg_type_iter, ret_type) = module.types.look_up(signature_index);
+        let (arg_type_iter, return_type_iter) = module.types.look_up(signature_index);
         let n_args = arg_type_iter.len();
+        let return_count = return_type_iter.len();
         if self.debug_string.is_some() {
-            self.debug_call(n_args, ret_type);
+            self.debug_call(n_args, return_type_iter);
+        }
+        if self.breakpoints.contains(&(fn_index as u32)) {
+            self.breakpoint_hit = Some(fn_index as u32);
+        }
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.call(fn_index);
         }
 
         if let Some(import) = opt_import {
@@ -540,6 +760,9 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             if let Some(return_val) = optional_return_val {
                 self.value_store.push(return_val);
             }
+            if let Some(profiler) = self.profiler.as_mut() {
+                profiler.switch_to(self.current_frame.fn_index);
+            }
             if let Some(debug_string) = self.debug_string.as_mut() {
                 write!(debug_string, " {}.{}", import.module, import.name).unwrap();
             }
@@ -562,7 +785,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 return_addr,
                 body_block_index,
                 n_args,
-                ret_type,
+                return_count,
                 &module.code.bytes,
                 &mut self.value_store,
                 &mut self.program_counter,
@@ -580,7 +803,89 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         Ok(())
     }
 
-    fn debug_call(&mut self, n_args: usize, return_type: Option<ValueType>) {
+    /// SIMD operations on 128-bit vectors. We store them as little-endian `u128`s,
+    /// so byte `i` of memory (lane `i` of an `i8x16`) is `to_le_bytes()[i]`.
+    fn execute_simd_instruction(
+        &mut self,
+        op: SimdInstruction,
+        module: &WasmModule<'a>,
+    ) -> Result<(), Error> {
+        use SimdInstruction::*;
+
+        match op {
+            V128Load => {
+                let addr = self.get_load_address(module)? as usize;
+                let mut bytes = [0; 16];
+                bytes.copy_from_slice(&self.memory[addr..][..16]);
+                self.value_store
+                    .push(Value::V128(u128::from_le_bytes(bytes)));
+            }
+            V128Store => {
+                let (addr, value) = self.get_store_addr_value(module)?;
+                let unwrapped = value.expect_v128().map_err(Error::from)?;
+                let target = &mut self.memory[addr..][..16];
+                target.copy_from_slice(&unwrapped.to_le_bytes());
+            }
+            V128Const => {
+                let mut bytes = [0; 16];
+                bytes.copy_from_slice(&module.code.bytes[self.program_counter..][..16]);
+                let value = u128::from_le_bytes(bytes);
+                self.write_debug(value);
+                self.value_store.push(Value::V128(value));
+                self.program_counter += 16;
+            }
+            I8x16Splat => {
+                let byte = self.value_store.pop_u32()? as u8;
+                self.value_store
+                    .push(Value::V128(u128::from_le_bytes([byte; 16])));
+            }
+            I8x16Eq | I8x16Ne => {
+                let y = self.value_store.pop_v128()?.to_le_bytes();
+                let x = self.value_store.pop_v128()?.to_le_bytes();
+                let lane_is_true = |i: usize| (x[i] == y[i]) == (op == I8x16Eq);
+                let lanes: [u8; 16] =
+                    std::array::from_fn(|i| if lane_is_true(i) { 0xff } else { 0 });
+                self.value_store
+                    .push(Value::V128(u128::from_le_bytes(lanes)));
+            }
+            V128Not => {
+                let x = self.value_store.pop_v128()?;
+                self.value_store.push(Value::V128(!x));
+            }
+            V128And | V128Or | V128Xor => {
+                let y = self.value_store.pop_v128()?;
+                let x = self.value_store.pop_v128()?;
+                let result = match op {
+                    V128And => x & y,
+                    V128Or => x | y,
+                    _ => x ^ y,
+                };
+                self.value_store.push(Value::V128(result));
+            }
+            V128AnyTrue => {
+                let x = self.value_store.pop_v128()?;
+                self.value_store.push(Value::I32((x != 0) as i32));
+            }
+            I8x16AllTrue => {
+                let lanes = self.value_store.pop_v128()?.to_le_bytes();
+                let all_true = lanes.iter().all(|lane| *lane != 0);
+                self.value_store.push(Value::I32(all_true as i32));
+            }
+            I8x16Bitmask => {
+                // The top bit of each lane
+                let lanes = self.value_store.pop_v128()?.to_le_bytes();
+                let mut mask = 0;
+                for (i, lane) in lanes.iter().enumerate() {
+                    mask |= ((lane >> 7) as i32) << i;
+                }
+                self.value_store.push(Value::I32(mask));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn debug_call(&mut self, n_args: usize, return_types: SignatureParamsIter<'_>) {
         if let Some(debug_string) = self.debug_string.as_mut() {
             write!(debug_string, "         args=[").unwrap();
             let arg_iter = self
@@ -596,7 +901,8 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 }
                 write!(debug_string, "{arg:x?}").unwrap();
             }
-            writeln!(debug_string, "] return_type={return_type:?}").unwrap();
+            let return_types: std::vec::Vec<_> = return_types.collect();
+            writeln!(debug_string, "] return_types={return_types:?}").unwrap();
         }
     }
 
@@ -614,6 +920,9 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             debug_string.clear();
             self.write_debug(op_code);
         }
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.count_instruction(self.current_frame.fn_index);
+        }
 
         let mut action = Action::Continue;
         let mut implicit_return = false;
@@ -1001,6 +1310,12 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                     Err(other) => unreachable!("invalid memory instruction {other:?}"),
                 };
             }
+            SIMD => {
+                let sub_op = self.fetch_immediate_u32(module);
+                let simd_op = SimdInstruction::try_from(sub_op)
+                    .unwrap_or_else(|other| unreachable!("unsupported SIMD instruction {other}"));
+                self.execute_simd_instruction(simd_op, module)?;
+            }
             I32CONST => {
                 let value = i32::parse((), &module.code.bytes, &mut self.program_counter).unwrap();
                 self.write_debug(value);
@@ -1853,14 +2168,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 self.module.types.look_up(signature_index).0.len()
             };
 
-            let fn_name = self
-                .module
-                .names
-                .function_names
-                .iter()
-                .find(|(idx, _)| *idx == *fn_index as u32)
-                .map(|(_, name)| *name)
-                .unwrap_or("");
+            let fn_name = self.function_name(*fn_index as u32).unwrap_or("");
 
             // Function and address match wasm-objdump formatting, for easy copy & find
             writeln!(buffer, "func[{fn_index}]  {fn_name}")?;
@@ -1913,3 +2221,32 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         call_addr
     }
 }
+
+/// Look up a function index by name
+fn find_function(module: &WasmModule<'_>, fn_name: &str) -> Option<u32> {
+    // First look up the name in exports
+    let mut export_iter = module.export.exports.iter();
+    export_iter
+        .find_map(|ex| {
+            if ex.ty == ExportType::Func && ex.name == fn_name {
+                Some(ex.index)
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            // Then look it up in the debug info!
+            // This is non-spec behaviour that Wasm3 seems to implement,
+            // and that our wasm_linking tests accidentally rely on!
+            let mut names = module.names.function_names.iter();
+            names.find_map(
+                |(index, name)| {
+                    if *name == fn_name {
+                        Some(*index)
+                    } else {
+                        None
+                    }
+                },
+            )
+        })
+}
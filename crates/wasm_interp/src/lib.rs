@@ -1,12 +1,14 @@
 mod frame;
 mod instance;
+mod profiler;
 mod tests;
 mod value_store;
 pub mod wasi;
 
 // Main external interface
-pub use instance::Instance;
-pub use wasi::{WasiDispatcher, WasiFile};
+pub use instance::{Instance, StopReason};
+pub use profiler::{FunctionProfile, Profiler};
+pub use wasi::{Directory, WasiDispatcher, WasiFile};
 
 pub use roc_wasm_module::Value;
 use roc_wasm_module::ValueType;
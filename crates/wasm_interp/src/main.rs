@@ -12,6 +12,8 @@ use roc_wasm_module::WasmModule;
 pub const FLAG_FUNCTION: &str = "function";
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_HEX: &str = "hex";
+pub const FLAG_DIR: &str = "dir";
+pub const FLAG_PROFILE: &str = "profile";
 pub const WASM_FILE: &str = "WASM_FILE";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
 
@@ -38,6 +40,19 @@ fn main() -> io::Result<()> {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_profile = Arg::new(FLAG_PROFILE)
+        .long(FLAG_PROFILE)
+        .help("Count the instructions executed and time spent in each function, and print a report to stderr.")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_dir = Arg::new(FLAG_DIR)
+        .long(FLAG_DIR)
+        .help("Give the app access to a host directory, as HOST_DIR or HOST_DIR::GUEST_DIR.\nCan be repeated.")
+        .value_name("HOST_DIR[::GUEST_DIR]")
+        .action(ArgAction::Append)
+        .required(false);
+
     let wasm_file_to_run = Arg::new(WASM_FILE)
         .help("The .wasm file to run")
         .required(true);
@@ -51,6 +66,8 @@ fn main() -> io::Result<()> {
         .arg(flag_function)
         .arg(flag_debug)
         .arg(flag_hex)
+        .arg(flag_profile)
+        .arg(flag_dir)
         .arg(wasm_file_to_run)
         .arg(args_for_app);
 
@@ -60,6 +77,7 @@ fn main() -> io::Result<()> {
     let start_fn_name = matches.get_one::<String>(FLAG_FUNCTION).unwrap();
     let is_debug_mode = matches.get_flag(FLAG_DEBUG);
     let is_hex_format = matches.get_flag(FLAG_HEX);
+    let is_profile_mode = matches.get_flag(FLAG_PROFILE);
     let start_arg_strings = matches.get_many::<String>(ARGS_FOR_APP).unwrap_or_default();
     let wasm_path = matches.get_one::<String>(WASM_FILE).unwrap();
     // WASI expects the .wasm file to be argv[0]
@@ -87,17 +105,32 @@ fn main() -> io::Result<()> {
 
     // Create an execution instance
 
-    let dispatcher = DefaultImportDispatcher::new(&wasi_argv);
+    let mut dispatcher = DefaultImportDispatcher::new(&wasi_argv);
+    for dir in matches.get_many::<String>(FLAG_DIR).unwrap_or_default() {
+        let (host_path, guest_path) = dir.split_once("::").unwrap_or((dir, dir));
+        if let Err(e) = dispatcher.wasi.preopen_dir(guest_path, host_path) {
+            eprintln!("I couldn't give the app access to {host_path}: {e}");
+            process::exit(1);
+        }
+    }
     let mut inst =
         Instance::for_module(&arena, &module, dispatcher, is_debug_mode).unwrap_or_else(|e| {
             eprintln!("{e}");
             process::exit(2);
         });
 
+    if is_profile_mode {
+        inst.enable_profiler();
+    }
+
     // Run
 
     let result = inst.call_export_from_cli(&module, start_fn_name, &wasi_argv);
 
+    if let Some(profiler) = inst.profiler() {
+        eprint!("{}", profiler.report(&module));
+    }
+
     // Print out return value, if any
 
     match result {
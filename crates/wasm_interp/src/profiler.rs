@@ -0,0 +1,125 @@
+use roc_wasm_module::WasmModule;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Statistics for one function, collected by the `Profiler`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionProfile {
+    /// Number of times the function was called
+    pub calls: u64,
+    /// Number of instructions executed in the function itself, not including its callees
+    pub instructions: u64,
+    /// Time spent in the function itself, not including its callees.
+    /// For imported functions, this is the time spent in the host.
+    pub self_time: Duration,
+}
+
+/// Counts calls and instructions for every function, and measures the time spent in each one.
+/// Time is measured on every call and return, rather than on every instruction, to keep
+/// the overhead down. Enable it with `Instance::enable_profiler`.
+#[derive(Debug)]
+pub struct Profiler {
+    functions: Vec<FunctionProfile>,
+    current_fn: usize,
+    last_switch: Instant,
+}
+
+impl Profiler {
+    pub(crate) fn new(function_count: usize) -> Self {
+        Profiler {
+            functions: vec![FunctionProfile::default(); function_count],
+            current_fn: 0,
+            last_switch: Instant::now(),
+        }
+    }
+
+    /// Statistics for every function, indexed by function index (including imports)
+    pub fn functions(&self) -> &[FunctionProfile] {
+        &self.functions
+    }
+
+    fn get_mut(&mut self, fn_index: usize) -> &mut FunctionProfile {
+        if fn_index >= self.functions.len() {
+            self.functions
+                .resize(fn_index + 1, FunctionProfile::default());
+        }
+        &mut self.functions[fn_index]
+    }
+
+    pub(crate) fn count_instruction(&mut self, fn_index: usize) {
+        self.get_mut(fn_index).instructions += 1;
+    }
+
+    pub(crate) fn call(&mut self, fn_index: usize) {
+        self.get_mut(fn_index).calls += 1;
+        self.switch_to(fn_index);
+    }
+
+    /// Charge the time since the last switch to the function we were in, and start timing another
+    pub(crate) fn switch_to(&mut self, fn_index: usize) {
+        let now = Instant::now();
+        let elapsed = now - self.last_switch;
+        let current_fn = self.current_fn;
+        self.get_mut(current_fn).self_time += elapsed;
+        self.current_fn = fn_index;
+        self.last_switch = now;
+    }
+
+    /// Charge the time so far to the current function, e.g. when the program ends
+    pub(crate) fn stop(&mut self) {
+        self.switch_to(self.current_fn);
+    }
+
+    /// A table of all the functions that were called, with the slowest first
+    /// ```text
+    /// instructions      calls   self time       %  function
+    ///      1234567         10     12.34ms   85.1%  func[12] roc__main_1_exposed
+    /// ```
+    pub fn report(&self, module: &WasmModule<'_>) -> String {
+        let total_time: Duration = self.functions.iter().map(|f| f.self_time).sum();
+        let total_secs = total_time.as_secs_f64().max(f64::MIN_POSITIVE);
+
+        let mut called: Vec<(usize, &FunctionProfile)> = self
+            .functions
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.calls > 0)
+            .collect();
+        called.sort_by(|(a_index, a), (b_index, b)| {
+            b.self_time
+                .cmp(&a.self_time)
+                .then(b.instructions.cmp(&a.instructions))
+                .then(a_index.cmp(b_index))
+        });
+
+        let mut report = String::new();
+        writeln!(
+            report,
+            "{:>12} {:>10} {:>11} {:>7}  function",
+            "instructions", "calls", "self time", "%"
+        )
+        .unwrap();
+
+        for (fn_index, profile) in called {
+            let fn_name = module
+                .names
+                .function_names
+                .iter()
+                .find(|(idx, _)| *idx == fn_index as u32)
+                .map(|(_, name)| *name)
+                .unwrap_or("");
+            let millis = profile.self_time.as_secs_f64() * 1000.0;
+            let percent = 100.0 * profile.self_time.as_secs_f64() / total_secs;
+
+            // Function index matches wasm-objdump formatting, for easy copy & find
+            writeln!(
+                report,
+                "{:>12} {:>10} {:>9.3}ms {:>6.1}%  func[{fn_index}] {fn_name}",
+                profile.instructions, profile.calls, millis, percent
+            )
+            .unwrap();
+        }
+
+        report
+    }
+}
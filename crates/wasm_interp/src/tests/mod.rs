@@ -2,17 +2,20 @@
 
 mod test_basics;
 mod test_convert;
+mod test_debugger;
 mod test_f32;
 mod test_f64;
 mod test_i32;
 mod test_i64;
 mod test_mem;
+mod test_simd;
+mod test_wasi;
 
 use crate::{DefaultImportDispatcher, Instance};
 use bumpalo::{collections::Vec, Bump};
 use roc_wasm_module::{
-    opcodes::OpCode, Export, ExportType, SerialBuffer, Serialize, Signature, Value, ValueType,
-    WasmModule,
+    opcodes::{OpCode, SimdInstruction},
+    Export, ExportType, SerialBuffer, Serialize, Signature, Value, ValueType, WasmModule,
 };
 
 pub fn default_state(arena: &Bump) -> Instance<DefaultImportDispatcher> {
@@ -47,6 +50,11 @@ pub fn const_value(buf: &mut Vec<'_, u8>, value: Value) {
             buf.push(OpCode::F64CONST as u8);
             buf.encode_f64(x);
         }
+        V128(x) => {
+            buf.push(OpCode::SIMD as u8);
+            buf.push(SimdInstruction::V128Const as u8);
+            buf.extend_from_slice(&x.to_le_bytes());
+        }
     }
 }
 
@@ -74,7 +82,7 @@ where
         module.code.function_offsets.push(0);
         module.add_function_signature(Signature {
             param_types: Vec::new_in(&arena),
-            ret_type: Some(ValueType::from(expected)),
+            ret_types: bumpalo::vec![in &arena; ValueType::from(expected)],
         });
         module.export.append(Export {
             name: "test",
@@ -97,7 +97,7 @@ fn test_loop_help(end: i32, expected: i32) {
 
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: "test",
@@ -125,7 +125,7 @@ fn test_if_else_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(&mut module, "test", signature, &local_types, |buf| {
@@ -188,7 +188,7 @@ fn test_br() {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -288,7 +288,7 @@ fn test_br_if_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -393,7 +393,7 @@ fn test_br_table_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -527,7 +527,7 @@ fn test_call_import() {
     });
     module.types.insert(Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
 
     // Function 1, which calls the import
@@ -536,7 +536,7 @@ fn test_call_import() {
     module.code.function_offsets.push(func0_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: start_fn_name,
@@ -586,7 +586,7 @@ fn test_call_return_no_args() {
     module.code.function_offsets.push(func0_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: start_fn_name,
@@ -616,7 +616,7 @@ fn test_call_return_no_args() {
     module.code.function_offsets.push(func1_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     [
         0, // no locals
@@ -650,7 +650,7 @@ fn test_call_return_with_args() {
     // Function 0: calculate 2+2
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, "two_plus_two", signature0, |buf| {
         buf.push(OpCode::I32CONST as u8);
@@ -667,7 +667,7 @@ fn test_call_return_with_args() {
     module.code.function_offsets.push(func1_offset);
     module.add_function_signature(Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     [
         0, // no locals
@@ -682,7 +682,7 @@ fn test_call_return_with_args() {
 
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, "add", signature0, |buf| {
         buf.push(OpCode::GETLOCAL as u8);
@@ -700,6 +700,65 @@ fn test_call_return_with_args() {
     assert_eq!(result, Value::I32(4));
 }
 
+#[test]
+fn test_call_return_multi_value() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    // Function 0: (i32) -> (i32, i64), with an extra value on the stack when it returns
+    let signature0 = Signature {
+        param_types: bumpalo::vec![in &arena; ValueType::I32],
+        ret_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I64],
+    };
+    create_exported_function_no_locals(&mut module, "inc_and_seven", signature0, |buf| {
+        buf.push(OpCode::I32CONST as u8);
+        buf.push(99);
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.push(0);
+        buf.push(OpCode::I32CONST as u8);
+        buf.push(1);
+        buf.push(OpCode::I32ADD as u8);
+        buf.push(OpCode::I64CONST as u8);
+        buf.push(7);
+        buf.push(OpCode::RETURN as u8);
+        buf.push(OpCode::END as u8);
+    });
+
+    // Function 1: () -> (i64, i32), swapping the results of function 0
+    let signature1 = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::I64, ValueType::I32],
+    };
+    let locals = [(1, ValueType::I32), (1, ValueType::I64)];
+    create_exported_function_with_locals(&mut module, "swapped", signature1, &locals, |buf| {
+        buf.push(OpCode::I32CONST as u8);
+        buf.push(10);
+        buf.push(OpCode::CALL as u8);
+        buf.push(0);
+        buf.push(OpCode::SETLOCAL as u8);
+        buf.push(1);
+        buf.push(OpCode::SETLOCAL as u8);
+        buf.push(0);
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.push(1);
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.push(0);
+        buf.push(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+
+    let result = inst.call_export_multi_value("swapped", []).unwrap();
+    assert_eq!(result, [Value::I64(7), Value::I32(11)]);
+
+    let result = inst.call_export_multi_value("inc_and_seven", [Value::I32(1)]);
+    assert_eq!(result, Ok(vec![Value::I32(2), Value::I64(7)]));
+
+    let result = inst.call_export("swapped", []);
+    assert!(result.unwrap_err().contains("returns 2 values"));
+}
+
 #[test]
 fn test_call_indirect_ok() {
     let result = test_call_indirect_help(0, 0);
@@ -734,7 +793,7 @@ fn test_call_indirect_help(table_index: u32, elem_index: u32) -> Value {
     // function 0: caller
     let signature0 = || Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, start_fn_name, signature0(), |buf| {
         buf.append_u8(OpCode::I32CONST as u8);
@@ -755,7 +814,7 @@ fn test_call_indirect_help(table_index: u32, elem_index: u32) -> Value {
     // function 2: callee, wrong signature
     let signature1 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::F32),
+        ret_types: bumpalo::vec![in &arena; ValueType::F32],
     };
     create_exported_function_no_locals(&mut module, "callee2", signature1, |buf| {
         buf.append_u8(OpCode::F32CONST as u8);
@@ -802,7 +861,7 @@ fn test_select_help(first: Value, second: Value, condition: i32, expected: Value
     // Function 0: calculate 2+2
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::from(expected)),
+        ret_types: bumpalo::vec![in &arena; ValueType::from(expected)],
     };
     create_exported_function_no_locals(&mut module, "test", signature0, |buf| {
         const_value(buf, first);
@@ -839,13 +898,13 @@ fn test_set_get_local() {
     let return_addr = 0x1234;
     let return_block_depth = 0;
     let n_args = 0;
-    let ret_type = Some(ValueType::I32);
+    let return_count = 1;
     inst.current_frame = Frame::enter(
         fn_index,
         return_addr,
         return_block_depth,
         n_args,
-        ret_type,
+        return_count,
         &buffer,
         &mut inst.value_store,
         &mut cursor,
@@ -886,13 +945,13 @@ fn test_tee_get_local() {
     let return_addr = 0x1234;
     let return_block_depth = 0;
     let n_args = 0;
-    let ret_type = Some(ValueType::I32);
+    let return_count = 1;
     inst.current_frame = Frame::enter(
         fn_index,
         return_addr,
         return_block_depth,
         n_args,
-        ret_type,
+        return_count,
         &buffer,
         &mut inst.value_store,
         &mut cursor,
@@ -0,0 +1,146 @@
+use super::create_exported_function_no_locals;
+use crate::{DefaultImportDispatcher, Instance, StopReason};
+use bumpalo::Bump;
+use roc_wasm_module::{
+    opcodes::OpCode, sections::MemorySection, SerialBuffer, Signature, Value, ValueType, WasmModule,
+};
+
+/// Function 0 "main" calls function 1 "double" three times, starting with the argument
+fn double_three_times(arena: &Bump) -> WasmModule<'_> {
+    let mut module = WasmModule::new(arena);
+    module.memory = MemorySection::new(arena, MemorySection::PAGE_SIZE);
+
+    let signature = || Signature {
+        param_types: bumpalo::vec![in arena; ValueType::I32],
+        ret_types: bumpalo::vec![in arena; ValueType::I32],
+    };
+
+    create_exported_function_no_locals(&mut module, "main", signature(), |buf| {
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        for _ in 0..3 {
+            buf.push(OpCode::CALL as u8);
+            buf.encode_u32(1);
+        }
+        buf.push(OpCode::END as u8);
+    });
+
+    create_exported_function_no_locals(&mut module, "double", signature(), |buf| {
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        buf.push(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        buf.push(OpCode::I32ADD as u8);
+        buf.push(OpCode::END as u8);
+    });
+
+    module.names.append_function(0, "main");
+    module.names.append_function(1, "double");
+
+    module
+}
+
+#[test]
+fn test_breakpoint_by_name() {
+    let arena = Bump::new();
+    let module = double_three_times(&arena);
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+
+    assert_eq!(inst.add_breakpoint_by_name("double"), Ok(1));
+    assert!(inst.add_breakpoint_by_name("triple").is_err());
+
+    inst.start_export("main", [Value::I32(5)]).unwrap();
+    assert_eq!(inst.current_function(), 0);
+    assert_eq!(inst.locals(), &[Value::I32(5)]);
+
+    assert_eq!(inst.resume(), Ok(StopReason::Breakpoint(1)));
+    assert_eq!(inst.current_function(), 1);
+    assert_eq!(inst.function_name(1), Some("double"));
+    assert_eq!(inst.call_stack(), vec![0, 1]);
+    assert_eq!(inst.locals(), &[Value::I32(5)]);
+    assert_eq!(inst.value_stack(), &[]);
+
+    assert_eq!(inst.step(), Ok(StopReason::Step));
+    assert_eq!(inst.value_stack(), &[Value::I32(5)]);
+
+    assert_eq!(inst.resume(), Ok(StopReason::Breakpoint(1)));
+    assert_eq!(inst.locals(), &[Value::I32(10)]);
+
+    assert!(inst.remove_breakpoint(1));
+    assert!(!inst.remove_breakpoint(1));
+
+    assert_eq!(
+        inst.resume(),
+        Ok(StopReason::Finished(Some(Value::I32(40))))
+    );
+    assert!(inst.step().is_err());
+}
+
+#[test]
+fn test_breakpoint_by_index() {
+    let arena = Bump::new();
+    let module = double_three_times(&arena);
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+
+    inst.add_breakpoint(1);
+    inst.start_export("main", [Value::I32(1)]).unwrap();
+
+    let mut breakpoints_hit = 0;
+    let result = loop {
+        match inst.resume().unwrap() {
+            StopReason::Breakpoint(1) => breakpoints_hit += 1,
+            StopReason::Finished(result) => break result,
+            other => panic!("Unexpected {other:?}"),
+        }
+    };
+
+    assert_eq!(breakpoints_hit, 3);
+    assert_eq!(result, Some(Value::I32(8)));
+
+    // Breakpoints don't affect call_export
+    assert_eq!(
+        inst.call_export("main", [Value::I32(1)]),
+        Ok(Some(Value::I32(8)))
+    );
+}
+
+#[test]
+fn test_read_memory() {
+    let arena = Bump::new();
+    let module = double_three_times(&arena);
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+
+    inst.memory[16..20].copy_from_slice(&[1, 2, 3, 4]);
+
+    assert_eq!(inst.read_memory(17, 2), Some(&[2, 3][..]));
+    assert_eq!(inst.read_memory(MemorySection::PAGE_SIZE - 1, 2), None);
+}
+
+#[test]
+fn test_profiler() {
+    let arena = Bump::new();
+    let module = double_three_times(&arena);
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+
+    assert!(inst.profiler().is_none());
+    inst.enable_profiler();
+    inst.call_export("main", [Value::I32(1)]).unwrap();
+
+    let profiler = inst.profiler().unwrap();
+    let functions = profiler.functions();
+    assert_eq!(functions[0].calls, 1);
+    assert_eq!(functions[0].instructions, 5);
+    assert_eq!(functions[1].calls, 3);
+    assert_eq!(functions[1].instructions, 3 * 4);
+
+    let report = profiler.report(&module);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].contains("instructions"));
+    assert!(report.contains("func[0] main"));
+    assert!(report.contains("func[1] double"));
+}
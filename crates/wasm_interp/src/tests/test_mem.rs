@@ -137,7 +137,7 @@ fn test_load(load_op: OpCode, ty: ValueType, data: &[u8], addr: u32, offset: u32
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ty),
+        ret_types: bumpalo::vec![in &arena; ty],
     };
 
     create_exported_function_no_locals(&mut module, start_fn_name, signature, |buf| {
@@ -325,7 +325,7 @@ fn test_store<'a>(
 
     let signature = Signature {
         param_types: bumpalo::vec![in arena],
-        ret_type: None,
+        ret_types: bumpalo::vec![in arena],
     };
 
     create_exported_function_no_locals(module, start_fn_name, signature, |buf| {
@@ -348,6 +348,7 @@ fn test_store<'a>(
                 buf.append_u8(OpCode::F64CONST as u8);
                 buf.encode_f64(x);
             }
+            Value::V128(_) => unimplemented!("SIMD stores are tested in test_simd"),
         }
         buf.append_u8(store_op as u8);
         buf.encode_u32(0); // align
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use super::{
+    const_value, create_exported_function_no_locals, create_exported_function_with_locals,
+};
+use crate::{DefaultImportDispatcher, Instance};
+use bumpalo::Bump;
+use roc_wasm_module::{
+    opcodes::{OpCode, SimdInstruction, SimdInstruction::*},
+    sections::{DataMode, DataSegment, MemorySection},
+    ConstExpr, SerialBuffer, Signature, Value, ValueType, WasmModule,
+};
+
+const ALPHABET: [u8; 16] = *b"abcdefghijklmnop";
+
+fn v128(lanes: [u8; 16]) -> Value {
+    Value::V128(u128::from_le_bytes(lanes))
+}
+
+fn test_simd_op<A>(op: SimdInstruction, args: A, expected: Value)
+where
+    A: IntoIterator<Item = Value>,
+{
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    let signature = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::from(expected)],
+    };
+    create_exported_function_no_locals(&mut module, "test", signature, |buf| {
+        for arg in args {
+            const_value(buf, arg);
+        }
+        buf.append_u8(OpCode::SIMD as u8);
+        buf.encode_u32(op as u32);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+    let result = inst.call_export("test", []).unwrap().unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_i8x16_eq_ne() {
+    let mut other = ALPHABET;
+    other[3] = b'D';
+    other[15] = b'P';
+
+    let mut expected = [0xff; 16];
+    expected[3] = 0;
+    expected[15] = 0;
+    test_simd_op(I8x16Eq, [v128(ALPHABET), v128(other)], v128(expected));
+
+    let expected = expected.map(|lane| !lane);
+    test_simd_op(I8x16Ne, [v128(ALPHABET), v128(other)], v128(expected));
+}
+
+#[test]
+fn test_i8x16_splat() {
+    test_simd_op(I8x16Splat, [Value::I32(0x161)], v128([0x61; 16]));
+}
+
+fn test_v128_binop(op: SimdInstruction, x: u128, y: u128, expected: u128) {
+    test_simd_op(op, [Value::V128(x), Value::V128(y)], Value::V128(expected));
+}
+
+#[test]
+fn test_v128_bitwise() {
+    let x = 0xff00_ff00_1234_5678_0000_ffff_aaaa_5555u128;
+    let y = 0x0ff0_0ff0_ffff_0000_1111_1111_5555_5555u128;
+    test_v128_binop(V128And, x, y, x & y);
+    test_v128_binop(V128Or, x, y, x | y);
+    test_v128_binop(V128Xor, x, y, x ^ y);
+    test_simd_op(V128Not, [Value::V128(x)], Value::V128(!x));
+}
+
+#[test]
+fn test_v128_any_true_i8x16_all_true() {
+    let mut one_zero = [0xff; 16];
+    one_zero[7] = 0;
+
+    test_simd_op(V128AnyTrue, [Value::V128(0)], Value::I32(0));
+    test_simd_op(V128AnyTrue, [Value::V128(1 << 100)], Value::I32(1));
+    test_simd_op(I8x16AllTrue, [v128(ALPHABET)], Value::I32(1));
+    test_simd_op(I8x16AllTrue, [v128(one_zero)], Value::I32(0));
+}
+
+#[test]
+fn test_i8x16_bitmask() {
+    let mut lanes = [0x7f; 16];
+    lanes[0] = 0x80;
+    lanes[9] = 0xff;
+    lanes[15] = 0x81;
+    test_simd_op(I8x16Bitmask, [v128(lanes)], Value::I32(0x8201));
+}
+
+#[test]
+fn test_v128_load_store() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+    module.memory = MemorySection::new(&arena, MemorySection::PAGE_SIZE);
+    let mut init = bumpalo::vec![in &arena; 0xaa, 0xbb, 0xcc, 0xdd, 0xee];
+    init.extend_from_slice(&ALPHABET);
+    module.data.append_segment(DataSegment {
+        mode: DataMode::Active {
+            offset: ConstExpr::I32(0),
+        },
+        init,
+    });
+
+    // Copy 16 bytes from address 5 to address 100, via a v128 local
+    let signature = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena],
+    };
+    let locals = [(1, ValueType::V128)];
+    create_exported_function_with_locals(&mut module, "test", signature, &locals, |buf| {
+        buf.append_u8(OpCode::I32CONST as u8);
+        buf.encode_i32(1);
+        buf.append_u8(OpCode::SIMD as u8);
+        buf.encode_u32(V128Load as u32);
+        buf.encode_u32(0); // align
+        buf.encode_u32(4); // offset
+        buf.append_u8(OpCode::SETLOCAL as u8);
+        buf.encode_u32(0);
+
+        buf.append_u8(OpCode::I32CONST as u8);
+        buf.encode_i32(90);
+        buf.append_u8(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        buf.append_u8(OpCode::SIMD as u8);
+        buf.encode_u32(V128Store as u32);
+        buf.encode_u32(0); // align
+        buf.encode_u32(10); // offset
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+    let result = inst.call_export("test", []).unwrap();
+
+    assert_eq!(result, None);
+    assert_eq!(inst.read_memory(100, 16), Some(&ALPHABET[..]));
+}
+
+#[test]
+fn test_v128_local_is_zeroed() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    let signature = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::V128],
+    };
+    let locals = [(1, ValueType::V128)];
+    create_exported_function_with_locals(&mut module, "test", signature, &locals, |buf| {
+        buf.append_u8(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+    let result = inst.call_export("test", []).unwrap();
+
+    assert_eq!(result, Some(Value::V128(0)));
+}
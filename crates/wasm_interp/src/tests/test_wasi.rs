@@ -0,0 +1,542 @@
+use crate::wasi::{Errno, WasiDispatcher};
+use roc_wasm_module::Value;
+use std::fs;
+
+const PATH: usize = 0x100;
+const IOVS: usize = 0x200;
+const BUF: usize = 0x300;
+const OUT: usize = 0x800;
+
+const OFLAGS_CREAT: i32 = 1;
+const OFLAGS_DIRECTORY: i32 = 2;
+const RIGHTS_FD_READ_WRITE: i64 = (1 << 1) | (1 << 6);
+
+fn call(
+    wasi: &mut WasiDispatcher,
+    memory: &mut [u8],
+    function_name: &str,
+    arguments: &[Value],
+) -> i32 {
+    match wasi.dispatch(function_name, arguments, memory) {
+        Some(Value::I32(code)) => code,
+        other => panic!("{function_name} returned {other:?}"),
+    }
+}
+
+fn read_u32(memory: &[u8], addr: usize) -> u32 {
+    u32::from_le_bytes(memory[addr..][..4].try_into().unwrap())
+}
+
+fn read_u64(memory: &[u8], addr: usize) -> u64 {
+    u64::from_le_bytes(memory[addr..][..8].try_into().unwrap())
+}
+
+fn write_path(memory: &mut [u8], path: &str) -> [Value; 2] {
+    memory[PATH..][..path.len()].copy_from_slice(path.as_bytes());
+    [Value::I32(PATH as i32), Value::I32(path.len() as i32)]
+}
+
+fn write_iovec(memory: &mut [u8], bytes: &[u8]) {
+    memory[BUF..][..bytes.len()].copy_from_slice(bytes);
+    memory[IOVS..][..4].copy_from_slice(&(BUF as u32).to_le_bytes());
+    memory[IOVS + 4..][..4].copy_from_slice(&(bytes.len() as u32).to_le_bytes());
+}
+
+fn path_open(
+    wasi: &mut WasiDispatcher,
+    memory: &mut [u8],
+    dir_fd: u32,
+    path: &str,
+    oflags: i32,
+) -> Result<i32, i32> {
+    let [ptr_path, path_len] = write_path(memory, path);
+    let errno = call(
+        wasi,
+        memory,
+        "path_open",
+        &[
+            Value::I32(dir_fd as i32),
+            Value::I32(0),
+            ptr_path,
+            path_len,
+            Value::I32(oflags),
+            Value::I64(RIGHTS_FD_READ_WRITE),
+            Value::I64(RIGHTS_FD_READ_WRITE),
+            Value::I32(0),
+            Value::I32(OUT as i32),
+        ],
+    );
+    if errno == Errno::Success as i32 {
+        Ok(read_u32(memory, OUT) as i32)
+    } else {
+        Err(errno)
+    }
+}
+
+#[test]
+fn test_prestat() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+
+    let fd = wasi.preopen_dir("/sandbox", dir.path()).unwrap();
+    assert_eq!(fd, 3);
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_prestat_get",
+        &[Value::I32(fd as i32), Value::I32(OUT as i32)],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(memory[OUT], 0);
+    assert_eq!(read_u32(&memory, OUT + 4), "/sandbox".len() as u32);
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_prestat_dir_name",
+        &[Value::I32(fd as i32), Value::I32(BUF as i32), Value::I32(8)],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(&memory[BUF..][..8], b"/sandbox");
+
+    // The end of the preopens is signalled with Badf
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_prestat_get",
+        &[Value::I32(fd as i32 + 1), Value::I32(OUT as i32)],
+    );
+    assert_eq!(errno, Errno::Badf as i32);
+}
+
+#[test]
+fn test_write_seek_read() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+    let dir_fd = wasi.preopen_dir(".", dir.path()).unwrap();
+
+    let fd = path_open(&mut wasi, &mut memory, dir_fd, "hello.txt", OFLAGS_CREAT).unwrap();
+
+    write_iovec(&mut memory, b"Hello, World!");
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_write",
+        &[
+            Value::I32(fd),
+            Value::I32(IOVS as i32),
+            Value::I32(1),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(read_u32(&memory, OUT), 13);
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_seek",
+        &[
+            Value::I32(fd),
+            Value::I64(7),
+            Value::I32(0), // WHENCE_SET
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(read_u64(&memory, OUT), 7);
+
+    write_iovec(&mut memory, &[0; 5]);
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_read",
+        &[
+            Value::I32(fd),
+            Value::I32(IOVS as i32),
+            Value::I32(1),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(read_u32(&memory, OUT), 5);
+    assert_eq!(&memory[BUF..][..5], b"World");
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_tell",
+        &[Value::I32(fd), Value::I32(OUT as i32)],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(read_u64(&memory, OUT), 12);
+
+    let errno = call(&mut wasi, &mut memory, "fd_close", &[Value::I32(fd)]);
+    assert_eq!(errno, Errno::Success as i32);
+    let errno = call(&mut wasi, &mut memory, "fd_close", &[Value::I32(fd)]);
+    assert_eq!(errno, Errno::Badf as i32);
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("hello.txt")).unwrap(),
+        "Hello, World!"
+    );
+
+    // The closed file descriptor gets reused
+    let reopened = path_open(&mut wasi, &mut memory, dir_fd, "hello.txt", 0).unwrap();
+    assert_eq!(reopened, fd);
+}
+
+#[test]
+fn test_sandbox_escape() {
+    let parent = tempfile::tempdir().unwrap();
+    let sandbox = parent.path().join("sandbox");
+    fs::create_dir(&sandbox).unwrap();
+    fs::write(parent.path().join("secret.txt"), "secret").unwrap();
+
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+    let dir_fd = wasi.preopen_dir(".", &sandbox).unwrap();
+
+    for path in ["../secret.txt", "a/../../secret.txt", "/etc/passwd"] {
+        assert_eq!(
+            path_open(&mut wasi, &mut memory, dir_fd, path, 0),
+            Err(Errno::Notcapable as i32),
+            "{path}"
+        );
+    }
+
+    // Going up is fine, as long as we stay inside
+    fs::create_dir(sandbox.join("a")).unwrap();
+    fs::write(sandbox.join("inside.txt"), "").unwrap();
+    assert!(path_open(&mut wasi, &mut memory, dir_fd, "a/../inside.txt", 0).is_ok());
+
+    // Symlinks can't be used to get out either
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(parent.path(), sandbox.join("link")).unwrap();
+        assert_eq!(
+            path_open(&mut wasi, &mut memory, dir_fd, "link/secret.txt", 0),
+            Err(Errno::Notcapable as i32)
+        );
+    }
+}
+
+#[cfg(unix)]
+fn path_symlink(
+    wasi: &mut WasiDispatcher,
+    memory: &mut [u8],
+    dir_fd: u32,
+    target: &str,
+    link: &str,
+) -> i32 {
+    memory[BUF..][..target.len()].copy_from_slice(target.as_bytes());
+    let [ptr_path, path_len] = write_path(memory, link);
+    call(
+        wasi,
+        memory,
+        "path_symlink",
+        &[
+            Value::I32(BUF as i32),
+            Value::I32(target.len() as i32),
+            Value::I32(dir_fd as i32),
+            ptr_path,
+            path_len,
+        ],
+    )
+}
+
+#[cfg(unix)]
+fn path_filestat_get(
+    wasi: &mut WasiDispatcher,
+    memory: &mut [u8],
+    dir_fd: u32,
+    path: &str,
+    follow: bool,
+) -> i32 {
+    let [ptr_path, path_len] = write_path(memory, path);
+    call(
+        wasi,
+        memory,
+        "path_filestat_get",
+        &[
+            Value::I32(dir_fd as i32),
+            Value::I32(follow as i32),
+            ptr_path,
+            path_len,
+            Value::I32(OUT as i32),
+        ],
+    )
+}
+
+#[test]
+#[cfg(unix)]
+fn test_symlink_escape() {
+    let parent = tempfile::tempdir().unwrap();
+    let sandbox = parent.path().join("sandbox");
+    fs::create_dir_all(sandbox.join("a")).unwrap();
+    fs::write(sandbox.join("inside.txt"), "inside").unwrap();
+    fs::write(parent.path().join("secret.txt"), "secret").unwrap();
+
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+    let dir_fd = wasi.preopen_dir(".", &sandbox).unwrap();
+
+    // The app can't create links that point out of the sandbox
+    let outside = parent.path().join("evil.txt");
+    let outside = outside.to_str().unwrap();
+    for (target, link) in [
+        (outside, "a/l"),
+        (outside, "l"),
+        ("../secret.txt", "l"),
+        ("../../secret.txt", "a/l"),
+    ] {
+        assert_eq!(
+            path_symlink(&mut wasi, &mut memory, dir_fd, target, link),
+            Errno::Notcapable as i32,
+            "{target} from {link}"
+        );
+        assert!(fs::symlink_metadata(sandbox.join(link)).is_err(), "{link}");
+    }
+
+    // Links that stay inside are fine, relative to the directory of the link
+    for (target, link) in [("inside.txt", "top"), ("../inside.txt", "a/up")] {
+        assert_eq!(
+            path_symlink(&mut wasi, &mut memory, dir_fd, target, link),
+            Errno::Success as i32,
+            "{target} from {link}"
+        );
+        assert!(path_open(&mut wasi, &mut memory, dir_fd, link, 0).is_ok());
+    }
+
+    // Links that were put there by someone else aren't followed out of the sandbox,
+    // not even to create a file that doesn't exist yet
+    std::os::unix::fs::symlink(outside, sandbox.join("a/dangling")).unwrap();
+    assert_eq!(
+        path_open(&mut wasi, &mut memory, dir_fd, "a/dangling", OFLAGS_CREAT),
+        Err(Errno::Notcapable as i32)
+    );
+    assert!(fs::symlink_metadata(outside).is_err());
+
+    std::os::unix::fs::symlink(parent.path().join("secret.txt"), sandbox.join("secret")).unwrap();
+    assert_eq!(
+        path_open(&mut wasi, &mut memory, dir_fd, "secret", 0),
+        Err(Errno::Notcapable as i32)
+    );
+    assert_eq!(
+        path_filestat_get(&mut wasi, &mut memory, dir_fd, "secret", true),
+        Errno::Notcapable as i32
+    );
+
+    // The links themselves are still in the sandbox, so they can be looked at and removed
+    assert_eq!(
+        path_filestat_get(&mut wasi, &mut memory, dir_fd, "secret", false),
+        Errno::Success as i32
+    );
+    for link in ["secret", "a/dangling"] {
+        let [ptr_path, path_len] = write_path(&mut memory, link);
+        let args = [Value::I32(dir_fd as i32), ptr_path, path_len];
+        assert_eq!(
+            call(&mut wasi, &mut memory, "path_unlink_file", &args),
+            Errno::Success as i32,
+            "{link}"
+        );
+    }
+    assert_eq!(
+        fs::read_to_string(parent.path().join("secret.txt")).unwrap(),
+        "secret"
+    );
+}
+
+#[test]
+fn test_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+    let dir_fd = wasi.preopen_dir(".", dir.path()).unwrap();
+
+    let [ptr_path, path_len] = write_path(&mut memory, "sub");
+    let args = [Value::I32(dir_fd as i32), ptr_path, path_len];
+    assert_eq!(
+        call(&mut wasi, &mut memory, "path_create_directory", &args),
+        Errno::Success as i32
+    );
+    assert!(dir.path().join("sub").is_dir());
+
+    fs::write(dir.path().join("sub/b.txt"), "bb").unwrap();
+    fs::write(dir.path().join("sub/a.txt"), "a").unwrap();
+
+    let sub_fd = path_open(&mut wasi, &mut memory, dir_fd, "sub", OFLAGS_DIRECTORY).unwrap();
+    assert_eq!(
+        path_open(
+            &mut wasi,
+            &mut memory,
+            sub_fd as u32,
+            "a.txt",
+            OFLAGS_DIRECTORY
+        ),
+        Err(Errno::Notdir as i32)
+    );
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_readdir",
+        &[
+            Value::I32(sub_fd),
+            Value::I32(BUF as i32),
+            Value::I32(0x400),
+            Value::I64(0),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+
+    let buf_used = read_u32(&memory, OUT) as usize;
+    let mut names = vec![];
+    let mut offset = 0;
+    while offset < buf_used {
+        let dirent = &memory[BUF + offset..];
+        let namlen = read_u32(dirent, 16) as usize;
+        names.push(String::from_utf8(dirent[24..][..namlen].to_vec()).unwrap());
+        offset += 24 + namlen;
+    }
+    assert_eq!(names, [".", "..", "a.txt", "b.txt"]);
+
+    // Resume from a cookie
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "fd_readdir",
+        &[
+            Value::I32(sub_fd),
+            Value::I32(BUF as i32),
+            Value::I32(0x400),
+            Value::I64(3),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert_eq!(read_u32(&memory, OUT), 24 + 5);
+    assert_eq!(&memory[BUF + 24..][..5], b"b.txt");
+
+    let [ptr_path, path_len] = write_path(&mut memory, "sub");
+    let args = [Value::I32(dir_fd as i32), ptr_path, path_len];
+    assert_eq!(
+        call(&mut wasi, &mut memory, "path_remove_directory", &args),
+        Errno::Notempty as i32
+    );
+    assert_eq!(
+        call(&mut wasi, &mut memory, "path_unlink_file", &args),
+        Errno::Isdir as i32
+    );
+
+    for file in ["sub/a.txt", "sub/b.txt"] {
+        let [ptr_path, path_len] = write_path(&mut memory, file);
+        let args = [Value::I32(dir_fd as i32), ptr_path, path_len];
+        assert_eq!(
+            call(&mut wasi, &mut memory, "path_unlink_file", &args),
+            Errno::Success as i32
+        );
+    }
+
+    let [ptr_path, path_len] = write_path(&mut memory, "sub");
+    let args = [Value::I32(dir_fd as i32), ptr_path, path_len];
+    assert_eq!(
+        call(&mut wasi, &mut memory, "path_remove_directory", &args),
+        Errno::Success as i32
+    );
+    assert!(!dir.path().join("sub").exists());
+
+    assert_eq!(
+        path_open(&mut wasi, &mut memory, dir_fd, "missing.txt", 0),
+        Err(Errno::Noent as i32)
+    );
+}
+
+#[test]
+fn test_clocks() {
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+
+    let mut time_get = |wasi: &mut WasiDispatcher, clock_id| {
+        let errno = call(
+            wasi,
+            &mut memory,
+            "clock_time_get",
+            &[Value::I32(clock_id), Value::I64(1), Value::I32(OUT as i32)],
+        );
+        (errno, read_u64(&memory, OUT))
+    };
+
+    let (errno, realtime) = time_get(&mut wasi, 0);
+    assert_eq!(errno, Errno::Success as i32);
+    // Sometime after 2020
+    assert!(realtime > 1_577_836_800 * 1_000_000_000);
+
+    let (errno, first) = time_get(&mut wasi, 1);
+    assert_eq!(errno, Errno::Success as i32);
+    let (_, second) = time_get(&mut wasi, 1);
+    assert!(second >= first);
+
+    let (errno, _) = time_get(&mut wasi, 4);
+    assert_eq!(errno, Errno::Inval as i32);
+}
+
+#[test]
+fn test_poll_oneoff_clock() {
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+
+    // A relative timeout of 1ms on the monotonic clock
+    let subscription = &mut memory[BUF..][..48];
+    subscription[0..8].copy_from_slice(&1234u64.to_le_bytes());
+    subscription[8] = 0; // EVENTTYPE_CLOCK
+    subscription[16..20].copy_from_slice(&1u32.to_le_bytes());
+    subscription[24..32].copy_from_slice(&1_000_000u64.to_le_bytes());
+
+    let start = std::time::Instant::now();
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "poll_oneoff",
+        &[
+            Value::I32(BUF as i32),
+            Value::I32(IOVS as i32),
+            Value::I32(1),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Success as i32);
+    assert!(start.elapsed().as_nanos() >= 1_000_000);
+
+    assert_eq!(read_u32(&memory, OUT), 1);
+    assert_eq!(read_u64(&memory, IOVS), 1234);
+    assert_eq!(memory[IOVS + 8], 0); // no error
+    assert_eq!(memory[IOVS + 10], 0); // EVENTTYPE_CLOCK
+}
+
+#[test]
+fn test_unsupported() {
+    let mut wasi = WasiDispatcher::default();
+    let mut memory = vec![0; 0x1000];
+
+    let errno = call(
+        &mut wasi,
+        &mut memory,
+        "sock_send",
+        &[
+            Value::I32(3),
+            Value::I32(IOVS as i32),
+            Value::I32(0),
+            Value::I32(0),
+            Value::I32(OUT as i32),
+        ],
+    );
+    assert_eq!(errno, Errno::Notsup as i32);
+
+    let errno = call(&mut wasi, &mut memory, "fd_close", &[Value::I32(9)]);
+    assert_eq!(errno, Errno::Badf as i32);
+}
@@ -104,6 +104,14 @@ impl<'a> ValueStore<'a> {
         }
     }
 
+    pub(crate) fn pop_v128(&mut self) -> Result<u128, Error> {
+        match self.values.pop() {
+            Some(Value::V128(x)) => Ok(x),
+            Some(bad) => Err(Error::Type(ValueType::V128, ValueType::from(bad))),
+            None => Err(Error::StackEmpty),
+        }
+    }
+
     pub(crate) fn iter(&self) -> std::slice::Iter<Value> {
         self.values.iter()
     }
@@ -112,9 +120,20 @@ impl<'a> ValueStore<'a> {
         self.values.truncate(depth)
     }
 
-    pub(crate) fn get_slice(&mut self, from: usize) -> &[Value] {
+    /// Move the top `count` values down to `depth`, dropping everything that was in between
+    pub(crate) fn truncate_keeping_top(&mut self, depth: usize, count: usize) {
+        let top = self.values.len() - count;
+        self.values.copy_within(top.., depth);
+        self.values.truncate(depth + count);
+    }
+
+    pub(crate) fn get_slice(&self, from: usize) -> &[Value] {
         &self.values[from..]
     }
+
+    pub(crate) fn get_range(&self, range: std::ops::Range<usize>) -> &[Value] {
+        &self.values[range]
+    }
 }
 
 impl Debug for ValueStore<'_> {
@@ -127,11 +146,12 @@ impl Debug for ValueStore<'_> {
 mod tests {
     use super::*;
 
-    const VALUES: [Value; 4] = [
+    const VALUES: [Value; 5] = [
         Value::I32(123),
         Value::I64(123456),
         Value::F32(1.01),
         Value::F64(-1.1),
+        Value::V128(u128::MAX - 1),
     ];
 
     #[test]
@@ -160,4 +180,17 @@ mod tests {
 
         assert_eq!(format!("{VALUES:?}"), format!("{stack:?}"));
     }
+
+    #[test]
+    fn test_truncate_keeping_top() {
+        let arena = Bump::new();
+        let mut stack = ValueStore::new(&arena);
+
+        for val in VALUES {
+            stack.push(val);
+        }
+        stack.truncate_keeping_top(1, 2);
+
+        assert_eq!(stack.get_slice(0), [VALUES[0], VALUES[3], VALUES[4]]);
+    }
 }
@@ -1,7 +1,11 @@
 use rand::prelude::*;
 use roc_wasm_module::Value;
-use std::io::{self, Read, StderrLock, StdoutLock, Write};
+use std::ffi::OsStr;
+use std::fs::{self, File, Metadata, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, StderrLock, StdoutLock, Write};
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 pub const MODULE_NAME: &str = "wasi_snapshot_preview1";
 
@@ -9,6 +13,7 @@ pub struct WasiDispatcher<'a> {
     pub args: &'a [&'a [u8]],
     pub rng: ThreadRng,
     pub files: Vec<WasiFile>,
+    start_time: Instant,
 }
 
 impl Default for WasiDispatcher<'_> {
@@ -22,14 +27,76 @@ pub enum WasiFile {
     WriteOnly(Vec<u8>),
     ReadWrite(Vec<u8>),
     HostSystemFile,
+    /// A host directory the app was given access to, like `wasmtime --dir`
+    Preopen {
+        guest_path: String,
+        dir: Directory,
+    },
+    /// A directory opened with `path_open`
+    Directory(Directory),
+    /// A regular file opened with `path_open`
+    HostFile(File),
+    /// The number of a closed file descriptor can be reused by `path_open`
+    Closed,
+}
+
+/// A directory on the host. Paths resolved relative to it can't leave its sandbox.
+pub struct Directory {
+    pub host_path: PathBuf,
+    /// The preopened directory this one is in
+    pub sandbox_root: PathBuf,
 }
 
 enum WriteLock<'a> {
     StdOut(StdoutLock<'a>),
     Stderr(StderrLock<'a>),
     RegularFile(&'a mut Vec<u8>),
+    HostFile(&'a mut File),
 }
 
+const FILETYPE_UNKNOWN: u8 = 0;
+const FILETYPE_CHARACTER_DEVICE: u8 = 2;
+const FILETYPE_DIRECTORY: u8 = 3;
+const FILETYPE_REGULAR_FILE: u8 = 4;
+const FILETYPE_SYMBOLIC_LINK: u8 = 7;
+
+const PREOPENTYPE_DIR: u8 = 0;
+
+const CLOCK_REALTIME: u32 = 0;
+const CLOCK_MONOTONIC: u32 = 1;
+const CLOCK_PROCESS_CPUTIME: u32 = 2;
+const CLOCK_THREAD_CPUTIME: u32 = 3;
+
+const WHENCE_SET: u8 = 0;
+const WHENCE_CUR: u8 = 1;
+const WHENCE_END: u8 = 2;
+
+const LOOKUPFLAGS_SYMLINK_FOLLOW: u32 = 1;
+
+const OFLAGS_CREAT: u32 = 1;
+const OFLAGS_DIRECTORY: u32 = 2;
+const OFLAGS_EXCL: u32 = 4;
+const OFLAGS_TRUNC: u32 = 8;
+
+const FDFLAGS_APPEND: u32 = 1;
+
+const RIGHTS_FD_READ: u64 = 1 << 1;
+const RIGHTS_FD_WRITE: u64 = 1 << 6;
+/// Every right defined by wasi_snapshot_preview1. We don't restrict what apps can do with a file
+/// beyond what the host OS allows, so we report all of them.
+const RIGHTS_ALL: u64 = (1 << 29) - 1;
+
+const EVENTTYPE_CLOCK: u8 = 0;
+const EVENTTYPE_FD_READ: u8 = 1;
+const EVENTTYPE_FD_WRITE: u8 = 2;
+
+const SUBCLOCKFLAGS_SUBSCRIPTION_CLOCK_ABSTIME: u16 = 1;
+
+const SIZE_OF_FDSTAT: usize = 24;
+const SIZE_OF_DIRENT: usize = 24;
+const SIZE_OF_SUBSCRIPTION: usize = 48;
+const SIZE_OF_EVENT: usize = 32;
+
 /// Implementation of WASI syscalls
 /// References for other engines:
 /// https://github.com/wasmerio/wasmer/blob/ef8d2f651ed29b4b06fdc2070eb8189922c54d82/lib/wasi/src/syscalls/mod.rs
@@ -44,7 +111,36 @@ impl<'a> WasiDispatcher<'a> {
                 WasiFile::HostSystemFile,
                 WasiFile::HostSystemFile,
             ],
+            start_time: Instant::now(),
+        }
+    }
+
+    /// Give the app access to a directory on the host, which it will see as `guest_path`.
+    /// Returns the file descriptor of the preopened directory.
+    pub fn preopen_dir(
+        &mut self,
+        guest_path: &str,
+        host_path: impl AsRef<Path>,
+    ) -> io::Result<u32> {
+        let host_path = host_path.as_ref().canonicalize()?;
+
+        if !host_path.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a directory", host_path.display()),
+            ));
         }
+
+        let fd = self.files.len() as u32;
+        self.files.push(WasiFile::Preopen {
+            guest_path: guest_path.to_string(),
+            dir: Directory {
+                host_path: host_path.clone(),
+                sandbox_root: host_path,
+            },
+        });
+
+        Ok(fd)
     }
 
     pub fn dispatch(
@@ -107,49 +203,31 @@ impl<'a> WasiDispatcher<'a> {
 
                 success_code
             }
-            "clock_res_get" => success_code, // this dummy implementation seems to be good enough for some functions
-            "clock_time_get" => success_code,
-            "fd_advise" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_allocate" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_close" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_datasync" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_fdstat_get" => {
-                // (i32, i32) -> i32
-
-                // file descriptor
-                let fd = arguments[0].expect_i32().unwrap() as usize;
-                // ptr to a wasi_fdstat_t
-                let stat_mut_ptr = arguments[1].expect_i32().unwrap() as usize;
-
-                match fd {
-                    1 => {
-                        // Tell WASI that stdout is a tty (no seek or tell)
-                        // https://github.com/WebAssembly/wasi-libc/blob/659ff414560721b1660a19685110e484a081c3d4/libc-bottom-half/sources/isatty.c
-                        // *Not* a tty if:
-                        //     (statbuf.fs_filetype != __WASI_FILETYPE_CHARACTER_DEVICE ||
-                        //         (statbuf.fs_rights_base & (__WASI_RIGHTS_FD_SEEK | __WASI_RIGHTS_FD_TELL)) != 0)
-                        // So it's sufficient to set:
-                        //     .fs_filetype = __WASI_FILETYPE_CHARACTER_DEVICE
-                        //     .fs_rights_base = 0
-
-                        const WASI_FILETYPE_CHARACTER_DEVICE: u8 = 2;
-                        memory[stat_mut_ptr] = WASI_FILETYPE_CHARACTER_DEVICE;
-
-                        for b in memory[stat_mut_ptr + 1..stat_mut_ptr + 24].iter_mut() {
-                            *b = 0;
-                        }
-                    }
-                    _ => todo!("WASI {}({:?})", function_name, arguments),
-                }
-
-                success_code
+            "clock_res_get" => errno_code(self.clock_res_get(arguments, memory)),
+            "clock_time_get" => errno_code(self.clock_time_get(arguments, memory)),
+            "fd_advise" => {
+                // The advice is only a hint, so it's fine to ignore it
+                errno_code(self.file(usize_arg(arguments, 0)).map(|_| ()))
+            }
+            "fd_allocate" => errno_code(self.fd_allocate(arguments)),
+            "fd_close" => errno_code(self.fd_close(arguments)),
+            "fd_datasync" => errno_code(self.fd_sync(arguments, File::sync_data)),
+            "fd_fdstat_get" => errno_code(self.fd_fdstat_get(arguments, memory)),
+            "fd_fdstat_set_flags" => {
+                // Changing to append or non-blocking mode after opening would need platform-specific code
+                Some(Value::I32(Errno::Notsup as i32))
+            }
+            "fd_fdstat_set_rights" => {
+                // Rights can only be removed, and we don't enforce them anyway
+                errno_code(self.file(usize_arg(arguments, 0)).map(|_| ()))
             }
-            "fd_fdstat_set_flags" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_fdstat_set_rights" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_filestat_get" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_filestat_set_size" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_filestat_set_times" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_pread" => todo!("WASI {}({:?})", function_name, arguments),
+            "fd_filestat_get" => errno_code(self.fd_filestat_get(arguments, memory)),
+            "fd_filestat_set_size" => errno_code(self.fd_filestat_set_size(arguments)),
+            "fd_filestat_set_times" => {
+                // std::fs has no portable way to set access and modification times
+                Some(Value::I32(Errno::Notsup as i32))
+            }
+            "fd_pread" => errno_code(self.fd_pread(arguments, memory)),
             "fd_prestat_get" => {
                 // The preopened file descriptor to query
                 let fd = arguments[0].expect_i32().unwrap() as usize;
@@ -157,19 +235,30 @@ impl<'a> WasiDispatcher<'a> {
                 //  preopen type: 4 bytes, where 0=dir is the only one supported, it seems
                 //  preopen name length: 4 bytes
                 let ptr_buf = arguments[1].expect_i32().unwrap() as usize;
-                memory[ptr_buf..][..8].copy_from_slice(&0u64.to_le_bytes());
-                if fd < self.files.len() {
-                    success_code
-                } else {
-                    println!("WASI warning: file descriptor {fd} does not exist");
-                    Some(Value::I32(Errno::Badf as i32))
+                match self.files.get(fd) {
+                    Some(WasiFile::Preopen { guest_path, .. }) => {
+                        memory[ptr_buf..][..4].copy_from_slice(&[PREOPENTYPE_DIR, 0, 0, 0]);
+                        write_u32(memory, ptr_buf + 4, guest_path.len() as u32);
+                        success_code
+                    }
+                    // wasi-libc asks about every file descriptor from 3 upwards, until it gets Badf
+                    _ => Some(Value::I32(Errno::Badf as i32)),
                 }
             }
             "fd_prestat_dir_name" => {
-                // We're not giving names to any of our files so just return success
-                success_code
+                let fd = arguments[0].expect_i32().unwrap() as usize;
+                let ptr_path = arguments[1].expect_i32().unwrap() as usize;
+                let path_len = arguments[2].expect_i32().unwrap() as usize;
+                match self.files.get(fd) {
+                    Some(WasiFile::Preopen { guest_path, .. }) => {
+                        let len = path_len.min(guest_path.len());
+                        memory[ptr_path..][..len].copy_from_slice(&guest_path.as_bytes()[..len]);
+                        success_code
+                    }
+                    _ => Some(Value::I32(Errno::Badf as i32)),
+                }
             }
-            "fd_pwrite" => todo!("WASI {}({:?})", function_name, arguments),
+            "fd_pwrite" => errno_code(self.fd_pwrite(arguments, memory)),
             "fd_read" => {
                 use WasiFile::*;
 
@@ -178,36 +267,27 @@ impl<'a> WasiDispatcher<'a> {
                 // Array of IO vectors
                 let ptr_iovs = arguments[1].expect_i32().unwrap() as usize;
                 // Length of array
-                let iovs_len = arguments[2].expect_i32().unwrap();
+                let iovs_len = arguments[2].expect_i32().unwrap() as usize;
                 // Out param: number of bytes read
                 let ptr_nread = arguments[3].expect_i32().unwrap() as usize;
 
-                // https://man7.org/linux/man-pages/man2/readv.2.html
-                // struct iovec {
-                //     void  *iov_base;    /* Starting address */
-                //     size_t iov_len;     /* Number of bytes to transfer */
-                // };
+                let iovs = read_iovecs(memory, ptr_iovs, iovs_len);
 
                 let mut n_read: usize = 0;
-                match self.files.get(fd) {
+                match self.files.get_mut(fd) {
                     Some(ReadOnly(content) | ReadWrite(content)) => {
-                        for _ in 0..iovs_len {
-                            let iov_base = read_u32(memory, ptr_iovs) as usize;
-                            let iov_len = read_i32(memory, ptr_iovs + 4) as usize;
-                            let remaining = content.len() - n_read;
-                            let len = remaining.min(iov_len);
-                            if len == 0 {
-                                break;
-                            }
-                            memory[iov_base..][..len].copy_from_slice(&content[n_read..][..len]);
-                            n_read += len;
+                        match read_into_iovecs(&mut content.as_slice(), memory, &iovs) {
+                            Ok(n) => n_read = n,
+                            Err(e) => return Some(Value::I32(Errno::from(e) as i32)),
                         }
                     }
+                    Some(HostFile(file)) => match read_into_iovecs(file, memory, &iovs) {
+                        Ok(n) => n_read = n,
+                        Err(e) => return Some(Value::I32(Errno::from(e) as i32)),
+                    },
                     Some(HostSystemFile) if fd == 0 => {
                         let mut stdin = io::stdin();
-                        for _ in 0..iovs_len {
-                            let iov_base = read_u32(memory, ptr_iovs) as usize;
-                            let iov_len = read_i32(memory, ptr_iovs + 4) as usize;
+                        for (iov_base, iov_len) in iovs {
                             match stdin.read(&mut memory[iov_base..][..iov_len]) {
                                 Ok(n) => {
                                     n_read += n;
@@ -218,17 +298,20 @@ impl<'a> WasiDispatcher<'a> {
                             }
                         }
                     }
+                    Some(Preopen { .. } | Directory(_)) => {
+                        return Some(Value::I32(Errno::Isdir as i32))
+                    }
                     _ => return Some(Value::I32(Errno::Badf as i32)),
                 };
 
                 memory[ptr_nread..][..4].copy_from_slice(&(n_read as u32).to_le_bytes());
                 success_code
             }
-            "fd_readdir" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_renumber" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_seek" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_sync" => todo!("WASI {}({:?})", function_name, arguments),
-            "fd_tell" => todo!("WASI {}({:?})", function_name, arguments),
+            "fd_readdir" => errno_code(self.fd_readdir(arguments, memory)),
+            "fd_renumber" => errno_code(self.fd_renumber(arguments)),
+            "fd_seek" => errno_code(self.fd_seek(arguments, memory)),
+            "fd_sync" => errno_code(self.fd_sync(arguments, File::sync_all)),
+            "fd_tell" => errno_code(self.fd_tell(arguments, memory)),
             "fd_write" => {
                 use WasiFile::*;
 
@@ -252,6 +335,10 @@ impl<'a> WasiDispatcher<'a> {
                     Some(WriteOnly(content) | ReadWrite(content)) => {
                         WriteLock::RegularFile(content)
                     }
+                    Some(HostFile(file)) => WriteLock::HostFile(file),
+                    Some(Preopen { .. } | Directory(_)) => {
+                        return Some(Value::I32(Errno::Isdir as i32))
+                    }
                     _ => return Some(Value::I32(Errno::Badf as i32)),
                 };
 
@@ -280,6 +367,7 @@ impl<'a> WasiDispatcher<'a> {
                         WriteLock::StdOut(stdout) => stdout.write_all(bytes),
                         WriteLock::Stderr(stderr) => stderr.write_all(bytes),
                         WriteLock::RegularFile(content) => content.write_all(bytes),
+                        WriteLock::HostFile(file) => file.write_all(bytes),
                     };
                     if write_result.is_err() {
                         break;
@@ -300,23 +388,32 @@ impl<'a> WasiDispatcher<'a> {
                     Err(_) => Some(Value::I32(Errno::Io as i32)),
                 }
             }
-            "path_create_directory" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_filestat_get" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_filestat_set_times" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_link" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_open" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_readlink" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_remove_directory" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_rename" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_symlink" => todo!("WASI {}({:?})", function_name, arguments),
-            "path_unlink_file" => todo!("WASI {}({:?})", function_name, arguments),
-            "poll_oneoff" => todo!("WASI {}({:?})", function_name, arguments),
+            "path_create_directory" => errno_code(self.path_create_directory(arguments, memory)),
+            "path_filestat_get" => errno_code(self.path_filestat_get(arguments, memory)),
+            "path_filestat_set_times" => {
+                // std::fs has no portable way to set access and modification times
+                Some(Value::I32(Errno::Notsup as i32))
+            }
+            "path_link" => errno_code(self.path_link(arguments, memory)),
+            "path_open" => errno_code(self.path_open(arguments, memory)),
+            "path_readlink" => errno_code(self.path_readlink(arguments, memory)),
+            "path_remove_directory" => errno_code(self.path_remove_directory(arguments, memory)),
+            "path_rename" => errno_code(self.path_rename(arguments, memory)),
+            "path_symlink" => errno_code(self.path_symlink(arguments, memory)),
+            "path_unlink_file" => errno_code(self.path_unlink_file(arguments, memory)),
+            "poll_oneoff" => errno_code(self.poll_oneoff(arguments, memory)),
             "proc_exit" => {
                 let exit_code = arguments[0].expect_i32().unwrap();
                 exit(exit_code);
             }
-            "proc_raise" => todo!("WASI {}({:?})", function_name, arguments),
-            "sched_yield" => todo!("WASI {}({:?})", function_name, arguments),
+            "proc_raise" => {
+                // There's nothing to deliver a signal to, other than the interpreter itself
+                Some(Value::I32(Errno::Notsup as i32))
+            }
+            "sched_yield" => {
+                std::thread::yield_now();
+                success_code
+            }
             "random_get" => {
                 // A pointer to a buffer where the random bytes will be written
                 let ptr_buf = arguments[0].expect_i32().unwrap() as usize;
@@ -327,12 +424,954 @@ impl<'a> WasiDispatcher<'a> {
                 }
                 success_code
             }
-            "sock_recv" => todo!("WASI {}({:?})", function_name, arguments),
-            "sock_send" => todo!("WASI {}({:?})", function_name, arguments),
-            "sock_shutdown" => todo!("WASI {}({:?})", function_name, arguments),
+            // The sandbox has no network access
+            "sock_accept" | "sock_recv" | "sock_send" | "sock_shutdown" => {
+                Some(Value::I32(Errno::Notsup as i32))
+            }
             _ => panic!("Unknown WASI function {function_name}({arguments:?})"),
         }
     }
+
+    fn file(&self, fd: usize) -> Result<&WasiFile, Errno> {
+        match self.files.get(fd) {
+            None | Some(WasiFile::Closed) => Err(Errno::Badf),
+            Some(file) => Ok(file),
+        }
+    }
+
+    fn file_mut(&mut self, fd: usize) -> Result<&mut WasiFile, Errno> {
+        match self.files.get_mut(fd) {
+            None | Some(WasiFile::Closed) => Err(Errno::Badf),
+            Some(file) => Ok(file),
+        }
+    }
+
+    fn directory(&self, fd: usize) -> Result<&Directory, Errno> {
+        match self.file(fd)? {
+            WasiFile::Preopen { dir, .. } | WasiFile::Directory(dir) => Ok(dir),
+            _ => Err(Errno::Notdir),
+        }
+    }
+
+    /// Find the host path for a path argument, which is relative to the directory `fd`
+    fn resolve_path(
+        &self,
+        fd: usize,
+        memory: &[u8],
+        ptr_path: usize,
+        path_len: usize,
+    ) -> Result<PathBuf, Errno> {
+        let path = read_path(memory, ptr_path, path_len)?;
+        self.directory(fd)?.resolve(path)
+    }
+
+    /// Like POSIX, reuse the lowest free file descriptor
+    fn insert_file(&mut self, file: WasiFile) -> u32 {
+        match self
+            .files
+            .iter()
+            .position(|f| matches!(f, WasiFile::Closed))
+        {
+            Some(fd) => {
+                self.files[fd] = file;
+                fd as u32
+            }
+            None => {
+                self.files.push(file);
+                self.files.len() as u32 - 1
+            }
+        }
+    }
+
+    fn now(&self, clock_id: u32) -> Result<u64, Errno> {
+        match clock_id {
+            CLOCK_REALTIME => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|t| t.as_nanos() as u64)
+                .map_err(|_| Errno::Overflow),
+            // We don't measure CPU time. The interpreter only runs one thread, and only sleeps in
+            // poll_oneoff, so the time since it started is a decent approximation.
+            CLOCK_MONOTONIC | CLOCK_PROCESS_CPUTIME | CLOCK_THREAD_CPUTIME => {
+                Ok(self.start_time.elapsed().as_nanos() as u64)
+            }
+            _ => Err(Errno::Inval),
+        }
+    }
+
+    fn clock_res_get(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let clock_id = u32_arg(arguments, 0);
+        let ptr_resolution = usize_arg(arguments, 1);
+
+        // Check the clock exists
+        self.now(clock_id)?;
+
+        // Both SystemTime and Instant count in nanoseconds
+        write_u64(memory, ptr_resolution, 1);
+        Ok(())
+    }
+
+    fn clock_time_get(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let clock_id = u32_arg(arguments, 0);
+        // arguments[1] is the precision, and we always give the best we've got
+        let ptr_time = usize_arg(arguments, 2);
+
+        write_u64(memory, ptr_time, self.now(clock_id)?);
+        Ok(())
+    }
+
+    fn fd_allocate(&mut self, arguments: &[Value]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let offset = u64_arg(arguments, 1);
+        let len = u64_arg(arguments, 2);
+        let end = offset.checked_add(len).ok_or(Errno::Fbig)?;
+
+        match self.file_mut(fd)? {
+            WasiFile::HostFile(file) => {
+                if file.metadata()?.len() < end {
+                    file.set_len(end)?;
+                }
+            }
+            WasiFile::WriteOnly(content) | WasiFile::ReadWrite(content) => {
+                if (content.len() as u64) < end {
+                    content.resize(end as usize, 0);
+                }
+            }
+            WasiFile::ReadOnly(_) => return Err(Errno::Badf),
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => return Err(Errno::Isdir),
+            WasiFile::HostSystemFile | WasiFile::Closed => return Err(Errno::Spipe),
+        }
+
+        Ok(())
+    }
+
+    fn fd_close(&mut self, arguments: &[Value]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+
+        self.file(fd)?;
+        self.files[fd] = WasiFile::Closed;
+        Ok(())
+    }
+
+    fn fd_sync(
+        &mut self,
+        arguments: &[Value],
+        sync: fn(&File) -> io::Result<()>,
+    ) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+
+        match self.file(fd)? {
+            WasiFile::HostFile(file) => Ok(sync(file)?),
+            // Nothing is buffered anywhere else
+            _ => Ok(()),
+        }
+    }
+
+    fn fd_fdstat_get(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        // file descriptor
+        let fd = usize_arg(arguments, 0);
+        // ptr to a wasi_fdstat_t
+        let stat_mut_ptr = usize_arg(arguments, 1);
+
+        let (filetype, rights) = match self.file(fd)? {
+            _ if fd <= 2 => {
+                // Tell WASI that stdio is a tty (no seek or tell)
+                // https://github.com/WebAssembly/wasi-libc/blob/659ff414560721b1660a19685110e484a081c3d4/libc-bottom-half/sources/isatty.c
+                // *Not* a tty if:
+                //     (statbuf.fs_filetype != __WASI_FILETYPE_CHARACTER_DEVICE ||
+                //         (statbuf.fs_rights_base & (__WASI_RIGHTS_FD_SEEK | __WASI_RIGHTS_FD_TELL)) != 0)
+                // So it's sufficient to set:
+                //     .fs_filetype = __WASI_FILETYPE_CHARACTER_DEVICE
+                //     .fs_rights_base = 0
+                (FILETYPE_CHARACTER_DEVICE, 0)
+            }
+            file => (file.filetype(), RIGHTS_ALL),
+        };
+
+        // struct fdstat { u8 filetype; u16 flags; u64 rights_base; u64 rights_inheriting; }
+        let stat = &mut memory[stat_mut_ptr..][..SIZE_OF_FDSTAT];
+        stat.fill(0);
+        stat[0] = filetype;
+        stat[8..16].copy_from_slice(&rights.to_le_bytes());
+        stat[16..24].copy_from_slice(&rights.to_le_bytes());
+
+        Ok(())
+    }
+
+    fn fd_filestat_get(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_filestat = usize_arg(arguments, 1);
+
+        let filestat = match self.file(fd)? {
+            WasiFile::HostFile(file) => Filestat::from_metadata(&file.metadata()?),
+            WasiFile::Preopen { dir, .. } | WasiFile::Directory(dir) => {
+                Filestat::from_metadata(&fs::metadata(&dir.host_path)?)
+            }
+            WasiFile::ReadOnly(content)
+            | WasiFile::WriteOnly(content)
+            | WasiFile::ReadWrite(content) => Filestat {
+                filetype: FILETYPE_REGULAR_FILE,
+                nlink: 1,
+                size: content.len() as u64,
+                ..Default::default()
+            },
+            WasiFile::HostSystemFile | WasiFile::Closed => Filestat {
+                filetype: FILETYPE_CHARACTER_DEVICE,
+                nlink: 1,
+                ..Default::default()
+            },
+        };
+
+        filestat.write(memory, ptr_filestat);
+        Ok(())
+    }
+
+    fn fd_filestat_set_size(&mut self, arguments: &[Value]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let size = u64_arg(arguments, 1);
+
+        match self.file_mut(fd)? {
+            WasiFile::HostFile(file) => file.set_len(size)?,
+            WasiFile::WriteOnly(content) | WasiFile::ReadWrite(content) => {
+                content.resize(size as usize, 0)
+            }
+            WasiFile::ReadOnly(_) | WasiFile::HostSystemFile | WasiFile::Closed => {
+                return Err(Errno::Inval)
+            }
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => return Err(Errno::Isdir),
+        }
+
+        Ok(())
+    }
+
+    fn fd_pread(&mut self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_iovs = usize_arg(arguments, 1);
+        let iovs_len = usize_arg(arguments, 2);
+        let offset = u64_arg(arguments, 3);
+        let ptr_nread = usize_arg(arguments, 4);
+
+        let iovs = read_iovecs(memory, ptr_iovs, iovs_len);
+
+        let n_read = match self.file_mut(fd)? {
+            WasiFile::HostFile(file) => {
+                // pread doesn't move the file position
+                let position = file.stream_position()?;
+                file.seek(SeekFrom::Start(offset))?;
+                let result = read_into_iovecs(file, memory, &iovs);
+                file.seek(SeekFrom::Start(position))?;
+                result?
+            }
+            WasiFile::ReadOnly(content) | WasiFile::ReadWrite(content) => {
+                let mut rest = content.get(offset as usize..).unwrap_or_default();
+                read_into_iovecs(&mut rest, memory, &iovs)?
+            }
+            WasiFile::WriteOnly(_) | WasiFile::Closed => return Err(Errno::Badf),
+            WasiFile::HostSystemFile => return Err(Errno::Spipe),
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => return Err(Errno::Isdir),
+        };
+
+        write_u32(memory, ptr_nread, n_read as u32);
+        Ok(())
+    }
+
+    fn fd_pwrite(&mut self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_iovs = usize_arg(arguments, 1);
+        let iovs_len = usize_arg(arguments, 2);
+        let offset = u64_arg(arguments, 3);
+        let ptr_nwritten = usize_arg(arguments, 4);
+
+        let iovs = read_iovecs(memory, ptr_iovs, iovs_len);
+
+        let n_written = match self.file_mut(fd)? {
+            WasiFile::HostFile(file) => {
+                // pwrite doesn't move the file position
+                let position = file.stream_position()?;
+                file.seek(SeekFrom::Start(offset))?;
+                let result = write_from_iovecs(file, memory, &iovs);
+                file.seek(SeekFrom::Start(position))?;
+                result?
+            }
+            WasiFile::WriteOnly(content) | WasiFile::ReadWrite(content) => {
+                let mut cursor = io::Cursor::new(content);
+                cursor.set_position(offset);
+                write_from_iovecs(&mut cursor, memory, &iovs)?
+            }
+            WasiFile::ReadOnly(_) | WasiFile::Closed => return Err(Errno::Badf),
+            WasiFile::HostSystemFile => return Err(Errno::Spipe),
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => return Err(Errno::Isdir),
+        };
+
+        write_u32(memory, ptr_nwritten, n_written as u32);
+        Ok(())
+    }
+
+    fn fd_readdir(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_buf = usize_arg(arguments, 1);
+        let buf_len = usize_arg(arguments, 2);
+        let cookie = u64_arg(arguments, 3);
+        let ptr_bufused = usize_arg(arguments, 4);
+
+        let dir = self.directory(fd)?;
+
+        // wasi-libc looks up the inode of any entry where it's zero, so give "." and ".." real ones.
+        // At the root of the sandbox, ".." is the same as ".", just like at the root of a file system.
+        let dot = Filestat::from_metadata(&fs::metadata(&dir.host_path)?);
+        let dot_dot = match dir.host_path.parent() {
+            Some(parent) if dir.host_path != dir.sandbox_root => {
+                Filestat::from_metadata(&fs::metadata(parent)?)
+            }
+            _ => dot,
+        };
+
+        let mut entries = vec![(".".to_string(), dot), ("..".to_string(), dot_dot)];
+        let mut host_entries = fs::read_dir(&dir.host_path)?
+            .map(|entry| {
+                let entry = entry?;
+                let filestat = Filestat::from_metadata(&entry.metadata()?);
+                Ok((entry.file_name().to_string_lossy().into_owned(), filestat))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // Cookies are indices into this list, so it needs to be in a stable order
+        host_entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries.extend(host_entries);
+
+        // struct dirent { u64 d_next; u64 d_ino; u32 d_namlen; u8 d_type; }, followed by the name.
+        // If the last entry doesn't fit, we write as much of it as we can, and the app will ask
+        // again starting from that entry's cookie.
+        let mut buf_used = 0;
+        for (index, (name, filestat)) in entries.iter().enumerate().skip(cookie as usize) {
+            let mut dirent = [0; SIZE_OF_DIRENT];
+            dirent[0..8].copy_from_slice(&(index as u64 + 1).to_le_bytes());
+            dirent[8..16].copy_from_slice(&filestat.ino.to_le_bytes());
+            dirent[16..20].copy_from_slice(&(name.len() as u32).to_le_bytes());
+            dirent[20] = filestat.filetype;
+
+            for bytes in [&dirent[..], name.as_bytes()] {
+                let len = bytes.len().min(buf_len - buf_used);
+                memory[ptr_buf + buf_used..][..len].copy_from_slice(&bytes[..len]);
+                buf_used += len;
+            }
+
+            if buf_used == buf_len {
+                break;
+            }
+        }
+
+        write_u32(memory, ptr_bufused, buf_used as u32);
+        Ok(())
+    }
+
+    fn fd_renumber(&mut self, arguments: &[Value]) -> Result<(), Errno> {
+        let from = usize_arg(arguments, 0);
+        let to = usize_arg(arguments, 1);
+
+        self.file(from)?;
+        self.file(to)?;
+
+        if from != to {
+            // Overwriting `to` closes whatever it was
+            self.files[to] = std::mem::replace(&mut self.files[from], WasiFile::Closed);
+        }
+
+        Ok(())
+    }
+
+    fn fd_seek(&mut self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let offset = arguments[1].expect_i64().unwrap();
+        let whence = u32_arg(arguments, 2) as u8;
+        let ptr_newoffset = usize_arg(arguments, 3);
+
+        let position = match whence {
+            WHENCE_SET => SeekFrom::Start(u64::try_from(offset).map_err(|_| Errno::Inval)?),
+            WHENCE_CUR => SeekFrom::Current(offset),
+            WHENCE_END => SeekFrom::End(offset),
+            _ => return Err(Errno::Inval),
+        };
+
+        let new_offset = self.seekable_file(fd)?.seek(position)?;
+
+        write_u64(memory, ptr_newoffset, new_offset);
+        Ok(())
+    }
+
+    fn fd_tell(&mut self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_offset = usize_arg(arguments, 1);
+
+        let offset = self.seekable_file(fd)?.stream_position()?;
+
+        write_u64(memory, ptr_offset, offset);
+        Ok(())
+    }
+
+    /// In-memory files behave like pipes: we don't keep track of a position in them
+    fn seekable_file(&mut self, fd: usize) -> Result<&mut File, Errno> {
+        match self.file_mut(fd)? {
+            WasiFile::HostFile(file) => Ok(file),
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => Err(Errno::Isdir),
+            _ => Err(Errno::Spipe),
+        }
+    }
+
+    fn path_create_directory(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_path = usize_arg(arguments, 1);
+        let path_len = usize_arg(arguments, 2);
+
+        let path = self.resolve_path(fd, memory, ptr_path, path_len)?;
+
+        Ok(fs::create_dir(path)?)
+    }
+
+    fn path_filestat_get(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let lookup_flags = u32_arg(arguments, 1);
+        let ptr_path = usize_arg(arguments, 2);
+        let path_len = usize_arg(arguments, 3);
+        let ptr_filestat = usize_arg(arguments, 4);
+
+        let path = read_path(memory, ptr_path, path_len)?;
+        let dir = self.directory(fd)?;
+        let metadata = if lookup_flags & LOOKUPFLAGS_SYMLINK_FOLLOW != 0 {
+            fs::metadata(dir.resolve_following(path)?)?
+        } else {
+            fs::symlink_metadata(dir.resolve(path)?)?
+        };
+
+        Filestat::from_metadata(&metadata).write(memory, ptr_filestat);
+        Ok(())
+    }
+
+    fn path_link(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let old_fd = usize_arg(arguments, 0);
+        // arguments[1] is the lookup flags for the old path
+        let ptr_old_path = usize_arg(arguments, 2);
+        let old_path_len = usize_arg(arguments, 3);
+        let new_fd = usize_arg(arguments, 4);
+        let ptr_new_path = usize_arg(arguments, 5);
+        let new_path_len = usize_arg(arguments, 6);
+
+        let old_path = self.resolve_path(old_fd, memory, ptr_old_path, old_path_len)?;
+        let new_path = self.resolve_path(new_fd, memory, ptr_new_path, new_path_len)?;
+
+        Ok(fs::hard_link(old_path, new_path)?)
+    }
+
+    fn path_open(&mut self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        // arguments[1] is the lookup flags. We always follow symlinks, as long as they stay in the sandbox.
+        let ptr_path = usize_arg(arguments, 2);
+        let path_len = usize_arg(arguments, 3);
+        let oflags = u32_arg(arguments, 4);
+        let rights = u64_arg(arguments, 5);
+        // arguments[6] is the rights for files opened from this one, which we don't enforce
+        let fdflags = u32_arg(arguments, 7);
+        let ptr_fd = usize_arg(arguments, 8);
+
+        let path = read_path(memory, ptr_path, path_len)?;
+        let dir = self.directory(fd)?;
+        let path = dir.resolve_following(path)?;
+        let sandbox_root = dir.sandbox_root.clone();
+
+        let file = if oflags & OFLAGS_DIRECTORY != 0 || path.is_dir() {
+            if oflags & OFLAGS_CREAT != 0 && oflags & OFLAGS_EXCL != 0 {
+                return Err(if path.exists() {
+                    Errno::Exist
+                } else {
+                    Errno::Inval
+                });
+            }
+
+            if !fs::metadata(&path)?.is_dir() {
+                return Err(Errno::Notdir);
+            }
+
+            WasiFile::Directory(Directory {
+                host_path: path,
+                sandbox_root,
+            })
+        } else {
+            let create = oflags & OFLAGS_CREAT != 0;
+            let truncate = oflags & OFLAGS_TRUNC != 0;
+            let append = fdflags & FDFLAGS_APPEND != 0;
+            // std only lets us create or truncate files that we open for writing
+            let write = rights & RIGHTS_FD_WRITE != 0 || create || truncate || append;
+            let read = rights & RIGHTS_FD_READ != 0 || !write;
+
+            let file = OpenOptions::new()
+                .read(read)
+                .write(write)
+                .append(append)
+                .truncate(truncate)
+                .create(create)
+                .create_new(create && oflags & OFLAGS_EXCL != 0)
+                .open(path)?;
+
+            WasiFile::HostFile(file)
+        };
+
+        let new_fd = self.insert_file(file);
+        write_u32(memory, ptr_fd, new_fd);
+        Ok(())
+    }
+
+    fn path_readlink(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_path = usize_arg(arguments, 1);
+        let path_len = usize_arg(arguments, 2);
+        let ptr_buf = usize_arg(arguments, 3);
+        let buf_len = usize_arg(arguments, 4);
+        let ptr_bufused = usize_arg(arguments, 5);
+
+        let path = self.resolve_path(fd, memory, ptr_path, path_len)?;
+        let target = fs::read_link(path)?;
+        let target = target.to_string_lossy();
+
+        // Like readlink(2), the result is silently truncated if it doesn't fit
+        let len = target.len().min(buf_len);
+        memory[ptr_buf..][..len].copy_from_slice(&target.as_bytes()[..len]);
+
+        write_u32(memory, ptr_bufused, len as u32);
+        Ok(())
+    }
+
+    fn path_remove_directory(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_path = usize_arg(arguments, 1);
+        let path_len = usize_arg(arguments, 2);
+
+        let path = self.resolve_path(fd, memory, ptr_path, path_len)?;
+
+        // std doesn't have stable error kinds for these yet
+        if !fs::symlink_metadata(&path)?.is_dir() {
+            return Err(Errno::Notdir);
+        }
+        if fs::read_dir(&path)?.next().is_some() {
+            return Err(Errno::Notempty);
+        }
+
+        Ok(fs::remove_dir(path)?)
+    }
+
+    fn path_rename(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let old_fd = usize_arg(arguments, 0);
+        let ptr_old_path = usize_arg(arguments, 1);
+        let old_path_len = usize_arg(arguments, 2);
+        let new_fd = usize_arg(arguments, 3);
+        let ptr_new_path = usize_arg(arguments, 4);
+        let new_path_len = usize_arg(arguments, 5);
+
+        let old_path = self.resolve_path(old_fd, memory, ptr_old_path, old_path_len)?;
+        let new_path = self.resolve_path(new_fd, memory, ptr_new_path, new_path_len)?;
+
+        Ok(fs::rename(old_path, new_path)?)
+    }
+
+    fn path_symlink(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let ptr_target = usize_arg(arguments, 0);
+        let target_len = usize_arg(arguments, 1);
+        let fd = usize_arg(arguments, 2);
+        let ptr_path = usize_arg(arguments, 3);
+        let path_len = usize_arg(arguments, 4);
+
+        let target = read_path(memory, ptr_target, target_len)?;
+        let link = read_path(memory, ptr_path, path_len)?;
+
+        // An absolute target is always outside the sandbox. A relative one is relative to the
+        // directory the link is in, and must stay in the sandbox too.
+        if target.starts_with('/') {
+            return Err(Errno::Notcapable);
+        }
+        let target_from_dir = match link.rsplit_once('/') {
+            Some((link_dir, _)) => format!("{link_dir}/{target}"),
+            None => target.to_string(),
+        };
+        let dir = self.directory(fd)?;
+        dir.resolve_lexically(&target_from_dir)?;
+        let link = dir.resolve(link)?;
+
+        #[cfg(unix)]
+        {
+            Ok(std::os::unix::fs::symlink(target, link)?)
+        }
+
+        #[cfg(not(unix))]
+        {
+            // Windows needs to know whether the target is a file or a directory
+            let _ = link;
+            Err(Errno::Notsup)
+        }
+    }
+
+    fn path_unlink_file(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let fd = usize_arg(arguments, 0);
+        let ptr_path = usize_arg(arguments, 1);
+        let path_len = usize_arg(arguments, 2);
+
+        let path = self.resolve_path(fd, memory, ptr_path, path_len)?;
+
+        if fs::symlink_metadata(&path)?.is_dir() {
+            return Err(Errno::Isdir);
+        }
+
+        Ok(fs::remove_file(path)?)
+    }
+
+    fn poll_oneoff(&self, arguments: &[Value], memory: &mut [u8]) -> Result<(), Errno> {
+        let ptr_subscriptions = usize_arg(arguments, 0);
+        let ptr_events = usize_arg(arguments, 1);
+        let n_subscriptions = usize_arg(arguments, 2);
+        let ptr_nevents = usize_arg(arguments, 3);
+
+        if n_subscriptions == 0 {
+            return Err(Errno::Inval);
+        }
+
+        let mut events = Vec::with_capacity(n_subscriptions);
+        let mut clocks = Vec::with_capacity(n_subscriptions);
+
+        // struct subscription { u64 userdata; u8 tag; union { clock, fd_read, fd_write } u; }
+        for i in 0..n_subscriptions {
+            let ptr_subscription = ptr_subscriptions + i * SIZE_OF_SUBSCRIPTION;
+            let userdata = read_u64(memory, ptr_subscription);
+            let tag = memory[ptr_subscription + 8];
+
+            match tag {
+                EVENTTYPE_CLOCK => {
+                    // struct clock { u32 id; u64 timeout; u64 precision; u16 flags; }
+                    let clock_id = read_u32(memory, ptr_subscription + 16);
+                    let timeout = read_u64(memory, ptr_subscription + 24);
+                    let flags = read_u16(memory, ptr_subscription + 40);
+
+                    let delay = if flags & SUBCLOCKFLAGS_SUBSCRIPTION_CLOCK_ABSTIME != 0 {
+                        self.now(clock_id).map(|now| timeout.saturating_sub(now))
+                    } else {
+                        self.now(clock_id).map(|_| timeout)
+                    };
+
+                    match delay {
+                        Ok(delay) => clocks.push((userdata, delay)),
+                        Err(errno) => events.push(Event::new(userdata, errno, tag, 0)),
+                    }
+                }
+                EVENTTYPE_FD_READ | EVENTTYPE_FD_WRITE => {
+                    // Our reads and writes block rather than failing with `Again`,
+                    // so every file is always ready.
+                    let fd = read_u32(memory, ptr_subscription + 16) as usize;
+                    let event = match self.file(fd) {
+                        Ok(file) if tag == EVENTTYPE_FD_READ => {
+                            Event::new(userdata, Errno::Success, tag, file.bytes_available())
+                        }
+                        Ok(_) => Event::new(userdata, Errno::Success, tag, 0),
+                        Err(errno) => Event::new(userdata, errno, tag, 0),
+                    };
+                    events.push(event);
+                }
+                _ => return Err(Errno::Inval),
+            }
+        }
+
+        if events.is_empty() {
+            // Nothing else can happen, so sleep until the first timeout
+            let delay = clocks.iter().map(|(_, delay)| *delay).min().unwrap_or(0);
+            std::thread::sleep(Duration::from_nanos(delay));
+
+            events.extend(
+                clocks
+                    .iter()
+                    .filter(|(_, d)| *d == delay)
+                    .map(|(userdata, _)| Event::new(*userdata, Errno::Success, EVENTTYPE_CLOCK, 0)),
+            );
+        }
+
+        for (i, event) in events.iter().enumerate() {
+            event.write(memory, ptr_events + i * SIZE_OF_EVENT);
+        }
+
+        write_u32(memory, ptr_nevents, events.len() as u32);
+        Ok(())
+    }
+}
+
+impl WasiFile {
+    fn filetype(&self) -> u8 {
+        match self {
+            WasiFile::ReadOnly(_) | WasiFile::WriteOnly(_) | WasiFile::ReadWrite(_) => {
+                FILETYPE_REGULAR_FILE
+            }
+            WasiFile::HostFile(_) => FILETYPE_REGULAR_FILE,
+            WasiFile::HostSystemFile => FILETYPE_CHARACTER_DEVICE,
+            WasiFile::Preopen { .. } | WasiFile::Directory(_) => FILETYPE_DIRECTORY,
+            WasiFile::Closed => FILETYPE_UNKNOWN,
+        }
+    }
+
+    /// How many bytes a read could return without blocking, for poll_oneoff
+    fn bytes_available(&self) -> u64 {
+        match self {
+            WasiFile::ReadOnly(content) | WasiFile::ReadWrite(content) => content.len() as u64,
+            WasiFile::HostFile(file) => {
+                let len = file.metadata().map_or(0, |m| m.len());
+                // `Seek` is implemented for `&File`, so this doesn't need `&mut self`
+                let position = (&mut &*file).stream_position().unwrap_or(len);
+                len.saturating_sub(position)
+            }
+            _ => 0,
+        }
+    }
+}
+
+impl Directory {
+    /// Find the host path for a path that the app gave relative to this directory.
+    /// Like other WASI runtimes, we refuse paths that would leave the sandbox.
+    /// If the last component is a symlink, the path is the link itself, not what it points to.
+    fn resolve(&self, guest_path: &str) -> Result<PathBuf, Errno> {
+        let resolved = self.resolve_lexically(guest_path)?;
+
+        if resolved == self.sandbox_root {
+            return Ok(resolved);
+        }
+
+        // A symlink inside the sandbox could still point outside of it, so check where the
+        // directory containing the last component really is
+        let parent = resolved.parent().ok_or(Errno::Notcapable)?;
+        let canonical = parent.canonicalize().map_err(|_| Errno::Notcapable)?;
+        if !canonical.starts_with(&self.sandbox_root) {
+            return Err(Errno::Notcapable);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Like `resolve`, but follows the last component if it is a symlink. We refuse symlinks that
+    /// lead out of the sandbox, and dangling ones, since creating a file through them could too.
+    fn resolve_following(&self, guest_path: &str) -> Result<PathBuf, Errno> {
+        let resolved = self.resolve(guest_path)?;
+
+        let is_symlink = fs::symlink_metadata(&resolved)
+            .map_or(false, |metadata| metadata.file_type().is_symlink());
+        if !is_symlink {
+            return Ok(resolved);
+        }
+
+        let target = resolved.canonicalize().map_err(|_| Errno::Notcapable)?;
+        if !target.starts_with(&self.sandbox_root) {
+            return Err(Errno::Notcapable);
+        }
+
+        Ok(target)
+    }
+
+    /// Join a path to this directory, without looking at the file system.
+    /// Fails if the path is absolute, or if `..` goes above the sandbox root.
+    fn resolve_lexically(&self, guest_path: &str) -> Result<PathBuf, Errno> {
+        if guest_path.starts_with('/') {
+            return Err(Errno::Notcapable);
+        }
+
+        let relative = self
+            .host_path
+            .strip_prefix(&self.sandbox_root)
+            .map_err(|_| Errno::Notcapable)?;
+
+        let mut components: Vec<&OsStr> = relative.iter().collect();
+        for component in guest_path.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    if components.pop().is_none() {
+                        return Err(Errno::Notcapable);
+                    }
+                }
+                name => components.push(OsStr::new(name)),
+            }
+        }
+
+        let mut resolved = self.sandbox_root.clone();
+        resolved.extend(components);
+
+        Ok(resolved)
+    }
+}
+
+/// struct filestat, as returned by fd_filestat_get and path_filestat_get
+#[derive(Clone, Copy, Default)]
+struct Filestat {
+    dev: u64,
+    ino: u64,
+    filetype: u8,
+    nlink: u64,
+    size: u64,
+    atim: u64,
+    mtim: u64,
+    ctim: u64,
+}
+
+impl Filestat {
+    fn from_metadata(metadata: &Metadata) -> Self {
+        let file_type = metadata.file_type();
+        let filetype = if file_type.is_dir() {
+            FILETYPE_DIRECTORY
+        } else if file_type.is_file() {
+            FILETYPE_REGULAR_FILE
+        } else if file_type.is_symlink() {
+            FILETYPE_SYMBOLIC_LINK
+        } else {
+            FILETYPE_UNKNOWN
+        };
+
+        let atim = timestamp(metadata.accessed());
+        let mtim = timestamp(metadata.modified());
+
+        #[cfg(unix)]
+        let (dev, ino, nlink, ctim) = {
+            use std::os::unix::fs::MetadataExt;
+            let ctim = metadata.ctime() as u64 * 1_000_000_000 + metadata.ctime_nsec() as u64;
+            (metadata.dev(), metadata.ino(), metadata.nlink(), ctim)
+        };
+
+        #[cfg(not(unix))]
+        let (dev, ino, nlink, ctim) = (0, 0, 1, mtim);
+
+        Filestat {
+            dev,
+            ino,
+            filetype,
+            nlink,
+            size: metadata.len(),
+            atim,
+            mtim,
+            ctim,
+        }
+    }
+
+    fn write(&self, memory: &mut [u8], ptr: usize) {
+        memory[ptr..][..64].fill(0);
+        write_u64(memory, ptr, self.dev);
+        write_u64(memory, ptr + 8, self.ino);
+        memory[ptr + 16] = self.filetype;
+        write_u64(memory, ptr + 24, self.nlink);
+        write_u64(memory, ptr + 32, self.size);
+        write_u64(memory, ptr + 40, self.atim);
+        write_u64(memory, ptr + 48, self.mtim);
+        write_u64(memory, ptr + 56, self.ctim);
+    }
+}
+
+/// struct event, as returned by poll_oneoff
+struct Event {
+    userdata: u64,
+    error: Errno,
+    eventtype: u8,
+    nbytes: u64,
+}
+
+impl Event {
+    fn new(userdata: u64, error: Errno, eventtype: u8, nbytes: u64) -> Self {
+        Event {
+            userdata,
+            error,
+            eventtype,
+            nbytes,
+        }
+    }
+
+    fn write(&self, memory: &mut [u8], ptr: usize) {
+        memory[ptr..][..SIZE_OF_EVENT].fill(0);
+        write_u64(memory, ptr, self.userdata);
+        memory[ptr + 8..][..2].copy_from_slice(&(self.error as u16).to_le_bytes());
+        memory[ptr + 10] = self.eventtype;
+        write_u64(memory, ptr + 16, self.nbytes);
+    }
+}
+
+fn timestamp(time: io::Result<SystemTime>) -> u64 {
+    time.ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_nanos() as u64)
+}
+
+fn errno_code(result: Result<(), Errno>) -> Option<Value> {
+    let errno = match result {
+        Ok(()) => Errno::Success,
+        Err(errno) => errno,
+    };
+    Some(Value::I32(errno as i32))
+}
+
+fn usize_arg(arguments: &[Value], index: usize) -> usize {
+    arguments[index].expect_i32().unwrap() as u32 as usize
+}
+
+fn u32_arg(arguments: &[Value], index: usize) -> u32 {
+    arguments[index].expect_i32().unwrap() as u32
+}
+
+fn u64_arg(arguments: &[Value], index: usize) -> u64 {
+    arguments[index].expect_i64().unwrap() as u64
+}
+
+fn read_path(memory: &[u8], ptr: usize, len: usize) -> Result<&str, Errno> {
+    std::str::from_utf8(&memory[ptr..][..len]).map_err(|_| Errno::Ilseq)
+}
+
+/// https://man7.org/linux/man-pages/man2/readv.2.html
+/// struct iovec {
+///     void  *iov_base;    /* Starting address */
+///     size_t iov_len;     /* Number of bytes to transfer */
+/// };
+fn read_iovecs(memory: &[u8], ptr_iovs: usize, iovs_len: usize) -> Vec<(usize, usize)> {
+    (0..iovs_len)
+        .map(|i| {
+            let ptr_iov = ptr_iovs + 8 * i;
+            let iov_base = read_u32(memory, ptr_iov) as usize;
+            let iov_len = read_u32(memory, ptr_iov + 4) as usize;
+            (iov_base, iov_len)
+        })
+        .collect()
+}
+
+fn read_into_iovecs(
+    reader: &mut impl Read,
+    memory: &mut [u8],
+    iovs: &[(usize, usize)],
+) -> io::Result<usize> {
+    let mut n_read = 0;
+    for &(iov_base, iov_len) in iovs {
+        let buf = &mut memory[iov_base..][..iov_len];
+        let mut filled = 0;
+        while filled < iov_len {
+            match reader.read(&mut buf[filled..])? {
+                0 => return Ok(n_read + filled),
+                n => filled += n,
+            }
+        }
+        n_read += iov_len;
+    }
+    Ok(n_read)
+}
+
+fn write_from_iovecs(
+    writer: &mut impl Write,
+    memory: &[u8],
+    iovs: &[(usize, usize)],
+) -> io::Result<usize> {
+    let mut n_written = 0;
+    for &(iov_base, iov_len) in iovs {
+        writer.write_all(&memory[iov_base..][..iov_len])?;
+        n_written += iov_len;
+    }
+    Ok(n_written)
+}
+
+fn read_u16(memory: &[u8], addr: usize) -> u16 {
+    let mut bytes = [0; 2];
+    bytes.copy_from_slice(&memory[addr..][..2]);
+    u16::from_le_bytes(bytes)
 }
 
 fn read_u32(memory: &[u8], addr: usize) -> u32 {
@@ -347,6 +1386,12 @@ fn read_i32(memory: &[u8], addr: usize) -> i32 {
     i32::from_le_bytes(bytes)
 }
 
+fn read_u64(memory: &[u8], addr: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&memory[addr..][..8]);
+    u64::from_le_bytes(bytes)
+}
+
 fn write_u32(memory: &mut [u8], addr: usize, value: u32) {
     memory[addr..][..4].copy_from_slice(&value.to_le_bytes());
 }
@@ -355,12 +1400,16 @@ fn write_i32(memory: &mut [u8], addr: usize, value: i32) {
     memory[addr..][..4].copy_from_slice(&value.to_le_bytes());
 }
 
+fn write_u64(memory: &mut [u8], addr: usize, value: u64) {
+    memory[addr..][..8].copy_from_slice(&value.to_le_bytes());
+}
+
 /// Error codes returned by functions.
 /// Not all of these error codes are returned by the functions provided by this
 /// API; some are used in higher-level library layers, and others are provided
 /// merely for alignment with POSIX.
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Errno {
     /// No error occurred. System call completed successfully.
     Success,
@@ -517,3 +1566,22 @@ pub enum Errno {
     /// Extension: Capabilities insufficient.
     Notcapable,
 }
+
+impl From<io::Error> for Errno {
+    fn from(error: io::Error) -> Self {
+        use io::ErrorKind::*;
+
+        match error.kind() {
+            NotFound => Errno::Noent,
+            PermissionDenied => Errno::Access,
+            AlreadyExists => Errno::Exist,
+            InvalidInput => Errno::Inval,
+            InvalidData => Errno::Ilseq,
+            Interrupted => Errno::Intr,
+            WouldBlock => Errno::Again,
+            Unsupported => Errno::Notsup,
+            OutOfMemory => Errno::Nomem,
+            _ => Errno::Io,
+        }
+    }
+}
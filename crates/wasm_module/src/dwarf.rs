@@ -0,0 +1,447 @@
+//! DWARF debug info for the functions we generate, so that Wasm runtimes and browser devtools can
+//! map code addresses in stack traces back to source files, lines and columns.
+//!
+//! We emit one compile unit, with a subprogram for each function that has source locations, and a
+//! line table with one sequence per function. It's DWARF 4 in the 32-bit format, using inline
+//! strings and fixed-size forms, which is all the custom sections `.debug_abbrev`, `.debug_info`
+//! and `.debug_line` need.
+//!
+//! Following the WebAssembly tool conventions, code addresses are offsets from the start of the
+//! Code section contents. https://github.com/WebAssembly/tool-conventions/blob/main/Debugging.md
+use bumpalo::collections::vec::Vec;
+use bumpalo::Bump;
+
+use super::sections::{update_section_size, write_custom_section_header, CodeSection, NameSection};
+use super::serialize::SerialBuffer;
+
+const DWARF_VERSION: u16 = 4;
+const ADDRESS_SIZE: u8 = 4;
+
+/// There's no DWARF language code for Roc. C is what the LLVM backend uses too.
+const DW_LANG_C: u16 = 0x0002;
+const PRODUCER: &str = "roc";
+const COMPILE_UNIT_NAME: &str = "roc_app";
+const COMPILE_UNIT_DIR: &str = ".";
+
+// Tags, attributes and forms, from the DWARF 4 spec, section 7.5
+const DW_TAG_COMPILE_UNIT: u8 = 0x11;
+const DW_TAG_SUBPROGRAM: u8 = 0x2e;
+const DW_CHILDREN_NO: u8 = 0;
+const DW_CHILDREN_YES: u8 = 1;
+const DW_AT_NAME: u8 = 0x03;
+const DW_AT_STMT_LIST: u8 = 0x10;
+const DW_AT_LOW_PC: u8 = 0x11;
+const DW_AT_HIGH_PC: u8 = 0x12;
+const DW_AT_LANGUAGE: u8 = 0x13;
+const DW_AT_COMP_DIR: u8 = 0x1b;
+const DW_AT_PRODUCER: u8 = 0x25;
+const DW_AT_DECL_FILE: u8 = 0x3a;
+const DW_AT_DECL_LINE: u8 = 0x3b;
+const DW_FORM_ADDR: u8 = 0x01;
+const DW_FORM_DATA2: u8 = 0x05;
+const DW_FORM_DATA4: u8 = 0x06;
+const DW_FORM_STRING: u8 = 0x08;
+const DW_FORM_UDATA: u8 = 0x0f;
+const DW_FORM_SEC_OFFSET: u8 = 0x17;
+
+const ABBREV_COMPILE_UNIT: u8 = 1;
+const ABBREV_SUBPROGRAM: u8 = 2;
+
+/// The only abbreviations we use. The high_pc of a function is its size, as DWARF 4 allows.
+#[rustfmt::skip]
+const DEBUG_ABBREV: [u8; 35] = [
+    ABBREV_COMPILE_UNIT, DW_TAG_COMPILE_UNIT, DW_CHILDREN_YES,
+        DW_AT_PRODUCER, DW_FORM_STRING,
+        DW_AT_LANGUAGE, DW_FORM_DATA2,
+        DW_AT_NAME, DW_FORM_STRING,
+        DW_AT_COMP_DIR, DW_FORM_STRING,
+        DW_AT_STMT_LIST, DW_FORM_SEC_OFFSET,
+        DW_AT_LOW_PC, DW_FORM_ADDR,
+        DW_AT_HIGH_PC, DW_FORM_DATA4,
+        0, 0,
+    ABBREV_SUBPROGRAM, DW_TAG_SUBPROGRAM, DW_CHILDREN_NO,
+        DW_AT_LOW_PC, DW_FORM_ADDR,
+        DW_AT_HIGH_PC, DW_FORM_DATA4,
+        DW_AT_NAME, DW_FORM_STRING,
+        DW_AT_DECL_FILE, DW_FORM_UDATA,
+        DW_AT_DECL_LINE, DW_FORM_UDATA,
+        0, 0,
+    0,
+];
+
+// Line number program, from the DWARF 4 spec, section 6.2
+const LINE_BASE: i8 = -5;
+const LINE_RANGE: u8 = 14;
+const OPCODE_BASE: u8 = 13;
+const STANDARD_OPCODE_LENGTHS: [u8; OPCODE_BASE as usize - 1] =
+    [0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1];
+const DW_LNS_COPY: u8 = 0x01;
+const DW_LNS_ADVANCE_PC: u8 = 0x02;
+const DW_LNS_ADVANCE_LINE: u8 = 0x03;
+const DW_LNS_SET_FILE: u8 = 0x04;
+const DW_LNS_SET_COLUMN: u8 = 0x05;
+const DW_LNE_END_SEQUENCE: u8 = 0x01;
+const DW_LNE_SET_ADDRESS: u8 = 0x02;
+
+/// A position in a source file. Lines and columns start at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Index into [DwarfSections::files]
+    pub file: u32,
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Debug)]
+pub struct FunctionLines<'a> {
+    pub fn_index: u32,
+    /// Offsets of instructions from the start of the function body (after its size),
+    /// with the source locations they were generated from. Sorted by offset.
+    pub rows: Vec<'a, (u32, SourceLocation)>,
+}
+
+/// Source locations for the code in the module, to be serialized as DWARF custom sections.
+/// Function names come from the Name section.
+#[derive(Debug)]
+pub struct DwarfSections<'a> {
+    /// Paths of the source files
+    pub files: Vec<'a, &'a str>,
+    pub functions: Vec<'a, FunctionLines<'a>>,
+}
+
+impl<'a> DwarfSections<'a> {
+    pub fn new(arena: &'a Bump) -> Self {
+        DwarfSections {
+            files: Vec::new_in(arena),
+            functions: Vec::new_in(arena),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    pub fn append_function(&mut self, fn_index: u32, rows: Vec<'a, (u32, SourceLocation)>) {
+        self.functions.push(FunctionLines { fn_index, rows });
+    }
+
+    /// Estimated size in bytes, for allocating buffer capacity
+    pub fn size(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let file_bytes: usize = self.files.iter().map(|path| path.len() + 4).sum();
+        let row_bytes: usize = self.functions.iter().map(|f| 16 * f.rows.len() + 64).sum();
+        256 + file_bytes + row_bytes
+    }
+
+    /// Write the `.debug_abbrev`, `.debug_info` and `.debug_line` custom sections.
+    /// Needs to know where the functions are in the serialized Code section.
+    pub fn serialize<T: SerialBuffer>(
+        &self,
+        buffer: &mut T,
+        code: &CodeSection<'a>,
+        import_fn_count: u32,
+        names: &NameSection<'a>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        let body_ranges: std::vec::Vec<_> = code.serialized_body_ranges().collect();
+        let mut functions: std::vec::Vec<_> = self
+            .functions
+            .iter()
+            .filter(|f| !f.rows.is_empty())
+            .map(|f| {
+                (
+                    body_ranges[(f.fn_index - import_fn_count) as usize].clone(),
+                    f,
+                )
+            })
+            .collect();
+        functions.sort_by_key(|(range, _)| range.start);
+
+        let (Some((first, _)), Some((last, _))) = (functions.first(), functions.last()) else {
+            return;
+        };
+        let low_pc = first.start;
+        let high_pc = last.end;
+
+        // .debug_info
+        let mut info: std::vec::Vec<u8> = std::vec::Vec::with_capacity(self.size());
+        info.write_unencoded_u32(0); // unit_length, filled in below
+        append_u16(&mut info, DWARF_VERSION);
+        info.write_unencoded_u32(0); // offset of our abbreviations in .debug_abbrev
+        info.append_u8(ADDRESS_SIZE);
+
+        info.append_u8(ABBREV_COMPILE_UNIT);
+        append_c_str(&mut info, PRODUCER);
+        append_u16(&mut info, DW_LANG_C);
+        append_c_str(&mut info, COMPILE_UNIT_NAME);
+        append_c_str(&mut info, COMPILE_UNIT_DIR);
+        info.write_unencoded_u32(0); // offset of our line program in .debug_line
+        info.write_unencoded_u32(low_pc);
+        info.write_unencoded_u32(high_pc - low_pc);
+
+        for (range, f) in functions.iter() {
+            let name = names
+                .function_names
+                .iter()
+                .find(|(index, _)| *index == f.fn_index)
+                .map_or("", |(_, name)| *name);
+            let decl = f.rows[0].1;
+
+            info.append_u8(ABBREV_SUBPROGRAM);
+            info.write_unencoded_u32(range.start);
+            info.write_unencoded_u32(range.end - range.start);
+            append_c_str(&mut info, name);
+            info.encode_u32(decl.file + 1);
+            info.encode_u32(decl.line);
+        }
+        info.append_u8(0); // end of the compile unit's children
+
+        overwrite_unit_length(&mut info);
+
+        // .debug_line
+        let mut line: std::vec::Vec<u8> = std::vec::Vec::with_capacity(self.size());
+        line.write_unencoded_u32(0); // unit_length, filled in below
+        append_u16(&mut line, DWARF_VERSION);
+        let header_length_index = line.len();
+        line.write_unencoded_u32(0); // header_length, filled in below
+        let header_start = line.len();
+
+        line.append_u8(1); // minimum_instruction_length
+        line.append_u8(1); // maximum_operations_per_instruction
+        line.append_u8(1); // default_is_stmt
+        line.append_u8(LINE_BASE as u8);
+        line.append_u8(LINE_RANGE);
+        line.append_u8(OPCODE_BASE);
+        line.append_slice(&STANDARD_OPCODE_LENGTHS);
+        line.append_u8(0); // no include_directories, all paths are relative to the compile unit
+        for path in self.files.iter() {
+            append_c_str(&mut line, path);
+            line.encode_u32(0); // directory index
+            line.encode_u32(0); // modification time
+            line.encode_u32(0); // file length
+        }
+        line.append_u8(0);
+
+        let header_length = (line.len() - header_start) as u32;
+        line[header_length_index..header_start].copy_from_slice(&header_length.to_le_bytes());
+
+        for (range, f) in functions.iter() {
+            write_line_sequence(&mut line, range.clone(), &f.rows);
+        }
+
+        overwrite_unit_length(&mut line);
+
+        write_custom_section(buffer, ".debug_abbrev", &DEBUG_ABBREV);
+        write_custom_section(buffer, ".debug_info", &info);
+        write_custom_section(buffer, ".debug_line", &line);
+    }
+}
+
+/// Write the line table rows for one function, as a sequence of standard opcodes.
+/// Each sequence starts from the initial state of the line number state machine.
+fn write_line_sequence(
+    line: &mut std::vec::Vec<u8>,
+    body: std::ops::Range<u32>,
+    rows: &[(u32, SourceLocation)],
+) {
+    let mut file = 1;
+    let mut line_no = 1;
+    let mut column = 0;
+    let mut address = body.start;
+
+    line.append_slice(&[0, 1 + ADDRESS_SIZE, DW_LNE_SET_ADDRESS]);
+    line.write_unencoded_u32(address);
+
+    for (offset, location) in rows.iter() {
+        let row_address = body.start + offset;
+        debug_assert!(row_address >= address && row_address < body.end);
+
+        if location.file + 1 != file {
+            file = location.file + 1;
+            line.append_u8(DW_LNS_SET_FILE);
+            line.encode_u32(file);
+        }
+        if location.column != column {
+            column = location.column;
+            line.append_u8(DW_LNS_SET_COLUMN);
+            line.encode_u32(column);
+        }
+        if location.line != line_no {
+            line.append_u8(DW_LNS_ADVANCE_LINE);
+            line.encode_i64(location.line as i64 - line_no as i64);
+            line_no = location.line;
+        }
+        if row_address != address {
+            line.append_u8(DW_LNS_ADVANCE_PC);
+            line.encode_u32(row_address - address);
+            address = row_address;
+        }
+        line.append_u8(DW_LNS_COPY);
+    }
+
+    // The sequence ends at the first address after the function
+    line.append_u8(DW_LNS_ADVANCE_PC);
+    line.encode_u32(body.end - address);
+    line.append_slice(&[0, 1, DW_LNE_END_SEQUENCE]);
+}
+
+fn write_custom_section<T: SerialBuffer>(buffer: &mut T, name: &str, bytes: &[u8]) {
+    let header_indices = write_custom_section_header(buffer, name);
+    buffer.append_slice(bytes);
+    update_section_size(buffer, header_indices);
+}
+
+fn append_u16(buffer: &mut std::vec::Vec<u8>, value: u16) {
+    buffer.append_slice(&value.to_le_bytes());
+}
+
+/// DWARF strings are null-terminated, unlike Wasm strings
+fn append_c_str(buffer: &mut std::vec::Vec<u8>, s: &str) {
+    buffer.append_slice(s.as_bytes());
+    buffer.append_u8(0);
+}
+
+/// A DWARF unit starts with its length, not including the length itself
+fn overwrite_unit_length(unit: &mut [u8]) {
+    let unit_length = (unit.len() - 4) as u32;
+    unit[0..4].copy_from_slice(&unit_length.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Parse;
+    use crate::serialize::Serialize;
+    use gimli::{AttributeValue, ColumnType, EndianSlice, LittleEndian};
+
+    fn custom_section<'b>(module_bytes: &'b [u8], name: &str) -> &'b [u8] {
+        let mut cursor = 0;
+        while cursor < module_bytes.len() {
+            let id = module_bytes[cursor];
+            cursor += 1;
+            let size = u32::parse((), module_bytes, &mut cursor).unwrap() as usize;
+            let end = cursor + size;
+            if id == 0 {
+                let name_len = u32::parse((), module_bytes, &mut cursor).unwrap() as usize;
+                let section_name = &module_bytes[cursor..cursor + name_len];
+                if section_name == name.as_bytes() {
+                    return &module_bytes[cursor + name_len..end];
+                }
+            }
+            cursor = end;
+        }
+        &[]
+    }
+
+    #[test]
+    fn test_lines_and_subprograms() {
+        let arena = &Bump::new();
+
+        // Two imports, a dummy, and two functions with 2- and 5-byte bodies
+        let mut code = CodeSection::new(arena);
+        code.dead_import_dummy_count = 1;
+        for body in [&[0x00, 0x0b][..], &[0x00, 0x41, 0x2a, 0x1a, 0x0b][..]] {
+            code.function_offsets.push(code.bytes.len() as u32);
+            code.function_count += 1;
+            (body.len() as u32).serialize(&mut code.bytes);
+            code.bytes.extend_from_slice(body);
+        }
+
+        let mut names = NameSection::new(arena);
+        names.append_function(3, "first");
+        names.append_function(4, "second");
+
+        let at = |file, line, column| SourceLocation { file, line, column };
+        let mut dwarf = DwarfSections::new(arena);
+        dwarf.files.extend(["src/Main.roc", "src/Other.roc"]);
+        dwarf.functions.push(FunctionLines {
+            fn_index: 4,
+            rows: bumpalo::vec![in arena; (0, at(1, 10, 1)), (1, at(1, 12, 5)), (3, at(1, 9, 5))],
+        });
+        dwarf.functions.push(FunctionLines {
+            fn_index: 3,
+            rows: bumpalo::vec![in arena; (0, at(0, 3, 1))],
+        });
+
+        let mut module_bytes = std::vec::Vec::new();
+        dwarf.serialize(&mut module_bytes, &code, 2, &names);
+
+        let load = |id: gimli::SectionId| {
+            let section = custom_section(&module_bytes, id.name());
+            Ok::<_, gimli::Error>(EndianSlice::new(section, LittleEndian))
+        };
+        let dwarf = gimli::Dwarf::load(load).unwrap();
+
+        let mut units = dwarf.units();
+        let unit = dwarf.unit(units.next().unwrap().unwrap()).unwrap();
+        assert!(units.next().unwrap().is_none());
+
+        // The Code section contents are the function count, then the dummy (1 + 3 bytes),
+        // then each function with its size. Bodies start after the size.
+        let mut subprograms = vec![];
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() != gimli::DW_TAG_subprogram {
+                continue;
+            }
+            let name = dwarf
+                .attr_string(&unit, entry.attr_value(gimli::DW_AT_name).unwrap().unwrap())
+                .unwrap()
+                .to_string_lossy()
+                .into_owned();
+            let Some(AttributeValue::Addr(low_pc)) = entry.attr_value(gimli::DW_AT_low_pc).unwrap()
+            else {
+                panic!("no low_pc for {name}");
+            };
+            let decl_line = entry
+                .attr_value(gimli::DW_AT_decl_line)
+                .unwrap()
+                .and_then(|value| value.udata_value())
+                .unwrap();
+            subprograms.push((name, low_pc, decl_line));
+        }
+        assert_eq!(
+            subprograms,
+            [
+                (String::from("first"), 6, 3),
+                (String::from("second"), 9, 10)
+            ]
+        );
+
+        let mut lines = vec![];
+        let program = unit.line_program.clone().unwrap();
+        let mut rows = program.rows();
+        while let Some((header, row)) = rows.next_row().unwrap() {
+            let file = row.file(header).unwrap().path_name();
+            let file = dwarf.attr_string(&unit, file).unwrap().to_string_lossy();
+            let column = match row.column() {
+                ColumnType::LeftEdge => 0,
+                ColumnType::Column(column) => column.get(),
+            };
+            let line = row.line().map_or(0, |line| line.get());
+            lines.push((
+                row.address(),
+                file.into_owned(),
+                line,
+                column,
+                row.end_sequence(),
+            ));
+        }
+        assert_eq!(
+            lines,
+            [
+                (6, "src/Main.roc".into(), 3, 1, false),
+                (8, "src/Main.roc".into(), 3, 1, true),
+                (9, "src/Other.roc".into(), 10, 1, false),
+                (10, "src/Other.roc".into(), 12, 5, false),
+                (12, "src/Other.roc".into(), 9, 5, false),
+                (14, "src/Other.roc".into(), 9, 5, true),
+            ]
+        );
+    }
+}
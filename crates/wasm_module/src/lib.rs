@@ -1,8 +1,11 @@
+pub mod dwarf;
 pub mod linking;
 pub mod opcodes;
 pub mod parse;
 pub mod sections;
 pub mod serialize;
+pub mod validate;
+pub mod wat;
 
 use std::iter::repeat;
 
@@ -14,6 +17,7 @@ pub use sections::{ConstExpr, Export, ExportType, Global, GlobalType, Signature}
 use bitvec::vec::BitVec;
 use bumpalo::{collections::Vec, Bump};
 
+use self::dwarf::DwarfSections;
 use self::linking::{IndexRelocType, LinkingSection, RelocationSection, WasmObjectSymbol};
 use self::parse::{Parse, ParseError};
 use self::sections::{
@@ -22,6 +26,7 @@ use self::sections::{
     TableSection, TypeSection,
 };
 pub use self::serialize::{SerialBuffer, Serialize};
+pub use self::validate::ValidationError;
 
 pub const STACK_POINTER_GLOBAL_ID: u32 = 0;
 pub const FRAME_ALIGNMENT_BYTES: i32 = 16;
@@ -45,6 +50,7 @@ pub struct WasmModule<'a> {
     pub reloc_code: RelocationSection<'a>,
     pub reloc_data: RelocationSection<'a>,
     pub names: NameSection<'a>,
+    pub dwarf: DwarfSections<'a>,
 }
 
 impl<'a> WasmModule<'a> {
@@ -67,6 +73,7 @@ impl<'a> WasmModule<'a> {
             reloc_code: RelocationSection::new(arena, "reloc.CODE"),
             reloc_data: RelocationSection::new(arena, "reloc.DATA"),
             names: NameSection::new(arena),
+            dwarf: DwarfSections::new(arena),
         }
     }
 
@@ -96,6 +103,12 @@ impl<'a> WasmModule<'a> {
         self.code.serialize(buffer);
         self.data.serialize(buffer);
         self.names.serialize(buffer);
+        self.dwarf.serialize(
+            buffer,
+            &self.code,
+            self.import.function_count() as u32,
+            &self.names,
+        );
     }
 
     /// Module size in bytes (assuming no linker data)
@@ -113,6 +126,18 @@ impl<'a> WasmModule<'a> {
             + self.code.size()
             + self.data.size()
             + self.names.size()
+            + self.dwarf.size()
+    }
+
+    /// Check the module against the validation rules in the Wasm spec, for the sections we model.
+    /// Catches code gen bugs before they reach a browser or interpreter.
+    pub fn validate(&self, arena: &Bump) -> Result<(), ValidationError> {
+        validate::validate_module(arena, self)
+    }
+
+    /// Print the module in WebAssembly text format, using the Name section for function names
+    pub fn to_wat(&self, arena: &Bump) -> String {
+        wat::module_to_wat(arena, self)
     }
 
     pub fn preload(
@@ -230,6 +255,7 @@ impl<'a> WasmModule<'a> {
             reloc_code,
             reloc_data,
             names,
+            dwarf: DwarfSections::new(arena),
         })
     }
 
@@ -390,6 +416,11 @@ impl<'a> WasmModule<'a> {
         }
 
         self.code.bytes = buffer;
+
+        // Dead functions have no code left to map to source locations
+        self.dwarf
+            .functions
+            .retain(|f| live_flags[f.fn_index as usize]);
     }
 
     fn trace_live_functions<I: Iterator<Item = u32>>(
@@ -692,6 +723,7 @@ pub enum ValueType {
     I64 = 0x7e,
     F32 = 0x7d,
     F64 = 0x7c,
+    V128 = 0x7b,
 }
 
 impl ValueType {
@@ -711,6 +743,7 @@ impl From<u8> for ValueType {
             0x7e => Self::I64,
             0x7d => Self::F32,
             0x7c => Self::F64,
+            0x7b => Self::V128,
             _ => internal_error!("Invalid ValueType 0x{:02x}", x),
         }
     }
@@ -723,6 +756,7 @@ impl From<Value> for ValueType {
             Value::I64(_) => Self::I64,
             Value::F32(_) => Self::F32,
             Value::F64(_) => Self::F64,
+            Value::V128(_) => Self::V128,
         }
     }
 }
@@ -749,6 +783,8 @@ pub enum Value {
     I64(i64),
     F32(f32),
     F64(f64),
+    /// 128-bit vector, with lane 0 in the least significant bits
+    V128(u128),
 }
 
 impl Value {
@@ -776,6 +812,12 @@ impl Value {
             _ => Err((ValueType::F64, ValueType::from(*self))),
         }
     }
+    pub fn expect_v128(&self) -> Result<u128, (ValueType, ValueType)> {
+        match self {
+            Value::V128(x) => Ok(*x),
+            _ => Err((ValueType::V128, ValueType::from(*self))),
+        }
+    }
 }
 
 impl From<u32> for Value {
@@ -1,3 +1,6 @@
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+
 use crate::Serialize;
 
 use super::parse::{Parse, ParseError, SkipBytes};
@@ -52,6 +55,7 @@ pub enum OpCode {
     CURRENTMEMORY = 0x3f,
     GROWMEMORY = 0x40,
     MEMORY = 0xFC,
+    SIMD = 0xFD,
     I32CONST = 0x41,
     I64CONST = 0x42,
     F32CONST = 0x43,
@@ -244,6 +248,7 @@ pub const LOOKUP_TABLE: [Option<OpCode>; 256] = {
     result[0x3f] = Some(CURRENTMEMORY);
     result[0x40] = Some(GROWMEMORY);
     result[0xfc] = Some(MEMORY);
+    result[0xfd] = Some(SIMD);
     result[0x41] = Some(I32CONST);
     result[0x42] = Some(I64CONST);
     result[0x43] = Some(F32CONST);
@@ -421,6 +426,49 @@ impl TryFrom<u8> for MemoryInstruction {
     }
 }
 
+/// The instructions from the SIMD proposal that we support, all of them with the `SIMD` prefix.
+/// The sub-opcode after the prefix is a LEB-128 u32, but these all fit in a single byte.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimdInstruction {
+    V128Load = 0,
+    V128Store = 11,
+    V128Const = 12,
+    I8x16Splat = 15,
+    I8x16Eq = 35,
+    I8x16Ne = 36,
+    V128Not = 77,
+    V128And = 78,
+    V128Or = 80,
+    V128Xor = 81,
+    V128AnyTrue = 83,
+    I8x16AllTrue = 99,
+    I8x16Bitmask = 100,
+}
+
+impl TryFrom<u32> for SimdInstruction {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::V128Load),
+            11 => Ok(Self::V128Store),
+            12 => Ok(Self::V128Const),
+            15 => Ok(Self::I8x16Splat),
+            35 => Ok(Self::I8x16Eq),
+            36 => Ok(Self::I8x16Ne),
+            77 => Ok(Self::V128Not),
+            78 => Ok(Self::V128And),
+            80 => Ok(Self::V128Or),
+            81 => Ok(Self::V128Xor),
+            83 => Ok(Self::V128AnyTrue),
+            99 => Ok(Self::I8x16AllTrue),
+            100 => Ok(Self::I8x16Bitmask),
+            _ => Err(value),
+        }
+    }
+}
+
 /// The format of the *immediate* operands of an operator
 /// Immediates appear directly in the byte stream after the opcode,
 /// rather than being popped off the value stack. These are the possible forms.
@@ -435,6 +483,7 @@ enum OpImmediates {
     Leb32x2,
     BrTable,
     Memory,
+    Simd,
 }
 
 fn immediates_for(op: OpCode) -> Result<OpImmediates, String> {
@@ -464,6 +513,7 @@ fn immediates_for(op: OpCode) -> Result<OpImmediates, String> {
 
         CURRENTMEMORY | GROWMEMORY => Byte1,
         MEMORY => Memory,
+        SIMD => Simd,
 
         I32CONST => Leb32x1,
         I64CONST => Leb64x1,
@@ -566,11 +616,216 @@ impl SkipBytes for OpCode {
                     Err(other) => unreachable!("invalid memory instruction {other:?}"),
                 }
             }
+            Simd => {
+                *cursor += 1;
+                let sub_op_offset = *cursor;
+                let sub_op = u32::parse((), bytes, cursor)?;
+                match SimdInstruction::try_from(sub_op) {
+                    Ok(SimdInstruction::V128Load | SimdInstruction::V128Store) => {
+                        u32::skip_bytes(bytes, cursor)?;
+                        u32::skip_bytes(bytes, cursor)?;
+                    }
+                    Ok(SimdInstruction::V128Const) => {
+                        *cursor += 16;
+                    }
+                    Ok(_) => {}
+                    Err(other) => {
+                        return Err(ParseError {
+                            offset: sub_op_offset,
+                            message: format!("Unsupported Wasm instruction 0xfd {other}"),
+                        })
+                    }
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// The immediate operands of an instruction, decoded from the byte stream
+#[derive(Debug, PartialEq)]
+pub enum Immediates<'a> {
+    None,
+    /// Result type of `block`, `loop` or `if`. Either `ValueType::VOID` or a `ValueType` byte.
+    BlockType(u8),
+    /// A label depth, or a function, local or global index, depending on the opcode
+    Index(u32),
+    BrTable {
+        targets: Vec<'a, u32>,
+        default: u32,
+    },
+    CallIndirect {
+        type_index: u32,
+        table_index: u32,
+    },
+    /// Alignment (as a power of 2) and offset of a load or store
+    MemArg {
+        align: u32,
+        offset: u32,
+    },
+    /// Memory index of `memory.size` or `memory.grow`. Always zero in Wasm 1.0.
+    MemoryIndex(u8),
+    Memory(MemoryInstruction),
+    /// A SIMD instruction that takes all of its operands from the value stack
+    Simd(SimdInstruction),
+    /// `v128.load` or `v128.store`, with alignment and offset like [Immediates::MemArg]
+    SimdMemArg {
+        op: SimdInstruction,
+        align: u32,
+        offset: u32,
+    },
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    /// The constant of `v128.const`
+    V128(u128),
+}
+
+/// A single instruction from a function body, for tools that need more than `skip_bytes`
+#[derive(Debug, PartialEq)]
+pub struct Instruction<'a> {
+    pub op: OpCode,
+    pub immediates: Immediates<'a>,
+}
+
+fn parse_byte(bytes: &[u8], cursor: &mut usize) -> Result<u8, ParseError> {
+    match bytes.get(*cursor) {
+        Some(byte) => {
+            *cursor += 1;
+            Ok(*byte)
+        }
+        None => Err(ParseError {
+            offset: *cursor,
+            message: "Unexpected end of function body".into(),
+        }),
+    }
+}
+
+fn parse_array<const N: usize>(bytes: &[u8], cursor: &mut usize) -> Result<[u8; N], ParseError> {
+    let mut array = [0; N];
+    for byte in array.iter_mut() {
+        *byte = parse_byte(bytes, cursor)?;
+    }
+    Ok(array)
+}
+
+impl<'a> Parse<&'a Bump> for Instruction<'a> {
+    fn parse(arena: &'a Bump, bytes: &[u8], cursor: &mut usize) -> Result<Self, ParseError> {
+        use OpImmediates::*;
+
+        let op_offset = *cursor;
+        let opcode_byte = parse_byte(bytes, cursor)?;
+        let op = LOOKUP_TABLE[opcode_byte as usize].ok_or_else(|| ParseError {
+            offset: op_offset,
+            message: format!("Unsupported Wasm instruction 0x{opcode_byte:02x}"),
+        })?;
+
+        let immediates = match immediates_for(op).map_err(|message| ParseError {
+            message,
+            offset: op_offset,
+        })? {
+            NoImmediate => Immediates::None,
+            Byte1 => {
+                let byte = parse_byte(bytes, cursor)?;
+                if matches!(op, OpCode::CURRENTMEMORY | OpCode::GROWMEMORY) {
+                    Immediates::MemoryIndex(byte)
+                } else {
+                    Immediates::BlockType(byte)
+                }
+            }
+            Bytes4 => Immediates::F32(f32::from_le_bytes(parse_array(bytes, cursor)?)),
+            Bytes8 => Immediates::F64(f64::from_le_bytes(parse_array(bytes, cursor)?)),
+            Leb32x1 => {
+                if op == OpCode::I32CONST {
+                    Immediates::I32(i32::parse((), bytes, cursor)?)
+                } else {
+                    Immediates::Index(u32::parse((), bytes, cursor)?)
+                }
+            }
+            Leb64x1 => Immediates::I64(i64::parse((), bytes, cursor)?),
+            Leb32x2 => {
+                let first = u32::parse((), bytes, cursor)?;
+                let second = u32::parse((), bytes, cursor)?;
+                if op == OpCode::CALLINDIRECT {
+                    Immediates::CallIndirect {
+                        type_index: first,
+                        table_index: second,
+                    }
+                } else {
+                    Immediates::MemArg {
+                        align: first,
+                        offset: second,
+                    }
+                }
+            }
+            BrTable => {
+                let n_targets = u32::parse((), bytes, cursor)?;
+                let mut targets = Vec::with_capacity_in(n_targets as usize, arena);
+                for _ in 0..n_targets {
+                    targets.push(u32::parse((), bytes, cursor)?);
+                }
+                let default = u32::parse((), bytes, cursor)?;
+                Immediates::BrTable { targets, default }
+            }
+            Memory => {
+                let sub_op_offset = *cursor;
+                let sub_op = u32::parse((), bytes, cursor)?;
+                let unsupported = || ParseError {
+                    offset: sub_op_offset,
+                    message: format!("Unsupported Wasm instruction 0xfc {sub_op}"),
+                };
+                let mem_op = u8::try_from(sub_op)
+                    .ok()
+                    .and_then(|x| MemoryInstruction::try_from(x).ok())
+                    .ok_or_else(unsupported)?;
+                let memory_indices = match mem_op {
+                    MemoryInstruction::MemoryCopy => 2,
+                    MemoryInstruction::MemoryFill => 1,
+                    MemoryInstruction::MemoryInit | MemoryInstruction::DataDrop => {
+                        return Err(unsupported());
+                    }
+                };
+                for _ in 0..memory_indices {
+                    let index_offset = *cursor;
+                    if parse_byte(bytes, cursor)? != 0 {
+                        return Err(ParseError {
+                            offset: index_offset,
+                            message: "Only memory index 0 is supported".into(),
+                        });
+                    }
+                }
+                Immediates::Memory(mem_op)
+            }
+            Simd => {
+                let sub_op_offset = *cursor;
+                let sub_op = u32::parse((), bytes, cursor)?;
+                let simd_op = SimdInstruction::try_from(sub_op).map_err(|_| ParseError {
+                    offset: sub_op_offset,
+                    message: format!("Unsupported Wasm instruction 0xfd {sub_op}"),
+                })?;
+                match simd_op {
+                    SimdInstruction::V128Load | SimdInstruction::V128Store => {
+                        let align = u32::parse((), bytes, cursor)?;
+                        let offset = u32::parse((), bytes, cursor)?;
+                        Immediates::SimdMemArg {
+                            op: simd_op,
+                            align,
+                            offset,
+                        }
+                    }
+                    SimdInstruction::V128Const => {
+                        Immediates::V128(u128::from_le_bytes(parse_array(bytes, cursor)?))
+                    }
+                    _ => Immediates::Simd(simd_op),
+                }
+            }
+        };
+
+        Ok(Instruction { op, immediates })
+    }
+}
+
 impl Serialize for OpCode {
     fn serialize<T: crate::SerialBuffer>(&self, buffer: &mut T) {
         (*self as u8).serialize(buffer)
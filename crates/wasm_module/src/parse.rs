@@ -40,7 +40,7 @@ impl Parse<()> for u32 {
                 offset: *cursor,
                 message: format!(
                     "Failed to decode u32 as LEB-128 from bytes: {:2x?}",
-                    &bytes[*cursor..][..MAX_SIZE_ENCODED_U32]
+                    &bytes[*cursor..(*cursor + MAX_SIZE_ENCODED_U32).min(bytes.len())]
                 ),
             }),
         }
@@ -85,7 +85,7 @@ impl Parse<()> for i32 {
                 offset: *cursor,
                 message: format!(
                     "Failed to decode i32 as LEB-128 from bytes: {:2x?}",
-                    &bytes[*cursor..][..MAX_SIZE_ENCODED_U32]
+                    &bytes[*cursor..(*cursor + MAX_SIZE_ENCODED_U32).min(bytes.len())]
                 ),
             }),
         }
@@ -122,7 +122,7 @@ impl Parse<()> for i64 {
                 offset: *cursor,
                 message: format!(
                     "Failed to decode i64 as LEB-128 from bytes: {:2x?}",
-                    &bytes[*cursor..][..MAX_SIZE_ENCODED_U64]
+                    &bytes[*cursor..(*cursor + MAX_SIZE_ENCODED_U64).min(bytes.len())]
                 ),
             }),
         }
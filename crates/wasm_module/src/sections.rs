@@ -197,7 +197,8 @@ pub fn update_section_size<T: SerialBuffer>(buffer: &mut T, header_indices: Sect
 #[derive(PartialEq, Eq, Debug)]
 pub struct Signature<'a> {
     pub param_types: Vec<'a, ValueType>,
-    pub ret_type: Option<ValueType>,
+    /// Empty for no return value. More than one needs the multi-value feature.
+    pub ret_types: Vec<'a, ValueType>,
 }
 
 impl Signature<'_> {
@@ -208,7 +209,7 @@ impl<'a> Serialize for Signature<'a> {
     fn serialize<T: SerialBuffer>(&self, buffer: &mut T) {
         buffer.append_u8(Self::SEPARATOR);
         self.param_types.serialize(buffer);
-        self.ret_type.serialize(buffer);
+        self.ret_types.serialize(buffer);
     }
 }
 
@@ -271,7 +272,8 @@ impl<'a> TypeSection<'a> {
 
     /// Find a matching signature or insert a new one. Return the index.
     pub fn insert(&mut self, signature: Signature<'a>) -> u32 {
-        let mut sig_bytes = Vec::with_capacity_in(signature.param_types.len() + 4, self.arena);
+        let capacity = signature.param_types.len() + signature.ret_types.len() + 4;
+        let mut sig_bytes = Vec::with_capacity_in(capacity, self.arena);
         signature.serialize(&mut sig_bytes);
 
         let sig_len = sig_bytes.len();
@@ -298,7 +300,13 @@ impl<'a> TypeSection<'a> {
         self.bytes.is_empty()
     }
 
-    pub fn look_up(&'a self, sig_index: u32) -> (SignatureParamsIter<'a>, Option<ValueType>) {
+    /// Number of signatures in the section
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Parameter types and return types of a signature
+    pub fn look_up(&'a self, sig_index: u32) -> (SignatureParamsIter<'a>, SignatureParamsIter<'a>) {
         let mut offset = self.offsets[sig_index as usize];
         offset += 1; // separator
         let param_count = u32::parse((), &self.bytes, &mut offset).unwrap() as usize;
@@ -309,12 +317,13 @@ impl<'a> TypeSection<'a> {
         };
         offset += param_count;
 
-        let return_type = if self.bytes[offset] == 0 {
-            None
-        } else {
-            Some(ValueType::from(self.bytes[offset + 1]))
+        let return_count = u32::parse((), &self.bytes, &mut offset).unwrap() as usize;
+        let returns_iter = SignatureParamsIter {
+            bytes: &self.bytes[offset..][..return_count],
+            index: 0,
+            end: return_count,
         };
-        (params_iter, return_type)
+        (params_iter, returns_iter)
     }
 }
 
@@ -349,8 +358,8 @@ impl<'a> Parse<&'a Bump> for TypeSection<'a> {
             let n_params = u32::parse((), &bytes, &mut i).unwrap();
             i += n_params as usize; // skip over one byte per param type
 
-            let n_return_values = bytes[i];
-            i += 1 + n_return_values as usize;
+            let n_return_values = u32::parse((), &bytes, &mut i).unwrap();
+            i += n_return_values as usize;
         }
 
         Ok(TypeSection {
@@ -964,11 +973,13 @@ impl Parse<()> for ConstExpr {
             OpCode::F32CONST => {
                 let mut b = [0; 4];
                 b.copy_from_slice(&bytes[*cursor..][..4]);
+                *cursor += 4;
                 Ok(ConstExpr::F32(f32::from_le_bytes(b)))
             }
             OpCode::F64CONST => {
                 let mut b = [0; 8];
                 b.copy_from_slice(&bytes[*cursor..][..8]);
+                *cursor += 8;
                 Ok(ConstExpr::F64(f64::from_le_bytes(b)))
             }
             _ => Err(ParseError {
@@ -1447,6 +1458,36 @@ impl<'a> CodeSection<'a> {
             dead_import_dummy_count: 0,
         })
     }
+
+    /// Where each function body will be when the section is serialized, dummies included.
+    /// Offsets are relative to the start of the section contents (just after the section size),
+    /// and a body starts after its own size, at the local variable declarations.
+    /// Walks the bodies in order, since `function_offsets` is out of date after dead code elimination.
+    pub fn serialized_body_ranges(&self) -> impl Iterator<Item = std::ops::Range<u32>> + '_ {
+        let mut count_bytes: std::vec::Vec<u8> = std::vec::Vec::with_capacity(MAX_SIZE_ENCODED_U32);
+        let count_size = count_bytes.encode_u32(self.dead_import_dummy_count + self.function_count);
+
+        // A dummy is serialized as a length byte followed by the body
+        let dummy_body_size = DUMMY_FUNCTION.len() as u32;
+        let dummy_entry_size = 1 + dummy_body_size;
+        let dummies = (0..self.dead_import_dummy_count).map(move |i| {
+            let start = count_size as u32 + i * dummy_entry_size + 1;
+            start..start + dummy_body_size
+        });
+
+        let first_fn_start = self.function_offsets.first().copied().unwrap_or(0) as usize;
+        let real_fns_start =
+            count_size + (self.dead_import_dummy_count * dummy_entry_size) as usize;
+        let mut cursor = first_fn_start;
+        let real_fns = (0..self.function_count).map(move |_| {
+            let body_size = u32::parse((), &self.bytes, &mut cursor).unwrap() as usize;
+            let start = real_fns_start + cursor - first_fn_start;
+            cursor += body_size;
+            start as u32..(start + body_size) as u32
+        });
+
+        dummies.chain(real_fns)
+    }
 }
 
 impl<'a> Serialize for CodeSection<'a> {
@@ -1593,6 +1634,23 @@ impl<'a> DataSection<'a> {
         }
         Ok(())
     }
+
+    /// Decode the mode and initial contents of each segment
+    pub fn segments<'b>(
+        &'b self,
+        arena: &'b Bump,
+    ) -> Result<Vec<'b, (DataMode, &'b [u8])>, ParseError> {
+        let mut segments = Vec::with_capacity_in(self.count as usize, arena);
+        let mut cursor = 0;
+        for _ in 0..self.count {
+            let mode = DataMode::parse((), &self.bytes, &mut cursor)?;
+            let len = u32::parse((), &self.bytes, &mut cursor)? as usize;
+            let init = &self.bytes[cursor..][..len];
+            cursor += len;
+            segments.push((mode, init));
+        }
+        Ok(segments)
+    }
 }
 
 impl<'a> Parse<&'a Bump> for DataSection<'a> {
@@ -1897,15 +1955,19 @@ mod tests {
         let signatures = [
             Signature {
                 param_types: bumpalo::vec![in arena],
-                ret_type: None,
+                ret_types: bumpalo::vec![in arena],
             },
             Signature {
                 param_types: bumpalo::vec![in arena; I32, I64, F32, F64],
-                ret_type: None,
+                ret_types: bumpalo::vec![in arena],
             },
             Signature {
                 param_types: bumpalo::vec![in arena; I32, I32, I32],
-                ret_type: Some(I32),
+                ret_types: bumpalo::vec![in arena; I32],
+            },
+            Signature {
+                param_types: bumpalo::vec![in arena; I32],
+                ret_types: bumpalo::vec![in arena; I64, I32, V128],
             },
         ];
         let capacity = signatures.len();
@@ -1919,5 +1981,9 @@ mod tests {
             section.insert(sig);
         }
         test_assert_types_preload(arena, &section);
+
+        let (params, results) = section.look_up(3);
+        assert_eq!(params.collect::<std::vec::Vec<_>>(), [I32]);
+        assert_eq!(results.collect::<std::vec::Vec<_>>(), [I64, I32, V128]);
     }
 }
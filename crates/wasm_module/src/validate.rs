@@ -0,0 +1,1205 @@
+//! Validation of Wasm modules, to catch code gen bugs before a browser or interpreter does.
+//!
+//! Function bodies are type-checked using the algorithm from the appendix of the spec
+//! https://webassembly.github.io/spec/core/appendix/algorithm.html
+//!
+//! We only validate the sections that WasmModule models. Opaque sections are passed through
+//! unchanged from the host, so whatever produced them is responsible for their validity.
+
+use std::fmt::{Display, Formatter};
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+
+use crate::opcodes::{Immediates, Instruction, MemoryInstruction, OpCode, SimdInstruction};
+use crate::parse::{Parse, ParseError};
+use crate::sections::{ConstExpr, DataMode, GlobalType, ImportDesc, Limits, SectionId};
+use crate::{ExportType, ValueType, WasmModule};
+
+/// Wasm memory is limited to 4GiB, or 2^16 pages of 64kiB
+const MAX_MEMORY_PAGES: u32 = 1 << 16;
+
+#[derive(Debug)]
+pub struct ValidationError {
+    pub section: SectionId,
+    /// For errors in the Code section, the index of the function containing the error
+    pub fn_index: Option<u32>,
+    /// Byte offset of the error in the section's `bytes`, if it has any
+    pub offset: usize,
+    pub message: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid Wasm in {:?} section", self.section)?;
+        if let Some(fn_index) = self.fn_index {
+            write!(f, ", function {fn_index}")?;
+        }
+        write!(f, " at offset 0x{:x}: {}", self.offset, self.message)
+    }
+}
+
+fn section_error(section: SectionId, message: String) -> ValidationError {
+    ValidationError {
+        section,
+        fn_index: None,
+        offset: 0,
+        message,
+    }
+}
+
+fn parse_error(section: SectionId, e: ParseError) -> ValidationError {
+    ValidationError {
+        section,
+        fn_index: None,
+        offset: e.offset,
+        message: e.message,
+    }
+}
+
+/// Everything we need to know about the module in order to validate function bodies
+struct ModuleContext<'a> {
+    signatures: Vec<'a, (Vec<'a, ValueType>, Vec<'a, ValueType>)>,
+    /// Signature index of every function, including imports and dead import dummies
+    fn_signatures: Vec<'a, u32>,
+    globals: Vec<'a, GlobalType>,
+    has_memory: bool,
+    has_table: bool,
+}
+
+impl<'a> ModuleContext<'a> {
+    fn signature(&self, sig_index: u32) -> Result<(&[ValueType], &[ValueType]), String> {
+        match self.signatures.get(sig_index as usize) {
+            Some((params, results)) => Ok((&params[..], &results[..])),
+            None => Err(format!("unknown type {sig_index}")),
+        }
+    }
+
+    fn fn_signature(&self, fn_index: u32) -> Result<(&[ValueType], &[ValueType]), String> {
+        match self.fn_signatures.get(fn_index as usize) {
+            Some(sig_index) => self.signature(*sig_index),
+            None => Err(format!("unknown function {fn_index}")),
+        }
+    }
+}
+
+pub fn validate_module(arena: &Bump, module: &WasmModule<'_>) -> Result<(), ValidationError> {
+    //
+    // Type section
+    //
+    let mut signatures = Vec::with_capacity_in(module.types.len(), arena);
+    for sig_index in 0..module.types.len() {
+        let (params, results) = module.types.look_up(sig_index as u32);
+        signatures.push((
+            Vec::from_iter_in(params, arena),
+            Vec::from_iter_in(results, arena),
+        ));
+    }
+
+    //
+    // Import section
+    //
+    let mut fn_signatures = Vec::with_capacity_in(
+        module.import.function_count() + module.function.signatures.len(),
+        arena,
+    );
+    let mut globals = Vec::new_in(arena);
+    let mut memory_count = module.memory.count;
+    let mut has_table = !module.element.is_empty();
+    for import in module.import.imports.iter() {
+        match &import.description {
+            ImportDesc::Func { signature_index } => fn_signatures.push(*signature_index),
+            ImportDesc::Table { .. } => has_table = true,
+            ImportDesc::Mem { limits } => {
+                memory_count += 1;
+                validate_limits(limits).map_err(|m| section_error(SectionId::Import, m))?;
+            }
+            ImportDesc::Global { ty } => globals.push(*ty),
+        }
+    }
+
+    //
+    // Function & Code sections
+    //
+    let import_fn_count = fn_signatures.len() as u32;
+    fn_signatures.extend_from_slice(&module.function.signatures);
+    let code_fn_count = module.code.dead_import_dummy_count + module.code.function_count;
+    if module.function.signatures.len() != code_fn_count as usize {
+        return Err(section_error(
+            SectionId::Function,
+            format!(
+                "Function section declares {} functions but Code section has {}",
+                module.function.signatures.len(),
+                code_fn_count
+            ),
+        ));
+    }
+    for sig_index in fn_signatures.iter() {
+        if *sig_index as usize >= signatures.len() {
+            return Err(section_error(
+                SectionId::Function,
+                format!("unknown type {sig_index}"),
+            ));
+        }
+    }
+    let fn_count = fn_signatures.len() as u32;
+
+    //
+    // Memory section
+    //
+    if memory_count > 1 {
+        return Err(section_error(
+            SectionId::Memory,
+            format!("Found {memory_count} memories but only one is allowed"),
+        ));
+    }
+    let mut cursor = 0;
+    for _ in 0..module.memory.count {
+        let limits = Limits::parse((), &module.memory.bytes, &mut cursor)
+            .map_err(|e| parse_error(SectionId::Memory, e))?;
+        validate_limits(&limits).map_err(|m| section_error(SectionId::Memory, m))?;
+    }
+    let has_memory = memory_count > 0;
+
+    //
+    // Global section
+    // Initialisers can only be constants, since we don't support imported globals in them
+    //
+    let mut cursor = 0;
+    for _ in 0..module.global.count {
+        let global_offset = cursor;
+        let ty = GlobalType::parse((), &module.global.bytes, &mut cursor)
+            .map_err(|e| parse_error(SectionId::Global, e))?;
+        let init = ConstExpr::parse((), &module.global.bytes, &mut cursor)
+            .map_err(|e| parse_error(SectionId::Global, e))?;
+        let init_type = match init {
+            ConstExpr::I32(_) => ValueType::I32,
+            ConstExpr::I64(_) => ValueType::I64,
+            ConstExpr::F32(_) => ValueType::F32,
+            ConstExpr::F64(_) => ValueType::F64,
+        };
+        if init_type != ty.value_type {
+            return Err(ValidationError {
+                section: SectionId::Global,
+                fn_index: None,
+                offset: global_offset,
+                message: format!(
+                    "Global of type {:?} is initialised with a value of type {:?}",
+                    ty.value_type, init_type
+                ),
+            });
+        }
+        globals.push(ty);
+    }
+
+    //
+    // Export section
+    //
+    for (i, export) in module.export.exports.iter().enumerate() {
+        let is_duplicate = module.export.exports[..i]
+            .iter()
+            .any(|ex| ex.name == export.name);
+        if is_duplicate {
+            return Err(section_error(
+                SectionId::Export,
+                format!("Duplicate export name \"{}\"", export.name),
+            ));
+        }
+        let is_valid_index = match export.ty {
+            ExportType::Func => export.index < fn_count,
+            ExportType::Table => has_table && export.index == 0,
+            ExportType::Mem => has_memory && export.index == 0,
+            ExportType::Global => (export.index as usize) < globals.len(),
+        };
+        if !is_valid_index {
+            return Err(section_error(
+                SectionId::Export,
+                format!(
+                    "Export \"{}\" refers to unknown {:?} {}",
+                    export.name, export.ty, export.index
+                ),
+            ));
+        }
+    }
+
+    //
+    // Element section
+    //
+    if has_table {
+        for segment in module.element.segments.iter() {
+            if !matches!(segment.offset, ConstExpr::I32(_)) {
+                return Err(section_error(
+                    SectionId::Element,
+                    format!("Element segment offset {:?} is not an i32", segment.offset),
+                ));
+            }
+            if let Some(f) = segment.fn_indices.iter().find(|f| **f >= fn_count) {
+                return Err(section_error(
+                    SectionId::Element,
+                    format!("Element segment refers to unknown function {f}"),
+                ));
+            }
+        }
+    }
+
+    //
+    // Data section
+    //
+    let segments = module
+        .data
+        .segments(arena)
+        .map_err(|e| parse_error(SectionId::Data, e))?;
+    for (mode, _) in segments.iter() {
+        if matches!(mode, DataMode::Active { .. }) && !has_memory {
+            return Err(section_error(
+                SectionId::Data,
+                "Active data segment requires a memory".into(),
+            ));
+        }
+    }
+
+    //
+    // Function bodies
+    // Dead import dummies are generated by `CodeSection::serialize`, so only the real functions
+    // are stored in the Code section bytes.
+    //
+    let ctx = ModuleContext {
+        signatures,
+        fn_signatures,
+        globals,
+        has_memory,
+        has_table,
+    };
+    let bytes = &module.code.bytes;
+    let mut cursor = module.code.function_offsets.first().copied().unwrap_or(0) as usize;
+    let first_fn_index = import_fn_count + module.code.dead_import_dummy_count;
+    for fn_index in first_fn_index..(first_fn_index + module.code.function_count) {
+        let fn_error = |offset: usize, message: String| ValidationError {
+            section: SectionId::Code,
+            fn_index: Some(fn_index),
+            offset,
+            message,
+        };
+        let body_size =
+            u32::parse((), bytes, &mut cursor).map_err(|e| fn_error(e.offset, e.message))? as usize;
+        let body_end = cursor + body_size;
+        if body_end > bytes.len() {
+            return Err(fn_error(
+                cursor,
+                format!("Function body of {body_size} bytes runs past the end of the section"),
+            ));
+        }
+        let sig_index = ctx.fn_signatures[fn_index as usize];
+        validate_function(arena, &ctx, sig_index, &bytes[..body_end], cursor)
+            .map_err(|(offset, message)| fn_error(offset, message))?;
+        cursor = body_end;
+    }
+
+    Ok(())
+}
+
+fn validate_limits(limits: &Limits) -> Result<(), String> {
+    match limits {
+        Limits::Min(min) if *min > MAX_MEMORY_PAGES => {
+            Err(format!("Memory size of {min} pages is too large"))
+        }
+        Limits::MinMax(min, max) if *min > *max || *max > MAX_MEMORY_PAGES => {
+            Err(format!("Invalid memory limits: min {min}, max {max} pages"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/*******************************************************************
+ *
+ * Function bodies
+ *
+ *******************************************************************/
+
+/// An operand type. `None` is the "unknown" type of values in unreachable code,
+/// which matches any other type.
+type Operand = Option<ValueType>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrameKind {
+    Function,
+    Block,
+    Loop,
+    If,
+    Else,
+}
+
+#[derive(Debug)]
+struct ControlFrame<'c> {
+    kind: FrameKind,
+    /// Types of the values left on the stack at the end. Only functions can have more than one.
+    results: &'c [ValueType],
+    /// Height of the operand stack when the frame was entered
+    height: usize,
+    unreachable: bool,
+}
+
+impl<'c> ControlFrame<'c> {
+    /// The types of the values that a branch to this frame's label must provide.
+    /// Branching to a loop jumps back to the start, which takes no values in Wasm 1.0.
+    fn label_types(&self) -> &'c [ValueType] {
+        match self.kind {
+            FrameKind::Loop => &[],
+            _ => self.results,
+        }
+    }
+}
+
+struct FunctionValidator<'a, 'c> {
+    ctx: &'c ModuleContext<'c>,
+    locals: Vec<'a, ValueType>,
+    operands: Vec<'a, Operand>,
+    frames: Vec<'a, ControlFrame<'c>>,
+}
+
+impl<'a, 'c> FunctionValidator<'a, 'c> {
+    fn push(&mut self, operand: Operand) {
+        self.operands.push(operand);
+    }
+
+    fn pop(&mut self) -> Result<Operand, String> {
+        let frame = self.frames.last().unwrap();
+        if self.operands.len() == frame.height {
+            if frame.unreachable {
+                Ok(None)
+            } else {
+                Err("Operand stack underflow".into())
+            }
+        } else {
+            Ok(self.operands.pop().unwrap())
+        }
+    }
+
+    fn pop_expecting(&mut self, expected: ValueType) -> Result<(), String> {
+        match self.pop()? {
+            Some(actual) if actual != expected => Err(format!(
+                "Type mismatch: expected {expected:?} but found {actual:?}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn pop_params(&mut self, params: &[ValueType]) -> Result<(), String> {
+        for param in params.iter().rev() {
+            self.pop_expecting(*param)?;
+        }
+        Ok(())
+    }
+
+    fn push_results(&mut self, results: &[ValueType]) {
+        for ty in results.iter() {
+            self.push(Some(*ty));
+        }
+    }
+
+    fn push_frame(&mut self, kind: FrameKind, results: &'c [ValueType]) {
+        self.frames.push(ControlFrame {
+            kind,
+            results,
+            height: self.operands.len(),
+            unreachable: false,
+        });
+    }
+
+    fn pop_frame(&mut self) -> Result<ControlFrame<'c>, String> {
+        let results = self.frames.last().unwrap().results;
+        self.pop_params(results)?;
+        let frame = self.frames.pop().unwrap();
+        let extra_values = self.operands.len() - frame.height;
+        if extra_values > 0 {
+            return Err(format!(
+                "{extra_values} unused values on the stack at the end of a {:?}",
+                frame.kind
+            ));
+        }
+        Ok(frame)
+    }
+
+    fn set_unreachable(&mut self) {
+        let frame = self.frames.last_mut().unwrap();
+        self.operands.truncate(frame.height);
+        frame.unreachable = true;
+    }
+
+    fn label_types(&self, depth: u32) -> Result<&'c [ValueType], String> {
+        let n_frames = self.frames.len();
+        if depth as usize >= n_frames {
+            return Err(format!(
+                "Branch depth {depth} is outside of the {n_frames} enclosing blocks"
+            ));
+        }
+        Ok(self.frames[n_frames - 1 - depth as usize].label_types())
+    }
+
+    fn local_type(&self, index: u32) -> Result<ValueType, String> {
+        self.locals
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| format!("Unknown local {index}"))
+    }
+
+    fn global_type(&self, index: u32) -> Result<GlobalType, String> {
+        self.ctx
+            .globals
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| format!("Unknown global {index}"))
+    }
+
+    fn require_memory(&self) -> Result<(), String> {
+        if self.ctx.has_memory {
+            Ok(())
+        } else {
+            Err("Memory instruction in a module with no memory".into())
+        }
+    }
+
+    fn instruction(&mut self, instruction: Instruction) -> Result<(), String> {
+        use OpCode::*;
+
+        let Instruction { op, immediates } = instruction;
+        match (op, immediates) {
+            (UNREACHABLE, _) => self.set_unreachable(),
+            (NOP, _) => {}
+            (BLOCK, Immediates::BlockType(bt)) => {
+                self.push_frame(FrameKind::Block, block_type(bt)?)
+            }
+            (LOOP, Immediates::BlockType(bt)) => self.push_frame(FrameKind::Loop, block_type(bt)?),
+            (IF, Immediates::BlockType(bt)) => {
+                self.pop_expecting(ValueType::I32)?;
+                self.push_frame(FrameKind::If, block_type(bt)?);
+            }
+            (ELSE, _) => {
+                let frame = self.pop_frame()?;
+                if frame.kind != FrameKind::If {
+                    return Err(format!("Found else inside a {:?}", frame.kind));
+                }
+                self.push_frame(FrameKind::Else, frame.results);
+            }
+            (END, _) => {
+                let frame = self.pop_frame()?;
+                if frame.kind == FrameKind::If && !frame.results.is_empty() {
+                    return Err("An if with a result type must have an else branch".into());
+                }
+                self.push_results(frame.results);
+            }
+            (BR, Immediates::Index(depth)) => {
+                let label_types = self.label_types(depth)?;
+                self.pop_params(label_types)?;
+                self.set_unreachable();
+            }
+            (BRIF, Immediates::Index(depth)) => {
+                self.pop_expecting(ValueType::I32)?;
+                let label_types = self.label_types(depth)?;
+                self.pop_params(label_types)?;
+                self.push_results(label_types);
+            }
+            (BRTABLE, Immediates::BrTable { targets, default }) => {
+                self.pop_expecting(ValueType::I32)?;
+                let default_types = self.label_types(default)?;
+                for target in targets.iter() {
+                    let target_types = self.label_types(*target)?;
+                    if target_types != default_types {
+                        return Err(format!(
+                            "br_table target {target} has types {target_types:?} but default {default} has types {default_types:?}"
+                        ));
+                    }
+                }
+                self.pop_params(default_types)?;
+                self.set_unreachable();
+            }
+            (RETURN, _) => {
+                let results = self.frames[0].results;
+                self.pop_params(results)?;
+                self.set_unreachable();
+            }
+            (CALL, Immediates::Index(fn_index)) => {
+                let (params, results) = self.ctx.fn_signature(fn_index)?;
+                self.pop_params(params)?;
+                self.push_results(results);
+            }
+            (
+                CALLINDIRECT,
+                Immediates::CallIndirect {
+                    type_index,
+                    table_index,
+                },
+            ) => {
+                if table_index != 0 || !self.ctx.has_table {
+                    return Err(format!(
+                        "call_indirect refers to unknown table {table_index}"
+                    ));
+                }
+                let (params, results) = self.ctx.signature(type_index)?;
+                self.pop_expecting(ValueType::I32)?;
+                self.pop_params(params)?;
+                self.push_results(results);
+            }
+            (DROP, _) => {
+                self.pop()?;
+            }
+            (SELECT, _) => {
+                self.pop_expecting(ValueType::I32)?;
+                let first = self.pop()?;
+                let second = self.pop()?;
+                match (first, second) {
+                    (Some(t1), Some(t2)) if t1 != t2 => {
+                        return Err(format!(
+                            "select operands have different types {t1:?} and {t2:?}"
+                        ));
+                    }
+                    _ => self.push(first.or(second)),
+                }
+            }
+            (GETLOCAL, Immediates::Index(index)) => {
+                let ty = self.local_type(index)?;
+                self.push(Some(ty));
+            }
+            (SETLOCAL, Immediates::Index(index)) => {
+                let ty = self.local_type(index)?;
+                self.pop_expecting(ty)?;
+            }
+            (TEELOCAL, Immediates::Index(index)) => {
+                let ty = self.local_type(index)?;
+                self.pop_expecting(ty)?;
+                self.push(Some(ty));
+            }
+            (GETGLOBAL, Immediates::Index(index)) => {
+                let global = self.global_type(index)?;
+                self.push(Some(global.value_type));
+            }
+            (SETGLOBAL, Immediates::Index(index)) => {
+                let global = self.global_type(index)?;
+                if !global.is_mutable {
+                    return Err(format!("Global {index} is immutable"));
+                }
+                self.pop_expecting(global.value_type)?;
+            }
+            (CURRENTMEMORY, Immediates::MemoryIndex(index)) => {
+                self.require_memory()?;
+                if index != 0 {
+                    return Err(format!("Unknown memory {index}"));
+                }
+                self.push(Some(ValueType::I32));
+            }
+            (GROWMEMORY, Immediates::MemoryIndex(index)) => {
+                self.require_memory()?;
+                if index != 0 {
+                    return Err(format!("Unknown memory {index}"));
+                }
+                self.pop_expecting(ValueType::I32)?;
+                self.push(Some(ValueType::I32));
+            }
+            (MEMORY, Immediates::Memory(mem_op)) => {
+                debug_assert!(matches!(
+                    mem_op,
+                    MemoryInstruction::MemoryCopy | MemoryInstruction::MemoryFill
+                ));
+                self.require_memory()?;
+                // memory.copy takes (dest, src, size), memory.fill takes (dest, value, size)
+                for _ in 0..3 {
+                    self.pop_expecting(ValueType::I32)?;
+                }
+            }
+            (I32CONST, _) => self.push(Some(ValueType::I32)),
+            (I64CONST, _) => self.push(Some(ValueType::I64)),
+            (F32CONST, _) => self.push(Some(ValueType::F32)),
+            (F64CONST, _) => self.push(Some(ValueType::F64)),
+            (_, Immediates::MemArg { align, .. }) => {
+                let (value_type, natural_align, is_store) = memory_access(op);
+                self.require_memory()?;
+                if align > natural_align {
+                    return Err(format!(
+                        "{op:?} alignment of {} bytes is larger than its natural alignment of {} bytes",
+                        1u64 << align.min(63),
+                        1 << natural_align
+                    ));
+                }
+                if is_store {
+                    self.pop_expecting(value_type)?;
+                    self.pop_expecting(ValueType::I32)?;
+                } else {
+                    self.pop_expecting(ValueType::I32)?;
+                    self.push(Some(value_type));
+                }
+            }
+            (
+                SIMD,
+                Immediates::SimdMemArg {
+                    op: simd_op, align, ..
+                },
+            ) => {
+                self.require_memory()?;
+                if align > 4 {
+                    return Err(format!(
+                        "{simd_op:?} alignment of {} bytes is larger than its natural alignment of 16 bytes",
+                        1u64 << align.min(63),
+                    ));
+                }
+                if simd_op == SimdInstruction::V128Store {
+                    self.pop_expecting(ValueType::V128)?;
+                    self.pop_expecting(ValueType::I32)?;
+                } else {
+                    self.pop_expecting(ValueType::I32)?;
+                    self.push(Some(ValueType::V128));
+                }
+            }
+            (SIMD, Immediates::V128(_)) => self.push(Some(ValueType::V128)),
+            (SIMD, Immediates::Simd(simd_op)) => {
+                let (params, result) = simd_signature(simd_op);
+                self.pop_params(params)?;
+                self.push(Some(result));
+            }
+            (_, Immediates::None) => {
+                let (params, result) = numeric_signature(op);
+                self.pop_params(params)?;
+                self.push(Some(result));
+            }
+            (op, immediates) => {
+                return Err(format!("{op:?} has unexpected immediates {immediates:?}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Result types of a block. We only support the single-byte block types of Wasm 1.0.
+fn block_type(byte: u8) -> Result<&'static [ValueType], String> {
+    use ValueType::*;
+
+    match value_type(byte) {
+        _ if byte == ValueType::VOID => Ok(&[]),
+        Some(I32) => Ok(&[I32]),
+        Some(I64) => Ok(&[I64]),
+        Some(F32) => Ok(&[F32]),
+        Some(F64) => Ok(&[F64]),
+        Some(V128) => Ok(&[V128]),
+        None => Err(format!("Unsupported block type 0x{byte:02x}")),
+    }
+}
+
+/// Like `ValueType::from`, but returns None instead of panicking
+fn value_type(byte: u8) -> Option<ValueType> {
+    match byte {
+        0x7f => Some(ValueType::I32),
+        0x7e => Some(ValueType::I64),
+        0x7d => Some(ValueType::F32),
+        0x7c => Some(ValueType::F64),
+        0x7b => Some(ValueType::V128),
+        _ => None,
+    }
+}
+
+/// Value type, natural alignment (as a power of 2), and whether it's a store rather than a load
+fn memory_access(op: OpCode) -> (ValueType, u32, bool) {
+    use OpCode::*;
+    use ValueType::*;
+
+    match op {
+        I32LOAD => (I32, 2, false),
+        I64LOAD => (I64, 3, false),
+        F32LOAD => (F32, 2, false),
+        F64LOAD => (F64, 3, false),
+        I32LOAD8S | I32LOAD8U => (I32, 0, false),
+        I32LOAD16S | I32LOAD16U => (I32, 1, false),
+        I64LOAD8S | I64LOAD8U => (I64, 0, false),
+        I64LOAD16S | I64LOAD16U => (I64, 1, false),
+        I64LOAD32S | I64LOAD32U => (I64, 2, false),
+        I32STORE => (I32, 2, true),
+        I64STORE => (I64, 3, true),
+        F32STORE => (F32, 2, true),
+        F64STORE => (F64, 3, true),
+        I32STORE8 => (I32, 0, true),
+        I32STORE16 => (I32, 1, true),
+        I64STORE8 => (I64, 0, true),
+        I64STORE16 => (I64, 1, true),
+        I64STORE32 => (I64, 2, true),
+        _ => unreachable!("{op:?} is not a load or store"),
+    }
+}
+
+/// Operand and result types of numeric instructions
+fn numeric_signature(op: OpCode) -> (&'static [ValueType], ValueType) {
+    use OpCode::*;
+    use ValueType::*;
+
+    match op {
+        I32EQZ => (&[I32], I32),
+        I64EQZ => (&[I64], I32),
+
+        I32EQ | I32NE | I32LTS | I32LTU | I32GTS | I32GTU | I32LES | I32LEU | I32GES | I32GEU => {
+            (&[I32, I32], I32)
+        }
+        I64EQ | I64NE | I64LTS | I64LTU | I64GTS | I64GTU | I64LES | I64LEU | I64GES | I64GEU => {
+            (&[I64, I64], I32)
+        }
+        F32EQ | F32NE | F32LT | F32GT | F32LE | F32GE => (&[F32, F32], I32),
+        F64EQ | F64NE | F64LT | F64GT | F64LE | F64GE => (&[F64, F64], I32),
+
+        I32CLZ | I32CTZ | I32POPCNT | I32EXTEND8S | I32EXTEND16S => (&[I32], I32),
+        I32ADD | I32SUB | I32MUL | I32DIVS | I32DIVU | I32REMS | I32REMU | I32AND | I32OR
+        | I32XOR | I32SHL | I32SHRS | I32SHRU | I32ROTL | I32ROTR => (&[I32, I32], I32),
+
+        I64CLZ | I64CTZ | I64POPCNT | I64EXTEND8S | I64EXTEND16S | I64EXTEND32S => (&[I64], I64),
+        I64ADD | I64SUB | I64MUL | I64DIVS | I64DIVU | I64REMS | I64REMU | I64AND | I64OR
+        | I64XOR | I64SHL | I64SHRS | I64SHRU | I64ROTL | I64ROTR => (&[I64, I64], I64),
+
+        F32ABS | F32NEG | F32CEIL | F32FLOOR | F32TRUNC | F32NEAREST | F32SQRT => (&[F32], F32),
+        F32ADD | F32SUB | F32MUL | F32DIV | F32MIN | F32MAX | F32COPYSIGN => (&[F32, F32], F32),
+
+        F64ABS | F64NEG | F64CEIL | F64FLOOR | F64TRUNC | F64NEAREST | F64SQRT => (&[F64], F64),
+        F64ADD | F64SUB | F64MUL | F64DIV | F64MIN | F64MAX | F64COPYSIGN => (&[F64, F64], F64),
+
+        I32WRAPI64 => (&[I64], I32),
+        I32TRUNCSF32 | I32TRUNCUF32 | I32REINTERPRETF32 => (&[F32], I32),
+        I32TRUNCSF64 | I32TRUNCUF64 => (&[F64], I32),
+        I64EXTENDSI32 | I64EXTENDUI32 => (&[I32], I64),
+        I64TRUNCSF32 | I64TRUNCUF32 => (&[F32], I64),
+        I64TRUNCSF64 | I64TRUNCUF64 | I64REINTERPRETF64 => (&[F64], I64),
+        F32CONVERTSI32 | F32CONVERTUI32 | F32REINTERPRETI32 => (&[I32], F32),
+        F32CONVERTSI64 | F32CONVERTUI64 => (&[I64], F32),
+        F32DEMOTEF64 => (&[F64], F32),
+        F64CONVERTSI32 | F64CONVERTUI32 => (&[I32], F64),
+        F64CONVERTSI64 | F64CONVERTUI64 | F64REINTERPRETI64 => (&[I64], F64),
+        F64PROMOTEF32 => (&[F32], F64),
+
+        _ => unreachable!("{op:?} is not a numeric instruction"),
+    }
+}
+
+/// Operand and result types of SIMD instructions that have no immediates
+fn simd_signature(op: SimdInstruction) -> (&'static [ValueType], ValueType) {
+    use SimdInstruction::*;
+    use ValueType::*;
+
+    match op {
+        I8x16Splat => (&[I32], V128),
+        I8x16Eq | I8x16Ne | V128And | V128Or | V128Xor => (&[V128, V128], V128),
+        V128Not => (&[V128], V128),
+        V128AnyTrue | I8x16AllTrue | I8x16Bitmask => (&[V128], I32),
+        V128Load | V128Store | V128Const => unreachable!("{op:?} has immediates"),
+    }
+}
+
+/// Type-check a function body, returning the offset and message of the first error
+fn validate_function(
+    arena: &Bump,
+    ctx: &ModuleContext,
+    sig_index: u32,
+    bytes: &[u8],
+    body_start: usize,
+) -> Result<(), (usize, String)> {
+    let mut cursor = body_start;
+    let (params, results) = ctx.signature(sig_index).map_err(|m| (cursor, m))?;
+
+    let mut locals = Vec::from_iter_in(params.iter().copied(), arena);
+    let n_local_groups = u32::parse((), bytes, &mut cursor).map_err(|e| (e.offset, e.message))?;
+    for _ in 0..n_local_groups {
+        let group_offset = cursor;
+        let count = u32::parse((), bytes, &mut cursor).map_err(|e| (e.offset, e.message))?;
+        let ty = bytes
+            .get(cursor)
+            .and_then(|byte| value_type(*byte))
+            .ok_or_else(|| (cursor, "Invalid local variable type".to_string()))?;
+        cursor += 1;
+        if locals.len() + count as usize > u32::MAX as usize {
+            return Err((group_offset, "Too many local variables".into()));
+        }
+        locals.extend(std::iter::repeat(ty).take(count as usize));
+    }
+
+    let mut validator = FunctionValidator {
+        ctx,
+        locals,
+        operands: Vec::new_in(arena),
+        frames: Vec::new_in(arena),
+    };
+    validator.push_frame(FrameKind::Function, results);
+
+    while !validator.frames.is_empty() {
+        let op_offset = cursor;
+        let instruction =
+            Instruction::parse(arena, bytes, &mut cursor).map_err(|e| (e.offset, e.message))?;
+        validator
+            .instruction(instruction)
+            .map_err(|message| (op_offset, message))?;
+    }
+
+    if cursor != bytes.len() {
+        return Err((
+            cursor,
+            "Found instructions after the end of the function".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/*******************************************************************
+ *
+ * Unit tests
+ *
+ *******************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{Import, MemorySection};
+    use crate::{Export, Global, SerialBuffer, Serialize, Signature};
+
+    /// Append a function with the given locals and instruction bytes to the module.
+    /// The instructions should include the final `end`.
+    fn add_function<'a>(
+        module: &mut WasmModule<'a>,
+        signature: Signature<'a>,
+        local_types: &[(u32, ValueType)],
+        instructions: &[u8],
+    ) {
+        module.add_function_signature(signature);
+
+        let offset = module.code.bytes.encode_padded_u32(0);
+        let start = module.code.bytes.len();
+        local_types.serialize(&mut module.code.bytes);
+        module.code.bytes.extend_from_slice(instructions);
+        let len = module.code.bytes.len() - start;
+        module.code.bytes.overwrite_padded_u32(offset, len as u32);
+
+        module.code.function_count += 1;
+        module.code.function_offsets.push(offset as u32);
+    }
+
+    fn validate_body(
+        arena: &Bump,
+        params: &[ValueType],
+        ret_types: &[ValueType],
+        local_types: &[(u32, ValueType)],
+        instructions: &[u8],
+    ) -> Result<(), ValidationError> {
+        let mut module = WasmModule::new(arena);
+        module.memory = MemorySection::new(arena, MemorySection::PAGE_SIZE);
+        let signature = Signature {
+            param_types: Vec::from_iter_in(params.iter().copied(), arena),
+            ret_types: Vec::from_iter_in(ret_types.iter().copied(), arena),
+        };
+        add_function(&mut module, signature, local_types, instructions);
+        module.validate(arena)
+    }
+
+    fn expect_error(result: Result<(), ValidationError>, expected_message: &str) {
+        match result {
+            Ok(()) => panic!("Expected a validation error containing \"{expected_message}\""),
+            Err(e) => assert!(
+                e.message.contains(expected_message),
+                "Expected a validation error containing \"{}\" but got \"{}\"",
+                expected_message,
+                e.message
+            ),
+        }
+    }
+
+    #[test]
+    fn test_valid_arithmetic() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let params = [ValueType::I32, ValueType::I32];
+        #[rustfmt::skip]
+        let instructions = [
+            GETLOCAL as u8, 0,
+            GETLOCAL as u8, 1,
+            I32ADD as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &params, &[ValueType::I32], &[], &instructions);
+        result.unwrap();
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let params = [ValueType::I32, ValueType::I64];
+        #[rustfmt::skip]
+        let instructions = [
+            GETLOCAL as u8, 0,
+            GETLOCAL as u8, 1,
+            I32ADD as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &params, &[ValueType::I32], &[], &instructions);
+        expect_error(result, "expected I32 but found I64");
+    }
+
+    #[test]
+    fn test_stack_underflow() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let params = [ValueType::I32];
+        let instructions = [GETLOCAL as u8, 0, I32ADD as u8, END as u8];
+        let result = validate_body(arena, &params, &[ValueType::I32], &[], &instructions);
+        expect_error(result, "underflow");
+    }
+
+    #[test]
+    fn test_unused_values() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let instructions = [I32CONST as u8, 1, END as u8];
+        let result = validate_body(arena, &[], &[], &[], &instructions);
+        expect_error(result, "1 unused values");
+    }
+
+    #[test]
+    fn test_blocks_and_branches() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        // A loop that counts down from the first argument, with an early exit
+        let params = [ValueType::I32];
+        let locals = [(1, ValueType::I64)];
+        #[rustfmt::skip]
+        let instructions = [
+            BLOCK as u8, ValueType::I32 as u8,
+                LOOP as u8, ValueType::VOID,
+                    GETLOCAL as u8, 0,
+                    I32EQZ as u8,
+                    IF as u8, ValueType::VOID,
+                        I32CONST as u8, 42,
+                        BR as u8, 2,
+                    END as u8,
+                    GETLOCAL as u8, 0,
+                    I32CONST as u8, 1,
+                    I32SUB as u8,
+                    TEELOCAL as u8, 0,
+                    BRIF as u8, 0,
+                END as u8,
+                UNREACHABLE as u8,
+            END as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &params, &[ValueType::I32], &locals, &instructions);
+        result.unwrap();
+    }
+
+    #[test]
+    fn test_branch_type_mismatch() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        #[rustfmt::skip]
+        let instructions = [
+            BLOCK as u8, ValueType::I32 as u8,
+                I64CONST as u8, 0,
+                BR as u8, 0,
+            END as u8,
+            DROP as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &[], &[], &[], &instructions);
+        expect_error(result, "expected I32 but found I64");
+    }
+
+    #[test]
+    fn test_if_without_else() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let params = [ValueType::I32];
+        #[rustfmt::skip]
+        let instructions = [
+            GETLOCAL as u8, 0,
+            IF as u8, ValueType::I32 as u8,
+                I32CONST as u8, 1,
+            END as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &params, &[ValueType::I32], &[], &instructions);
+        expect_error(result, "must have an else branch");
+    }
+
+    #[test]
+    fn test_unknown_local() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let result = validate_body(
+            arena,
+            &[ValueType::I32],
+            &[],
+            &[(1, ValueType::F64)],
+            &[GETLOCAL as u8, 2, DROP as u8, END as u8],
+        );
+        expect_error(result, "Unknown local 2");
+    }
+
+    #[test]
+    fn test_over_aligned_load() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let result = validate_body(
+            arena,
+            &[],
+            &[ValueType::I32],
+            &[],
+            &[I32CONST as u8, 0, I32LOAD as u8, 3, 0, END as u8],
+        );
+        expect_error(result, "natural alignment");
+    }
+
+    #[test]
+    fn test_missing_end() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let result = validate_body(arena, &[], &[], &[], &[NOP as u8]);
+        expect_error(result, "Unexpected end of function body");
+    }
+
+    #[test]
+    fn test_calls_and_globals() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let mut module = WasmModule::new(arena);
+
+        module.import.imports.push(Import {
+            module: "env",
+            name: "log",
+            description: ImportDesc::Func {
+                signature_index: module.types.insert(Signature {
+                    param_types: bumpalo::vec![in arena; ValueType::I32],
+                    ret_types: bumpalo::vec![in arena],
+                }),
+            },
+        });
+        module.global.append(Global {
+            ty: GlobalType {
+                value_type: ValueType::I32,
+                is_mutable: false,
+            },
+            init: ConstExpr::I32(7),
+        });
+
+        add_function(
+            &mut module,
+            Signature {
+                param_types: bumpalo::vec![in arena],
+                ret_types: bumpalo::vec![in arena],
+            },
+            &[],
+            &[GETGLOBAL as u8, 0, CALL as u8, 0, END as u8],
+        );
+        module.export.append(Export {
+            name: "main",
+            ty: ExportType::Func,
+            index: 1,
+        });
+        module.validate(arena).unwrap();
+
+        // Now try to write to the immutable global
+        module
+            .code
+            .bytes
+            .truncate(module.code.function_offsets[0] as usize);
+        module.code.function_offsets.clear();
+        module.code.function_count = 0;
+        module.function.signatures.clear();
+        add_function(
+            &mut module,
+            Signature {
+                param_types: bumpalo::vec![in arena],
+                ret_types: bumpalo::vec![in arena],
+            },
+            &[],
+            &[I32CONST as u8, 1, SETGLOBAL as u8, 0, END as u8],
+        );
+        let error = module.validate(arena).unwrap_err();
+        assert_eq!(error.fn_index, Some(1));
+        assert!(error.message.contains("immutable"), "{}", error.message);
+    }
+
+    #[test]
+    fn test_multi_value() {
+        use OpCode::*;
+        let arena = &Bump::new();
+        let mut module = WasmModule::new(arena);
+
+        // (i32) -> (i64, i32)
+        add_function(
+            &mut module,
+            Signature {
+                param_types: bumpalo::vec![in arena; ValueType::I32],
+                ret_types: bumpalo::vec![in arena; ValueType::I64, ValueType::I32],
+            },
+            &[],
+            &[I64CONST as u8, 1, GETLOCAL as u8, 0, END as u8],
+        );
+
+        // () -> i32, dropping the first result of the call
+        #[rustfmt::skip]
+        let caller_instructions = [
+            I32CONST as u8, 5,
+            CALL as u8, 0,
+            SETLOCAL as u8, 0,
+            DROP as u8,
+            GETLOCAL as u8, 0,
+            END as u8,
+        ];
+        add_function(
+            &mut module,
+            Signature {
+                param_types: bumpalo::vec![in arena],
+                ret_types: bumpalo::vec![in arena; ValueType::I32],
+            },
+            &[(1, ValueType::I32)],
+            &caller_instructions,
+        );
+        module.validate(arena).unwrap();
+
+        let result = validate_body(
+            arena,
+            &[],
+            &[ValueType::I32, ValueType::I64],
+            &[],
+            &[I64CONST as u8, 0, I32CONST as u8, 0, END as u8],
+        );
+        expect_error(result, "expected I64 but found I32");
+    }
+
+    #[test]
+    fn test_simd() {
+        use OpCode::*;
+        use SimdInstruction::*;
+        let arena = &Bump::new();
+        let params = [ValueType::I32, ValueType::I32];
+        // Are the 16 bytes at the two addresses equal?
+        #[rustfmt::skip]
+        let instructions = [
+            GETLOCAL as u8, 0,
+            SIMD as u8, V128Load as u8, 0, 0,
+            GETLOCAL as u8, 1,
+            SIMD as u8, V128Load as u8, 0, 0,
+            SIMD as u8, I8x16Eq as u8,
+            SIMD as u8, I8x16AllTrue as u8,
+            END as u8,
+        ];
+        let result = validate_body(arena, &params, &[ValueType::I32], &[], &instructions);
+        result.unwrap();
+
+        let mut instructions = vec![SIMD as u8, V128Const as u8];
+        instructions.extend_from_slice(&[0; 16]);
+        instructions.extend_from_slice(&[SIMD as u8, I8x16Bitmask as u8, END as u8]);
+        let result = validate_body(arena, &[], &[ValueType::V128], &[], &instructions);
+        expect_error(result, "expected V128 but found I32");
+    }
+
+    #[test]
+    fn test_export_unknown_function() {
+        let arena = &Bump::new();
+        let mut module = WasmModule::new(arena);
+        module.export.append(Export {
+            name: "main",
+            ty: ExportType::Func,
+            index: 0,
+        });
+        expect_error(module.validate(arena), "unknown Func 0");
+    }
+}
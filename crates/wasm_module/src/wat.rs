@@ -0,0 +1,870 @@
+//! Print a module in WebAssembly text format (WAT), for debugging.
+//!
+//! The output follows the conventions of `wasm2wat` closely enough to be diffed against it,
+//! and uses the Name section to label functions. It's intended to be readable even when the
+//! module is invalid, so decoding errors are printed as comments rather than returned.
+
+use std::fmt::Write;
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+
+use crate::opcodes::{Immediates, Instruction, MemoryInstruction, OpCode, SimdInstruction};
+use crate::parse::Parse;
+use crate::sections::{
+    ConstExpr, DataMode, GlobalType, ImportDesc, Limits, RefType, Signature, TableType,
+};
+use crate::{ExportType, ValueType, WasmModule, DUMMY_FUNCTION};
+
+const INDENT: &str = "  ";
+
+pub fn module_to_wat(arena: &Bump, module: &WasmModule<'_>) -> String {
+    let mut out = String::with_capacity(4 * module.size());
+    let fn_names = function_names(arena, module);
+
+    out.push_str("(module\n");
+
+    //
+    // Types
+    //
+    let mut signatures = Vec::with_capacity_in(module.types.len(), arena);
+    for sig_index in 0..module.types.len() {
+        let (params, results) = module.types.look_up(sig_index as u32);
+        let signature = Signature {
+            param_types: Vec::from_iter_in(params, arena),
+            ret_types: Vec::from_iter_in(results, arena),
+        };
+        write!(out, "{INDENT}(type (;{sig_index};) (func").unwrap();
+        write_signature(&mut out, &signature);
+        out.push_str("))\n");
+        signatures.push(signature);
+    }
+
+    //
+    // Imports
+    //
+    let mut fn_index = 0;
+    let mut global_index = 0;
+    for import in module.import.imports.iter() {
+        write!(
+            out,
+            "{INDENT}(import \"{}\" \"{}\" (",
+            import.module, import.name
+        )
+        .unwrap();
+        match &import.description {
+            ImportDesc::Func { signature_index } => {
+                out.push_str("func");
+                write_fn_label(&mut out, &fn_names, fn_index);
+                write!(out, " (type {signature_index})").unwrap();
+                fn_index += 1;
+            }
+            ImportDesc::Table { ty } => {
+                out.push_str("table (;0;)");
+                write_table_type(&mut out, ty);
+            }
+            ImportDesc::Mem { limits } => {
+                out.push_str("memory (;0;)");
+                write_limits(&mut out, limits);
+            }
+            ImportDesc::Global { ty } => {
+                write!(out, "global (;{global_index};) ").unwrap();
+                write_global_type(&mut out, ty);
+                global_index += 1;
+            }
+        }
+        out.push_str("))\n");
+    }
+
+    //
+    // Functions
+    //
+    let bytes = &module.code.bytes;
+    let mut cursor = module.code.function_offsets.first().copied().unwrap_or(0) as usize;
+    for (i, sig_index) in module.function.signatures.iter().enumerate() {
+        let signature = signatures.get(*sig_index as usize);
+
+        out.push_str(INDENT);
+        out.push_str("(func");
+        write_fn_label(&mut out, &fn_names, fn_index);
+        write!(out, " (type {sig_index})").unwrap();
+        if let Some(sig) = signature {
+            write_signature(&mut out, sig);
+        }
+        out.push('\n');
+
+        let param_count = signature.map(|sig| sig.param_types.len()).unwrap_or(0);
+        if i < module.code.dead_import_dummy_count as usize {
+            write_function_body(arena, &mut out, &fn_names, param_count, &DUMMY_FUNCTION);
+        } else {
+            match u32::parse((), bytes, &mut cursor) {
+                Ok(size) if cursor + size as usize <= bytes.len() => {
+                    let body_end = cursor + size as usize;
+                    let body = &bytes[cursor..body_end];
+                    write_function_body(arena, &mut out, &fn_names, param_count, body);
+                    cursor = body_end;
+                }
+                _ => {
+                    writeln!(out, "{INDENT}{INDENT};; missing function body)").unwrap();
+                    cursor = bytes.len();
+                }
+            }
+        }
+        fn_index += 1;
+    }
+
+    //
+    // Table
+    // The serializer only writes a table if there are elements in it
+    //
+    if !module.element.is_empty() {
+        write!(out, "{INDENT}(table (;0;)").unwrap();
+        write_table_type(&mut out, &module.table.function_table);
+        out.push_str(")\n");
+    }
+
+    //
+    // Memory
+    //
+    let mut cursor = 0;
+    for memory_index in 0..module.memory.count {
+        write!(out, "{INDENT}(memory (;{memory_index};)").unwrap();
+        match Limits::parse((), &module.memory.bytes, &mut cursor) {
+            Ok(limits) => write_limits(&mut out, &limits),
+            Err(e) => write!(out, " ;; {} ;;", e.message).unwrap(),
+        }
+        out.push_str(")\n");
+    }
+
+    //
+    // Globals
+    //
+    let mut cursor = 0;
+    for _ in 0..module.global.count {
+        write!(out, "{INDENT}(global (;{global_index};) ").unwrap();
+        let parsed = GlobalType::parse((), &module.global.bytes, &mut cursor).and_then(|ty| {
+            ConstExpr::parse((), &module.global.bytes, &mut cursor).map(|init| (ty, init))
+        });
+        match parsed {
+            Ok((ty, init)) => {
+                write_global_type(&mut out, &ty);
+                out.push(' ');
+                write_const_expr(&mut out, &init);
+            }
+            Err(e) => {
+                writeln!(out, ";; {} ;;)", e.message).unwrap();
+                break;
+            }
+        }
+        out.push_str(")\n");
+        global_index += 1;
+    }
+
+    //
+    // Exports
+    //
+    for export in module.export.exports.iter() {
+        write!(out, "{INDENT}(export \"{}\" (", export.name).unwrap();
+        match export.ty {
+            ExportType::Func => {
+                out.push_str("func ");
+                write_fn_ref(&mut out, &fn_names, export.index);
+            }
+            ExportType::Table => write!(out, "table {}", export.index).unwrap(),
+            ExportType::Mem => write!(out, "memory {}", export.index).unwrap(),
+            ExportType::Global => write!(out, "global {}", export.index).unwrap(),
+        }
+        out.push_str("))\n");
+    }
+
+    //
+    // Elements
+    //
+    if !module.element.is_empty() {
+        for (i, segment) in module.element.segments.iter().enumerate() {
+            write!(out, "{INDENT}(elem (;{i};) ").unwrap();
+            write_const_expr(&mut out, &segment.offset);
+            out.push_str(" func");
+            for f in segment.fn_indices.iter() {
+                out.push(' ');
+                write_fn_ref(&mut out, &fn_names, *f);
+            }
+            out.push_str(")\n");
+        }
+    }
+
+    //
+    // Data
+    //
+    match module.data.segments(arena) {
+        Ok(segments) => {
+            for (i, (mode, init)) in segments.iter().enumerate() {
+                write!(out, "{INDENT}(data (;{i};) ").unwrap();
+                if let DataMode::Active { offset } = mode {
+                    write_const_expr(&mut out, offset);
+                    out.push(' ');
+                }
+                write_data_string(&mut out, init);
+                out.push_str(")\n");
+            }
+        }
+        Err(e) => {
+            writeln!(out, "{INDENT};; Data section: {}", e.message).unwrap();
+        }
+    }
+
+    out.push_str(")\n");
+    out
+}
+
+/// Look up a WAT identifier for each function, from the Name section.
+/// Characters that aren't allowed in identifiers are replaced, and repeated names are left out.
+fn function_names<'a>(arena: &'a Bump, module: &WasmModule<'_>) -> Vec<'a, Option<&'a str>> {
+    let fn_count = module.import.function_count() + module.function.signatures.len();
+    let mut names = Vec::from_iter_in(std::iter::repeat(None).take(fn_count), arena);
+    let mut used = Vec::with_capacity_in(module.names.function_names.len(), arena);
+
+    for (index, name) in module.names.function_names.iter() {
+        if *index as usize >= fn_count || name.is_empty() {
+            continue;
+        }
+        let id: &str = arena.alloc_str(&name.replace(|c| !is_id_char(c), "_"));
+        if used.contains(&id) {
+            continue;
+        }
+        used.push(id);
+        names[*index as usize] = Some(id);
+    }
+
+    names
+}
+
+/// Characters allowed in WAT identifiers
+/// https://webassembly.github.io/spec/core/text/values.html#text-id
+fn is_id_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-./:<=>?@\\^_`|~".contains(c)
+}
+
+/// Write a function's name if it has one, and its index as a comment
+fn write_fn_label(out: &mut String, fn_names: &[Option<&str>], fn_index: u32) {
+    if let Some(Some(name)) = fn_names.get(fn_index as usize) {
+        write!(out, " ${name}").unwrap();
+    }
+    write!(out, " (;{fn_index};)").unwrap();
+}
+
+/// Refer to a function by name if it has one, otherwise by index
+fn write_fn_ref(out: &mut String, fn_names: &[Option<&str>], fn_index: u32) {
+    match fn_names.get(fn_index as usize) {
+        Some(Some(name)) => write!(out, "${name}").unwrap(),
+        _ => write!(out, "{fn_index}").unwrap(),
+    }
+}
+
+fn value_type_name(ty: ValueType) -> &'static str {
+    match ty {
+        ValueType::I32 => "i32",
+        ValueType::I64 => "i64",
+        ValueType::F32 => "f32",
+        ValueType::F64 => "f64",
+        ValueType::V128 => "v128",
+    }
+}
+
+fn write_signature(out: &mut String, signature: &Signature) {
+    if !signature.param_types.is_empty() {
+        out.push_str(" (param");
+        for ty in signature.param_types.iter() {
+            write!(out, " {}", value_type_name(*ty)).unwrap();
+        }
+        out.push(')');
+    }
+    if !signature.ret_types.is_empty() {
+        out.push_str(" (result");
+        for ty in signature.ret_types.iter() {
+            write!(out, " {}", value_type_name(*ty)).unwrap();
+        }
+        out.push(')');
+    }
+}
+
+fn write_limits(out: &mut String, limits: &Limits) {
+    match limits {
+        Limits::Min(min) => write!(out, " {min}").unwrap(),
+        Limits::MinMax(min, max) => write!(out, " {min} {max}").unwrap(),
+    }
+}
+
+fn write_table_type(out: &mut String, ty: &TableType) {
+    write_limits(out, &ty.limits);
+    match ty.ref_type {
+        RefType::Func => out.push_str(" funcref"),
+        RefType::Extern => out.push_str(" externref"),
+    }
+}
+
+fn write_global_type(out: &mut String, ty: &GlobalType) {
+    let name = value_type_name(ty.value_type);
+    if ty.is_mutable {
+        write!(out, "(mut {name})").unwrap();
+    } else {
+        out.push_str(name);
+    }
+}
+
+fn write_const_expr(out: &mut String, expr: &ConstExpr) {
+    match expr {
+        ConstExpr::I32(x) => write!(out, "(i32.const {x})").unwrap(),
+        ConstExpr::I64(x) => write!(out, "(i64.const {x})").unwrap(),
+        ConstExpr::F32(x) => write!(out, "(f32.const {})", f32_text(*x)).unwrap(),
+        ConstExpr::F64(x) => write!(out, "(f64.const {})", f64_text(*x)).unwrap(),
+    }
+}
+
+fn f32_text(x: f32) -> String {
+    if x.is_nan() {
+        let sign = if x.is_sign_negative() { "-" } else { "" };
+        format!("{sign}nan:0x{:x}", x.to_bits() & 0x7f_ffff)
+    } else if x.is_infinite() {
+        let sign = if x < 0.0 { "-" } else { "" };
+        format!("{sign}inf")
+    } else {
+        format!("{x:?}")
+    }
+}
+
+fn f64_text(x: f64) -> String {
+    if x.is_nan() {
+        let sign = if x.is_sign_negative() { "-" } else { "" };
+        format!("{sign}nan:0x{:x}", x.to_bits() & 0xf_ffff_ffff_ffff)
+    } else if x.is_infinite() {
+        let sign = if x < 0.0 { "-" } else { "" };
+        format!("{sign}inf")
+    } else {
+        format!("{x:?}")
+    }
+}
+
+fn write_data_string(out: &mut String, bytes: &[u8]) {
+    out.push('"');
+    for byte in bytes {
+        match byte {
+            b'"' | b'\\' => write!(out, "\\{}", *byte as char).unwrap(),
+            0x20..=0x7e => out.push(*byte as char),
+            _ => write!(out, "\\{byte:02x}").unwrap(),
+        }
+    }
+    out.push('"');
+}
+
+fn instruction_name(op: OpCode, immediates: &Immediates) -> &'static str {
+    use OpCode::*;
+
+    match op {
+        UNREACHABLE => "unreachable",
+        NOP => "nop",
+        BLOCK => "block",
+        LOOP => "loop",
+        IF => "if",
+        ELSE => "else",
+        END => "end",
+        BR => "br",
+        BRIF => "br_if",
+        BRTABLE => "br_table",
+        RETURN => "return",
+        CALL => "call",
+        CALLINDIRECT => "call_indirect",
+        DROP => "drop",
+        SELECT => "select",
+        GETLOCAL => "local.get",
+        SETLOCAL => "local.set",
+        TEELOCAL => "local.tee",
+        GETGLOBAL => "global.get",
+        SETGLOBAL => "global.set",
+        I32LOAD => "i32.load",
+        I64LOAD => "i64.load",
+        F32LOAD => "f32.load",
+        F64LOAD => "f64.load",
+        I32LOAD8S => "i32.load8_s",
+        I32LOAD8U => "i32.load8_u",
+        I32LOAD16S => "i32.load16_s",
+        I32LOAD16U => "i32.load16_u",
+        I64LOAD8S => "i64.load8_s",
+        I64LOAD8U => "i64.load8_u",
+        I64LOAD16S => "i64.load16_s",
+        I64LOAD16U => "i64.load16_u",
+        I64LOAD32S => "i64.load32_s",
+        I64LOAD32U => "i64.load32_u",
+        I32STORE => "i32.store",
+        I64STORE => "i64.store",
+        F32STORE => "f32.store",
+        F64STORE => "f64.store",
+        I32STORE8 => "i32.store8",
+        I32STORE16 => "i32.store16",
+        I64STORE8 => "i64.store8",
+        I64STORE16 => "i64.store16",
+        I64STORE32 => "i64.store32",
+        CURRENTMEMORY => "memory.size",
+        GROWMEMORY => "memory.grow",
+        MEMORY => match immediates {
+            Immediates::Memory(MemoryInstruction::MemoryInit) => "memory.init",
+            Immediates::Memory(MemoryInstruction::DataDrop) => "data.drop",
+            Immediates::Memory(MemoryInstruction::MemoryCopy) => "memory.copy",
+            _ => "memory.fill",
+        },
+        SIMD => match immediates {
+            Immediates::Simd(simd_op) | Immediates::SimdMemArg { op: simd_op, .. } => {
+                simd_instruction_name(*simd_op)
+            }
+            _ => "v128.const",
+        },
+        I32CONST => "i32.const",
+        I64CONST => "i64.const",
+        F32CONST => "f32.const",
+        F64CONST => "f64.const",
+        I32EQZ => "i32.eqz",
+        I32EQ => "i32.eq",
+        I32NE => "i32.ne",
+        I32LTS => "i32.lt_s",
+        I32LTU => "i32.lt_u",
+        I32GTS => "i32.gt_s",
+        I32GTU => "i32.gt_u",
+        I32LES => "i32.le_s",
+        I32LEU => "i32.le_u",
+        I32GES => "i32.ge_s",
+        I32GEU => "i32.ge_u",
+        I64EQZ => "i64.eqz",
+        I64EQ => "i64.eq",
+        I64NE => "i64.ne",
+        I64LTS => "i64.lt_s",
+        I64LTU => "i64.lt_u",
+        I64GTS => "i64.gt_s",
+        I64GTU => "i64.gt_u",
+        I64LES => "i64.le_s",
+        I64LEU => "i64.le_u",
+        I64GES => "i64.ge_s",
+        I64GEU => "i64.ge_u",
+        F32EQ => "f32.eq",
+        F32NE => "f32.ne",
+        F32LT => "f32.lt",
+        F32GT => "f32.gt",
+        F32LE => "f32.le",
+        F32GE => "f32.ge",
+        F64EQ => "f64.eq",
+        F64NE => "f64.ne",
+        F64LT => "f64.lt",
+        F64GT => "f64.gt",
+        F64LE => "f64.le",
+        F64GE => "f64.ge",
+        I32CLZ => "i32.clz",
+        I32CTZ => "i32.ctz",
+        I32POPCNT => "i32.popcnt",
+        I32ADD => "i32.add",
+        I32SUB => "i32.sub",
+        I32MUL => "i32.mul",
+        I32DIVS => "i32.div_s",
+        I32DIVU => "i32.div_u",
+        I32REMS => "i32.rem_s",
+        I32REMU => "i32.rem_u",
+        I32AND => "i32.and",
+        I32OR => "i32.or",
+        I32XOR => "i32.xor",
+        I32SHL => "i32.shl",
+        I32SHRS => "i32.shr_s",
+        I32SHRU => "i32.shr_u",
+        I32ROTL => "i32.rotl",
+        I32ROTR => "i32.rotr",
+        I64CLZ => "i64.clz",
+        I64CTZ => "i64.ctz",
+        I64POPCNT => "i64.popcnt",
+        I64ADD => "i64.add",
+        I64SUB => "i64.sub",
+        I64MUL => "i64.mul",
+        I64DIVS => "i64.div_s",
+        I64DIVU => "i64.div_u",
+        I64REMS => "i64.rem_s",
+        I64REMU => "i64.rem_u",
+        I64AND => "i64.and",
+        I64OR => "i64.or",
+        I64XOR => "i64.xor",
+        I64SHL => "i64.shl",
+        I64SHRS => "i64.shr_s",
+        I64SHRU => "i64.shr_u",
+        I64ROTL => "i64.rotl",
+        I64ROTR => "i64.rotr",
+        F32ABS => "f32.abs",
+        F32NEG => "f32.neg",
+        F32CEIL => "f32.ceil",
+        F32FLOOR => "f32.floor",
+        F32TRUNC => "f32.trunc",
+        F32NEAREST => "f32.nearest",
+        F32SQRT => "f32.sqrt",
+        F32ADD => "f32.add",
+        F32SUB => "f32.sub",
+        F32MUL => "f32.mul",
+        F32DIV => "f32.div",
+        F32MIN => "f32.min",
+        F32MAX => "f32.max",
+        F32COPYSIGN => "f32.copysign",
+        F64ABS => "f64.abs",
+        F64NEG => "f64.neg",
+        F64CEIL => "f64.ceil",
+        F64FLOOR => "f64.floor",
+        F64TRUNC => "f64.trunc",
+        F64NEAREST => "f64.nearest",
+        F64SQRT => "f64.sqrt",
+        F64ADD => "f64.add",
+        F64SUB => "f64.sub",
+        F64MUL => "f64.mul",
+        F64DIV => "f64.div",
+        F64MIN => "f64.min",
+        F64MAX => "f64.max",
+        F64COPYSIGN => "f64.copysign",
+        I32WRAPI64 => "i32.wrap_i64",
+        I32TRUNCSF32 => "i32.trunc_f32_s",
+        I32TRUNCUF32 => "i32.trunc_f32_u",
+        I32TRUNCSF64 => "i32.trunc_f64_s",
+        I32TRUNCUF64 => "i32.trunc_f64_u",
+        I64EXTENDSI32 => "i64.extend_i32_s",
+        I64EXTENDUI32 => "i64.extend_i32_u",
+        I64TRUNCSF32 => "i64.trunc_f32_s",
+        I64TRUNCUF32 => "i64.trunc_f32_u",
+        I64TRUNCSF64 => "i64.trunc_f64_s",
+        I64TRUNCUF64 => "i64.trunc_f64_u",
+        F32CONVERTSI32 => "f32.convert_i32_s",
+        F32CONVERTUI32 => "f32.convert_i32_u",
+        F32CONVERTSI64 => "f32.convert_i64_s",
+        F32CONVERTUI64 => "f32.convert_i64_u",
+        F32DEMOTEF64 => "f32.demote_f64",
+        F64CONVERTSI32 => "f64.convert_i32_s",
+        F64CONVERTUI32 => "f64.convert_i32_u",
+        F64CONVERTSI64 => "f64.convert_i64_s",
+        F64CONVERTUI64 => "f64.convert_i64_u",
+        F64PROMOTEF32 => "f64.promote_f32",
+        I32REINTERPRETF32 => "i32.reinterpret_f32",
+        I64REINTERPRETF64 => "i64.reinterpret_f64",
+        F32REINTERPRETI32 => "f32.reinterpret_i32",
+        F64REINTERPRETI64 => "f64.reinterpret_i64",
+        I32EXTEND8S => "i32.extend8_s",
+        I32EXTEND16S => "i32.extend16_s",
+        I64EXTEND8S => "i64.extend8_s",
+        I64EXTEND16S => "i64.extend16_s",
+        I64EXTEND32S => "i64.extend32_s",
+    }
+}
+
+fn simd_instruction_name(op: SimdInstruction) -> &'static str {
+    use SimdInstruction::*;
+
+    match op {
+        V128Load => "v128.load",
+        V128Store => "v128.store",
+        V128Const => "v128.const",
+        I8x16Splat => "i8x16.splat",
+        I8x16Eq => "i8x16.eq",
+        I8x16Ne => "i8x16.ne",
+        V128Not => "v128.not",
+        V128And => "v128.and",
+        V128Or => "v128.or",
+        V128Xor => "v128.xor",
+        V128AnyTrue => "v128.any_true",
+        I8x16AllTrue => "i8x16.all_true",
+        I8x16Bitmask => "i8x16.bitmask",
+    }
+}
+
+/// Natural alignment of a load or store, as a power of 2
+fn natural_alignment(op: OpCode) -> u32 {
+    use OpCode::*;
+
+    match op {
+        I32LOAD8S | I32LOAD8U | I64LOAD8S | I64LOAD8U | I32STORE8 | I64STORE8 => 0,
+        I32LOAD16S | I32LOAD16U | I64LOAD16S | I64LOAD16U | I32STORE16 | I64STORE16 => 1,
+        I32LOAD | F32LOAD | I64LOAD32S | I64LOAD32U | I32STORE | F32STORE | I64STORE32 => 2,
+        _ => 3,
+    }
+}
+
+/// Write the locals and instructions of a function body, followed by the closing paren
+fn write_function_body(
+    arena: &Bump,
+    out: &mut String,
+    fn_names: &[Option<&str>],
+    param_count: usize,
+    body: &[u8],
+) {
+    let mut cursor = 0;
+
+    // Locals are numbered after the params
+    if let Ok(n_local_groups) = u32::parse((), body, &mut cursor) {
+        let mut local_types = Vec::new_in(arena);
+        for _ in 0..n_local_groups {
+            let Ok(count) = u32::parse((), body, &mut cursor) else {
+                break;
+            };
+            let Some(&ty_byte) = body.get(cursor) else {
+                break;
+            };
+            cursor += 1;
+            local_types.push((count, ty_byte));
+        }
+        if !local_types.is_empty() {
+            write!(out, "{INDENT}{INDENT}(local").unwrap();
+            for (count, ty_byte) in local_types.iter() {
+                let name = match ty_byte {
+                    0x7f => "i32",
+                    0x7e => "i64",
+                    0x7d => "f32",
+                    0x7c => "f64",
+                    0x7b => "v128",
+                    _ => "<invalid type>",
+                };
+                for _ in 0..*count {
+                    write!(out, " {name}").unwrap();
+                }
+            }
+            writeln!(out, ") ;; locals start at {param_count}").unwrap();
+        }
+    }
+
+    let mut depth = 1;
+    while cursor < body.len() {
+        let op_offset = cursor;
+        let Instruction { op, immediates } = match Instruction::parse(arena, body, &mut cursor) {
+            Ok(instruction) => instruction,
+            Err(e) => {
+                let indent = INDENT.repeat(depth + 1);
+                writeln!(out, "{indent};; offset 0x{op_offset:x}: {}", e.message).unwrap();
+                break;
+            }
+        };
+
+        if matches!(op, OpCode::END | OpCode::ELSE) {
+            depth -= 1;
+        }
+        if depth == 0 {
+            // The final `end` closes the function
+            break;
+        }
+
+        out.push_str(&INDENT.repeat(depth + 1));
+        out.push_str(instruction_name(op, &immediates));
+        match &immediates {
+            Immediates::None
+            | Immediates::MemoryIndex(_)
+            | Immediates::Memory(_)
+            | Immediates::Simd(_) => {}
+            Immediates::BlockType(bt) => {
+                if *bt != ValueType::VOID {
+                    let name = match bt {
+                        0x7f => "i32",
+                        0x7e => "i64",
+                        0x7d => "f32",
+                        0x7c => "f64",
+                        0x7b => "v128",
+                        _ => "<invalid type>",
+                    };
+                    write!(out, " (result {name})").unwrap();
+                }
+                write!(out, "  ;; label = @{depth}").unwrap();
+            }
+            Immediates::Index(index) => {
+                out.push(' ');
+                if op == OpCode::CALL {
+                    write_fn_ref(out, fn_names, *index);
+                } else {
+                    write!(out, "{index}").unwrap();
+                }
+            }
+            Immediates::BrTable { targets, default } => {
+                for target in targets.iter() {
+                    write!(out, " {target}").unwrap();
+                }
+                write!(out, " {default}").unwrap();
+            }
+            Immediates::CallIndirect {
+                type_index,
+                table_index,
+            } => {
+                if *table_index != 0 {
+                    write!(out, " {table_index}").unwrap();
+                }
+                write!(out, " (type {type_index})").unwrap();
+            }
+            Immediates::MemArg { align, offset } => {
+                if *offset != 0 {
+                    write!(out, " offset={offset}").unwrap();
+                }
+                if *align != natural_alignment(op) {
+                    write!(out, " align={}", 1u64 << (*align).min(63)).unwrap();
+                }
+            }
+            Immediates::SimdMemArg { align, offset, .. } => {
+                if *offset != 0 {
+                    write!(out, " offset={offset}").unwrap();
+                }
+                if *align != 4 {
+                    write!(out, " align={}", 1u64 << (*align).min(63)).unwrap();
+                }
+            }
+            Immediates::I32(x) => write!(out, " {x}").unwrap(),
+            Immediates::I64(x) => write!(out, " {x}").unwrap(),
+            Immediates::F32(x) => write!(out, " {}", f32_text(*x)).unwrap(),
+            Immediates::F64(x) => write!(out, " {}", f64_text(*x)).unwrap(),
+            Immediates::V128(x) => {
+                out.push_str(" i32x4");
+                for lane in 0..4 {
+                    write!(out, " 0x{:08x}", (*x >> (32 * lane)) as u32).unwrap();
+                }
+            }
+        }
+        out.push('\n');
+
+        if matches!(op, OpCode::BLOCK | OpCode::LOOP | OpCode::IF | OpCode::ELSE) {
+            depth += 1;
+        }
+    }
+
+    // Close the function on the same line as its last instruction, like wasm2wat
+    if out.ends_with('\n') {
+        out.pop();
+    }
+    out.push_str(")\n");
+}
+
+/*******************************************************************
+ *
+ * Unit tests
+ *
+ *******************************************************************/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{DataSegment, MemorySection};
+    use crate::{Export, SerialBuffer};
+
+    #[test]
+    fn test_function_with_names_and_blocks() {
+        use OpCode::*;
+
+        let arena = &Bump::new();
+        let mut module = WasmModule::new(arena);
+        module.memory = MemorySection::new(arena, MemorySection::PAGE_SIZE);
+        module.add_function_signature(Signature {
+            param_types: bumpalo::vec![in arena; ValueType::I32],
+            ret_types: bumpalo::vec![in arena; ValueType::I32],
+        });
+        module.names.append_function(0, "Num.abs");
+        module.export.append(Export {
+            name: "abs",
+            ty: ExportType::Func,
+            index: 0,
+        });
+
+        #[rustfmt::skip]
+        let body = [
+            1, 1, 0x7e, // one i64 local
+            GETLOCAL as u8, 0,
+            I32CONST as u8, 0,
+            I32LTS as u8,
+            IF as u8, ValueType::I32 as u8,
+                I32CONST as u8, 0,
+                GETLOCAL as u8, 0,
+                I32SUB as u8,
+            ELSE as u8,
+                GETLOCAL as u8, 0,
+            END as u8,
+            I32LOAD as u8, 0, 8,
+            END as u8,
+        ];
+        let buf = &mut module.code.bytes;
+        let len_index = buf.encode_padded_u32(0);
+        let start = buf.len();
+        buf.extend_from_slice(&body);
+        buf.overwrite_padded_u32(len_index, (buf.len() - start) as u32);
+        module.code.function_offsets.push(len_index as u32);
+        module.code.function_count = 1;
+
+        module.data.append_segment(DataSegment {
+            mode: DataMode::active_at(1024),
+            init: bumpalo::vec![in arena; b'h', b'i', b'"', 0],
+        });
+
+        let expected = r#"(module
+  (type (;0;) (func (param i32) (result i32)))
+  (func $Num.abs (;0;) (type 0) (param i32) (result i32)
+    (local i64) ;; locals start at 1
+    local.get 0
+    i32.const 0
+    i32.lt_s
+    if (result i32)  ;; label = @1
+      i32.const 0
+      local.get 0
+      i32.sub
+    else
+      local.get 0
+    end
+    i32.load offset=8 align=1)
+  (memory (;0;) 1)
+  (export "abs" (func $Num.abs))
+  (data (;0;) (i32.const 1024) "hi\"\00")
+)
+"#;
+        assert_eq!(module.to_wat(arena), expected);
+    }
+
+    #[test]
+    fn test_multi_value_and_simd() {
+        use OpCode::*;
+        use SimdInstruction::*;
+
+        let arena = &Bump::new();
+        let mut module = WasmModule::new(arena);
+        module.add_function_signature(Signature {
+            param_types: bumpalo::vec![in arena; ValueType::I32],
+            ret_types: bumpalo::vec![in arena; ValueType::I32, ValueType::I32],
+        });
+
+        #[rustfmt::skip]
+        let load = [
+            1, 1, 0x7b, // one v128 local
+            GETLOCAL as u8, 0,
+            SIMD as u8, V128Load as u8, 0, 16,
+            SIMD as u8, V128Const as u8,
+        ];
+        #[rustfmt::skip]
+        let compare = [
+            SIMD as u8, I8x16Eq as u8,
+            TEELOCAL as u8, 1,
+            SIMD as u8, I8x16AllTrue as u8,
+            GETLOCAL as u8, 1,
+            SIMD as u8, I8x16Bitmask as u8,
+            END as u8,
+        ];
+        let body = [&load[..], &0x0403020100u128.to_le_bytes(), &compare[..]].concat();
+        let buf = &mut module.code.bytes;
+        let len_index = buf.encode_padded_u32(0);
+        let start = buf.len();
+        buf.extend_from_slice(&body);
+        buf.overwrite_padded_u32(len_index, (buf.len() - start) as u32);
+        module.code.function_offsets.push(len_index as u32);
+        module.code.function_count = 1;
+
+        let expected = r#"(module
+  (type (;0;) (func (param i32) (result i32 i32)))
+  (func (;0;) (type 0) (param i32) (result i32 i32)
+    (local v128) ;; locals start at 1
+    local.get 0
+    v128.load offset=16 align=1
+    v128.const i32x4 0x03020100 0x00000004 0x00000000 0x00000000
+    i8x16.eq
+    local.tee 1
+    i8x16.all_true
+    local.get 1
+    i8x16.bitmask)
+)
+"#;
+        assert_eq!(module.to_wat(arena), expected);
+    }
+}
@@ -39,6 +39,7 @@ GuiFormatter := { nodes : List Elem }
             f32: f32,
             f64: f64,
             dec: dec,
+            nat: nat,
 
         },
     ]
@@ -219,6 +220,11 @@ dec = \num ->
     f0 <- Inspect.custom
     addNode f0 (num |> Num.toStr |> Text)
 
+nat : Nat -> Inspector GuiFormatter
+nat = \num ->
+    f0 <- Inspect.custom
+    addNode f0 (num |> Num.toStr |> Text)
+
 addNode : GuiFormatter, Elem -> GuiFormatter
 addNode = \@GuiFormatter { nodes }, node ->
     @GuiFormatter { nodes: List.append nodes node }
@@ -33,6 +33,7 @@ LogFormatter := { data : Str }
             f32: f32,
             f64: f64,
             dec: dec,
+            nat: nat,
 
         },
     ]
@@ -238,6 +239,11 @@ dec = \num ->
     f0 <- Inspect.custom
     write f0 (num |> Num.toStr)
 
+nat : Nat -> Inspector LogFormatter
+nat = \num ->
+    f0 <- Inspect.custom
+    write f0 (num |> Num.toStr)
+
 write : LogFormatter, Str -> LogFormatter
 write = \@LogFormatter { data }, added ->
     @LogFormatter { data: Str.concat data added }